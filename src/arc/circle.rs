@@ -1,5 +1,7 @@
-use super::super::bezier::path::BezierPathFactory;
+use super::super::bezier::path::{BezierPathFactory, PathDirection};
 use super::super::bezier::{BezierCurve, BezierCurveFactory, Coordinate, Coordinate2D, Curve};
+use super::super::line::Line;
+use super::ellipse::{Ellipse, EllipticalArc};
 
 use std::f64;
 
@@ -21,16 +23,27 @@ pub struct Circle<Coord: Coordinate2D + Coordinate> {
 ///
 /// Represents an arc of a circle in 2 dimensions
 ///
-#[derive(Clone, Copy)]
-pub struct CircularArc<'a, Coord: 'a + Coordinate2D + Coordinate> {
-    /// The circle that this is an arc of
-    circle: &'a Circle<Coord>,
+/// Angles are measured anticlockwise from the x axis, so the point at angle `a` is `(radius * cos(a), radius * sin(a))`
+/// moved to the center of the circle. The arc moves from the start angle to the end angle in its direction, so it covers
+/// less than a full circle: if the start and end angles are the same, the arc is empty. The exception is when the end
+/// angle is a full turn or more past the start angle in the arc's direction, in which case the arc is the whole circle.
+///
+#[derive(Clone, Copy, Debug)]
+pub struct CircularArc<Coord: Coordinate2D + Coordinate> {
+    /// The center of the circle that this is an arc of
+    center: Coord,
+
+    /// The radius of the circle that this is an arc of
+    radius: f64,
 
     /// The start point of this arc, in radians
     start_radians: f64,
 
     /// The end point of this arc, in radians
     end_radians: f64,
+
+    /// The direction that this arc travels around the circle in
+    direction: PathDirection,
 }
 
 impl<Coord: Coordinate2D + Coordinate> Circle<Coord> {
@@ -44,12 +57,24 @@ impl<Coord: Coordinate2D + Coordinate> Circle<Coord> {
     ///
     /// Returns an object representing an arc from this circle
     ///
+    /// The angles for this function are measured clockwise from the y axis, so `arc(0.0, PI/2.0)` runs clockwise from
+    /// the top of the circle to its right-hand side. See `CircularArc::new()` to create an arc using angles measured
+    /// from the x axis.
+    ///
     pub fn arc(&self, start_radians: f64, end_radians: f64) -> CircularArc<Coord> {
-        CircularArc {
-            circle: self,
-            start_radians,
-            end_radians,
-        }
+        let direction = if end_radians >= start_radians {
+            PathDirection::Clockwise
+        } else {
+            PathDirection::Anticlockwise
+        };
+
+        CircularArc::new(
+            self.center,
+            self.radius,
+            f64::consts::FRAC_PI_2 - start_radians,
+            f64::consts::FRAC_PI_2 - end_radians,
+            direction,
+        )
     }
 
     ///
@@ -91,64 +116,270 @@ impl<Coord: Coordinate2D + Coordinate> Circle<Coord> {
     }
 }
 
-impl<'a, Coord: Coordinate2D + Coordinate> CircularArc<'a, Coord> {
+impl<Coord: Coordinate2D + Coordinate> CircularArc<Coord> {
     ///
-    /// Converts this arc to a bezier curve
+    /// Creates an arc of the circle with the specified center and radius, travelling from the start angle to the end angle
+    /// in the specified direction
     ///
-    /// If this arc covers an angle > 90 degrees, the curve will
-    /// be very inaccurate.
+    pub fn new(
+        center: Coord,
+        radius: f64,
+        start_radians: f64,
+        end_radians: f64,
+        direction: PathDirection,
+    ) -> Self {
+        Self {
+            center,
+            radius,
+            start_radians,
+            end_radians,
+            direction,
+        }
+    }
+
     ///
-    pub fn to_bezier_curve<Curve: BezierCurveFactory<Point = Coord>>(&self) -> Curve {
-        // Algorithm described here: https://www.tinaja.com/glib/bezcirc2.pdf
-        // Curve for the unit arc with its center at (1,0)
-        let theta = self.end_radians - self.start_radians;
-        let (x0, y0) = ((theta / 2.0).cos(), (theta / 2.0).sin());
-        let (x1, y1) = ((4.0 - x0) / 3.0, ((1.0 - x0) * (3.0 - x0) / (3.0 * y0)));
-        let (x2, y2) = (x1, -y1);
-        let (x3, y3) = (x0, -y0);
-
-        // Rotate so the curve starts at start_radians
-        fn rotate(x: f64, y: f64, theta: f64) -> (f64, f64) {
-            let (cos_theta, sin_theta) = (theta.cos(), theta.sin());
+    /// Creates the arc that starts at `start`, passes through `through` and finishes at `end`
+    ///
+    /// Returns `None` if the three points are on a straight line (or if two of them are in the same place), as there's
+    /// no circle that passes through all of them.
+    ///
+    pub fn from_three_points(start: Coord, through: Coord, end: Coord) -> Option<Self> {
+        // The center is where the perpendicular bisectors of the lines between the points meet
+        let (ax, ay) = (start.x(), start.y());
+        let (bx, by) = (through.x(), through.y());
+        let (cx, cy) = (end.x(), end.y());
+
+        let determinant = 2.0 * (ax * (by - cy) + bx * (cy - ay) + cx * (ay - by));
+        if determinant.abs() < 1e-12 {
+            return None;
+        }
+
+        let (a_squared, b_squared, c_squared) =
+            (ax * ax + ay * ay, bx * bx + by * by, cx * cx + cy * cy);
+        let center_x =
+            (a_squared * (by - cy) + b_squared * (cy - ay) + c_squared * (ay - by)) / determinant;
+        let center_y =
+            (a_squared * (cx - bx) + b_squared * (ax - cx) + c_squared * (bx - ax)) / determinant;
+        let center = Coord::from_components(&[center_x, center_y]);
 
+        // The arc turns left (anticlockwise) if the through point is on the left of the line from the start to the end
+        let direction = if determinant > 0.0 {
+            PathDirection::Anticlockwise
+        } else {
+            PathDirection::Clockwise
+        };
+
+        Some(Self::new(
+            center,
+            start.distance_to(&center),
+            angle_to(&center, &start),
+            angle_to(&center, &end),
+            direction,
+        ))
+    }
+
+    ///
+    /// Creates an arc with the specified radius that is tangent to two lines, rounding off the corner where they meet
+    ///
+    /// The first line is considered to run into the corner and the second line to run away from it, so the arc starts on
+    /// the first line and finishes on the second, turning the same way as the lines do (this is the 'fillet' operation
+    /// found in CAD software). The lines are treated as infinitely long, so if the radius is large, the arc can touch them
+    /// beyond their end points. Returns `None` if the lines are parallel, as they have no corner to round off.
+    ///
+    pub fn from_tangent_lines<L1: Line<Point = Coord>, L2: Line<Point = Coord>>(
+        line1: &L1,
+        line2: &L2,
+        radius: f64,
+    ) -> Option<Self> {
+        let (start1, end1) = line1.points();
+        let (start2, end2) = line2.points();
+
+        let direction1 = (end1 - start1).to_unit_vector();
+        let direction2 = (end2 - start2).to_unit_vector();
+        let cross = direction1.x() * direction2.y() - direction1.y() * direction2.x();
+
+        if cross.abs() < 1e-12 {
+            return None;
+        }
+
+        // Find the corner where the lines meet
+        let offset = start2 - start1;
+        let along_line1 = (offset.x() * direction2.y() - offset.y() * direction2.x()) / cross;
+        let corner = start1 + direction1 * along_line1;
+
+        // The tangent points are the same distance from the corner, which depends on how sharply the lines turn
+        let turn_angle = direction1.dot(&direction2).clamp(-1.0, 1.0).acos();
+        let corner_distance = radius * (turn_angle / 2.0).tan();
+        let tangent1 = corner - direction1 * corner_distance;
+        let tangent2 = corner + direction2 * corner_distance;
+
+        // The center is on the side of the first line that the lines turn towards
+        let (normal, direction) = if cross > 0.0 {
+            (
+                Coord::from_components(&[-direction1.y(), direction1.x()]),
+                PathDirection::Anticlockwise,
+            )
+        } else {
             (
-                x * cos_theta + y * sin_theta,
-                x * -sin_theta + y * cos_theta,
+                Coord::from_components(&[direction1.y(), -direction1.x()]),
+                PathDirection::Clockwise,
             )
+        };
+        let center = tangent1 + normal * radius;
+
+        Some(Self::new(
+            center,
+            radius,
+            angle_to(&center, &tangent1),
+            angle_to(&center, &tangent2),
+            direction,
+        ))
+    }
+
+    ///
+    /// The center of the circle that this is an arc of
+    ///
+    pub fn center(&self) -> Coord {
+        self.center
+    }
+
+    ///
+    /// The radius of the circle that this is an arc of
+    ///
+    pub fn radius(&self) -> f64 {
+        self.radius
+    }
+
+    ///
+    /// The angle that this arc starts at, in radians
+    ///
+    pub fn start_radians(&self) -> f64 {
+        self.start_radians
+    }
+
+    ///
+    /// The angle that this arc ends at, in radians
+    ///
+    pub fn end_radians(&self) -> f64 {
+        self.end_radians
+    }
+
+    ///
+    /// The direction that this arc travels around its circle
+    ///
+    pub fn direction(&self) -> PathDirection {
+        self.direction
+    }
+
+    ///
+    /// The angle covered by this arc, in radians: this is positive for anticlockwise arcs and negative for clockwise ones
+    ///
+    pub fn sweep_radians(&self) -> f64 {
+        let full_circle = f64::consts::PI * 2.0;
+        let turn = match self.direction {
+            PathDirection::Anticlockwise => self.end_radians - self.start_radians,
+            PathDirection::Clockwise => self.start_radians - self.end_radians,
+        };
+
+        // Angles that are a full turn or more apart cover the whole circle, rather than wrapping around to an empty arc
+        let sweep = if turn >= full_circle {
+            full_circle
+        } else {
+            turn.rem_euclid(full_circle)
+        };
+
+        match self.direction {
+            PathDirection::Anticlockwise => sweep,
+            PathDirection::Clockwise => -sweep,
         }
+    }
 
-        let angle = -(f64::consts::PI / 2.0 - (theta / 2.0));
-        let angle = angle + self.start_radians;
+    ///
+    /// The point where this arc starts
+    ///
+    pub fn start_point(&self) -> Coord {
+        point_on_circle(&self.center, self.radius, self.start_radians)
+    }
 
-        let (x0, y0) = rotate(x0, y0, angle);
-        let (x1, y1) = rotate(x1, y1, angle);
-        let (x2, y2) = rotate(x2, y2, angle);
-        let (x3, y3) = rotate(x3, y3, angle);
+    ///
+    /// The point where this arc ends
+    ///
+    pub fn end_point(&self) -> Coord {
+        point_on_circle(&self.center, self.radius, self.end_radians)
+    }
 
-        // Scale by radius
-        let radius = self.circle.radius;
-        let (x0, y0) = (x0 * radius, y0 * radius);
-        let (x1, y1) = (x1 * radius, y1 * radius);
-        let (x2, y2) = (x2 * radius, y2 * radius);
-        let (x3, y3) = (x3 * radius, y3 * radius);
+    ///
+    /// Converts this arc to a bezier curve
+    ///
+    /// If this arc covers an angle > 90 degrees, the curve will
+    /// be very inaccurate.
+    ///
+    pub fn to_bezier_curve<Curve: BezierCurveFactory<Point = Coord>>(&self) -> Curve {
+        // The control points are along the tangents at the start and end, 4/3*tan(sweep/4) times the radius away
+        let sweep_radians = self.sweep_radians();
+        let handle_length = self.radius * (4.0 / 3.0) * (sweep_radians / 4.0).tan();
+        let end_radians = self.start_radians + sweep_radians;
 
-        // Translate by center
-        let center = &self.circle.center;
-        let (x0, y0) = (x0 + center.x(), y0 + center.y());
-        let (x1, y1) = (x1 + center.x(), y1 + center.y());
-        let (x2, y2) = (x2 + center.x(), y2 + center.y());
-        let (x3, y3) = (x3 + center.x(), y3 + center.y());
+        let start = self.start_point();
+        let end = self.end_point();
+        let (start_sin, start_cos) = self.start_radians.sin_cos();
+        let (end_sin, end_cos) = end_radians.sin_cos();
+        let cp1 = start + Coord::from_components(&[-start_sin, start_cos]) * handle_length;
+        let cp2 = end - Coord::from_components(&[-end_sin, end_cos]) * handle_length;
 
-        // Create the curve
-        let p0 = Coord::from_components(&[x0, y0]);
-        let p1 = Coord::from_components(&[x1, y1]);
-        let p2 = Coord::from_components(&[x2, y2]);
-        let p3 = Coord::from_components(&[x3, y3]);
+        Curve::from_points(start, (cp1, cp2), end)
+    }
 
-        Curve::from_points(p0, (p1, p2), p3)
+    ///
+    /// Converts this arc to a set of bezier curves that are within `max_error` of the true arc
+    ///
+    pub fn to_curves<Curve: BezierCurveFactory<Point = Coord>>(
+        &self,
+        max_error: f64,
+    ) -> Vec<Curve> {
+        self.as_elliptical_arc().to_curves(max_error)
+    }
+
+    ///
+    /// Converts this arc to a path that's within `max_error` of the true arc
+    ///
+    pub fn to_path<P: BezierPathFactory<Point = Coord>>(&self, max_error: f64) -> P {
+        self.as_elliptical_arc().to_path(max_error)
+    }
+
+    ///
+    /// Returns this arc as the arc of an ellipse with equal radii
+    ///
+    fn as_elliptical_arc(&self) -> EllipticalArc<Coord> {
+        Ellipse::new(self.center, self.radius, self.radius, 0.0).arc(
+            self.start_radians,
+            self.start_radians + self.sweep_radians(),
+        )
     }
 }
 
+///
+/// Returns the point at an angle around a circle
+///
+#[inline]
+fn point_on_circle<Coord: Coordinate + Coordinate2D>(
+    center: &Coord,
+    radius: f64,
+    radians: f64,
+) -> Coord {
+    let (sin, cos) = radians.sin_cos();
+
+    Coord::from_components(&[center.x() + radius * cos, center.y() + radius * sin])
+}
+
+///
+/// Returns the angle of a point relative to a center point, measured anticlockwise from the x axis
+///
+#[inline]
+fn angle_to<Coord: Coordinate + Coordinate2D>(center: &Coord, point: &Coord) -> f64 {
+    (point.y() - center.y()).atan2(point.x() - center.x())
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -192,4 +423,163 @@ mod test {
             }
         }
     }
+
+    #[test]
+    fn arc_between_angles_is_on_circle() {
+        let arc = CircularArc::new(
+            Coord2(5.0, 5.0),
+            4.0,
+            0.0,
+            f64::consts::PI * 1.5,
+            PathDirection::Anticlockwise,
+        );
+        let curves = arc.to_curves::<Curve<_>>(0.001);
+
+        assert!(curves.len() >= 3);
+        assert!(curves[0].start_point().distance_to(&Coord2(9.0, 5.0)) < 0.001);
+        assert!(
+            curves[curves.len() - 1]
+                .end_point()
+                .distance_to(&Coord2(5.0, 1.0))
+                < 0.001
+        );
+        assert!(curves.iter().any(|curve| curve.point_at_pos(0.5).y() > 8.0));
+
+        for curve in curves {
+            for t in 0..=10 {
+                let p = curve.point_at_pos((t as f64) / 10.0);
+                assert!((p.distance_to(&Coord2(5.0, 5.0)) - 4.0).abs() < 0.001);
+            }
+        }
+    }
+
+    #[test]
+    fn full_turn_arc_is_whole_circle() {
+        let circle = Circle::new(Coord2(5.0, 5.0), 4.0);
+        let arc = circle.arc(0.0, f64::consts::PI * 2.0);
+
+        assert!((arc.sweep_radians() + f64::consts::PI * 2.0).abs() < 1e-9);
+
+        let curves = arc.to_curves::<Curve<_>>(0.001);
+        assert!(curves.len() >= 4);
+        assert!(curves[0].start_point().distance_to(&Coord2(5.0, 9.0)) < 0.001);
+        assert!(
+            curves[curves.len() - 1]
+                .end_point()
+                .distance_to(&Coord2(5.0, 9.0))
+                < 0.001
+        );
+        assert!(curves.iter().any(|curve| curve.point_at_pos(0.5).y() < 2.0));
+
+        for curve in curves {
+            for t in 0..=10 {
+                let p = curve.point_at_pos((t as f64) / 10.0);
+                assert!((p.distance_to(&Coord2(5.0, 5.0)) - 4.0).abs() < 0.001);
+            }
+        }
+
+        // Angles the same distance apart in the other direction are also a full circle
+        let arc = circle.arc(f64::consts::PI * 2.0, 0.0);
+        assert!((arc.sweep_radians() - f64::consts::PI * 2.0).abs() < 1e-9);
+        assert!(arc.to_curves::<Curve<_>>(0.001).len() >= 4);
+    }
+
+    #[test]
+    fn clockwise_arc_goes_the_short_way_round() {
+        let arc = CircularArc::new(
+            Coord2(0.0, 0.0),
+            1.0,
+            f64::consts::PI / 2.0,
+            0.0,
+            PathDirection::Clockwise,
+        );
+
+        assert!((arc.sweep_radians() + f64::consts::PI / 2.0).abs() < 1e-9);
+
+        let curve = arc.to_bezier_curve::<Curve<_>>();
+        let mid_point = curve.point_at_pos(0.5);
+        assert!(mid_point.x() > 0.5 && mid_point.y() > 0.5);
+    }
+
+    #[test]
+    fn arc_from_three_points() {
+        let arc =
+            CircularArc::from_three_points(Coord2(6.0, 2.0), Coord2(2.0, 6.0), Coord2(-2.0, 2.0))
+                .unwrap();
+
+        assert!(arc.center().distance_to(&Coord2(2.0, 2.0)) < 1e-9);
+        assert!((arc.radius() - 4.0).abs() < 1e-9);
+        assert!(arc.direction() == PathDirection::Anticlockwise);
+
+        let path = arc.to_path::<SimpleBezierPath>(0.001);
+        let curves = path_to_curves::<_, Curve<_>>(&path).collect::<Vec<_>>();
+        assert!(curves[0].start_point().distance_to(&Coord2(6.0, 2.0)) < 0.001);
+        assert!(
+            curves[curves.len() - 1]
+                .end_point()
+                .distance_to(&Coord2(-2.0, 2.0))
+                < 0.001
+        );
+        assert!(curves
+            .iter()
+            .any(|curve| curve.point_at_pos(0.5).distance_to(&Coord2(2.0, 6.0)) < 0.5));
+    }
+
+    #[test]
+    fn arc_from_three_points_in_a_line() {
+        assert!(CircularArc::from_three_points(
+            Coord2(0.0, 0.0),
+            Coord2(1.0, 1.0),
+            Coord2(2.0, 2.0)
+        )
+        .is_none());
+    }
+
+    #[test]
+    fn arc_tangent_to_two_lines() {
+        // Right-angle corner at (10, 0), turning left
+        let arc = CircularArc::from_tangent_lines(
+            &(Coord2(0.0, 0.0), Coord2(10.0, 0.0)),
+            &(Coord2(10.0, 0.0), Coord2(10.0, 10.0)),
+            2.0,
+        )
+        .unwrap();
+
+        assert!(arc.center().distance_to(&Coord2(8.0, 2.0)) < 1e-9);
+        assert!(arc.direction() == PathDirection::Anticlockwise);
+        assert!(arc.start_point().distance_to(&Coord2(8.0, 0.0)) < 1e-9);
+        assert!(arc.end_point().distance_to(&Coord2(10.0, 2.0)) < 1e-9);
+        assert!((arc.sweep_radians() - f64::consts::PI / 2.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn arc_tangent_to_two_lines_turning_right() {
+        let arc = CircularArc::from_tangent_lines(
+            &(Coord2(0.0, 0.0), Coord2(10.0, 0.0)),
+            &(Coord2(20.0, -10.0), Coord2(30.0, -20.0)),
+            1.0,
+        )
+        .unwrap();
+
+        assert!(arc.direction() == PathDirection::Clockwise);
+
+        // The curve should meet both lines at a tangent
+        let curve = arc.to_bezier_curve::<Curve<_>>();
+        let (cp1, cp2) = curve.control_points();
+        assert!(curve.start_point().y().abs() < 1e-9);
+        assert!((cp1.y() - curve.start_point().y()).abs() < 1e-9);
+        assert!(((curve.end_point().x() - 10.0) + curve.end_point().y()).abs() < 1e-9);
+        let end_tangent = curve.end_point() - cp2;
+        assert!((end_tangent.x() + end_tangent.y()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn no_arc_tangent_to_parallel_lines() {
+        assert!(CircularArc::from_tangent_lines(
+            &(Coord2(0.0, 0.0), Coord2(10.0, 0.0)),
+            &(Coord2(0.0, 5.0), Coord2(10.0, 5.0)),
+            2.0
+        )
+        .is_none());
+    }
 }
@@ -0,0 +1,390 @@
+use crate::bezier::path::BezierPathFactory;
+use crate::bezier::{
+    nearest_point_on_curve, BezierCurve, BezierCurveFactory, Coordinate, Coordinate2D, Curve,
+};
+
+/// The largest number of curves that a clothoid will be divided into
+const MAX_CLOTHOID_CURVES: usize = 1024;
+
+/// The largest change in angle covered by a single step when integrating along a clothoid
+const MAX_INTEGRATION_RADIANS: f64 = 0.05;
+
+/// The fractions of the length of each curve where the clothoid is compared against its approximation
+const ERROR_SAMPLES: [f64; 3] = [0.25, 0.5, 0.75];
+
+///
+/// Represents a clothoid (also known as an Euler spiral) in 2 dimensions
+///
+/// A clothoid is a curve where the curvature changes linearly with the distance along the curve. They're used for easing
+/// between straight lines and arcs in road and railway design, as a vehicle following one turns its steering wheel at a
+/// constant rate. Curvatures are positive for curves that turn anticlockwise and negative for curves that turn clockwise,
+/// and the curvature of an arc is 1 divided by its radius, so a clothoid with a start curvature of 0 and an end curvature
+/// of `1.0/radius` joins a straight line to an anticlockwise arc.
+///
+#[derive(Clone, Copy, Debug)]
+pub struct Clothoid<Coord: Coordinate2D + Coordinate> {
+    /// The point where the clothoid starts
+    start_point: Coord,
+
+    /// The direction that the clothoid is travelling in at its start, in radians anticlockwise from the x axis
+    start_radians: f64,
+
+    /// The curvature at the start of the clothoid
+    start_curvature: f64,
+
+    /// The curvature at the end of the clothoid
+    end_curvature: f64,
+
+    /// The length of the clothoid
+    length: f64,
+}
+
+impl<Coord: Coordinate2D + Coordinate> Clothoid<Coord> {
+    ///
+    /// Creates a clothoid that starts at a point travelling in the direction `start_radians`, and whose curvature changes
+    /// from `start_curvature` to `end_curvature` over `length` units
+    ///
+    pub fn new(
+        start_point: Coord,
+        start_radians: f64,
+        start_curvature: f64,
+        end_curvature: f64,
+        length: f64,
+    ) -> Self {
+        Self {
+            start_point,
+            start_radians,
+            start_curvature,
+            end_curvature,
+            length,
+        }
+    }
+
+    ///
+    /// The point where this clothoid starts
+    ///
+    pub fn start_point(&self) -> Coord {
+        self.start_point
+    }
+
+    ///
+    /// The point where this clothoid ends
+    ///
+    pub fn end_point(&self) -> Coord {
+        self.point_at_length(self.length)
+    }
+
+    ///
+    /// The direction that this clothoid is travelling in at its start, in radians
+    ///
+    pub fn start_radians(&self) -> f64 {
+        self.start_radians
+    }
+
+    ///
+    /// The direction that this clothoid is travelling in at its end, in radians
+    ///
+    pub fn end_radians(&self) -> f64 {
+        self.angle_at_length(self.length)
+    }
+
+    ///
+    /// The curvature at the start of this clothoid
+    ///
+    pub fn start_curvature(&self) -> f64 {
+        self.start_curvature
+    }
+
+    ///
+    /// The curvature at the end of this clothoid
+    ///
+    pub fn end_curvature(&self) -> f64 {
+        self.end_curvature
+    }
+
+    ///
+    /// The length of this clothoid
+    ///
+    pub fn length(&self) -> f64 {
+        self.length
+    }
+
+    ///
+    /// The curvature of this clothoid at a particular distance along it
+    ///
+    pub fn curvature_at_length(&self, length: f64) -> f64 {
+        if self.length == 0.0 {
+            self.start_curvature
+        } else {
+            self.start_curvature
+                + (self.end_curvature - self.start_curvature) * length / self.length
+        }
+    }
+
+    ///
+    /// The direction that this clothoid is travelling in at a particular distance along it, in radians
+    ///
+    pub fn angle_at_length(&self, length: f64) -> f64 {
+        // The angle is the integral of the curvature
+        let curvature_change = if self.length == 0.0 {
+            0.0
+        } else {
+            (self.end_curvature - self.start_curvature) / self.length
+        };
+
+        self.start_radians
+            + self.start_curvature * length
+            + curvature_change * length * length * 0.5
+    }
+
+    ///
+    /// The point at a particular distance along this clothoid
+    ///
+    pub fn point_at_length(&self, length: f64) -> Coord {
+        self.point_between(self.start_point, 0.0, length)
+    }
+
+    ///
+    /// Given the point at `from_length`, finds the point at `to_length` by integrating the direction of the curve
+    ///
+    /// Clothoids have no closed form (the integrals are the Fresnel integrals), so this uses Simpson's rule, with enough
+    /// steps that the direction of the curve changes by only a small amount in each one.
+    ///
+    fn point_between(&self, from_point: Coord, from_length: f64, to_length: f64) -> Coord {
+        let distance = to_length - from_length;
+        if distance == 0.0 {
+            return from_point;
+        }
+
+        let max_curvature = self
+            .curvature_at_length(from_length)
+            .abs()
+            .max(self.curvature_at_length(to_length).abs());
+        let num_steps =
+            ((max_curvature * distance.abs() / MAX_INTEGRATION_RADIANS).ceil() as usize).max(4) * 2;
+        let step = distance / (num_steps as f64);
+
+        let (mut x, mut y) = (0.0, 0.0);
+        for step_idx in 0..=num_steps {
+            let weight = if step_idx == 0 || step_idx == num_steps {
+                1.0
+            } else if step_idx % 2 == 1 {
+                4.0
+            } else {
+                2.0
+            };
+
+            let (sin, cos) = self
+                .angle_at_length(from_length + (step_idx as f64) * step)
+                .sin_cos();
+            x += weight * cos;
+            y += weight * sin;
+        }
+
+        let scale = step / 3.0;
+        Coord::from_components(&[from_point.x() + x * scale, from_point.y() + y * scale])
+    }
+
+    ///
+    /// Creates the bezier curve approximating the section of this clothoid between two lengths
+    ///
+    fn curve_for_section<Curve: BezierCurveFactory<Point = Coord>>(
+        &self,
+        start_point: Coord,
+        end_point: Coord,
+        start_length: f64,
+        end_length: f64,
+    ) -> Curve {
+        // Use the handle length of the circular arc that turns by the same angle over the same length
+        let section_length = end_length - start_length;
+        let start_radians = self.angle_at_length(start_length);
+        let end_radians = self.angle_at_length(end_length);
+        let sweep_radians = end_radians - start_radians;
+
+        let handle_length = if sweep_radians.abs() < 1e-9 {
+            section_length / 3.0
+        } else {
+            section_length * (4.0 / 3.0) * (sweep_radians / 4.0).tan() / sweep_radians
+        };
+
+        let (start_sin, start_cos) = start_radians.sin_cos();
+        let (end_sin, end_cos) = end_radians.sin_cos();
+        let cp1 = start_point + Coord::from_components(&[start_cos, start_sin]) * handle_length;
+        let cp2 = end_point - Coord::from_components(&[end_cos, end_sin]) * handle_length;
+
+        Curve::from_points(start_point, (cp1, cp2), end_point)
+    }
+
+    ///
+    /// Converts this clothoid to a set of bezier curves that are within `max_error` of the true curve
+    ///
+    /// The clothoid is divided into sections of equal length, doubling the number of sections until each one is close
+    /// enough to the true curve.
+    ///
+    pub fn to_curves<Curve: BezierCurveFactory<Point = Coord>>(
+        &self,
+        max_error: f64,
+    ) -> Vec<Curve> {
+        if self.length <= 0.0 {
+            return vec![];
+        }
+
+        // Start with sections that turn by at most 90 degrees
+        let total_radians = (self.end_radians() - self.start_radians).abs().max(
+            (self.curvature_at_length(0.0).abs() + self.curvature_at_length(self.length).abs())
+                * 0.5
+                * self.length,
+        );
+        let mut num_sections = ((total_radians / (std::f64::consts::PI / 2.0)).ceil() as usize)
+            .clamp(1, MAX_CLOTHOID_CURVES);
+
+        loop {
+            let section_length = self.length / (num_sections as f64);
+            let mut curves = Vec::with_capacity(num_sections);
+            let mut start_point = self.start_point;
+            let mut max_section_error: f64 = 0.0;
+
+            for section in 0..num_sections {
+                let start_length = (section as f64) * section_length;
+                let end_length = if section + 1 == num_sections {
+                    self.length
+                } else {
+                    start_length + section_length
+                };
+                let end_point = self.point_between(start_point, start_length, end_length);
+                let curve = self.curve_for_section::<Curve>(
+                    start_point,
+                    end_point,
+                    start_length,
+                    end_length,
+                );
+
+                // Measure how far the true curve is from the approximation
+                for fraction in ERROR_SAMPLES {
+                    let sample_length = start_length + (end_length - start_length) * fraction;
+                    let sample = self.point_between(start_point, start_length, sample_length);
+                    let (_, nearest) = nearest_point_on_curve(&curve, &sample);
+
+                    max_section_error = max_section_error.max(nearest.distance_to(&sample));
+                }
+
+                curves.push(curve);
+                start_point = end_point;
+            }
+
+            if max_section_error <= max_error || num_sections >= MAX_CLOTHOID_CURVES {
+                return curves;
+            }
+
+            num_sections = (num_sections * 2).min(MAX_CLOTHOID_CURVES);
+        }
+    }
+
+    ///
+    /// Converts this clothoid to a path that's within `max_error` of the true curve
+    ///
+    pub fn to_path<P: BezierPathFactory<Point = Coord>>(&self, max_error: f64) -> P {
+        let curves = self.to_curves::<Curve<_>>(max_error);
+
+        P::from_points(
+            self.start_point,
+            curves.into_iter().map(|curve| {
+                let (cp1, cp2) = curve.control_points();
+                let end_point = curve.end_point();
+
+                (cp1, cp2, end_point)
+            }),
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::arc::Circle;
+    use crate::Coord2;
+
+    use std::f64;
+
+    #[test]
+    fn straight_clothoid() {
+        let clothoid = Clothoid::new(Coord2(1.0, 2.0), f64::consts::PI / 2.0, 0.0, 0.0, 10.0);
+        let curves = clothoid.to_curves::<Curve<_>>(0.001);
+
+        assert!(curves.len() == 1);
+        assert!(curves[0].start_point().distance_to(&Coord2(1.0, 2.0)) < 1e-9);
+        assert!(curves[0].end_point().distance_to(&Coord2(1.0, 12.0)) < 1e-9);
+    }
+
+    #[test]
+    fn constant_curvature_is_circular() {
+        // Half of a circle of radius 4, starting at the bottom and turning anticlockwise
+        let clothoid = Clothoid::new(Coord2(5.0, 1.0), 0.0, 0.25, 0.25, f64::consts::PI * 4.0);
+
+        assert!(clothoid.end_point().distance_to(&Coord2(5.0, 9.0)) < 1e-6);
+        assert!((clothoid.end_radians() - f64::consts::PI).abs() < 1e-9);
+
+        let circle = Circle::new(Coord2(5.0, 5.0), 4.0);
+        for curve in clothoid.to_curves::<Curve<_>>(0.001) {
+            for t in 0..=10 {
+                let p = curve.point_at_pos((t as f64) / 10.0);
+                assert!((p.distance_to(&circle.center) - circle.radius).abs() < 0.001);
+            }
+        }
+    }
+
+    #[test]
+    fn unit_clothoid_matches_fresnel_integrals() {
+        // Curvature increases from 0 to 1 over a length of 1, so the end point is (integral of cos(s^2/2), integral of sin(s^2/2))
+        let clothoid = Clothoid::new(Coord2(0.0, 0.0), 0.0, 0.0, 1.0, 1.0);
+        let end_point = clothoid.end_point();
+
+        assert!((end_point.x() - 0.975288).abs() < 1e-6);
+        assert!((end_point.y() - 0.163714).abs() < 1e-6);
+        assert!((clothoid.end_radians() - 0.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn approximation_is_within_error() {
+        let clothoid = Clothoid::new(Coord2(0.0, 0.0), 0.3, -0.1, 2.0, 6.0);
+        let curves = clothoid.to_curves::<Curve<_>>(0.001);
+
+        assert!(curves.len() > 1);
+        assert!(curves[0].start_point() == clothoid.start_point());
+        assert!(
+            curves[curves.len() - 1]
+                .end_point()
+                .distance_to(&clothoid.end_point())
+                < 1e-6
+        );
+
+        // Sample the true curve and check that it's close to one of the curves
+        for sample in 0..=100 {
+            let point = clothoid.point_at_length(clothoid.length() * (sample as f64) / 100.0);
+            let distance = curves
+                .iter()
+                .map(|curve| nearest_point_on_curve(curve, &point).1.distance_to(&point))
+                .fold(f64::MAX, f64::min);
+
+            assert!(distance < 0.001);
+        }
+    }
+
+    #[test]
+    fn clothoid_joins_straight_to_arc() {
+        let radius = 10.0;
+        let clothoid = Clothoid::new(Coord2(0.0, 0.0), 0.0, 0.0, 1.0 / radius, 5.0);
+        let curves = clothoid.to_curves::<Curve<_>>(0.0001);
+
+        // The start should be tangent to the x axis and the end should be tangent to the arc
+        let (cp1, _) = curves[0].control_points();
+        assert!(cp1.y().abs() < 1e-9);
+
+        let last_curve = &curves[curves.len() - 1];
+        let (_, cp2) = last_curve.control_points();
+        let end_tangent = (last_curve.end_point() - cp2).to_unit_vector();
+        let (end_sin, end_cos) = clothoid.end_radians().sin_cos();
+        assert!(end_tangent.distance_to(&Coord2(end_cos, end_sin)) < 1e-9);
+        assert!((clothoid.end_radians() - 0.25).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,488 @@
+use crate::bezier::path::BezierPathFactory;
+use crate::bezier::{BezierCurve, BezierCurveFactory, Coordinate, Coordinate2D, Curve};
+
+use std::f64;
+
+/// The largest number of curves that an elliptical arc will be divided into
+const MAX_ARC_CURVES: usize = 1024;
+
+///
+/// Represents an ellipse in 2 dimensions
+///
+#[derive(Clone, Copy, Debug)]
+pub struct Ellipse<Coord: Coordinate2D + Coordinate> {
+    /// The center of this ellipse
+    pub center: Coord,
+
+    /// The radius of this ellipse along its (rotated) x axis
+    pub radius_x: f64,
+
+    /// The radius of this ellipse along its (rotated) y axis
+    pub radius_y: f64,
+
+    /// The anticlockwise rotation of the x axis of this ellipse, in radians
+    pub rotation: f64,
+}
+
+///
+/// Represents an arc of an ellipse in 2 dimensions
+///
+/// Angles are measured around the ellipse before it's rotated, so the point at angle `a` is
+/// `(radius_x * cos(a), radius_y * sin(a))` rotated by the rotation of the ellipse and moved to its center. The arc
+/// moves from the start angle to the end angle, so it travels anticlockwise if the end angle is greater than the start
+/// angle and clockwise otherwise.
+///
+#[derive(Clone, Copy, Debug)]
+pub struct EllipticalArc<Coord: Coordinate2D + Coordinate> {
+    /// The ellipse that this is an arc of
+    ellipse: Ellipse<Coord>,
+
+    /// The start point of this arc, in radians
+    start_radians: f64,
+
+    /// The end point of this arc, in radians
+    end_radians: f64,
+}
+
+impl<Coord: Coordinate2D + Coordinate> Ellipse<Coord> {
+    ///
+    /// Creates a new ellipse with a center, its two radii and the rotation of its x axis in radians
+    ///
+    pub fn new(center: Coord, radius_x: f64, radius_y: f64, rotation: f64) -> Self {
+        Self {
+            center,
+            radius_x,
+            radius_y,
+            rotation,
+        }
+    }
+
+    ///
+    /// Returns the point at a particular angle around this ellipse
+    ///
+    pub fn point_at_angle(&self, radians: f64) -> Coord {
+        let (sin_rotation, cos_rotation) = self.rotation.sin_cos();
+        let (x, y) = (self.radius_x * radians.cos(), self.radius_y * radians.sin());
+
+        Coord::from_components(&[
+            self.center.x() + x * cos_rotation - y * sin_rotation,
+            self.center.y() + x * sin_rotation + y * cos_rotation,
+        ])
+    }
+
+    ///
+    /// Returns the direction the ellipse is travelling in at a particular angle (the derivative of `point_at_angle()`)
+    ///
+    fn tangent_at_angle(&self, radians: f64) -> Coord {
+        let (sin_rotation, cos_rotation) = self.rotation.sin_cos();
+        let (x, y) = (
+            -self.radius_x * radians.sin(),
+            self.radius_y * radians.cos(),
+        );
+
+        Coord::from_components(&[
+            x * cos_rotation - y * sin_rotation,
+            x * sin_rotation + y * cos_rotation,
+        ])
+    }
+
+    ///
+    /// Returns an object representing an arc from this ellipse
+    ///
+    pub fn arc(&self, start_radians: f64, end_radians: f64) -> EllipticalArc<Coord> {
+        EllipticalArc {
+            ellipse: *self,
+            start_radians,
+            end_radians,
+        }
+    }
+
+    ///
+    /// Returns a set of bezier curves that are within `max_error` of this ellipse
+    ///
+    /// The ellipse is divided into at least four curves, starting and finishing at the point at angle 0.
+    ///
+    pub fn to_curves<Curve: BezierCurveFactory<Point = Coord>>(
+        &self,
+        max_error: f64,
+    ) -> Vec<Curve> {
+        self.arc(0.0, f64::consts::PI * 2.0).to_curves(max_error)
+    }
+
+    ///
+    /// Returns a closed path that's within `max_error` of this ellipse
+    ///
+    pub fn to_path<P: BezierPathFactory<Point = Coord>>(&self, max_error: f64) -> P {
+        path_for_curves(self.to_curves::<Curve<_>>(max_error))
+    }
+}
+
+impl<Coord: Coordinate2D + Coordinate> EllipticalArc<Coord> {
+    ///
+    /// Creates an arc using the endpoint parameterisation used by the SVG `A` path command
+    ///
+    /// The arc goes from `start` to `end` around an ellipse with radii of `radius_x` and `radius_y`, whose x axis is rotated
+    /// by `x_axis_rotation` radians (note that SVG specifies the rotation in degrees: use `to_radians()` to convert it).
+    /// There are usually four arcs that fit these constraints: `large_arc` picks the one that sweeps more than 180 degrees
+    /// and `sweep` picks the one that travels in the direction of increasing angles.
+    ///
+    /// As in SVG, the radii are made positive and are scaled up if they're too small for the ellipse to reach from the start
+    /// to the end point. Returns `None` if the start and end points are the same (where SVG omits the arc) or if either
+    /// radius is zero (where SVG draws a straight line instead).
+    ///
+    pub fn from_svg_endpoints(
+        start: Coord,
+        end: Coord,
+        radius_x: f64,
+        radius_y: f64,
+        x_axis_rotation: f64,
+        large_arc: bool,
+        sweep: bool,
+    ) -> Option<Self> {
+        // Algorithm described in the 'Implementation notes' appendix of the SVG specification
+        if start == end {
+            return None;
+        }
+
+        let (mut radius_x, mut radius_y) = (radius_x.abs(), radius_y.abs());
+        if radius_x == 0.0 || radius_y == 0.0 {
+            return None;
+        }
+
+        // Move the start point so the midpoint of the arc is at the origin and the axes of the ellipse are aligned
+        let (sin_rotation, cos_rotation) = x_axis_rotation.sin_cos();
+        let (half_dx, half_dy) = ((start.x() - end.x()) / 2.0, (start.y() - end.y()) / 2.0);
+        let (x1, y1) = (
+            cos_rotation * half_dx + sin_rotation * half_dy,
+            -sin_rotation * half_dx + cos_rotation * half_dy,
+        );
+
+        // Scale the radii up if the ellipse can't reach between the points
+        let lambda = (x1 * x1) / (radius_x * radius_x) + (y1 * y1) / (radius_y * radius_y);
+        if lambda > 1.0 {
+            radius_x *= lambda.sqrt();
+            radius_y *= lambda.sqrt();
+        }
+
+        // Find the center
+        let (rx_sq, ry_sq) = (radius_x * radius_x, radius_y * radius_y);
+        let numerator = rx_sq * ry_sq - rx_sq * y1 * y1 - ry_sq * x1 * x1;
+        let denominator = rx_sq * y1 * y1 + ry_sq * x1 * x1;
+        let sign = if large_arc != sweep { 1.0 } else { -1.0 };
+        let coefficient = sign * (numerator / denominator).max(0.0).sqrt();
+
+        let (cx1, cy1) = (
+            coefficient * radius_x * y1 / radius_y,
+            -coefficient * radius_y * x1 / radius_x,
+        );
+        let center = Coord::from_components(&[
+            cos_rotation * cx1 - sin_rotation * cy1 + (start.x() + end.x()) / 2.0,
+            sin_rotation * cx1 + cos_rotation * cy1 + (start.y() + end.y()) / 2.0,
+        ]);
+
+        // Find the angles around the ellipse
+        let start_radians = ((y1 - cy1) / radius_y).atan2((x1 - cx1) / radius_x);
+        let end_radians = ((-y1 - cy1) / radius_y).atan2((-x1 - cx1) / radius_x);
+        let mut sweep_radians = end_radians - start_radians;
+
+        if sweep && sweep_radians < 0.0 {
+            sweep_radians += 2.0 * f64::consts::PI;
+        } else if !sweep && sweep_radians > 0.0 {
+            sweep_radians -= 2.0 * f64::consts::PI;
+        }
+
+        Some(EllipticalArc {
+            ellipse: Ellipse::new(center, radius_x, radius_y, x_axis_rotation),
+            start_radians,
+            end_radians: start_radians + sweep_radians,
+        })
+    }
+
+    ///
+    /// The ellipse that this is an arc of
+    ///
+    pub fn ellipse(&self) -> &Ellipse<Coord> {
+        &self.ellipse
+    }
+
+    ///
+    /// The angle that this arc starts at, in radians
+    ///
+    pub fn start_radians(&self) -> f64 {
+        self.start_radians
+    }
+
+    ///
+    /// The angle that this arc ends at, in radians
+    ///
+    pub fn end_radians(&self) -> f64 {
+        self.end_radians
+    }
+
+    ///
+    /// The point where this arc starts
+    ///
+    pub fn start_point(&self) -> Coord {
+        self.ellipse.point_at_angle(self.start_radians)
+    }
+
+    ///
+    /// The point where this arc ends
+    ///
+    pub fn end_point(&self) -> Coord {
+        self.ellipse.point_at_angle(self.end_radians)
+    }
+
+    ///
+    /// Creates the bezier curve approximating a section of this arc
+    ///
+    fn curve_for_section<Curve: BezierCurveFactory<Point = Coord>>(
+        &self,
+        start_radians: f64,
+        sweep_radians: f64,
+    ) -> Curve {
+        let end_radians = start_radians + sweep_radians;
+        let handle_length = (4.0 / 3.0) * (sweep_radians / 4.0).tan();
+
+        let start = self.ellipse.point_at_angle(start_radians);
+        let end = self.ellipse.point_at_angle(end_radians);
+        let cp1 = start + self.ellipse.tangent_at_angle(start_radians) * handle_length;
+        let cp2 = end - self.ellipse.tangent_at_angle(end_radians) * handle_length;
+
+        Curve::from_points(start, (cp1, cp2), end)
+    }
+
+    ///
+    /// Converts this arc to a set of bezier curves that are within `max_error` of the true arc
+    ///
+    /// The arc is divided into sections of at most 90 degrees, and further subdivided until the approximation is accurate
+    /// enough.
+    ///
+    pub fn to_curves<Curve: BezierCurveFactory<Point = Coord>>(
+        &self,
+        max_error: f64,
+    ) -> Vec<Curve> {
+        let sweep_radians = self.end_radians - self.start_radians;
+        if sweep_radians == 0.0 {
+            return vec![];
+        }
+
+        // The error of a circular arc approximated by a bezier curve: the ellipse is a scaled circle so the error is at
+        // most this value for the larger of its radii
+        let max_radius = self.ellipse.radius_x.abs().max(self.ellipse.radius_y.abs());
+        let error_for_sections = |num_sections: usize| {
+            let quarter_angle = (sweep_radians / (num_sections as f64) / 4.0).abs();
+            max_radius * (4.0 / 27.0) * quarter_angle.sin().powi(6) / quarter_angle.cos().powi(2)
+        };
+
+        let mut num_sections = (sweep_radians.abs() / (f64::consts::PI / 2.0)).ceil() as usize;
+        num_sections = num_sections.max(1);
+        while num_sections < MAX_ARC_CURVES && error_for_sections(num_sections) > max_error {
+            num_sections += 1;
+        }
+
+        let section_radians = sweep_radians / (num_sections as f64);
+
+        (0..num_sections)
+            .map(|section| {
+                let start_radians = self.start_radians + (section as f64) * section_radians;
+                self.curve_for_section(start_radians, section_radians)
+            })
+            .collect()
+    }
+
+    ///
+    /// Converts this arc to a path that's within `max_error` of the true arc
+    ///
+    pub fn to_path<P: BezierPathFactory<Point = Coord>>(&self, max_error: f64) -> P {
+        let curves = self.to_curves::<Curve<_>>(max_error);
+
+        if curves.is_empty() {
+            P::from_points(self.start_point(), vec![])
+        } else {
+            path_for_curves(curves)
+        }
+    }
+}
+
+///
+/// Creates a path from a non-empty list of curves
+///
+fn path_for_curves<Coord: Coordinate, P: BezierPathFactory<Point = Coord>>(
+    curves: Vec<Curve<Coord>>,
+) -> P {
+    P::from_points(
+        curves[0].start_point(),
+        curves.into_iter().map(|curve| {
+            let (cp1, cp2) = curve.control_points();
+            let end_point = curve.end_point();
+
+            (cp1, cp2, end_point)
+        }),
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        bezier::path::{path_to_curves, BezierPath, SimpleBezierPath},
+        Coord2,
+    };
+
+    ///
+    /// Checks that every point along a set of curves is on an ellipse
+    ///
+    fn assert_on_ellipse(curves: &[Curve<Coord2>], ellipse: &Ellipse<Coord2>, max_error: f64) {
+        let (sin_rotation, cos_rotation) = (-ellipse.rotation).sin_cos();
+
+        for curve in curves.iter() {
+            for t in 0..=20 {
+                let p = curve.point_at_pos((t as f64) / 20.0) - ellipse.center;
+                let (x, y) = (
+                    p.x() * cos_rotation - p.y() * sin_rotation,
+                    p.x() * sin_rotation + p.y() * cos_rotation,
+                );
+
+                // Find the nearest point on the ellipse by scaling the angle of the point
+                let angle = (y / ellipse.radius_y).atan2(x / ellipse.radius_x);
+                let nearest = ellipse.point_at_angle(angle);
+
+                assert!((p + ellipse.center).distance_to(&nearest) < max_error);
+            }
+        }
+    }
+
+    #[test]
+    fn ellipse_is_roughly_elliptical() {
+        let ellipse = Ellipse::new(Coord2(10.0, 5.0), 8.0, 3.0, 0.5);
+        let curves = ellipse.to_curves::<Curve<_>>(0.01);
+
+        assert!(curves.len() == 4);
+        assert_on_ellipse(&curves, &ellipse, 0.01);
+    }
+
+    #[test]
+    fn ellipse_is_within_tolerance() {
+        let ellipse = Ellipse::new(Coord2(10.0, 5.0), 80.0, 30.0, 0.5);
+        let rough = ellipse.to_curves::<Curve<_>>(1.0);
+        let accurate = ellipse.to_curves::<Curve<_>>(0.0001);
+
+        assert!(rough.len() == 4);
+        assert!(accurate.len() > 4);
+        assert_on_ellipse(&rough, &ellipse, 1.0);
+        assert_on_ellipse(&accurate, &ellipse, 0.0001);
+    }
+
+    #[test]
+    fn ellipse_path_is_closed() {
+        let ellipse = Ellipse::new(Coord2(10.0, 5.0), 8.0, 3.0, 0.5);
+        let path = ellipse.to_path::<SimpleBezierPath>(0.01);
+        let (_, _, last_point) = path.points().last().unwrap();
+
+        assert!(path.start_point().distance_to(&last_point) < 1e-9);
+        assert!(path.start_point().distance_to(&ellipse.point_at_angle(0.0)) < 1e-9);
+    }
+
+    #[test]
+    fn arc_is_within_tolerance() {
+        let ellipse = Ellipse::new(Coord2(0.0, 0.0), 100.0, 50.0, 0.0);
+        let arc = ellipse.arc(0.0, f64::consts::PI * 1.5);
+
+        let rough = arc.to_curves::<Curve<_>>(1.0);
+        let accurate = arc.to_curves::<Curve<_>>(0.001);
+
+        assert!(rough.len() >= 3);
+        assert!(accurate.len() > rough.len());
+        assert_on_ellipse(&rough, &ellipse, 1.0);
+        assert_on_ellipse(&accurate, &ellipse, 0.001);
+
+        assert!(accurate[0].start_point().distance_to(&Coord2(100.0, 0.0)) < 1e-9);
+        assert!(
+            accurate
+                .last()
+                .unwrap()
+                .end_point()
+                .distance_to(&Coord2(0.0, -50.0))
+                < 1e-9
+        );
+    }
+
+    #[test]
+    fn clockwise_arc() {
+        let ellipse = Ellipse::new(Coord2(0.0, 0.0), 10.0, 10.0, 0.0);
+        let arc = ellipse.arc(0.0, -f64::consts::PI / 2.0);
+        let curves = arc.to_curves::<Curve<_>>(0.01);
+
+        assert!(curves[0].start_point().distance_to(&Coord2(10.0, 0.0)) < 1e-9);
+        assert!(
+            curves
+                .last()
+                .unwrap()
+                .end_point()
+                .distance_to(&Coord2(0.0, -10.0))
+                < 1e-9
+        );
+
+        let midpoint = path_to_curves::<_, Curve<_>>(&arc.to_path::<SimpleBezierPath>(0.01))
+            .next()
+            .unwrap()
+            .point_at_pos(0.5);
+        assert!(midpoint.y() < 0.0);
+    }
+
+    #[test]
+    fn svg_arc_meets_endpoints() {
+        let start = Coord2(10.0, 20.0);
+        let end = Coord2(40.0, 35.0);
+
+        for &(large_arc, sweep) in
+            [(false, false), (false, true), (true, false), (true, true)].iter()
+        {
+            let arc =
+                EllipticalArc::from_svg_endpoints(start, end, 25.0, 15.0, 0.3, large_arc, sweep)
+                    .unwrap();
+            let sweep_radians = arc.end_radians() - arc.start_radians();
+
+            assert!(arc.start_point().distance_to(&start) < 1e-6);
+            assert!(arc.end_point().distance_to(&end) < 1e-6);
+            assert!((sweep_radians.abs() > f64::consts::PI) == large_arc);
+            assert!((sweep_radians > 0.0) == sweep);
+
+            let curves = arc.to_curves::<Curve<_>>(0.01);
+            assert_on_ellipse(&curves, arc.ellipse(), 0.01);
+        }
+    }
+
+    #[test]
+    fn svg_arc_scales_up_small_radii() {
+        let start = Coord2(0.0, 0.0);
+        let end = Coord2(100.0, 0.0);
+        let arc =
+            EllipticalArc::from_svg_endpoints(start, end, 10.0, 10.0, 0.0, false, true).unwrap();
+
+        // The radius is scaled so the arc is a semicircle between the two points
+        assert!((arc.ellipse().radius_x - 50.0).abs() < 1e-6);
+        assert!(arc.ellipse().center.distance_to(&Coord2(50.0, 0.0)) < 1e-6);
+        assert!(((arc.end_radians() - arc.start_radians()).abs() - f64::consts::PI).abs() < 1e-6);
+    }
+
+    #[test]
+    fn svg_arc_degenerate_cases() {
+        let start = Coord2(0.0, 0.0);
+
+        assert!(
+            EllipticalArc::from_svg_endpoints(start, start, 10.0, 10.0, 0.0, false, true).is_none()
+        );
+        assert!(EllipticalArc::from_svg_endpoints(
+            start,
+            Coord2(10.0, 0.0),
+            0.0,
+            10.0,
+            0.0,
+            false,
+            true
+        )
+        .is_none());
+    }
+}
@@ -0,0 +1,512 @@
+use super::ellipse::Ellipse;
+use crate::bezier::path::BezierPath;
+use crate::bezier::unit_tangent_at_pos;
+use crate::bezier::{
+    features_for_curve, BezierCurve, BezierCurveFactory, Coordinate, Coordinate2D, Curve,
+    CurveFeatures,
+};
+use crate::line::line_to_bezier;
+
+use std::f64;
+
+/// The number of times a curve can be subdivided while fitting arcs to it
+const MAX_FIT_DEPTH: usize = 12;
+
+/// The number of points on a curve that are compared against the arcs fitted to it
+const FIT_SAMPLES: usize = 16;
+
+///
+/// A straight line or a circular arc produced by fitting arcs to a curve
+///
+/// Arcs are described with their center and radius and the angles of their start and end points (measured anticlockwise
+/// from the positive x axis). Arcs travel anticlockwise when the end angle is greater than the start angle and clockwise
+/// otherwise.
+///
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum ArcSegment<Point> {
+    /// A straight line between two points
+    Line(Point, Point),
+
+    /// A circular arc
+    Arc {
+        center: Point,
+        radius: f64,
+        start_radians: f64,
+        end_radians: f64,
+    },
+}
+
+impl<Point: Coordinate + Coordinate2D> ArcSegment<Point> {
+    ///
+    /// Creates the arc that starts at a point travelling in a particular direction and then ends at another point
+    ///
+    /// This is a line if the end point is directly ahead of the start point
+    ///
+    fn from_tangent(start: Point, tangent: Point, end: Point) -> Self {
+        let normal = Point::from_components(&[-tangent.y(), tangent.x()]);
+        let chord = end - start;
+        let offset = normal.dot(&chord);
+
+        if offset.abs() <= chord.magnitude() * 1e-9 {
+            return ArcSegment::Line(start, end);
+        }
+
+        // The center is along the normal, at the same distance from both points
+        let signed_radius = chord.dot(&chord) / (2.0 * offset);
+        let center = start + normal * signed_radius;
+        let start_radians = (start.y() - center.y()).atan2(start.x() - center.x());
+        let end_radians = (end.y() - center.y()).atan2(end.x() - center.x());
+
+        // A positive radius puts the center to the left of the tangent, so the arc travels anticlockwise
+        let sweep_radians = if signed_radius > 0.0 {
+            (end_radians - start_radians).rem_euclid(2.0 * f64::consts::PI)
+        } else {
+            -(start_radians - end_radians).rem_euclid(2.0 * f64::consts::PI)
+        };
+
+        ArcSegment::Arc {
+            center,
+            radius: signed_radius.abs(),
+            start_radians,
+            end_radians: start_radians + sweep_radians,
+        }
+    }
+
+    ///
+    /// The point where this segment starts
+    ///
+    pub fn start_point(&self) -> Point {
+        match self {
+            ArcSegment::Line(start, _) => *start,
+            ArcSegment::Arc {
+                center,
+                radius,
+                start_radians,
+                ..
+            } => point_on_circle(center, *radius, *start_radians),
+        }
+    }
+
+    ///
+    /// The point where this segment ends
+    ///
+    pub fn end_point(&self) -> Point {
+        match self {
+            ArcSegment::Line(_, end) => *end,
+            ArcSegment::Arc {
+                center,
+                radius,
+                end_radians,
+                ..
+            } => point_on_circle(center, *radius, *end_radians),
+        }
+    }
+
+    ///
+    /// The point halfway along this segment
+    ///
+    fn mid_point(&self) -> Point {
+        match self {
+            ArcSegment::Line(start, end) => (*start + *end) * 0.5,
+            ArcSegment::Arc {
+                center,
+                radius,
+                start_radians,
+                end_radians,
+            } => point_on_circle(center, *radius, (start_radians + end_radians) * 0.5),
+        }
+    }
+
+    ///
+    /// Returns the distance from a point to the closest point on this segment
+    ///
+    pub fn distance_to(&self, point: &Point) -> f64 {
+        match self {
+            ArcSegment::Line(start, end) => {
+                let line = *end - *start;
+                let length_sq = line.dot(&line);
+                let t = if length_sq > 0.0 {
+                    ((*point - *start).dot(&line) / length_sq).clamp(0.0, 1.0)
+                } else {
+                    0.0
+                };
+
+                point.distance_to(&(*start + line * t))
+            }
+
+            ArcSegment::Arc {
+                center,
+                radius,
+                start_radians,
+                end_radians,
+            } => {
+                let sweep_radians = end_radians - start_radians;
+                let angle = (point.y() - center.y()).atan2(point.x() - center.x());
+                let along_arc = if sweep_radians >= 0.0 {
+                    (angle - start_radians).rem_euclid(2.0 * f64::consts::PI) <= sweep_radians
+                } else {
+                    (start_radians - angle).rem_euclid(2.0 * f64::consts::PI) <= -sweep_radians
+                };
+
+                if along_arc {
+                    (point.distance_to(center) - radius).abs()
+                } else {
+                    point
+                        .distance_to(&self.start_point())
+                        .min(point.distance_to(&self.end_point()))
+                }
+            }
+        }
+    }
+
+    ///
+    /// Converts this segment to bezier curves that are within `max_error` of it
+    ///
+    pub fn to_curves<Curve: BezierCurveFactory<Point = Point>>(
+        &self,
+        max_error: f64,
+    ) -> Vec<Curve> {
+        match self {
+            ArcSegment::Line(start, end) => vec![line_to_bezier(&(*start, *end))],
+            ArcSegment::Arc {
+                center,
+                radius,
+                start_radians,
+                end_radians,
+            } => Ellipse::new(*center, *radius, *radius, 0.0)
+                .arc(*start_radians, *end_radians)
+                .to_curves(max_error),
+        }
+    }
+}
+
+///
+/// Returns the point at an angle around a circle
+///
+#[inline]
+fn point_on_circle<Point: Coordinate + Coordinate2D>(
+    center: &Point,
+    radius: f64,
+    radians: f64,
+) -> Point {
+    let (sin, cos) = radians.sin_cos();
+
+    Point::from_components(&[center.x() + radius * cos, center.y() + radius * sin])
+}
+
+///
+/// Finds the biarc (a pair of arcs that join smoothly) that matches the end points and the tangents of a curve
+///
+fn biarc_for_curve<Point: Coordinate + Coordinate2D>(
+    curve: &Curve<Point>,
+) -> Option<(ArcSegment<Point>, ArcSegment<Point>)> {
+    let start = curve.start_point();
+    let end = curve.end_point();
+    let start_tangent = unit_tangent_at_pos(curve, 0.0)?;
+    let end_tangent = unit_tangent_at_pos(curve, 1.0)?;
+
+    // Use the same distance along both tangents to create the join point (the 'equal tangent length' biarc)
+    let chord = end - start;
+    let chord_dot_tangents = chord.dot(&(start_tangent + end_tangent));
+    let one_minus_cos = 1.0 - start_tangent.dot(&end_tangent);
+
+    let distance = if one_minus_cos.abs() < 1e-12 {
+        chord.dot(&chord) / (2.0 * chord_dot_tangents)
+    } else {
+        let discriminant =
+            chord_dot_tangents * chord_dot_tangents + 2.0 * one_minus_cos * chord.dot(&chord);
+        (-chord_dot_tangents + discriminant.sqrt()) / (2.0 * one_minus_cos)
+    };
+
+    if !distance.is_finite() || distance <= 0.0 {
+        return None;
+    }
+
+    let join = ((start + start_tangent * distance) + (end - end_tangent * distance)) * 0.5;
+    let first = ArcSegment::from_tangent(start, start_tangent, join);
+
+    // The second arc is found by following it backwards from the end point
+    let second = match ArcSegment::from_tangent(end, end_tangent * -1.0, join) {
+        ArcSegment::Line(a, b) => ArcSegment::Line(b, a),
+        ArcSegment::Arc {
+            center,
+            radius,
+            start_radians,
+            end_radians,
+        } => ArcSegment::Arc {
+            center,
+            radius,
+            start_radians: end_radians,
+            end_radians: start_radians,
+        },
+    };
+
+    Some((first, second))
+}
+
+///
+/// Returns how far a set of segments are from a curve
+///
+fn fit_error<Point: Coordinate + Coordinate2D>(
+    curve: &Curve<Point>,
+    segments: &[ArcSegment<Point>],
+) -> f64 {
+    let samples = (0..=FIT_SAMPLES)
+        .map(|sample| curve.point_at_pos((sample as f64) / (FIT_SAMPLES as f64)))
+        .collect::<Vec<_>>();
+
+    // Distance from the curve to the segments
+    let curve_error = samples
+        .iter()
+        .map(|point| {
+            segments
+                .iter()
+                .map(|segment| segment.distance_to(point))
+                .fold(f64::MAX, f64::min)
+        })
+        .fold(0.0, f64::max);
+
+    // Distance from the middle of each segment to the curve (so segments that bulge away from the curve are detected)
+    let sample_spacing = samples[0].distance_to(&samples[1]);
+    let segment_error = segments
+        .iter()
+        .map(|segment| {
+            let mid_point = segment.mid_point();
+            samples
+                .iter()
+                .map(|point| point.distance_to(&mid_point))
+                .fold(f64::MAX, f64::min)
+        })
+        .fold(0.0, f64::max);
+
+    curve_error.max(segment_error - sample_spacing)
+}
+
+///
+/// Fits arcs to a curve, subdividing it until the arcs are close enough
+///
+fn fit_section<Point: Coordinate + Coordinate2D>(
+    curve: &Curve<Point>,
+    max_error: f64,
+    depth: usize,
+    output: &mut Vec<ArcSegment<Point>>,
+) {
+    let start = curve.start_point();
+    let end = curve.end_point();
+    let (cp1, cp2) = curve.control_points();
+
+    if start.is_near_to(&end, max_error)
+        && cp1.is_near_to(&start, max_error)
+        && cp2.is_near_to(&end, max_error)
+    {
+        // Curve is too short to be worth representing with an arc
+        if start != end {
+            output.push(ArcSegment::Line(start, end));
+        }
+        return;
+    }
+
+    // A single line will do if the control points are close to the line between the end points
+    let line = ArcSegment::Line(start, end);
+    if line.distance_to(&cp1) <= max_error && line.distance_to(&cp2) <= max_error {
+        output.push(line);
+        return;
+    }
+
+    if let Some((first, second)) = biarc_for_curve(curve) {
+        if depth >= MAX_FIT_DEPTH || fit_error(curve, &[first, second]) <= max_error {
+            output.push(first);
+            output.push(second);
+            return;
+        }
+    } else if depth >= MAX_FIT_DEPTH {
+        output.push(line);
+        return;
+    }
+
+    // Subdivide the curve and try again
+    let (before, after) = curve.subdivide::<Curve<_>>(0.5);
+    fit_section(&before, max_error, depth + 1, output);
+    fit_section(&after, max_error, depth + 1, output);
+}
+
+///
+/// Approximates a curve with a series of circular arcs and straight lines
+///
+/// The curve is first divided at its inflection points, then each section is approximated by a biarc (two arcs that
+/// meet smoothly), subdividing until every biarc is within `max_error` of the curve. The arcs meet the ends of the curve
+/// and its tangents at each end, so the result is smooth wherever the original curve is. This is useful for machines such
+/// as CNC routers and laser cutters that can only follow arcs and lines.
+///
+pub fn fit_arcs<C>(curve: &C, max_error: f64) -> Vec<ArcSegment<C::Point>>
+where
+    C: BezierCurve,
+    C::Point: Coordinate2D,
+{
+    let curve = Curve::from_curve(curve);
+
+    // Arcs can't change direction, so split the curve wherever it does
+    let mut split_points = match features_for_curve(&curve, 0.01) {
+        CurveFeatures::SingleInflectionPoint(t) => vec![t],
+        CurveFeatures::DoubleInflectionPoint(t1, t2) => vec![t1, t2],
+        CurveFeatures::Loop(t1, t2) => vec![t1, (t1 + t2) * 0.5, t2],
+        _ => vec![],
+    };
+    split_points.retain(|t| *t > 0.0 && *t < 1.0);
+    split_points.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+
+    let mut result = vec![];
+    let mut last_t = 0.0;
+
+    for t in split_points.into_iter().chain(std::iter::once(1.0)) {
+        if t > last_t {
+            let section = Curve::from_curve(&curve.section(last_t, t));
+            fit_section(&section, max_error, 0, &mut result);
+        }
+        last_t = t;
+    }
+
+    result
+}
+
+///
+/// Approximates a path with a series of circular arcs and straight lines
+///
+/// See `fit_arcs()` for the details of how each section is approximated.
+///
+pub fn fit_path_arcs<P>(path: &P, max_error: f64) -> Vec<ArcSegment<P::Point>>
+where
+    P: BezierPath,
+    P::Point: Coordinate2D,
+{
+    let mut last_point = path.start_point();
+    let mut result = vec![];
+
+    for (cp1, cp2, end_point) in path.points() {
+        let curve = Curve::from_points(last_point, (cp1, cp2), end_point);
+        result.extend(fit_arcs(&curve, max_error));
+
+        last_point = end_point;
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::arc::Circle;
+    use crate::bezier::path::SimpleBezierPath;
+    use crate::Coord2;
+
+    ///
+    /// Checks that arcs are continuous and close to a curve
+    ///
+    fn assert_fits(curve: &Curve<Coord2>, arcs: &[ArcSegment<Coord2>], max_error: f64) {
+        assert!(arcs[0].start_point().distance_to(&curve.start_point()) < 1e-6);
+        assert!(
+            arcs.last()
+                .unwrap()
+                .end_point()
+                .distance_to(&curve.end_point())
+                < 1e-6
+        );
+
+        for (before, after) in arcs.iter().zip(arcs.iter().skip(1)) {
+            assert!(before.end_point().distance_to(&after.start_point()) < 1e-6);
+        }
+
+        for t in 0..=200 {
+            let point = curve.point_at_pos((t as f64) / 200.0);
+            let distance = arcs
+                .iter()
+                .map(|arc| arc.distance_to(&point))
+                .fold(f64::MAX, f64::min);
+
+            assert!(distance <= max_error * 1.1);
+        }
+    }
+
+    #[test]
+    fn straight_line_is_a_line() {
+        let curve = line_to_bezier::<_, Curve<_>>(&(Coord2(0.0, 0.0), Coord2(100.0, 50.0)));
+        let arcs = fit_arcs(&curve, 0.1);
+
+        assert!(arcs == vec![ArcSegment::Line(Coord2(0.0, 0.0), Coord2(100.0, 50.0))]);
+    }
+
+    #[test]
+    fn circle_fits_with_few_arcs() {
+        let circle = Circle::new(Coord2(50.0, 50.0), 30.0);
+        let path = circle.to_path::<SimpleBezierPath>();
+        let arcs = fit_path_arcs(&path, 0.1);
+
+        // Each quarter of the circle is already very close to an arc
+        assert!(arcs.len() == 8);
+
+        for arc in arcs.iter() {
+            match arc {
+                ArcSegment::Arc { center, radius, .. } => {
+                    assert!(center.distance_to(&Coord2(50.0, 50.0)) < 0.1);
+                    assert!((radius - 30.0).abs() < 0.1);
+                }
+                ArcSegment::Line(..) => panic!("unexpected line"),
+            }
+        }
+    }
+
+    #[test]
+    fn arcs_are_within_tolerance() {
+        let curve = Curve::from_points(
+            Coord2(10.0, 10.0),
+            (Coord2(200.0, 20.0), Coord2(-50.0, 150.0)),
+            Coord2(120.0, 180.0),
+        );
+
+        let rough = fit_arcs(&curve, 1.0);
+        let accurate = fit_arcs(&curve, 0.01);
+
+        assert!(accurate.len() > rough.len());
+        assert_fits(&curve, &rough, 1.0);
+        assert_fits(&curve, &accurate, 0.01);
+    }
+
+    #[test]
+    fn curve_with_inflection_point() {
+        let curve = Curve::from_points(
+            Coord2(0.0, 0.0),
+            (Coord2(50.0, 100.0), Coord2(50.0, -100.0)),
+            Coord2(100.0, 0.0),
+        );
+        let arcs = fit_arcs(&curve, 0.05);
+
+        assert_fits(&curve, &arcs, 0.05);
+    }
+
+    #[test]
+    fn arc_segment_to_curves() {
+        let curve = Curve::from_points(
+            Coord2(10.0, 10.0),
+            (Coord2(200.0, 20.0), Coord2(-50.0, 150.0)),
+            Coord2(120.0, 180.0),
+        );
+
+        for arc in fit_arcs(&curve, 0.1) {
+            let curves = arc.to_curves::<Curve<_>>(0.01);
+
+            assert!(curves[0].start_point().distance_to(&arc.start_point()) < 1e-6);
+            assert!(
+                curves
+                    .last()
+                    .unwrap()
+                    .end_point()
+                    .distance_to(&arc.end_point())
+                    < 1e-6
+            );
+
+            for curve in curves.iter() {
+                assert!(arc.distance_to(&curve.point_at_pos(0.5)) < 0.01);
+            }
+        }
+    }
+}
@@ -1,12 +1,27 @@
 //!
 //! # Describing circular arcs
 //!
-//! The `arc` module provides routines for describing circular arcs and converting them to bezier
-//! curves.
+//! The `arc` module provides routines for describing circular and elliptical arcs and converting
+//! them to bezier curves. `EllipticalArc::from_svg_endpoints()` supports the parameters used by
+//! the SVG `A` path command, and `CircularArc` supports the usual CAD constructions: an arc
+//! through three points (`from_three_points()`) and an arc of a given radius that rounds off the
+//! corner between two lines (`from_tangent_lines()`).
+//!
+//! `Clothoid` describes an Euler spiral, whose curvature changes linearly along its length: these
+//! are used to make smooth transitions between straight lines and arcs.
+//!
+//! `fit_arcs()` goes the other way, approximating bezier curves with arcs and straight lines, which
+//! is useful for output devices such as CNC machines that can only follow arcs and lines.
 //!
 
 mod circle;
+mod clothoid;
+mod ellipse;
+mod fit;
 
 pub use self::circle::*;
+pub use self::clothoid::*;
+pub use self::ellipse::*;
+pub use self::fit::*;
 
 // TODO: represent arcs in more than 2 dimensions
@@ -23,6 +23,55 @@ pub fn bezier_coefficients<Point: Coordinate>(
     )
 }
 
+///
+/// Computes the polynomial coefficients (A, B, C, D) for each component of a bezier curve, such that the point at `t` is
+/// `A*t^3 + B*t^2 + C*t + D`
+///
+pub fn bezier_to_polynomial<Point: Coordinate>(
+    w1: Point,
+    w2: Point,
+    w3: Point,
+    w4: Point,
+) -> (Point, Point, Point, Point) {
+    (
+        w4 - w3 * 3.0 + w2 * 3.0 - w1,
+        w3 * 3.0 - w2 * 6.0 + w1 * 3.0,
+        (w2 - w1) * 3.0,
+        w1,
+    )
+}
+
+///
+/// Computes the points of the bezier curve described by the polynomial `A*t^3 + B*t^2 + C*t + D` for `t` between 0 and 1
+///
+/// This is the reverse of `bezier_to_polynomial()`.
+///
+pub fn polynomial_to_bezier<Point: Coordinate>(
+    a: Point,
+    b: Point,
+    c: Point,
+    d: Point,
+) -> (Point, Point, Point, Point) {
+    let w2 = d + c * (1.0 / 3.0);
+    let w3 = w2 * 2.0 + b * (1.0 / 3.0) - d;
+    let w4 = a + b + c + d;
+
+    (d, w2, w3, w4)
+}
+
+///
+/// Computes the points of the bezier curve for a cubic Hermite spline segment, which runs from `p0` to `p1` with the
+/// tangents (derivatives) `m0` and `m1` at either end
+///
+pub fn hermite_to_bezier<Point: Coordinate>(
+    p0: Point,
+    m0: Point,
+    p1: Point,
+    m1: Point,
+) -> (Point, Point, Point, Point) {
+    (p0, p0 + m0 * (1.0 / 3.0), p1 - m1 * (1.0 / 3.0), p1)
+}
+
 ///
 /// The cubic bezier weighted basis function
 ///
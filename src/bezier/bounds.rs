@@ -1,5 +1,8 @@
-use super::super::geo::{BoundingBox, Coordinate};
+use super::super::geo::{BoundingBox, Coordinate, Coordinate2D, Transform2D};
 use super::basis::de_casteljau4;
+use super::curve::BezierCurve;
+use super::derivative::{derivative_coefficients, derivative_roots_in_range};
+use super::section::CurveSection;
 
 ///
 /// Finds the t values of the extremities of a curve (these are the points at which
@@ -10,36 +13,22 @@ pub fn find_extremities<Point: Coordinate>(w1: Point, w2: Point, w3: Point, w4:
     let mut t_extremes = vec![1.0];
 
     // The derivative is a quadratic function, so we can compute the locations of these (t values) by solving the quadratic formula for them
-    for component_index in 0..Point::len() {
-        // Fetch the parameters for this component
-        let p1 = w1.get(component_index);
-        let p2 = w2.get(component_index);
-        let p3 = w3.get(component_index);
-        let p4 = w4.get(component_index);
-
-        // Compute the bezier coefficients
-        let a = (-p1 + p2 * 3.0 - p3 * 3.0 + p4) * 3.0;
-        let b = (p1 - p2 * 2.0 + p3) * 6.0;
-        let c = (p2 - p1) * 3.0;
+    let coefficients = derivative_coefficients(&w1, &w2, &w3, &w4);
+    let (_, b, c) = coefficients;
 
+    for component_index in 0..Point::len() {
         // Extremities are points at which the curve has a 0 gradient (in any of its dimensions)
-        let root1 = (-b + f64::sqrt(b * b - a * c * 4.0)) / (a * 2.0);
-        let root2 = (-b - f64::sqrt(b * b - a * c * 4.0)) / (a * 2.0);
-
-        if root1 > 0.0 && root1 < 1.0 {
-            t_extremes.push(root1);
-        }
-        if root2 > 0.0 && root2 < 1.0 {
-            t_extremes.push(root2);
-        }
+        t_extremes.extend(
+            derivative_roots_in_range(&coefficients, component_index)
+                .into_iter()
+                .filter(|t| *t > 0.0 && *t < 1.0),
+        );
 
-        // We also solve for the second derivative
-        let aa = 2.0 * (b - a);
-        let bb = 2.0 * (c - a);
+        // We also solve for the second derivative (b + c*t = 0)
+        let (b, c) = (b.get(component_index), c.get(component_index));
 
-        // Solve for a'*t+b = 0 (0-b/a')
-        if aa != 0.0 {
-            let root3 = -bb / aa;
+        if c != 0.0 {
+            let root3 = -b / c;
             if root3 > 0.0 && root3 < 1.0 {
                 t_extremes.push(root3);
             }
@@ -49,6 +38,54 @@ pub fn find_extremities<Point: Coordinate>(w1: Point, w2: Point, w3: Point, w4:
     t_extremes
 }
 
+///
+/// Finds the t values where any of the components of a curve changes direction
+///
+/// Unlike `find_extremities()`, this only returns the points where the curve's derivative is zero in one of its
+/// dimensions, and returns them in order without any duplicates.
+///
+fn find_turning_points<Point: Coordinate>(w1: Point, w2: Point, w3: Point, w4: Point) -> Vec<f64> {
+    const MIN_T: f64 = 1e-9;
+
+    let coefficients = derivative_coefficients(&w1, &w2, &w3, &w4);
+    let mut turning_points = vec![];
+
+    for component_index in 0..Point::len() {
+        turning_points.extend(
+            derivative_roots_in_range(&coefficients, component_index)
+                .into_iter()
+                .filter(|t| *t > MIN_T && *t < 1.0 - MIN_T),
+        );
+    }
+
+    turning_points.sort_by(|a, b| a.total_cmp(b));
+    turning_points.dedup_by(|a, b| (*a - *b).abs() <= MIN_T);
+
+    turning_points
+}
+
+///
+/// Splits a curve into sections that are monotonic in every dimension
+///
+/// The curve is divided at the points where any of its components stops increasing and starts decreasing (or vice
+/// versa), so along each of the returned sections every component either only increases or only decreases. The sections
+/// are returned in order, and can be converted back to t values on the original curve using `t_for_t()`. This is useful
+/// for algorithms such as scanline filling that need each section to cross any horizontal or vertical line only once.
+///
+pub fn split_into_monotonic<C: BezierCurve>(curve: &C) -> Vec<CurveSection<'_, C>> {
+    let (cp1, cp2) = curve.control_points();
+    let turning_points = find_turning_points(curve.start_point(), cp1, cp2, curve.end_point());
+
+    let mut t_values = vec![0.0];
+    t_values.extend(turning_points);
+    t_values.push(1.0);
+
+    t_values
+        .windows(2)
+        .map(|range| curve.section(range[0], range[1]))
+        .collect()
+}
+
 ///
 /// Finds the upper and lower points in a cubic curve's bounding box
 ///
@@ -74,3 +111,79 @@ pub fn bounding_box4<Point: Coordinate, Bounds: BoundingBox<Point = Point>>(
 
     Bounds::from_min_max(min_pos, max_pos)
 }
+
+///
+/// Finds the exact bounding box of a curve after an affine transformation has been applied to it
+///
+/// Bezier curves are unchanged in shape by affine transformations, so transforming the control points produces exactly
+/// the transformed curve. This finds the box from the transformed control points directly, so it's much cheaper than
+/// calling `transform()` on the curve and then finding its bounding box when many transformed boxes are needed.
+///
+pub fn bounding_box_of_transformed<C: BezierCurve, Bounds: BoundingBox<Point = C::Point>>(
+    curve: &C,
+    transform: &Transform2D,
+) -> Bounds
+where
+    C::Point: Coordinate2D,
+{
+    let (cp1, cp2) = curve.control_points();
+
+    bounding_box4(
+        transform.transform_point(&curve.start_point()),
+        transform.transform_point(&cp1),
+        transform.transform_point(&cp2),
+        transform.transform_point(&curve.end_point()),
+    )
+}
+
+///
+/// Finds a box that is guaranteed to enclose the part of a curve between two t values
+///
+/// The range is divided into `subdivisions` equal parts, and the result is the union of the boxes around the control points
+/// of each part. As a bezier curve is always inside the hull of its control points, the result always encloses the curve,
+/// and it gets closer to the exact bounding box of the range as the number of subdivisions increases (the error shrinks
+/// with the square of the number of subdivisions). The box is widened by a small amount to allow for floating point
+/// rounding, so a point outside of the box is certain not to be on this part of the curve.
+///
+/// This is much cheaper than finding the exact bounding box of each part of a curve, so it's useful for quickly ruling
+/// out collisions with parts of a curve.
+///
+pub fn curve_interval_bounds<C: BezierCurve, Bounds: BoundingBox<Point = C::Point>>(
+    curve: &C,
+    t_min: f64,
+    t_max: f64,
+    subdivisions: usize,
+) -> Bounds {
+    let subdivisions = subdivisions.max(1);
+    let (t_min, t_max) = (t_min.clamp(0.0, 1.0), t_max.clamp(0.0, 1.0));
+    let step = (t_max - t_min) / (subdivisions as f64);
+
+    let mut min = curve.point_at_pos(t_min);
+    let mut max = min;
+
+    for idx in 0..subdivisions {
+        let start = t_min + step * (idx as f64);
+        let end = if idx + 1 == subdivisions {
+            t_max
+        } else {
+            start + step
+        };
+
+        let section = curve.section(start, end);
+        let (cp1, cp2) = section.control_points();
+
+        for point in [section.start_point(), cp1, cp2, section.end_point()] {
+            min = C::Point::from_smallest_components(min, point);
+            max = C::Point::from_biggest_components(max, point);
+        }
+    }
+
+    // Allow for the rounding errors in subdividing the curve
+    let (cp1, cp2) = curve.control_points();
+    let magnitude = [curve.start_point(), cp1, cp2, curve.end_point()]
+        .iter()
+        .flat_map(|point| (0..C::Point::len()).map(move |idx| point.get(idx).abs()))
+        .fold(0.0, f64::max);
+
+    Bounds::from_min_max(min, max).inflate(magnitude * f64::EPSILON * 16.0)
+}
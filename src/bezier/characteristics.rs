@@ -2,12 +2,18 @@ use super::super::consts::SMALL_DISTANCE;
 use super::super::geo::{Coordinate, Coordinate2D};
 use super::super::line::line_coefficients_2d;
 use super::curve::{BezierCurve, BezierCurveFactory, Curve};
+use super::derivative::{
+    derivative_coefficients, derivative_roots_in_range, quadratic_roots_in_range,
+};
 use super::intersection::find_self_intersection_point;
 
 use std::f64;
 
 const SMALL_DIVISOR: f64 = 0.0000001;
 
+/// How small the speed of a curve has to be (relative to the size of its derivative's coefficients) to count as a cusp
+const CUSP_SPEED: f64 = 1e-6;
+
 // See also "A Geometrical Characterization of Parametric Curves"
 //  ACM Transactions on Graphics, Vol 8, No 3 (July 1989), 147-162
 // https://graphics.pixar.com/people/derose/publications/CubicClassification/paper.pdf
@@ -268,7 +274,7 @@ enum InflectionPoints {
 ///
 /// Finds the inflection points for a curve that has been reduced to our canonical form, given the free point b4
 ///
-fn find_inflection_points(b4: (f64, f64)) -> InflectionPoints {
+fn canonical_inflection_points(b4: (f64, f64)) -> InflectionPoints {
     // Compute coefficients
     let (x4, y4) = b4;
     let a = -3.0 + x4 + y4;
@@ -330,7 +336,7 @@ fn features_from_canonical_point<Point: Coordinate + Coordinate2D>(
         CurveCategory::Parabolic => CurveFeatures::Parabolic,
         CurveCategory::Point => CurveFeatures::Point,
         CurveCategory::DoubleInflectionPoint | CurveCategory::SingleInflectionPoint => {
-            find_inflection_points((x, y)).into()
+            canonical_inflection_points((x, y)).into()
         }
         CurveCategory::Loop => {
             let curve = Curve::from_points(*w1, (*w2, *w3), *w4);
@@ -453,6 +459,83 @@ where
     features_for_cubic_bezier(&start_point, &cp1, &cp2, &end_point, accuracy)
 }
 
+///
+/// Finds the t value of the cusp in a curve, if it has one
+///
+/// A cusp is a point where the curve stops and changes direction abruptly (the derivative is zero). This returns `None` for
+/// curves without a cusp between t=0 and t=1.
+///
+pub fn find_cusp<C: BezierCurve>(curve: &C) -> Option<f64>
+where
+    C::Point: Coordinate + Coordinate2D,
+{
+    let (cp1, cp2) = curve.control_points();
+    let coefficients =
+        derivative_coefficients(&curve.start_point(), &cp1, &cp2, &curve.end_point());
+    let (a, b, c) = coefficients;
+
+    let scale = a.magnitude().max(b.magnitude()).max(c.magnitude());
+    if scale <= 0.0 {
+        // Curve is a point
+        return None;
+    }
+
+    // At a cusp, both components of the derivative are zero: check where each is zero (including the turning points of each
+    // component, in case rounding errors mean that a double root is missed)
+    let speed_at = |t: f64| (a + b * (2.0 * t) + c * (t * t)).magnitude();
+    let mut candidates = derivative_roots_in_range(&coefficients, 0);
+    candidates.extend(derivative_roots_in_range(&coefficients, 1));
+    candidates.extend(
+        [(b.x(), c.x()), (b.y(), c.y())]
+            .iter()
+            .filter(|(_, c)| *c != 0.0)
+            .map(|(b, c)| -b / c)
+            .filter(|t| (0.0..=1.0).contains(t)),
+    );
+
+    candidates
+        .into_iter()
+        .map(|t| (t, speed_at(t)))
+        .filter(|(_, speed)| *speed <= CUSP_SPEED * scale)
+        .min_by(|(_, a), (_, b)| a.total_cmp(b))
+        .map(|(t, _)| t)
+}
+
+///
+/// Finds the t values of the inflection points of a curve (the points where it changes from bending one way to bending the
+/// other way)
+///
+/// The t values are returned in order. Straight lines have no inflection points, and the position of a cusp is not counted
+/// as an inflection point (use `find_cusp()` to find it).
+///
+pub fn find_inflection_points<C: BezierCurve>(curve: &C) -> Vec<f64>
+where
+    C::Point: Coordinate + Coordinate2D,
+{
+    let (cp1, cp2) = curve.control_points();
+    let (a, b, c) = derivative_coefficients(&curve.start_point(), &cp1, &cp2, &curve.end_point());
+
+    // The curvature changes sign where the cross product of the first and second derivatives is 0
+    let cross = |p: &C::Point, q: &C::Point| p.x() * q.y() - p.y() * q.x();
+    let (p0, p1, p2) = (cross(&a, &b), cross(&a, &c), cross(&b, &c));
+
+    let scale = p0.abs().max(p1.abs()).max(p2.abs());
+    if scale <= f64::EPSILON * (a.magnitude() + b.magnitude() + c.magnitude()).powi(2) {
+        // The curve is a straight line
+        return vec![];
+    }
+
+    let mut inflections = quadratic_roots_in_range(p0, p1, p2);
+    inflections.sort_by(|a, b| a.total_cmp(b));
+    inflections.dedup_by(|a, b| (*a - *b).abs() <= 1e-9);
+
+    if let Some(cusp) = find_cusp(curve) {
+        inflections.retain(|t| (t - cusp).abs() > 1e-6);
+    }
+
+    inflections
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
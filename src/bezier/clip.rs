@@ -0,0 +1,83 @@
+use super::curve::{BezierCurve, BezierCurveFactory};
+use super::solve::solve_basis_for_t;
+use crate::geo::{BoundingBox, Coordinate2D};
+
+///
+/// Divides a curve at the points where it crosses the edges of a bounding box
+///
+/// The result is a list of `(t_min, t_max, is_inside)` spans covering the whole curve, in order.
+///
+pub(crate) fn bounds_spans<C: BezierCurve, B: BoundingBox<Point = C::Point>>(
+    curve: &C,
+    bounds: &B,
+) -> Vec<(f64, f64, bool)>
+where
+    C::Point: Coordinate2D,
+{
+    let (min, max) = (bounds.min(), bounds.max());
+    let start_point = curve.start_point();
+    let (cp1, cp2) = curve.control_points();
+    let end_point = curve.end_point();
+
+    // Find where the curve crosses each of the lines making up the edges of the bounds
+    let mut splits = vec![0.0, 1.0];
+
+    for x in [min.x(), max.x()] {
+        splits.extend(solve_basis_for_t(
+            start_point.x(),
+            cp1.x(),
+            cp2.x(),
+            end_point.x(),
+            x,
+        ));
+    }
+    for y in [min.y(), max.y()] {
+        splits.extend(solve_basis_for_t(
+            start_point.y(),
+            cp1.y(),
+            cp2.y(),
+            end_point.y(),
+            y,
+        ));
+    }
+
+    splits.sort_by(|a, b| a.total_cmp(b));
+    splits.dedup_by(|a, b| (*a - *b).abs() <= 1e-9);
+
+    // Each span between the crossings is either entirely inside or entirely outside the bounds
+    let is_inside = |point: C::Point| {
+        point.x() >= min.x() && point.x() <= max.x() && point.y() >= min.y() && point.y() <= max.y()
+    };
+
+    let mut spans: Vec<(f64, f64, bool)> = vec![];
+    for range in splits.windows(2) {
+        let (t_min, t_max) = (range[0], range[1]);
+        let inside = is_inside(curve.point_at_pos((t_min + t_max) * 0.5));
+
+        match spans.last_mut() {
+            Some(last) if last.2 == inside => last.1 = t_max,
+            _ => spans.push((t_min, t_max, inside)),
+        }
+    }
+
+    spans
+}
+
+///
+/// Returns the parts of a curve that are inside an axis-aligned bounding box
+///
+/// The curve is split at the points where it crosses the edges of the bounding box. Parts of the curve that run along the
+/// edges are counted as inside.
+///
+pub fn clip_curve_to_bounds<C, B>(curve: &C, bounds: &B) -> Vec<C>
+where
+    C: BezierCurveFactory,
+    C::Point: Coordinate2D,
+    B: BoundingBox<Point = C::Point>,
+{
+    bounds_spans(curve, bounds)
+        .into_iter()
+        .filter(|(_, _, inside)| *inside)
+        .map(|(t_min, t_max, _)| C::from_curve(&curve.section(t_min, t_max)))
+        .collect()
+}
@@ -1,16 +1,21 @@
-use super::basis::basis;
+use super::basis::{basis, bezier_to_polynomial, hermite_to_bezier, polynomial_to_bezier};
 use super::bounds::{bounding_box4, find_extremities};
 use super::characteristics::{
     characterize_cubic_bezier, features_for_cubic_bezier, CurveCategory, CurveFeatures,
 };
+use super::derivative::{derivative3, derivative4};
 use super::fit::fit_curve;
 use super::length::curve_length;
+use super::quadratic::QuadraticCurve;
 use super::search::search_bounds4;
 use super::section::CurveSection;
 use super::solve::solve_curve_for_t;
 use super::subdivide::subdivide4;
 
-use crate::geo::{BoundingBox, Coordinate, Coordinate2D, Geo, HasBoundingBox};
+use crate::geo::{
+    ApproxEq, BoundingBox, Coordinate, Coordinate2D, Geo, HasBoundingBox, QuantisedHash,
+    Transform2D, Transformable,
+};
 
 ///
 /// Trait implemented by bezier curves that can create new versions of themselves
@@ -37,6 +42,28 @@ pub trait BezierCurveFactory: BezierCurve {
         )
     }
 
+    ///
+    /// Creates a new bezier curve from a cubic Hermite spline segment, which runs from `p0` to `p1` with the tangents
+    /// (derivatives) `m0` and `m1` at either end
+    ///
+    #[inline]
+    fn from_hermite(p0: Self::Point, m0: Self::Point, p1: Self::Point, m1: Self::Point) -> Self {
+        let (w1, w2, w3, w4) = hermite_to_bezier(p0, m0, p1, m1);
+
+        Self::from_points(w1, (w2, w3), w4)
+    }
+
+    ///
+    /// Creates a new bezier curve following the polynomial `a*t^3 + b*t^2 + c*t + d` (evaluated per component) for `t`
+    /// between 0 and 1
+    ///
+    #[inline]
+    fn from_polynomial(a: Self::Point, b: Self::Point, c: Self::Point, d: Self::Point) -> Self {
+        let (w1, w2, w3, w4) = polynomial_to_bezier(a, b, c, d);
+
+        Self::from_points(w1, (w2, w3), w4)
+    }
+
     ///
     /// Generates a curve by attempting to find a best fit against a set of points
     ///
@@ -88,6 +115,46 @@ pub trait BezierCurve: Geo + Clone + Sized {
         )
     }
 
+    ///
+    /// Returns the polynomial coefficients `(a, b, c, d)` for this curve, such that the point at `t` is
+    /// `a*t^3 + b*t^2 + c*t + d`
+    ///
+    #[inline]
+    fn polynomial_coefficients(&self) -> (Self::Point, Self::Point, Self::Point, Self::Point) {
+        let (cp1, cp2) = self.control_points();
+
+        bezier_to_polynomial(self.start_point(), cp1, cp2, self.end_point())
+    }
+
+    ///
+    /// Returns the derivative of this curve (the velocity at each t value) as a quadratic curve
+    ///
+    /// The points of the derivative are vectors rather than positions: `derivative().point_at_pos(t)` is the same as
+    /// the tangent at `t`. The result can be converted to a cubic curve with `to_cubic()` to analyse it with the other
+    /// curve functions (for example, to find where the velocity crosses a particular value).
+    ///
+    #[inline]
+    fn derivative(&self) -> QuadraticCurve<Self::Point> {
+        let (cp1, cp2) = self.control_points();
+        let (d1, d2, d3) = derivative4(self.start_point(), cp1, cp2, self.end_point());
+
+        QuadraticCurve::from_points(d1, d2, d3)
+    }
+
+    ///
+    /// Returns the second derivative of this curve (the acceleration at each t value) as a line
+    ///
+    #[inline]
+    fn second_derivative(&self) -> (Self::Point, Self::Point) {
+        let derivative = self.derivative();
+
+        derivative3(
+            derivative.start_point,
+            derivative.control_point,
+            derivative.end_point,
+        )
+    }
+
     ///
     /// Given a point that is on or very close to the curve, returns the t value where the point can be found
     /// (or None if the point is not very close to the curve)
@@ -255,6 +322,52 @@ impl<Coord: Coordinate> HasBoundingBox for Curve<Coord> {
     }
 }
 
+impl<Coord: Coordinate + Coordinate2D> Transformable for Curve<Coord> {
+    ///
+    /// Transforms this curve (affine transformations can be applied exactly by transforming the control points)
+    ///
+    fn transform(&self, transform: &Transform2D) -> Self {
+        let (cp1, cp2) = self.control_points;
+
+        Curve {
+            start_point: transform.transform_point(&self.start_point),
+            end_point: transform.transform_point(&self.end_point),
+            control_points: (
+                transform.transform_point(&cp1),
+                transform.transform_point(&cp2),
+            ),
+        }
+    }
+}
+
+impl<Coord: Coordinate> ApproxEq for Curve<Coord> {
+    fn approx_eq(&self, other: &Self, tolerance: f64) -> bool {
+        self.start_point.approx_eq(&other.start_point, tolerance)
+            && self
+                .control_points
+                .0
+                .approx_eq(&other.control_points.0, tolerance)
+            && self
+                .control_points
+                .1
+                .approx_eq(&other.control_points.1, tolerance)
+            && self.end_point.approx_eq(&other.end_point, tolerance)
+    }
+}
+
+impl<Coord: Coordinate> QuantisedHash for Curve<Coord> {
+    fn quantised_components(&self, quantum: f64, components: &mut Vec<i64>) {
+        self.start_point.quantised_components(quantum, components);
+        self.control_points
+            .0
+            .quantised_components(quantum, components);
+        self.control_points
+            .1
+            .quantised_components(quantum, components);
+        self.end_point.quantised_components(quantum, components);
+    }
+}
+
 ///
 /// Functions supported on 2D bezier curves
 ///
@@ -1,5 +1,7 @@
 use super::super::geo::Coordinate;
 
+use roots::{find_roots_quadratic, Roots};
+
 ///
 /// Returns the 1st derivative of a cubic bezier curve
 ///
@@ -25,3 +27,53 @@ pub fn derivative3<Point: Coordinate>(wn1: Point, wn2: Point, wn3: Point) -> (Po
 pub fn derivative2<Point: Coordinate>(wnn1: Point, wnn2: Point) -> Point {
     wnn2 - wnn1
 }
+
+///
+/// The coefficients of the derivative of a cubic curve, as `(a, b, c)` where the derivative is `3(a + 2bt + ct^2)`
+///
+#[inline]
+pub(crate) fn derivative_coefficients<Point: Coordinate>(
+    w1: &Point,
+    w2: &Point,
+    w3: &Point,
+    w4: &Point,
+) -> (Point, Point, Point) {
+    let a = *w2 - *w1;
+    let b = *w3 - *w2 * 2.0 + *w1;
+    let c = *w4 - *w3 * 3.0 + *w2 * 3.0 - *w1;
+
+    (a, b, c)
+}
+
+///
+/// Returns the roots of `p0 + p1*t + p2*t^2` in the range 0..=1
+///
+pub(crate) fn quadratic_roots_in_range(p0: f64, p1: f64, p2: f64) -> Vec<f64> {
+    let roots = match find_roots_quadratic(p2, p1, p0) {
+        Roots::No(_) => vec![],
+        Roots::One([a]) => vec![a],
+        Roots::Two([a, b]) => vec![a, b],
+        Roots::Three([a, b, c]) => vec![a, b, c],
+        Roots::Four([a, b, c, d]) => vec![a, b, c, d],
+    };
+
+    roots
+        .into_iter()
+        .filter(|t| (0.0..=1.0).contains(t))
+        .collect()
+}
+
+///
+/// Returns the t values in the range 0..=1 where one component of the derivative of a cubic curve is zero
+///
+/// `coefficients` are the values returned by `derivative_coefficients()` for the curve.
+///
+#[inline]
+pub(crate) fn derivative_roots_in_range<Point: Coordinate>(
+    coefficients: &(Point, Point, Point),
+    component: usize,
+) -> Vec<f64> {
+    let (a, b, c) = coefficients;
+
+    quadratic_roots_in_range(a.get(component), 2.0 * b.get(component), c.get(component))
+}
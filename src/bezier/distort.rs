@@ -1,12 +1,22 @@
 use super::curve::{BezierCurve, BezierCurveFactory, Curve};
-use super::fit::fit_curve;
+use super::fit::{fit_curve, fit_curve_cubic};
 use super::normal::Normalize;
 use super::path::{BezierPath, BezierPathFactory};
 use super::walk::walk_curve_evenly;
-use crate::geo::Coordinate2D;
+use crate::consts::SMALL_DISTANCE;
+use crate::geo::{Coordinate, Coordinate2D};
 
 use std::iter;
 
+/// Number of sections to initially divide each curve into when sampling it
+const INITIAL_SAMPLES: usize = 4;
+
+/// Maximum number of times a section can be subdivided when sampling a curve
+const MAX_SUBDIVISIONS: usize = 6;
+
+/// Distance along a curve (as a t value) used to estimate the tangent at its ends after warping
+const TANGENT_T: f64 = 1e-4;
+
 ///
 /// Distorts a curve using an arbitrary function
 ///
@@ -54,6 +64,58 @@ where
     PathIn: BezierPath,
     PathOut: BezierPathFactory<Point = PathIn::Point>,
     DistortFn: Fn(PathIn::Point, &Curve<PathIn::Point>, f64) -> PathOut::Point,
+{
+    let (start_point, new_points) =
+        distort_path_sections(path, distort_fn, step_len, max_error, false)?;
+
+    // Create the new path from the result
+    Some(PathOut::from_points(start_point, new_points))
+}
+
+///
+/// Distorts a path using an arbitrary function, keeping the joins between its sections continuous
+///
+/// This is the same as `distort_path()`, except that the start of each distorted section is always placed at the end of
+/// the previous one, so the result is continuous even if the function moves the points at the joins differently for each
+/// curve, and closed paths remain closed.
+///
+pub fn distort_path_continuous<PathIn, DistortFn, PathOut>(
+    path: &PathIn,
+    distort_fn: DistortFn,
+    step_len: f64,
+    max_error: f64,
+) -> Option<PathOut>
+where
+    PathIn: BezierPath,
+    PathOut: BezierPathFactory<Point = PathIn::Point>,
+    DistortFn: Fn(PathIn::Point, &Curve<PathIn::Point>, f64) -> PathOut::Point,
+{
+    let (start_point, mut new_points) =
+        distort_path_sections(path, distort_fn, step_len, max_error, true)?;
+
+    // Closed paths should remain closed after distortion
+    close_distorted_path(path, start_point, &mut new_points);
+
+    // Create the new path from the result
+    Some(PathOut::from_points(start_point, new_points))
+}
+
+/// The start point and sections of a distorted path
+type DistortedSections<Point> = (Point, Vec<(Point, Point, Point)>);
+
+///
+/// Distorts each of the sections of a path, optionally starting each one where the previous one ended
+///
+fn distort_path_sections<PathIn, DistortFn>(
+    path: &PathIn,
+    distort_fn: DistortFn,
+    step_len: f64,
+    max_error: f64,
+    join_sections: bool,
+) -> Option<DistortedSections<PathIn::Point>>
+where
+    PathIn: BezierPath,
+    DistortFn: Fn(PathIn::Point, &Curve<PathIn::Point>, f64) -> PathIn::Point,
 {
     // The initial point is derived from the first curve
     let start_point = path.start_point();
@@ -68,12 +130,13 @@ where
 
     // Process the remaining points to generate the new path
     let mut new_points = vec![];
+    let mut last_point = start_point;
 
     loop {
         // Distort the current curve
         let sections = walk_curve_evenly(&current_curve, step_len, step_len / 4.0);
 
-        let fit_points = sections
+        let mut fit_points = sections
             .map(|section| {
                 let (t, _) = section.original_curve_t_values();
                 let pos = current_curve.point_at_pos(t);
@@ -84,12 +147,18 @@ where
             .map(|(point, t)| distort_fn(point, &current_curve, t))
             .collect::<Vec<_>>();
 
+        // The distortion function is passed the curve, so it can move the start of this curve away from the end of the previous one
+        if join_sections {
+            fit_points[0] = last_point;
+        }
+
         // Fit the points to generate the new curves
         let new_curves = fit_curve::<Curve<_>>(&fit_points, max_error)?;
         new_points.extend(new_curves.into_iter().map(|curve| {
             let (cp1, cp2) = curve.control_points();
             (cp1, cp2, curve.end_point())
         }));
+        last_point = new_points.last()?.2;
 
         // Move to the next curve (stopping once we reach the end of the list of the points)
         let next_start_point = current_curve.end_point();
@@ -105,6 +174,150 @@ where
         );
     }
 
-    // Create the new path from the result
-    Some(PathOut::from_points(start_point, new_points))
+    Some((start_point, new_points))
+}
+
+///
+/// If the original path is closed, moves the end of the last curve in a distorted path so that it meets its start point
+///
+fn close_distorted_path<P: BezierPath>(
+    original_path: &P,
+    start_point: P::Point,
+    new_points: &mut [(P::Point, P::Point, P::Point)],
+) {
+    let original_end = original_path
+        .points()
+        .last()
+        .map(|(_, _, end_point)| end_point);
+
+    if let (Some(original_end), Some(last)) = (original_end, new_points.last_mut()) {
+        if original_end.is_near_to(&original_path.start_point(), SMALL_DISTANCE) {
+            let offset = start_point - last.2;
+
+            last.1 = last.1 + offset;
+            last.2 = start_point;
+        }
+    }
+}
+
+///
+/// Samples a curve after applying a mapping function to it, subdividing until the samples are within `max_error` of the
+/// mapped curve
+///
+/// The mapping function can return `None` to indicate that a point cannot be mapped, in which case this will also return
+/// `None`.
+///
+pub(crate) fn sample_mapped_curve<TCurve, MapFn>(
+    curve: &TCurve,
+    map_fn: MapFn,
+    max_error: f64,
+) -> Option<Vec<TCurve::Point>>
+where
+    TCurve: BezierCurve,
+    MapFn: Fn(TCurve::Point) -> Option<TCurve::Point>,
+{
+    let mapped_at = |t: f64| map_fn(curve.point_at_pos(t));
+
+    let mut samples = vec![mapped_at(0.0)?];
+
+    for section in 0..INITIAL_SAMPLES {
+        let t_start = (section as f64) / (INITIAL_SAMPLES as f64);
+        let t_end = ((section + 1) as f64) / (INITIAL_SAMPLES as f64);
+
+        // Work through the sections in order using a stack of (t_start, t_end, depth) values
+        let mut pending = vec![(t_start, t_end, 0)];
+
+        while let Some((t_start, t_end, depth)) = pending.pop() {
+            let start = *samples.last().unwrap();
+            let end = mapped_at(t_end)?;
+            let t_mid = (t_start + t_end) * 0.5;
+            let mid = mapped_at(t_mid)?;
+
+            // Subdivide if the section isn't flat enough to be represented by a line between the two samples
+            let chord_mid = (start + end) * 0.5;
+
+            if depth < MAX_SUBDIVISIONS && mid.distance_to(&chord_mid) > max_error * 0.5 {
+                pending.push((t_mid, t_end, depth + 1));
+                pending.push((t_start, t_mid, depth + 1));
+            } else {
+                samples.push(mid);
+                samples.push(end);
+            }
+        }
+    }
+
+    Some(samples)
+}
+
+///
+/// Warps a curve by moving every point on it using a mapping function
+///
+/// This differs from `distort_curve()` in that the mapping function is only passed the point to move, so the curve is
+/// sampled more closely where the warp bends it the most, and the result is within `max_error` of the warped curve. The
+/// start and end points of the result are exactly the mapped start and end points of the curve.
+///
+pub fn warp_curve<CurveIn, WarpFn, CurveOut>(
+    curve: &CurveIn,
+    warp_fn: WarpFn,
+    max_error: f64,
+) -> Vec<CurveOut>
+where
+    CurveIn: BezierCurve,
+    CurveOut: BezierCurveFactory<Point = CurveIn::Point>,
+    WarpFn: Fn(CurveIn::Point) -> CurveIn::Point,
+{
+    let points = sample_mapped_curve(curve, |point| Some(warp_fn(point)), max_error).unwrap();
+
+    // Estimate the tangents at the end of the warped curve from points just inside it
+    let tangent = |from: CurveIn::Point, towards: CurveIn::Point, fallback: CurveIn::Point| {
+        let direction = towards - from;
+
+        if direction.magnitude() > 0.0 {
+            direction.to_unit_vector()
+        } else {
+            (fallback - from).to_unit_vector()
+        }
+    };
+
+    let start_tangent = tangent(points[0], warp_fn(curve.point_at_pos(TANGENT_T)), points[1]);
+    let end_tangent = tangent(
+        points[points.len() - 1],
+        warp_fn(curve.point_at_pos(1.0 - TANGENT_T)),
+        points[points.len() - 2],
+    );
+
+    fit_curve_cubic(&points, &start_tangent, &end_tangent, max_error)
+}
+
+///
+/// Warps a path by moving every point on it using a mapping function
+///
+/// Each section of the path is warped using `warp_curve()`, so the result is within `max_error` of the warped path. As
+/// the mapping function is only passed the point to move, the sections of the result always join up, and closed paths
+/// remain closed. This can be used for effects such as flags, arches or fisheye lenses that need to bend a whole
+/// outline at once.
+///
+pub fn warp_path<PathIn, WarpFn, PathOut>(path: &PathIn, warp_fn: WarpFn, max_error: f64) -> PathOut
+where
+    PathIn: BezierPath,
+    PathOut: BezierPathFactory<Point = PathIn::Point>,
+    WarpFn: Fn(PathIn::Point) -> PathIn::Point,
+{
+    let mut last_point = path.start_point();
+    let start_point = warp_fn(last_point);
+    let mut new_points = vec![];
+
+    for (cp1, cp2, end_point) in path.points() {
+        let curve = Curve::from_points(last_point, (cp1, cp2), end_point);
+        let new_curves = warp_curve::<_, _, Curve<_>>(&curve, &warp_fn, max_error);
+
+        new_points.extend(new_curves.into_iter().map(|curve| {
+            let (cp1, cp2) = curve.control_points();
+            (cp1, cp2, curve.end_point())
+        }));
+
+        last_point = end_point;
+    }
+
+    PathOut::from_points(start_point, new_points)
 }
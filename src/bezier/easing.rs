@@ -0,0 +1,191 @@
+use super::curve::BezierCurve;
+use crate::geo::{Coord2, Geo};
+
+/// Maximum number of Newton-Raphson iterations used to find the t value for an input
+const NEWTON_ITERATIONS: usize = 8;
+
+/// Newton-Raphson iteration stops once x is this close to the input
+const NEWTON_PRECISION: f64 = 1e-7;
+
+/// Newton-Raphson iteration is abandoned when the slope of the curve is flatter than this
+const MIN_SLOPE: f64 = 1e-6;
+
+/// Number of bisection steps used when Newton-Raphson iteration fails
+const BISECTION_ITERATIONS: usize = 48;
+
+///
+/// The standard easing functions defined by CSS
+///
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum EasingPreset {
+    /// The output is the same as the input
+    Linear,
+
+    /// Speeds up quickly and slows down gradually
+    Ease,
+
+    /// Starts slowly
+    EaseIn,
+
+    /// Ends slowly
+    EaseOut,
+
+    /// Starts and ends slowly
+    EaseInOut,
+}
+
+///
+/// A cubic bezier easing function, as used by CSS transitions and animations
+///
+/// The curve runs from (0, 0) to (1, 1), with two control points. The x axis represents the input (the proportion of
+/// the time that has passed) and the y axis the output (the proportion of the way through the animation). The x values
+/// of the control points are clamped to the range 0..1 so that there's exactly one output for every input, but the y
+/// values can be outside this range for functions that overshoot.
+///
+/// `EasingCurve::preset()` creates the standard CSS functions. `ease()` finds the output for an input: the polynomial
+/// coefficients are calculated when the function is created, so this is fast enough to call every frame. This also
+/// implements `BezierCurve`, so the curve can be used with the rest of the library.
+///
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct EasingCurve {
+    /// The first control point
+    cp1: Coord2,
+
+    /// The second control point
+    cp2: Coord2,
+
+    /// Polynomial coefficients for the x component (a*t^3 + b*t^2 + c*t)
+    x_coefficients: (f64, f64, f64),
+
+    /// Polynomial coefficients for the y component (a*t^3 + b*t^2 + c*t)
+    y_coefficients: (f64, f64, f64),
+}
+
+impl EasingCurve {
+    ///
+    /// Creates an easing function from the positions of its control points (as for the CSS `cubic-bezier()` function)
+    ///
+    pub fn new(x1: f64, y1: f64, x2: f64, y2: f64) -> EasingCurve {
+        let (x1, x2) = (x1.clamp(0.0, 1.0), x2.clamp(0.0, 1.0));
+
+        EasingCurve {
+            cp1: Coord2(x1, y1),
+            cp2: Coord2(x2, y2),
+            x_coefficients: Self::coefficients(x1, x2),
+            y_coefficients: Self::coefficients(y1, y2),
+        }
+    }
+
+    ///
+    /// Creates one of the standard CSS easing functions
+    ///
+    pub fn preset(preset: EasingPreset) -> EasingCurve {
+        match preset {
+            EasingPreset::Linear => Self::new(0.0, 0.0, 1.0, 1.0),
+            EasingPreset::Ease => Self::new(0.25, 0.1, 0.25, 1.0),
+            EasingPreset::EaseIn => Self::new(0.42, 0.0, 1.0, 1.0),
+            EasingPreset::EaseOut => Self::new(0.0, 0.0, 0.58, 1.0),
+            EasingPreset::EaseInOut => Self::new(0.42, 0.0, 0.58, 1.0),
+        }
+    }
+
+    ///
+    /// Returns the output of this easing function for an input between 0 and 1 (inputs outside this range are clamped)
+    ///
+    pub fn ease(&self, x: f64) -> f64 {
+        let t = self.t_for_x(x.clamp(0.0, 1.0));
+
+        Self::evaluate(self.y_coefficients, t)
+    }
+
+    ///
+    /// Finds the t value where the curve has a particular x value
+    ///
+    pub fn t_for_x(&self, x: f64) -> f64 {
+        if x <= 0.0 {
+            return 0.0;
+        } else if x >= 1.0 {
+            return 1.0;
+        }
+
+        // Newton-Raphson iteration converges quickly for most curves
+        let (a, b, c) = self.x_coefficients;
+        let mut t = x;
+
+        for _ in 0..NEWTON_ITERATIONS {
+            let error = Self::evaluate(self.x_coefficients, t) - x;
+            if error.abs() < NEWTON_PRECISION {
+                return t;
+            }
+
+            let slope = 3.0 * a * t * t + 2.0 * b * t + c;
+            if slope.abs() < MIN_SLOPE {
+                break;
+            }
+
+            t -= error / slope;
+        }
+
+        // Fall back to bisection where the curve is too flat (x is always increasing, so this always finds the answer)
+        let (mut low, mut high) = (0.0, 1.0);
+        for _ in 0..BISECTION_ITERATIONS {
+            let mid = (low + high) * 0.5;
+
+            if Self::evaluate(self.x_coefficients, mid) < x {
+                low = mid;
+            } else {
+                high = mid;
+            }
+        }
+
+        (low + high) * 0.5
+    }
+
+    ///
+    /// Returns the polynomial coefficients for a component of a curve starting at 0 and ending at 1
+    ///
+    #[inline]
+    fn coefficients(w2: f64, w3: f64) -> (f64, f64, f64) {
+        let c = 3.0 * w2;
+        let b = 3.0 * (w3 - w2) - c;
+        let a = 1.0 - c - b;
+
+        (a, b, c)
+    }
+
+    ///
+    /// Evaluates a component at a t value
+    ///
+    #[inline]
+    fn evaluate((a, b, c): (f64, f64, f64), t: f64) -> f64 {
+        ((a * t + b) * t + c) * t
+    }
+}
+
+impl Geo for EasingCurve {
+    type Point = Coord2;
+}
+
+impl BezierCurve for EasingCurve {
+    #[inline]
+    fn start_point(&self) -> Coord2 {
+        Coord2(0.0, 0.0)
+    }
+
+    #[inline]
+    fn end_point(&self) -> Coord2 {
+        Coord2(1.0, 1.0)
+    }
+
+    #[inline]
+    fn control_points(&self) -> (Coord2, Coord2) {
+        (self.cp1, self.cp2)
+    }
+}
+
+impl From<EasingPreset> for EasingCurve {
+    #[inline]
+    fn from(preset: EasingPreset) -> EasingCurve {
+        EasingCurve::preset(preset)
+    }
+}
@@ -0,0 +1,73 @@
+use crate::bezier::curve::BezierCurve;
+use crate::bezier::solve::solve_basis_for_t;
+use crate::geo::{BoundingBox, Coordinate2D};
+
+/// t values closer together than this are considered to be the same place on the curve
+const SAME_T_DISTANCE: f64 = 1e-9;
+
+///
+/// Finds the sections of a curve that lie inside an axis-aligned rectangle, returning the t values where the curve
+/// enters and leaves it
+///
+/// The spans are returned in order along the curve as `(t_enter, t_exit)` pairs. A span starts at 0 if the curve starts
+/// inside the rectangle, and ends at 1 if the curve finishes inside it. Points on the edge of the rectangle are counted
+/// as inside, so a curve that runs along one of the edges is inside there, but a curve that just touches a corner or an
+/// edge without going inside doesn't produce a span.
+///
+/// This is useful for clipping strokes against a viewport: `section(t_enter, t_exit)` will generate the visible parts of
+/// the curve.
+///
+pub fn curve_intersects_bounds<C: BezierCurve, Bounds: BoundingBox<Point = C::Point>>(
+    curve: &C,
+    bounds: &Bounds,
+) -> Vec<(f64, f64)>
+where
+    C::Point: Coordinate2D,
+{
+    let min = bounds.min();
+    let max = bounds.max();
+
+    if min.x() > max.x() || min.y() > max.y() {
+        return vec![];
+    }
+
+    // Find every place where the curve crosses one of the lines that the sides of the rectangle are on
+    let start = curve.start_point();
+    let (cp1, cp2) = curve.control_points();
+    let end = curve.end_point();
+
+    let mut crossings = vec![0.0, 1.0];
+    for x in [min.x(), max.x()] {
+        crossings.extend(solve_basis_for_t(start.x(), cp1.x(), cp2.x(), end.x(), x));
+    }
+    for y in [min.y(), max.y()] {
+        crossings.extend(solve_basis_for_t(start.y(), cp1.y(), cp2.y(), end.y(), y));
+    }
+
+    crossings.sort_by(|t1, t2| t1.total_cmp(t2));
+    crossings.dedup_by(|t2, t1| (*t2 - *t1).abs() < SAME_T_DISTANCE);
+
+    // The curve is either entirely inside or entirely outside the rectangle between each crossing
+    let is_inside = |t: f64| {
+        let point = curve.point_at_pos(t);
+
+        point.x() >= min.x() && point.x() <= max.x() && point.y() >= min.y() && point.y() <= max.y()
+    };
+
+    let mut spans: Vec<(f64, f64)> = vec![];
+    for section in crossings.windows(2) {
+        let (t1, t2) = (section[0], section[1]);
+
+        if !is_inside((t1 + t2) * 0.5) {
+            continue;
+        }
+
+        // Join sections that meet at a point where the curve touches the side of the rectangle from the inside
+        match spans.last_mut() {
+            Some(last) if last.1 == t1 => last.1 = t2,
+            _ => spans.push((t1, t2)),
+        }
+    }
+
+    spans
+}
@@ -0,0 +1,154 @@
+use super::super::basis::{bezier_coefficients, de_casteljau4};
+use super::super::curve::BezierCurve;
+use super::super::polynomial::{poly_mul, poly_roots_in_range};
+use crate::geo::{Coordinate, Coordinate2D};
+
+use smallvec::SmallVec;
+
+/// Polynomial values within this fraction of the largest coefficient are treated as 0 (so tangent points count as roots)
+const RELATIVE_ZERO: f64 = 1e-9;
+
+///
+/// The coefficients of the implicit equation of a conic section: `a*x^2 + b*x*y + c*y^2 + d*x + e*y + f = 0`
+///
+/// Circles, ellipses, parabolas and hyperbolas can all be described this way.
+///
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Conic {
+    /// The coefficient of `x^2`
+    pub a: f64,
+
+    /// The coefficient of `x*y`
+    pub b: f64,
+
+    /// The coefficient of `y^2`
+    pub c: f64,
+
+    /// The coefficient of `x`
+    pub d: f64,
+
+    /// The coefficient of `y`
+    pub e: f64,
+
+    /// The constant term
+    pub f: f64,
+}
+
+impl Conic {
+    ///
+    /// Creates the conic describing a circle
+    ///
+    pub fn circle<Point: Coordinate2D>(center: &Point, radius: f64) -> Conic {
+        let (cx, cy) = (center.x(), center.y());
+
+        Conic {
+            a: 1.0,
+            b: 0.0,
+            c: 1.0,
+            d: -2.0 * cx,
+            e: -2.0 * cy,
+            f: cx * cx + cy * cy - radius * radius,
+        }
+    }
+
+    ///
+    /// Evaluates the implicit equation at a point (0 for points on the conic)
+    ///
+    pub fn value_at<Point: Coordinate2D>(&self, point: &Point) -> f64 {
+        let (x, y) = (point.x(), point.y());
+
+        self.a * x * x + self.b * x * y + self.c * y * y + self.d * x + self.e * y + self.f
+    }
+}
+
+///
+/// Finds the t values between 0 and 1 where a curve defined by its control points meets a conic
+///
+fn conic_roots<Point: Coordinate + Coordinate2D>(
+    w1: &Point,
+    w2: &Point,
+    w3: &Point,
+    w4: &Point,
+    conic: &Conic,
+) -> SmallVec<[f64; 6]> {
+    // Polynomials for the x and y coordinates of the curve, from the constant term upwards
+    let (ax, bx, cx, dx) = bezier_coefficients(0, w1, w2, w3, w4);
+    let (ay, by, cy, dy) = bezier_coefficients(1, w1, w2, w3, w4);
+    let x = [dx, cx, bx, ax];
+    let y = [dy, cy, by, ay];
+
+    // Substitute into the equation of the conic to get a polynomial whose roots are the intersections
+    let xx = poly_mul(&x, &x);
+    let xy = poly_mul(&x, &y);
+    let yy = poly_mul(&y, &y);
+
+    let mut p = [0.0; 7];
+    for power in 0..7 {
+        p[power] = conic.a * xx[power] + conic.b * xy[power] + conic.c * yy[power];
+    }
+    for power in 0..4 {
+        p[power] += conic.d * x[power] + conic.e * y[power];
+    }
+    p[0] += conic.f;
+
+    // Values very close to 0 are treated as roots so that tangent points are found
+    let scale = p
+        .iter()
+        .fold(0.0f64, |scale, coefficient| scale.max(coefficient.abs()));
+
+    poly_roots_in_range(&p, 0.0, 1.0, scale * RELATIVE_ZERO)
+}
+
+///
+/// Finds the points where a curve crosses or touches a conic section
+///
+/// Return value is a list of `(curve_t, intersection_point)` values, ordered by `t` value. If the curve lies entirely on
+/// the conic (for example, a straight curve on a degenerate conic made up of a pair of lines), no intersections are
+/// returned.
+///
+pub fn curve_intersects_conic<C: BezierCurve>(
+    curve: &C,
+    conic: &Conic,
+) -> SmallVec<[(f64, C::Point); 6]>
+where
+    C::Point: Coordinate2D,
+{
+    let w1 = curve.start_point();
+    let (w2, w3) = curve.control_points();
+    let w4 = curve.end_point();
+
+    conic_roots(&w1, &w2, &w3, &w4, conic)
+        .into_iter()
+        .map(|t| (t, de_casteljau4(t, w1, w2, w3, w4)))
+        .collect()
+}
+
+///
+/// Finds the points where a curve crosses or touches a circle
+///
+/// Return value is a list of `(curve_t, intersection_point)` values, ordered by `t` value. This solves against the
+/// equation of the circle, so it's faster and more accurate than converting the circle to a path and finding the
+/// intersections between that and the curve.
+///
+pub fn curve_intersects_circle<C: BezierCurve>(
+    curve: &C,
+    center: &C::Point,
+    radius: f64,
+) -> SmallVec<[(f64, C::Point); 6]>
+where
+    C::Point: Coordinate2D,
+{
+    // Solve with the circle at the origin so the coefficients stay small
+    let offset = *center;
+    let w1 = curve.start_point() - offset;
+    let (w2, w3) = curve.control_points();
+    let (w2, w3) = (w2 - offset, w3 - offset);
+    let w4 = curve.end_point() - offset;
+
+    let circle = Conic::circle(&C::Point::origin(), radius);
+
+    conic_roots(&w1, &w2, &w3, &w4, &circle)
+        .into_iter()
+        .map(|t| (t, de_casteljau4(t, w1, w2, w3, w4) + offset))
+        .collect()
+}
@@ -0,0 +1,215 @@
+use super::curve_curve_clip::curve_intersects_curve_clip;
+use crate::bezier::{nearest_point_on_curve, overlapping_region, BezierCurve, NormalCurve};
+use crate::consts::SMALL_T_DISTANCE;
+use crate::geo::{Coordinate, Coordinate2D};
+
+use smallvec::SmallVec;
+
+/// Intersections where the curves meet at less than this angle (in radians) are considered tangential when there's no
+/// other way to tell if they cross
+const MIN_CROSSING_ANGLE: f64 = 0.01;
+
+/// Largest change in the t value used when checking which side of one curve the other curve is on either side of an
+/// intersection
+const MAX_SIDE_T_DISTANCE: f64 = 0.05;
+
+///
+/// Describes a place where two curves meet
+///
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum CurveIntersection {
+    /// The curves cross at a single point, given as the `t` values for the first and second curves
+    Point(f64, f64),
+
+    /// The curves overlap across a range: these are the start and end `t` values on the first curve followed by the
+    /// corresponding `t` values on the second curve
+    Overlap((f64, f64), (f64, f64)),
+}
+
+impl CurveIntersection {
+    ///
+    /// Returns the `t` value at which this intersection starts on the first curve
+    ///
+    #[inline]
+    pub fn t1(&self) -> f64 {
+        match self {
+            CurveIntersection::Point(t1, _) => *t1,
+            CurveIntersection::Overlap((t1a, t1b), _) => t1a.min(*t1b),
+        }
+    }
+}
+
+///
+/// Determines where two curves meet, reporting both the points where they cross and the regions where they overlap
+///
+/// `curve_intersects_curve_clip()` reports the end points of any overlapping region as intersections and
+/// `overlapping_region()` only finds the overlap: this combines the two, so point intersections that are just the end
+/// points of an overlapping region are not reported twice. The results are ordered by their `t` value on the first curve.
+///
+pub fn curve_intersects_curve_with_overlaps<'a, C: BezierCurve>(
+    curve1: &'a C,
+    curve2: &'a C,
+    accuracy: f64,
+) -> SmallVec<[CurveIntersection; 8]>
+where
+    C::Point: 'a + Coordinate2D,
+{
+    let mut result: SmallVec<[CurveIntersection; 8]> = SmallVec::new();
+
+    // Find the overlapping region first, if there is one
+    let overlap = overlapping_region(curve1, curve2);
+    if let Some((c1_ts, c2_ts)) = overlap {
+        result.push(CurveIntersection::Overlap(c1_ts, c2_ts));
+    }
+
+    // Add in the point intersections, skipping any that are part of the overlapping region
+    for (t1, t2) in curve_intersects_curve_clip(curve1, curve2, accuracy) {
+        if let Some(((c1_ta, c1_tb), (c2_ta, c2_tb))) = overlap {
+            // Points inside the overlap (or close to either end of it) are already covered
+            let in_range = |t: f64, ta: f64, tb: f64| {
+                t >= ta.min(tb) - SMALL_T_DISTANCE && t <= ta.max(tb) + SMALL_T_DISTANCE
+            };
+
+            if in_range(t1, c1_ta, c1_tb) && in_range(t2, c2_ta, c2_tb) {
+                continue;
+            }
+
+            // The clipping algorithm only works to within 'accuracy', so also check the positions of the end points
+            let pos = curve1.point_at_pos(t1);
+            if pos.is_near_to(&curve1.point_at_pos(c1_ta), accuracy)
+                || pos.is_near_to(&curve1.point_at_pos(c1_tb), accuracy)
+            {
+                continue;
+            }
+        }
+
+        result.push(CurveIntersection::Point(t1, t2));
+    }
+
+    // Order by position along the first curve
+    result.sort_by(|a, b| {
+        a.t1()
+            .partial_cmp(&b.t1())
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+
+    result
+}
+
+///
+/// Describes a point where two curves meet, as returned by `curve_intersects_curve_detailed()`
+///
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct CurveIntersectionPoint<Point> {
+    /// The t value of the intersection on the first curve
+    pub t1: f64,
+
+    /// The t value of the intersection on the second curve
+    pub t2: f64,
+
+    /// The position of the intersection (on the first curve)
+    pub position: Point,
+
+    /// The angle between the tangents of the two curves at the intersection, in radians from 0 (the curves are going in
+    /// the same or opposite directions) to PI/2 (the curves are perpendicular)
+    pub angle: f64,
+
+    /// True if the curves touch at this point without crossing over each other
+    pub is_tangent: bool,
+}
+
+///
+/// Returns the side of `from_curve` that a point on `curve` is on, or `None` if the point is (very nearly) on the curve
+///
+fn side_of_curve<C: BezierCurve>(from_curve: &C, curve: &C, t: f64, accuracy: f64) -> Option<f64>
+where
+    C::Point: Coordinate2D,
+{
+    let point = curve.point_at_pos(t);
+    let (nearest_t, nearest) = nearest_point_on_curve(from_curve, &point);
+    let tangent = from_curve.tangent_at_pos(nearest_t);
+    let offset = point - nearest;
+
+    if offset.magnitude() < accuracy * 0.01 {
+        None
+    } else {
+        Some(tangent.x() * offset.y() - tangent.y() * offset.x())
+    }
+}
+
+///
+/// Determines if `curve` crosses `from_curve` at `t` by checking which side of `from_curve` it is on either side of the
+/// intersection, returning `None` if the intersection is at the end of `curve`
+///
+fn crosses_at<C: BezierCurve>(from_curve: &C, curve: &C, t: f64, accuracy: f64) -> Option<bool>
+where
+    C::Point: Coordinate2D,
+{
+    // Move a short distance along the curve in each direction
+    let speed = curve.tangent_at_pos(t).magnitude();
+    let t_distance = if speed > 0.0 {
+        (accuracy * 10.0 / speed).min(MAX_SIDE_T_DISTANCE)
+    } else {
+        MAX_SIDE_T_DISTANCE
+    };
+
+    let (before, after) = (t - t_distance, t + t_distance);
+    if before < 0.0 || after > 1.0 {
+        return None;
+    }
+
+    // Touching curves end up on the same side, and curves that stay on top of each other are overlapping rather than
+    // crossing
+    match (
+        side_of_curve(from_curve, curve, before, accuracy),
+        side_of_curve(from_curve, curve, after, accuracy),
+    ) {
+        (Some(side_before), Some(side_after)) => Some(side_before.signum() != side_after.signum()),
+        _ => Some(false),
+    }
+}
+
+///
+/// Determines where two curves intersect, returning the position of each intersection along with the angle between the
+/// curves and whether or not they cross over each other
+///
+/// This finds the same intersections as `curve_intersects_curve_clip()`. An intersection is tangential if the second curve
+/// stays on the same side of the first curve either side of the intersection (or the first curve stays on the same side
+/// of the second, if the intersection is at the end of the second curve). Where the intersection is at the end of both
+/// curves, it's considered tangential if the curves meet at a very shallow angle.
+///
+pub fn curve_intersects_curve_detailed<'a, C: BezierCurve>(
+    curve1: &'a C,
+    curve2: &'a C,
+    accuracy: f64,
+) -> SmallVec<[CurveIntersectionPoint<C::Point>; 8]>
+where
+    C::Point: 'a + Coordinate2D,
+{
+    curve_intersects_curve_clip(curve1, curve2, accuracy)
+        .into_iter()
+        .map(|(t1, t2)| {
+            let tangent1 = curve1.tangent_at_pos(t1);
+            let tangent2 = curve2.tangent_at_pos(t2);
+            let lengths = tangent1.magnitude() * tangent2.magnitude();
+
+            let angle = if lengths > 0.0 {
+                (tangent1.dot(&tangent2).abs() / lengths).min(1.0).acos()
+            } else {
+                0.0
+            };
+
+            let crosses = crosses_at(curve1, curve2, t2, accuracy)
+                .or_else(|| crosses_at(curve2, curve1, t1, accuracy))
+                .unwrap_or(angle >= MIN_CROSSING_ANGLE);
+
+            CurveIntersectionPoint {
+                t1,
+                t2,
+                position: curve1.point_at_pos(t1),
+                angle,
+                is_tangent: !crosses,
+            }
+        })
+        .collect()
+}
@@ -2,7 +2,7 @@ use super::super::basis::{bezier_coefficients, de_casteljau4};
 use super::super::curve::BezierCurve;
 use crate::consts::SMALL_DISTANCE;
 use crate::geo::Coordinate2D;
-use crate::line::Line;
+use crate::line::{Line, Ray};
 
 use roots::{find_roots_cubic, find_roots_quadratic, Roots};
 use smallvec::{smallvec, SmallVec};
@@ -132,6 +132,22 @@ where
     result
 }
 
+///
+/// Find the t values where a curve intersects a ray
+///
+/// This is the same as `curve_intersects_ray()`, except the ray is described explicitly by a `Ray` rather than a `Line`.
+/// Return value is a vector of (curve_t, ray_t, intersection_point) values.
+///
+pub fn curve_ray_intersections<C: BezierCurve, R: Ray<Point = C::Point>>(
+    curve: &C,
+    ray: &R,
+) -> SmallVec<[(f64, f64, C::Point); 4]>
+where
+    C::Point: Coordinate2D,
+{
+    curve_intersects_ray(curve, &ray.to_line())
+}
+
 ///
 /// Find the t values where a curve intersects a line
 ///
@@ -1,8 +1,16 @@
+mod curve_bounds;
+mod curve_conic;
+mod curve_curve;
 mod curve_curve_clip;
 mod curve_line;
 mod fat_line;
 mod self_intersection;
+mod tangency;
 
+pub use self::curve_bounds::*;
+pub use self::curve_conic::*;
+pub use self::curve_curve::*;
 pub use self::curve_curve_clip::*;
 pub use self::curve_line::*;
 pub use self::self_intersection::*;
+pub use self::tangency::*;
@@ -0,0 +1,121 @@
+use crate::bezier::{nearest_point_on_curve, BezierCurve, NormalCurve};
+use crate::geo::{Coordinate, Coordinate2D};
+
+use smallvec::SmallVec;
+
+/// Number of points on the first curve to check when searching for places where the curves come close together
+const NUM_TANGENCY_SAMPLES: usize = 64;
+
+/// Number of iterations used to find the closest point between the curves
+const TANGENCY_SEARCH_ITERATIONS: usize = 48;
+
+/// Points this close in t to the end of a curve are considered to be at the end
+const END_T_DISTANCE: f64 = 1e-6;
+
+///
+/// A place where two curves touch or come close to touching without crossing, as returned by `curve_tangencies()`
+///
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct CurveTangency<Point> {
+    /// The t value of the closest point on the first curve
+    pub t1: f64,
+
+    /// The t value of the closest point on the second curve
+    pub t2: f64,
+
+    /// The position of the closest point on the first curve
+    pub position: Point,
+
+    /// The distance between the curves at this point (0 if they touch)
+    pub distance: f64,
+}
+
+///
+/// Finds the places where two curves are tangent to each other
+///
+/// These are the places where the curves touch without crossing over each other, or come within `tolerance` of each
+/// other while running alongside each other (so a small change to either curve would make them touch). Unlike the
+/// intersection functions, this doesn't report the places where the curves cross, and does report places where the
+/// curves almost touch but don't meet. Places where the curves come close together at their end points are not
+/// included, as the curves there can be at any angle to each other.
+///
+/// This searches for the places where the distance between the curves is at a minimum: the curves are parallel
+/// wherever this happens away from their end points. Regions where the curves overlap are not reported.
+///
+pub fn curve_tangencies<C: BezierCurve>(
+    curve1: &C,
+    curve2: &C,
+    tolerance: f64,
+) -> SmallVec<[CurveTangency<C::Point>; 4]>
+where
+    C::Point: Coordinate2D,
+{
+    // The signed distance from a point on the first curve to the second curve (positive on the left of the second curve)
+    let signed_distance = |t1: f64| {
+        let point = curve1.point_at_pos(t1);
+        let (t2, nearest) = nearest_point_on_curve(curve2, &point);
+        let tangent = curve2.tangent_at_pos(t2);
+        let offset = point - nearest;
+        let side = tangent.x() * offset.y() - tangent.y() * offset.x();
+
+        (t2, offset.magnitude(), side)
+    };
+
+    let samples = (0..=NUM_TANGENCY_SAMPLES)
+        .map(|idx| {
+            let t1 = (idx as f64) / (NUM_TANGENCY_SAMPLES as f64);
+            let (_, distance, side) = signed_distance(t1);
+
+            (t1, distance, side)
+        })
+        .collect::<Vec<_>>();
+
+    let mut tangencies = SmallVec::new();
+
+    for idx in 1..(samples.len() - 1) {
+        let (before, current, after) = (samples[idx - 1], samples[idx], samples[idx + 1]);
+
+        // Look for local minima in the distance, where the curves don't cross
+        if current.1 >= before.1 || current.1 > after.1 {
+            continue;
+        }
+
+        if before.2.signum() != after.2.signum() {
+            continue;
+        }
+
+        // Search for the closest point
+        let (mut low, mut high) = (before.0, after.0);
+        for _ in 0..TANGENCY_SEARCH_ITERATIONS {
+            let third = (high - low) / 3.0;
+            let (mid1, mid2) = (low + third, high - third);
+
+            if signed_distance(mid1).1 < signed_distance(mid2).1 {
+                high = mid2;
+            } else {
+                low = mid1;
+            }
+        }
+
+        let t1 = (low + high) * 0.5;
+        let (t2, distance, _) = signed_distance(t1);
+
+        if distance > tolerance {
+            continue;
+        }
+
+        let interior = END_T_DISTANCE..=(1.0 - END_T_DISTANCE);
+        if !interior.contains(&t1) || !interior.contains(&t2) {
+            continue;
+        }
+
+        tangencies.push(CurveTangency {
+            t1,
+            t2,
+            position: curve1.point_at_pos(t1),
+            distance,
+        });
+    }
+
+    tangencies
+}
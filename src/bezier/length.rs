@@ -1,7 +1,122 @@
-use super::curve::BezierCurve;
+use super::basis::de_casteljau3;
+use super::curve::{BezierCurve, BezierCurveFactory};
+use super::derivative::derivative4;
 use super::section::CurveSection;
 use crate::geo::Coordinate;
 
+/// Number of iterations to use when searching for the t value at a particular length along a curve
+const LENGTH_SEARCH_ITERATIONS: usize = 32;
+
+/// Lowest order supported by `curve_length_quadrature()`
+const MIN_QUADRATURE_ORDER: usize = 2;
+
+/// Highest order supported by `curve_length_quadrature()`
+const MAX_QUADRATURE_ORDER: usize = 16;
+
+/// The non-negative abscissae and their weights for Legendre-Gauss quadrature over `-1..1`, for each order starting at
+/// `MIN_QUADRATURE_ORDER` (the negative abscissae are the same with the sign flipped)
+const LEGENDRE_GAUSS: [&[(f64, f64)]; MAX_QUADRATURE_ORDER - MIN_QUADRATURE_ORDER + 1] = [
+    &[(0.577350269189626, 1.0)],
+    &[
+        (0.0, 0.888888888888889),
+        (0.774596669241483, 0.555555555555555),
+    ],
+    &[
+        (0.339981043584856, 0.652145154862546),
+        (0.861136311594053, 0.347854845137454),
+    ],
+    &[
+        (0.0, 0.568888888888889),
+        (0.538469310105683, 0.478628670499366),
+        (0.906179845938664, 0.236926885056189),
+    ],
+    &[
+        (0.238619186083197, 0.467913934572691),
+        (0.661209386466265, 0.360761573048139),
+        (0.932469514203152, 0.17132449237917),
+    ],
+    &[
+        (0.0, 0.417959183673469),
+        (0.405845151377397, 0.381830050505119),
+        (0.741531185599394, 0.279705391489277),
+        (0.949107912342758, 0.12948496616887),
+    ],
+    &[
+        (0.18343464249565, 0.362683783378362),
+        (0.525532409916329, 0.313706645877887),
+        (0.796666477413627, 0.222381034453374),
+        (0.960289856497536, 0.101228536290376),
+    ],
+    &[
+        (0.0, 0.33023935500126),
+        (0.324253423403809, 0.312347077040003),
+        (0.61337143270059, 0.260610696402935),
+        (0.836031107326636, 0.180648160694857),
+        (0.968160239507626, 0.081274388361575),
+    ],
+    &[
+        (0.148874338981631, 0.295524224714753),
+        (0.433395394129247, 0.269266719309996),
+        (0.679409568299024, 0.219086362515982),
+        (0.865063366688985, 0.149451349150581),
+        (0.973906528517172, 0.066671344308688),
+    ],
+    &[
+        (0.0, 0.272925086777901),
+        (0.269543155952345, 0.262804544510247),
+        (0.519096129206812, 0.23319376459199),
+        (0.730152005574049, 0.186290210927734),
+        (0.887062599768095, 0.125580369464905),
+        (0.978228658146057, 0.055668567116174),
+    ],
+    &[
+        (0.125233408511469, 0.249147045813403),
+        (0.36783149899818, 0.233492536538355),
+        (0.587317954286617, 0.203167426723066),
+        (0.769902674194305, 0.160078328543346),
+        (0.904117256370475, 0.106939325995319),
+        (0.981560634246719, 0.047175336386512),
+    ],
+    &[
+        (0.0, 0.232551553230874),
+        (0.230458315955135, 0.226283180262897),
+        (0.448492751036447, 0.207816047536889),
+        (0.64234933944034, 0.178145980761946),
+        (0.80157809073331, 0.138873510219787),
+        (0.917598399222978, 0.092121499837728),
+        (0.984183054718588, 0.040484004765316),
+    ],
+    &[
+        (0.108054948707344, 0.215263853463158),
+        (0.31911236892789, 0.205198463721296),
+        (0.515248636358154, 0.185538397477938),
+        (0.687292904811685, 0.157203167158194),
+        (0.827201315069765, 0.121518570687903),
+        (0.928434883663574, 0.08015808715976),
+        (0.986283808696812, 0.035119460331752),
+    ],
+    &[
+        (0.0, 0.202578241925561),
+        (0.201194093997435, 0.198431485327112),
+        (0.394151347077563, 0.186161000015562),
+        (0.570972172608539, 0.166269205816994),
+        (0.72441773136017, 0.139570677926154),
+        (0.848206583410427, 0.107159220467172),
+        (0.937273392400706, 0.070366047488108),
+        (0.987992518020485, 0.030753241996117),
+    ],
+    &[
+        (0.095012509837637, 0.189450610455068),
+        (0.281603550779259, 0.182603415044924),
+        (0.458016777657227, 0.169156519395003),
+        (0.617876244402644, 0.149595988816577),
+        (0.755404408355003, 0.124628971255534),
+        (0.865631202387832, 0.095158511682493),
+        (0.944575023073233, 0.062253523938648),
+        (0.98940093499165, 0.027152459411754),
+    ],
+];
+
 ///
 /// Returns the length of the control polygon for a bezier curve
 ///
@@ -30,6 +145,102 @@ pub fn curve_length<Curve: BezierCurve>(curve: &Curve, max_error: f64) -> f64 {
     section_length(curve.section(0.0, 1.0), max_error)
 }
 
+///
+/// Returns the magnitude of the first derivative of a curve at a particular t value (the speed at which a point moves
+/// along the curve as t changes)
+///
+pub fn derivative_magnitude_at<Curve: BezierCurve>(curve: &Curve, t: f64) -> f64 {
+    let w1 = curve.start_point();
+    let (w2, w3) = curve.control_points();
+    let w4 = curve.end_point();
+
+    let (d1, d2, d3) = derivative4(w1, w2, w3, w4);
+    de_casteljau3(t, d1, d2, d3).magnitude()
+}
+
+///
+/// Estimates the length of a bezier curve using Legendre-Gauss quadrature
+///
+/// This integrates the magnitude of the derivative of the curve at `order` fixed t values, so it takes a fixed amount of
+/// time and does not allocate (unlike `curve_length()`, which subdivides the curve until an error bound is met). Orders
+/// from 2 to 16 are supported, and other values are clamped to this range. Smooth curves are measured very accurately
+/// with orders around 8, but higher orders are needed for curves with sharp turns or cusps, where the speed changes
+/// rapidly: these can be measured more accurately by splitting them into sections and measuring each one.
+///
+pub fn curve_length_quadrature<Curve: BezierCurve>(curve: &Curve, order: usize) -> f64 {
+    let order = order.clamp(MIN_QUADRATURE_ORDER, MAX_QUADRATURE_ORDER);
+
+    // Quadrature is performed over -1..1, so map the abscissae to t values around 0.5
+    let mut length = 0.0;
+    for (x, weight) in LEGENDRE_GAUSS[order - MIN_QUADRATURE_ORDER].iter() {
+        if *x == 0.0 {
+            length += weight * derivative_magnitude_at(curve, 0.5);
+        } else {
+            length += weight
+                * (derivative_magnitude_at(curve, 0.5 + x * 0.5)
+                    + derivative_magnitude_at(curve, 0.5 - x * 0.5));
+        }
+    }
+
+    length * 0.5
+}
+
+///
+/// Finds the t value at a particular distance along a curve
+///
+pub(crate) fn t_for_length<Curve: BezierCurve>(
+    curve: &Curve,
+    length: f64,
+    total_length: f64,
+    max_error: f64,
+) -> f64 {
+    if length <= 0.0 {
+        return 0.0;
+    } else if length >= total_length {
+        return 1.0;
+    }
+
+    let (mut low, mut high) = (0.0, 1.0);
+
+    for _ in 0..LENGTH_SEARCH_ITERATIONS {
+        let mid = (low + high) * 0.5;
+
+        if curve_length(&curve.section(0.0, mid), max_error) < length {
+            low = mid;
+        } else {
+            high = mid;
+        }
+    }
+
+    (low + high) * 0.5
+}
+
+///
+/// Returns the part of a curve between two distances along it
+///
+/// Unlike `section()`, which takes t values, the positions are measured along the length of the curve (to within
+/// `max_error`), so the result doesn't depend on how the curve is parameterised. Distances are clamped to the length of
+/// the curve, and are swapped if `to_length` is before `from_length`.
+///
+pub fn trim_curve<C: BezierCurveFactory>(
+    curve: &C,
+    from_length: f64,
+    to_length: f64,
+    max_error: f64,
+) -> C {
+    let (from_length, to_length) = if to_length < from_length {
+        (to_length, from_length)
+    } else {
+        (from_length, to_length)
+    };
+
+    let total_length = curve_length(curve, max_error);
+    let t_min = t_for_length(curve, from_length, total_length, max_error);
+    let t_max = t_for_length(curve, to_length, total_length, max_error);
+
+    C::from_curve(&curve.section(t_min, t_max))
+}
+
 ///
 /// Computes the length of a section of a bezier curve
 ///
@@ -19,47 +19,70 @@
 mod basis;
 mod bounds;
 mod characteristics;
+mod clip;
 mod curve;
 mod deform;
 mod derivative;
 mod distort;
+mod easing;
 mod fit;
 mod intersection;
 mod length;
+mod nearest;
 mod normal;
 mod offset;
+mod offset_adaptive;
+mod offset_error;
 mod offset_lms;
 mod offset_scaling;
+mod oriented_bounds;
 mod overlaps;
+mod perspective;
+mod polynomial;
+mod quadratic;
+mod reduce_degree;
 mod search;
 mod section;
 mod solve;
+mod speed;
 mod subdivide;
 mod tangent;
 mod walk;
+mod walk_offset;
 
 pub mod path;
 
 pub use self::basis::*;
 pub use self::bounds::*;
 pub use self::characteristics::*;
+pub use self::clip::*;
 pub use self::curve::*;
 pub use self::deform::*;
 pub use self::derivative::*;
 pub use self::distort::*;
+pub use self::easing::*;
 pub use self::fit::*;
 pub use self::intersection::*;
 pub use self::length::*;
+pub use self::nearest::*;
 pub use self::normal::*;
 pub use self::offset::*;
+pub use self::offset_adaptive::*;
+pub use self::offset_error::*;
 pub use self::offset_lms::*;
 pub use self::offset_scaling::*;
+pub use self::oriented_bounds::*;
 pub use self::overlaps::*;
+pub use self::perspective::*;
+pub use self::quadratic::*;
+pub use self::reduce_degree::*;
 pub use self::search::*;
 pub use self::section::*;
 pub use self::solve::*;
+pub use self::speed::*;
 pub use self::subdivide::*;
 pub use self::tangent::*;
 pub use self::walk::*;
+pub use self::walk_offset::*;
 
 pub use super::geo::*;
@@ -0,0 +1,68 @@
+use super::basis::{bezier_coefficients, de_casteljau4};
+use super::curve::BezierCurve;
+use super::polynomial::{poly_mul, poly_roots_in_range};
+use crate::geo::{Coordinate, Coordinate2D};
+
+///
+/// Finds the point on a curve that's closest to a target point
+///
+/// Return value is the `t` value of the closest point and the point itself. The closest point is either one of the ends of
+/// the curve or a point where the line to the target is perpendicular to the curve, so this finds all of the points where
+/// that's the case and picks the nearest.
+///
+pub fn nearest_point_on_curve<C: BezierCurve>(curve: &C, point: &C::Point) -> (f64, C::Point)
+where
+    C::Point: Coordinate2D,
+{
+    let w1 = curve.start_point();
+    let (w2, w3) = curve.control_points();
+    let w4 = curve.end_point();
+
+    // Polynomials for the offset from the target point and the derivative of the curve, from the constant term upwards
+    let (ax, bx, cx, dx) = bezier_coefficients(0, &w1, &w2, &w3, &w4);
+    let (ay, by, cy, dy) = bezier_coefficients(1, &w1, &w2, &w3, &w4);
+    let offset_x = [dx - point.x(), cx, bx, ax];
+    let offset_y = [dy - point.y(), cy, by, ay];
+    let tangent_x = [cx, 2.0 * bx, 3.0 * ax];
+    let tangent_y = [cy, 2.0 * by, 3.0 * ay];
+
+    // The distance is at a minimum or maximum where the offset is perpendicular to the tangent
+    let dot_x = poly_mul(&offset_x, &tangent_x);
+    let dot_y = poly_mul(&offset_y, &tangent_y);
+    let dot = dot_x
+        .iter()
+        .zip(dot_y.iter())
+        .map(|(x, y)| x + y)
+        .collect::<Vec<_>>();
+
+    let candidates = poly_roots_in_range(&dot, 0.0, 1.0, 0.0);
+
+    candidates
+        .into_iter()
+        .chain([0.0, 1.0])
+        .map(|t| {
+            let pos = de_casteljau4(t, w1, w2, w3, w4);
+            (t, pos, pos.distance_to(point))
+        })
+        .fold(
+            None,
+            |nearest: Option<(f64, C::Point, f64)>, candidate| match nearest {
+                Some(nearest) if nearest.2 <= candidate.2 => Some(nearest),
+                _ => Some(candidate),
+            },
+        )
+        .map(|(t, pos, _)| (t, pos))
+        .unwrap_or((0.0, w1))
+}
+
+///
+/// Returns the distance from a point to the nearest point on a curve
+///
+pub fn distance_to_curve<C: BezierCurve>(curve: &C, point: &C::Point) -> f64
+where
+    C::Point: Coordinate2D,
+{
+    let (_, nearest) = nearest_point_on_curve(curve, point);
+
+    nearest.distance_to(point)
+}
@@ -3,6 +3,8 @@ use super::basis::{de_casteljau3, de_casteljau4};
 use super::curve::BezierCurve;
 use super::derivative::derivative4;
 
+use std::iter;
+
 // TODO: normalize should be a trait associated with coordinate rather than bezier curves (move outwards)
 
 ///
@@ -77,6 +79,37 @@ pub trait NormalCurve: BezierCurve {
     fn normal_at_pos(&self, t: f64) -> Self::Point;
 }
 
+///
+/// Returns the unit tangent of a curve at a t value, or `None` if the curve is a single point
+///
+/// Where the curve has no speed (for example, at an end point that has both control points on top of it), this uses the
+/// direction from the start to the nearest distinct control point or end point instead (or into the end point, for the
+/// second half of the curve), so the result always points along the curve.
+///
+pub(crate) fn unit_tangent_at_pos<Curve>(curve: &Curve, t: f64) -> Option<Curve::Point>
+where
+    Curve: BezierCurve,
+    Curve::Point: Normalize,
+{
+    let start = curve.start_point();
+    let (cp1, cp2) = curve.control_points();
+    let end = curve.end_point();
+
+    let fallbacks = if t <= 0.5 {
+        [cp1 - start, cp2 - start, end - start]
+    } else {
+        [end - cp2, end - cp1, end - start]
+    };
+
+    iter::once(curve.tangent_at_pos(t))
+        .chain(fallbacks)
+        .find(|tangent| {
+            let length = tangent.magnitude();
+            length > 0.0 && length.is_finite()
+        })
+        .map(|tangent| tangent.to_unit_vector())
+}
+
 impl<Curve: BezierCurve> NormalCurve for Curve
 where
     Curve::Point: Normalize,
@@ -0,0 +1,283 @@
+use super::basis::{de_casteljau2, de_casteljau3};
+use super::curve::{BezierCurve, BezierCurveFactory};
+use super::derivative::{derivative3, derivative4};
+use super::intersection::curve_intersects_curve_clip;
+use super::normal::{NormalCurve, Normalize};
+use super::offset_error::offset_max_error;
+use super::offset_scaling::offset_scaling;
+use crate::consts::SMALL_DISTANCE;
+use crate::geo::{Coordinate, Coordinate2D};
+use crate::line::line_to_bezier;
+
+// The offset of a curve moves each point a distance `d` along the normal. Where the curve has curvature `k`, the offset
+// curve moves at `1 - d*k` times the speed of the original curve (with the normal pointing to the left of the tangent),
+// so wherever `d*k` is greater than 1 the offset curve runs backwards. This forms a 'swallowtail' with a cusp at either
+// end and a loop where the backwards section crosses the parts either side of it.
+//
+// This algorithm finds the places where the offset curve changes direction by sampling `1 - d*k`, and offsets the parts
+// of the curve where it's positive. The parts either side of each backwards section are then trimmed back to the point
+// where they cross, which removes the loop. Each forward section is also split where the curvature is high relative
+// to the offset, and is subdivided further until the offset is within the requested error.
+
+/// Number of samples used to find the places where the offset curve changes direction
+const NUM_DIRECTION_SAMPLES: usize = 64;
+
+/// Number of iterations of the binary search for the exact place where the offset curve changes direction
+const DIRECTION_SEARCH_ITERATIONS: usize = 32;
+
+/// Forward sections are split at peaks in the curvature where `d*k` is higher than this
+const HIGH_CURVATURE: f64 = 0.5;
+
+/// Number of samples used to measure the error of the offset for each section
+const NUM_ERROR_SAMPLES: usize = 16;
+
+/// Maximum number of times a section is divided in two to reduce the error in the offset
+const MAX_SUBDIVISIONS: usize = 6;
+
+///
+/// Returns the signed curvature of a curve at a particular t value (positive when the curve is turning anticlockwise)
+///
+fn signed_curvature<Curve: BezierCurve>(curve: &Curve, t: f64) -> f64
+where
+    Curve::Point: Coordinate2D,
+{
+    let w1 = curve.start_point();
+    let (w2, w3) = curve.control_points();
+    let w4 = curve.end_point();
+
+    let (d1, d2, d3) = derivative4(w1, w2, w3, w4);
+    let (dd1, dd2) = derivative3(d1, d2, d3);
+    let first = de_casteljau3(t, d1, d2, d3);
+    let second = de_casteljau2(t, dd1, dd2);
+
+    let speed = first.magnitude();
+    if speed <= 0.0 {
+        return 0.0;
+    }
+
+    (first.x() * second.y() - first.y() * second.x()) / (speed * speed * speed)
+}
+
+///
+/// Offsets a section of a curve, subdividing it until the result is within `max_error` of the ideal offset
+///
+fn subdivide_offset_to_error<Curve>(
+    curve: &Curve,
+    (t1, t2): (f64, f64),
+    offset_for_t: &impl Fn(f64) -> f64,
+    max_error: f64,
+    depth: usize,
+) -> Vec<Curve>
+where
+    Curve: BezierCurveFactory + NormalCurve,
+    Curve::Point: Normalize + Coordinate2D,
+{
+    let section = Curve::from_curve(&curve.section(t1, t2));
+    let (offset1, offset2) = (offset_for_t(t1), offset_for_t(t2));
+    let offset = offset_scaling(&section, offset1, offset2);
+
+    if depth >= MAX_SUBDIVISIONS {
+        return offset;
+    }
+
+    let error = offset_max_error(
+        &section,
+        &offset,
+        |t| offset1 + (offset2 - offset1) * t,
+        |_| 0.0,
+        NUM_ERROR_SAMPLES,
+    );
+
+    if error <= max_error {
+        offset
+    } else {
+        let mid = (t1 + t2) * 0.5;
+        let mut left =
+            subdivide_offset_to_error(curve, (t1, mid), offset_for_t, max_error, depth + 1);
+        let right = subdivide_offset_to_error(curve, (mid, t2), offset_for_t, max_error, depth + 1);
+
+        left.extend(right);
+        left
+    }
+}
+
+///
+/// Finds where two offset curves cross, returning the index and t value of the crossing point in each set of curves
+///
+/// Where there's more than one crossing, this picks the one that removes the least from the offset.
+///
+fn find_crossing<Curve>(before: &[Curve], after: &[Curve]) -> Option<((usize, f64), (usize, f64))>
+where
+    Curve: BezierCurve,
+    Curve::Point: Coordinate2D,
+{
+    let mut best: Option<((usize, f64), (usize, f64))> = None;
+
+    for (before_idx, before_curve) in before.iter().enumerate() {
+        for (after_idx, after_curve) in after.iter().enumerate() {
+            for (before_t, after_t) in
+                curve_intersects_curve_clip(before_curve, after_curve, SMALL_DISTANCE)
+            {
+                let kept = (before_idx as f64 + before_t) - (after_idx as f64 + after_t);
+                let is_better = best
+                    .map(
+                        |((best_before_idx, best_before_t), (best_after_idx, best_after_t))| {
+                            kept > (best_before_idx as f64 + best_before_t)
+                                - (best_after_idx as f64 + best_after_t)
+                        },
+                    )
+                    .unwrap_or(true);
+
+                if is_better {
+                    best = Some(((before_idx, before_t), (after_idx, after_t)));
+                }
+            }
+        }
+    }
+
+    best
+}
+
+///
+/// Adds a straight line between two points to a list of curves, if they're not already in the same place
+///
+fn join_with_line<Curve>(curves: &mut Vec<Curve>, from: Curve::Point, to: Curve::Point)
+where
+    Curve: BezierCurveFactory,
+{
+    if !from.is_near_to(&to, SMALL_DISTANCE) {
+        curves.push(line_to_bezier(&(from, to)));
+    }
+}
+
+///
+/// Computes a series of curves that approximate an offset curve, removing the loops that form where the offset is larger
+/// than the radius of curvature
+///
+/// Unlike `offset()`, this finds the places where the offset curve would turn back on itself (where the offset is on the
+/// inside of a bend that's tighter than the offset distance) and splits the curve there. The backwards sections are
+/// removed and the sections either side are trimmed back to the point where they cross, so the result follows the
+/// outline of the area covered by the offset and doesn't self-intersect around tight bends. Sections of the curve are
+/// also split where the curvature is high, and are subdivided until the offset is within `max_error` of the ideal
+/// offset (up to a limit on the number of subdivisions).
+///
+/// The result always starts and ends at the offset of the start and end points of the curve. Where the offset curve is
+/// running backwards at either end, or the sections either side of a backwards section don't cross, these points are
+/// joined with straight lines.
+///
+pub fn offset_adaptive<Curve>(
+    curve: &Curve,
+    initial_offset: f64,
+    final_offset: f64,
+    max_error: f64,
+) -> Vec<Curve>
+where
+    Curve: BezierCurveFactory + NormalCurve,
+    Curve::Point: Normalize + Coordinate2D,
+{
+    let offset_for_t = |t: f64| initial_offset + (final_offset - initial_offset) * t;
+    let relative_curvature = |t: f64| offset_for_t(t) * signed_curvature(curve, t);
+
+    // Sample the curvature to find where the offset changes direction and where the curvature peaks
+    let samples = (0..=NUM_DIRECTION_SAMPLES)
+        .map(|idx| {
+            let t = (idx as f64) / (NUM_DIRECTION_SAMPLES as f64);
+            (t, relative_curvature(t))
+        })
+        .collect::<Vec<_>>();
+
+    let mut split_points = vec![0.0];
+    for idx in 1..samples.len() {
+        let (t1, curvature1) = samples[idx - 1];
+        let (t2, curvature2) = samples[idx];
+
+        if (curvature1 < 1.0) != (curvature2 < 1.0) {
+            // Search for where 1 - d*k is 0
+            let (mut low, mut high) = (t1, t2);
+            for _ in 0..DIRECTION_SEARCH_ITERATIONS {
+                let mid = (low + high) * 0.5;
+                if (relative_curvature(mid) < 1.0) == (curvature1 < 1.0) {
+                    low = mid;
+                } else {
+                    high = mid;
+                }
+            }
+
+            split_points.push((low + high) * 0.5);
+        } else if idx + 1 < samples.len() {
+            // Split at peaks in the curvature
+            let next_curvature = samples[idx + 1].1;
+            let magnitude = curvature2.abs();
+
+            if magnitude > HIGH_CURVATURE
+                && magnitude > curvature1.abs()
+                && magnitude >= next_curvature.abs()
+                && curvature2 < 1.0
+                && next_curvature < 1.0
+            {
+                split_points.push(t2);
+            }
+        }
+    }
+    split_points.push(1.0);
+
+    // Offset the forward sections
+    let pieces = split_points
+        .windows(2)
+        .filter(|range| range[1] > range[0])
+        .filter(|range| relative_curvature((range[0] + range[1]) * 0.5) < 1.0)
+        .map(|range| {
+            subdivide_offset_to_error(curve, (range[0], range[1]), &offset_for_t, max_error, 0)
+        })
+        .filter(|piece| !piece.is_empty())
+        .collect::<Vec<_>>();
+
+    let start_point =
+        curve.point_at_pos(0.0) + curve.normal_at_pos(0.0).to_unit_vector() * offset_for_t(0.0);
+    let end_point =
+        curve.point_at_pos(1.0) + curve.normal_at_pos(1.0).to_unit_vector() * offset_for_t(1.0);
+
+    // Join the pieces together, trimming them where they cross
+    let mut result: Vec<Curve> = vec![];
+    let mut pieces = pieces.into_iter();
+    let mut current = match pieces.next() {
+        Some(piece) => piece,
+        None => {
+            join_with_line(&mut result, start_point, end_point);
+            return result;
+        }
+    };
+
+    join_with_line(&mut result, start_point, current[0].start_point());
+
+    for mut next in pieces {
+        let current_end = current[current.len() - 1].end_point();
+        let next_start = next[0].start_point();
+
+        if current_end.is_near_to(&next_start, SMALL_DISTANCE) {
+            // Sections split at a curvature peak follow on from each other
+            result.extend(current);
+        } else if let Some(((current_idx, current_t), (next_idx, next_t))) =
+            find_crossing(&current, &next)
+        {
+            // Trim away the loop
+            current.truncate(current_idx + 1);
+            current[current_idx] = Curve::from_curve(&current[current_idx].section(0.0, current_t));
+            next.drain(0..next_idx);
+            next[0] = Curve::from_curve(&next[0].section(next_t, 1.0));
+
+            result.extend(current);
+        } else {
+            result.extend(current);
+            join_with_line(&mut result, current_end, next_start);
+        }
+
+        current = next;
+    }
+
+    let current_end = current[current.len() - 1].end_point();
+    result.extend(current);
+    join_with_line(&mut result, current_end, end_point);
+
+    result
+}
@@ -0,0 +1,91 @@
+use super::curve::BezierCurve;
+use super::nearest::distance_to_curve;
+use super::normal::{NormalCurve, Normalize};
+use super::offset_lms::offset_point_at_pos;
+use crate::geo::{Coordinate, Coordinate2D};
+
+///
+/// Returns the distance from a point to the nearest point on a line segment
+///
+pub(crate) fn distance_to_segment<Point: Coordinate>(
+    point: &Point,
+    (start, end): (&Point, &Point),
+) -> f64 {
+    let direction = *end - *start;
+    let length_sq = direction.dot(&direction);
+
+    if length_sq <= 0.0 {
+        return point.distance_to(start);
+    }
+
+    let t = ((*point - *start).dot(&direction) / length_sq).clamp(0.0, 1.0);
+    point.distance_to(&(*start + direction * t))
+}
+
+///
+/// Measures how far an offset curve is from the ideal offset of the curve it was generated from
+///
+/// The ideal offset is moved `normal_offset_for_t(t)` along the normal and `tangent_offset_for_t(t)` along the tangent of
+/// the source curve at each point (these are the same functions that are passed to `offset_lms_sampling()`). The return
+/// value is the largest distance between the offset curves and the ideal offset, measured in both directions so that it
+/// includes any parts of the ideal offset that the result misses as well as any parts of the result (such as spikes or
+/// loops) that do not follow the ideal offset. Both are sampled at `num_samples` points, so the result is an estimate that
+/// becomes more accurate as the number of samples is increased.
+///
+pub fn offset_max_error<Curve, NormalOffsetFn, TangentOffsetFn>(
+    curve: &Curve,
+    offset_curves: &[Curve],
+    normal_offset_for_t: NormalOffsetFn,
+    tangent_offset_for_t: TangentOffsetFn,
+    num_samples: usize,
+) -> f64
+where
+    Curve: BezierCurve + NormalCurve,
+    Curve::Point: Normalize + Coordinate2D,
+    NormalOffsetFn: Fn(f64) -> f64,
+    TangentOffsetFn: Fn(f64) -> f64,
+{
+    if offset_curves.is_empty() {
+        return f64::MAX;
+    }
+
+    let num_samples = num_samples.max(2);
+
+    // Sample the ideal offset
+    let ideal_points = (0..=num_samples)
+        .map(|idx| {
+            let t = (idx as f64) / (num_samples as f64);
+            offset_point_at_pos(curve, t, normal_offset_for_t(t), tangent_offset_for_t(t))
+        })
+        .collect::<Vec<_>>();
+
+    // Distance from the ideal offset to the offset curves
+    let ideal_error = ideal_points
+        .iter()
+        .map(|ideal_point| {
+            offset_curves
+                .iter()
+                .map(|offset_curve| distance_to_curve(offset_curve, ideal_point))
+                .fold(f64::MAX, f64::min)
+        })
+        .fold(0.0, f64::max);
+
+    // Distance from the offset curves to the ideal offset (approximated by the lines between the samples)
+    let samples_per_curve = (num_samples / offset_curves.len()).max(2);
+    let offset_error = offset_curves
+        .iter()
+        .flat_map(|offset_curve| {
+            (0..=samples_per_curve).map(move |idx| {
+                offset_curve.point_at_pos((idx as f64) / (samples_per_curve as f64))
+            })
+        })
+        .map(|offset_point| {
+            ideal_points
+                .windows(2)
+                .map(|segment| distance_to_segment(&offset_point, (&segment[0], &segment[1])))
+                .fold(f64::MAX, f64::min)
+        })
+        .fold(0.0, f64::max);
+
+    ideal_error.max(offset_error)
+}
@@ -7,12 +7,35 @@ use crate::geo::{Coordinate, Coordinate2D};
 use smallvec::{smallvec, SmallVec};
 use std::iter;
 
+///
+/// Returns the point at a particular t value on the ideal offset of a curve, given the offsets along its normal and
+/// tangent at that point
+///
+pub(crate) fn offset_point_at_pos<Curve>(
+    curve: &Curve,
+    t: f64,
+    normal_offset: f64,
+    tangent_offset: f64,
+) -> Curve::Point
+where
+    Curve: NormalCurve,
+    Curve::Point: Normalize + Coordinate2D,
+{
+    let original_point = curve.point_at_pos(t);
+    let unit_tangent = curve.tangent_at_pos(t).to_unit_vector();
+    let unit_normal = Curve::Point::to_normal(&original_point, &unit_tangent);
+    let unit_normal = Curve::Point::from_components(&unit_normal);
+
+    original_point + (unit_normal * normal_offset) + (unit_tangent * tangent_offset)
+}
+
 ///
 /// Produces an offset curve by performing a least-mean-square curve fit against the output of a function
 ///
 /// This is about 5x slower than the scaling algorithm with 10 subdivisions (which is a number that seems to
 /// produce good results). Too few subdivisions can result in flat sections in the curve, and too many can
-/// result in artifacts caused by overfitting.
+/// result in artifacts caused by overfitting. `offset_max_error()` can be used to check how closely the result follows
+/// the ideal offset, so the number of subdivisions can be increased only for the curves that need it.
 ///
 pub fn offset_lms_sampling<Curve, NormalOffsetFn, TangentOffsetFn>(
     curve: &Curve,
@@ -107,16 +130,7 @@ where
 
     // Take a sample at each point
     let sample_points = sections
-        .map(|t| {
-            let original_point = curve.point_at_pos(t);
-            let unit_tangent = curve.tangent_at_pos(t).to_unit_vector();
-            let unit_normal = Curve::Point::to_normal(&original_point, &unit_tangent);
-            let unit_normal = Curve::Point::from_components(&unit_normal);
-            let normal_offset = normal_offset_for_t(t);
-            let tangent_offset = tangent_offset_for_t(t);
-
-            original_point + (unit_normal * normal_offset) + (unit_tangent * tangent_offset)
-        })
+        .map(|t| offset_point_at_pos(curve, t, normal_offset_for_t(t), tangent_offset_for_t(t)))
         .collect::<Vec<_>>();
 
     // Generate a curve using the sample points
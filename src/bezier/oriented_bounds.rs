@@ -0,0 +1,300 @@
+use super::super::geo::{BoundingBox, Bounds, Coordinate, Coordinate2D};
+use super::bounds::{bounding_box4, find_extremities};
+use super::curve::BezierCurve;
+
+use std::cmp::Ordering;
+
+/// Number of points to sample from each curve when finding the hull used to choose the orientation of the bounding box
+const HULL_SAMPLES_PER_CURVE: usize = 16;
+
+///
+/// A bounding rectangle that can be rotated to any angle
+///
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct OrientedBounds<Point: Coordinate + Coordinate2D> {
+    /// The center of the rectangle
+    center: Point,
+
+    /// Unit vector in the direction of the 'width' axis of the rectangle
+    axis: Point,
+
+    /// The size of the rectangle along its axis
+    width: f64,
+
+    /// The size of the rectangle perpendicular to its axis
+    height: f64,
+}
+
+impl<Point: Coordinate + Coordinate2D> OrientedBounds<Point> {
+    ///
+    /// Creates a new oriented bounding box from its center, the direction of its width axis and its size
+    ///
+    pub fn new(center: Point, axis: Point, width: f64, height: f64) -> Self {
+        let axis_len = axis.magnitude();
+        let axis = if axis_len > 0.0 {
+            axis * (1.0 / axis_len)
+        } else {
+            Point::from_components(&[1.0, 0.0])
+        };
+
+        OrientedBounds {
+            center,
+            axis,
+            width,
+            height,
+        }
+    }
+
+    ///
+    /// The center point of this rectangle
+    ///
+    #[inline]
+    pub fn center(&self) -> Point {
+        self.center
+    }
+
+    ///
+    /// A unit vector in the direction that the width of this rectangle is measured along
+    ///
+    #[inline]
+    pub fn axis(&self) -> Point {
+        self.axis
+    }
+
+    ///
+    /// The angle of the width axis of this rectangle, in radians
+    ///
+    #[inline]
+    pub fn angle(&self) -> f64 {
+        self.axis.y().atan2(self.axis.x())
+    }
+
+    ///
+    /// The size of this rectangle along its axis
+    ///
+    #[inline]
+    pub fn width(&self) -> f64 {
+        self.width
+    }
+
+    ///
+    /// The size of this rectangle perpendicular to its axis
+    ///
+    #[inline]
+    pub fn height(&self) -> f64 {
+        self.height
+    }
+
+    ///
+    /// The area covered by this rectangle
+    ///
+    #[inline]
+    pub fn area(&self) -> f64 {
+        self.width * self.height
+    }
+
+    ///
+    /// Returns the four corners of this rectangle, in order around its edge
+    ///
+    pub fn corners(&self) -> [Point; 4] {
+        let along = self.axis * (self.width * 0.5);
+        let across = Point::from_components(&[-self.axis.y(), self.axis.x()]) * (self.height * 0.5);
+
+        [
+            self.center - along - across,
+            self.center + along - across,
+            self.center + along + across,
+            self.center - along + across,
+        ]
+    }
+
+    ///
+    /// Returns true if a point is inside this rectangle
+    ///
+    pub fn contains_point(&self, point: &Point) -> bool {
+        let offset = *point - self.center;
+        let along = offset.x() * self.axis.x() + offset.y() * self.axis.y();
+        let across = offset.y() * self.axis.x() - offset.x() * self.axis.y();
+
+        along.abs() <= self.width * 0.5 && across.abs() <= self.height * 0.5
+    }
+}
+
+///
+/// 2D cross product of two vectors
+///
+#[inline]
+fn cross<Point: Coordinate2D>(origin: &Point, a: &Point, b: &Point) -> f64 {
+    (a.x() - origin.x()) * (b.y() - origin.y()) - (a.y() - origin.y()) * (b.x() - origin.x())
+}
+
+///
+/// Finds the convex hull of a set of points (using the monotone chain algorithm)
+///
+fn convex_hull<Point: Coordinate + Coordinate2D>(mut points: Vec<Point>) -> Vec<Point> {
+    points.sort_by(|a, b| {
+        a.x()
+            .partial_cmp(&b.x())
+            .unwrap_or(Ordering::Equal)
+            .then(a.y().partial_cmp(&b.y()).unwrap_or(Ordering::Equal))
+    });
+    points.dedup();
+
+    if points.len() < 3 {
+        return points;
+    }
+
+    let mut hull: Vec<Point> = vec![];
+
+    // Lower hull, then upper hull
+    for pass in 0..2 {
+        let start_len = hull.len();
+
+        for point in points.iter() {
+            while hull.len() >= start_len + 2
+                && cross(&hull[hull.len() - 2], &hull[hull.len() - 1], point) <= 0.0
+            {
+                hull.pop();
+            }
+
+            hull.push(*point);
+        }
+
+        // The last point is the first point of the next pass
+        hull.pop();
+
+        if pass == 0 {
+            points.reverse();
+        }
+    }
+
+    hull
+}
+
+///
+/// Finds the exact extent of a set of curves along a pair of perpendicular axes, as `(min_along, max_along, min_across, max_across)`
+///
+fn extent_along_axis<Curve: BezierCurve>(
+    curves: &[Curve],
+    axis: &Curve::Point,
+) -> (f64, f64, f64, f64)
+where
+    Curve::Point: Coordinate2D,
+{
+    let to_axis = |point: Curve::Point| {
+        Curve::Point::from_components(&[
+            point.dot(axis),
+            axis.x() * point.y() - axis.y() * point.x(),
+        ])
+    };
+
+    let mut extent = (f64::MAX, f64::MIN, f64::MAX, f64::MIN);
+
+    for curve in curves.iter() {
+        let (cp1, cp2) = curve.control_points();
+        let bounds: Bounds<Curve::Point> = bounding_box4(
+            to_axis(curve.start_point()),
+            to_axis(cp1),
+            to_axis(cp2),
+            to_axis(curve.end_point()),
+        );
+
+        let (min, max) = (bounds.min(), bounds.max());
+        extent = (
+            extent.0.min(min.x()),
+            extent.1.max(max.x()),
+            extent.2.min(min.y()),
+            extent.3.max(max.y()),
+        );
+    }
+
+    extent
+}
+
+///
+/// Finds the minimum-area oriented bounding box of a set of curves
+///
+/// The orientation is chosen using the rotating calipers algorithm on the convex hull of the extremities and a set of
+/// sample points from each curve, and the extent of the box along that orientation is then calculated exactly. This
+/// means that the box always contains all of the curves, but may be very slightly larger than the true minimum.
+///
+pub fn curves_oriented_bounding_box<Curve: BezierCurve>(
+    curves: &[Curve],
+) -> OrientedBounds<Curve::Point>
+where
+    Curve::Point: Coordinate2D,
+{
+    if curves.is_empty() {
+        return OrientedBounds::new(Curve::Point::origin(), Curve::Point::origin(), 0.0, 0.0);
+    }
+
+    // Gather the points that make up the hull
+    let mut hull_points = vec![];
+
+    for curve in curves.iter() {
+        let (cp1, cp2) = curve.control_points();
+        let extremities = find_extremities(curve.start_point(), cp1, cp2, curve.end_point());
+
+        hull_points.push(curve.start_point());
+        hull_points.extend(extremities.into_iter().map(|t| curve.point_at_pos(t)));
+        hull_points.extend(
+            (1..HULL_SAMPLES_PER_CURVE).map(|sample| {
+                curve.point_at_pos((sample as f64) / (HULL_SAMPLES_PER_CURVE as f64))
+            }),
+        );
+    }
+
+    let hull = convex_hull(hull_points);
+
+    // The minimum-area rectangle has a side that's in line with one of the edges of the hull
+    let mut candidate_axes = vec![Curve::Point::from_components(&[1.0, 0.0])];
+    for point_idx in 0..hull.len() {
+        let edge = hull[(point_idx + 1) % hull.len()] - hull[point_idx];
+        let edge_len = edge.magnitude();
+
+        if edge_len > 0.0 {
+            candidate_axes.push(edge * (1.0 / edge_len));
+        }
+    }
+
+    // Pick the axis that produces the smallest area
+    let mut best = None;
+    let mut best_area = f64::MAX;
+
+    for axis in candidate_axes {
+        let extent = extent_along_axis(curves, &axis);
+        let area = (extent.1 - extent.0) * (extent.3 - extent.2);
+
+        if area < best_area {
+            best_area = area;
+            best = Some((axis, extent));
+        }
+    }
+
+    let (axis, (min_along, max_along, min_across, max_across)) = best.unwrap();
+
+    // Convert the extent back to a center point
+    let along = (min_along + max_along) * 0.5;
+    let across = (min_across + max_across) * 0.5;
+    let center = Curve::Point::from_components(&[
+        along * axis.x() - across * axis.y(),
+        along * axis.y() + across * axis.x(),
+    ]);
+
+    OrientedBounds::new(center, axis, max_along - min_along, max_across - min_across)
+}
+
+///
+/// Finds the minimum-area oriented bounding box of a curve
+///
+/// Axis-aligned bounding boxes can be very loose for curves that run diagonally: this finds a rotated rectangle that fits
+/// the curve more closely.
+///
+pub fn curve_oriented_bounding_box<Curve: BezierCurve>(
+    curve: &Curve,
+) -> OrientedBounds<Curve::Point>
+where
+    Curve::Point: Coordinate2D,
+{
+    curves_oriented_bounding_box(std::slice::from_ref(curve))
+}
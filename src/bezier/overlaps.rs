@@ -1,8 +1,37 @@
 use super::super::consts::{SMALL_DISTANCE, SMALL_T_DISTANCE};
+#[cfg(feature = "robust_predicates")]
+use super::super::geo::orient_2d;
 use super::super::geo::{Coordinate, Coordinate2D};
+#[cfg(not(feature = "robust_predicates"))]
 use super::super::line::Line2D;
 use super::curve::BezierCurve;
 
+///
+/// Returns true if a point is within `SMALL_DISTANCE` of the line through two other points
+///
+#[cfg(not(feature = "robust_predicates"))]
+#[inline]
+fn is_collinear<P: Coordinate + Coordinate2D>(line: &(P, P), p: &P) -> bool {
+    let (a, b, c) = line.coefficients();
+
+    (a * p.x() + b * p.y() + c).abs() < SMALL_DISTANCE
+}
+
+///
+/// Returns true if a point is within `SMALL_DISTANCE` of the line through two other points, using a robust orientation test
+///
+/// `orient_2d()` is the length of the line multiplied by the distance of the point from it, so points are never treated as
+/// collinear because of rounding errors in finding the coefficients of the line.
+///
+#[cfg(feature = "robust_predicates")]
+#[inline]
+fn is_collinear<P: Coordinate + Coordinate2D>(line: &(P, P), p: &P) -> bool {
+    let length = line.0.distance_to(&line.1);
+
+    // Every point is collinear with a line that's just a point
+    length == 0.0 || (orient_2d(&line.0, &line.1, p) / length).abs() < SMALL_DISTANCE
+}
+
 ///
 /// If `curve2` overlaps `curve1`, returns two sets of `t` values (those for `curve1` and those for `curve2`)
 ///
@@ -51,22 +80,17 @@ where
     }
 
     // If curve1 and curve2 are collinear - two overlapping lines - we've already got the results (and the control points will differ anyway)
-    #[inline]
-    fn is_collinear<P: Coordinate2D>(p: &P, &(a, b, c): &(f64, f64, f64)) -> bool {
-        (a * p.x() + b * p.y() + c).abs() < SMALL_DISTANCE
-    }
-
-    let coeff = (curve1.start_point(), curve1.end_point()).coefficients();
+    let line = (curve1.start_point(), curve1.end_point());
     let (c1_cp1, c1_cp2) = curve1.control_points();
 
-    if is_collinear(&c1_cp1, &coeff)
-        && is_collinear(&c1_cp2, &coeff)
-        && is_collinear(&curve2.start_point(), &coeff)
-        && is_collinear(&curve2.end_point(), &coeff)
+    if is_collinear(&line, &c1_cp1)
+        && is_collinear(&line, &c1_cp2)
+        && is_collinear(&line, &curve2.start_point())
+        && is_collinear(&line, &curve2.end_point())
     {
         let (c2_cp1, c2_cp2) = curve2.control_points();
 
-        if is_collinear(&c2_cp1, &coeff) && is_collinear(&c2_cp2, &coeff) {
+        if is_collinear(&line, &c2_cp1) && is_collinear(&line, &c2_cp2) {
             return Some(((c1_t1, c1_t2), (c2_t1, c2_t2)));
         }
     }
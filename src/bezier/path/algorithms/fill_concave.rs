@@ -1,7 +1,11 @@
 use super::fill_convex::{trace_outline_convex, trace_outline_convex_partial, RayCollision};
 use super::fill_settings::FillSettings;
 
-use crate::bezier::path::{path_remove_interior_points, BezierPathFactory};
+use crate::bezier::path::progress::{report_progress, NoProgressMonitor};
+use crate::bezier::path::{
+    path_remove_interior_points, BezierPathFactory, OperationCancelled, OperationPhase,
+    ProgressMonitor,
+};
 use crate::bezier::{fit_curve, BezierCurve, Curve};
 use crate::geo::{Coordinate, Coordinate2D};
 use crate::line::{line_intersects_ray, Line};
@@ -200,6 +204,32 @@ where
     RayList: IntoIterator<Item = RayCollision<Coord, Item>>,
     RayFn: Fn(Coord, Coord) -> RayList,
 {
+    match trace_outline_concave_with_progress(center, options, cast_ray, &NoProgressMonitor) {
+        Ok(edges) => edges,
+        Err(OperationCancelled) => unreachable!(),
+    }
+}
+
+///
+/// Traces the outline of a complex area using ray-casting, reporting progress to a monitor that can cancel the operation
+///
+/// This is the same as `trace_outline_concave()`, except that `monitor` is called before each extra set of rays is cast.
+/// Returns `OperationCancelled` if the monitor returns `false`.
+///
+pub fn trace_outline_concave_with_progress<Coord, Item, RayList, RayFn, Monitor>(
+    center: Coord,
+    options: &FillSettings,
+    cast_ray: RayFn,
+    monitor: &Monitor,
+) -> Result<Vec<RayCollision<Coord, Option<Item>>>, OperationCancelled>
+where
+    Coord: Coordinate + Coordinate2D,
+    RayList: IntoIterator<Item = RayCollision<Coord, Item>>,
+    RayFn: Fn(Coord, Coord) -> RayList,
+    Monitor: ProgressMonitor + ?Sized,
+{
+    report_progress(monitor, OperationPhase::TracingOutline, 0.0)?;
+
     // Modify the raycasting function to return concave items (so we can distinguish between edges we introduced and ones matched by the original raycasting algorithm)
     // TODO: this just ensures we return optional items
     let cast_ray = &cast_ray;
@@ -209,6 +239,7 @@ where
             .map(|collision| RayCollision {
                 position: collision.position,
                 what: ConcaveItem::Edge(collision.what),
+                direction: collision.direction,
             })
     };
 
@@ -225,7 +256,7 @@ where
 
     // Stop if we found no collisions
     if edges.len() < 2 {
-        return vec![];
+        return Ok(vec![]);
     }
 
     // Find the edges where we need to cast extra rays
@@ -239,6 +270,10 @@ where
     // TODO: cast rays from each of the 'long' edges and update the edge list
     let mut long_edge_index = 0;
     while long_edge_index < long_edges.len() {
+        // More long edges can be found as rays are cast, so this is only an estimate of how much of the outline is traced
+        let amount_traced = (long_edge_index as f64) / (long_edges.len() as f64);
+        report_progress(monitor, OperationPhase::TracingOutline, amount_traced * 0.8)?;
+
         // Fetch the next edge to cast from
         let next_edge = &long_edges[long_edge_index];
 
@@ -279,6 +314,7 @@ where
                             Some(RayCollision {
                                 position: intersection_point,
                                 what: ConcaveItem::SelfIntersection(edge_index),
+                                direction: None,
                             })
                         } else {
                             None
@@ -355,13 +391,14 @@ where
     }
 
     // The edges we retrieved are the result
-    edges
+    Ok(edges
         .into_iter()
         .map(|collision| RayCollision {
             position: collision.position,
             what: collision.what.into(),
+            direction: collision.direction,
         })
-        .collect()
+        .collect())
 }
 
 ///
@@ -381,11 +418,39 @@ where
     Coord: Coordinate + Coordinate2D,
     RayList: IntoIterator<Item = RayCollision<Coord, Item>>,
     RayFn: Fn(Coord, Coord) -> RayList,
+{
+    match flood_fill_concave_with_progress(center, options, cast_ray, &NoProgressMonitor) {
+        Ok(paths) => paths,
+        Err(OperationCancelled) => unreachable!(),
+    }
+}
+
+///
+/// Creates a Bezier path by flood-filling an area whose bounds can be determined by ray-casting, reporting progress to a
+/// monitor that can cancel the operation
+///
+/// This is the same as `flood_fill_concave()`, except that `monitor` is called while the outline is being traced and
+/// between the phases of generating the path. Returns `OperationCancelled` if the monitor returns `false`. Pass a
+/// `CancellationToken` to be able to cancel the fill from another thread.
+///
+pub fn flood_fill_concave_with_progress<Path, Coord, Item, RayList, RayFn, Monitor>(
+    center: Coord,
+    options: &FillSettings,
+    cast_ray: RayFn,
+    monitor: &Monitor,
+) -> Result<Option<Vec<Path>>, OperationCancelled>
+where
+    Path: BezierPathFactory<Point = Coord>,
+    Coord: Coordinate + Coordinate2D,
+    RayList: IntoIterator<Item = RayCollision<Coord, Item>>,
+    RayFn: Fn(Coord, Coord) -> RayList,
+    Monitor: ProgressMonitor + ?Sized,
 {
     // Trace where the ray casting algorithm indicates collisions with the specified center
-    let collisions = trace_outline_concave(center, options, cast_ray);
+    let collisions = trace_outline_concave_with_progress(center, options, cast_ray, monitor)?;
 
     // Build a path using the LMS algorithm
+    report_progress(monitor, OperationPhase::FittingCurves, 0.8)?;
     let curves = fit_curve::<Curve<Coord>>(
         &collisions
             .iter()
@@ -394,7 +459,7 @@ where
         options.fit_error,
     );
 
-    if let Some(curves) = curves {
+    let result = if let Some(curves) = curves {
         if !curves.is_empty() {
             // Convert the curves into a path
             let initial_point = curves[0].start_point();
@@ -408,6 +473,7 @@ where
             );
 
             // Remove any interior points that the path might have (this happens when the fill path overlaps itself)
+            report_progress(monitor, OperationPhase::BuildingPaths, 0.9)?;
             Some(path_remove_interior_points(&[overlapped_path], 0.01))
         } else {
             // No curves in the path
@@ -416,5 +482,9 @@ where
     } else {
         // Failed to fit a curve to these points
         None
-    }
+    };
+
+    report_progress(monitor, OperationPhase::Finished, 1.0)?;
+
+    Ok(result)
 }
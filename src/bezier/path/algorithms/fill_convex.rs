@@ -1,8 +1,11 @@
 use super::super::super::super::geo::{Coordinate, Coordinate2D};
 use super::super::super::{fit_curve, BezierCurve, Curve};
-use super::super::BezierPathFactory;
+use super::super::{BezierPathFactory, FillRule};
 use super::fill_settings::FillSettings;
 
+use smallvec::SmallVec;
+
+use std::cmp::Ordering;
 use std::f64;
 use std::ops::Range;
 
@@ -19,6 +22,9 @@ where
 
     /// The object that this ray colided with
     pub what: Item,
+
+    /// The change in the winding count when the ray crosses this edge, if it's known (see `with_direction()`)
+    pub(crate) direction: Option<i32>,
 }
 
 impl<Coord, Item> RayCollision<Coord, Item>
@@ -29,7 +35,35 @@ where
     /// Creates a new collision at a specific point
     ///
     pub fn new(position: Coord, what: Item) -> Self {
-        Self { position, what }
+        Self {
+            position,
+            what,
+            direction: None,
+        }
+    }
+
+    ///
+    /// Creates a new collision with an edge that has a known direction
+    ///
+    /// The direction is the change in winding count as the ray crosses the edge: which way round is +1 and which is -1 does
+    /// not matter, so long as it's consistent for all of the edges. Directional collisions are used by the fill rule set in
+    /// the `FillSettings` to find the edge of the filled region within self-overlapping shapes.
+    ///
+    pub fn with_direction(position: Coord, what: Item, direction: i32) -> Self {
+        Self {
+            position,
+            what,
+            direction: Some(direction),
+        }
+    }
+
+    ///
+    /// The change in the winding count when the ray crosses this edge in the direction it's travelling (+1 or -1), or
+    /// `None` if the direction of the edge isn't known
+    ///
+    #[inline]
+    pub fn direction(&self) -> Option<i32> {
+        self.direction
     }
 }
 
@@ -43,6 +77,10 @@ where
 /// along this ray. If there is an intersection, the returned list should always include the closest
 /// intersection in the direction of the ray defined by the two coordinates.
 ///
+/// If the collisions are created with `RayCollision::with_direction()`, the fill rule from the options is used to
+/// decide which collision forms the edge of the region: in this case the list should include every collision in
+/// the direction of the ray so the winding count of the center point can be worked out.
+///
 pub fn trace_outline_convex<Coord, Item, RayList, RayFn>(
     center: Coord,
     options: &FillSettings,
@@ -59,37 +97,66 @@ where
 ///
 /// Finds the nearest collision and the square of its distance from the center from the results of a ray-casting operation
 ///
+/// If all of the collisions have a direction, this is the nearest collision where the winding count changes from inside
+/// to outside according to the fill rule, instead of the nearest collision overall.
+///
 fn find_nearest_collision<Coord, Item, RayList>(
     candidates: RayList,
     center: Coord,
     ray_vector: Coord,
+    fill_rule: FillRule,
 ) -> Option<(RayCollision<Coord, Item>, f64)>
 where
     Coord: Coordinate + Coordinate2D,
     RayList: IntoIterator<Item = RayCollision<Coord, Item>>,
 {
-    // Pick the first positive collision in the direction of the ray
-    let mut nearest_collision = None;
-    let mut nearest_distance_squared = f64::MAX;
+    // Gather the collisions in the direction of the ray, along with their distances from the center
+    let mut collisions = candidates
+        .into_iter()
+        .filter_map(|ray_collision| {
+            let collision_vector = ray_collision.position - center;
 
-    for ray_collision in candidates {
-        let collision_vector = ray_collision.position - center;
+            // Ignore collisions in the opposite direction of our ray (or that aren't at a valid position)
+            let direction = collision_vector.dot(&ray_vector);
+            let distance = collision_vector.dot(&collision_vector);
+            if direction < 0.0 || !distance.is_finite() {
+                None
+            } else {
+                Some((ray_collision, distance))
+            }
+        })
+        .collect::<SmallVec<[_; 8]>>();
 
-        // Ignore collisions in the opposite direction of our ray
-        let direction = collision_vector.dot(&ray_vector);
-        if direction < 0.0 {
-            continue;
-        }
+    collisions.sort_by(|(_, distance_a), (_, distance_b)| {
+        distance_a
+            .partial_cmp(distance_b)
+            .unwrap_or(Ordering::Equal)
+    });
 
-        // If this collision is closer to the center than before, then it becomes the nearest collision
-        let distance = collision_vector.dot(&collision_vector);
-        if distance < nearest_distance_squared {
-            nearest_collision = Some(ray_collision);
-            nearest_distance_squared = distance;
-        }
+    // The winding count is 0 past the last collision, so the count at the center is found by undoing all of the crossings
+    let mut winding_count = -collisions
+        .iter()
+        .map(|(collision, _)| collision.direction.unwrap_or(0))
+        .sum::<i32>();
+
+    if !fill_rule.is_inside(winding_count) {
+        // The center isn't inside a filled region (or the collisions don't have directions): the nearest collision is the edge
+        return collisions.into_iter().next();
     }
 
-    nearest_collision.map(|nearest_collision| (nearest_collision, nearest_distance_squared))
+    // Move along the ray until we reach a point that's outside of the filled region (or an edge without a direction)
+    let edge_idx = collisions
+        .iter()
+        .position(|(collision, _)| match collision.direction {
+            Some(direction) => {
+                winding_count += direction;
+                !fill_rule.is_inside(winding_count)
+            }
+            None => true,
+        })
+        .unwrap_or(0);
+
+    collisions.into_iter().nth(edge_idx)
 }
 
 ///
@@ -98,6 +165,7 @@ where
 fn perform_ray_cast<Coord, Item, RayList, RayFn>(
     center: Coord,
     theta: f64,
+    fill_rule: FillRule,
     cast_ray: RayFn,
 ) -> Option<(RayCollision<Coord, Item>, f64)>
 where
@@ -114,7 +182,7 @@ where
     let ray_collisions = cast_ray(center, ray_target);
 
     // Pick the first positive collision in the direction of the ray
-    find_nearest_collision(ray_collisions, center, ray_vector)
+    find_nearest_collision(ray_collisions, center, ray_vector, fill_rule)
 }
 
 ///
@@ -156,8 +224,8 @@ where
         let theta = angles.start + (angles.end - angles.start) / 4.0 * check_point;
         let end_theta = theta + (angles.end - angles.start) / 4.0;
 
-        let start_pos = perform_ray_cast(center, theta, &cast_ray);
-        let end_pos = perform_ray_cast(center, end_theta, &cast_ray);
+        let start_pos = perform_ray_cast(center, theta, options.fill_rule, &cast_ray);
+        let end_pos = perform_ray_cast(center, end_theta, options.fill_rule, &cast_ray);
 
         stack.push(StackEntry {
             angle: theta..end_theta,
@@ -181,7 +249,7 @@ where
             } else {
                 // Divide the entry into two by casting a ray between the two points
                 let mid_point = (entry.angle.start + entry.angle.end) / 2.0;
-                let mid_ray = perform_ray_cast(center, mid_point, &cast_ray);
+                let mid_ray = perform_ray_cast(center, mid_point, options.fill_rule, &cast_ray);
                 let mid_ray_pos = mid_ray.as_ref().map(|(collision, _)| collision.position);
 
                 // If there's a discontinuity (eg, a corner we can't see around), we'll see that the mid point is very close to the end point and far from the start point
@@ -222,7 +290,7 @@ where
             if entry.angle.end - entry.angle.start > min_step {
                 // Cast a ray between the two points
                 let mid_point = (entry.angle.start + entry.angle.end) / 2.0;
-                let mid_ray = perform_ray_cast(center, mid_point, &cast_ray);
+                let mid_ray = perform_ray_cast(center, mid_point, options.fill_rule, &cast_ray);
                 let mid_ray_pos = mid_ray.as_ref().map(|(collision, _)| collision.position);
 
                 // Divide into two pairs of ranges (process the earlier one first)
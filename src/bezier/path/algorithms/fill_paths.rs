@@ -0,0 +1,108 @@
+use super::fill_concave::flood_fill_concave;
+use super::fill_convex::RayCollision;
+use super::fill_settings::FillSettings;
+
+use crate::bezier::path::{
+    path_index, path_to_curves, ray_cast_paths, BezierPath, BezierPathFactory,
+};
+use crate::bezier::{BezierCurve, Curve, NormalCurve};
+use crate::geo::{Coordinate, Coordinate2D};
+
+/// Intersections this close to the end of a section may also be found at the start of the following section
+const SECTION_END_T: f64 = 1.0 - 1e-6;
+
+///
+/// Creates a Bezier path by flood-filling the region around a point that is bounded by a set of paths
+///
+/// This is a version of `flood_fill_concave()` that generates the ray-casting function from the paths, so the regions
+/// bounded by any shape can be filled. The paths are treated as walls: the fill will stop at the first path it reaches in
+/// any direction, so a path inside the region will become a hole in the result, and a path surrounding the start point
+/// will contain the fill even if that path is itself inside another path.
+///
+/// The ray collisions include the direction of each edge, so a fill rule set with `FillSettings::with_fill_rule()` can be
+/// used to fill across the edges inside self-overlapping boundaries instead.
+///
+pub fn flood_fill_in_paths<P, POut>(
+    paths: &[P],
+    start_point: P::Point,
+    options: &FillSettings,
+) -> Option<Vec<POut>>
+where
+    P: BezierPath,
+    P::Point: Coordinate + Coordinate2D,
+    POut: BezierPathFactory<Point = P::Point>,
+{
+    // The curves making up each path, so we can find the position and direction of the intersections
+    let curves = paths
+        .iter()
+        .map(|path| path_to_curves::<_, Curve<_>>(path).collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+
+    // The end of the last section of a closed path is the same as the start of the first section
+    let is_closed = paths
+        .iter()
+        .zip(curves.iter())
+        .map(|(path, curves)| {
+            curves
+                .last()
+                .map(|curve| curve.end_point().is_near_to(&path.start_point(), 1e-6))
+                .unwrap_or(false)
+        })
+        .collect::<Vec<_>>();
+
+    // Index the paths so each ray only needs to check the curves that it's near to
+    let index = path_index(paths);
+
+    let cast_ray = |from: P::Point, to: P::Point| {
+        let ray = (from, to);
+        let ray_direction = to - from;
+
+        let hits = ray_cast_paths(&index, &ray)
+            .into_iter()
+            .map(|(path_idx, section_idx, t, _point)| (path_idx, section_idx, t))
+            .collect::<Vec<_>>();
+
+        // A ray passing through the point where two sections join can hit both of them: only count the hit on the later section
+        let is_duplicate_hit = |(path_idx, section_idx, t): &(usize, usize, f64)| {
+            if *t < SECTION_END_T {
+                return false;
+            }
+
+            let num_sections = curves[*path_idx].len();
+            let next_section = if *section_idx + 1 < num_sections {
+                *section_idx + 1
+            } else if is_closed[*path_idx] {
+                0
+            } else {
+                return false;
+            };
+
+            hits.iter().any(|(other_path, other_section, other_t)| {
+                *other_path == *path_idx
+                    && *other_section == next_section
+                    && *other_t <= 1.0 - SECTION_END_T
+            })
+        };
+
+        hits.iter()
+            .filter(|hit| !is_duplicate_hit(hit))
+            .map(|(path_idx, section_idx, t)| {
+                let (path_idx, section_idx, t) = (*path_idx, *section_idx, *t);
+                let curve = &curves[path_idx][section_idx];
+                let position = curve.point_at_pos(t);
+                let tangent = curve.tangent_at_pos(t);
+
+                // The direction is the side of the ray that the edge is crossing towards
+                let cross = ray_direction.x() * tangent.y() - ray_direction.y() * tangent.x();
+
+                RayCollision::with_direction(
+                    position,
+                    (path_idx, section_idx, t),
+                    cross.signum() as i32,
+                )
+            })
+            .collect::<Vec<_>>()
+    };
+
+    flood_fill_concave(start_point, options, cast_ray)
+}
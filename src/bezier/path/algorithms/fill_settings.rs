@@ -1,3 +1,5 @@
+use super::super::FillRule;
+
 ///
 /// Options that affect the fill algorithm
 ///
@@ -14,6 +16,9 @@ pub struct FillSettings {
 
     /// For concave fills, the minimum gap size that a fill can escape through
     pub(crate) min_gap: Option<f64>,
+
+    /// The rule used to find the edge of the filled region when the ray casting function returns directional collisions
+    pub(crate) fill_rule: FillRule,
 }
 
 impl FillSettings {
@@ -53,6 +58,21 @@ impl FillSettings {
         new_options.min_gap = new_min_gap;
         new_options
     }
+
+    ///
+    /// Sets the fill rule used to decide where the edge of the filled region is
+    ///
+    /// This only has an effect when the ray casting function returns collisions created by `RayCollision::with_direction()`.
+    /// In that case, the winding count of each point along a ray is tracked and the fill extends until it reaches a point
+    /// that is outside according to this rule, so it can fill across edges that are inside a self-overlapping shape when the
+    /// `NonZero` rule is used. Collisions without a direction always stop the fill. The default is `EvenOdd`, where every
+    /// edge stops the fill.
+    ///
+    pub fn with_fill_rule(self, new_fill_rule: FillRule) -> Self {
+        let mut new_options = self;
+        new_options.fill_rule = new_fill_rule;
+        new_options
+    }
 }
 
 impl Default for FillSettings {
@@ -64,6 +84,7 @@ impl Default for FillSettings {
             step: 2.0,
             fit_error: 0.5,
             min_gap: Some(5.0),
+            fill_rule: FillRule::EvenOdd,
         }
     }
 }
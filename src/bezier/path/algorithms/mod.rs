@@ -1,7 +1,9 @@
 mod fill_concave;
 mod fill_convex;
+mod fill_paths;
 mod fill_settings;
 
 pub use self::fill_concave::*;
 pub use self::fill_convex::*;
+pub use self::fill_paths::*;
 pub use self::fill_settings::*;
@@ -1,7 +1,15 @@
 use super::super::super::super::geo::{Coordinate, Coordinate2D};
 use super::super::graph_path::GraphPath;
 use super::super::path::{BezierPath, BezierPathFactory};
-use super::ray_cast::{PathDirection, PathLabel};
+use super::super::progress::{
+    report_progress, NoProgressMonitor, OperationCancelled, OperationPhase, ProgressMonitor,
+};
+use super::error::PathArithmeticError;
+use super::ray_cast::{
+    collide_labelled_graphs, collide_labelled_paths, labelled_graph_path,
+    labelled_graph_path_with_sources, HasPathLabel, PathDirection, PathLabel, PathSectionSource,
+    RayCastStrategy,
+};
 
 //
 // This uses a simple ray casting algorithm to perform the addition
@@ -10,7 +18,7 @@ use super::ray_cast::{PathDirection, PathLabel};
 // exterior depending on whether or not we consider it as crossing into or out of the final shape.
 //
 
-impl<Point: Coordinate + Coordinate2D> GraphPath<Point, PathLabel> {
+impl<Point: Coordinate + Coordinate2D, Label: HasPathLabel> GraphPath<Point, Label> {
     ///
     /// Given a labelled graph path, marks exterior edges by adding `PathSource::Path1` and `PathSource::Path2`
     ///
@@ -21,6 +29,16 @@ impl<Point: Coordinate + Coordinate2D> GraphPath<Point, PathLabel> {
         });
     }
 
+    ///
+    /// As for `set_exterior_by_adding()`, but returns an error if the exterior edges can't be found reliably
+    ///
+    pub fn try_set_exterior_by_adding(&mut self) -> Result<(), PathArithmeticError<Point>> {
+        self.try_set_edge_kinds_by_ray_casting(
+            |path_crossings| (path_crossings[0] & 1) != 0 || (path_crossings[1] & 1) != 0,
+            &RayCastStrategy::default(),
+        )
+    }
+
     ///
     /// Given a path that intersects itself (ie, only contains SourcePath::Path1), discovers the 'true' exterior edge.
     ///
@@ -30,6 +48,71 @@ impl<Point: Coordinate + Coordinate2D> GraphPath<Point, PathLabel> {
             path_crossings[0] != 0 || path_crossings[1] != 0
         });
     }
+
+    ///
+    /// As for `set_exterior_by_removing_interior_points()`, but returns an error if the exterior edges can't be found reliably
+    ///
+    pub fn try_set_exterior_by_removing_interior_points(
+        &mut self,
+    ) -> Result<(), PathArithmeticError<Point>> {
+        self.try_set_edge_kinds_by_ray_casting(
+            |path_crossings| path_crossings[0] != 0 || path_crossings[1] != 0,
+            &RayCastStrategy::default(),
+        )
+    }
+}
+
+///
+/// Creates a graph path from a set of paths that has been collided with itself
+///
+fn self_collided_graph_path<P1: BezierPath>(
+    path: &[P1],
+    accuracy: f64,
+) -> GraphPath<P1::Point, PathLabel>
+where
+    P1::Point: Coordinate + Coordinate2D,
+{
+    // Create the graph path from the source side
+    let mut merged_path = GraphPath::new();
+    merged_path = merged_path.merge(GraphPath::from_merged_paths(
+        path.iter()
+            .map(|path| (path, PathLabel(0, PathDirection::from(path)))),
+    ));
+
+    // Collide the path with itself to find the intersections
+    merged_path.self_collide(accuracy);
+    merged_path.round(accuracy);
+
+    merged_path
+}
+
+///
+/// Adds two labelled graph paths together, reporting progress to a monitor, and returns the graph with its exterior edges
+/// marked
+///
+fn add_labelled_graphs<Point, Label, Monitor>(
+    graph1: GraphPath<Point, Label>,
+    graph2: GraphPath<Point, Label>,
+    accuracy: f64,
+    monitor: &Monitor,
+) -> Result<GraphPath<Point, Label>, OperationCancelled>
+where
+    Point: Coordinate + Coordinate2D,
+    Label: HasPathLabel,
+    Monitor: ProgressMonitor + ?Sized,
+{
+    // Colliding the paths is the first 60% of the operation
+    let collide_monitor = |phase, amount_complete| monitor.progress(phase, amount_complete * 0.6);
+    let mut merged_path = collide_labelled_graphs(graph1, graph2, accuracy, &collide_monitor)?;
+
+    // Set the exterior edges using the 'add' algorithm
+    report_progress(monitor, OperationPhase::ClassifyingEdges, 0.6)?;
+    merged_path.set_exterior_by_adding();
+    merged_path.heal_exterior_gaps();
+
+    report_progress(monitor, OperationPhase::BuildingPaths, 0.9)?;
+
+    Ok(merged_path)
 }
 
 ///
@@ -48,38 +131,120 @@ where
     P2: BezierPath<Point = P1::Point>,
     POut: BezierPathFactory<Point = P1::Point>,
 {
+    match path_add_with_progress(path1, path2, accuracy, &NoProgressMonitor) {
+        Ok(result) => result,
+        Err(OperationCancelled) => unreachable!(),
+    }
+}
+
+///
+/// Generates the path formed by adding two sets of paths, returning where each section of the result came from
+///
+/// This is the same as `path_add()`, except each path in the result is paired with a list of `PathSectionSource`s, one for
+/// each section of the path (in the same order as `points()`), describing the input path and the section within it that
+/// the result was taken from. This can be used to carry styling or other metadata attached to the input paths through
+/// to the result.
+///
+pub fn path_add_with_labels<P1: BezierPath, P2, POut>(
+    path1: &[P1],
+    path2: &[P2],
+    accuracy: f64,
+) -> Vec<(POut, Vec<PathSectionSource>)>
+where
+    P1::Point: Coordinate + Coordinate2D,
+    P2: BezierPath<Point = P1::Point>,
+    POut: BezierPathFactory<Point = P1::Point>,
+{
+    // Copies a set of paths with the sources of their sections
+    fn unchanged_paths<P: BezierPath, POut: BezierPathFactory<Point = P::Point>>(
+        paths: &[P],
+        path_set: u32,
+    ) -> Vec<(POut, Vec<PathSectionSource>)> {
+        paths
+            .iter()
+            .enumerate()
+            .map(|(path_idx, path)| {
+                let sources = (0..path.points().count())
+                    .map(|section_idx| PathSectionSource {
+                        path_set,
+                        path_idx,
+                        section_idx,
+                    })
+                    .collect();
+
+                (POut::from_path(path), sources)
+            })
+            .collect()
+    }
+
     // If either path is empty, short-circuit by returning the other
     if path1.is_empty() {
-        return path2.iter().map(|path| POut::from_path(path)).collect();
+        return unchanged_paths(path2, 1);
     } else if path2.is_empty() {
-        return path1.iter().map(|path| POut::from_path(path)).collect();
+        return unchanged_paths(path1, 0);
     }
 
-    // Create the graph path from the source side
-    let mut merged_path = GraphPath::new();
-    merged_path = merged_path.merge(GraphPath::from_merged_paths(
-        path1
-            .iter()
-            .map(|path| (path, PathLabel(0, PathDirection::from(path)))),
-    ));
-
-    // Collide with the target side to generate a full path
-    merged_path = merged_path.collide(
-        GraphPath::from_merged_paths(
-            path2
-                .iter()
-                .map(|path| (path, PathLabel(1, PathDirection::from(path)))),
-        ),
+    // Collide the two sides to generate a full path, with the source of each edge in the labels
+    let merged_path = match add_labelled_graphs(
+        labelled_graph_path_with_sources(path1, 0),
+        labelled_graph_path_with_sources(path2, 1),
         accuracy,
-    );
-    merged_path.round(accuracy);
-
-    // Set the exterior edges using the 'add' algorithm
-    merged_path.set_exterior_by_adding();
-    merged_path.heal_exterior_gaps();
+        &NoProgressMonitor,
+    ) {
+        Ok(merged_path) => merged_path,
+        Err(OperationCancelled) => unreachable!(),
+    };
 
     // Produce the final result
-    merged_path.exterior_paths()
+    merged_path
+        .exterior_paths_with_labels()
+        .into_iter()
+        .map(|(path, labels)| {
+            let sources = labels.into_iter().map(|(_label, source)| source).collect();
+            (path, sources)
+        })
+        .collect()
+}
+
+///
+/// Generates the path formed by adding two sets of paths, reporting progress to a monitor that can cancel the operation
+///
+/// This is the same as `path_add()`, except that `monitor` is called between each phase of the operation, and between the
+/// steps of finding and dividing the edges where the paths collide. If the monitor returns `false`, the operation is stopped
+/// and `OperationCancelled` is returned. Pass a `CancellationToken` to be able to cancel the operation from another thread.
+///
+pub fn path_add_with_progress<P1: BezierPath, P2, POut, Monitor>(
+    path1: &[P1],
+    path2: &[P2],
+    accuracy: f64,
+    monitor: &Monitor,
+) -> Result<Vec<POut>, OperationCancelled>
+where
+    P1::Point: Coordinate + Coordinate2D,
+    P2: BezierPath<Point = P1::Point>,
+    POut: BezierPathFactory<Point = P1::Point>,
+    Monitor: ProgressMonitor + ?Sized,
+{
+    report_progress(monitor, OperationPhase::Colliding, 0.0)?;
+
+    // If either path is empty, short-circuit by returning the other
+    let result = if path1.is_empty() {
+        path2.iter().map(|path| POut::from_path(path)).collect()
+    } else if path2.is_empty() {
+        path1.iter().map(|path| POut::from_path(path)).collect()
+    } else {
+        add_labelled_graphs(
+            labelled_graph_path(path1, 0),
+            labelled_graph_path(path2, 1),
+            accuracy,
+            monitor,
+        )?
+        .exterior_paths()
+    };
+
+    report_progress(monitor, OperationPhase::Finished, 1.0)?;
+
+    Ok(result)
 }
 
 ///
@@ -100,16 +265,8 @@ where
     P1::Point: Coordinate + Coordinate2D,
     POut: BezierPathFactory<Point = P1::Point>,
 {
-    // Create the graph path from the source side
-    let mut merged_path = GraphPath::new();
-    merged_path = merged_path.merge(GraphPath::from_merged_paths(
-        path.iter()
-            .map(|path| (path, PathLabel(0, PathDirection::from(path)))),
-    ));
-
     // Collide the path with itself to find the intersections
-    merged_path.self_collide(accuracy);
-    merged_path.round(accuracy);
+    let mut merged_path = self_collided_graph_path(path, accuracy);
 
     // Set the exterior edges by considering all points inside an edge as 'interior'
     merged_path.set_exterior_by_removing_interior_points();
@@ -143,16 +300,8 @@ where
     P1::Point: Coordinate + Coordinate2D,
     POut: BezierPathFactory<Point = P1::Point>,
 {
-    // Create the graph path from the source side
-    let mut merged_path = GraphPath::new();
-    merged_path = merged_path.merge(GraphPath::from_merged_paths(
-        path.iter()
-            .map(|path| (path, PathLabel(0, PathDirection::from(path)))),
-    ));
-
     // Collide the path with itself to find the intersections
-    merged_path.self_collide(accuracy);
-    merged_path.round(accuracy);
+    let mut merged_path = self_collided_graph_path(path, accuracy);
 
     // Set the exterior edges using the 'add' algorithm
     merged_path.set_exterior_by_adding();
@@ -164,3 +313,73 @@ where
 
     result
 }
+
+///
+/// Generates the path formed by adding two sets of paths, or returns an error if the result can't be found reliably
+///
+/// This is the same as `path_add()`, except where `path_add()` would make a best guess at which edges are on the outside
+/// of the result (which can produce a corrupted shape), this returns a `PathArithmeticError` describing the problem, so the
+/// application can fall back to another way of combining the paths.
+///
+pub fn try_path_add<P1: BezierPath, P2, POut>(
+    path1: &[P1],
+    path2: &[P2],
+    accuracy: f64,
+) -> Result<Vec<POut>, PathArithmeticError<P1::Point>>
+where
+    P1::Point: Coordinate + Coordinate2D,
+    P2: BezierPath<Point = P1::Point>,
+    POut: BezierPathFactory<Point = P1::Point>,
+{
+    // If either path is empty, short-circuit by returning the other
+    if path1.is_empty() {
+        return Ok(path2.iter().map(|path| POut::from_path(path)).collect());
+    } else if path2.is_empty() {
+        return Ok(path1.iter().map(|path| POut::from_path(path)).collect());
+    }
+
+    let mut merged_path = collide_labelled_paths(path1, path2, accuracy);
+
+    merged_path.try_set_exterior_by_adding()?;
+    merged_path.heal_exterior_gaps();
+
+    Ok(merged_path.exterior_paths())
+}
+
+///
+/// As for `path_remove_interior_points()`, but returns an error if the result can't be found reliably
+///
+pub fn try_path_remove_interior_points<P1: BezierPath, POut>(
+    path: &[P1],
+    accuracy: f64,
+) -> Result<Vec<POut>, PathArithmeticError<P1::Point>>
+where
+    P1::Point: Coordinate + Coordinate2D,
+    POut: BezierPathFactory<Point = P1::Point>,
+{
+    let mut merged_path = self_collided_graph_path(path, accuracy);
+
+    merged_path.try_set_exterior_by_removing_interior_points()?;
+    merged_path.heal_exterior_gaps();
+
+    Ok(merged_path.exterior_paths())
+}
+
+///
+/// As for `path_remove_overlapped_points()`, but returns an error if the result can't be found reliably
+///
+pub fn try_path_remove_overlapped_points<P1: BezierPath, POut>(
+    path: &[P1],
+    accuracy: f64,
+) -> Result<Vec<POut>, PathArithmeticError<P1::Point>>
+where
+    P1::Point: Coordinate + Coordinate2D,
+    POut: BezierPathFactory<Point = P1::Point>,
+{
+    let mut merged_path = self_collided_graph_path(path, accuracy);
+
+    merged_path.try_set_exterior_by_adding()?;
+    merged_path.heal_exterior_gaps();
+
+    Ok(merged_path.exterior_paths())
+}
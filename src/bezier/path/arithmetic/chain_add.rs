@@ -1,6 +1,7 @@
-use super::super::super::super::geo::{Coordinate, Coordinate2D};
+use super::super::super::super::geo::{BoundingBox, Bounds, Coordinate, Coordinate2D};
 use super::super::graph_path::GraphPath;
 use super::super::path::{BezierPath, BezierPathFactory};
+use super::add::path_add;
 use super::ray_cast::{PathDirection, PathLabel};
 
 ///
@@ -44,3 +45,62 @@ where
     // Produce the final result
     merged_path.exterior_paths()
 }
+
+///
+/// Adds a large number of paths together, by repeatedly adding pairs of paths
+///
+/// Each of the paths is considered to be a separate shape, and the result is the union of all of them. Neighbouring paths
+/// (sorted from left to right) are added in pairs, then the results are added in pairs and so on until there is a single
+/// result. This is faster than adding the paths one at a time, as each step only has to consider a few of the other paths,
+/// and there are fewer steps where rounding errors can build up. Groups of paths whose bounding boxes don't overlap are
+/// combined without performing any arithmetic.
+///
+pub fn path_add_many<P, POut, PathIter>(paths: PathIter, accuracy: f64) -> Vec<POut>
+where
+    P: BezierPath,
+    P::Point: Coordinate + Coordinate2D,
+    POut: BezierPathFactory<Point = P::Point>,
+    PathIter: IntoIterator<Item = P>,
+{
+    // Each group is a set of paths that have already been added together and their bounding box
+    let mut groups = paths
+        .into_iter()
+        .map(|path| {
+            let bounds = path.fast_bounding_box::<Bounds<_>>().inflate(accuracy);
+            (vec![POut::from_path(&path)], bounds)
+        })
+        .collect::<Vec<_>>();
+    groups.sort_by(|(_, a), (_, b)| a.center().x().total_cmp(&b.center().x()));
+
+    // Add neighbouring pairs of groups until there's only one left
+    while groups.len() > 1 {
+        let mut next_groups = Vec::with_capacity(groups.len() / 2 + 1);
+        let mut remaining = groups.into_iter();
+
+        while let Some((paths1, bounds1)) = remaining.next() {
+            let (paths2, bounds2) = match remaining.next() {
+                Some(group) => group,
+                None => {
+                    next_groups.push((paths1, bounds1));
+                    break;
+                }
+            };
+
+            let combined = if bounds1.overlaps(&bounds2) {
+                path_add::<_, _, POut>(&paths1, &paths2, accuracy)
+            } else {
+                paths1.into_iter().chain(paths2).collect()
+            };
+
+            next_groups.push((combined, bounds1.union_bounds(bounds2)));
+        }
+
+        groups = next_groups;
+    }
+
+    groups
+        .into_iter()
+        .next()
+        .map(|(paths, _)| paths)
+        .unwrap_or_else(Vec::new)
+}
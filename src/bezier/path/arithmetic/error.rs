@@ -0,0 +1,67 @@
+use super::super::graph_path::GraphEdgeRef;
+
+use std::error::Error;
+use std::fmt;
+
+///
+/// Error generated when a path arithmetic operation can't reliably work out which edges are on the outside of the result
+///
+/// These errors are returned by the `try_` variants of the arithmetic functions (such as `try_path_add()`). The functions
+/// without the `try_` prefix make a best guess instead, which can produce corrupted shapes when the collisions between
+/// the paths are inconsistent, so applications that need to detect this case (for example, to fall back to rendering
+/// with a winding rule instead) should use the `try_` variants.
+///
+#[derive(Clone, Debug, PartialEq)]
+pub enum PathArithmeticError<Point> {
+    /// None of the rays cast at an edge had a consistent set of collisions (usually because the graph is missing an
+    /// intersection, or has gaps so it is not closed). The ray is the first one that was tried, and the collisions and
+    /// crossings are the ones that were found along it.
+    InconsistentRayCollisions {
+        /// The edge that the ray was cast at
+        edge: GraphEdgeRef,
+
+        /// The ray that was cast, as two points that it passes through
+        ray: (Point, Point),
+
+        /// The number of edges that the ray collided with (a ray through a closed shape should hit an even number)
+        num_collisions: usize,
+
+        /// The number of times the ray entered each path minus the number of times it left (these should all be 0)
+        path_crossings: Vec<i32>,
+    },
+
+    /// A ray found that an edge was on the outside of the result after another ray decided it was on the inside, or vice
+    /// versa
+    ConflictingEdgeKind {
+        /// The edge that was classified in two different ways
+        edge: GraphEdgeRef,
+
+        /// The ray that found the conflicting classification, as two points that it passes through
+        ray: (Point, Point),
+    },
+}
+
+impl<Point: fmt::Debug> fmt::Display for PathArithmeticError<Point> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PathArithmeticError::InconsistentRayCollisions {
+                edge,
+                ray,
+                num_collisions,
+                path_crossings,
+            } => write!(
+                f,
+                "inconsistent ray collisions for edge {:?}: ray {:?} had {} collisions and path crossings {:?}",
+                edge, ray, num_collisions, path_crossings
+            ),
+
+            PathArithmeticError::ConflictingEdgeKind { edge, ray } => write!(
+                f,
+                "edge {:?} was found to be both inside and outside the path (by ray {:?})",
+                edge, ray
+            ),
+        }
+    }
+}
+
+impl<Point: fmt::Debug> Error for PathArithmeticError<Point> {}
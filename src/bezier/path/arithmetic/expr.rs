@@ -0,0 +1,161 @@
+use super::super::super::super::geo::{Coordinate, Coordinate2D};
+use super::super::graph_path::GraphPath;
+use super::super::path::{BezierPath, BezierPathFactory};
+use super::ray_cast::{PathDirection, PathLabel};
+
+use smallvec::SmallVec;
+
+///
+/// A boolean expression made up of several paths
+///
+/// Unlike `path_combine()`, which performs each operation in turn, this evaluates the whole expression in a single pass:
+/// every path in the expression is collided at once and the edges are then categorised by evaluating the expression at
+/// each point. This avoids re-colliding the intermediate results, which is both faster and more accurate.
+///
+/// Each set of paths in the expression is treated as a shape filled with the even-odd winding rule.
+///
+#[derive(Clone, Debug)]
+pub enum PathExpr<P: BezierPath> {
+    /// A set of paths
+    Path(Vec<P>),
+
+    /// The union of a set of expressions
+    Union(Vec<PathExpr<P>>),
+
+    /// The intersection of a set of expressions
+    Intersect(Vec<PathExpr<P>>),
+
+    /// The first expression with the second one subtracted from it
+    Subtract(Box<PathExpr<P>>, Box<PathExpr<P>>),
+}
+
+impl<P: BezierPath> PathExpr<P>
+where
+    P::Point: Coordinate + Coordinate2D,
+{
+    ///
+    /// Creates an expression representing a set of paths
+    ///
+    pub fn path(paths: Vec<P>) -> Self {
+        PathExpr::Path(paths)
+    }
+
+    ///
+    /// Creates an expression representing the union of a set of expressions
+    ///
+    pub fn union(exprs: Vec<PathExpr<P>>) -> Self {
+        PathExpr::Union(exprs)
+    }
+
+    ///
+    /// Creates an expression representing the intersection of a set of expressions
+    ///
+    pub fn intersect(exprs: Vec<PathExpr<P>>) -> Self {
+        PathExpr::Intersect(exprs)
+    }
+
+    ///
+    /// Creates an expression that subtracts another expression from this one
+    ///
+    pub fn subtract(self, to_subtract: PathExpr<P>) -> Self {
+        PathExpr::Subtract(Box::new(self), Box::new(to_subtract))
+    }
+
+    ///
+    /// Adds the sets of paths in this expression to a list, in the order that `is_inside()` reads them
+    ///
+    fn leaves<'a>(&'a self, leaves: &mut Vec<&'a Vec<P>>) {
+        match self {
+            PathExpr::Path(paths) => leaves.push(paths),
+            PathExpr::Union(exprs) | PathExpr::Intersect(exprs) => {
+                for expr in exprs.iter() {
+                    expr.leaves(leaves);
+                }
+            }
+            PathExpr::Subtract(expr, to_subtract) => {
+                expr.leaves(leaves);
+                to_subtract.leaves(leaves);
+            }
+        }
+    }
+
+    ///
+    /// Given the number of crossings for each set of paths, evaluates whether or not a point is inside this expression
+    ///
+    /// `next_leaf` is the index of the first set of paths in this expression, and is updated to the index after the last one.
+    ///
+    fn is_inside(&self, path_crossings: &[i32], next_leaf: &mut usize) -> bool {
+        match self {
+            PathExpr::Path(_) => {
+                let crossings = path_crossings.get(*next_leaf).copied().unwrap_or(0);
+                *next_leaf += 1;
+
+                (crossings & 1) != 0
+            }
+
+            PathExpr::Union(exprs) => {
+                // Every sub-expression must be evaluated so that next_leaf is updated
+                let inside = exprs
+                    .iter()
+                    .map(|expr| expr.is_inside(path_crossings, next_leaf))
+                    .collect::<SmallVec<[bool; 8]>>();
+
+                inside.into_iter().any(|is_inside| is_inside)
+            }
+
+            PathExpr::Intersect(exprs) => {
+                let inside = exprs
+                    .iter()
+                    .map(|expr| expr.is_inside(path_crossings, next_leaf))
+                    .collect::<SmallVec<[bool; 8]>>();
+
+                // The intersection of nothing is empty
+                !inside.is_empty() && inside.into_iter().all(|is_inside| is_inside)
+            }
+
+            PathExpr::Subtract(expr, to_subtract) => {
+                let is_inside = expr.is_inside(path_crossings, next_leaf);
+                let is_subtracted = to_subtract.is_inside(path_crossings, next_leaf);
+
+                is_inside && !is_subtracted
+            }
+        }
+    }
+
+    ///
+    /// Evaluates this expression, generating the resulting path
+    ///
+    pub fn evaluate<POut>(&self, accuracy: f64) -> Vec<POut>
+    where
+        POut: BezierPathFactory<Point = P::Point>,
+    {
+        // Each set of paths in the expression gets its own label
+        let mut leaves = vec![];
+        self.leaves(&mut leaves);
+
+        let mut merged_path = GraphPath::new();
+
+        for (path_idx, paths) in leaves.into_iter().enumerate() {
+            let path_idx = path_idx as u32;
+            merged_path = merged_path.collide(
+                GraphPath::from_merged_paths(
+                    paths
+                        .iter()
+                        .map(|path| (path, PathLabel(path_idx, PathDirection::from(path)))),
+                ),
+                accuracy,
+            );
+        }
+
+        merged_path.round(accuracy);
+
+        // Categorise the edges by evaluating the expression
+        merged_path.set_edge_kinds_by_ray_casting(|path_crossings| {
+            let mut next_leaf = 0;
+            self.is_inside(path_crossings, &mut next_leaf)
+        });
+        merged_path.heal_exterior_gaps();
+
+        merged_path.exterior_paths()
+    }
+}
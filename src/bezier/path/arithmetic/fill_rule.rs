@@ -0,0 +1,85 @@
+use super::super::super::super::geo::{Coordinate, Coordinate2D};
+use super::super::graph_path::GraphPath;
+use super::super::path::{BezierPath, BezierPathFactory};
+use super::ray_cast::{PathDirection, PathLabel};
+
+///
+/// The rule used to decide which points are inside a path made up of several subpaths
+///
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum FillRule {
+    /// A point is inside the path if a ray from it crosses an odd number of edges
+    EvenOdd,
+
+    /// A point is inside the path if the edges a ray from it crosses have a non-zero total winding direction
+    NonZero,
+}
+
+impl FillRule {
+    ///
+    /// Given the winding count of a point (the total of the directions of the edges crossed to reach it), returns whether or not
+    /// that point is inside the path
+    ///
+    #[inline]
+    pub fn is_inside(&self, winding_count: i32) -> bool {
+        match self {
+            FillRule::EvenOdd => (winding_count & 1) != 0,
+            FillRule::NonZero => winding_count != 0,
+        }
+    }
+}
+
+///
+/// Generates the path formed by adding together a set of paths, each of which is filled using its own fill rule
+///
+/// Each item in the input is a set of subpaths (for example, a single shape imported from an SVG file) along with the rule
+/// used to decide which regions of that shape are filled. The result is the union of the filled regions of all the shapes.
+/// The shapes may overlap each other or themselves.
+///
+/// With the non-zero rule, subpaths wound in opposite directions will cut holes in each other, as in most rendering engines.
+///
+pub fn path_add_with_fill_rules<P1: BezierPath, POut>(
+    paths: &[(Vec<P1>, FillRule)],
+    accuracy: f64,
+) -> Vec<POut>
+where
+    P1::Point: Coordinate + Coordinate2D,
+    POut: BezierPathFactory<Point = P1::Point>,
+{
+    let fill_rules = paths
+        .iter()
+        .map(|(_paths, fill_rule)| *fill_rule)
+        .collect::<Vec<_>>();
+
+    // Each shape gets its own path number, so its crossings are counted separately. The direction in the label flips the
+    // crossings of anticlockwise edges: the even-odd rule only needs the parity of the count, but the non-zero rule needs
+    // crossings of subpaths going in opposite directions to cancel out, so non-zero shapes leave their crossings unflipped
+    let mut merged_path = GraphPath::new();
+
+    for (path_idx, (subpaths, fill_rule)) in paths.iter().enumerate() {
+        merged_path =
+            merged_path.merge(GraphPath::from_merged_paths(subpaths.iter().map(|path| {
+                let direction = match fill_rule {
+                    FillRule::EvenOdd => PathDirection::from(path),
+                    FillRule::NonZero => PathDirection::Clockwise,
+                };
+
+                (path, PathLabel(path_idx as u32, direction))
+            })));
+    }
+
+    // Collide everything so overlaps between shapes and within shapes are both found
+    merged_path.self_collide(accuracy);
+    merged_path.round(accuracy);
+
+    // A point is in the result if it's inside any of the shapes
+    merged_path.set_edge_kinds_by_ray_casting(|path_crossings| {
+        path_crossings
+            .iter()
+            .zip(fill_rules.iter())
+            .any(|(winding_count, fill_rule)| fill_rule.is_inside(*winding_count))
+    });
+    merged_path.heal_exterior_gaps();
+
+    merged_path.exterior_paths()
+}
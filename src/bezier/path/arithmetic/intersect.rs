@@ -1,7 +1,8 @@
 use super::super::super::super::geo::{Coordinate, Coordinate2D};
 use super::super::graph_path::GraphPath;
 use super::super::path::{BezierPath, BezierPathFactory};
-use super::ray_cast::{PathDirection, PathLabel};
+use super::error::PathArithmeticError;
+use super::ray_cast::{collide_labelled_paths, PathLabel, RayCastStrategy};
 
 impl<Point: Coordinate + Coordinate2D> GraphPath<Point, PathLabel> {
     ///
@@ -13,6 +14,16 @@ impl<Point: Coordinate + Coordinate2D> GraphPath<Point, PathLabel> {
             (path_crossings[0] & 1) != 0 && (path_crossings[1] & 1) != 0
         });
     }
+
+    ///
+    /// As for `set_exterior_by_intersecting()`, but returns an error if the exterior edges can't be found reliably
+    ///
+    pub fn try_set_exterior_by_intersecting(&mut self) -> Result<(), PathArithmeticError<Point>> {
+        self.try_set_edge_kinds_by_ray_casting(
+            |path_crossings| (path_crossings[0] & 1) != 0 && (path_crossings[1] & 1) != 0,
+            &RayCastStrategy::default(),
+        )
+    }
 }
 
 ///
@@ -38,24 +49,8 @@ where
         return path1.iter().map(|path| POut::from_path(path)).collect();
     }
 
-    // Create the graph path from the source side
-    let mut merged_path = GraphPath::new();
-    merged_path = merged_path.merge(GraphPath::from_merged_paths(
-        path1
-            .iter()
-            .map(|path| (path, PathLabel(0, PathDirection::from(path)))),
-    ));
-
-    // Collide with the target side to generate a full path
-    merged_path = merged_path.collide(
-        GraphPath::from_merged_paths(
-            path2
-                .iter()
-                .map(|path| (path, PathLabel(1, PathDirection::from(path)))),
-        ),
-        accuracy,
-    );
-    merged_path.round(accuracy);
+    // Collide the two sides to generate a full path
+    let mut merged_path = collide_labelled_paths(path1, path2, accuracy);
 
     // Set the exterior edges using the 'intersect' algorithm
     merged_path.set_exterior_by_intersecting();
@@ -64,3 +59,34 @@ where
     // Produce the final result
     merged_path.exterior_paths()
 }
+
+///
+/// Generates the path formed by intersecting two sets of paths, or returns an error if the result can't be found reliably
+///
+/// This is the same as `path_intersect()`, except that a `PathArithmeticError` is returned instead of a possibly corrupted shape
+/// when the edges on the outside of the result can't be found reliably.
+///
+pub fn try_path_intersect<P1: BezierPath, P2, POut>(
+    path1: &[P1],
+    path2: &[P2],
+    accuracy: f64,
+) -> Result<Vec<POut>, PathArithmeticError<P1::Point>>
+where
+    P1::Point: Coordinate + Coordinate2D,
+    P2: BezierPath<Point = P1::Point>,
+    POut: BezierPathFactory<Point = P1::Point>,
+{
+    // If either path is empty, short-circuit by returning the other
+    if path1.is_empty() {
+        return Ok(path2.iter().map(|path| POut::from_path(path)).collect());
+    } else if path2.is_empty() {
+        return Ok(path1.iter().map(|path| POut::from_path(path)).collect());
+    }
+
+    let mut merged_path = collide_labelled_paths(path1, path2, accuracy);
+
+    merged_path.try_set_exterior_by_intersecting()?;
+    merged_path.heal_exterior_gaps();
+
+    Ok(merged_path.exterior_paths())
+}
@@ -2,16 +2,28 @@ mod add;
 mod chain;
 mod chain_add;
 mod cut;
+mod error;
+mod expr;
+mod fill_rule;
 mod full_intersect;
 mod intersect;
+mod polygon;
+mod precision;
 mod ray_cast;
+mod remove_overlaps;
 mod sub;
 
 pub use self::add::*;
 pub use self::chain::*;
 pub use self::chain_add::*;
 pub use self::cut::*;
+pub use self::error::*;
+pub use self::expr::*;
+pub use self::fill_rule::*;
 pub use self::full_intersect::*;
 pub use self::intersect::*;
+pub use self::polygon::*;
+pub use self::precision::*;
 pub use self::ray_cast::*;
+pub use self::remove_overlaps::*;
 pub use self::sub::*;
@@ -0,0 +1,379 @@
+use super::super::super::super::consts::SMALL_DISTANCE;
+use super::super::super::super::geo::{Coordinate, Coordinate2D};
+use super::super::super::curve::{BezierCurve, Curve};
+use super::super::graph_path::{straight_line_positions, GraphPath};
+use super::super::path::{BezierPath, BezierPathFactory};
+use super::super::to_curves::path_to_curves;
+use super::add::path_add;
+use super::intersect::path_intersect;
+use super::ray_cast::{PathDirection, PathLabel};
+use super::sub::path_sub;
+
+use std::cmp::Ordering;
+
+/// A set of polygons, as bezier paths
+type PolygonPaths<Point> = Vec<(Point, Vec<(Point, Point, Point)>)>;
+
+/// Two sets of polygons that have been split where they intersect
+type SplitPolygons<Point> = (PolygonPaths<Point>, PolygonPaths<Point>);
+
+///
+/// Returns true if every section of a path is a straight line (ie, the path is a polygon)
+///
+/// The control points of each section must be on the line between its end points, and between the end points, so that
+/// each section covers the line exactly once.
+///
+pub fn path_is_polygon<P: BezierPath>(path: &P) -> bool
+where
+    P::Point: Coordinate + Coordinate2D,
+{
+    path_to_curves::<_, Curve<_>>(path).all(|section| {
+        let start_point = section.start_point();
+        let (cp1, cp2) = section.control_points();
+
+        if start_point.is_near_to(&section.end_point(), SMALL_DISTANCE) {
+            // Zero-length sections are only straight if the control points are in the same place
+            start_point.is_near_to(&cp1, SMALL_DISTANCE)
+                && start_point.is_near_to(&cp2, SMALL_DISTANCE)
+        } else {
+            straight_line_positions(&section, SMALL_DISTANCE).is_some()
+        }
+    })
+}
+
+///
+/// 2D cross product of two vectors
+///
+#[inline]
+fn cross<Point: Coordinate2D>(a: &Point, b: &Point) -> f64 {
+    a.x() * b.y() - a.y() * b.x()
+}
+
+///
+/// Finds where a point lies on a line segment, if it lies strictly between the start and end points
+///
+#[inline]
+fn t_on_collinear_segment<Point: Coordinate + Coordinate2D>(
+    start: &Point,
+    end: &Point,
+    point: &Point,
+) -> Option<f64> {
+    let direction = *end - *start;
+    let len_sq = direction.dot(&direction);
+
+    if len_sq == 0.0 || cross(&direction, &(*point - *start)) != 0.0 {
+        return None;
+    }
+
+    let t = direction.dot(&(*point - *start)) / len_sq;
+
+    if t > 0.0 && t < 1.0 {
+        Some(t)
+    } else {
+        None
+    }
+}
+
+///
+/// Finds the places where the segments `a` and `b` need to be split so they meet at a shared vertex, as a list of
+/// `(t, point)` values for each segment. The same point value is always used for both segments.
+///
+fn segment_splits<Point: Coordinate + Coordinate2D>(
+    (a0, a1): (Point, Point),
+    (b0, b1): (Point, Point),
+    a_splits: &mut Vec<(f64, Point)>,
+    b_splits: &mut Vec<(f64, Point)>,
+) {
+    let a_dir = a1 - a0;
+    let b_dir = b1 - b0;
+    let offset = b0 - a0;
+    let denominator = cross(&a_dir, &b_dir);
+
+    if denominator == 0.0 {
+        // Parallel segments: if they're collinear, each one needs to be split where the other one ends
+        if cross(&offset, &a_dir) == 0.0 {
+            for point in [b0, b1] {
+                if let Some(t) = t_on_collinear_segment(&a0, &a1, &point) {
+                    a_splits.push((t, point));
+                }
+            }
+            for point in [a0, a1] {
+                if let Some(t) = t_on_collinear_segment(&b0, &b1, &point) {
+                    b_splits.push((t, point));
+                }
+            }
+        }
+
+        return;
+    }
+
+    let a_t = cross(&offset, &b_dir) / denominator;
+    let b_t = cross(&offset, &a_dir) / denominator;
+
+    if !(0.0..=1.0).contains(&a_t) || !(0.0..=1.0).contains(&b_t) {
+        return;
+    }
+
+    // Re-use an existing vertex wherever possible so no new coordinates are introduced
+    let a_is_interior = a_t > 0.0 && a_t < 1.0;
+    let b_is_interior = b_t > 0.0 && b_t < 1.0;
+
+    let point = if !b_is_interior {
+        if b_t == 0.0 {
+            b0
+        } else {
+            b1
+        }
+    } else if !a_is_interior {
+        if a_t == 0.0 {
+            a0
+        } else {
+            a1
+        }
+    } else {
+        a0 + a_dir * a_t
+    };
+
+    if a_is_interior {
+        a_splits.push((a_t, point));
+    }
+    if b_is_interior {
+        b_splits.push((b_t, point));
+    }
+}
+
+///
+/// Splits the polygons in two sets wherever their edges cross so that all of the intersections are at shared vertices
+///
+fn split_polygons<Point: Coordinate + Coordinate2D>(
+    polygons1: &mut [Vec<Point>],
+    polygons2: &mut [Vec<Point>],
+) {
+    // Generate the splits for each segment of each polygon
+    let mut splits1 = polygons1
+        .iter()
+        .map(|polygon| vec![vec![]; polygon.len().saturating_sub(1)])
+        .collect::<Vec<_>>();
+    let mut splits2 = polygons2
+        .iter()
+        .map(|polygon| vec![vec![]; polygon.len().saturating_sub(1)])
+        .collect::<Vec<_>>();
+
+    for (poly1_idx, polygon1) in polygons1.iter().enumerate() {
+        for seg1_idx in 0..polygon1.len().saturating_sub(1) {
+            let segment1 = (polygon1[seg1_idx], polygon1[seg1_idx + 1]);
+
+            for (poly2_idx, polygon2) in polygons2.iter().enumerate() {
+                for seg2_idx in 0..polygon2.len().saturating_sub(1) {
+                    let segment2 = (polygon2[seg2_idx], polygon2[seg2_idx + 1]);
+
+                    segment_splits(
+                        segment1,
+                        segment2,
+                        &mut splits1[poly1_idx][seg1_idx],
+                        &mut splits2[poly2_idx][seg2_idx],
+                    );
+                }
+            }
+        }
+    }
+
+    // Rebuild the polygons with the new vertices
+    fn apply_splits<Point: Coordinate>(
+        polygons: &mut [Vec<Point>],
+        splits: Vec<Vec<Vec<(f64, Point)>>>,
+    ) {
+        for (polygon, polygon_splits) in polygons.iter_mut().zip(splits) {
+            if polygon.is_empty() {
+                continue;
+            }
+
+            let mut new_polygon = vec![polygon[0]];
+
+            for (seg_idx, mut segment_splits) in polygon_splits.into_iter().enumerate() {
+                segment_splits
+                    .sort_by(|(t1, _), (t2, _)| t1.partial_cmp(t2).unwrap_or(Ordering::Equal));
+
+                for (_t, point) in segment_splits {
+                    if new_polygon[new_polygon.len() - 1] != point {
+                        new_polygon.push(point);
+                    }
+                }
+
+                new_polygon.push(polygon[seg_idx + 1]);
+            }
+
+            *polygon = new_polygon;
+        }
+    }
+
+    apply_splits(polygons1, splits1);
+    apply_splits(polygons2, splits2);
+}
+
+///
+/// Returns the vertices of a polygonal path
+///
+fn polygon_vertices<P: BezierPath>(path: &P) -> Vec<P::Point> {
+    let mut vertices = vec![path.start_point()];
+    vertices.extend(path.points().map(|(_cp1, _cp2, end_point)| end_point));
+
+    vertices
+}
+
+///
+/// Converts a list of polygon vertices back to a bezier path, with straight lines between each vertex
+///
+fn polygon_to_path<Point: Coordinate + Coordinate2D>(
+    vertices: &[Point],
+) -> (Point, Vec<(Point, Point, Point)>) {
+    let sections = vertices
+        .windows(2)
+        .map(|segment| {
+            let (start, end) = (segment[0], segment[1]);
+            let direction = end - start;
+
+            (
+                start + direction * (1.0 / 3.0),
+                start + direction * (2.0 / 3.0),
+                end,
+            )
+        })
+        .collect();
+
+    (vertices[0], sections)
+}
+
+///
+/// If both sets of paths are non-empty sets of polygons, splits them at the points where they intersect, returning `None`
+/// otherwise
+///
+fn prepare_polygons<P1: BezierPath, P2: BezierPath<Point = P1::Point>>(
+    path1: &[P1],
+    path2: &[P2],
+) -> Option<SplitPolygons<P1::Point>>
+where
+    P1::Point: Coordinate + Coordinate2D,
+{
+    if path1.is_empty() || path2.is_empty() {
+        return None;
+    }
+    if !path1.iter().all(path_is_polygon) || !path2.iter().all(path_is_polygon) {
+        return None;
+    }
+
+    let mut polygons1 = path1.iter().map(polygon_vertices).collect::<Vec<_>>();
+    let mut polygons2 = path2.iter().map(polygon_vertices).collect::<Vec<_>>();
+
+    split_polygons(&mut polygons1, &mut polygons2);
+
+    Some((
+        polygons1
+            .iter()
+            .map(|polygon| polygon_to_path(polygon))
+            .collect(),
+        polygons2
+            .iter()
+            .map(|polygon| polygon_to_path(polygon))
+            .collect(),
+    ))
+}
+
+///
+/// Builds the graph for two sets of polygons that have been split where they intersect
+///
+/// Every place where the polygons cross is already a shared vertex, so unlike `GraphPath::collide()` there's no need to
+/// search for intersections between the edges: joining up the vertices that are in the same place is enough. The points
+/// are not rounded, so the vertices keep their exact positions.
+///
+fn polygon_graph_path<Point: Coordinate + Coordinate2D>(
+    (polygons1, polygons2): SplitPolygons<Point>,
+    accuracy: f64,
+) -> GraphPath<Point, PathLabel> {
+    let labelled_polygons = |polygons: &PolygonPaths<Point>, path_set| {
+        GraphPath::from_merged_paths(
+            polygons
+                .iter()
+                .map(|polygon| (polygon, PathLabel(path_set, PathDirection::from(polygon)))),
+        )
+    };
+
+    let mut merged_path = labelled_polygons(&polygons1, 0).merge(labelled_polygons(&polygons2, 1));
+    merged_path.combine_overlapping_points(accuracy);
+    merged_path.remove_all_very_short_edges();
+
+    merged_path
+}
+
+///
+/// Adds two sets of paths, using exact segment intersections if both sets of paths are polygons
+///
+/// The generic bezier intersection algorithm is approximate, which can leave thin slivers behind where two polygons meet.
+/// This works out the intersections between polygon edges exactly and splits the edges there, so that the edges of both
+/// paths meet at exactly the same vertex, and then skips the search for intersections that `path_add()` has to perform.
+/// If either path is not a polygon, this is the same as `path_add()`.
+///
+pub fn polygon_add<P1: BezierPath, P2, POut>(path1: &[P1], path2: &[P2], accuracy: f64) -> Vec<POut>
+where
+    P1::Point: Coordinate + Coordinate2D,
+    P2: BezierPath<Point = P1::Point>,
+    POut: BezierPathFactory<Point = P1::Point>,
+{
+    if let Some(polygons) = prepare_polygons(path1, path2) {
+        let mut merged_path = polygon_graph_path(polygons, accuracy);
+
+        merged_path.set_exterior_by_adding();
+        merged_path.heal_exterior_gaps();
+        merged_path.exterior_paths()
+    } else {
+        path_add(path1, path2, accuracy)
+    }
+}
+
+///
+/// Subtracts one set of paths from another, using exact segment intersections if both sets of paths are polygons
+///
+/// If either path is not a polygon, this is the same as `path_sub()`.
+///
+pub fn polygon_sub<P1: BezierPath, P2, POut>(path1: &[P1], path2: &[P2], accuracy: f64) -> Vec<POut>
+where
+    P1::Point: Coordinate + Coordinate2D,
+    P2: BezierPath<Point = P1::Point>,
+    POut: BezierPathFactory<Point = P1::Point>,
+{
+    if let Some(polygons) = prepare_polygons(path1, path2) {
+        let mut merged_path = polygon_graph_path(polygons, accuracy);
+
+        merged_path.set_exterior_by_subtracting();
+        merged_path.heal_exterior_gaps();
+        merged_path.exterior_paths()
+    } else {
+        path_sub(path1, path2, accuracy)
+    }
+}
+
+///
+/// Intersects two sets of paths, using exact segment intersections if both sets of paths are polygons
+///
+/// If either path is not a polygon, this is the same as `path_intersect()`.
+///
+pub fn polygon_intersect<P1: BezierPath, P2, POut>(
+    path1: &[P1],
+    path2: &[P2],
+    accuracy: f64,
+) -> Vec<POut>
+where
+    P1::Point: Coordinate + Coordinate2D,
+    P2: BezierPath<Point = P1::Point>,
+    POut: BezierPathFactory<Point = P1::Point>,
+{
+    if let Some(polygons) = prepare_polygons(path1, path2) {
+        let mut merged_path = polygon_graph_path(polygons, accuracy);
+
+        merged_path.set_exterior_by_intersecting();
+        merged_path.heal_exterior_gaps();
+        merged_path.exterior_paths()
+    } else {
+        path_intersect(path1, path2, accuracy)
+    }
+}
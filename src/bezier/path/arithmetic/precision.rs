@@ -0,0 +1,333 @@
+use super::super::super::super::consts::CLOSE_DISTANCE;
+use super::super::super::super::geo::{
+    BoundingBox, Bounds, Coordinate, Coordinate2D, Transform2D, Transformable,
+};
+use super::super::graph_path::GraphPath;
+use super::super::path::{BezierPath, BezierPathFactory};
+use super::add::{path_add, path_remove_interior_points, path_remove_overlapped_points};
+use super::intersect::path_intersect;
+use super::sub::path_sub;
+
+/// The size of the paths that the default precision is intended for
+const DEFAULT_PRECISION_SIZE: f64 = 1000.0;
+
+///
+/// The tolerances to use when performing path arithmetic
+///
+/// The path arithmetic functions use a few fixed distances internally, which suit paths measured in units about the size of
+/// a pixel: for example, points closer together than `CLOSE_DISTANCE` (0.01 units) are snapped together. These don't work
+/// well for paths measured in other units, say microns or thousands of pixels. A precision context describes the distances
+/// to use instead.
+///
+/// The `accuracy` is the maximum error allowed when finding intersections, and is the value that's passed as the accuracy to
+/// functions like `path_add()`. The `snap_distance` is the distance at which points are considered to be at the same place:
+/// all of the other internal distances are changed in proportion to it. This works by scaling the paths so the snap distance
+/// becomes `CLOSE_DISTANCE`, performing the operation and then scaling the result back again.
+///
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct PrecisionContext {
+    /// The maximum error allowed when finding intersections
+    pub accuracy: f64,
+
+    /// The distance at which points are snapped together
+    pub snap_distance: f64,
+}
+
+impl Default for PrecisionContext {
+    ///
+    /// The precision used by the path arithmetic functions when no context is supplied (an accuracy of 0.01)
+    ///
+    fn default() -> Self {
+        PrecisionContext {
+            accuracy: 0.01,
+            snap_distance: CLOSE_DISTANCE,
+        }
+    }
+}
+
+impl PrecisionContext {
+    ///
+    /// Creates a new precision context
+    ///
+    pub fn new(accuracy: f64, snap_distance: f64) -> Self {
+        PrecisionContext {
+            accuracy,
+            snap_distance,
+        }
+    }
+
+    ///
+    /// Chooses a precision for paths that fit within a particular bounding box
+    ///
+    /// The default precision is suitable for paths around 1000 units across: this scales it in proportion to the largest side
+    /// of the bounding box, so shapes in the range 0..1 are treated the same way as the same shapes in the range 0..1000.
+    /// The default precision is returned if the bounding box is empty.
+    ///
+    pub fn for_bounds<B: BoundingBox>(bounds: &B) -> Self
+    where
+        B::Point: Coordinate2D,
+    {
+        let extent = bounds.extent();
+        let size = extent.x().abs().max(extent.y().abs());
+
+        if size > 0.0 && size.is_finite() {
+            PrecisionContext::default().scaled(size / DEFAULT_PRECISION_SIZE)
+        } else {
+            PrecisionContext::default()
+        }
+    }
+
+    ///
+    /// Chooses a precision for a set of paths, from their bounding box
+    ///
+    /// See `for_bounds()` for details. To choose a precision for two sets of paths that are going to be combined, use the
+    /// union of their bounding boxes.
+    ///
+    pub fn for_paths<P: BezierPath>(paths: &[P]) -> Self
+    where
+        P::Point: Coordinate2D,
+    {
+        let bounds = Bounds::union_all(paths.iter().map(|path| path.bounding_box::<Bounds<_>>()));
+
+        Self::for_bounds(&bounds)
+    }
+
+    ///
+    /// Returns a precision context with all of its distances multiplied by a scale factor
+    ///
+    /// For example, `PrecisionContext::default().scaled(0.001)` suits paths measured in units a thousand times larger
+    /// than a pixel.
+    ///
+    pub fn scaled(&self, scale: f64) -> Self {
+        PrecisionContext {
+            accuracy: self.accuracy * scale,
+            snap_distance: self.snap_distance * scale,
+        }
+    }
+
+    ///
+    /// The factor that paths are scaled by to turn them into the coordinates used internally by the path arithmetic
+    /// functions
+    ///
+    #[inline]
+    fn internal_scale(&self) -> f64 {
+        CLOSE_DISTANCE / self.snap_distance
+    }
+
+    ///
+    /// The accuracy to pass to the path arithmetic functions after the paths have been scaled
+    ///
+    #[inline]
+    fn internal_accuracy(&self) -> f64 {
+        self.accuracy * self.internal_scale()
+    }
+}
+
+/// The type of path used while performing an operation in internal coordinates
+type ScaledPath<Point> = (Point, Vec<(Point, Point, Point)>);
+
+///
+/// Scales a path about the origin
+///
+fn scale_path<P: BezierPath, POut: BezierPathFactory<Point = P::Point>>(
+    path: &P,
+    scale: f64,
+) -> POut {
+    POut::from_points(
+        path.start_point() * scale,
+        path.points()
+            .map(|(cp1, cp2, end_point)| (cp1 * scale, cp2 * scale, end_point * scale)),
+    )
+}
+
+///
+/// Scales a set of paths into internal coordinates, performs an operation on them and scales the result back again
+///
+fn with_internal_scale<P1, P2, POut, OpFn>(
+    precision: &PrecisionContext,
+    path1: &[P1],
+    path2: &[P2],
+    operation: OpFn,
+) -> Vec<POut>
+where
+    P1: BezierPath,
+    P1::Point: Coordinate + Coordinate2D,
+    P2: BezierPath<Point = P1::Point>,
+    POut: BezierPathFactory<Point = P1::Point>,
+    OpFn: FnOnce(
+        &[ScaledPath<P1::Point>],
+        &[ScaledPath<P1::Point>],
+        f64,
+    ) -> Vec<ScaledPath<P1::Point>>,
+{
+    let scale = precision.internal_scale();
+    let path1 = path1
+        .iter()
+        .map(|path| scale_path(path, scale))
+        .collect::<Vec<_>>();
+    let path2 = path2
+        .iter()
+        .map(|path| scale_path(path, scale))
+        .collect::<Vec<_>>();
+
+    operation(&path1, &path2, precision.internal_accuracy())
+        .iter()
+        .map(|path| scale_path(path, 1.0 / scale))
+        .collect()
+}
+
+///
+/// Generates the path formed by adding two sets of paths, using a particular precision
+///
+/// See `path_add()` for details of the operation and `PrecisionContext` for details of how the precision is chosen.
+///
+pub fn path_add_with_precision<P1, P2, POut>(
+    path1: &[P1],
+    path2: &[P2],
+    precision: &PrecisionContext,
+) -> Vec<POut>
+where
+    P1: BezierPath,
+    P1::Point: Coordinate + Coordinate2D,
+    P2: BezierPath<Point = P1::Point>,
+    POut: BezierPathFactory<Point = P1::Point>,
+{
+    with_internal_scale(precision, path1, path2, |path1, path2, accuracy| {
+        path_add(path1, path2, accuracy)
+    })
+}
+
+///
+/// Generates the path formed by subtracting two sets of paths, using a particular precision
+///
+/// See `path_sub()` for details of the operation and `PrecisionContext` for details of how the precision is chosen.
+///
+pub fn path_sub_with_precision<P1, P2, POut>(
+    path1: &[P1],
+    path2: &[P2],
+    precision: &PrecisionContext,
+) -> Vec<POut>
+where
+    P1: BezierPath,
+    P1::Point: Coordinate + Coordinate2D,
+    P2: BezierPath<Point = P1::Point>,
+    POut: BezierPathFactory<Point = P1::Point>,
+{
+    with_internal_scale(precision, path1, path2, |path1, path2, accuracy| {
+        path_sub(path1, path2, accuracy)
+    })
+}
+
+///
+/// Generates the path formed by intersecting two sets of paths, using a particular precision
+///
+/// See `path_intersect()` for details of the operation and `PrecisionContext` for details of how the precision is chosen.
+///
+pub fn path_intersect_with_precision<P1, P2, POut>(
+    path1: &[P1],
+    path2: &[P2],
+    precision: &PrecisionContext,
+) -> Vec<POut>
+where
+    P1: BezierPath,
+    P1::Point: Coordinate + Coordinate2D,
+    P2: BezierPath<Point = P1::Point>,
+    POut: BezierPathFactory<Point = P1::Point>,
+{
+    with_internal_scale(precision, path1, path2, |path1, path2, accuracy| {
+        path_intersect(path1, path2, accuracy)
+    })
+}
+
+///
+/// Removes any interior points from a path, using a particular precision
+///
+/// See `path_remove_interior_points()` for details of the operation and `PrecisionContext` for details of how the
+/// precision is chosen.
+///
+pub fn path_remove_interior_points_with_precision<P1, POut>(
+    path: &[P1],
+    precision: &PrecisionContext,
+) -> Vec<POut>
+where
+    P1: BezierPath,
+    P1::Point: Coordinate + Coordinate2D,
+    POut: BezierPathFactory<Point = P1::Point>,
+{
+    with_internal_scale::<_, P1, _, _>(precision, path, &[], |path, _, accuracy| {
+        path_remove_interior_points(path, accuracy)
+    })
+}
+
+///
+/// Removes any overlapped points from a path, using a particular precision
+///
+/// See `path_remove_overlapped_points()` for details of the operation and `PrecisionContext` for details of how the
+/// precision is chosen.
+///
+pub fn path_remove_overlapped_points_with_precision<P1, POut>(
+    path: &[P1],
+    precision: &PrecisionContext,
+) -> Vec<POut>
+where
+    P1: BezierPath,
+    P1::Point: Coordinate + Coordinate2D,
+    POut: BezierPathFactory<Point = P1::Point>,
+{
+    with_internal_scale::<_, P1, _, _>(precision, path, &[], |path, _, accuracy| {
+        path_remove_overlapped_points(path, accuracy)
+    })
+}
+
+impl<Point: Coordinate + Coordinate2D, Label: Copy> GraphPath<Point, Label> {
+    ///
+    /// Creates a graph path from a bezier path, using a particular precision
+    ///
+    /// See `from_path()` for details of the operation and `PrecisionContext` for details of how the precision is chosen.
+    ///
+    pub fn from_path_with_precision<P: BezierPath<Point = Point>>(
+        path: &P,
+        label: Label,
+        precision: &PrecisionContext,
+    ) -> Self {
+        let scale = precision.internal_scale();
+        let from_internal = Transform2D::scale(1.0 / scale, 1.0 / scale);
+        let path = scale_path::<_, ScaledPath<Point>>(path, scale);
+
+        GraphPath::from_path(&path, label).transform(&from_internal)
+    }
+
+    ///
+    /// Collides this path against another using a particular precision
+    ///
+    /// See `collide()` for details of the operation and `PrecisionContext` for details of how the precision is chosen.
+    ///
+    pub fn collide_with_precision(self, collide_path: Self, precision: &PrecisionContext) -> Self {
+        let scale = precision.internal_scale();
+        let to_internal = Transform2D::scale(scale, scale);
+        let from_internal = Transform2D::scale(1.0 / scale, 1.0 / scale);
+
+        self.transform(&to_internal)
+            .collide(
+                collide_path.transform(&to_internal),
+                precision.internal_accuracy(),
+            )
+            .transform(&from_internal)
+    }
+
+    ///
+    /// Finds any collisions between existing points in the graph path using a particular precision
+    ///
+    /// See `self_collide()` for details of the operation and `PrecisionContext` for details of how the precision is chosen.
+    ///
+    pub fn self_collide_with_precision(&mut self, precision: &PrecisionContext) {
+        let scale = precision.internal_scale();
+        let to_internal = Transform2D::scale(scale, scale);
+        let from_internal = Transform2D::scale(1.0 / scale, 1.0 / scale);
+
+        let mut internal = self.transform(&to_internal);
+        internal.self_collide(precision.internal_accuracy());
+
+        *self = internal.transform(&from_internal);
+    }
+}
@@ -4,6 +4,10 @@ use super::super::super::normal::NormalCurve;
 use super::super::graph_path::{GraphPath, GraphPathEdgeKind, GraphRayCollision};
 use super::super::is_clockwise::PathWithIsClockwise;
 use super::super::path::BezierPath;
+use super::super::progress::{NoProgressMonitor, OperationCancelled, ProgressMonitor};
+use super::error::PathArithmeticError;
+#[cfg(feature = "instrumentation")]
+use crate::debug::GraphPathEvent;
 use crate::line::Line;
 
 use smallvec::{smallvec, SmallVec};
@@ -36,10 +40,126 @@ where
 ///
 /// The parameters are the path number (counting from 0) and the winding direction of the path
 ///
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, PartialEq, Debug)]
 pub struct PathLabel(pub u32, pub PathDirection);
 
-impl<Point: Coordinate + Coordinate2D> GraphPath<Point, PathLabel> {
+///
+/// Where a section of the result of a path arithmetic operation came from
+///
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+pub struct PathSectionSource {
+    /// The set of paths that the section came from: 0 for the first set passed to the operation and 1 for the second (as for `PathLabel`)
+    pub path_set: u32,
+
+    /// The index of the path within its set
+    pub path_idx: usize,
+
+    /// The index of the section within the path (a path that doesn't end at its start point is closed with a straight line, which has the index after the last section)
+    pub section_idx: usize,
+}
+
+///
+/// Trait implemented by the labels of graph paths that can be classified by ray casting
+///
+/// Ray casting only needs to know which path each edge came from and the direction of that path, so the edges can have
+/// labels that carry more information through an arithmetic operation, provided they contain a `PathLabel`.
+///
+pub trait HasPathLabel: Copy {
+    /// The path number and direction of the path that this edge came from
+    fn path_label(&self) -> PathLabel;
+}
+
+impl HasPathLabel for PathLabel {
+    #[inline]
+    fn path_label(&self) -> PathLabel {
+        *self
+    }
+}
+
+impl HasPathLabel for (PathLabel, PathSectionSource) {
+    #[inline]
+    fn path_label(&self) -> PathLabel {
+        self.0
+    }
+}
+
+///
+/// The rays to try when classifying the edges of a `GraphPath` by ray casting
+///
+/// Each edge is classified by casting a ray through it and counting how many times the ray crosses the paths before it
+/// reaches the edge. If the collisions for a ray are inconsistent (usually because the ray grazes one of the edges it
+/// crosses, or passes exactly through a point where several edges meet), the result could be wrong, so the next ray in
+/// the strategy is tried instead. The rays are always tried in the same order, which makes the results reproducible.
+///
+#[derive(Clone, PartialEq, Debug)]
+pub struct RayCastStrategy {
+    /// The rays to try, as the t value of the point on the edge that the ray is aimed at and the angle of the ray relative
+    /// to the normal of the edge at that point (in radians)
+    rays: Vec<(f64, f64)>,
+}
+
+impl RayCastStrategy {
+    /// Rays that cross an edge closer to parallel than this (as the cosine of the angle between the ray and the edge's
+    /// normal) are considered to be grazing it
+    const MIN_COS_ANGLE: f64 = 0.01;
+
+    ///
+    /// Creates a strategy that tries a particular sequence of rays
+    ///
+    /// Each ray is described by the t value of the point on the edge that it's aimed at and its angle relative to the normal
+    /// of the edge at that point, in radians. If none of the rays give a reliable result, the first ray is used.
+    ///
+    pub fn new<RayIter: IntoIterator<Item = (f64, f64)>>(rays: RayIter) -> Self {
+        let rays = rays
+            .into_iter()
+            .map(|(t, angle)| (t.clamp(0.0, 1.0), angle))
+            .collect::<Vec<_>>();
+
+        if rays.is_empty() {
+            Self::single_ray()
+        } else {
+            RayCastStrategy { rays }
+        }
+    }
+
+    ///
+    /// Creates a strategy that only casts a single ray along the normal at the midpoint of each edge
+    ///
+    pub fn single_ray() -> Self {
+        RayCastStrategy {
+            rays: vec![(0.5, 0.0)],
+        }
+    }
+
+    ///
+    /// The rays that are tried by this strategy, as `(t, angle)` pairs
+    ///
+    pub fn rays(&self) -> impl '_ + Iterator<Item = (f64, f64)> {
+        self.rays.iter().cloned()
+    }
+}
+
+impl Default for RayCastStrategy {
+    ///
+    /// The default strategy starts at the midpoint of each edge, then tries points either side of it, and then tries
+    /// rays at an angle to the normal
+    ///
+    fn default() -> Self {
+        RayCastStrategy {
+            rays: vec![
+                (0.5, 0.0),
+                (0.35, 0.0),
+                (0.65, 0.0),
+                (0.5, 0.3),
+                (0.5, -0.3),
+                (0.42, 0.6),
+                (0.58, -0.6),
+            ],
+        }
+    }
+}
+
+impl<Point: Coordinate + Coordinate2D, Label: HasPathLabel> GraphPath<Point, Label> {
     ///
     /// Returns the ray collisions with an ordering algorithm applied so that the rays enters and exits sets of overlapping edges
     /// in a consistent order.
@@ -53,6 +173,18 @@ impl<Point: Coordinate + Coordinate2D> GraphPath<Point, PathLabel> {
         // There should always be an even number of collisions on a particular ray cast through a closed shape
         test_assert!((collisions.len() & 1) == 0);
 
+        self.order_overlapping_collisions(&mut collisions);
+
+        collisions
+    }
+
+    ///
+    /// Orders the collisions at the same point on a ray so that the first shape is outermost
+    ///
+    fn order_overlapping_collisions(
+        &self,
+        collisions: &mut [(GraphRayCollision, f64, f64, Point)],
+    ) {
         // For collisions that overlap, ensure that the first shape is outermost so that subtractions work (swap based on the direction)
         // This interacts with the ordering chosen in ray_collisions: if that ordering changes this may no longer be correct
         if !collisions.is_empty() {
@@ -71,7 +203,8 @@ impl<Point: Coordinate + Coordinate2D> GraphPath<Point, PathLabel> {
                         } else {
                             edge_b
                         };
-                        let PathLabel(_, edge_direction) = self.edge_label(earlier_edge);
+                        let PathLabel(_, edge_direction) =
+                            self.edge_label(earlier_edge).path_label();
 
                         if edge_direction == PathDirection::Anticlockwise {
                             collisions.swap(collision_idx, collision_idx + 1);
@@ -80,8 +213,102 @@ impl<Point: Coordinate + Coordinate2D> GraphPath<Point, PathLabel> {
                 }
             }
         }
+    }
 
+    ///
+    /// Works out which side of each edge a ray crosses from, for each of its collisions
+    ///
+    /// The result is (path number, side) for each collision, where the side is -1 or 1 depending on whether or not the ray
+    /// is entering or leaving the path (or 0 if it's parallel to the edge)
+    ///
+    fn collision_sides(
+        &self,
+        collisions: &[(GraphRayCollision, f64, f64, Point)],
+        ray_direction: &Point,
+    ) -> Vec<(u32, i32)> {
         collisions
+            .iter()
+            .map(|(collision, curve_t, _line_t, _pos)| {
+                let PathLabel(path_number, direction) =
+                    self.edge_label(collision.edge()).path_label();
+
+                // The relative direction of the tangent to the ray indicates the direction we're crossing in
+                let normal = self.get_edge(collision.edge()).normal_at_pos(*curve_t);
+
+                let side = ray_direction.dot(&normal).signum() as i32;
+                let side = match direction {
+                    PathDirection::Clockwise => side,
+                    PathDirection::Anticlockwise => -side,
+                };
+
+                (path_number, side)
+            })
+            .collect()
+    }
+
+    ///
+    /// Finds the number of times a ray enters each path minus the number of times it leaves it
+    ///
+    fn ray_path_crossings(
+        &self,
+        collisions: &[(GraphRayCollision, f64, f64, Point)],
+        ray_direction: &Point,
+    ) -> SmallVec<[i32; 8]> {
+        let mut path_crossings: SmallVec<[i32; 8]> = smallvec![];
+        for (path_number, side) in self.collision_sides(collisions, ray_direction) {
+            while path_crossings.len() <= path_number as usize {
+                path_crossings.push(0);
+            }
+            path_crossings[path_number as usize] += side;
+        }
+
+        path_crossings
+    }
+
+    ///
+    /// True if the collisions for a ray are consistent: that is, it has an even number of collisions and it leaves every
+    /// path as many times as it enters it
+    ///
+    fn ray_is_consistent(
+        &self,
+        collisions: &[(GraphRayCollision, f64, f64, Point)],
+        ray_direction: &Point,
+    ) -> bool {
+        (collisions.len() & 1) == 0
+            && self
+                .ray_path_crossings(collisions, ray_direction)
+                .iter()
+                .all(|crossing_count| *crossing_count == 0)
+    }
+
+    ///
+    /// True if the collisions for a ray are consistent enough to use for classifying edges
+    ///
+    /// A ray is unreliable if it has an odd number of collisions, if it doesn't leave every path as many times as it enters
+    /// it, or if it grazes any of the edges it crosses (where a small change in the path could change the result).
+    ///
+    fn ray_is_reliable(
+        &self,
+        collisions: &[(GraphRayCollision, f64, f64, Point)],
+        ray_direction: &Point,
+    ) -> bool {
+        if !self.ray_is_consistent(collisions, ray_direction) {
+            return false;
+        }
+
+        // The ray should cross each edge cleanly rather than running along it
+        let ray_direction = ray_direction.to_unit_vector();
+        collisions
+            .iter()
+            .filter(|(collision, _, _, _)| !collision.is_intersection())
+            .all(|(collision, curve_t, _, _)| {
+                let normal = self.get_edge(collision.edge()).normal_at_pos(*curve_t);
+                if normal.magnitude() <= 0.0 {
+                    return false;
+                }
+
+                ray_direction.dot(&normal.to_unit_vector()).abs() >= RayCastStrategy::MIN_COS_ANGLE
+            })
     }
 
     ///
@@ -91,10 +318,60 @@ impl<Point: Coordinate + Coordinate2D> GraphPath<Point, PathLabel> {
     /// path 1 and path 2. It should return true if this number of crossings represents a point inside the final shape, or false
     /// if it represents a point outside of the shape.
     ///
+    /// This uses the default `RayCastStrategy`, which tries some alternative rays when the first ray cast at an edge
+    /// produces inconsistent results.
+    ///
     pub fn set_edge_kinds_by_ray_casting<FnIsInside: Fn(&SmallVec<[i32; 8]>) -> bool>(
         &mut self,
         is_inside: FnIsInside,
     ) {
+        self.set_edge_kinds_by_ray_casting_with_strategy(is_inside, &RayCastStrategy::default())
+    }
+
+    ///
+    /// Sets the edge kinds by performing ray casting, using a particular strategy for choosing the rays
+    ///
+    /// This is the same as `set_edge_kinds_by_ray_casting()`, except the rays that are tried when the results of a ray are
+    /// unreliable (because it hits the path at a grazing angle or otherwise gets an inconsistent set of collisions) are
+    /// taken from the supplied strategy. As the rays are always tried in the same order, the results are reproducible.
+    ///
+    pub fn set_edge_kinds_by_ray_casting_with_strategy<
+        FnIsInside: Fn(&SmallVec<[i32; 8]>) -> bool,
+    >(
+        &mut self,
+        is_inside: FnIsInside,
+        strategy: &RayCastStrategy,
+    ) {
+        // Errors are only returned in strict mode
+        let _ = self.classify_edges_by_ray_casting(is_inside, strategy, false);
+    }
+
+    ///
+    /// Sets the edge kinds by performing ray casting, returning an error if the edges can't be classified reliably
+    ///
+    /// This works like `set_edge_kinds_by_ray_casting_with_strategy()`, except that instead of making a best guess when
+    /// none of the rays cast at an edge has a consistent set of collisions, or when a ray finds an edge that has already
+    /// been classified to be on the other side of the path, this stops and returns the details of the problem. The edge
+    /// kinds are left partially set when this returns an error.
+    ///
+    pub fn try_set_edge_kinds_by_ray_casting<FnIsInside: Fn(&SmallVec<[i32; 8]>) -> bool>(
+        &mut self,
+        is_inside: FnIsInside,
+        strategy: &RayCastStrategy,
+    ) -> Result<(), PathArithmeticError<Point>> {
+        self.classify_edges_by_ray_casting(is_inside, strategy, true)
+    }
+
+    ///
+    /// Implementation of the ray casting algorithm: in strict mode this stops with an error when the results are
+    /// inconsistent, otherwise it tries to make the best of what it's got
+    ///
+    fn classify_edges_by_ray_casting<FnIsInside: Fn(&SmallVec<[i32; 8]>) -> bool>(
+        &mut self,
+        is_inside: FnIsInside,
+        strategy: &RayCastStrategy,
+        strict: bool,
+    ) -> Result<(), PathArithmeticError<Point>> {
         for point_idx in 0..self.num_points() {
             for next_edge in self.edge_refs_for_point(point_idx) {
                 // Only process edges that have not yet been categorised
@@ -102,71 +379,88 @@ impl<Point: Coordinate + Coordinate2D> GraphPath<Point, PathLabel> {
                     continue;
                 }
 
-                // Cast a ray at this edge
-                let real_edge = self.get_edge(next_edge);
-                let next_point = real_edge.point_at_pos(0.5);
-                let next_normal = real_edge.normal_at_pos(0.5);
-
                 // Mark the next edge as visited (this prevents an infinite loop in the event the edge we're aiming at has a length of 0 and thus will always be an intersection)
                 self.set_edge_kind(next_edge, GraphPathEdgeKind::Visited);
 
-                // The 'total direction' indicates how often we've crossed an edge moving in a particular direction
-                // We're inside the path when it's non-zero
-                let mut path_crossings: SmallVec<[i32; 8]> = smallvec![0, 0];
+                // Try the rays from the strategy in turn until one gives a reliable result (falling back to the first
+                // consistent ray, and then to the first ray if none of them are consistent)
+                let real_edge = self.get_edge(next_edge);
+                let mut first_ray = None;
+                let mut consistent_ray = None;
+                let mut reliable_ray = None;
+
+                for (t, angle) in strategy.rays() {
+                    let next_point = real_edge.point_at_pos(t);
+                    let next_normal = real_edge.normal_at_pos(t);
+                    let (sin, cos) = angle.sin_cos();
+                    let ray_normal = Point::from_components(&[
+                        next_normal.x() * cos - next_normal.y() * sin,
+                        next_normal.x() * sin + next_normal.y() * cos,
+                    ]);
+
+                    // Cast a ray at the target edge
+                    let ray = (next_point - ray_normal, next_point);
+                    let ray_direction = ray.1 - ray.0;
+                    let mut collisions = self.ray_collisions(&ray);
+                    self.order_overlapping_collisions(&mut collisions);
+
+                    let has_direction = ray_direction.magnitude() > 0.0;
+
+                    if has_direction && self.ray_is_reliable(&collisions, &ray_direction) {
+                        reliable_ray = Some((ray, collisions));
+                        break;
+                    } else if consistent_ray.is_none()
+                        && has_direction
+                        && self.ray_is_consistent(&collisions, &ray_direction)
+                    {
+                        consistent_ray = Some((ray, collisions));
+                    } else if first_ray.is_none() {
+                        first_ray = Some((ray, collisions));
+                    }
+                }
+
+                let (ray, collisions) = match reliable_ray.or(consistent_ray) {
+                    Some(ray) => ray,
+
+                    None => match first_ray {
+                        Some((ray, collisions)) if strict => {
+                            return Err(PathArithmeticError::InconsistentRayCollisions {
+                                edge: next_edge,
+                                ray,
+                                num_collisions: collisions.len(),
+                                path_crossings: self
+                                    .ray_path_crossings(&collisions, &(ray.1 - ray.0))
+                                    .into_vec(),
+                            });
+                        }
 
-                // Cast a ray at the target edge
-                let ray = (next_point - next_normal, next_point);
+                        Some(ray) => ray,
+                        None => continue,
+                    },
+                };
                 let ray_direction = ray.1 - ray.0;
-                let mut collisions = self.ray_collisions(&ray);
+
+                trace_event!(GraphPathEvent::RayCast {
+                    edge: next_edge,
+                    ray: ((ray.0.x(), ray.0.y()), (ray.1.x(), ray.1.y())),
+                    num_collisions: collisions.len(),
+                });
 
                 // There should always be an even number of collisions on a particular ray cast through a closed shape
                 test_assert!((collisions.len() & 1) == 0);
 
-                // For collisions that overlap, ensure that the first shape is outermost so that subtractions work (swap based on the direction)
-                // This interacts with the ordering chosen in ray_collisions: if that ordering changes this may no longer be correct
-                if !collisions.is_empty() {
-                    for collision_idx in 0..(collisions.len() - 1) {
-                        let (collision_a, _curve_t, line_t_a, _pos) = &collisions[collision_idx];
-                        let (collision_b, _curve_t, line_t_b, _pos) =
-                            &collisions[collision_idx + 1];
-
-                        if line_t_a == line_t_b {
-                            let edge_a = collision_a.edge();
-                            let edge_b = collision_b.edge();
-
-                            // Swap if the earlier of the two edges is moving in the appropriate direction
-                            if edge_a.start_idx == edge_b.start_idx {
-                                let earlier_edge = if edge_a.edge_idx < edge_b.edge_idx {
-                                    edge_a
-                                } else {
-                                    edge_b
-                                };
-                                let PathLabel(_, edge_direction) = self.edge_label(earlier_edge);
-
-                                if edge_direction == PathDirection::Anticlockwise {
-                                    collisions.swap(collision_idx, collision_idx + 1);
-                                }
-                            }
-                        }
-                    }
-                }
+                // The 'total direction' indicates how often we've crossed an edge moving in a particular direction
+                // We're inside the path when it's non-zero
+                let mut path_crossings: SmallVec<[i32; 8]> = smallvec![0, 0];
+                let sides = self.collision_sides(&collisions, &ray_direction);
 
                 // Work out which edges are interior or exterior for every edge the ray has crossed
-                for (collision, curve_t, _line_t, _pos) in collisions {
+                for ((collision, curve_t, _line_t, _pos), (path_number, side)) in
+                    collisions.into_iter().zip(sides)
+                {
                     let is_intersection = collision.is_intersection();
                     let edge = collision.edge();
 
-                    let PathLabel(path_number, direction) = self.edge_label(edge);
-
-                    // The relative direction of the tangent to the ray indicates the direction we're crossing in
-                    let normal = self.get_edge(edge).normal_at_pos(curve_t);
-
-                    let side = ray_direction.dot(&normal).signum() as i32;
-                    let side = match direction {
-                        PathDirection::Clockwise => side,
-                        PathDirection::Anticlockwise => -side,
-                    };
-
                     // Extend the path_crossings vector
                     while path_crossings.len() <= path_number as usize {
                         path_crossings.push(0);
@@ -194,17 +488,34 @@ impl<Point: Coordinate + Coordinate2D> GraphPath<Point, PathLabel> {
                             if was_inside ^ is_inside {
                                 // Exterior edge
                                 self.set_edge_kind_connected(edge, GraphPathEdgeKind::Exterior);
+                                trace_event!(GraphPathEvent::EdgeClassified {
+                                    edge,
+                                    kind: GraphPathEdgeKind::Exterior,
+                                });
                             } else {
                                 // Interior edge
                                 self.set_edge_kind_connected(edge, GraphPathEdgeKind::Interior);
+                                trace_event!(GraphPathEvent::EdgeClassified {
+                                    edge,
+                                    kind: GraphPathEdgeKind::Interior,
+                                });
                             }
                         }
                     } else if !is_intersection && curve_t > 0.1 && curve_t < 0.9 {
-                        if was_inside ^ is_inside {
+                        let is_exterior = was_inside ^ is_inside;
+                        if strict && is_exterior != (edge_kind == GraphPathEdgeKind::Exterior) {
+                            return Err(PathArithmeticError::ConflictingEdgeKind { edge, ray });
+                        }
+
+                        if is_exterior {
                             if edge_kind != GraphPathEdgeKind::Exterior {
                                 // We've likely got a missing collision in the graph so an edge is both inside and outside
                                 // Set the edge to be an 'exterior' one so that we increase the chances of finding a path
                                 self.set_edge_kind_connected(edge, GraphPathEdgeKind::Exterior);
+                                trace_event!(GraphPathEvent::EdgeClassified {
+                                    edge,
+                                    kind: GraphPathEdgeKind::Exterior,
+                                });
                             }
 
                             // This is a bug so fail in debug builds
@@ -221,5 +532,99 @@ impl<Point: Coordinate + Coordinate2D> GraphPath<Point, PathLabel> {
                     .all(|crossing_count| crossing_count == 0));
             }
         }
+
+        Ok(())
+    }
+}
+
+///
+/// Creates a graph path from a set of paths, labelling its edges with the path number `path_set`
+///
+pub(crate) fn labelled_graph_path<P: BezierPath>(
+    paths: &[P],
+    path_set: u32,
+) -> GraphPath<P::Point, PathLabel>
+where
+    P::Point: Coordinate + Coordinate2D,
+{
+    GraphPath::from_merged_paths(
+        paths
+            .iter()
+            .map(|path| (path, PathLabel(path_set, PathDirection::from(path)))),
+    )
+}
+
+///
+/// As for `labelled_graph_path()`, but also labels each edge with the section of the input path that it came from
+///
+pub(crate) fn labelled_graph_path_with_sources<P: BezierPath>(
+    paths: &[P],
+    path_set: u32,
+) -> GraphPath<P::Point, (PathLabel, PathSectionSource)>
+where
+    P::Point: Coordinate + Coordinate2D,
+{
+    paths
+        .iter()
+        .enumerate()
+        .fold(GraphPath::new(), |graph_path, (path_idx, path)| {
+            let label = PathLabel(path_set, PathDirection::from(path));
+
+            graph_path.merge(GraphPath::from_path_with_section_labels(
+                path,
+                |section_idx| {
+                    (
+                        label,
+                        PathSectionSource {
+                            path_set,
+                            path_idx,
+                            section_idx,
+                        },
+                    )
+                },
+            ))
+        })
+}
+
+///
+/// Creates the labelled graph path for an arithmetic operation by colliding two sets of paths (labelled as path 0 and path 1)
+///
+pub(crate) fn collide_labelled_paths<P1: BezierPath, P2: BezierPath<Point = P1::Point>>(
+    path1: &[P1],
+    path2: &[P2],
+    accuracy: f64,
+) -> GraphPath<P1::Point, PathLabel>
+where
+    P1::Point: Coordinate + Coordinate2D,
+{
+    match collide_labelled_graphs(
+        labelled_graph_path(path1, 0),
+        labelled_graph_path(path2, 1),
+        accuracy,
+        &NoProgressMonitor,
+    ) {
+        Ok(merged_path) => merged_path,
+        Err(OperationCancelled) => unreachable!(),
     }
 }
+
+///
+/// Collides two labelled graph paths for an arithmetic operation, reporting the progress of the collision to a monitor
+/// that can cancel it
+///
+pub(crate) fn collide_labelled_graphs<Point, Label, Monitor>(
+    graph1: GraphPath<Point, Label>,
+    graph2: GraphPath<Point, Label>,
+    accuracy: f64,
+    monitor: &Monitor,
+) -> Result<GraphPath<Point, Label>, OperationCancelled>
+where
+    Point: Coordinate + Coordinate2D,
+    Label: HasPathLabel,
+    Monitor: ProgressMonitor + ?Sized,
+{
+    let mut merged_path = graph1.collide_with_progress(graph2, accuracy, monitor)?;
+    merged_path.round(accuracy);
+
+    Ok(merged_path)
+}
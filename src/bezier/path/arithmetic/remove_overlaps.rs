@@ -0,0 +1,75 @@
+use super::super::super::super::geo::{Coordinate, Coordinate2D};
+use super::super::super::curve::{BezierCurve, Curve};
+use super::super::graph_path::GraphPath;
+use super::super::is_clockwise::PathWithIsClockwise;
+use super::super::path::{BezierPath, BezierPathFactory};
+use super::super::point::path_contains_point;
+use super::super::to_curves::path_to_curves;
+use super::ray_cast::{PathDirection, PathLabel};
+
+///
+/// Resolves all of the self-intersections and overlapping subpaths in a path, producing a set of non-overlapping outlines
+/// with a consistent winding direction (this is the equivalent of the 'remove overlap' operation found in font editors).
+///
+/// This uses the non-zero winding rule: overlapping subpaths with the same winding direction are joined together, and
+/// subpaths wound the opposite way to the path enclosing them are left as holes. This differs from
+/// `path_remove_interior_points()`, which removes all of the holes. In the result, outer outlines are always clockwise and holes are always
+/// anticlockwise, regardless of the direction of the input paths.
+///
+pub fn path_remove_overlaps<P1: BezierPath, POut>(path: &[P1], accuracy: f64) -> Vec<POut>
+where
+    P1::Point: Coordinate + Coordinate2D,
+    POut: BezierPathFactory<Point = P1::Point>,
+{
+    // Label every path as clockwise, so the ray casting algorithm counts the real winding number instead of normalising the
+    // direction of each subpath (a subpath wound in the opposite direction will then cut a hole)
+    let mut merged_path = GraphPath::new();
+    merged_path = merged_path.merge(GraphPath::from_merged_paths(
+        path.iter()
+            .map(|path| (path, PathLabel(0, PathDirection::Clockwise))),
+    ));
+
+    // Collide the path with itself to find the intersections
+    merged_path.self_collide(accuracy);
+    merged_path.round(accuracy);
+
+    // Use the non-zero winding rule to find the exterior edges
+    merged_path.set_edge_kinds_by_ray_casting(|path_crossings| path_crossings[0] != 0);
+    merged_path.heal_exterior_gaps();
+
+    let outlines: Vec<POut> = merged_path.exterior_paths();
+
+    // Pick a point on each outline to test against the others
+    let sample_points = outlines
+        .iter()
+        .map(|outline| {
+            path_to_curves::<_, Curve<_>>(outline)
+                .next()
+                .map(|curve| curve.point_at_pos(0.5))
+                .unwrap_or_else(|| outline.start_point())
+        })
+        .collect::<Vec<_>>();
+
+    // Outlines inside an even number of other outlines are exterior edges: these should be clockwise and the rest anticlockwise
+    outlines
+        .iter()
+        .enumerate()
+        .map(|(outline_idx, outline)| {
+            let depth = outlines
+                .iter()
+                .enumerate()
+                .filter(|(other_idx, other)| {
+                    *other_idx != outline_idx
+                        && path_contains_point(*other, &sample_points[outline_idx])
+                })
+                .count();
+            let should_be_clockwise = (depth & 1) == 0;
+
+            if outline.is_clockwise() == should_be_clockwise {
+                outline.clone()
+            } else {
+                outline.reversed()
+            }
+        })
+        .collect()
+}
@@ -1,5 +1,6 @@
-use super::super::super::geo::{BoundingBox, Coordinate};
+use super::super::super::geo::{BoundingBox, Coordinate2D};
 use super::super::curve::{BezierCurve, Curve};
+use super::super::oriented_bounds::{curves_oriented_bounding_box, OrientedBounds};
 use super::path::BezierPath;
 use super::to_curves::path_to_curves;
 
@@ -7,10 +8,7 @@ use super::to_curves::path_to_curves;
 /// Finds the bounds of a path
 ///
 pub fn path_bounding_box<P: BezierPath, Bounds: BoundingBox<Point = P::Point>>(path: &P) -> Bounds {
-    path_to_curves(path)
-        .map(|curve: Curve<P::Point>| curve.bounding_box())
-        .reduce(|first: Bounds, second| first.union_bounds(second))
-        .unwrap_or_else(|| Bounds::from_min_max(P::Point::origin(), P::Point::origin()))
+    Bounds::union_all(path_to_curves(path).map(|curve: Curve<P::Point>| curve.bounding_box()))
 }
 
 ///
@@ -19,8 +17,20 @@ pub fn path_bounding_box<P: BezierPath, Bounds: BoundingBox<Point = P::Point>>(p
 pub fn path_fast_bounding_box<P: BezierPath, Bounds: BoundingBox<Point = P::Point>>(
     path: &P,
 ) -> Bounds {
-    path_to_curves(path)
-        .map(|curve: Curve<P::Point>| curve.fast_bounding_box())
-        .reduce(|first: Bounds, second| first.union_bounds(second))
-        .unwrap_or_else(|| Bounds::from_min_max(P::Point::origin(), P::Point::origin()))
+    Bounds::union_all(path_to_curves(path).map(|curve: Curve<P::Point>| curve.fast_bounding_box()))
+}
+
+///
+/// Finds the minimum-area oriented bounding box of a path
+///
+/// This is a rotated rectangle that can fit much more closely than the axis-aligned bounding box, for example
+/// around a long diagonal stroke.
+///
+pub fn path_oriented_bounding_box<P: BezierPath>(path: &P) -> OrientedBounds<P::Point>
+where
+    P::Point: Coordinate2D,
+{
+    let curves = path_to_curves::<_, Curve<P::Point>>(path).collect::<Vec<_>>();
+
+    curves_oriented_bounding_box(&curves)
 }
@@ -0,0 +1,86 @@
+use super::path::{BezierPath, BezierPathFactory};
+use crate::geo::{BoundingBox, Bounds, Coordinate};
+
+use std::cmp::Ordering;
+
+///
+/// Compares two points by their components, in order
+///
+fn compare_points<Point: Coordinate>(a: &Point, b: &Point) -> Ordering {
+    (0..Point::len())
+        .map(|component_idx| a.get(component_idx).total_cmp(&b.get(component_idx)))
+        .find(|ordering| *ordering != Ordering::Equal)
+        .unwrap_or(Ordering::Equal)
+}
+
+///
+/// Returns a version of a path that starts at its lowest point (the point with the smallest x coordinate, or the smallest
+/// y coordinate if there's more than one with the same x coordinate)
+///
+/// The path follows the same curves in the same direction: only the point the path starts at is changed. Paths that
+/// don't end at their start point are returned unchanged, as moving their start would change their shape. Points are
+/// compared exactly, so a path with two points that are almost level with each other can start at either one after a
+/// small change to its coordinates.
+///
+pub fn canonicalize_path<P: BezierPath, POut: BezierPathFactory<Point = P::Point>>(
+    path: &P,
+) -> POut {
+    let start_point = path.start_point();
+    let curves = path.points().collect::<Vec<_>>();
+
+    let is_closed = curves
+        .last()
+        .map(|(_, _, end_point)| *end_point == start_point)
+        .unwrap_or(false);
+
+    if !is_closed {
+        return POut::from_points(start_point, curves);
+    }
+
+    // curves[idx] ends at the point where curve idx+1 starts
+    let lowest_idx = (0..curves.len())
+        .min_by(|a, b| compare_points(&curves[*a].2, &curves[*b].2))
+        .unwrap();
+    let new_start = curves[lowest_idx].2;
+
+    POut::from_points(
+        new_start,
+        curves[(lowest_idx + 1)..]
+            .iter()
+            .chain(curves[..=lowest_idx].iter())
+            .copied(),
+    )
+}
+
+///
+/// Puts a set of paths (such as the output of path arithmetic) into a canonical form, so that the same shapes always
+/// generate the same list of paths
+///
+/// Each path is moved to start at its lowest point using `canonicalize_path()`, and then the paths are sorted by their
+/// bounding boxes (by the minimum point, then the maximum point, then the start point). Paths in the output of the
+/// path arithmetic functions can be in any order and start at any point depending on how the graph was traversed; this
+/// makes it possible to compare them against a previous result in snapshot tests.
+///
+/// The direction of the paths is not changed, so holes still go in the opposite direction to their outlines.
+///
+pub fn canonicalize_paths<P: BezierPath, POut: BezierPathFactory<Point = P::Point>>(
+    paths: &[P],
+) -> Vec<POut> {
+    let mut paths = paths
+        .iter()
+        .map(|path| {
+            let path = canonicalize_path::<_, POut>(path);
+            let bounds = path.bounding_box::<Bounds<_>>();
+
+            (bounds, path)
+        })
+        .collect::<Vec<_>>();
+
+    paths.sort_by(|(bounds_a, path_a), (bounds_b, path_b)| {
+        compare_points(&bounds_a.min(), &bounds_b.min())
+            .then_with(|| compare_points(&bounds_a.max(), &bounds_b.max()))
+            .then_with(|| compare_points(&path_a.start_point(), &path_b.start_point()))
+    });
+
+    paths.into_iter().map(|(_, path)| path).collect()
+}
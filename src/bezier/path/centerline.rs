@@ -0,0 +1,180 @@
+use super::super::super::consts::SMALL_DISTANCE;
+use super::super::super::geo::{Coordinate, Coordinate2D};
+use super::super::super::line::SimpleRay;
+use super::super::curve::{BezierCurve, Curve};
+use super::super::fit::fit_curve;
+use super::super::normal::NormalCurve;
+use super::intersection::path_ray_intersections;
+use super::is_clockwise::path_signed_area;
+use super::measure::PathMeasure;
+use super::path::{BezierPath, BezierPathFactory};
+
+/// Points on opposite sides of a stroke must have normals that are within this of being parallel (as the cosine of the
+/// angle between them)
+const MIN_OPPOSITE_COS_ANGLE: f64 = 0.94;
+
+/// Largest distance (as a multiple of the sample spacing) between the midpoints of neighbouring samples before they're
+/// considered to be on different parts of the centerline
+const MAX_MIDPOINT_STEP: f64 = 4.0;
+
+///
+/// The centerline and width of a stroke, as estimated from its outline by `path_stroke_centerline()`
+///
+#[derive(Clone, Debug)]
+pub struct StrokeCenterline<P> {
+    /// The line that the stroke follows
+    pub centerline: P,
+
+    /// The width of the stroke at points along the centerline, as `(distance, width)` pairs where the distance is measured
+    /// along the centerline from its start
+    pub widths: Vec<(f64, f64)>,
+}
+
+///
+/// Estimates the centerline of a stroke from the outline that was produced by stroking it
+///
+/// This is the reverse of generating an outline with `path_offset()` or a brush: it places points every `spacing` units
+/// around the outline and pairs each one with the point on the opposite side of the stroke (found by following the normal
+/// into the shape, and only accepted if the normals at both points are close to parallel). The midpoints of these pairs
+/// are fitted to a curve to within `max_error`, and the distance between them gives the width of the stroke.
+///
+/// The centerline follows the direction of one of the sides of the outline, and stops where the sides stop being
+/// parallel, so it doesn't include any part of the stroke covered by a round cap. Returns `None` if `spacing` is not a
+/// positive, finite distance, if no opposite points could be found, or if they're all paired up (which happens when the
+/// outline has no ends, as for a circle).
+///
+pub fn path_stroke_centerline<P, POut>(
+    outline: &P,
+    spacing: f64,
+    max_error: f64,
+) -> Option<StrokeCenterline<POut>>
+where
+    P: BezierPath,
+    P::Point: Coordinate + Coordinate2D,
+    POut: BezierPathFactory<Point = P::Point>,
+{
+    if !spacing.is_finite() || spacing <= 0.0 {
+        return None;
+    }
+
+    let measure = PathMeasure::new(outline, max_error);
+    let length = measure.length();
+
+    if length <= 0.0 {
+        return None;
+    }
+
+    // Pair each sample point with the point opposite it, if there is one
+    let anticlockwise = path_signed_area(outline) > 0.0;
+    let num_samples = (length / spacing).ceil() as usize;
+
+    let pairs = (0..num_samples)
+        .map(|idx| {
+            let frame = measure.frame_at_length((idx as f64) * spacing)?;
+            let inwards = if anticlockwise {
+                frame.normal
+            } else {
+                frame.normal * -1.0
+            };
+
+            // Follow the normal into the shape to find the nearest point on the other side
+            let ray = SimpleRay::new(frame.position, inwards);
+            let (section, t, distance) = path_ray_intersections(outline, &ray)
+                .filter(|(_, _, distance)| *distance > SMALL_DISTANCE)
+                .min_by(|(_, _, a), (_, _, b)| a.total_cmp(b))?;
+
+            // The outline on the other side should be going in the opposite direction
+            let tangent = measure.curves()[section].tangent_at_pos(t);
+            if tangent.magnitude() <= 0.0 {
+                return None;
+            }
+
+            let tangent = tangent.to_unit_vector();
+            if tangent.dot(&frame.tangent) > -MIN_OPPOSITE_COS_ANGLE {
+                return None;
+            }
+
+            Some((frame.position + inwards * (distance * 0.5), distance))
+        })
+        .collect::<Vec<_>>();
+
+    if pairs.iter().all(|pair| pair.is_some()) {
+        return None;
+    }
+
+    // Divide the pairs into runs where the midpoints follow on from each other
+    let first_unpaired = pairs.iter().position(|pair| pair.is_none())?;
+    let mut runs: Vec<Vec<(P::Point, f64)>> = vec![];
+    let mut current_run: Vec<(P::Point, f64)> = vec![];
+
+    for idx in 0..num_samples {
+        match pairs[(idx + first_unpaired) % num_samples] {
+            Some((midpoint, width)) => {
+                let follows_on = current_run
+                    .last()
+                    .map(|(last, _)| last.distance_to(&midpoint) <= spacing * MAX_MIDPOINT_STEP)
+                    .unwrap_or(true);
+
+                if !follows_on {
+                    runs.push(current_run);
+                    current_run = vec![];
+                }
+
+                if current_run
+                    .last()
+                    .map(|(last, _)| !last.is_near_to(&midpoint, SMALL_DISTANCE))
+                    .unwrap_or(true)
+                {
+                    current_run.push((midpoint, width));
+                }
+            }
+
+            None => {
+                if !current_run.is_empty() {
+                    runs.push(current_run);
+                    current_run = vec![];
+                }
+            }
+        }
+    }
+    if !current_run.is_empty() {
+        runs.push(current_run);
+    }
+
+    // Each side of the stroke generates the same centerline, so use the longest run
+    let run = runs.into_iter().max_by_key(|run| run.len())?;
+    if run.len() < 2 {
+        return None;
+    }
+
+    let midpoints = run
+        .iter()
+        .map(|(midpoint, _)| *midpoint)
+        .collect::<Vec<_>>();
+    let curves = fit_curve::<Curve<_>>(&midpoints, max_error)?;
+
+    let mut distance = 0.0;
+    let mut last_point = midpoints[0];
+    let widths = run
+        .iter()
+        .map(|(midpoint, width)| {
+            distance += last_point.distance_to(midpoint);
+            last_point = *midpoint;
+
+            (distance, *width)
+        })
+        .collect();
+
+    let centerline = POut::from_points(
+        curves[0].start_point(),
+        curves
+            .iter()
+            .map(|curve| {
+                let (cp1, cp2) = curve.control_points();
+                (cp1, cp2, curve.end_point())
+            })
+            .collect::<Vec<_>>(),
+    );
+
+    Some(StrokeCenterline { centerline, widths })
+}
@@ -0,0 +1,288 @@
+use super::path::{BezierPath, BezierPathFactory};
+use crate::bezier::offset_error::distance_to_segment;
+use crate::bezier::{
+    reduce_to_line, reduce_to_quadratic, BezierCurveFactory, Curve, QuadraticCurve,
+};
+use crate::geo::{Coordinate, Coordinate2D};
+
+use std::marker::PhantomData;
+
+///
+/// The type of a section in a `CompressedPath`
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum CompressedSectionKind {
+    /// A straight line, stored as its end point
+    Line,
+
+    /// A quadratic curve, stored as its control point and its end point
+    Quadratic,
+
+    /// A cubic curve, stored as its two control points and its end point
+    Cubic,
+}
+
+impl CompressedSectionKind {
+    ///
+    /// The number of points stored for this kind of section
+    ///
+    #[inline]
+    pub fn num_points(&self) -> usize {
+        match self {
+            CompressedSectionKind::Line => 1,
+            CompressedSectionKind::Quadratic => 2,
+            CompressedSectionKind::Cubic => 3,
+        }
+    }
+}
+
+///
+/// A path stored in a compact form, as generated by `compress_path()`
+///
+/// The coordinates are stored as integer multiples of `quantum()`. `values()` contains the components of the start
+/// point followed by the components of the points for each section, in the order given by `sections()`.
+///
+#[derive(Clone, Debug, PartialEq)]
+pub struct CompressedPath<Point> {
+    /// The size of the grid that the points are snapped to
+    quantum: f64,
+
+    /// The kind of each section in the path
+    sections: Vec<CompressedSectionKind>,
+
+    /// The quantised components of the points in the path
+    values: Vec<i32>,
+
+    point: PhantomData<Point>,
+}
+
+impl<Point: Coordinate> CompressedPath<Point> {
+    ///
+    /// Creates a compressed path from its parts (such as from values read back from storage)
+    ///
+    /// Returns `None` if the number of values doesn't match the number of points the sections need.
+    ///
+    pub fn from_parts(
+        quantum: f64,
+        sections: Vec<CompressedSectionKind>,
+        values: Vec<i32>,
+    ) -> Option<CompressedPath<Point>> {
+        let num_points = 1 + sections
+            .iter()
+            .map(|section| section.num_points())
+            .sum::<usize>();
+
+        if values.len() != num_points * Point::len() {
+            return None;
+        }
+
+        Some(CompressedPath {
+            quantum,
+            sections,
+            values,
+            point: PhantomData,
+        })
+    }
+
+    ///
+    /// The size of the grid that the coordinates of this path are snapped to
+    ///
+    #[inline]
+    pub fn quantum(&self) -> f64 {
+        self.quantum
+    }
+
+    ///
+    /// The kind of each section of this path
+    ///
+    #[inline]
+    pub fn sections(&self) -> &[CompressedSectionKind] {
+        &self.sections
+    }
+
+    ///
+    /// The components of the points in this path, as multiples of the quantum
+    ///
+    #[inline]
+    pub fn values(&self) -> &[i32] {
+        &self.values
+    }
+
+    ///
+    /// Converts this compressed path back to a path made up of cubic curves
+    ///
+    pub fn to_path<POut: BezierPathFactory<Point = Point>>(&self) -> POut {
+        let mut points = self.values.chunks(Point::len()).map(|components| {
+            let components = components
+                .iter()
+                .map(|value| (*value as f64) * self.quantum)
+                .collect::<Vec<_>>();
+
+            Point::from_components(&components)
+        });
+
+        let start_point = points.next().unwrap_or_else(Point::origin);
+        let mut last_point = start_point;
+        let mut curves = vec![];
+
+        for section in self.sections.iter() {
+            let curve: Curve<Point> = match section {
+                CompressedSectionKind::Line => {
+                    let end_point = points.next().unwrap();
+                    let offset = end_point - last_point;
+
+                    Curve::from_points(
+                        last_point,
+                        (
+                            last_point + offset * (1.0 / 3.0),
+                            last_point + offset * (2.0 / 3.0),
+                        ),
+                        end_point,
+                    )
+                }
+
+                CompressedSectionKind::Quadratic => {
+                    let control_point = points.next().unwrap();
+                    let end_point = points.next().unwrap();
+
+                    QuadraticCurve::from_points(last_point, control_point, end_point).to_cubic()
+                }
+
+                CompressedSectionKind::Cubic => {
+                    let cp1 = points.next().unwrap();
+                    let cp2 = points.next().unwrap();
+                    let end_point = points.next().unwrap();
+
+                    Curve::from_points(last_point, (cp1, cp2), end_point)
+                }
+            };
+
+            curves.push((
+                curve.control_points.0,
+                curve.control_points.1,
+                curve.end_point,
+            ));
+            last_point = curve.end_point;
+        }
+
+        POut::from_points(start_point, curves)
+    }
+}
+
+///
+/// Compresses a path for storage, returning the compressed path and an upper bound on the distance between it and the
+/// original path
+///
+/// The path is compressed in three stages, each using part of the `tolerance`: curves are replaced with lines or
+/// quadratic curves where these are close enough to the original curve, runs of collinear lines are merged into single
+/// lines, and finally the coordinates are snapped to a grid and stored as integers. The error that's returned is the sum
+/// of the largest errors introduced by each stage, so it is never more than `tolerance` and is usually quite a bit less.
+///
+/// The quantised coordinates are stored as `i32`s, so the points in the path must be within `i32::MAX` grid squares of
+/// the origin (the grid size is a little smaller than the tolerance).
+///
+pub fn compress_path<P: BezierPath>(path: &P, tolerance: f64) -> (CompressedPath<P::Point>, f64)
+where
+    P::Point: Coordinate2D,
+{
+    let reduce_tolerance = tolerance * 0.25;
+    let merge_tolerance = tolerance * 0.25;
+
+    // A point can move by up to half the diagonal of a grid square when it's snapped to the grid
+    let quantum = tolerance / (P::Point::len() as f64).sqrt();
+
+    // Reduce the degree of each curve where possible
+    let mut reduce_error = 0.0f64;
+    let mut sections: Vec<(CompressedSectionKind, Vec<P::Point>)> = vec![];
+    let mut last_point = path.start_point();
+
+    for (cp1, cp2, end_point) in path.points() {
+        let curve = Curve::from_points(last_point, (cp1, cp2), end_point);
+        let (_, line_error) = reduce_to_line(&curve);
+
+        if line_error <= reduce_tolerance {
+            reduce_error = reduce_error.max(line_error);
+
+            // Merge with the previous line if the result is still close enough to the original shape
+            match sections.last_mut() {
+                Some((CompressedSectionKind::Line, line_points)) => {
+                    let line_start = line_points[0];
+
+                    if line_points[1..].iter().all(|point| {
+                        distance_to_segment(point, (&line_start, &end_point)) <= merge_tolerance
+                    }) {
+                        line_points.push(end_point);
+                    } else {
+                        sections.push((CompressedSectionKind::Line, vec![last_point, end_point]));
+                    }
+                }
+
+                _ => sections.push((CompressedSectionKind::Line, vec![last_point, end_point])),
+            }
+        } else {
+            let (quadratic, quadratic_error) = reduce_to_quadratic(&curve);
+
+            if quadratic_error <= reduce_tolerance {
+                reduce_error = reduce_error.max(quadratic_error);
+                sections.push((
+                    CompressedSectionKind::Quadratic,
+                    vec![quadratic.control_point, end_point],
+                ));
+            } else {
+                sections.push((CompressedSectionKind::Cubic, vec![cp1, cp2, end_point]));
+            }
+        }
+
+        last_point = end_point;
+    }
+
+    // Measure the error from merging lines: the runs of lines keep their start point, the points that were merged, and
+    // their end point
+    let merge_error = sections
+        .iter_mut()
+        .filter(|(kind, _)| *kind == CompressedSectionKind::Line)
+        .map(|(_, line_points)| {
+            let (start, end) = (line_points[0], line_points[line_points.len() - 1]);
+            let error = line_points[1..(line_points.len() - 1)]
+                .iter()
+                .map(|point| distance_to_segment(point, (&start, &end)))
+                .fold(0.0, f64::max);
+
+            *line_points = vec![end];
+            error
+        })
+        .fold(0.0, f64::max);
+
+    // Quantise the points
+    let mut quantise_error = 0.0f64;
+    let mut values = vec![];
+
+    let all_points = Some(path.start_point()).into_iter().chain(
+        sections
+            .iter()
+            .flat_map(|(_, points)| points.iter().copied()),
+    );
+
+    for point in all_points {
+        let mut quantised = vec![];
+
+        for component_idx in 0..P::Point::len() {
+            let value = (point.get(component_idx) / quantum).round() as i32;
+
+            values.push(value);
+            quantised.push((value as f64) * quantum);
+        }
+
+        quantise_error =
+            quantise_error.max(point.distance_to(&P::Point::from_components(&quantised)));
+    }
+
+    let compressed = CompressedPath {
+        quantum,
+        sections: sections.into_iter().map(|(kind, _)| kind).collect(),
+        values,
+        point: PhantomData,
+    };
+
+    (compressed, reduce_error + merge_error + quantise_error)
+}
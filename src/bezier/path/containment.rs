@@ -0,0 +1,174 @@
+use super::super::super::consts::{CLOSE_DISTANCE, SMALL_DISTANCE};
+use super::super::super::geo::{BoundingBox, Bounds, Coordinate2D};
+use super::super::curve::{BezierCurve, Curve};
+use super::intersection::path_intersects_path;
+use super::path::BezierPath;
+use super::point::{path_contains_point, path_stroke_contains_point};
+use super::to_curves::path_to_curves;
+
+///
+/// Describes how two paths are positioned relative to each other
+///
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum PathRelation {
+    /// The paths do not overlap
+    Disjoint,
+
+    /// The first path entirely encloses the second path
+    AContainsB,
+
+    /// The second path entirely encloses the first path
+    BContainsA,
+
+    /// The interiors of the paths partially overlap
+    Overlapping,
+
+    /// The paths have the same outline
+    Identical,
+}
+
+///
+/// Where the parts of a path are relative to another path
+///
+#[derive(Clone, Copy, PartialEq, Debug)]
+struct SampleSummary {
+    /// True if at least one part of the path is inside the other path
+    any_inside: bool,
+
+    /// True if at least one part of the path is outside the other path
+    any_outside: bool,
+}
+
+///
+/// Determines where the parts of a path between its intersections with another path are
+///
+/// `intersections` are the `(curve_idx, t)` positions of the intersections on `path`. Each section of the path between two
+/// intersections is either entirely inside or entirely outside of the other path, so it's enough to test one point from each.
+/// Points that are on the outline of the other path are ignored.
+///
+fn summarise_samples<P: BezierPath>(
+    path: &P,
+    intersections: &[(usize, f64)],
+    other: &P,
+) -> SampleSummary
+where
+    P::Point: Coordinate2D,
+{
+    let curves = path_to_curves::<_, Curve<_>>(path).collect::<Vec<_>>();
+    let num_curves = curves.len() as f64;
+
+    // Positions along the whole path where the sections start and end: the midpoint of each curve is always included so
+    // that paths that only touch at points are still sampled in several places
+    let mut positions = intersections
+        .iter()
+        .map(|(curve_idx, t)| (*curve_idx as f64) + t)
+        .chain((0..curves.len()).map(|curve_idx| (curve_idx as f64) + 0.5))
+        .collect::<Vec<_>>();
+    positions.sort_by(|a, b| a.total_cmp(b));
+
+    let mut summary = SampleSummary {
+        any_inside: false,
+        any_outside: false,
+    };
+
+    for (idx, start) in positions.iter().enumerate() {
+        let end = if idx + 1 < positions.len() {
+            positions[idx + 1]
+        } else {
+            positions[0] + num_curves
+        };
+
+        let mid = ((start + end) * 0.5) % num_curves;
+        let curve_idx = (mid.floor() as usize).min(curves.len() - 1);
+        let sample = curves[curve_idx].point_at_pos(mid - (curve_idx as f64));
+
+        if path_stroke_contains_point(other, &sample, CLOSE_DISTANCE * 2.0) {
+            // Ignore points on the outline of the other path
+            continue;
+        }
+
+        if path_contains_point(other, &sample) {
+            summary.any_inside = true;
+        } else {
+            summary.any_outside = true;
+        }
+    }
+
+    summary
+}
+
+///
+/// Determines how two paths are positioned relative to each other
+///
+/// Paths that touch without their interiors overlapping are not considered to overlap: for example, a path touching the
+/// inside of an enclosing path is still contained by it.
+///
+pub fn classify_paths<P: BezierPath>(a: &P, b: &P) -> PathRelation
+where
+    P::Point: Coordinate2D,
+{
+    if path_to_curves::<_, Curve<_>>(a).next().is_none()
+        || path_to_curves::<_, Curve<_>>(b).next().is_none()
+    {
+        // Empty paths have no interior, so can't overlap anything
+        return PathRelation::Disjoint;
+    }
+
+    // Paths whose bounding boxes don't overlap can't overlap each other
+    let bounds_a = a.fast_bounding_box::<Bounds<_>>().inflate(CLOSE_DISTANCE);
+    let bounds_b = b.fast_bounding_box::<Bounds<_>>().inflate(CLOSE_DISTANCE);
+    if !bounds_a.overlaps(&bounds_b) {
+        return PathRelation::Disjoint;
+    }
+
+    // Classify the parts of each path between the points where they intersect
+    let intersections = path_intersects_path(a, b, SMALL_DISTANCE);
+    let intersections_a = intersections
+        .iter()
+        .map(|(pos_a, _)| *pos_a)
+        .collect::<Vec<_>>();
+    let intersections_b = intersections
+        .iter()
+        .map(|(_, pos_b)| *pos_b)
+        .collect::<Vec<_>>();
+
+    let a_in_b = summarise_samples(a, &intersections_a, b);
+    let b_in_a = summarise_samples(b, &intersections_b, a);
+
+    if (a_in_b.any_inside && (b_in_a.any_inside || a_in_b.any_outside))
+        || (b_in_a.any_inside && b_in_a.any_outside)
+    {
+        // Some of the interior of each path is inside the other
+        PathRelation::Overlapping
+    } else if a_in_b.any_inside {
+        PathRelation::BContainsA
+    } else if b_in_a.any_inside {
+        PathRelation::AContainsB
+    } else if a_in_b.any_outside && b_in_a.any_outside {
+        PathRelation::Disjoint
+    } else if a_in_b.any_outside {
+        // All of b is on the outline of a
+        PathRelation::AContainsB
+    } else if b_in_a.any_outside {
+        // All of a is on the outline of b
+        PathRelation::BContainsA
+    } else {
+        // Each path is entirely on the outline of the other
+        PathRelation::Identical
+    }
+}
+
+///
+/// Returns true if the `inner` path is entirely enclosed by the `outer` path
+///
+/// Identical paths are considered to contain each other.
+///
+pub fn path_contains_path<P: BezierPath>(outer: &P, inner: &P) -> bool
+where
+    P::Point: Coordinate2D,
+{
+    match classify_paths(outer, inner) {
+        PathRelation::AContainsB | PathRelation::Identical => true,
+        PathRelation::Disjoint | PathRelation::BContainsA | PathRelation::Overlapping => false,
+    }
+}
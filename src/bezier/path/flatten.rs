@@ -0,0 +1,79 @@
+use super::super::super::geo::{BoundingBox, Bounds, Coordinate, Coordinate2D};
+use super::super::super::line::Line2D;
+use super::super::subdivide::subdivide4;
+use super::path::BezierPath;
+
+/// The maximum number of times a curve is subdivided when flattening it
+const MAX_FLATTEN_DEPTH: usize = 10;
+
+/// The smallest tolerance that's used when flattening a path, as a fraction of the size of the path
+const MIN_RELATIVE_TOLERANCE: f64 = 1e-6;
+
+///
+/// Returns the largest distance between the control points of a curve and the line between its end points
+///
+fn control_point_distance<Point: Coordinate + Coordinate2D>(
+    w1: &Point,
+    w2: &Point,
+    w3: &Point,
+    w4: &Point,
+) -> f64 {
+    if w1.is_near_to(w4, 1e-12) {
+        w1.distance_to(w2).max(w1.distance_to(w3))
+    } else {
+        let chord = (*w1, *w4);
+
+        chord.distance_to(w2).abs().max(chord.distance_to(w3).abs())
+    }
+}
+
+///
+/// Adds the points of a flattened curve to a list of points (excluding the start point)
+///
+fn flatten_curve<Point: Coordinate + Coordinate2D>(
+    (w1, w2, w3, w4): (Point, Point, Point, Point),
+    tolerance: f64,
+    depth: usize,
+    points: &mut Vec<Point>,
+) {
+    // Curves with non-finite points can't be subdivided any further, so they're treated as flat
+    let distance = control_point_distance(&w1, &w2, &w3, &w4);
+
+    if depth >= MAX_FLATTEN_DEPTH || !distance.is_finite() || distance <= tolerance {
+        points.push(w4);
+    } else {
+        let (first, second) = subdivide4(0.5, w1, w2, w3, w4);
+
+        flatten_curve(first, tolerance, depth + 1, points);
+        flatten_curve(second, tolerance, depth + 1, points);
+    }
+}
+
+///
+/// Approximates a path as a series of straight lines
+///
+/// The returned points are the start point of the path followed by the end point of each line. No point on the lines is
+/// further than `tolerance` from the path, though the tolerance is never less than a millionth of the size of the path.
+///
+pub fn path_flatten<P: BezierPath>(path: &P, tolerance: f64) -> Vec<P::Point>
+where
+    P::Point: Coordinate2D,
+{
+    let bounds = path.bounding_box::<Bounds<_>>();
+    let size = bounds.max() - bounds.min();
+    let min_tolerance = (size.x().max(size.y()) * MIN_RELATIVE_TOLERANCE).max(1e-9);
+    let tolerance = if tolerance.is_nan() {
+        min_tolerance
+    } else {
+        tolerance.abs().max(min_tolerance)
+    };
+    let mut last_point = path.start_point();
+    let mut points = vec![last_point];
+
+    for (cp1, cp2, end_point) in path.points() {
+        flatten_curve((last_point, cp1, cp2, end_point), tolerance, 0, &mut points);
+        last_point = end_point;
+    }
+
+    points
+}
@@ -0,0 +1,436 @@
+use super::super::super::geo::{Coordinate, Coordinate2D};
+use super::path::{BezierPath, BezierPathFactory};
+use super::polygon::{path_to_polygon, polygon_to_path, Polygon};
+
+use serde_json::{json, Value};
+
+use std::error::Error;
+use std::fmt;
+
+// GeoJSON and WKT both describe shapes as polygons, each made up of an exterior ring followed by its holes, where the first
+// point of each ring is repeated at the end. Paths are flattened into `Polygon`s before they're written, and polygons that
+// are read are converted back into paths made up of straight lines, with the exterior of each polygon followed by its
+// holes. Only polygons and multi-polygons are supported: other types of geometry (points and lines) have no interior, so
+// can't be represented as paths.
+
+///
+/// Errors that can occur when reading GeoJSON or WKT
+///
+#[derive(Clone, Debug, PartialEq)]
+pub enum GeoFormatError {
+    /// The input could not be parsed (the string describes the problem)
+    Syntax(String),
+
+    /// The input contains a type of geometry that is not a polygon or a multi-polygon
+    UnsupportedGeometry(String),
+}
+
+impl fmt::Display for GeoFormatError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            GeoFormatError::Syntax(problem) => write!(f, "syntax error: {}", problem),
+            GeoFormatError::UnsupportedGeometry(geometry) => {
+                write!(f, "unsupported geometry type: {}", geometry)
+            }
+        }
+    }
+}
+
+impl Error for GeoFormatError {}
+
+///
+/// Returns the rings of a polygon with the first point repeated at the end of each one
+///
+fn closed_rings<Point: Coordinate + Coordinate2D>(
+    polygon: &Polygon<Point>,
+) -> impl '_ + Iterator<Item = Vec<(f64, f64)>> {
+    polygon.rings().map(|ring| {
+        ring.iter()
+            .chain(ring.first())
+            .map(|point| (point.x(), point.y()))
+            .collect()
+    })
+}
+
+///
+/// Converts a list of rings read from a file to a set of paths
+///
+fn paths_from_rings<POut: BezierPathFactory>(polygons: Vec<Vec<Vec<(f64, f64)>>>) -> Vec<POut>
+where
+    POut::Point: Coordinate + Coordinate2D,
+{
+    let to_ring = |ring: Vec<(f64, f64)>| {
+        let mut ring = ring
+            .into_iter()
+            .map(|(x, y)| POut::Point::from_components(&[x, y]))
+            .collect::<Vec<_>>();
+        ring.dedup();
+        while ring.len() > 1 && ring[0] == ring[ring.len() - 1] {
+            ring.pop();
+        }
+
+        ring
+    };
+
+    polygons
+        .into_iter()
+        .flat_map(|rings| {
+            let mut rings = rings
+                .into_iter()
+                .map(to_ring)
+                .filter(|ring| ring.len() >= 3);
+
+            match rings.next() {
+                Some(exterior) => polygon_to_path(&Polygon::new(exterior, rings.collect())),
+                None => vec![],
+            }
+        })
+        .collect()
+}
+
+///
+/// Writes a set of paths as a WKT `MULTIPOLYGON`, flattening the curves to within `tolerance`
+///
+/// The paths are divided into polygons using `path_to_polygon()`, so holes are assigned to the polygons that contain them.
+///
+pub fn paths_to_wkt<P: BezierPath>(paths: &[P], tolerance: f64) -> String
+where
+    P::Point: Coordinate + Coordinate2D,
+{
+    let polygons = path_to_polygon(paths, tolerance);
+
+    if polygons.is_empty() {
+        return "MULTIPOLYGON EMPTY".to_string();
+    }
+
+    let polygons = polygons
+        .iter()
+        .map(|polygon| {
+            let rings = closed_rings(polygon)
+                .map(|ring| {
+                    let points = ring
+                        .into_iter()
+                        .map(|(x, y)| format!("{} {}", x, y))
+                        .collect::<Vec<_>>();
+
+                    format!("({})", points.join(", "))
+                })
+                .collect::<Vec<_>>();
+
+            format!("({})", rings.join(", "))
+        })
+        .collect::<Vec<_>>();
+
+    format!("MULTIPOLYGON ({})", polygons.join(", "))
+}
+
+///
+/// Simple parser for the parts of WKT that describe polygons
+///
+struct WktParser<'a> {
+    /// The remaining text to parse
+    remaining: &'a str,
+}
+
+impl<'a> WktParser<'a> {
+    ///
+    /// Skips any whitespace at the start of the remaining text
+    ///
+    fn skip_whitespace(&mut self) {
+        self.remaining = self.remaining.trim_start();
+    }
+
+    ///
+    /// Reads a keyword (such as `POLYGON`), returning it in upper case
+    ///
+    fn keyword(&mut self) -> String {
+        self.skip_whitespace();
+
+        let length = self
+            .remaining
+            .find(|c: char| !c.is_ascii_alphabetic())
+            .unwrap_or(self.remaining.len());
+        let keyword = &self.remaining[0..length];
+        self.remaining = &self.remaining[length..];
+
+        keyword.to_ascii_uppercase()
+    }
+
+    ///
+    /// Returns true and skips the symbol if the next character is a particular symbol
+    ///
+    fn accept(&mut self, symbol: char) -> bool {
+        self.skip_whitespace();
+
+        if self.remaining.starts_with(symbol) {
+            self.remaining = &self.remaining[symbol.len_utf8()..];
+            true
+        } else {
+            false
+        }
+    }
+
+    ///
+    /// Skips the next character if it is a particular symbol, or returns an error if it's something else
+    ///
+    fn expect(&mut self, symbol: char) -> Result<(), GeoFormatError> {
+        if self.accept(symbol) {
+            Ok(())
+        } else {
+            Err(GeoFormatError::Syntax(format!(
+                "expected '{}' before '{}'",
+                symbol,
+                self.remaining.chars().take(16).collect::<String>()
+            )))
+        }
+    }
+
+    ///
+    /// Reads a number
+    ///
+    fn number(&mut self) -> Result<f64, GeoFormatError> {
+        self.skip_whitespace();
+
+        let length = self
+            .remaining
+            .find(|c: char| {
+                !(c.is_ascii_digit() || c == '.' || c == '-' || c == '+' || c == 'e' || c == 'E')
+            })
+            .unwrap_or(self.remaining.len());
+        let number = &self.remaining[0..length];
+        self.remaining = &self.remaining[length..];
+
+        number
+            .parse()
+            .map_err(|_| GeoFormatError::Syntax(format!("'{}' is not a number", number)))
+    }
+
+    ///
+    /// Reads a ring of points, such as `(1 2, 3 4, 5 6, 1 2)`, ignoring any Z or M values
+    ///
+    fn ring(&mut self) -> Result<Vec<(f64, f64)>, GeoFormatError> {
+        let mut points = vec![];
+
+        self.expect('(')?;
+        loop {
+            let x = self.number()?;
+            let y = self.number()?;
+            points.push((x, y));
+
+            while !self.accept(',') {
+                if self.accept(')') {
+                    return Ok(points);
+                }
+
+                self.number()?;
+            }
+        }
+    }
+
+    ///
+    /// Reads a list of items separated by commas between brackets, or `EMPTY`
+    ///
+    fn list<T>(
+        &mut self,
+        read_item: impl Fn(&mut Self) -> Result<T, GeoFormatError>,
+    ) -> Result<Vec<T>, GeoFormatError> {
+        if !self.accept('(') {
+            return match self.keyword().as_str() {
+                "EMPTY" => Ok(vec![]),
+                _ => Err(GeoFormatError::Syntax("expected '(' or EMPTY".to_string())),
+            };
+        }
+
+        let mut items = vec![read_item(self)?];
+        while self.accept(',') {
+            items.push(read_item(self)?);
+        }
+        self.expect(')')?;
+
+        Ok(items)
+    }
+
+    ///
+    /// Skips an optional `Z`, `M` or `ZM` dimension after a geometry type
+    ///
+    fn skip_dimension(&mut self) {
+        self.skip_whitespace();
+
+        if self
+            .remaining
+            .starts_with(|c: char| c.is_ascii_alphabetic())
+            && !self.remaining.to_ascii_uppercase().starts_with("EMPTY")
+        {
+            self.keyword();
+        }
+    }
+}
+
+///
+/// Reads a WKT `POLYGON` or `MULTIPOLYGON` as a set of paths
+///
+/// Each polygon becomes an exterior path followed by a path for each of its holes: the exterior goes anticlockwise and
+/// the holes go clockwise. Any Z or M values are ignored.
+///
+pub fn paths_from_wkt<POut: BezierPathFactory>(wkt: &str) -> Result<Vec<POut>, GeoFormatError>
+where
+    POut::Point: Coordinate + Coordinate2D,
+{
+    let mut parser = WktParser { remaining: wkt };
+
+    let geometry_type = parser.keyword();
+    parser.skip_dimension();
+
+    let polygons = match geometry_type.as_str() {
+        "POLYGON" => vec![parser.list(|parser| parser.ring())?],
+        "MULTIPOLYGON" => parser.list(|parser| parser.list(|parser| parser.ring()))?,
+        "" => {
+            return Err(GeoFormatError::Syntax(
+                "expected a geometry type".to_string(),
+            ))
+        }
+        _ => return Err(GeoFormatError::UnsupportedGeometry(geometry_type)),
+    };
+
+    if !parser.remaining.trim().is_empty() {
+        return Err(GeoFormatError::Syntax(format!(
+            "unexpected text after the geometry: '{}'",
+            parser.remaining.trim()
+        )));
+    }
+
+    Ok(paths_from_rings(polygons))
+}
+
+///
+/// Writes a set of paths as a GeoJSON `MultiPolygon` geometry, flattening the curves to within `tolerance`
+///
+/// The paths are divided into polygons using `path_to_polygon()`, so holes are assigned to the polygons that contain them.
+/// Exterior rings go anticlockwise and holes go clockwise, as recommended by RFC 7946.
+///
+pub fn paths_to_geojson<P: BezierPath>(paths: &[P], tolerance: f64) -> String
+where
+    P::Point: Coordinate + Coordinate2D,
+{
+    let coordinates = path_to_polygon(paths, tolerance)
+        .iter()
+        .map(|polygon| {
+            closed_rings(polygon)
+                .map(|ring| ring.into_iter().map(|(x, y)| json!([x, y])).collect())
+                .collect::<Vec<Value>>()
+        })
+        .collect::<Vec<_>>();
+
+    json!({
+        "type": "MultiPolygon",
+        "coordinates": coordinates,
+    })
+    .to_string()
+}
+
+///
+/// Reads the rings of a polygon from a GeoJSON coordinates array
+///
+fn geojson_polygon(coordinates: &Value) -> Result<Vec<Vec<(f64, f64)>>, GeoFormatError> {
+    let invalid = || GeoFormatError::Syntax("invalid polygon coordinates".to_string());
+
+    coordinates
+        .as_array()
+        .ok_or_else(invalid)?
+        .iter()
+        .map(|ring| {
+            ring.as_array()
+                .ok_or_else(invalid)?
+                .iter()
+                .map(|point| {
+                    let point = point.as_array().ok_or_else(invalid)?;
+
+                    match (
+                        point.first().and_then(Value::as_f64),
+                        point.get(1).and_then(Value::as_f64),
+                    ) {
+                        (Some(x), Some(y)) => Ok((x, y)),
+                        _ => Err(invalid()),
+                    }
+                })
+                .collect()
+        })
+        .collect()
+}
+
+///
+/// Reads the polygons from a GeoJSON object, adding them to a list
+///
+fn geojson_polygons(
+    object: &Value,
+    polygons: &mut Vec<Vec<Vec<(f64, f64)>>>,
+) -> Result<(), GeoFormatError> {
+    let geometry_type = object
+        .get("type")
+        .and_then(Value::as_str)
+        .ok_or_else(|| GeoFormatError::Syntax("object has no type".to_string()))?;
+    let member = |name: &str| {
+        object
+            .get(name)
+            .ok_or_else(|| GeoFormatError::Syntax(format!("{} has no {}", geometry_type, name)))
+    };
+
+    match geometry_type {
+        "Polygon" => polygons.push(geojson_polygon(member("coordinates")?)?),
+
+        "MultiPolygon" => {
+            for polygon in member("coordinates")?.as_array().into_iter().flatten() {
+                polygons.push(geojson_polygon(polygon)?);
+            }
+        }
+
+        "GeometryCollection" => {
+            for geometry in member("geometries")?.as_array().into_iter().flatten() {
+                geojson_polygons(geometry, polygons)?;
+            }
+        }
+
+        "Feature" => {
+            let geometry = member("geometry")?;
+            if !geometry.is_null() {
+                geojson_polygons(geometry, polygons)?;
+            }
+        }
+
+        "FeatureCollection" => {
+            for feature in member("features")?.as_array().into_iter().flatten() {
+                geojson_polygons(feature, polygons)?;
+            }
+        }
+
+        _ => {
+            return Err(GeoFormatError::UnsupportedGeometry(
+                geometry_type.to_string(),
+            ))
+        }
+    }
+
+    Ok(())
+}
+
+///
+/// Reads the polygons in a GeoJSON document as a set of paths
+///
+/// The document can be a `Polygon` or `MultiPolygon` geometry, or a geometry collection, feature or feature collection
+/// containing them. Each polygon becomes an exterior path followed by a path for each of its holes: the exterior goes
+/// anticlockwise and the holes go clockwise.
+///
+pub fn paths_from_geojson<POut: BezierPathFactory>(
+    geojson: &str,
+) -> Result<Vec<POut>, GeoFormatError>
+where
+    POut::Point: Coordinate + Coordinate2D,
+{
+    let document = serde_json::from_str::<Value>(geojson)
+        .map_err(|err| GeoFormatError::Syntax(err.to_string()))?;
+
+    let mut polygons = vec![];
+    geojson_polygons(&document, &mut polygons)?;
+
+    Ok(paths_from_rings(polygons))
+}
@@ -0,0 +1,325 @@
+use super::{GraphPath, GraphPathEdge, GraphPathEdgeKind, GraphPathPoint};
+use crate::bezier::path::arithmetic::{PathDirection, PathLabel};
+use crate::geo::{Coordinate, Coordinate2D};
+
+use smallvec::smallvec;
+
+use std::error::Error;
+use std::fmt;
+use std::fmt::Write;
+
+/// The first line of a graph path debug string
+const DEBUG_STRING_HEADER: &str = "graph_path v1";
+
+///
+/// Trait implemented by labels that can be written to and read from a graph path debug string
+///
+pub trait GraphPathDebugLabel: Sized {
+    ///
+    /// Converts this label to a string (which should fit on a single line)
+    ///
+    fn to_debug_string(&self) -> String;
+
+    ///
+    /// Reads a label from a string generated by `to_debug_string()`
+    ///
+    fn from_debug_string(label: &str) -> Option<Self>;
+}
+
+impl GraphPathDebugLabel for () {
+    fn to_debug_string(&self) -> String {
+        String::new()
+    }
+
+    fn from_debug_string(_label: &str) -> Option<Self> {
+        Some(())
+    }
+}
+
+macro_rules! number_debug_label {
+    ($num_type: ty) => {
+        impl GraphPathDebugLabel for $num_type {
+            fn to_debug_string(&self) -> String {
+                self.to_string()
+            }
+
+            fn from_debug_string(label: &str) -> Option<Self> {
+                label.trim().parse().ok()
+            }
+        }
+    };
+}
+
+number_debug_label!(u32);
+number_debug_label!(u64);
+number_debug_label!(usize);
+number_debug_label!(i32);
+number_debug_label!(i64);
+
+impl GraphPathDebugLabel for PathLabel {
+    fn to_debug_string(&self) -> String {
+        let PathLabel(path_idx, direction) = self;
+        let direction = match direction {
+            PathDirection::Clockwise => "Clockwise",
+            PathDirection::Anticlockwise => "Anticlockwise",
+        };
+
+        format!("{} {}", path_idx, direction)
+    }
+
+    fn from_debug_string(label: &str) -> Option<Self> {
+        let mut parts = label.split_whitespace();
+        let path_idx = parts.next()?.parse().ok()?;
+        let direction = match parts.next()? {
+            "Clockwise" => PathDirection::Clockwise,
+            "Anticlockwise" => PathDirection::Anticlockwise,
+            _ => return None,
+        };
+
+        if parts.next().is_some() {
+            return None;
+        }
+
+        Some(PathLabel(path_idx, direction))
+    }
+}
+
+///
+/// Error generated when a graph path debug string cannot be read
+///
+#[derive(Clone, Debug, PartialEq)]
+pub struct GraphPathParseError {
+    /// The line number (counting from 1) where the error occurred, or 0 if the error is not on a particular line
+    pub line: usize,
+
+    /// Description of the error
+    pub message: String,
+}
+
+impl fmt::Display for GraphPathParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+impl Error for GraphPathParseError {}
+
+///
+/// Writes out the components of a point
+///
+fn write_point<Point: Coordinate>(result: &mut String, point: &Point) {
+    for component_idx in 0..Point::len() {
+        write!(result, " {:?}", point.get(component_idx)).unwrap();
+    }
+}
+
+///
+/// Reads the components of a point
+///
+fn read_point<'a, Point: Coordinate, Tokens: Iterator<Item = &'a str>>(
+    tokens: &mut Tokens,
+) -> Option<Point> {
+    let components = (0..Point::len())
+        .map(|_| tokens.next().and_then(|token| token.parse::<f64>().ok()))
+        .collect::<Option<Vec<_>>>()?;
+
+    Some(Point::from_components(&components))
+}
+
+///
+/// Returns the name used for an edge kind in a debug string
+///
+fn kind_name(kind: GraphPathEdgeKind) -> &'static str {
+    match kind {
+        GraphPathEdgeKind::Uncategorised => "Uncategorised",
+        GraphPathEdgeKind::Visited => "Visited",
+        GraphPathEdgeKind::Exterior => "Exterior",
+        GraphPathEdgeKind::Interior => "Interior",
+    }
+}
+
+///
+/// Reads an edge kind from a debug string
+///
+fn kind_from_name(name: &str) -> Option<GraphPathEdgeKind> {
+    match name {
+        "Uncategorised" => Some(GraphPathEdgeKind::Uncategorised),
+        "Visited" => Some(GraphPathEdgeKind::Visited),
+        "Exterior" => Some(GraphPathEdgeKind::Exterior),
+        "Interior" => Some(GraphPathEdgeKind::Interior),
+        _ => None,
+    }
+}
+
+impl<Point: Coordinate + Coordinate2D, Label: Copy + GraphPathDebugLabel> GraphPath<Point, Label> {
+    ///
+    /// Writes out this graph path as a string that can be read back in using `from_debug_string()`
+    ///
+    /// The string contains every point and edge in the graph, along with their labels and kinds, so it can be used to
+    /// capture the state of a path arithmetic operation (for example, to reproduce a failing case in a test). Coordinates
+    /// are written so that they read back as exactly the same value.
+    ///
+    pub fn to_debug_string(&self) -> String {
+        let mut result = String::new();
+
+        writeln!(result, "{}", DEBUG_STRING_HEADER).unwrap();
+        writeln!(result, "next_path_index {}", self.next_path_index).unwrap();
+
+        for (point_idx, point) in self.points.iter().enumerate() {
+            write!(result, "point {}", point_idx).unwrap();
+            write_point(&mut result, &point.position);
+            writeln!(result).unwrap();
+
+            for edge in point.forward_edges.iter() {
+                write!(
+                    result,
+                    "  edge {} following {} kind {} cp1",
+                    edge.end_idx,
+                    edge.following_edge_idx,
+                    kind_name(edge.kind)
+                )
+                .unwrap();
+                write_point(&mut result, &edge.cp1);
+                write!(result, " cp2").unwrap();
+                write_point(&mut result, &edge.cp2);
+                writeln!(result, " label {}", edge.label.to_debug_string()).unwrap();
+            }
+        }
+
+        result
+    }
+
+    ///
+    /// Reads a graph path from a string generated by `to_debug_string()`
+    ///
+    pub fn from_debug_string(debug_string: &str) -> Result<Self, GraphPathParseError> {
+        let error = |line: usize, message: &str| GraphPathParseError {
+            line: line + 1,
+            message: message.to_string(),
+        };
+        let graph_error = |message: &str| GraphPathParseError {
+            line: 0,
+            message: message.to_string(),
+        };
+
+        let mut lines = debug_string
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty());
+
+        // Header
+        match lines.next() {
+            Some((_, line)) if line.trim() == DEBUG_STRING_HEADER => {}
+            Some((line_num, _)) => return Err(error(line_num, "Missing graph_path header")),
+            None => return Err(graph_error("Empty string")),
+        }
+
+        let mut next_path_index = 0;
+        let mut points: Vec<GraphPathPoint<Point, Label>> = vec![];
+
+        for (line_num, line) in lines {
+            let mut tokens = line.split_whitespace();
+
+            match tokens.next() {
+                Some("next_path_index") => {
+                    next_path_index = tokens
+                        .next()
+                        .and_then(|token| token.parse().ok())
+                        .ok_or_else(|| error(line_num, "Invalid next_path_index"))?;
+                }
+
+                Some("point") => {
+                    let point_idx = tokens
+                        .next()
+                        .and_then(|token| token.parse::<usize>().ok())
+                        .ok_or_else(|| error(line_num, "Invalid point index"))?;
+                    if point_idx != points.len() {
+                        return Err(error(line_num, "Points are not in order"));
+                    }
+
+                    let position = read_point(&mut tokens)
+                        .ok_or_else(|| error(line_num, "Invalid point position"))?;
+
+                    points.push(GraphPathPoint::new(position, smallvec![], smallvec![]));
+                }
+
+                Some("edge") => {
+                    // The label is everything after the 'label' keyword
+                    let (edge_def, label) = line
+                        .split_once(" label ")
+                        .or_else(|| line.strip_suffix(" label").map(|edge_def| (edge_def, "")))
+                        .ok_or_else(|| error(line_num, "Edge has no label"))?;
+                    let label = Label::from_debug_string(label)
+                        .ok_or_else(|| error(line_num, "Invalid label"))?;
+
+                    let mut tokens = edge_def.split_whitespace().skip(1);
+                    let end_idx = tokens
+                        .next()
+                        .and_then(|token| token.parse::<usize>().ok())
+                        .ok_or_else(|| error(line_num, "Invalid end point index"))?;
+
+                    let following_edge_idx = match (tokens.next(), tokens.next()) {
+                        (Some("following"), Some(idx)) => idx.parse::<usize>().ok(),
+                        _ => None,
+                    }
+                    .ok_or_else(|| error(line_num, "Invalid following edge index"))?;
+
+                    let kind = match (tokens.next(), tokens.next()) {
+                        (Some("kind"), Some(kind)) => kind_from_name(kind),
+                        _ => None,
+                    }
+                    .ok_or_else(|| error(line_num, "Invalid edge kind"))?;
+
+                    let cp1 = match tokens.next() {
+                        Some("cp1") => read_point(&mut tokens),
+                        _ => None,
+                    }
+                    .ok_or_else(|| error(line_num, "Invalid first control point"))?;
+
+                    let cp2 = match tokens.next() {
+                        Some("cp2") => read_point(&mut tokens),
+                        _ => None,
+                    }
+                    .ok_or_else(|| error(line_num, "Invalid second control point"))?;
+
+                    let point = points
+                        .last_mut()
+                        .ok_or_else(|| error(line_num, "Edge is not attached to a point"))?;
+                    point.forward_edges.push(GraphPathEdge::new(
+                        kind,
+                        (cp1, cp2),
+                        end_idx,
+                        label,
+                        following_edge_idx,
+                    ));
+                }
+
+                _ => return Err(error(line_num, "Unrecognised line")),
+            }
+        }
+
+        // Check that all of the edges refer to valid points and edges
+        for point in points.iter() {
+            for edge in point.forward_edges.iter() {
+                if edge.end_idx >= points.len() {
+                    return Err(graph_error("Edge ends at a point that does not exist"));
+                }
+
+                let following_edges = points[edge.end_idx].forward_edges.len();
+                if following_edges > 0 && edge.following_edge_idx >= following_edges {
+                    return Err(graph_error(
+                        "Edge is followed by an edge that does not exist",
+                    ));
+                }
+            }
+        }
+
+        let mut path = Self {
+            points,
+            next_path_index,
+        };
+        path.recalculate_reverse_connections();
+
+        Ok(path)
+    }
+}
@@ -0,0 +1,224 @@
+use super::{GraphEdgeRef, GraphPath, GraphPathPoint};
+use crate::bezier::path::arithmetic::PathDirection;
+use crate::bezier::path::nesting::normalize_winding;
+use crate::bezier::path::path::BezierPathFactory;
+use crate::geo::{Coordinate, Coordinate2D};
+
+use smallvec::SmallVec;
+
+impl<Point: Coordinate + Coordinate2D, Label: Copy> GraphPath<Point, Label> {
+    ///
+    /// Reverses the direction of a single edge, returning a reference to the reversed edge
+    ///
+    /// The reversed edge starts where the original edge ended, so it will have a new position in the graph. Reversing a
+    /// single edge breaks the loop it was part of: the edges before and after it are left following whichever edges they
+    /// can (ie, the loop is only consistent again once all of its edges have been reversed). Use `reverse_path()` to
+    /// reverse a whole loop at once.
+    ///
+    pub fn reverse_edge(&mut self, edge: GraphEdgeRef) -> GraphEdgeRef {
+        let edge = self.forward_edge_ref(edge);
+        let new_indexes = self.reverse_edges(|start_idx, edge_idx| {
+            start_idx == edge.start_idx && edge_idx == edge.edge_idx
+        });
+
+        new_indexes[edge.start_idx][edge.edge_idx]
+    }
+
+    ///
+    /// Reverses the direction of the loop of edges that an edge is part of
+    ///
+    /// The loop is found by following the edges that follow the specified edge until it returns to the start. If it never
+    /// gets back to the start (in a graph with gaps in it), all of the edges that were followed are reversed.
+    ///
+    pub fn reverse_path(&mut self, edge: GraphEdgeRef) {
+        let edge = self.forward_edge_ref(edge);
+
+        // Follow the edges around the loop
+        let mut in_loop = vec![SmallVec::<[bool; 2]>::new(); self.points.len()];
+        for (point_idx, point) in self.points.iter().enumerate() {
+            in_loop[point_idx].resize(point.forward_edges.len(), false);
+        }
+
+        let mut current = edge;
+        while !in_loop[current.start_idx][current.edge_idx] {
+            in_loop[current.start_idx][current.edge_idx] = true;
+
+            let following = self.following_edge_ref(current);
+            if following.edge_idx >= self.points[following.start_idx].forward_edges.len() {
+                break;
+            }
+
+            current = following;
+        }
+
+        self.reverse_edges(|start_idx, edge_idx| in_loop[start_idx][edge_idx]);
+        self.check_following_edge_consistency();
+    }
+
+    ///
+    /// Returns the exterior paths of this graph (as for `exterior_paths()`), with the outlines going in a particular
+    /// direction and the holes going in the other direction
+    ///
+    /// The direction of the paths returned by `exterior_paths()` depends on how the graph was traversed. This uses
+    /// `normalize_winding()` to find which paths are holes and orient them consistently.
+    ///
+    pub fn exterior_paths_with_direction<POut: BezierPathFactory<Point = Point>>(
+        &self,
+        outline_direction: PathDirection,
+    ) -> Vec<POut> {
+        let paths = self.exterior_paths::<POut>();
+
+        normalize_winding(&paths, outline_direction)
+    }
+
+    ///
+    /// Returns the forward version of an edge ref
+    ///
+    #[inline]
+    fn forward_edge_ref(&self, edge: GraphEdgeRef) -> GraphEdgeRef {
+        // Reversed edge refs still refer to the edge by where it's stored
+        GraphEdgeRef {
+            reverse: false,
+            ..edge
+        }
+    }
+
+    ///
+    /// Reverses all the edges matching a predicate, returning where each of the original edges is in the new graph
+    ///
+    fn reverse_edges<FnShouldReverse: Fn(usize, usize) -> bool>(
+        &mut self,
+        should_reverse: FnShouldReverse,
+    ) -> Vec<SmallVec<[GraphEdgeRef; 2]>> {
+        let num_points = self.points.len();
+        let is_reversed = (0..num_points)
+            .map(|point_idx| {
+                (0..self.points[point_idx].forward_edges.len())
+                    .map(|edge_idx| should_reverse(point_idx, edge_idx))
+                    .collect::<SmallVec<[_; 2]>>()
+            })
+            .collect::<Vec<_>>();
+
+        // The edges that aren't reversed stay where they are, and the reversed edges are moved to the end of the list of
+        // edges for the point that they now start at
+        let mut new_counts = vec![0; num_points];
+        let mut new_indexes = vec![SmallVec::<[GraphEdgeRef; 2]>::new(); num_points];
+        for point_idx in 0..num_points {
+            for edge_is_reversed in is_reversed[point_idx].iter() {
+                if !*edge_is_reversed {
+                    new_indexes[point_idx].push(GraphEdgeRef {
+                        start_idx: point_idx,
+                        edge_idx: new_counts[point_idx],
+                        reverse: false,
+                    });
+                    new_counts[point_idx] += 1;
+                } else {
+                    // Placeholder, filled in below
+                    new_indexes[point_idx].push(GraphEdgeRef {
+                        start_idx: point_idx,
+                        edge_idx: 0,
+                        reverse: false,
+                    });
+                }
+            }
+        }
+
+        for point_idx in 0..num_points {
+            for edge_idx in 0..self.points[point_idx].forward_edges.len() {
+                if is_reversed[point_idx][edge_idx] {
+                    let end_idx = self.points[point_idx].forward_edges[edge_idx].end_idx;
+                    new_indexes[point_idx][edge_idx] = GraphEdgeRef {
+                        start_idx: end_idx,
+                        edge_idx: new_counts[end_idx],
+                        reverse: false,
+                    };
+                    new_counts[end_idx] += 1;
+                }
+            }
+        }
+
+        // Find the edge that precedes each edge, so the reversed edges can follow the reversed version of it
+        let mut preceding = self
+            .points
+            .iter()
+            .map(|point| {
+                SmallVec::<[Option<(usize, usize)>; 2]>::from_elem(None, point.forward_edges.len())
+            })
+            .collect::<Vec<_>>();
+        for point_idx in 0..num_points {
+            for (edge_idx, edge) in self.points[point_idx].forward_edges.iter().enumerate() {
+                if let Some(following) = preceding[edge.end_idx].get_mut(edge.following_edge_idx) {
+                    if following.is_none() {
+                        *following = Some((point_idx, edge_idx));
+                    }
+                }
+            }
+        }
+
+        // Build the new list of edges
+        let mut new_points = self
+            .points
+            .iter()
+            .map(|point| GraphPathPoint {
+                position: point.position,
+                forward_edges: SmallVec::new(),
+                connected_from: SmallVec::new(),
+            })
+            .collect::<Vec<_>>();
+        let mut reversed_edges = vec![];
+
+        for point_idx in 0..num_points {
+            for (edge_idx, edge) in self.points[point_idx].forward_edges.iter().enumerate() {
+                let mut new_edge = edge.clone();
+
+                if is_reversed[point_idx][edge_idx] {
+                    // Follow the reversed version of the preceding edge if it was reversed too, or any edge leaving
+                    // the new end point otherwise
+                    new_edge.following_edge_idx = match preceding[point_idx][edge_idx] {
+                        Some((previous_point_idx, previous_edge_idx))
+                            if is_reversed[previous_point_idx][previous_edge_idx] =>
+                        {
+                            new_indexes[previous_point_idx][previous_edge_idx].edge_idx
+                        }
+                        _ => 0,
+                    };
+                    new_edge.end_idx = point_idx;
+                    new_edge.cp1 = edge.cp2;
+                    new_edge.cp2 = edge.cp1;
+                    new_edge.invalidate_cache();
+
+                    let new_ref = new_indexes[point_idx][edge_idx];
+                    reversed_edges.push((new_ref, new_edge));
+                } else {
+                    // Keep following the same edge unless it has been reversed
+                    let following_reversed = is_reversed[edge.end_idx]
+                        .get(edge.following_edge_idx)
+                        .copied()
+                        .unwrap_or(false);
+
+                    new_edge.following_edge_idx = if following_reversed {
+                        0
+                    } else {
+                        new_indexes[edge.end_idx]
+                            .get(edge.following_edge_idx)
+                            .map(|following| following.edge_idx)
+                            .unwrap_or(0)
+                    };
+
+                    new_points[point_idx].forward_edges.push(new_edge);
+                }
+            }
+        }
+
+        // The reversed edges are added in the order that their indexes were assigned
+        for (new_ref, new_edge) in reversed_edges {
+            test_assert!(new_points[new_ref.start_idx].forward_edges.len() == new_ref.edge_idx);
+            new_points[new_ref.start_idx].forward_edges.push(new_edge);
+        }
+
+        self.points = new_points;
+        self.recalculate_reverse_connections();
+
+        new_indexes
+    }
+}
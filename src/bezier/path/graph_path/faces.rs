@@ -0,0 +1,162 @@
+use super::{GraphEdgeRef, GraphPath};
+use crate::bezier::curve::BezierCurve;
+use crate::bezier::path::path::BezierPathFactory;
+use crate::geo::{Coordinate, Coordinate2D};
+
+use std::cmp::Ordering;
+use std::collections::HashSet;
+use std::f64;
+
+/// Faces with an area smaller than this are considered to be degenerate (eg, the 'face' formed between two identical edges)
+const MIN_FACE_AREA: f64 = 1e-6;
+
+impl<Point: Coordinate + Coordinate2D, Label: Copy> GraphPath<Point, Label> {
+    ///
+    /// Returns the angle of the tangent at the start of an edge (which is the direction it leaves its start point in)
+    ///
+    fn edge_leaving_angle(&self, edge: GraphEdgeRef) -> f64 {
+        let edge = self.get_edge(edge);
+        let start = edge.start_point();
+        let (cp1, cp2) = edge.control_points();
+
+        // Use the first point that's not in the same place as the start point for the direction
+        let direction = [cp1, cp2, edge.end_point()]
+            .iter()
+            .map(|point| *point - start)
+            .find(|direction| direction.dot(direction) > 0.0)
+            .unwrap_or_else(Point::origin);
+
+        direction.y().atan2(direction.x())
+    }
+
+    ///
+    /// Returns all of the half-edges that leave a particular point, sorted anticlockwise by the angle they leave the point at
+    ///
+    fn edges_leaving_point_by_angle(&self, point_idx: usize) -> Vec<(f64, GraphEdgeRef)> {
+        let mut edges = self
+            .edges_for_point(point_idx)
+            .chain(self.reverse_edges_for_point(point_idx))
+            .map(|edge| GraphEdgeRef::from(&edge))
+            .map(|edge| (self.edge_leaving_angle(edge), edge))
+            .collect::<Vec<_>>();
+
+        edges.sort_by(|(angle_a, _), (angle_b, _)| {
+            angle_a.partial_cmp(angle_b).unwrap_or(Ordering::Equal)
+        });
+
+        edges
+    }
+
+    ///
+    /// Computes the signed area of a set of edges forming a loop (positive values indicate an anticlockwise area)
+    ///
+    fn signed_area(&self, edges: &[GraphEdgeRef]) -> f64 {
+        // Approximate the curves by sampling a few points from each one
+        let points = edges
+            .iter()
+            .flat_map(|edge| {
+                let edge = self.get_edge(*edge);
+                (0..8).map(move |t| edge.point_at_pos((t as f64) / 8.0))
+            })
+            .collect::<Vec<_>>();
+
+        let mut area = 0.0;
+        for point_idx in 0..points.len() {
+            let p1 = &points[point_idx];
+            let p2 = &points[(point_idx + 1) % points.len()];
+
+            area += p1.x() * p2.y() - p2.x() * p1.y();
+        }
+
+        area * 0.5
+    }
+
+    ///
+    /// Finds the faces formed by the edges in this graph
+    ///
+    /// After paths have been collided, the edges in the graph divide the plane into a set of regions. This returns each of the
+    /// bounded regions as a path, along with the labels of the edges that bound it (one label for each section of the path,
+    /// in the same order). All edges are considered regardless of their kind, and every face is returned with a clockwise
+    /// winding, which is the same direction as the outer paths generated by the path arithmetic operations.
+    ///
+    /// The faces are found by walking around the edges connected to each point: if a part of the graph is not connected to
+    /// the edges surrounding it (eg, a circle inside another circle), the outer face will not have a hole cut in it.
+    ///
+    pub fn faces<POut: BezierPathFactory<Point = Point>>(&self) -> Vec<(POut, Vec<Label>)> {
+        // The edges leaving each point, ordered by angle
+        let leaving_edges = (0..self.points.len())
+            .map(|point_idx| self.edges_leaving_point_by_angle(point_idx))
+            .collect::<Vec<_>>();
+
+        let max_face_len = leaving_edges.iter().map(|edges| edges.len()).sum::<usize>();
+        let mut visited = HashSet::new();
+        let mut faces = vec![];
+
+        for edges in leaving_edges.iter() {
+            for (_angle, first_edge) in edges.iter() {
+                if visited.contains(first_edge) {
+                    continue;
+                }
+
+                // Walk around the face, always turning onto the edge that's clockwise of the one we arrived on
+                let mut face_edges = vec![];
+                let mut current_edge = *first_edge;
+
+                loop {
+                    visited.insert(current_edge);
+                    face_edges.push(current_edge);
+
+                    let end_idx = self.get_edge(current_edge).end_point_index();
+                    let arriving_edge = current_edge.reversed();
+                    let candidates = &leaving_edges[end_idx];
+
+                    let arriving_idx = candidates
+                        .iter()
+                        .position(|(_angle, edge)| *edge == arriving_edge)
+                        .unwrap_or(0);
+                    let next_idx = (arriving_idx + candidates.len() - 1) % candidates.len();
+
+                    current_edge = candidates[next_idx].1;
+
+                    if current_edge == *first_edge
+                        || visited.contains(&current_edge)
+                        || face_edges.len() > max_face_len
+                    {
+                        break;
+                    }
+                }
+
+                // Bounded faces are anticlockwise: the unbounded face around each connected part of the graph is clockwise
+                if current_edge != *first_edge || self.signed_area(&face_edges) < MIN_FACE_AREA {
+                    continue;
+                }
+
+                // Follow the face in the opposite direction so it's returned clockwise
+                let face_edges = face_edges
+                    .iter()
+                    .rev()
+                    .map(|edge| edge.reversed())
+                    .collect::<Vec<_>>();
+
+                let start_point = self.get_edge(face_edges[0]).start_point();
+                let sections = face_edges
+                    .iter()
+                    .map(|edge| {
+                        let edge = self.get_edge(*edge);
+                        let (cp1, cp2) = edge.control_points();
+
+                        (cp1, cp2, edge.end_point())
+                    })
+                    .collect::<Vec<_>>();
+                let labels = face_edges
+                    .iter()
+                    .map(|edge| self.edge_label(*edge))
+                    .collect();
+
+                faces.push((POut::from_points(start_point, sections), labels));
+            }
+        }
+
+        faces
+    }
+}
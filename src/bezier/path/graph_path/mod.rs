@@ -1,24 +1,32 @@
 use super::path::{BezierPath, BezierPathFactory};
+use super::progress::{OperationCancelled, ProgressMonitor};
 use crate::bezier::curve::BezierCurve;
 use crate::consts::CLOSE_DISTANCE;
-use crate::geo::{Coordinate, Coordinate2D, Geo};
+use crate::geo::{Coordinate, Coordinate2D, Geo, Transform2D, Transformable};
 
 use smallvec::{smallvec, SmallVec};
 
 use std::cell::RefCell;
 use std::fmt;
 
+mod debug_string;
+mod direction;
 mod edge;
 mod edge_ref;
+mod faces;
 mod path_collision;
 mod ray_collision;
+mod remove_edges;
+mod reuse;
+mod svg_debug;
 
 #[cfg(test)]
 pub(crate) mod test;
 
+pub use self::debug_string::*;
 pub use self::edge::*;
 pub use self::edge_ref::*;
-pub use self::path_collision::*;
+pub(crate) use self::path_collision::*;
 pub use self::ray_collision::*;
 
 /// Maximum number of edges to traverse when 'healing' gaps found in an external path
@@ -165,8 +173,46 @@ impl<Point: Coordinate + Coordinate2D, Label: Copy> GraphPath<Point, Label> {
     /// Creates a graph path from a bezier path
     ///
     pub fn from_path<P: BezierPath<Point = Point>>(path: &P, label: Label) -> Self {
+        let mut graph_path = Self::new();
+        graph_path.append_path(path, label);
+
+        graph_path
+    }
+
+    ///
+    /// Creates a graph path from a bezier path, with a different label for each section of the path
+    ///
+    /// `label_for_section` is called with the index of each section of the path. If the path doesn't end at its start
+    /// point, it's closed with a straight line, which is labelled as the section after the last one.
+    ///
+    pub fn from_path_with_section_labels<P: BezierPath<Point = Point>>(
+        path: &P,
+        label_for_section: impl FnMut(usize) -> Label,
+    ) -> Self {
+        let mut graph_path = Self::new();
+        graph_path.append_path_with_section_labels(path, label_for_section);
+
+        graph_path
+    }
+
+    ///
+    /// Adds the edges for a bezier path to the end of this graph (without colliding it with the existing edges)
+    ///
+    fn append_path<P: BezierPath<Point = Point>>(&mut self, path: &P, label: Label) {
+        self.append_path_with_section_labels(path, |_| label);
+    }
+
+    ///
+    /// Adds the edges for a bezier path to the end of this graph, calling a function to generate the label for each section
+    ///
+    fn append_path_with_section_labels<P: BezierPath<Point = Point>>(
+        &mut self,
+        path: &P,
+        mut label_for_section: impl FnMut(usize) -> Label,
+    ) {
         // All edges are exterior for a single path
-        let mut points = vec![];
+        let points = &mut self.points;
+        let first_point_idx = points.len();
 
         // Push the start point (with an open path)
         let start_point = path.start_point();
@@ -174,11 +220,14 @@ impl<Point: Coordinate + Coordinate2D, Label: Copy> GraphPath<Point, Label> {
 
         // We'll add edges to the previous point
         let mut last_point_pos = start_point;
-        let mut last_point_idx = 0;
-        let mut next_point_idx = 1;
+        let mut last_point_idx = first_point_idx;
+        let mut next_point_idx = first_point_idx + 1;
 
         // Iterate through the points in the path
-        for (cp1, cp2, end_point) in path.points() {
+        let mut num_sections = 0;
+        for (section_idx, (cp1, cp2, end_point)) in path.points().enumerate() {
+            num_sections = section_idx + 1;
+
             // Ignore points that are too close to the last point
             if end_point.is_near_to(&last_point_pos, CLOSE_DISTANCE)
                 && cp1.is_near_to(&last_point_pos, CLOSE_DISTANCE)
@@ -197,7 +246,7 @@ impl<Point: Coordinate + Coordinate2D, Label: Copy> GraphPath<Point, Label> {
                     GraphPathEdgeKind::Uncategorised,
                     (cp1, cp2),
                     next_point_idx,
-                    label,
+                    label_for_section(section_idx),
                     0,
                 ));
 
@@ -208,7 +257,7 @@ impl<Point: Coordinate + Coordinate2D, Label: Copy> GraphPath<Point, Label> {
         }
 
         // Close the path
-        if last_point_idx > 0 {
+        if last_point_idx > first_point_idx {
             // Graph actually has some edges
             if start_point.distance_to(&points[last_point_idx].position) < CLOSE_DISTANCE {
                 // Remove the last point (we're replacing it with an edge back to the start)
@@ -216,7 +265,7 @@ impl<Point: Coordinate + Coordinate2D, Label: Copy> GraphPath<Point, Label> {
                 last_point_idx -= 1;
 
                 // Change the edge to point back to the start
-                points[last_point_idx].forward_edges[0].end_idx = 0;
+                points[last_point_idx].forward_edges[0].end_idx = first_point_idx;
             } else {
                 // Need to draw a line to the last point (as there is always a single following edge, the following edge index is always 0 here)
                 let close_vector = points[last_point_idx].position - start_point;
@@ -228,8 +277,8 @@ impl<Point: Coordinate + Coordinate2D, Label: Copy> GraphPath<Point, Label> {
                     .push(GraphPathEdge::new(
                         GraphPathEdgeKind::Uncategorised,
                         (cp1, cp2),
-                        0,
-                        label,
+                        first_point_idx,
+                        label_for_section(num_sections),
                         0,
                     ));
             }
@@ -238,13 +287,20 @@ impl<Point: Coordinate + Coordinate2D, Label: Copy> GraphPath<Point, Label> {
             points.pop();
         }
 
-        // Create the graph path from the points
-        let mut path = Self {
-            points,
-            next_path_index: 1,
-        };
-        path.recalculate_reverse_connections();
-        path
+        // Add the reverse connections for the new points (which can only be connected to each other)
+        for point_idx in first_point_idx..points.len() {
+            for edge_idx in 0..points[point_idx].forward_edges.len() {
+                let end_idx = points[point_idx].forward_edges[edge_idx].end_idx;
+                points[end_idx].connected_from.push(point_idx);
+            }
+        }
+
+        for point in points[first_point_idx..].iter_mut() {
+            point.connected_from.sort_unstable();
+            point.connected_from.dedup();
+        }
+
+        self.next_path_index += 1;
     }
 
     ///
@@ -262,13 +318,49 @@ impl<Point: Coordinate + Coordinate2D, Label: Copy> GraphPath<Point, Label> {
 
         // Merge each path in turn
         for (path, label) in paths {
-            let path = Self::from_path(path, label);
-            merged_path = merged_path.merge(path);
+            merged_path.append_path(path, label);
         }
 
         merged_path
     }
 
+    ///
+    /// Creates a copy of this graph path with a new set of labels, generated by calling a function on the existing labels
+    ///
+    /// The points and edges are not changed, so a `GraphEdgeRef` for this graph refers to the same edge in the new graph.
+    ///
+    pub fn map_labels<NewLabel: Copy>(
+        &self,
+        map_label: impl Fn(Label) -> NewLabel,
+    ) -> GraphPath<Point, NewLabel> {
+        let points = self
+            .points
+            .iter()
+            .map(|point| GraphPathPoint {
+                position: point.position,
+                forward_edges: point
+                    .forward_edges
+                    .iter()
+                    .map(|edge| GraphPathEdge {
+                        label: map_label(edge.label),
+                        following_edge_idx: edge.following_edge_idx,
+                        kind: edge.kind,
+                        cp1: edge.cp1,
+                        cp2: edge.cp2,
+                        end_idx: edge.end_idx,
+                        bbox: edge.bbox.clone(),
+                    })
+                    .collect(),
+                connected_from: point.connected_from.clone(),
+            })
+            .collect();
+
+        GraphPath {
+            points,
+            next_path_index: self.next_path_index,
+        }
+    }
+
     ///
     /// Recomputes the list of items that have connections to each point
     ///
@@ -353,6 +445,19 @@ impl<Point: Coordinate + Coordinate2D, Label: Copy> GraphPath<Point, Label> {
             })
     }
 
+    ///
+    /// Returns an iterator of the edges in this graph that have a particular label
+    ///
+    pub fn edges_with_label(
+        &self,
+        label: Label,
+    ) -> impl Iterator<Item = GraphEdge<'_, Point, Label>>
+    where
+        Label: PartialEq,
+    {
+        self.all_edges().filter(move |edge| edge.label() == label)
+    }
+
     ///
     /// Returns the edge refs for a particular point
     ///
@@ -584,7 +689,7 @@ impl<Point: Coordinate + Coordinate2D, Label: Copy> GraphPath<Point, Label> {
     ///
     /// 'Very short' edges are edges that start and end at the same point and have control points very close to the start position
     ///
-    fn remove_all_very_short_edges(&mut self) {
+    pub(crate) fn remove_all_very_short_edges(&mut self) {
         for point_idx in 0..(self.points.len()) {
             let mut edge_idx = 0;
             while edge_idx < self.points[point_idx].forward_edges.len() {
@@ -665,6 +770,32 @@ impl<Point: Coordinate + Coordinate2D, Label: Copy> GraphPath<Point, Label> {
         self
     }
 
+    ///
+    /// As for `collide()`, but reports progress to a monitor while the edges are being collided, stopping if it cancels
+    /// the operation
+    ///
+    pub(crate) fn collide_with_progress<Monitor: ProgressMonitor + ?Sized>(
+        mut self,
+        collide_path: Self,
+        accuracy: f64,
+        monitor: &Monitor,
+    ) -> Result<Self, OperationCancelled> {
+        // Generate a merged path with all of the edges
+        let collision_offset = self.points.len();
+        self = self.merge(collide_path);
+
+        // Search for collisions between our original path and the new one
+        let total_points = self.points.len();
+        self.detect_collisions_with_progress(
+            0..collision_offset,
+            collision_offset..total_points,
+            accuracy,
+            monitor,
+        )?;
+
+        Ok(self)
+    }
+
     ///
     /// Rounds all of the points in this path to a particular accuracy level
     ///
@@ -1000,6 +1131,23 @@ impl<Point: Coordinate + Coordinate2D, Label: Copy> GraphPath<Point, Label> {
     /// Finds the exterior edges and turns them into a series of paths
     ///
     pub fn exterior_paths<POut: BezierPathFactory<Point = Point>>(&self) -> Vec<POut> {
+        self.exterior_paths_with_labels()
+            .into_iter()
+            .map(|(path, _labels)| path)
+            .collect()
+    }
+
+    ///
+    /// Finds the exterior edges and turns them into a series of paths, returning the labels of the edges that make up
+    /// each section of the paths
+    ///
+    /// The labels are in the same order as the sections of the path they're paired with. After a path arithmetic
+    /// operation, these are the `PathLabel`s of the paths that each section came from, which can be used to carry styling
+    /// or other information about the source paths through to the result.
+    ///
+    pub fn exterior_paths_with_labels<POut: BezierPathFactory<Point = Point>>(
+        &self,
+    ) -> Vec<(POut, Vec<Label>)> {
         // List of paths returned by this function
         let mut exterior_paths = vec![];
 
@@ -1092,6 +1240,7 @@ impl<Point: Coordinate + Coordinate2D, Label: Copy> GraphPath<Point, Label> {
             // If we found a loop, generate a path
             if previous_point[point_idx].is_some() {
                 let mut path_points = vec![];
+                let mut path_labels = vec![];
                 let mut cur_point_idx = point_idx;
 
                 while let Some((last_point_idx, ref edge)) = previous_point[cur_point_idx] {
@@ -1100,6 +1249,7 @@ impl<Point: Coordinate + Coordinate2D, Label: Copy> GraphPath<Point, Label> {
                     let start_point = edge.start_point();
 
                     path_points.push((cp2, cp1, start_point));
+                    path_labels.push(edge.label());
 
                     // Mark this point as visited so we don't try to include it in a future path
                     visited[last_point_idx] = true;
@@ -1117,7 +1267,7 @@ impl<Point: Coordinate + Coordinate2D, Label: Copy> GraphPath<Point, Label> {
                 let start_point = self.points[point_idx].position;
 
                 let new_path = POut::from_points(start_point, path_points);
-                exterior_paths.push(new_path);
+                exterior_paths.push((new_path, path_labels));
             }
         }
 
@@ -1138,6 +1288,43 @@ pub struct GraphEdge<'a, Point: 'a, Label: 'a> {
     edge: GraphEdgeRef,
 }
 
+impl<Point: Coordinate + Coordinate2D, Label: Copy> Transformable for GraphPath<Point, Label> {
+    ///
+    /// Transforms all of the points and edges in this graph path, keeping the labels and the edge kinds
+    ///
+    fn transform(&self, transform: &Transform2D) -> Self {
+        let points = self
+            .points
+            .iter()
+            .map(|point| GraphPathPoint {
+                position: transform.transform_point(&point.position),
+                forward_edges: point
+                    .forward_edges
+                    .iter()
+                    .map(|edge| {
+                        GraphPathEdge::new(
+                            edge.kind,
+                            (
+                                transform.transform_point(&edge.cp1),
+                                transform.transform_point(&edge.cp2),
+                            ),
+                            edge.end_idx,
+                            edge.label,
+                            edge.following_edge_idx,
+                        )
+                    })
+                    .collect(),
+                connected_from: point.connected_from.clone(),
+            })
+            .collect();
+
+        GraphPath {
+            points,
+            next_path_index: self.next_path_index,
+        }
+    }
+}
+
 impl<Point: Coordinate2D + Coordinate + fmt::Debug, Label: Copy> fmt::Debug
     for GraphPath<Point, Label>
 {
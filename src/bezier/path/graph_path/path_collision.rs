@@ -1,7 +1,14 @@
 use super::{GraphEdge, GraphEdgeRef, GraphPath, GraphPathEdge, GraphPathPoint};
+use crate::bezier::basis::basis;
 use crate::bezier::curve::{BezierCurve, BezierCurveFactory, Curve};
 use crate::bezier::intersection::{curve_intersects_curve_clip, find_self_intersection_point};
+use crate::bezier::path::progress::{
+    report_progress, NoProgressMonitor, OperationCancelled, OperationPhase, ProgressMonitor,
+};
+use crate::bezier::solve::solve_basis_for_t;
 use crate::consts::{CLOSE_DISTANCE, SMALL_T_DISTANCE};
+#[cfg(feature = "instrumentation")]
+use crate::debug::GraphPathEvent;
 use crate::geo::{
     sweep_against, sweep_self, BoundingBox, Bounds, Coordinate, Coordinate2D, Geo, HasBoundingBox,
 };
@@ -12,6 +19,14 @@ use std::cmp::Ordering;
 use std::mem;
 use std::ops::Range;
 
+/// The proportion of the work of colliding two paths that is spent finding the collisions (the rest is spent dividing the
+/// edges where they collide)
+const FIND_COLLISIONS_PROGRESS: f64 = 0.9;
+
+/// Straight edges that cross at an angle with a sine smaller than this are considered to be parallel, and are collided
+/// using the clipping algorithm (which can find where they overlap)
+const MIN_LINE_INTERSECTION_SIN_ANGLE: f64 = 1e-6;
+
 ///
 /// Struct describing a collision between two edges
 ///
@@ -101,16 +116,29 @@ impl<Point: Coordinate + Coordinate2D, Label: Copy> GraphPath<Point, Label> {
     ///
     /// Finds the self collisions in a range
     ///
-    fn find_self_collisions(&self, points: Range<usize>, accuracy: f64) -> Vec<Collision> {
+    fn find_self_collisions<Monitor: ProgressMonitor + ?Sized>(
+        &self,
+        points: Range<usize>,
+        accuracy: f64,
+        monitor: &Monitor,
+    ) -> Result<Vec<Collision>, OperationCancelled> {
         // Sort the edges into min_x order
         let ordered_edges = self.get_ordered_edges(points);
 
         // Find the collisions
         let mut collisions = vec![];
+        let edge_pairs = sweep_self(ordered_edges.iter()).collect::<Vec<_>>();
+        let num_pairs = edge_pairs.len() as f64;
+
+        for (pair_idx, (src_curve, tgt_curve)) in edge_pairs.into_iter().enumerate() {
+            report_progress(
+                monitor,
+                OperationPhase::Colliding,
+                FIND_COLLISIONS_PROGRESS * (pair_idx as f64) / num_pairs,
+            )?;
 
-        for (src_curve, tgt_curve) in sweep_self(ordered_edges.iter()) {
             // Find any collisions between the two edges (to the required accuracy)
-            let mut edge_collisions = curve_intersects_curve_clip(src_curve, tgt_curve, accuracy);
+            let mut edge_collisions = edge_intersections(src_curve, tgt_curve, accuracy);
             if edge_collisions.is_empty() {
                 continue;
             }
@@ -167,20 +195,21 @@ impl<Point: Coordinate + Coordinate2D, Label: Copy> GraphPath<Point, Label> {
             }
         }
 
-        collisions
+        Ok(collisions)
     }
 
     ///
     /// Finds any collisions that might exist between two ranges of points
     ///
-    fn find_collisions(
+    fn find_collisions<Monitor: ProgressMonitor + ?Sized>(
         &self,
         collide_from: Range<usize>,
         collide_to: Range<usize>,
         accuracy: f64,
-    ) -> Vec<Collision> {
+        monitor: &Monitor,
+    ) -> Result<Vec<Collision>, OperationCancelled> {
         if collide_from == collide_to {
-            return self.find_self_collisions(collide_from, accuracy);
+            return self.find_self_collisions(collide_from, accuracy, monitor);
         }
 
         // Order the edges for the two sides that are going to be collided
@@ -189,10 +218,18 @@ impl<Point: Coordinate + Coordinate2D, Label: Copy> GraphPath<Point, Label> {
 
         // Perform a sweep to find any collisions
         let mut collisions = vec![];
+        let edge_pairs = sweep_against(collide_src.iter(), collide_tgt.iter()).collect::<Vec<_>>();
+        let num_pairs = edge_pairs.len() as f64;
+
+        for (pair_idx, (src_curve, tgt_curve)) in edge_pairs.into_iter().enumerate() {
+            report_progress(
+                monitor,
+                OperationPhase::Colliding,
+                FIND_COLLISIONS_PROGRESS * (pair_idx as f64) / num_pairs,
+            )?;
 
-        for (src_curve, tgt_curve) in sweep_against(collide_src.iter(), collide_tgt.iter()) {
             // Find any collisions between the two edges (to the required accuracy)
-            let mut edge_collisions = curve_intersects_curve_clip(src_curve, tgt_curve, accuracy);
+            let mut edge_collisions = edge_intersections(src_curve, tgt_curve, accuracy);
             if edge_collisions.is_empty() {
                 continue;
             }
@@ -234,7 +271,7 @@ impl<Point: Coordinate + Coordinate2D, Label: Copy> GraphPath<Point, Label> {
             collisions.extend(edge_collisions);
         }
 
-        collisions
+        Ok(collisions)
     }
 
     ///
@@ -321,12 +358,51 @@ impl<Point: Coordinate + Coordinate2D, Label: Copy> GraphPath<Point, Label> {
         collide_to: Range<usize>,
         accuracy: f64,
     ) -> bool {
+        match self.detect_collisions_with_progress(
+            collide_from,
+            collide_to,
+            accuracy,
+            &NoProgressMonitor,
+        ) {
+            Ok(collided) => collided,
+            Err(OperationCancelled) => unreachable!(),
+        }
+    }
+
+    ///
+    /// As for `detect_collisions()`, but reports progress to a monitor between each pair of edges that are checked for
+    /// collisions and each edge that is subdivided
+    ///
+    /// The amounts reported to the monitor are the proportion of the collision that has been completed. If the monitor
+    /// cancels the operation, the graph can be left with only some of its edges subdivided.
+    ///
+    pub(crate) fn detect_collisions_with_progress<Monitor: ProgressMonitor + ?Sized>(
+        &mut self,
+        collide_from: Range<usize>,
+        collide_to: Range<usize>,
+        accuracy: f64,
+        monitor: &Monitor,
+    ) -> Result<bool, OperationCancelled> {
+        trace_event!(GraphPathEvent::DetectingCollisions {
+            collide_from: collide_from.clone(),
+            collide_to: collide_to.clone(),
+        });
+
         // Find all of the collision points
-        let all_collisions = self.find_collisions(collide_from, collide_to, accuracy);
+        let all_collisions = self.find_collisions(collide_from, collide_to, accuracy, monitor)?;
+        #[cfg(feature = "instrumentation")]
+        for collision in all_collisions.iter() {
+            trace_event!(GraphPathEvent::CollisionFound {
+                edge1: collision.edge_1,
+                t1: collision.edge_1_t,
+                edge2: collision.edge_2,
+                t2: collision.edge_2_t,
+            });
+        }
         if all_collisions.is_empty() {
             let collided_at_point = self.combine_overlapping_points(accuracy);
             self.remove_all_very_short_edges();
-            return collided_at_point;
+            return Ok(collided_at_point);
         }
 
         // Add in any extra points that are required by the collisions we found
@@ -345,7 +421,16 @@ impl<Point: Coordinate + Coordinate2D, Label: Copy> GraphPath<Point, Label> {
                 });
 
         // Actually divide the edges by collision
+        let num_points = self.points.len() as f64;
+
         for (point_idx, edge_collisions) in collisions_by_point {
+            report_progress(
+                monitor,
+                OperationPhase::Colliding,
+                FIND_COLLISIONS_PROGRESS
+                    + (1.0 - FIND_COLLISIONS_PROGRESS) * (point_idx as f64) / num_points,
+            )?;
+
             for (edge_idx, mut collisions) in edge_collisions.into_iter().enumerate() {
                 // Skip edges with no collisions
                 if collisions.is_empty() {
@@ -390,6 +475,16 @@ impl<Point: Coordinate + Coordinate2D, Label: Copy> GraphPath<Point, Label> {
 
                 // First collision is special as we need to edit the existing edge instead of adding a new one
                 if let Some((t, end_point_idx)) = collisions.next() {
+                    trace_event!(GraphPathEvent::EdgeSubdivided {
+                        edge: GraphEdgeRef {
+                            start_idx: point_idx,
+                            edge_idx,
+                            reverse: false,
+                        },
+                        t,
+                        end_point_idx,
+                    });
+
                     // Subdivide the edge
                     let (next_edge, new_remaining_edge) = remaining_edge.subdivide::<Curve<_>>(t);
                     let following_edge_idx = self.points[end_point_idx].forward_edges.len();
@@ -421,6 +516,16 @@ impl<Point: Coordinate + Coordinate2D, Label: Copy> GraphPath<Point, Label> {
 
                 // Deal with the rest of the collisions
                 for (t, end_point_idx) in collisions {
+                    trace_event!(GraphPathEvent::EdgeSubdivided {
+                        edge: GraphEdgeRef {
+                            start_idx: point_idx,
+                            edge_idx,
+                            reverse: false,
+                        },
+                        t,
+                        end_point_idx,
+                    });
+
                     // Point the previous edge at the new edge we're adding
                     let new_edge_idx = self.points[last_point_idx].forward_edges.len();
                     if let Some((point_idx, edge_idx)) = previous_edge {
@@ -495,7 +600,7 @@ impl<Point: Coordinate + Coordinate2D, Label: Copy> GraphPath<Point, Label> {
 
         self.check_following_edge_consistency();
 
-        true
+        Ok(true)
     }
 
     ///
@@ -859,3 +964,122 @@ fn remove_and_round_close_collisions<C: BezierCurve>(
         }
     }
 }
+
+///
+/// If a curve is a straight line (its control points are within `max_distance` of the line between its end points), returns
+/// the positions of the control points along the line (where 0 is the start point and 1 is the end point)
+///
+pub(crate) fn straight_line_positions<C: BezierCurve>(
+    curve: &C,
+    max_distance: f64,
+) -> Option<(f64, f64)>
+where
+    C::Point: Coordinate + Coordinate2D,
+{
+    let start_point = curve.start_point();
+    let direction = curve.end_point() - start_point;
+    let length_sq = direction.dot(&direction);
+    if length_sq <= 0.0 {
+        return None;
+    }
+
+    let (cp1, cp2) = curve.control_points();
+    let normal =
+        C::Point::from_components(&[-direction.y(), direction.x()]) * (1.0 / length_sq.sqrt());
+    let position = |point: C::Point| {
+        let offset = point - start_point;
+
+        (
+            offset.dot(&direction) / length_sq,
+            offset.dot(&normal).abs(),
+        )
+    };
+
+    let (pos1, distance1) = position(cp1);
+    let (pos2, distance2) = position(cp2);
+
+    // The control points must be between the end points so that each point on the line corresponds to a single t value
+    if distance1 <= max_distance
+        && distance2 <= max_distance
+        && (0.0..=1.0).contains(&pos1)
+        && (0.0..=1.0).contains(&pos2)
+    {
+        Some((pos1, pos2))
+    } else {
+        None
+    }
+}
+
+///
+/// Finds the t value for a position along a straight line whose control points are at the specified positions
+///
+fn straight_line_t((pos1, pos2): (f64, f64), position: f64) -> f64 {
+    if position <= 0.0 {
+        0.0
+    } else if position >= 1.0 {
+        1.0
+    } else {
+        solve_basis_for_t(0.0, pos1, pos2, 1.0, position)
+            .into_iter()
+            .filter(|t| (0.0..=1.0).contains(t))
+            .min_by(|a, b| {
+                let a = (basis(*a, 0.0, pos1, pos2, 1.0) - position).abs();
+                let b = (basis(*b, 0.0, pos1, pos2, 1.0) - position).abs();
+                a.partial_cmp(&b).unwrap_or(Ordering::Equal)
+            })
+            .unwrap_or(position)
+    }
+}
+
+///
+/// Finds the intersections between two edges
+///
+/// Edges that are straight lines are common (for example, when the paths are polygons), and the intersection of two lines
+/// can be found exactly, which is much faster than using the clipping algorithm. Curves, and lines that are parallel
+/// (which can overlap), are intersected with `curve_intersects_curve_clip()`.
+///
+fn edge_intersections<C: BezierCurve>(src: &C, tgt: &C, accuracy: f64) -> SmallVec<[(f64, f64); 8]>
+where
+    C::Point: Coordinate + Coordinate2D,
+{
+    let max_distance = accuracy * 0.01;
+
+    if let (Some(src_positions), Some(tgt_positions)) = (
+        straight_line_positions(src, max_distance),
+        straight_line_positions(tgt, max_distance),
+    ) {
+        let (p1, p2) = (src.start_point(), src.end_point());
+        let (p3, p4) = (tgt.start_point(), tgt.end_point());
+        let (src_direction, tgt_direction) = (p2 - p1, p4 - p3);
+
+        let divisor = tgt_direction.y() * src_direction.x() - tgt_direction.x() * src_direction.y();
+        let scale = src_direction.magnitude() * tgt_direction.magnitude();
+
+        if divisor.abs() > scale * MIN_LINE_INTERSECTION_SIN_ANGLE {
+            let offset = p1 - p3;
+            let src_pos =
+                (tgt_direction.x() * offset.y() - tgt_direction.y() * offset.x()) / divisor;
+            let tgt_pos =
+                (src_direction.x() * offset.y() - src_direction.y() * offset.x()) / divisor;
+
+            // Allow the lines to miss each other by the accuracy (so lines that meet at their ends collide)
+            let src_tolerance = accuracy / src_direction.magnitude();
+            let tgt_tolerance = accuracy / tgt_direction.magnitude();
+
+            if src_pos >= -src_tolerance
+                && src_pos <= 1.0 + src_tolerance
+                && tgt_pos >= -tgt_tolerance
+                && tgt_pos <= 1.0 + tgt_tolerance
+            {
+                return smallvec![(
+                    straight_line_t(src_positions, src_pos),
+                    straight_line_t(tgt_positions, tgt_pos)
+                )];
+            } else {
+                return smallvec![];
+            }
+        }
+    }
+
+    curve_intersects_curve_clip(src, tgt, accuracy)
+}
@@ -1,7 +1,7 @@
 use super::{GraphEdge, GraphEdgeRef, GraphPath};
 use crate::bezier::path::ray::{ray_collisions, RayPath};
 use crate::geo::{Coordinate, Coordinate2D};
-use crate::line::Line;
+use crate::line::{Line, Ray};
 
 use smallvec::SmallVec;
 
@@ -29,6 +29,19 @@ impl<Point: Coordinate + Coordinate2D, Label: Copy> GraphPath<Point, Label> {
     ) -> Vec<(GraphRayCollision, f64, f64, Point)> {
         ray_collisions(&self, ray)
     }
+
+    ///
+    /// Finds all collisions between a ray and this path
+    ///
+    /// This is the same as `ray_collisions()`, except the ray is described explicitly by a `Ray` rather than a `Line`. The
+    /// return value is a tuple of (collision, curve_t, ray_t, position)
+    ///
+    pub fn collisions_with_ray<R: Ray<Point = Point>>(
+        &self,
+        ray: &R,
+    ) -> Vec<(GraphRayCollision, f64, f64, Point)> {
+        ray_collisions(&self, &ray.to_line())
+    }
 }
 
 impl GraphRayCollision {
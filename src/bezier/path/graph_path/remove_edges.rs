@@ -0,0 +1,214 @@
+use super::{GraphPath, GraphPathEdgeKind};
+use crate::bezier::subdivide4;
+use crate::consts::CLOSE_DISTANCE;
+use crate::geo::{Coordinate, Coordinate2D};
+
+///
+/// If two curves are the two halves of a curve that was split in two, returns the control points of the original curve
+///
+/// The curves are `(start, cp1, cp2, mid)` and `(mid, cp1, cp2, end)`. The result is only returned if subdividing it at the
+/// same point gives back both curves to within `CLOSE_DISTANCE`.
+///
+fn rejoin_split_curves<Point: Coordinate>(
+    (w1, w2, w3, w4): (Point, Point, Point, Point),
+    (_, x2, x3, x4): (Point, Point, Point, Point),
+) -> Option<(Point, Point)> {
+    // The tangents either side of the split point are in the same direction, and their lengths are in proportion to t
+    let before = w4.distance_to(&w3);
+    let after = x2.distance_to(&w4);
+    if before + after <= 0.0 {
+        return None;
+    }
+
+    let t = before / (before + after);
+    if t <= 0.0 || t >= 1.0 {
+        return None;
+    }
+
+    // The outer control points of the original curve can be recovered from the outer control points of the halves
+    let cp1 = w1 + (w2 - w1) * (1.0 / t);
+    let cp2 = x4 + (x3 - x4) * (1.0 / (1.0 - t));
+
+    let ((y1, y2, y3, y4), (z1, z2, z3, z4)) = subdivide4(t, w1, cp1, cp2, x4);
+    let matches = [
+        (y1, w1),
+        (y2, w2),
+        (y3, w3),
+        (y4, w4),
+        (z1, w4),
+        (z2, x2),
+        (z3, x3),
+        (z4, x4),
+    ]
+    .iter()
+    .all(|(a, b)| a.is_near_to(b, CLOSE_DISTANCE));
+
+    if matches {
+        Some((cp1, cp2))
+    } else {
+        None
+    }
+}
+
+impl<Point: Coordinate + Coordinate2D, Label: Copy> GraphPath<Point, Label> {
+    ///
+    /// Removes all of the edges whose label matches a predicate from this graph
+    ///
+    /// This makes it possible to update a graph path without rebuilding it: for example, to replace one of the operands
+    /// of a path arithmetic operation, remove the edges labelled with that operand and then `collide()` the result with
+    /// the replacement. Only the new edges need to be checked for collisions, as the collisions between the remaining
+    /// edges were found when the graph was first built.
+    ///
+    /// The points that were added where the removed edges collided with the remaining ones are removed where the edges
+    /// either side of them can be joined back into the curve that was originally split there, so repeatedly replacing an
+    /// operand does not keep subdividing the other one. All the edges are marked as uncategorised, as the edge kinds will
+    /// need to be worked out again for the new graph.
+    ///
+    pub fn remove_edges_with_label<FnShouldRemove: Fn(&Label) -> bool>(
+        &mut self,
+        should_remove: FnShouldRemove,
+    ) {
+        // Remove the edges, keeping track of where the remaining edges have moved to so the following edges can be updated
+        let mut new_edge_indexes = vec![];
+        for point in self.points.iter_mut() {
+            let mut next_idx = 0;
+            let mut new_indexes = vec![];
+
+            for edge in point.forward_edges.iter() {
+                if should_remove(&edge.label) {
+                    new_indexes.push(None);
+                } else {
+                    new_indexes.push(Some(next_idx));
+                    next_idx += 1;
+                }
+            }
+
+            point
+                .forward_edges
+                .retain(|edge| !should_remove(&edge.label));
+            new_edge_indexes.push(new_indexes);
+        }
+
+        for point_idx in 0..self.points.len() {
+            for edge_idx in 0..self.points[point_idx].forward_edges.len() {
+                let edge = &self.points[point_idx].forward_edges[edge_idx];
+                let following_edge_idx = new_edge_indexes[edge.end_idx]
+                    .get(edge.following_edge_idx)
+                    .copied()
+                    .flatten()
+                    .unwrap_or(0);
+
+                let edge = &mut self.points[point_idx].forward_edges[edge_idx];
+                edge.following_edge_idx = following_edge_idx;
+                edge.kind = GraphPathEdgeKind::Uncategorised;
+            }
+        }
+
+        self.recalculate_reverse_connections();
+
+        // Join up the edges that were split by collisions with the removed edges, then tidy up the unused points
+        self.join_split_edges();
+        self.remove_unused_points();
+    }
+
+    ///
+    /// Joins pairs of edges meeting at points with a single edge in and out of them, where the pair were made by splitting
+    /// a single curve
+    ///
+    fn join_split_edges(&mut self) {
+        for point_idx in 0..self.points.len() {
+            // Must be exactly one edge in and one edge out of this point
+            if self.points[point_idx].forward_edges.len() != 1
+                || self.points[point_idx].connected_from.len() != 1
+            {
+                continue;
+            }
+
+            let previous_idx = self.points[point_idx].connected_from[0];
+            let next_idx = self.points[point_idx].forward_edges[0].end_idx;
+            if previous_idx == point_idx || next_idx == previous_idx {
+                continue;
+            }
+
+            // The previous point may have other edges, but only one of them can end here
+            let mut incoming = self.points[previous_idx]
+                .forward_edges
+                .iter()
+                .enumerate()
+                .filter(|(_, edge)| edge.end_idx == point_idx)
+                .map(|(edge_idx, _)| edge_idx);
+            let previous_edge_idx = match (incoming.next(), incoming.next()) {
+                (Some(edge_idx), None) => edge_idx,
+                _ => continue,
+            };
+
+            let previous_edge = &self.points[previous_idx].forward_edges[previous_edge_idx];
+            let next_edge = &self.points[point_idx].forward_edges[0];
+            let first_half = (
+                self.points[previous_idx].position,
+                previous_edge.cp1,
+                previous_edge.cp2,
+                self.points[point_idx].position,
+            );
+            let second_half = (
+                self.points[point_idx].position,
+                next_edge.cp1,
+                next_edge.cp2,
+                self.points[next_idx].position,
+            );
+
+            if let Some((cp1, cp2)) = rejoin_split_curves(first_half, second_half) {
+                let following_edge_idx = next_edge.following_edge_idx;
+
+                let previous_edge = &mut self.points[previous_idx].forward_edges[previous_edge_idx];
+                previous_edge.cp1 = cp1;
+                previous_edge.cp2 = cp2;
+                previous_edge.end_idx = next_idx;
+                previous_edge.following_edge_idx = following_edge_idx;
+                previous_edge.invalidate_cache();
+
+                self.points[point_idx].forward_edges.clear();
+                self.points[point_idx].connected_from.clear();
+
+                let next_point = &mut self.points[next_idx];
+                for connected_from in next_point.connected_from.iter_mut() {
+                    if *connected_from == point_idx {
+                        *connected_from = previous_idx;
+                    }
+                }
+                next_point.connected_from.sort_unstable();
+                next_point.connected_from.dedup();
+            }
+        }
+    }
+
+    ///
+    /// Removes any points that have no edges attached to them
+    ///
+    fn remove_unused_points(&mut self) {
+        let mut new_point_indexes = vec![None; self.points.len()];
+        let mut next_idx = 0;
+        for (point_idx, point) in self.points.iter().enumerate() {
+            if !point.forward_edges.is_empty() || !point.connected_from.is_empty() {
+                new_point_indexes[point_idx] = Some(next_idx);
+                next_idx += 1;
+            }
+        }
+
+        let old_points = std::mem::take(&mut self.points);
+        self.points = old_points
+            .into_iter()
+            .enumerate()
+            .filter(|(point_idx, _)| new_point_indexes[*point_idx].is_some())
+            .map(|(_, mut point)| {
+                for edge in point.forward_edges.iter_mut() {
+                    edge.end_idx = new_point_indexes[edge.end_idx].unwrap();
+                }
+
+                point
+            })
+            .collect();
+
+        self.recalculate_reverse_connections();
+    }
+}
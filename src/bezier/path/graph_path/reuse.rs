@@ -0,0 +1,69 @@
+use super::GraphPath;
+use crate::bezier::path::path::BezierPath;
+use crate::geo::{Coordinate, Coordinate2D};
+
+//
+// Building a new graph path for every operation allocates storage for all of its points. When performing the same kind of
+// operation repeatedly (for example, updating the result of some path arithmetic every frame), a single graph can be
+// cleared and refilled instead, which re-uses the storage from the previous operation.
+//
+
+impl<Point: Coordinate + Coordinate2D, Label: Copy> GraphPath<Point, Label> {
+    ///
+    /// Removes all of the points and edges from this graph, keeping the storage that was allocated for them so it can be
+    /// re-used by `add_merged_paths()` and `collide_paths()`
+    ///
+    pub fn clear(&mut self) {
+        self.points.clear();
+        self.next_path_index = 0;
+    }
+
+    ///
+    /// Adds a set of paths with their labels to this graph without colliding them with each other or the existing edges
+    ///
+    /// This has the same result as `self.merge(GraphPath::from_merged_paths(paths))` but adds the edges directly to this
+    /// graph instead of creating a new one.
+    ///
+    pub fn add_merged_paths<
+        'a,
+        P: 'a + BezierPath<Point = Point>,
+        PathIter: IntoIterator<Item = (&'a P, Label)>,
+    >(
+        &mut self,
+        paths: PathIter,
+    ) {
+        for (path, label) in paths {
+            self.append_path(path, label);
+        }
+    }
+
+    ///
+    /// Collides a set of paths with their labels against the edges in this graph
+    ///
+    /// This has the same result as `self.collide(GraphPath::from_merged_paths(paths), accuracy)`, but updates this graph in
+    /// place. Combined with `clear()` and `add_merged_paths()`, this makes it possible to perform path arithmetic
+    /// repeatedly without allocating a new graph each time: clear the graph, add the first set of paths, collide the
+    /// second set and then set the edge kinds as usual (eg, with `set_exterior_by_adding()`).
+    ///
+    pub fn collide_paths<
+        'a,
+        P: 'a + BezierPath<Point = Point>,
+        PathIter: IntoIterator<Item = (&'a P, Label)>,
+    >(
+        &mut self,
+        paths: PathIter,
+        accuracy: f64,
+    ) {
+        // Add the new edges after the existing ones
+        let collision_offset = self.points.len();
+        self.add_merged_paths(paths);
+
+        // Search for collisions between the existing edges and the new ones
+        let total_points = self.points.len();
+        self.detect_collisions(
+            0..collision_offset,
+            collision_offset..total_points,
+            accuracy,
+        );
+    }
+}
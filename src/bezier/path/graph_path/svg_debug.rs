@@ -0,0 +1,142 @@
+use super::{GraphPath, GraphPathDebugLabel};
+use crate::debug::{svg_edge_colour, svg_transform};
+use crate::geo::{Coordinate, Coordinate2D};
+
+use std::fmt::Write;
+
+/// The dash patterns used to tell apart edges with different labels (the first label is drawn with solid lines)
+const LABEL_DASH_PATTERNS: [&str; 6] = ["none", "8 4", "2 3", "10 3 2 3", "4 8", "12 2 2 2 2 2"];
+
+///
+/// Escapes the characters in a string that can't appear in SVG text
+///
+fn escape_xml(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+impl<Point: Coordinate + Coordinate2D, Label: Copy + GraphPathDebugLabel> GraphPath<Point, Label> {
+    ///
+    /// Renders this graph path as an SVG snippet (a `<g>` element), for inspecting the intermediate state of a path
+    /// arithmetic operation
+    ///
+    /// The graph is scaled and coloured in the same way as `debug::graph_path_svg_string()`: it's fitted to a 1000x1000
+    /// area and edges are coloured by their kind (yellow for uncategorised, red for visited, blue for exterior and green
+    /// for interior). Edges with different labels are drawn with different dash patterns. Every point is drawn with its
+    /// index, and points that have no edges connected to them are circled in magenta. Each element has a `<title>` with
+    /// the original coordinates, so hovering over an edge in a browser shows which edge it is.
+    ///
+    pub fn to_svg_debug_string(&self) -> String {
+        let mut result = String::new();
+
+        // Use the same colours and scaling as graph_path_svg_string()
+        let (offset, scale) = svg_transform(self);
+        let to_svg = |pos: Point| {
+            let pos = (pos - offset) * scale;
+            (pos.x(), pos.y())
+        };
+
+        // Assign a dash pattern to each label, in the order they're found in
+        let mut labels = vec![];
+        for edge in self
+            .points
+            .iter()
+            .flat_map(|point| point.forward_edges.iter())
+        {
+            let label = edge.label.to_debug_string();
+            if !labels.contains(&label) {
+                labels.push(label);
+            }
+        }
+
+        writeln!(result, "<g class=\"graph-path-debug\">").unwrap();
+        for (label_idx, label) in labels.iter().enumerate() {
+            writeln!(
+                result,
+                "<!-- Label {}: \"{}\" dashes {} -->",
+                label_idx,
+                escape_xml(label),
+                LABEL_DASH_PATTERNS[label_idx % LABEL_DASH_PATTERNS.len()]
+            )
+            .unwrap();
+        }
+
+        // Draw the edges
+        for (point_idx, point) in self.points.iter().enumerate() {
+            for (edge_idx, edge) in point.forward_edges.iter().enumerate() {
+                let label = edge.label.to_debug_string();
+                let label_idx = labels.iter().position(|known| known == &label).unwrap_or(0);
+                let end_point = self.points[edge.end_idx].position;
+
+                let (x1, y1) = to_svg(point.position);
+                let (cx1, cy1) = to_svg(edge.cp1);
+                let (cx2, cy2) = to_svg(edge.cp2);
+                let (x2, y2) = to_svg(end_point);
+
+                writeln!(
+                    result,
+                    "<path d=\"M {} {} C {} {}, {} {}, {} {}\" fill=\"none\" stroke-width=\"1\" stroke=\"{}\" stroke-dasharray=\"{}\">",
+                    x1, y1, cx1, cy1, cx2, cy2, x2, y2,
+                    svg_edge_colour(edge.kind),
+                    LABEL_DASH_PATTERNS[label_idx % LABEL_DASH_PATTERNS.len()]
+                )
+                .unwrap();
+                writeln!(
+                    result,
+                    "<title>Edge {}.{}: {} -&gt; {} ({:?}, label \"{}\", following {}) Curve::from_points(Coord2({}, {}), (Coord2({}, {}), Coord2({}, {})), Coord2({}, {}))</title>",
+                    point_idx, edge_idx, point_idx, edge.end_idx,
+                    edge.kind,
+                    escape_xml(&label),
+                    edge.following_edge_idx,
+                    point.position.x(), point.position.y(),
+                    edge.cp1.x(), edge.cp1.y(),
+                    edge.cp2.x(), edge.cp2.y(),
+                    end_point.x(), end_point.y()
+                )
+                .unwrap();
+                writeln!(result, "</path>").unwrap();
+            }
+        }
+
+        // Draw the points
+        for (point_idx, point) in self.points.iter().enumerate() {
+            let (x, y) = to_svg(point.position);
+            let is_orphaned = point.forward_edges.is_empty() && point.connected_from.is_empty();
+
+            if is_orphaned {
+                writeln!(
+                    result,
+                    "<circle cx=\"{}\" cy=\"{}\" r=\"6\" fill=\"none\" stroke-width=\"2\" stroke=\"magenta\" />",
+                    x, y
+                )
+                .unwrap();
+            }
+
+            writeln!(
+                result,
+                "<circle cx=\"{}\" cy=\"{}\" r=\"2\" fill=\"black\"><title>Point {}{}: Coord2({}, {})</title></circle>",
+                x,
+                y,
+                point_idx,
+                if is_orphaned { " (orphaned)" } else { "" },
+                point.position.x(),
+                point.position.y()
+            )
+            .unwrap();
+            writeln!(
+                result,
+                "<text style=\"font-size: 8pt\" x=\"{}\" y=\"{}\">{}</text>",
+                x + 4.0,
+                y - 4.0,
+                point_idx
+            )
+            .unwrap();
+        }
+
+        writeln!(result, "</g>").unwrap();
+
+        result
+    }
+}
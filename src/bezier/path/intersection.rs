@@ -1,7 +1,10 @@
-use super::super::super::geo::{BoundingBox, Bounds, Coordinate2D};
-use super::super::super::line::Line;
+use super::super::super::geo::{BoundingBox, Bounds, Coordinate, Coordinate2D};
+use super::super::super::line::{Line, Ray};
 use super::super::curve::{BezierCurve, Curve};
-use super::super::intersection::{curve_intersects_curve_clip, curve_intersects_line};
+use super::super::intersection::{
+    curve_intersects_curve_clip, curve_intersects_line, curve_intersects_ray,
+    find_self_intersection_point,
+};
 use super::path::BezierPath;
 use super::to_curves::path_to_curves;
 
@@ -52,6 +55,30 @@ where
         })
 }
 
+///
+/// Determines the intersections of a path and a ray
+///
+/// This is the same as `path_intersects_ray()`, except the ray is described explicitly by a `Ray` rather than a `Line`.
+/// Return value is `(path_point_idx, curve_t, ray_t)`.
+///
+pub fn path_ray_intersections<'a, Path: BezierPath, R: Ray<Point = Path::Point>>(
+    path: &'a Path,
+    ray: &'a R,
+) -> impl 'a + Iterator<Item = (usize, f64, f64)>
+where
+    Path::Point: 'a + Coordinate2D,
+{
+    let line = ray.to_line();
+
+    path_to_curves::<_, Curve<_>>(path)
+        .enumerate()
+        .flat_map(move |(section_id, curve)| {
+            curve_intersects_ray(&curve, &line)
+                .into_iter()
+                .map(move |(t, s, _pos)| (section_id, t, s))
+        })
+}
+
 ///
 /// Finds the points where a path intersects another path
 ///
@@ -104,3 +131,79 @@ where
 
     result
 }
+
+///
+/// Finds the points where a path intersects itself
+///
+/// Intersections are returned as (segment index, t-value) pairs in the same format as `path_intersects_path()`: the
+/// first item is always on the segment with the lower index. Loops within a single segment are returned with the same
+/// segment index for both items. The points where one segment joins the next are not reported as intersections.
+///
+/// Unlike `GraphPath::self_collide()`, this just returns the list of intersections and does not build a graph.
+///
+pub fn path_self_intersections<'a, Path: BezierPath>(
+    path: &'a Path,
+    accuracy: f64,
+) -> Vec<((usize, f64), (usize, f64))>
+where
+    Path::Point: 'a + Coordinate2D,
+{
+    // Convert the path to sections, computing the bounding boxes so that sections that are far apart can be skipped
+    let sections = path_to_curves::<_, Curve<_>>(path)
+        .map(|curve| (curve, curve.bounding_box::<Bounds<_>>()))
+        .collect::<Vec<_>>();
+    let num_sections = sections.len();
+
+    // The path is closed if the last section ends where the first one begins
+    let is_closed = num_sections > 1
+        && sections[num_sections - 1]
+            .0
+            .end_point()
+            .is_near_to(&sections[0].0.start_point(), accuracy);
+
+    let mut result = vec![];
+
+    for (idx1, (curve1, bounds1)) in sections.iter().enumerate() {
+        // Sections that loop back on themselves intersect within a single curve
+        if let Some((t1, t2)) = find_self_intersection_point(curve1, accuracy) {
+            result.push(((idx1, t1.min(t2)), (idx1, t1.max(t2))));
+        }
+
+        // Compare against all the later sections in the path
+        for (idx2, (curve2, bounds2)) in sections.iter().enumerate().skip(idx1 + 1) {
+            if !bounds1.overlaps(bounds2) {
+                continue;
+            }
+
+            // Work out where this pair of sections join up, if they're adjacent
+            let follows = idx2 == idx1 + 1;
+            let wraps = is_closed && idx1 == 0 && idx2 == num_sections - 1;
+
+            let intersections = curve_intersects_curve_clip(curve1, curve2, accuracy);
+
+            result.extend(
+                intersections
+                    .into_iter()
+                    .filter(|(t1, t2)| {
+                        // Filter out the point where the two sections join
+                        let pos = curve1.point_at_pos(*t1);
+                        let is_join = (follows
+                            && pos.is_near_to(&curve1.end_point(), accuracy)
+                            && curve2
+                                .point_at_pos(*t2)
+                                .is_near_to(&curve2.start_point(), accuracy))
+                            || (wraps
+                                && pos.is_near_to(&curve1.start_point(), accuracy)
+                                && curve2
+                                    .point_at_pos(*t2)
+                                    .is_near_to(&curve2.end_point(), accuracy));
+
+                        !is_join
+                    })
+                    .map(|(t1, t2)| ((idx1, t1), (idx2, t2))),
+            );
+        }
+    }
+
+    result
+}
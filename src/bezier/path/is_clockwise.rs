@@ -1,5 +1,5 @@
 use super::super::super::geo::{Coordinate, Coordinate2D};
-use super::path::BezierPath;
+use super::path::{BezierPath, BezierPathFactory};
 
 use itertools::Itertools;
 
@@ -32,11 +32,36 @@ pub fn points_are_clockwise<Point: Coordinate + Coordinate2D, PointIter: Iterato
 ///
 /// Trait implemented by paths that can determine if their points are in a clockwise ordering or not
 ///
-pub trait PathWithIsClockwise {
+/// This is implemented for every `BezierPath` with 2D points, so importing it is all that's needed to check and fix the
+/// direction of paths loaded from elsewhere.
+///
+pub trait PathWithIsClockwise: BezierPath {
     ///
     /// Determines if this path is ordered in a clockwise direction
     ///
     fn is_clockwise(&self) -> bool;
+
+    ///
+    /// Returns this path if it's clockwise, or the reversed path if it's anticlockwise
+    ///
+    fn ensure_clockwise<POut: BezierPathFactory<Point = Self::Point>>(&self) -> POut {
+        if self.is_clockwise() {
+            POut::from_path(self)
+        } else {
+            self.reversed()
+        }
+    }
+
+    ///
+    /// Returns this path if it's anticlockwise, or the reversed path if it's clockwise
+    ///
+    fn ensure_anticlockwise<POut: BezierPathFactory<Point = Self::Point>>(&self) -> POut {
+        if self.is_clockwise() {
+            self.reversed()
+        } else {
+            POut::from_path(self)
+        }
+    }
 }
 
 impl<P: BezierPath> PathWithIsClockwise for P
@@ -48,3 +73,38 @@ where
         points_are_clockwise(self.points().map(|(_cp1, _cp2, p)| p))
     }
 }
+
+///
+/// Calculates the signed area enclosed by a path
+///
+/// The area is positive if the path is anticlockwise and negative if it's clockwise (assuming that a positive y value
+/// indicates an upwards direction, as for `points_are_clockwise()`). This is exact for the curves in the path rather than
+/// an approximation from its points.
+///
+pub fn path_signed_area<P: BezierPath>(path: &P) -> f64
+where
+    P::Point: Coordinate + Coordinate2D,
+{
+    let mut last_point = path.start_point();
+    let mut total = 0.0;
+
+    for (cp1, cp2, end_point) in path.points() {
+        let (x0, y0) = (last_point.x(), last_point.y());
+        let (x1, y1) = (cp1.x(), cp1.y());
+        let (x2, y2) = (cp2.x(), cp2.y());
+        let (x3, y3) = (end_point.x(), end_point.y());
+
+        // Green's theorem applied to a cubic bezier section
+        total += x0 * (6.0 * y1 + 3.0 * y2 + y3)
+            + 3.0 * (x1 * (-2.0 * y0 + y2 + y3) - x2 * (y0 + y1 - 2.0 * y3))
+            - x3 * (y0 + 3.0 * y1 + 6.0 * y2);
+
+        last_point = end_point;
+    }
+
+    // Close the path with a straight line if it doesn't end at its start point
+    let start_point = path.start_point();
+    total += 10.0 * (last_point.x() * start_point.y() - start_point.x() * last_point.y());
+
+    total / 20.0
+}
@@ -0,0 +1,139 @@
+use super::super::super::consts::SMALL_DISTANCE;
+use super::super::super::geo::{Coordinate, Coordinate2D};
+use super::path::BezierPath;
+
+use std::f64;
+
+///
+/// How the sections of a path meet at a join
+///
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum PathJoinKind {
+    /// The path carries on in the same direction (the sections are tangent-continuous)
+    Smooth,
+
+    /// The path changes direction
+    Corner,
+
+    /// The path turns back on itself
+    Cusp,
+}
+
+///
+/// Describes a point where two sections of a path meet
+///
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct PathJoin<Point> {
+    /// The index of the section that ends at this join (the following section is the next one, or the first section for
+    /// the join at the end of a closed path)
+    pub section: usize,
+
+    /// The position of the join
+    pub position: Point,
+
+    /// How the sections meet at this join
+    pub kind: PathJoinKind,
+
+    /// The angle the path turns through at the join, in radians, between -pi and pi. Positive values are anticlockwise
+    /// turns.
+    pub angle: f64,
+}
+
+///
+/// Returns the direction of a curve as it leaves its start point, or `None` if the curve is a single point
+///
+fn start_direction<Point: Coordinate>(
+    w1: &Point,
+    w2: &Point,
+    w3: &Point,
+    w4: &Point,
+) -> Option<Point> {
+    [*w2, *w3, *w4]
+        .iter()
+        .map(|point| *point - *w1)
+        .find(|direction| direction.magnitude() > 0.0)
+}
+
+///
+/// Returns the direction of a curve as it arrives at its end point, or `None` if the curve is a single point
+///
+fn end_direction<Point: Coordinate>(
+    w1: &Point,
+    w2: &Point,
+    w3: &Point,
+    w4: &Point,
+) -> Option<Point> {
+    [*w3, *w2, *w1]
+        .iter()
+        .map(|point| *w4 - *point)
+        .find(|direction| direction.magnitude() > 0.0)
+}
+
+///
+/// Finds and classifies the joins between the sections of a path
+///
+/// Joins where the direction changes by no more than `smooth_angle` (in radians) are reported as smooth, and joins where it
+/// changes by more than `pi - smooth_angle` are cusps, where the path turns back on itself. Every other join is a corner.
+/// Sections that are just a single point are skipped, so the direction either side of them is compared instead. If the
+/// path is closed, the join between its last and first sections is included too.
+///
+/// This can be used to decide which joins need a round or mitred join when stroking a path, or which corners to preserve
+/// when simplifying it.
+///
+pub fn path_joins<P: BezierPath>(path: &P, smooth_angle: f64) -> Vec<PathJoin<P::Point>>
+where
+    P::Point: Coordinate2D,
+{
+    // The directions at the start and end of every section that's not a point
+    let mut last_point = path.start_point();
+    let mut sections = vec![];
+
+    for (idx, (cp1, cp2, end_point)) in path.points().enumerate() {
+        let start = start_direction(&last_point, &cp1, &cp2, &end_point);
+        let end = end_direction(&last_point, &cp1, &cp2, &end_point);
+
+        if let (Some(start), Some(end)) = (start, end) {
+            sections.push((idx, start, end, end_point));
+        }
+
+        last_point = end_point;
+    }
+
+    if sections.is_empty() {
+        return vec![];
+    }
+
+    let is_closed = last_point.is_near_to(&path.start_point(), SMALL_DISTANCE);
+    let num_joins = if is_closed && sections.len() > 1 {
+        sections.len()
+    } else {
+        sections.len() - 1
+    };
+
+    let smooth_angle = smooth_angle.abs();
+
+    (0..num_joins)
+        .map(|idx| {
+            let (section, _, incoming, position) = sections[idx];
+            let (_, outgoing, _, _) = sections[(idx + 1) % sections.len()];
+
+            let cross = incoming.x() * outgoing.y() - incoming.y() * outgoing.x();
+            let angle = cross.atan2(incoming.dot(&outgoing));
+
+            let kind = if angle.abs() <= smooth_angle {
+                PathJoinKind::Smooth
+            } else if angle.abs() >= f64::consts::PI - smooth_angle {
+                PathJoinKind::Cusp
+            } else {
+                PathJoinKind::Corner
+            };
+
+            PathJoin {
+                section,
+                position,
+                kind,
+                angle,
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,181 @@
+use super::super::super::arc::Circle;
+use super::super::super::geo::{Coordinate, Coordinate2D, Transform2D, Transformable};
+use super::super::curve::{BezierCurve, Curve};
+use super::super::normal::unit_tangent_at_pos;
+use super::path::{BezierPath, BezierPathFactory};
+use super::path_builder::BezierPathBuilder;
+use super::to_curves::path_to_curves;
+
+///
+/// The shape of a marker placed along a path
+///
+/// Marker shapes are defined pointing along the positive x axis, with the point the marker is placed at as the origin.
+///
+#[derive(Clone, PartialEq, Debug)]
+pub enum MarkerShape<Point> {
+    /// A triangle with its tip on the marker position, pointing along the path (`length` is the distance from the tip to the
+    /// base and `width` is the length of the base)
+    Triangle { length: f64, width: f64 },
+
+    /// A circle centered on the marker position
+    Circle { radius: f64 },
+
+    /// A custom marker path
+    Custom((Point, Vec<(Point, Point, Point)>)),
+}
+
+///
+/// Describes a marker and where to place it along a path
+///
+/// By default, markers are placed at the start and end of a path. Markers at the end point in the direction the path is
+/// travelling in and markers at the start point backwards, so an arrowhead will point away from the path at both ends.
+/// Markers at the vertices of the path (the joins between its sections) point along the average of the directions of the
+/// two sections.
+///
+#[derive(Clone, PartialEq, Debug)]
+pub struct Marker<Point> {
+    /// The shape of this marker
+    pub(crate) shape: MarkerShape<Point>,
+
+    /// True if the marker should be placed at the start of the path
+    pub(crate) at_start: bool,
+
+    /// True if the marker should be placed at the end of the path
+    pub(crate) at_end: bool,
+
+    /// True if the marker should be placed at every join between sections
+    pub(crate) at_vertices: bool,
+}
+
+impl<Point> Marker<Point> {
+    ///
+    /// Creates a marker with the specified shape that's placed at both ends of a path
+    ///
+    pub fn new(shape: MarkerShape<Point>) -> Self {
+        Marker {
+            shape,
+            at_start: true,
+            at_end: true,
+            at_vertices: false,
+        }
+    }
+
+    ///
+    /// Sets whether or not this marker is placed at the start of the path
+    ///
+    pub fn with_start(mut self, at_start: bool) -> Self {
+        self.at_start = at_start;
+        self
+    }
+
+    ///
+    /// Sets whether or not this marker is placed at the end of the path
+    ///
+    pub fn with_end(mut self, at_end: bool) -> Self {
+        self.at_end = at_end;
+        self
+    }
+
+    ///
+    /// Sets whether or not this marker is placed at each of the joins between the sections of the path
+    ///
+    pub fn with_vertices(mut self, at_vertices: bool) -> Self {
+        self.at_vertices = at_vertices;
+        self
+    }
+}
+
+impl<Point: Coordinate + Coordinate2D> MarkerShape<Point> {
+    ///
+    /// Creates the path for this shape, before it has been moved into position
+    ///
+    fn to_path(&self) -> (Point, Vec<(Point, Point, Point)>) {
+        match self {
+            MarkerShape::Triangle { length, width } => {
+                BezierPathBuilder::<(Point, Vec<(Point, Point, Point)>)>::start(Point::origin())
+                    .line_to(Point::from_components(&[-length, width * 0.5]))
+                    .line_to(Point::from_components(&[-length, -width * 0.5]))
+                    .line_to(Point::origin())
+                    .build()
+            }
+
+            MarkerShape::Circle { radius } => Circle::new(Point::origin(), *radius).to_path(),
+
+            MarkerShape::Custom(path) => path.clone(),
+        }
+    }
+}
+
+///
+/// Generates the paths for the markers along a path
+///
+/// The markers are returned in order along the path: the start marker, then the markers for each vertex and finally the end
+/// marker. The vertices are the points where two sections of the path join, so a marker isn't placed at the start of a
+/// closed path unless `with_start()` is set.
+///
+pub fn path_markers<P, POut>(path: &P, marker: &Marker<P::Point>) -> Vec<POut>
+where
+    P: BezierPath,
+    P::Point: Coordinate2D,
+    POut: BezierPathFactory<Point = P::Point>,
+{
+    let curves = path_to_curves::<_, Curve<_>>(path).collect::<Vec<_>>();
+    if curves.is_empty() {
+        return vec![];
+    }
+
+    // The positions and directions of each marker
+    let mut positions = vec![];
+    let last_curve = curves.len() - 1;
+
+    if marker.at_start {
+        let direction = unit_tangent_at_pos(&curves[0], 0.0).map(|tangent| tangent * -1.0);
+        positions.push((curves[0].start_point(), direction));
+    }
+
+    if marker.at_vertices {
+        for (before, after) in curves.iter().zip(curves.iter().skip(1)) {
+            let direction = match (
+                unit_tangent_at_pos(before, 1.0),
+                unit_tangent_at_pos(after, 0.0),
+            ) {
+                (Some(before), Some(after)) => {
+                    let average = before + after;
+
+                    if average.magnitude() > 0.0 {
+                        Some(average.to_unit_vector())
+                    } else {
+                        // The path turns back on itself
+                        Some(after)
+                    }
+                }
+                (before, after) => before.or(after),
+            };
+
+            positions.push((after.start_point(), direction));
+        }
+    }
+
+    if marker.at_end {
+        positions.push((
+            curves[last_curve].end_point(),
+            unit_tangent_at_pos(&curves[last_curve], 1.0),
+        ));
+    }
+
+    // Transform the marker into place at each position
+    let shape = marker.shape.to_path();
+
+    positions
+        .into_iter()
+        .map(|(position, direction)| {
+            let direction = direction.unwrap_or_else(|| P::Point::from_components(&[1.0, 0.0]));
+            let transform = Transform2D([
+                [direction.x(), -direction.y(), position.x()],
+                [direction.y(), direction.x(), position.y()],
+            ]);
+
+            POut::from_path(&shape.transform(&transform))
+        })
+        .collect()
+}
@@ -0,0 +1,433 @@
+use super::super::super::geo::{Coordinate, Coordinate2D};
+use super::super::curve::{BezierCurve, Curve};
+use super::super::length::curve_length;
+use super::super::normal::unit_tangent_at_pos;
+use super::path::{BezierPath, BezierPathFactory};
+use super::to_curves::path_to_curves;
+
+/// Number of steps each curve is divided into when building the table of lengths
+const LENGTH_TABLE_STEPS: usize = 16;
+
+/// Number of iterations to use when searching for the t value at a particular length within a step
+const LENGTH_SEARCH_ITERATIONS: usize = 24;
+
+///
+/// Measures distances along a path using its arc length
+///
+/// This builds a table of the lengths of the sections of a path, which can then be used to find the section and t value
+/// at any distance along the path (and vice versa) without needing to measure the whole path each time.
+///
+#[derive(Clone, Debug)]
+pub struct PathMeasure<Point: Coordinate> {
+    /// The curves making up the path
+    curves: Vec<Curve<Point>>,
+
+    /// For each curve, the distance along the whole path at each step of its t values (`LENGTH_TABLE_STEPS + 1` values)
+    length_table: Vec<Vec<f64>>,
+
+    /// The maximum error to allow when measuring lengths
+    max_error: f64,
+}
+
+impl<Point: Coordinate> PathMeasure<Point> {
+    ///
+    /// Creates a new path measure for a path, measuring lengths to within `max_error`
+    ///
+    pub fn new<P: BezierPath<Point = Point>>(path: &P, max_error: f64) -> Self {
+        Self::from_curves(path_to_curves::<_, Curve<_>>(path).collect(), max_error)
+    }
+
+    ///
+    /// Creates a new path measure for a path that has already been divided into curves
+    ///
+    pub(crate) fn from_curves(curves: Vec<Curve<Point>>, max_error: f64) -> Self {
+        let mut length_table = Vec::with_capacity(curves.len());
+        let mut total_length = 0.0;
+
+        for curve in curves.iter() {
+            let mut lengths = Vec::with_capacity(LENGTH_TABLE_STEPS + 1);
+            lengths.push(total_length);
+
+            for step in 0..LENGTH_TABLE_STEPS {
+                let t_min = (step as f64) / (LENGTH_TABLE_STEPS as f64);
+                let t_max = ((step + 1) as f64) / (LENGTH_TABLE_STEPS as f64);
+
+                total_length += curve_length(&curve.section(t_min, t_max), max_error);
+                lengths.push(total_length);
+            }
+
+            length_table.push(lengths);
+        }
+
+        PathMeasure {
+            curves,
+            length_table,
+            max_error,
+        }
+    }
+
+    ///
+    /// The curves that make up the measured path
+    ///
+    #[inline]
+    pub fn curves(&self) -> &[Curve<Point>] {
+        &self.curves
+    }
+
+    ///
+    /// The total length of the path
+    ///
+    #[inline]
+    pub fn length(&self) -> f64 {
+        self.length_table
+            .last()
+            .and_then(|lengths| lengths.last())
+            .cloned()
+            .unwrap_or(0.0)
+    }
+
+    ///
+    /// Returns the distance along the path of a point on one of its sections
+    ///
+    pub fn length_at(&self, section: usize, t: f64) -> f64 {
+        if section >= self.curves.len() {
+            return self.length();
+        }
+
+        let t = t.clamp(0.0, 1.0);
+        let step = ((t * (LENGTH_TABLE_STEPS as f64)) as usize).min(LENGTH_TABLE_STEPS - 1);
+        let step_t = (step as f64) / (LENGTH_TABLE_STEPS as f64);
+
+        self.length_table[section][step]
+            + curve_length(&self.curves[section].section(step_t, t), self.max_error)
+    }
+
+    ///
+    /// Finds the section and the t value within that section at a particular distance along the path
+    ///
+    /// Distances before the start or after the end of the path are clamped to the start or end. Where a distance is at the
+    /// join between two sections, this returns the start of the later section.
+    ///
+    pub fn section_t_at_length(&self, distance: f64) -> (usize, f64) {
+        if self.curves.is_empty() {
+            return (0, 0.0);
+        }
+
+        // Find the section containing this distance
+        let section = self
+            .length_table
+            .iter()
+            .position(|lengths| lengths[LENGTH_TABLE_STEPS] > distance)
+            .unwrap_or(self.curves.len() - 1);
+        let lengths = &self.length_table[section];
+
+        if distance >= lengths[LENGTH_TABLE_STEPS] {
+            return (section, 1.0);
+        } else if distance <= lengths[0] {
+            return (section, 0.0);
+        }
+
+        // Find the step within the section
+        let step = (0..LENGTH_TABLE_STEPS)
+            .find(|step| lengths[step + 1] > distance)
+            .unwrap_or(LENGTH_TABLE_STEPS - 1);
+        let step_length = lengths[step + 1] - lengths[step];
+        let t_min = (step as f64) / (LENGTH_TABLE_STEPS as f64);
+        let t_max = ((step + 1) as f64) / (LENGTH_TABLE_STEPS as f64);
+
+        if step_length <= 0.0 {
+            return (section, t_min);
+        }
+
+        // Search for the t value with the right length within the step
+        let curve = &self.curves[section];
+        let target = distance - lengths[step];
+        let (mut low, mut high) = (t_min, t_max);
+
+        for _ in 0..LENGTH_SEARCH_ITERATIONS {
+            let mid = (low + high) * 0.5;
+
+            if curve_length(&curve.section(t_min, mid), self.max_error) < target {
+                low = mid;
+            } else {
+                high = mid;
+            }
+        }
+
+        (section, (low + high) * 0.5)
+    }
+
+    ///
+    /// Finds the point at a particular distance along the path
+    ///
+    pub fn point_at_length(&self, distance: f64) -> Point {
+        if self.curves.is_empty() {
+            return Point::origin();
+        }
+
+        let (section, t) = self.section_t_at_length(distance);
+        self.curves[section].point_at_pos(t)
+    }
+
+    ///
+    /// Returns the part of the path between two distances along it
+    ///
+    /// The result can start and end part of the way through a section, and covers all of the sections in between.
+    /// Distances are clamped to the length of the path, and are swapped if `to_length` is before `from_length`.
+    ///
+    pub fn trim<POut: BezierPathFactory<Point = Point>>(
+        &self,
+        from_length: f64,
+        to_length: f64,
+    ) -> POut {
+        if self.curves.is_empty() {
+            return POut::from_points(Point::origin(), vec![]);
+        }
+
+        let (from_length, to_length) = if to_length < from_length {
+            (to_length, from_length)
+        } else {
+            (from_length, to_length)
+        };
+
+        let (start_section, start_t) = self.section_t_at_length(from_length);
+        let (end_section, end_t) = self.section_t_at_length(to_length);
+
+        // A distance at a join is reported as the start of the later section: finish at the end of the earlier one instead
+        let (end_section, end_t) = if end_t <= 0.0 && end_section > start_section {
+            (end_section - 1, 1.0)
+        } else {
+            (end_section, end_t)
+        };
+
+        let sections = (start_section..=end_section)
+            .map(|section| {
+                let t_min = if section == start_section {
+                    start_t
+                } else {
+                    0.0
+                };
+                let t_max = if section == end_section { end_t } else { 1.0 };
+
+                self.curves[section].section(t_min, t_max)
+            })
+            .collect::<Vec<_>>();
+
+        POut::from_points(
+            sections[0].start_point(),
+            sections
+                .iter()
+                .map(|section| {
+                    let (cp1, cp2) = section.control_points();
+                    (cp1, cp2, section.end_point())
+                })
+                .collect::<Vec<_>>(),
+        )
+    }
+}
+
+///
+/// The position and orientation at a point along a path
+///
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct PathFrame<Point> {
+    /// The index of the section of the path that this frame is on
+    pub section: usize,
+
+    /// The t value of the frame within its section
+    pub t: f64,
+
+    /// The position of the frame
+    pub position: Point,
+
+    /// Unit vector in the direction of the path
+    pub tangent: Point,
+
+    /// Unit vector at right angles to the path (the tangent rotated anticlockwise by 90 degrees)
+    pub normal: Point,
+}
+
+impl<Point: Coordinate + Coordinate2D> PathMeasure<Point> {
+    ///
+    /// Finds the unit tangent of a section, searching the nearby sections if the section has no direction at this point
+    ///
+    fn unit_tangent(&self, section: usize, t: f64) -> Point {
+        if let Some(tangent) = unit_tangent_at_pos(&self.curves[section], t) {
+            return tangent;
+        }
+
+        // The section is a point: use the direction of the path after it, or before it if there's nothing after it
+        let following = self.curves[section + 1..]
+            .iter()
+            .map(|curve| unit_tangent_at_pos(curve, 0.0));
+        let preceding = self.curves[..section]
+            .iter()
+            .rev()
+            .map(|curve| unit_tangent_at_pos(curve, 1.0));
+
+        following
+            .chain(preceding)
+            .flatten()
+            .next()
+            .unwrap_or_else(|| Point::from_components(&[1.0, 0.0]))
+    }
+
+    ///
+    /// Finds the position, tangent and normal at a particular distance along the path
+    ///
+    /// Distances outside of the path are clamped to its start or end. At a join between two sections, the direction of the
+    /// later section is used, and the tangent at the ends of a section is found from the control points when the curve
+    /// has no speed there, so frames don't jump around as they pass over a join. Returns `None` if the path is empty.
+    ///
+    pub fn frame_at_length(&self, distance: f64) -> Option<PathFrame<Point>> {
+        if self.curves.is_empty() {
+            return None;
+        }
+
+        let (section, t) = self.section_t_at_length(distance);
+
+        // If the distance is at the end of a section, use the start of the next section instead
+        let (section, t) = if t >= 1.0 && section + 1 < self.curves.len() {
+            (section + 1, 0.0)
+        } else {
+            (section, t)
+        };
+
+        let position = self.curves[section].point_at_pos(t);
+        let tangent = self.unit_tangent(section, t);
+        let normal = Point::from_components(&[-tangent.y(), tangent.x()]);
+
+        Some(PathFrame {
+            section,
+            t,
+            position,
+            tangent,
+            normal,
+        })
+    }
+}
+
+///
+/// Finds the position, tangent and normal at a distance along a path
+///
+/// This measures the path each time it's called: use `PathMeasure::frame_at_length()` to find many frames along the same
+/// path.
+///
+pub fn path_frame_at_length<P: BezierPath>(
+    path: &P,
+    distance: f64,
+    max_error: f64,
+) -> Option<PathFrame<P::Point>>
+where
+    P::Point: Coordinate2D,
+{
+    PathMeasure::new(path, max_error).frame_at_length(distance)
+}
+
+///
+/// Returns the part of a path between two distances along it, measured to within `max_error`
+///
+/// This is the basis of 'trim path' animations, where a path is drawn progressively from its start. This measures the
+/// path each time it's called: use `PathMeasure::trim()` to take many parts of the same path.
+///
+pub fn trim_path<P: BezierPath, POut: BezierPathFactory<Point = P::Point>>(
+    path: &P,
+    from_length: f64,
+    to_length: f64,
+    max_error: f64,
+) -> POut {
+    PathMeasure::new(path, max_error).trim(from_length, to_length)
+}
+
+///
+/// A point found by walking along a path
+///
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct PathWalkPoint<Point> {
+    /// The index of the section of the path that this point is on
+    pub section: usize,
+
+    /// The t value of the point within its section
+    pub t: f64,
+
+    /// The distance along the path to this point
+    pub distance: f64,
+
+    /// The position of the point
+    pub point: Point,
+}
+
+///
+/// Iterator that returns evenly spaced points along a path
+///
+pub struct PathWalkIterator<Point: Coordinate> {
+    /// The measurements for the path being walked
+    measure: PathMeasure<Point>,
+
+    /// The distance between points
+    distance: f64,
+
+    /// The index of the next point to return
+    next_point: usize,
+}
+
+///
+/// Walks a whole path by moving forward a set distance (measured along the path) at each point
+///
+/// Unlike `walk_curve_evenly()`, the walk carries on across the joins between the sections of the path, so the points are
+/// evenly spaced along the whole path. This returns a point at the start of the path and then one every `distance` units
+/// until the end of the path is reached. Each point reports the section it's on and its t value within that section.
+/// Lengths are measured to within `max_error`.
+///
+pub fn walk_path_evenly<P: BezierPath>(
+    path: &P,
+    distance: f64,
+    max_error: f64,
+) -> PathWalkIterator<P::Point> {
+    // Too small or negative values might produce bad effects due to floating point inprecision
+    let max_error = if max_error < 1e-10 { 1e-10 } else { max_error };
+    let distance = if distance < 1e-10 { 1e-10 } else { distance };
+
+    PathWalkIterator {
+        measure: PathMeasure::new(path, max_error),
+        distance,
+        next_point: 0,
+    }
+}
+
+impl<Point: Coordinate> PathWalkIterator<Point> {
+    ///
+    /// The measurements for the path being walked
+    ///
+    #[inline]
+    pub fn measure(&self) -> &PathMeasure<Point> {
+        &self.measure
+    }
+}
+
+impl<Point: Coordinate> Iterator for PathWalkIterator<Point> {
+    type Item = PathWalkPoint<Point>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.measure.curves.is_empty() {
+            return None;
+        }
+
+        // Allow for a small amount of floating point error when deciding if the last point is at the end of the path
+        let distance = (self.next_point as f64) * self.distance;
+        if distance > self.measure.length() + self.measure.max_error {
+            return None;
+        }
+
+        self.next_point += 1;
+
+        let (section, t) = self.measure.section_t_at_length(distance);
+
+        Some(PathWalkPoint {
+            section,
+            t,
+            distance,
+            point: self.measure.curves[section].point_at_pos(t),
+        })
+    }
+}
@@ -0,0 +1,264 @@
+use super::super::super::geo::{Coordinate, Coordinate2D};
+use super::super::curve::Curve;
+use super::super::nearest::distance_to_curve;
+use super::measure::walk_path_evenly;
+use super::path::BezierPath;
+use super::scanline::path_scanline_intersections;
+use super::to_curves::path_to_curves;
+
+/// Number of samples that the ends of a triangulation edge must be separated by around the outline for the edge to be on
+/// the medial axis (triangulation edges between nearby samples produce short branches that lead to the outline)
+const MIN_SAMPLE_SEPARATION: usize = 3;
+
+///
+/// A straight section of the medial axis of a path
+///
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct MedialAxisSegment<Point> {
+    /// The start of the segment
+    pub start: Point,
+
+    /// The radius of the largest circle centered on the start point that fits inside the path
+    pub start_radius: f64,
+
+    /// The end of the segment
+    pub end: Point,
+
+    /// The radius of the largest circle centered on the end point that fits inside the path
+    pub end_radius: f64,
+}
+
+///
+/// A triangle in the Delaunay triangulation of the samples of an outline
+///
+#[derive(Clone, Copy, Debug)]
+struct DelaunayTriangle {
+    /// The indexes of the corners of the triangle
+    vertices: [usize; 3],
+
+    /// The center of the circle through the corners of the triangle
+    center: (f64, f64),
+
+    /// The square of the radius of the circle through the corners of the triangle
+    radius_sq: f64,
+}
+
+impl DelaunayTriangle {
+    ///
+    /// Creates a triangle from three points, or `None` if the points are in a line
+    ///
+    fn new(vertices: [usize; 3], points: &[(f64, f64)]) -> Option<DelaunayTriangle> {
+        let (ax, ay) = points[vertices[0]];
+        let (bx, by) = points[vertices[1]];
+        let (cx, cy) = points[vertices[2]];
+
+        let d = 2.0 * (ax * (by - cy) + bx * (cy - ay) + cx * (ay - by));
+        if d.abs() < 1e-12 {
+            return None;
+        }
+
+        let (a_sq, b_sq, c_sq) = (ax * ax + ay * ay, bx * bx + by * by, cx * cx + cy * cy);
+        let center_x = (a_sq * (by - cy) + b_sq * (cy - ay) + c_sq * (ay - by)) / d;
+        let center_y = (a_sq * (cx - bx) + b_sq * (ax - cx) + c_sq * (bx - ax)) / d;
+        let radius_sq = (ax - center_x) * (ax - center_x) + (ay - center_y) * (ay - center_y);
+
+        Some(DelaunayTriangle {
+            vertices,
+            center: (center_x, center_y),
+            radius_sq,
+        })
+    }
+
+    ///
+    /// True if a point is inside the circle through the corners of this triangle
+    ///
+    #[inline]
+    fn circle_contains(&self, (x, y): (f64, f64)) -> bool {
+        let (dx, dy) = (x - self.center.0, y - self.center.1);
+        dx * dx + dy * dy < self.radius_sq
+    }
+
+    ///
+    /// The edges of this triangle, with the lower vertex index first
+    ///
+    #[inline]
+    fn edges(&self) -> [(usize, usize); 3] {
+        let [a, b, c] = self.vertices;
+        [
+            (a.min(b), a.max(b)),
+            (b.min(c), b.max(c)),
+            (c.min(a), c.max(a)),
+        ]
+    }
+}
+
+///
+/// Computes the Delaunay triangulation of a set of points using the Bowyer-Watson algorithm
+///
+/// Triangles that have a corner at one of the three points added to make the initial triangle are left out of the result.
+///
+fn delaunay_triangles(points: &[(f64, f64)]) -> Vec<DelaunayTriangle> {
+    if points.len() < 3 {
+        return vec![];
+    }
+
+    // Start with a triangle enclosing all of the points
+    let (mut min_x, mut min_y) = (f64::MAX, f64::MAX);
+    let (mut max_x, mut max_y) = (f64::MIN, f64::MIN);
+    for (x, y) in points.iter() {
+        min_x = min_x.min(*x);
+        min_y = min_y.min(*y);
+        max_x = max_x.max(*x);
+        max_y = max_y.max(*y);
+    }
+
+    let size = (max_x - min_x).max(max_y - min_y).max(1.0) * 16.0;
+    let (mid_x, mid_y) = ((min_x + max_x) * 0.5, (min_y + max_y) * 0.5);
+    let num_points = points.len();
+
+    let mut all_points = points.to_vec();
+    all_points.push((mid_x - size, mid_y - size));
+    all_points.push((mid_x + size, mid_y - size));
+    all_points.push((mid_x, mid_y + size));
+
+    let mut triangles =
+        DelaunayTriangle::new([num_points, num_points + 1, num_points + 2], &all_points)
+            .into_iter()
+            .collect::<Vec<_>>();
+
+    // Add the points one at a time, replacing the triangles whose circles contain them
+    for point_idx in 0..num_points {
+        let point = all_points[point_idx];
+
+        let (bad, good): (Vec<_>, Vec<_>) = triangles
+            .into_iter()
+            .partition(|triangle| triangle.circle_contains(point));
+        triangles = good;
+
+        // The edges of the hole left by removing the bad triangles are the ones that only one of them has
+        let mut edges = bad
+            .iter()
+            .flat_map(|triangle| triangle.edges())
+            .collect::<Vec<_>>();
+        edges.sort();
+
+        let mut idx = 0;
+        while idx < edges.len() {
+            if idx + 1 < edges.len() && edges[idx] == edges[idx + 1] {
+                idx += 2;
+            } else {
+                let (a, b) = edges[idx];
+                triangles.extend(DelaunayTriangle::new([a, b, point_idx], &all_points));
+                idx += 1;
+            }
+        }
+    }
+
+    triangles.retain(|triangle| triangle.vertices.iter().all(|vertex| *vertex < num_points));
+    triangles
+}
+
+///
+/// True if a point is inside a path (using the non-zero winding rule)
+///
+fn path_contains<P: BezierPath>(path: &P, x: f64, y: f64) -> bool
+where
+    P::Point: Coordinate2D,
+{
+    path_scanline_intersections(path, y)
+        .into_iter()
+        .take_while(|(intersection_x, _)| *intersection_x < x)
+        .map(|(_, direction)| direction.winding())
+        .sum::<i32>()
+        != 0
+}
+
+///
+/// Computes an approximation of the medial axis (or skeleton) of a closed path
+///
+/// The medial axis is made up of the centers of all the circles that fit inside the path and touch its outline at two or
+/// more points. It runs down the middle of every part of the shape, so for a filled stroke it's the line that the stroke
+/// follows, and the radius of the circle at each point is half the width of the stroke there.
+///
+/// This works by placing points every `spacing` units around the outline and finding the edges of their Voronoi diagram
+/// that are inside the path, so the result is a set of straight segments that get closer to the real medial axis as the
+/// spacing is reduced. Branches caused by small details of the outline (smaller than a few times the spacing) are left
+/// out. Lengths along the outline are measured to within `max_error`. The radius at each end of a segment is the
+/// distance to the nearest point on the path. There are no segments if `spacing` is not a positive, finite distance.
+///
+pub fn path_medial_axis<P: BezierPath>(
+    path: &P,
+    spacing: f64,
+    max_error: f64,
+) -> Vec<MedialAxisSegment<P::Point>>
+where
+    P::Point: Coordinate + Coordinate2D,
+{
+    if !spacing.is_finite() || spacing <= 0.0 {
+        return vec![];
+    }
+
+    // Sample the outline (leaving out the last point if it's the same as the first, as the path is closed)
+    let mut samples = walk_path_evenly(path, spacing, max_error)
+        .map(|walk_point| (walk_point.point.x(), walk_point.point.y()))
+        .collect::<Vec<_>>();
+    if samples.len() > 1 {
+        let (first, last) = (samples[0], samples[samples.len() - 1]);
+        if (first.0 - last.0).abs() < spacing * 0.5 && (first.1 - last.1).abs() < spacing * 0.5 {
+            samples.pop();
+        }
+    }
+
+    let num_samples = samples.len();
+    let triangles = delaunay_triangles(&samples);
+
+    // The circumcenters of the triangles are the vertices of the Voronoi diagram: only the ones inside the path are part of
+    // the medial axis, so pair up the triangles with these vertices that share an edge to find the edges of the diagram
+    let mut edges = vec![];
+    for (idx, triangle) in triangles.iter().enumerate() {
+        if path_contains(path, triangle.center.0, triangle.center.1) {
+            edges.extend(triangle.edges().iter().map(|edge| (*edge, idx)));
+        }
+    }
+    edges.sort();
+
+    let curves = path_to_curves::<_, Curve<_>>(path).collect::<Vec<_>>();
+    let radius = |point: &P::Point| {
+        curves
+            .iter()
+            .map(|curve| distance_to_curve(curve, point))
+            .fold(f64::MAX, f64::min)
+    };
+
+    let mut segments = vec![];
+    for pair in edges.windows(2) {
+        let ((edge_a, triangle_a), (edge_b, triangle_b)) = (pair[0], pair[1]);
+        if edge_a != edge_b {
+            continue;
+        }
+
+        // Leave out the edges between samples that are close together around the outline
+        let separation = edge_a.1 - edge_a.0;
+        let separation = separation.min(num_samples - separation);
+        if separation < MIN_SAMPLE_SEPARATION {
+            continue;
+        }
+
+        let (center_a, center_b) = (triangles[triangle_a].center, triangles[triangle_b].center);
+        let start = P::Point::from_components(&[center_a.0, center_a.1]);
+        let end = P::Point::from_components(&[center_b.0, center_b.1]);
+
+        if start.is_near_to(&end, 1e-9) {
+            continue;
+        }
+
+        segments.push(MedialAxisSegment {
+            start,
+            start_radius: radius(&start),
+            end,
+            end_radius: radius(&end),
+        });
+    }
+
+    segments
+}
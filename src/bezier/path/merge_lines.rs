@@ -0,0 +1,121 @@
+use super::path::{BezierPath, BezierPathFactory};
+use crate::bezier::offset_error::distance_to_segment;
+use crate::geo::Coordinate;
+
+///
+/// A section of a path that's being simplified
+///
+enum Section<Point> {
+    /// A run of straight lines, as the list of points they pass through (including the start point)
+    Lines(Vec<Point>),
+
+    /// A curve (control points and end point)
+    Curve(Point, Point, Point),
+}
+
+///
+/// True if the points can be replaced by a single line from the first point to the last one
+///
+fn is_straight<Point: Coordinate>(points: &[Point], tolerance: f64) -> bool {
+    let (start, end) = (&points[0], &points[points.len() - 1]);
+
+    points[1..(points.len() - 1)]
+        .iter()
+        .all(|point| distance_to_segment(point, (start, end)) <= tolerance)
+}
+
+///
+/// Simplifies a path by removing duplicate points and merging runs of collinear lines into single lines
+///
+/// Curves where every point is within `tolerance` of the start are removed, as are the points in straight lines where
+/// the line doesn't change direction by more than `tolerance`. Curves are left as they are, so this mostly affects
+/// paths made up of lines, such as traced bitmaps or imported polygons. The start point is moved to the start of the
+/// line it's part of if the lines either side of it are collinear, so closed polygons don't keep an extra point at the
+/// start.
+///
+/// Points are only removed if every point that's removed is within `tolerance` of the line that replaces it, so lines
+/// that double back on themselves are not merged.
+///
+pub fn path_merge_collinear_lines<P: BezierPath, POut: BezierPathFactory<Point = P::Point>>(
+    path: &P,
+    tolerance: f64,
+) -> POut {
+    let mut sections = vec![];
+    let mut last_point = path.start_point();
+
+    for (cp1, cp2, end_point) in path.points() {
+        // Skip curves that are just a single point
+        if [cp1, cp2, end_point]
+            .iter()
+            .all(|point| point.distance_to(&last_point) <= tolerance)
+        {
+            continue;
+        }
+
+        let is_line = distance_to_segment(&cp1, (&last_point, &end_point)) <= tolerance
+            && distance_to_segment(&cp2, (&last_point, &end_point)) <= tolerance;
+
+        if !is_line {
+            sections.push(Section::Curve(cp1, cp2, end_point));
+        } else if let Some(Section::Lines(points)) = sections.last_mut() {
+            // Extend the current run of lines if it stays straight, or start a new run otherwise
+            points.push(end_point);
+
+            if !is_straight(points, tolerance) {
+                points.pop();
+                sections.push(Section::Lines(vec![last_point, end_point]));
+            }
+        } else {
+            sections.push(Section::Lines(vec![last_point, end_point]));
+        }
+
+        last_point = end_point;
+    }
+
+    // Merge the lines either side of the start point if they're collinear
+    let mut start_point = path.start_point();
+
+    if sections.len() > 2 {
+        if let (Some(Section::Lines(first)), Some(Section::Lines(last))) =
+            (sections.first(), sections.last())
+        {
+            let joined = last
+                .iter()
+                .chain(first[1..].iter())
+                .copied()
+                .collect::<Vec<_>>();
+
+            if is_straight(&joined, tolerance) {
+                start_point = joined[0];
+                sections[0] = Section::Lines(joined);
+                sections.pop();
+            }
+        }
+    }
+
+    // Generate the simplified path
+    let mut last_point = start_point;
+    let points = sections
+        .into_iter()
+        .map(|section| {
+            let (cp1, cp2, end_point) = match section {
+                Section::Curve(cp1, cp2, end_point) => (cp1, cp2, end_point),
+                Section::Lines(points) => {
+                    let end_point = points[points.len() - 1];
+                    let offset = end_point - last_point;
+
+                    (
+                        last_point + offset * (1.0 / 3.0),
+                        last_point + offset * (2.0 / 3.0),
+                        end_point,
+                    )
+                }
+            };
+
+            last_point = end_point;
+            (cp1, cp2, end_point)
+        })
+        .collect::<Vec<_>>();
+
+    POut::from_points(start_point, points)
+}
@@ -9,30 +9,125 @@
 //! The `path_add()`, `path_sub()` and `path_intersect()` functions can be used to perform path arithmetic: combining
 //! multiple paths into a single result. The `GraphPath` type is used to implement these functions: it can represent
 //! paths where points can have more than one following edge attached to them and provides functions for implementing
-//! similar operations.
+//! similar operations. The `try_path_add()`, `try_path_sub()` and `try_path_intersect()` variants return a
+//! `PathArithmeticError` instead of a possibly corrupted shape when the result can't be found reliably.
+//! `path_add_with_progress()` reports progress to a `ProgressMonitor`, which can also cancel the operation.
+//! `canonicalize_paths()` puts the result into a predictable order with each path starting at a predictable point, for
+//! comparing the results in tests.
+//!
+//! `clip_path_to_bounds()` is a fast way to find the part of a path inside a rectangle, for dividing a scene into
+//! tiles.
 //!
 //! `BezierPathBuilder` provides a way to quickly build paths from any type implementing the factory trait without
 //! needing to generate all of the primitives manually.
 //!
+//! `path_index()` builds a spatial index of the curves in a set of paths, for finding the curves crossed by a ray, the
+//! curves nearest to a point (`nearest_curves()`) or the points that a cursor can snap to (`snap_to_paths()`).
+//!
+//! `PathMeasure` measures distances along a whole path, and `walk_path_evenly()` uses it to generate evenly spaced
+//! points along a path (for placing dashes or markers, for example). `trim_path()` takes the part of a path between two
+//! distances along it. `path_ribbon()` generates a pair of rails either side of a path, joined by evenly spaced rungs.
+//!
+//! `path_tessellate()` converts a set of paths into a mesh of triangles covering their interiors, for rendering with
+//! a GPU, using `path_figures()` to find which paths are holes in which others. `path_scanline_intersections()` finds
+//! where a path crosses horizontal lines, for rasterizing it on the CPU instead, and `path_contains_points()` and
+//! `path_winding_numbers()` use the same scanlines to test large numbers of points against a path at once.
+//! `normalize_winding()` uses the same nesting as `path_figures()` to make the outlines and holes in imported shapes go
+//! in consistent directions.
+//!
+//! `path_joins()` finds the corners and cusps where the sections of a path meet, and `smooth_path()` removes the kinks
+//! at these joins, such as the ones left by path arithmetic.
+//!
+//! `path_medial_axis()` finds the skeleton running down the middle of a shape, along with the width of the shape at
+//! each point. `path_stroke_centerline()` does something similar for outlines that were made by stroking a line,
+//! recovering the line and the width of the stroke.
+//!
+//! `path_sweep()` generates the outline of a profile moved along a spine, for strokes drawn with a shaped pen, and
+//! `path_sweep_cross_sections()` places a profile along a 3D spine for building tubes and extrusions.
+//!
+//! `path_to_quadratics()` converts paths to quadratic curves, for exporting to formats such as TrueType fonts.
+//! `path_to_polygon()` converts paths to `Polygon`s with holes, for use with triangulators and geometry libraries, and
+//! `polygon_to_path()` converts them back again. With the `geo_formats` feature, `paths_to_geojson()`, `paths_to_wkt()`
+//! and the corresponding `paths_from_` functions read and write polygons with holes as GeoJSON and WKT.
+//!
+//! `validate_path()` finds problems such as NaN coordinates, curves with no length and gaps that can cause path
+//! arithmetic to fail, and `repair_path()` fixes them. `path_merge_collinear_lines()` removes the extra points from paths
+//! made up of many small straight lines, such as traced bitmaps. `compress_path()` stores a path in a compact form for
+//! saving, to within a tolerance.
+//!
 
 pub mod algorithms;
 mod arithmetic;
 mod bounds;
+mod canonical;
+mod centerline;
+mod clip;
+mod compress;
+mod containment;
+mod flatten;
+#[cfg(feature = "geo_formats")]
+mod geo_formats;
 mod graph_path;
 mod intersection;
 mod is_clockwise;
+mod joins;
+mod markers;
+mod measure;
+mod medial_axis;
+mod merge_lines;
+mod morph;
+mod nesting;
+mod offset;
 mod path;
 mod path_builder;
+mod path_index;
 mod point;
+mod polygon;
+mod progress;
 mod ray;
+mod ribbon;
+mod scanline;
+mod smooth;
+mod snap;
+mod sweep;
+mod tessellate;
 mod to_curves;
+mod to_quadratics;
+mod validate;
 
 pub use self::arithmetic::*;
 pub use self::bounds::*;
+pub use self::canonical::*;
+pub use self::centerline::*;
+pub use self::clip::*;
+pub use self::compress::*;
+pub use self::containment::*;
+pub use self::flatten::*;
+#[cfg(feature = "geo_formats")]
+pub use self::geo_formats::*;
 pub use self::graph_path::*;
 pub use self::intersection::*;
 pub use self::is_clockwise::*;
+pub use self::joins::*;
+pub use self::markers::*;
+pub use self::measure::*;
+pub use self::medial_axis::*;
+pub use self::merge_lines::*;
+pub use self::morph::*;
+pub use self::nesting::*;
+pub use self::offset::*;
 pub use self::path::*;
 pub use self::path_builder::*;
+pub use self::path_index::*;
 pub use self::point::*;
+pub use self::polygon::*;
+pub use self::progress::*;
+pub use self::ribbon::*;
+pub use self::scanline::*;
+pub use self::smooth::*;
+pub use self::snap::*;
+pub use self::sweep::*;
+pub use self::tessellate::*;
 pub use self::to_curves::*;
+pub use self::to_quadratics::*;
+pub use self::validate::*;
@@ -0,0 +1,483 @@
+use super::super::super::consts::{SMALL_DISTANCE, SMALL_T_DISTANCE};
+use super::super::super::geo::Coordinate;
+use super::super::curve::{BezierCurve, BezierCurveFactory, Curve};
+use super::super::length::{curve_length, t_for_length};
+use super::measure::PathMeasure;
+use super::path::{BezierPath, BezierPathFactory};
+use super::to_curves::path_to_curves;
+
+use std::cmp::Ordering;
+
+/// Maximum error to allow when measuring the length of the curves in a path
+const LENGTH_MAX_ERROR: f64 = 1e-6;
+
+/// Breakpoints closer together than this (as a proportion of the length of the path) are treated as the same point
+const MIN_BREAKPOINT_DISTANCE: f64 = 1e-9;
+
+///
+/// Returns true if the curves form a closed path
+///
+fn is_closed<Point: Coordinate>(curves: &[Curve<Point>]) -> bool {
+    match (curves.first(), curves.last()) {
+        (Some(first), Some(last)) => last
+            .end_point()
+            .is_near_to(&first.start_point(), SMALL_DISTANCE),
+        _ => false,
+    }
+}
+
+///
+/// Finds the position of the start of each curve as a proportion of the total length of the path (with a final value of 1.0)
+///
+fn join_positions<Point: Coordinate>(curves: &[Curve<Point>]) -> (Vec<f64>, Vec<f64>) {
+    let lengths = curves
+        .iter()
+        .map(|curve| curve_length(curve, LENGTH_MAX_ERROR))
+        .collect::<Vec<_>>();
+    let total_length: f64 = lengths.iter().sum();
+
+    let mut positions = vec![0.0];
+
+    if total_length > 0.0 {
+        let mut length_so_far = 0.0;
+        for length in lengths.iter() {
+            length_so_far += length;
+            positions.push(length_so_far / total_length);
+        }
+    } else {
+        // Every curve is a point, so divide the path evenly by curve instead
+        positions.extend((1..=curves.len()).map(|idx| (idx as f64) / (curves.len() as f64)));
+    }
+
+    // Avoid rounding errors at the end of the path
+    *positions.last_mut().unwrap() = 1.0;
+
+    (positions, lengths)
+}
+
+///
+/// Divides a path up into sections that end at the specified breakpoints (which must include the joins in the path)
+///
+/// `positions` and `lengths` are the values returned by `join_positions()` for the curves.
+///
+fn split_at_breakpoints<Point: Coordinate>(
+    curves: &[Curve<Point>],
+    positions: &[f64],
+    lengths: &[f64],
+    breakpoints: &[f64],
+) -> Vec<Curve<Point>> {
+    let mut result = vec![];
+    let mut curve_idx = 0;
+
+    for range in breakpoints.windows(2) {
+        let (start_pos, end_pos) = (range[0], range[1]);
+
+        // Find the curve containing this range
+        while curve_idx + 1 < curves.len()
+            && positions[curve_idx + 1] <= start_pos + MIN_BREAKPOINT_DISTANCE
+        {
+            curve_idx += 1;
+        }
+
+        let curve = &curves[curve_idx];
+        let (curve_start, curve_end) = (positions[curve_idx], positions[curve_idx + 1]);
+        let curve_proportion = curve_end - curve_start;
+
+        // Convert the positions to t values on this curve
+        let to_t = |pos: f64| {
+            if pos >= curve_end - MIN_BREAKPOINT_DISTANCE {
+                1.0
+            } else if pos <= curve_start + MIN_BREAKPOINT_DISTANCE {
+                0.0
+            } else if lengths[curve_idx] <= 0.0 {
+                (pos - curve_start) / curve_proportion
+            } else {
+                t_for_length(
+                    curve,
+                    (pos - curve_start) / curve_proportion * lengths[curve_idx],
+                    lengths[curve_idx],
+                    LENGTH_MAX_ERROR,
+                )
+            }
+        };
+
+        let section = curve.section(to_t(start_pos), to_t(end_pos));
+        result.push(Curve::from_curve(&section));
+    }
+
+    result
+}
+
+///
+/// Divides two non-empty lists of curves at the joins in either of them, so that they have the same number of sections
+///
+/// The joins are found as a proportion of the arc length of each path, so each section of the first path corresponds to
+/// the section at the same proportion of the way along the second path.
+///
+pub(crate) fn matching_sections<Point: Coordinate>(
+    curves_a: &[Curve<Point>],
+    curves_b: &[Curve<Point>],
+) -> (Vec<Curve<Point>>, Vec<Curve<Point>>) {
+    let (positions_a, lengths_a) = join_positions(curves_a);
+    let (positions_b, lengths_b) = join_positions(curves_b);
+
+    let mut breakpoints = positions_a
+        .iter()
+        .chain(positions_b.iter())
+        .cloned()
+        .collect::<Vec<_>>();
+    breakpoints.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+    breakpoints.dedup_by(|a, b| (*a - *b).abs() <= MIN_BREAKPOINT_DISTANCE);
+
+    let sections_a = split_at_breakpoints(curves_a, &positions_a, &lengths_a, &breakpoints);
+    let sections_b = split_at_breakpoints(curves_b, &positions_b, &lengths_b, &breakpoints);
+
+    (sections_a, sections_b)
+}
+
+///
+/// Reverses a list of curves representing a path
+///
+fn reverse_curves<Point: Coordinate>(curves: Vec<Curve<Point>>) -> Vec<Curve<Point>> {
+    curves
+        .into_iter()
+        .rev()
+        .map(|curve| curve.reverse::<Curve<Point>>())
+        .collect()
+}
+
+///
+/// Describes how the points of one path are matched up with the points of another path when morphing between them
+///
+/// This can be found using `morph_correspondence()` and then used with `morph_path_with_correspondence()` or
+/// `PathMorph::with_correspondence()`, so that an animation between two shapes only has to find the correspondence once.
+///
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct MorphCorrespondence {
+    /// True if the target path should be reversed before morphing
+    pub reversed: bool,
+
+    /// The position along the target path (after reversing it if necessary, and as a proportion of its length) that
+    /// corresponds to the start of the source path. This is always 0.0 for open paths.
+    pub start_position: f64,
+}
+
+impl Default for MorphCorrespondence {
+    fn default() -> Self {
+        MorphCorrespondence {
+            reversed: false,
+            start_position: 0.0,
+        }
+    }
+}
+
+/// Number of points to sample from each path when finding the correspondence between them
+const CORRESPONDENCE_SAMPLES: usize = 64;
+
+///
+/// Samples evenly spaced points along a path
+///
+fn sample_evenly<Point: Coordinate>(curves: &[Curve<Point>], num_samples: usize) -> Vec<Point> {
+    let measure = PathMeasure::from_curves(curves.to_vec(), LENGTH_MAX_ERROR);
+    let length = measure.length();
+
+    (0..num_samples)
+        .map(|sample| measure.point_at_length(length * (sample as f64) / (num_samples as f64)))
+        .collect()
+}
+
+///
+/// Finds the correspondence between two lists of curves that minimises the distance points need to travel when morphing
+///
+fn curves_correspondence<Point: Coordinate>(
+    curves_a: &[Curve<Point>],
+    curves_b: &[Curve<Point>],
+) -> MorphCorrespondence {
+    if curves_a.is_empty() || curves_b.is_empty() {
+        return MorphCorrespondence::default();
+    }
+
+    // Open paths can only be reversed, but closed paths can also start from any point
+    let closed = is_closed(curves_a) && is_closed(curves_b);
+    let num_samples = if closed {
+        CORRESPONDENCE_SAMPLES
+    } else {
+        CORRESPONDENCE_SAMPLES + 1
+    };
+    let samples_a = if closed {
+        sample_evenly(curves_a, num_samples)
+    } else {
+        // Include the end point for open paths
+        let mut samples = sample_evenly(curves_a, num_samples - 1);
+        samples.push(curves_a.last().unwrap().end_point());
+        samples
+    };
+
+    let mut best = MorphCorrespondence::default();
+    let mut best_distance = f64::MAX;
+
+    for reversed in [false, true].iter() {
+        let curves_b = if *reversed {
+            reverse_curves(curves_b.to_vec())
+        } else {
+            curves_b.to_vec()
+        };
+        let samples_b = if closed {
+            sample_evenly(&curves_b, num_samples)
+        } else {
+            let mut samples = sample_evenly(&curves_b, num_samples - 1);
+            samples.push(curves_b.last().unwrap().end_point());
+            samples
+        };
+
+        let num_offsets = if closed { num_samples } else { 1 };
+
+        for offset in 0..num_offsets {
+            let distance: f64 = samples_a
+                .iter()
+                .enumerate()
+                .map(|(idx, point)| point.distance_to(&samples_b[(idx + offset) % num_samples]))
+                .sum();
+
+            if distance < best_distance {
+                best_distance = distance;
+                best = MorphCorrespondence {
+                    reversed: *reversed,
+                    start_position: (offset as f64) / (num_samples as f64),
+                };
+            }
+        }
+    }
+
+    best
+}
+
+///
+/// Finds the best way to match up the points of two paths for morphing between them
+///
+/// This tries both directions for the second path, and if both paths are closed, every starting point along it, picking
+/// the one where the points need to travel the shortest total distance. Morphing between paths by matching their
+/// sections in order can make shapes twist or turn inside out, especially when one of them has been rotated: this
+/// finds the correspondence that avoids that.
+///
+pub fn morph_correspondence<PathA, PathB>(path_a: &PathA, path_b: &PathB) -> MorphCorrespondence
+where
+    PathA: BezierPath,
+    PathB: BezierPath<Point = PathA::Point>,
+{
+    let curves_a = path_to_curves::<_, Curve<_>>(path_a).collect::<Vec<_>>();
+    let curves_b = path_to_curves::<_, Curve<_>>(path_b).collect::<Vec<_>>();
+
+    curves_correspondence(&curves_a, &curves_b)
+}
+
+///
+/// Reverses and rotates a list of curves according to a correspondence
+///
+fn apply_correspondence<Point: Coordinate>(
+    curves: Vec<Curve<Point>>,
+    correspondence: &MorphCorrespondence,
+) -> Vec<Curve<Point>> {
+    let mut curves = if correspondence.reversed {
+        reverse_curves(curves)
+    } else {
+        curves
+    };
+
+    if curves.is_empty() || correspondence.start_position <= 0.0 || !is_closed(&curves) {
+        return curves;
+    }
+
+    // Find where the new start point is
+    let measure = PathMeasure::from_curves(curves.clone(), LENGTH_MAX_ERROR);
+    let (curve_idx, t) =
+        measure.section_t_at_length(correspondence.start_position * measure.length());
+
+    if t <= SMALL_T_DISTANCE {
+        curves.rotate_left(curve_idx);
+    } else if t >= 1.0 - SMALL_T_DISTANCE {
+        let num_curves = curves.len();
+        curves.rotate_left((curve_idx + 1) % num_curves);
+    } else {
+        // Split the curve containing the start point, so the path starts with the second half and ends with the first half
+        let (first_half, second_half) = curves[curve_idx].subdivide::<Curve<_>>(t);
+
+        curves[curve_idx] = first_half;
+        curves.insert(curve_idx + 1, second_half);
+        curves.rotate_left(curve_idx + 1);
+    }
+
+    curves
+}
+
+///
+/// Two paths that have been prepared for morphing between them
+///
+/// Finding the correspondence between the paths and dividing them up into matching sections is most of the work of a
+/// morph, so this does it once: `path_at()` can then generate the path at any point of the morph by interpolating the
+/// control points of the sections, which is much faster when animating between two shapes.
+///
+#[derive(Clone, Debug)]
+pub struct PathMorph<Point: Coordinate> {
+    /// The correspondence used to match up the points of the paths
+    correspondence: MorphCorrespondence,
+
+    /// The start points of the two paths (used if either path has no sections)
+    start_points: (Point, Point),
+
+    /// The sections of the first path, paired with the corresponding sections of the second path
+    sections: Vec<(Curve<Point>, Curve<Point>)>,
+}
+
+impl<Point: Coordinate> PathMorph<Point> {
+    ///
+    /// Prepares to morph between two paths, finding the correspondence between them using `morph_correspondence()`
+    ///
+    pub fn new<PathA, PathB>(path_a: &PathA, path_b: &PathB) -> Self
+    where
+        PathA: BezierPath<Point = Point>,
+        PathB: BezierPath<Point = Point>,
+    {
+        let curves_a = path_to_curves::<_, Curve<_>>(path_a).collect::<Vec<_>>();
+        let curves_b = path_to_curves::<_, Curve<_>>(path_b).collect::<Vec<_>>();
+        let correspondence = curves_correspondence(&curves_a, &curves_b);
+
+        Self::from_curves(
+            (path_a.start_point(), path_b.start_point()),
+            curves_a,
+            curves_b,
+            correspondence,
+        )
+    }
+
+    ///
+    /// Prepares to morph between two paths, using a correspondence that has already been found
+    ///
+    pub fn with_correspondence<PathA, PathB>(
+        path_a: &PathA,
+        path_b: &PathB,
+        correspondence: &MorphCorrespondence,
+    ) -> Self
+    where
+        PathA: BezierPath<Point = Point>,
+        PathB: BezierPath<Point = Point>,
+    {
+        let curves_a = path_to_curves::<_, Curve<_>>(path_a).collect::<Vec<_>>();
+        let curves_b = path_to_curves::<_, Curve<_>>(path_b).collect::<Vec<_>>();
+
+        Self::from_curves(
+            (path_a.start_point(), path_b.start_point()),
+            curves_a,
+            curves_b,
+            *correspondence,
+        )
+    }
+
+    ///
+    /// Divides the curves of two paths into matching sections
+    ///
+    fn from_curves(
+        start_points: (Point, Point),
+        curves_a: Vec<Curve<Point>>,
+        curves_b: Vec<Curve<Point>>,
+        correspondence: MorphCorrespondence,
+    ) -> Self {
+        let sections = if curves_a.is_empty() || curves_b.is_empty() {
+            vec![]
+        } else {
+            let curves_b = apply_correspondence(curves_b, &correspondence);
+            let (sections_a, sections_b) = matching_sections(&curves_a, &curves_b);
+
+            sections_a.into_iter().zip(sections_b).collect()
+        };
+
+        PathMorph {
+            correspondence,
+            start_points,
+            sections,
+        }
+    }
+
+    ///
+    /// The correspondence used to match up the points of the two paths
+    ///
+    #[inline]
+    pub fn correspondence(&self) -> MorphCorrespondence {
+        self.correspondence
+    }
+
+    ///
+    /// Generates the path that is `t` of the way between the two paths
+    ///
+    /// A value of 0.0 produces the first path and 1.0 produces the second.
+    ///
+    pub fn path_at<POut: BezierPathFactory<Point = Point>>(&self, t: f64) -> POut {
+        let lerp = |a: Point, b: Point| a * (1.0 - t) + b * t;
+
+        let (section_a, section_b) = match self.sections.first() {
+            Some(first_sections) => first_sections,
+            None => {
+                return POut::from_points(lerp(self.start_points.0, self.start_points.1), vec![])
+            }
+        };
+
+        // Interpolate between the control points of the matching sections
+        let start_point = lerp(section_a.start_point(), section_b.start_point());
+        let points = self
+            .sections
+            .iter()
+            .map(|(section_a, section_b)| {
+                let (cp1_a, cp2_a) = section_a.control_points();
+                let (cp1_b, cp2_b) = section_b.control_points();
+
+                (
+                    lerp(cp1_a, cp1_b),
+                    lerp(cp2_a, cp2_b),
+                    lerp(section_a.end_point(), section_b.end_point()),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        POut::from_points(start_point, points)
+    }
+}
+
+///
+/// Generates a path that is part of the way between two other paths
+///
+/// `t` is the proportion of the way between the paths to generate: a value of 0.0 produces `path_a` and 1.0 produces
+/// `path_b`. The paths do not need to have the same number of sections: both paths are divided at the positions of the
+/// joins in either path (measured as a proportion of the arc length of each path), so that corresponding parts of the
+/// two shapes are matched up.
+///
+/// The direction and start point of `path_b` are chosen using `morph_correspondence()`, so the shape doesn't twist during
+/// the morph. This prepares the paths every time it's called: use `PathMorph` to generate many frames of the same morph.
+///
+pub fn morph_path<PathA, PathB, POut>(path_a: &PathA, path_b: &PathB, t: f64) -> POut
+where
+    PathA: BezierPath,
+    PathB: BezierPath<Point = PathA::Point>,
+    POut: BezierPathFactory<Point = PathA::Point>,
+{
+    PathMorph::new(path_a, path_b).path_at(t)
+}
+
+///
+/// Generates a path that is part of the way between two other paths, using a correspondence that has already been found
+///
+/// This works like `morph_path()`, except the correspondence between the paths is supplied instead of being calculated
+/// each time the function is called.
+///
+pub fn morph_path_with_correspondence<PathA, PathB, POut>(
+    path_a: &PathA,
+    path_b: &PathB,
+    correspondence: &MorphCorrespondence,
+    t: f64,
+) -> POut
+where
+    PathA: BezierPath,
+    PathB: BezierPath<Point = PathA::Point>,
+    POut: BezierPathFactory<Point = PathA::Point>,
+{
+    PathMorph::with_correspondence(path_a, path_b, correspondence).path_at(t)
+}
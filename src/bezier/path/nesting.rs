@@ -0,0 +1,186 @@
+use super::super::super::geo::{Coordinate, Coordinate2D};
+use super::arithmetic::PathDirection;
+use super::containment::path_contains_path;
+use super::is_clockwise::path_signed_area;
+use super::path::{BezierPath, BezierPathFactory};
+
+///
+/// A path in a containment hierarchy generated by `path_nesting_tree()`
+///
+/// The nodes at the root of the tree are the paths that are not inside any other path. The children of each node are
+/// the paths directly inside it. Paths at an odd depth in the tree are holes in their parent.
+///
+#[derive(Clone, PartialEq, Debug)]
+pub struct PathNestingNode {
+    /// The index of this path in the list of paths the tree was generated from
+    pub path_idx: usize,
+
+    /// The number of paths that enclose this one
+    pub depth: usize,
+
+    /// The paths directly inside this one
+    pub children: Vec<PathNestingNode>,
+}
+
+impl PathNestingNode {
+    ///
+    /// True if this path is a hole in its parent path
+    ///
+    #[inline]
+    pub fn is_hole(&self) -> bool {
+        self.depth % 2 == 1
+    }
+
+    ///
+    /// Calls a function for this node and every node inside it, parents before children
+    ///
+    pub fn visit<VisitFn: FnMut(&PathNestingNode)>(&self, visit: &mut VisitFn) {
+        visit(self);
+
+        for child in self.children.iter() {
+            child.visit(visit);
+        }
+    }
+}
+
+///
+/// Builds a tree describing which of a set of paths are inside which other paths
+///
+/// This is how the holes in a shape described as a set of subpaths are found: the paths at odd depths in the tree are
+/// holes, and the paths at even depths are outlines (which might be islands inside a hole). Paths are nested according to
+/// their outlines, so the direction of each path is not important.
+///
+pub fn path_nesting_tree<P: BezierPath>(paths: &[P]) -> Vec<PathNestingNode>
+where
+    P::Point: Coordinate + Coordinate2D,
+{
+    // A path can only be inside a path with a larger area, so place the paths in order of size
+    let areas = paths
+        .iter()
+        .map(|path| path_signed_area(path).abs())
+        .collect::<Vec<_>>();
+    let mut order = (0..paths.len()).collect::<Vec<_>>();
+    order.sort_by(|a, b| areas[*b].total_cmp(&areas[*a]));
+
+    // The parent of each path is the smallest path that contains it
+    let mut parents = vec![None; paths.len()];
+
+    for (pos, path_idx) in order.iter().enumerate() {
+        parents[*path_idx] = order[0..pos]
+            .iter()
+            .rev()
+            .find(|possible_parent| {
+                path_contains_path(&paths[**possible_parent], &paths[*path_idx])
+            })
+            .copied();
+    }
+
+    // Generate the tree from the parents (children are kept in the order of the original list)
+    fn build_node(path_idx: usize, depth: usize, parents: &[Option<usize>]) -> PathNestingNode {
+        let children = (0..parents.len())
+            .filter(|child_idx| parents[*child_idx] == Some(path_idx))
+            .map(|child_idx| build_node(child_idx, depth + 1, parents))
+            .collect();
+
+        PathNestingNode {
+            path_idx,
+            depth,
+            children,
+        }
+    }
+
+    (0..paths.len())
+        .filter(|path_idx| parents[*path_idx].is_none())
+        .map(|path_idx| build_node(path_idx, 0, &parents))
+        .collect()
+}
+
+///
+/// Returns a copy of a path that goes in a particular direction, reversing it if necessary
+///
+fn with_direction<P: BezierPath, POut: BezierPathFactory<Point = P::Point>>(
+    path: &P,
+    direction: PathDirection,
+) -> POut
+where
+    P::Point: Coordinate + Coordinate2D,
+{
+    let is_clockwise = path_signed_area(path) <= 0.0;
+
+    if is_clockwise == (direction == PathDirection::Clockwise) {
+        POut::from_path(path)
+    } else {
+        path.reversed()
+    }
+}
+
+///
+/// Divides a set of paths into figures, each made up of an outline and the holes directly inside it
+///
+/// The first path in each figure is the outline, which is made to go clockwise, and the rest are its holes, which are
+/// made to go anticlockwise. Islands inside holes become figures of their own.
+///
+pub fn path_figures<P: BezierPath, POut: BezierPathFactory<Point = P::Point>>(
+    paths: &[P],
+) -> Vec<Vec<POut>>
+where
+    P::Point: Coordinate + Coordinate2D,
+{
+    let mut figures = vec![];
+
+    for root in path_nesting_tree(paths) {
+        root.visit(&mut |node| {
+            if !node.is_hole() {
+                let mut figure = vec![with_direction(
+                    &paths[node.path_idx],
+                    PathDirection::Clockwise,
+                )];
+                figure.extend(node.children.iter().map(|hole| {
+                    with_direction(&paths[hole.path_idx], PathDirection::Anticlockwise)
+                }));
+
+                figures.push(figure);
+            }
+        });
+    }
+
+    figures
+}
+
+///
+/// Makes the outlines in a set of paths all go in one direction, and the holes go in the other
+///
+/// Paths imported from fonts or SVG files often have inconsistent directions, which changes how they're filled with the
+/// non-zero winding rule, and which side of them is the outside. This uses `path_nesting_tree()` to find which paths are
+/// holes: the outlines (which include islands inside holes) are made to go in `outline_direction`, and the holes are
+/// made to go in the opposite direction. The paths are returned in the same order that they were supplied in.
+///
+pub fn normalize_winding<P: BezierPath, POut: BezierPathFactory<Point = P::Point>>(
+    paths: &[P],
+    outline_direction: PathDirection,
+) -> Vec<POut>
+where
+    P::Point: Coordinate + Coordinate2D,
+{
+    let hole_direction = match outline_direction {
+        PathDirection::Clockwise => PathDirection::Anticlockwise,
+        PathDirection::Anticlockwise => PathDirection::Clockwise,
+    };
+
+    let mut is_hole = vec![false; paths.len()];
+    for root in path_nesting_tree(paths) {
+        root.visit(&mut |node| is_hole[node.path_idx] = node.is_hole());
+    }
+
+    paths
+        .iter()
+        .zip(is_hole)
+        .map(|(path, is_hole)| {
+            if is_hole {
+                with_direction(path, hole_direction)
+            } else {
+                with_direction(path, outline_direction)
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,145 @@
+use super::super::super::geo::{Coordinate, Coordinate2D};
+use super::super::curve::{BezierCurve, Curve};
+use super::super::normal::{NormalCurve, Normalize};
+use super::super::offset_adaptive::offset_adaptive;
+use super::arithmetic::{PathDirection, PathLabel};
+use super::graph_path::GraphPath;
+use super::is_clockwise::PathWithIsClockwise;
+use super::path::{BezierPath, BezierPathFactory};
+use super::to_curves::path_to_curves;
+
+use std::iter;
+
+///
+/// Generates the join between two offset curves that meet at a corner of the original path
+///
+/// This is a round join: a bezier curve approximating the arc between the end of one offset curve and the start of the next.
+/// At a concave corner this will loop back on itself, so the result needs to be cleaned up afterwards.
+///
+pub(crate) fn round_join<Point: Coordinate + Coordinate2D + Normalize>(
+    previous: &Curve<Point>,
+    next: &Curve<Point>,
+    distance: f64,
+) -> (Point, Point, Point) {
+    let start = previous.end_point();
+    let end = next.start_point();
+
+    let start_tangent = previous.tangent_at_pos(1.0).to_unit_vector();
+    let end_tangent = next.tangent_at_pos(0.0).to_unit_vector();
+
+    // Standard approximation of a circular arc using a cubic curve with the angle between the two tangents
+    let angle = start_tangent.dot(&end_tangent).clamp(-1.0, 1.0).acos();
+    let control_len = (4.0 / 3.0) * (angle / 4.0).tan() * distance.abs();
+
+    (
+        start + start_tangent * control_len,
+        end - end_tangent * control_len,
+        end,
+    )
+}
+
+///
+/// Offsets a closed path by a fixed distance, generating its outline
+///
+/// Positive distances will grow the path outwards and negative distances will shrink it, regardless of the direction the
+/// path is wound in. Consecutive sections of the path are joined with round joins and the places where the offset edges
+/// cross over each other are removed, so the result is a set of non-overlapping paths. Insetting a path removes the parts
+/// that are narrower than twice the distance, so the result can have several paths where the shape splits apart (for
+/// example, insetting a dumbbell shape by more than half the width of its handle generates the two ends as separate
+/// paths), or none at all if the whole path collapses.
+///
+/// The accuracy value is the maximum error allowed when offsetting the curves, and is passed through to the path
+/// arithmetic used to remove the self-intersections.
+///
+pub fn path_offset<P: BezierPath, POut>(path: &P, distance: f64, accuracy: f64) -> Vec<POut>
+where
+    P::Point: Coordinate + Coordinate2D + Normalize,
+    POut: BezierPathFactory<Point = P::Point>,
+{
+    // Degenerate sections have no normal, so they are skipped
+    let curves = path_to_curves::<_, Curve<_>>(path)
+        .filter(|curve| {
+            let (cp1, cp2) = curve.control_points();
+            let start = curve.start_point();
+
+            !(start.is_near_to(&cp1, accuracy)
+                && start.is_near_to(&cp2, accuracy)
+                && start.is_near_to(&curve.end_point(), accuracy))
+        })
+        .collect::<Vec<_>>();
+
+    if curves.is_empty() {
+        return vec![];
+    }
+
+    // The normal points to the left of a clockwise path, which is the outside
+    let distance_along_normal = if path.is_clockwise() {
+        distance
+    } else {
+        -distance
+    };
+
+    // Offset each section of the path (the adaptive offset removes the loops that form where a section curves more tightly
+    // than the offset distance, which would otherwise need to be removed by the path arithmetic)
+    let offset_sections = curves
+        .iter()
+        .map(|curve| {
+            offset_adaptive(
+                curve,
+                distance_along_normal,
+                distance_along_normal,
+                accuracy,
+            )
+        })
+        .filter(|section| !section.is_empty())
+        .collect::<Vec<_>>();
+
+    if offset_sections.is_empty() {
+        return vec![];
+    }
+
+    // Join up the sections to form a new (probably self-intersecting) path
+    let start_point = offset_sections[0][0].start_point();
+    let mut points = vec![];
+
+    for section_idx in 0..offset_sections.len() {
+        let section = &offset_sections[section_idx];
+        let next_section = &offset_sections[(section_idx + 1) % offset_sections.len()];
+
+        for curve in section.iter() {
+            let (cp1, cp2) = curve.control_points();
+            points.push((cp1, cp2, curve.end_point()));
+        }
+
+        // Add a join if the sections don't meet
+        let last_curve = &section[section.len() - 1];
+        let next_curve = &next_section[0];
+
+        if !last_curve
+            .end_point()
+            .is_near_to(&next_curve.start_point(), accuracy)
+        {
+            points.push(round_join(last_curve, next_curve, distance));
+        }
+    }
+
+    let offset_path = (start_point, points);
+
+    // Remove the parts of the path that loop back on themselves: the main loop has the same direction as the original path, so
+    // we label the offset path with the original direction
+    let mut merged_path = GraphPath::new();
+    merged_path = merged_path.merge(GraphPath::from_merged_paths(iter::once((
+        &offset_path,
+        PathLabel(0, PathDirection::from(path)),
+    ))));
+
+    merged_path.self_collide(accuracy);
+    merged_path.round(accuracy);
+
+    // Only keep the regions wound in the same direction as the original path: reversed loops generated at concave corners and by
+    // insetting past the edge of the path are removed (the ray caster counts regions inside a path as negative crossings)
+    merged_path.set_edge_kinds_by_ray_casting(|path_crossings| path_crossings[0] < 0);
+    merged_path.heal_exterior_gaps();
+
+    merged_path.exterior_paths()
+}
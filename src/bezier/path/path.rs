@@ -1,4 +1,7 @@
-use super::super::super::geo::{BoundingBox, Coord2, Coordinate, Geo};
+use super::super::super::geo::{
+    ApproxEq, BoundingBox, Coord2, Coordinate, Coordinate2D, Geo, QuantisedHash, Transform2D,
+    Transformable,
+};
 use super::super::curve::BezierCurveFactory;
 use super::bounds::{path_bounding_box, path_fast_bounding_box};
 use super::to_curves::path_to_curves;
@@ -130,5 +133,47 @@ impl<Point: Clone + Coordinate> BezierPathFactory for (Point, Vec<(Point, Point,
     }
 }
 
+impl<Point: Coordinate + Coordinate2D> Transformable for (Point, Vec<(Point, Point, Point)>) {
+    fn transform(&self, transform: &Transform2D) -> Self {
+        (
+            transform.transform_point(&self.0),
+            self.1
+                .iter()
+                .map(|(cp1, cp2, end_point)| {
+                    (
+                        transform.transform_point(cp1),
+                        transform.transform_point(cp2),
+                        transform.transform_point(end_point),
+                    )
+                })
+                .collect(),
+        )
+    }
+}
+
+impl<Point: Coordinate> ApproxEq for (Point, Vec<(Point, Point, Point)>) {
+    fn approx_eq(&self, other: &Self, tolerance: f64) -> bool {
+        self.0.approx_eq(&other.0, tolerance)
+            && self.1.len() == other.1.len()
+            && self.1.iter().zip(other.1.iter()).all(|(a, b)| {
+                a.0.approx_eq(&b.0, tolerance)
+                    && a.1.approx_eq(&b.1, tolerance)
+                    && a.2.approx_eq(&b.2, tolerance)
+            })
+    }
+}
+
+impl<Point: Coordinate> QuantisedHash for (Point, Vec<(Point, Point, Point)>) {
+    fn quantised_components(&self, quantum: f64, components: &mut Vec<i64>) {
+        self.0.quantised_components(quantum, components);
+
+        for (cp1, cp2, end_point) in self.1.iter() {
+            cp1.quantised_components(quantum, components);
+            cp2.quantised_components(quantum, components);
+            end_point.quantised_components(quantum, components);
+        }
+    }
+}
+
 /// Basic Bezier path type
 pub type SimpleBezierPath = (Coord2, Vec<(Coord2, Coord2, Coord2)>);
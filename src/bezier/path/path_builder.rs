@@ -1,5 +1,21 @@
+use super::super::super::consts::SMALL_DISTANCE;
+use super::super::super::geo::Coordinate;
 use super::path::{BezierPath, BezierPathFactory};
 
+use std::f64;
+
+///
+/// How a corner in a path should be replaced when the path is built
+///
+#[derive(Clone, Copy, PartialEq, Debug)]
+enum Corner {
+    /// Replace the corner with a circular arc of the specified radius
+    Fillet(f64),
+
+    /// Cut off the corner with a line that starts and ends the specified distance from the corner
+    Chamfer(f64),
+}
+
 ///
 /// Used to build a bezier path
 ///
@@ -9,6 +25,9 @@ pub struct BezierPathBuilder<P: BezierPath> {
 
     /// The points in the path
     points: Vec<(P::Point, P::Point, P::Point)>,
+
+    /// The corners to replace when building the path, as the index of the point where the corner is
+    corners: Vec<(usize, Corner)>,
 }
 
 impl<P: BezierPathFactory> BezierPathBuilder<P> {
@@ -19,6 +38,7 @@ impl<P: BezierPathFactory> BezierPathBuilder<P> {
         Self {
             start_point: start,
             points: vec![],
+            corners: vec![],
         }
     }
 
@@ -26,7 +46,11 @@ impl<P: BezierPathFactory> BezierPathBuilder<P> {
     /// Builds the path for this builder
     ///
     pub fn build(self) -> P {
-        P::from_points(self.start_point, self.points)
+        if self.corners.is_empty() {
+            P::from_points(self.start_point, self.points)
+        } else {
+            self.replace_corners()
+        }
     }
 
     ///
@@ -57,4 +81,204 @@ impl<P: BezierPathFactory> BezierPathBuilder<P> {
 
         self
     }
+
+    ///
+    /// Adds a line to the specified point, and rounds off the corner at that point with a circular arc of the specified radius
+    ///
+    /// This is the same as calling `line_to(point)` followed by `fillet(radius)`.
+    ///
+    pub fn line_to_rounded(self, point: P::Point, radius: f64) -> Self {
+        self.line_to(point).fillet(radius)
+    }
+
+    ///
+    /// Rounds off the corner at the current point with a circular arc of the specified radius
+    ///
+    /// The corner is replaced when the path is built, once the following section is known. If the path is closed by
+    /// returning to the start point, the corner at the start of the path can be rounded by calling this after adding the last
+    /// section. Only corners between two lines are rounded: corners next to a curve are left as they are. The radius is
+    /// reduced if the lines are too short to fit it, so that the arc never takes up more than half of either line.
+    ///
+    pub fn fillet(mut self, radius: f64) -> Self {
+        if !self.points.is_empty() {
+            self.corners
+                .push((self.points.len() - 1, Corner::Fillet(radius)));
+        }
+
+        self
+    }
+
+    ///
+    /// Cuts off the corner at the current point with a straight line starting and ending the specified distance from the corner
+    ///
+    /// This is replaced when the path is built in the same way as `fillet()`.
+    ///
+    pub fn chamfer(mut self, distance: f64) -> Self {
+        if !self.points.is_empty() {
+            self.corners
+                .push((self.points.len() - 1, Corner::Chamfer(distance)));
+        }
+
+        self
+    }
+
+    ///
+    /// Returns true if the section of the path with the specified start and points is a straight line
+    ///
+    fn is_line(start: P::Point, (cp1, cp2, end): (P::Point, P::Point, P::Point)) -> bool {
+        let direction = end - start;
+        let length_squared = direction.dot(&direction);
+
+        if length_squared <= 0.0 {
+            return false;
+        }
+
+        [cp1, cp2].iter().all(|cp| {
+            let offset = *cp - start;
+            let along = offset.dot(&direction) / length_squared;
+
+            (offset - direction * along).magnitude() < SMALL_DISTANCE
+        })
+    }
+
+    ///
+    /// Creates the points for a line between two points
+    ///
+    fn line_points(start: P::Point, end: P::Point) -> (P::Point, P::Point, P::Point) {
+        let distance = end - start;
+
+        (end - (distance * 0.6666), end - (distance * 0.3333), end)
+    }
+
+    ///
+    /// Generates the path with the corners replaced
+    ///
+    fn replace_corners(&self) -> P {
+        let num_points = self.points.len();
+        let start_of = |idx: usize| {
+            if idx == 0 {
+                self.start_point
+            } else {
+                self.points[idx - 1].2
+            }
+        };
+        let is_closed = self.points[num_points - 1]
+            .2
+            .is_near_to(&self.start_point, SMALL_DISTANCE);
+
+        // For each point, the replacement for the corner as (end of the incoming line, points of the replacement curve)
+        let mut replacements = vec![None; num_points];
+
+        for (point_idx, corner) in self.corners.iter() {
+            let point_idx = *point_idx;
+            let next_idx = if point_idx + 1 < num_points {
+                point_idx + 1
+            } else if is_closed {
+                0
+            } else {
+                continue;
+            };
+
+            // Corners are only replaced between lines
+            let incoming = self.points[point_idx];
+            let outgoing = self.points[next_idx];
+
+            if !Self::is_line(start_of(point_idx), incoming)
+                || !Self::is_line(start_of(next_idx), outgoing)
+            {
+                continue;
+            }
+
+            // Work out the directions of the lines away from the corner
+            let vertex = incoming.2;
+            let to_previous = start_of(point_idx) - vertex;
+            let to_next = outgoing.2 - vertex;
+            let (previous_len, next_len) = (to_previous.magnitude(), to_next.magnitude());
+            let (dir_previous, dir_next) = (
+                to_previous * (1.0 / previous_len),
+                to_next * (1.0 / next_len),
+            );
+
+            // The angle between the two lines
+            let cos_angle = dir_previous.dot(&dir_next).clamp(-1.0, 1.0);
+            if cos_angle <= -1.0 + 1e-9 || cos_angle >= 1.0 - 1e-9 {
+                // Straight line or the path doubles back on itself
+                continue;
+            }
+
+            let half_angle_tan = ((1.0 - cos_angle) / (1.0 + cos_angle)).sqrt();
+            let max_distance = previous_len.min(next_len) * 0.5;
+
+            let replacement = match corner {
+                Corner::Fillet(radius) => {
+                    // The distance from the corner to where the arc meets the lines
+                    let distance = (radius / half_angle_tan).min(max_distance);
+                    let radius = distance * half_angle_tan;
+
+                    // Control points for an arc that turns through the angle between the lines
+                    let turn_angle = f64::consts::PI - cos_angle.acos();
+                    let handle_len = (4.0 / 3.0) * (turn_angle / 4.0).tan() * radius;
+
+                    let arc_start = vertex + dir_previous * distance;
+                    let arc_end = vertex + dir_next * distance;
+
+                    (
+                        arc_start,
+                        (
+                            arc_start - dir_previous * handle_len,
+                            arc_end - dir_next * handle_len,
+                            arc_end,
+                        ),
+                    )
+                }
+
+                Corner::Chamfer(distance) => {
+                    let distance = distance.min(max_distance);
+                    let cut_start = vertex + dir_previous * distance;
+                    let cut_end = vertex + dir_next * distance;
+
+                    (cut_start, Self::line_points(cut_start, cut_end))
+                }
+            };
+
+            replacements[point_idx] = Some(replacement);
+        }
+
+        // If the corner at the start of a closed path was replaced, the path starts at the end of the replacement
+        let (start_point, start_replaced) = match (is_closed, &replacements[num_points - 1]) {
+            (true, Some((_, (_, _, replacement_end)))) => (*replacement_end, true),
+            _ => (self.start_point, false),
+        };
+
+        // Rebuild the path with the lines shortened to meet the replacements
+        let mut points = vec![];
+        let mut last_point = start_point;
+
+        for (point_idx, section) in self.points.iter().enumerate() {
+            let replaced_start = if point_idx == 0 {
+                start_replaced
+            } else {
+                replacements[point_idx - 1].is_some()
+            };
+            let replacement = &replacements[point_idx];
+
+            if replaced_start || replacement.is_some() {
+                // A line that has been shortened
+                let line_end = replacement
+                    .map(|(line_end, _)| line_end)
+                    .unwrap_or(section.2);
+                points.push(Self::line_points(last_point, line_end));
+            } else {
+                points.push(*section);
+            }
+
+            if let Some((_, replacement_points)) = replacement {
+                points.push(*replacement_points);
+            }
+
+            last_point = points[points.len() - 1].2;
+        }
+
+        P::from_points(start_point, points)
+    }
 }
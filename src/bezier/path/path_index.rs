@@ -0,0 +1,159 @@
+use super::super::super::geo::{
+    BoundingBox, BoundingVolumeHierarchy, BvhItemId, Coordinate, Coordinate2D, Geo, HasBoundingBox,
+};
+use super::super::super::line::Line;
+use super::super::curve::{BezierCurve, Curve};
+use super::super::intersection::curve_intersects_ray;
+use super::super::nearest::nearest_point_on_curve;
+use super::path::BezierPath;
+use super::to_curves::path_to_curves;
+
+use std::cmp::Ordering;
+
+///
+/// A section of a path stored in a path index
+///
+#[derive(Clone, Debug)]
+pub struct IndexedPathCurve<Point: Coordinate> {
+    /// The ID of the path that this curve is from
+    pub path_id: usize,
+
+    /// The index of the section of the path that this curve represents
+    pub section: usize,
+
+    /// The curve itself
+    pub curve: Curve<Point>,
+}
+
+impl<Point: Coordinate> Geo for IndexedPathCurve<Point> {
+    type Point = Point;
+}
+
+impl<Point: Coordinate> HasBoundingBox for IndexedPathCurve<Point> {
+    #[inline]
+    fn get_bounding_box<Bounds: BoundingBox<Point = Self::Point>>(&self) -> Bounds {
+        self.curve.bounding_box()
+    }
+}
+
+///
+/// A spatial index of the curves in a set of paths
+///
+pub type PathIndex<Point> = BoundingVolumeHierarchy<IndexedPathCurve<Point>>;
+
+///
+/// Adds the curves from a path to a path index, returning the IDs of the new items
+///
+/// The path ID can be any value: it's returned alongside any matches against the curves in the path. The curves for a path can
+/// be removed from the index later on by passing the returned IDs to `remove()`.
+///
+pub fn add_path_to_index<P: BezierPath>(
+    index: &mut PathIndex<P::Point>,
+    path_id: usize,
+    path: &P,
+) -> Vec<BvhItemId> {
+    path_to_curves::<_, Curve<_>>(path)
+        .enumerate()
+        .map(|(section, curve)| {
+            index.insert(IndexedPathCurve {
+                path_id,
+                section,
+                curve,
+            })
+        })
+        .collect()
+}
+
+///
+/// Creates a spatial index of the curves in a set of paths (the paths are given IDs according to their position in the input)
+///
+pub fn path_index<'a, P, PathIter>(paths: PathIter) -> PathIndex<P::Point>
+where
+    P: 'a + BezierPath,
+    PathIter: IntoIterator<Item = &'a P>,
+{
+    let mut index = PathIndex::new();
+
+    for (path_id, path) in paths.into_iter().enumerate() {
+        add_path_to_index(&mut index, path_id, path);
+    }
+
+    index
+}
+
+///
+/// Finds all of the places where a ray crosses the paths stored in an index
+///
+/// The return value is a list of `(path_id, section, curve_t, point)` values, ordered by their position along the ray. As with
+/// the other ray functions, the ray is the infinite line passing through the two points of the line that's passed in, so this
+/// will also return intersections that are 'behind' the start of the line. Only the curves whose bounding boxes are crossed by
+/// the ray are checked, so this is much faster than checking every path when there are many paths in the index.
+///
+/// A ray that crosses a path at the point where two sections join may produce a match in both sections.
+///
+pub fn ray_cast_paths<Point, L>(
+    index: &PathIndex<Point>,
+    ray: &L,
+) -> Vec<(usize, usize, f64, Point)>
+where
+    Point: Coordinate + Coordinate2D,
+    L: Line<Point = Point>,
+{
+    let (from, to) = ray.points();
+
+    let mut hits = index
+        .crossed_by_ray(&from, &to)
+        .flat_map(|(_id, indexed_curve)| {
+            curve_intersects_ray(&indexed_curve.curve, ray)
+                .into_iter()
+                .map(move |(t, s, point)| {
+                    (s, (indexed_curve.path_id, indexed_curve.section, t, point))
+                })
+        })
+        .collect::<Vec<_>>();
+
+    hits.sort_by(|(s1, _), (s2, _)| s1.partial_cmp(s2).unwrap_or(Ordering::Equal));
+
+    hits.into_iter().map(|(_s, hit)| hit).collect()
+}
+
+///
+/// Finds the curves in an index that are nearest to a point, returning up to `max_curves` of them in order of distance
+///
+/// The return value is a list of `(path_id, section, curve_t, nearest_point, distance)` values, where `nearest_point` is
+/// the closest point on the curve to the target point, found at `curve_t`. Only the curves whose bounding boxes might
+/// contain a nearer point than the ones already found are checked, so this is much faster than measuring the distance to
+/// every curve when there are many paths in the index. This is useful for snapping to the nearest path, where several
+/// candidates can be offered.
+///
+/// A point nearest to the place where two sections of a path join may be reported for both sections.
+///
+pub fn nearest_curves<Point>(
+    index: &PathIndex<Point>,
+    point: &Point,
+    max_curves: usize,
+) -> Vec<(usize, usize, f64, Point, f64)>
+where
+    Point: Coordinate + Coordinate2D,
+{
+    let target = *point;
+
+    index
+        .nearest_to_point(point, move |indexed_curve| {
+            let (_t, nearest) = nearest_point_on_curve(&indexed_curve.curve, &target);
+            nearest.distance_to(&target)
+        })
+        .take(max_curves)
+        .map(|(_id, indexed_curve, distance)| {
+            let (t, nearest) = nearest_point_on_curve(&indexed_curve.curve, point);
+
+            (
+                indexed_curve.path_id,
+                indexed_curve.section,
+                t,
+                nearest,
+                distance,
+            )
+        })
+        .collect()
+}
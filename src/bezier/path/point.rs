@@ -1,5 +1,6 @@
 use super::super::super::geo::{Coordinate, Coordinate2D, Geo};
 use super::super::curve::{BezierCurve, Curve};
+use super::super::nearest::distance_to_curve;
 use super::super::normal::NormalCurve;
 use super::graph_path::GraphEdgeRef;
 use super::path::BezierPath;
@@ -196,3 +197,47 @@ where
         total_direction != 0
     }
 }
+
+///
+/// Returns true if a point is no further than `distance` outside of a bounding box
+///
+#[inline]
+fn is_near_bounds<Point: Coordinate2D>(
+    (min_bounds, max_bounds): &(Point, Point),
+    point: &Point,
+    distance: f64,
+) -> bool {
+    min_bounds.x() - distance <= point.x()
+        && max_bounds.x() + distance >= point.x()
+        && min_bounds.y() - distance <= point.y()
+        && max_bounds.y() + distance >= point.y()
+}
+
+///
+/// Returns true if a point is within half of `stroke_width` of the outline of a bezier path
+///
+/// This is the test to use for clicking on a stroked path: `path_contains_point()` tests against the interior of the path
+/// instead. Sections of the path whose bounding boxes are too far away from the point are skipped without finding the
+/// nearest point on them.
+///
+pub fn path_stroke_contains_point<P: BezierPath>(
+    path: &P,
+    point: &P::Point,
+    stroke_width: f64,
+) -> bool
+where
+    P::Point: Coordinate2D,
+{
+    let half_width = stroke_width.abs() * 0.5;
+
+    // Points outside the bounds of the stroke can't be within it
+    if !is_near_bounds(&path.fast_bounding_box(), point, half_width) {
+        return false;
+    }
+
+    path_to_curves::<_, Curve<_>>(path).any(|curve| {
+        // Only find the nearest point on curves whose bounds are close enough to the point
+        is_near_bounds(&curve.fast_bounding_box(), point, half_width)
+            && distance_to_curve(&curve, point) <= half_width
+    })
+}
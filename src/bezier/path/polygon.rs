@@ -0,0 +1,138 @@
+use super::super::super::geo::{Coordinate, Coordinate2D};
+use super::flatten::path_flatten;
+use super::nesting::path_figures;
+use super::path::{BezierPath, BezierPathFactory};
+use super::path_builder::BezierPathBuilder;
+
+/// A path in one of the figures found by `path_figures()`
+type FigurePath<Point> = (Point, Vec<(Point, Point, Point)>);
+
+///
+/// A polygon made up of an exterior ring and any number of holes
+///
+/// Each ring is a list of vertices, with an edge between each vertex and the next one and another edge from the last
+/// vertex back to the first (the first vertex is not repeated at the end). The exterior ring goes anticlockwise and the
+/// holes go clockwise, which is the convention used by GeoJSON and most geometry libraries: `Polygon::new()` reverses any
+/// rings that go the wrong way.
+///
+#[derive(Clone, PartialEq, Debug)]
+pub struct Polygon<Point> {
+    /// The vertices of the outside edge of the polygon, going anticlockwise
+    pub exterior: Vec<Point>,
+
+    /// The vertices of each of the holes in the polygon, going clockwise
+    pub holes: Vec<Vec<Point>>,
+}
+
+///
+/// Returns the signed area of a ring of points (positive if the ring goes anticlockwise)
+///
+fn ring_signed_area<Point: Coordinate2D>(ring: &[Point]) -> f64 {
+    let twice_area = (0..ring.len())
+        .map(|idx| {
+            let (p1, p2) = (&ring[idx], &ring[(idx + 1) % ring.len()]);
+            p1.x() * p2.y() - p2.x() * p1.y()
+        })
+        .sum::<f64>();
+
+    twice_area * 0.5
+}
+
+///
+/// Makes a ring go anticlockwise or clockwise, reversing it if necessary
+///
+fn orient_ring<Point: Coordinate2D>(mut ring: Vec<Point>, anticlockwise: bool) -> Vec<Point> {
+    if (ring_signed_area(&ring) > 0.0) != anticlockwise {
+        ring[1..].reverse();
+    }
+
+    ring
+}
+
+impl<Point: Coordinate + Coordinate2D> Polygon<Point> {
+    ///
+    /// Creates a polygon from an exterior ring and a list of holes, making the rings go in the standard directions
+    ///
+    pub fn new(exterior: Vec<Point>, holes: Vec<Vec<Point>>) -> Polygon<Point> {
+        Polygon {
+            exterior: orient_ring(exterior, true),
+            holes: holes
+                .into_iter()
+                .map(|hole| orient_ring(hole, false))
+                .collect(),
+        }
+    }
+
+    ///
+    /// Returns the rings in this polygon: the exterior followed by the holes
+    ///
+    pub fn rings(&self) -> impl '_ + Iterator<Item = &[Point]> {
+        std::iter::once(&self.exterior[..]).chain(self.holes.iter().map(|hole| &hole[..]))
+    }
+
+    ///
+    /// Returns the area of this polygon (the area of the exterior, less the area of the holes)
+    ///
+    pub fn area(&self) -> f64 {
+        self.rings().map(ring_signed_area).sum()
+    }
+}
+
+///
+/// Approximates a set of paths as a set of polygons with holes
+///
+/// The paths are divided into figures using `path_figures()`, so each polygon is an outline along with the holes directly
+/// inside it, and islands inside holes become polygons of their own. The direction of the original paths doesn't matter.
+/// No point on the edges of the polygons is further than `tolerance` from the original path.
+///
+pub fn path_to_polygon<P: BezierPath>(paths: &[P], tolerance: f64) -> Vec<Polygon<P::Point>>
+where
+    P::Point: Coordinate + Coordinate2D,
+{
+    // Flatten a path into a ring, removing the repeated points
+    let to_ring = |path: &FigurePath<P::Point>| {
+        let mut ring = path_flatten(path, tolerance);
+        ring.dedup();
+        while ring.len() > 1 && ring[0] == ring[ring.len() - 1] {
+            ring.pop();
+        }
+
+        ring
+    };
+
+    path_figures::<_, FigurePath<P::Point>>(paths)
+        .into_iter()
+        .map(|figure| {
+            let mut rings = figure.iter().map(to_ring);
+            let exterior = rings.next().unwrap_or_default();
+
+            Polygon::new(exterior, rings.filter(|hole| hole.len() >= 3).collect())
+        })
+        .filter(|polygon| polygon.exterior.len() >= 3)
+        .collect()
+}
+
+///
+/// Converts a polygon to a set of paths: one for the exterior, followed by one for each of the holes
+///
+/// The edges of the polygon become straight lines in the paths, and the paths go in the same direction as the rings in
+/// the polygon (so the holes will be subtracted from the exterior with either the even-odd or the non-zero fill rule).
+///
+pub fn polygon_to_path<POut: BezierPathFactory>(polygon: &Polygon<POut::Point>) -> Vec<POut>
+where
+    POut::Point: Coordinate + Coordinate2D,
+{
+    polygon
+        .rings()
+        .filter(|ring| !ring.is_empty())
+        .map(|ring| {
+            ring[1..]
+                .iter()
+                .chain(std::iter::once(&ring[0]))
+                .fold(BezierPathBuilder::<POut>::start(ring[0]), |path, point| {
+                    path.line_to(*point)
+                })
+                .build()
+        })
+        .collect()
+}
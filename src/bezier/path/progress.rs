@@ -0,0 +1,136 @@
+use std::error::Error;
+use std::fmt;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+///
+/// The phases of a long-running operation, as reported to a `ProgressMonitor`
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum OperationPhase {
+    /// Finding where the paths intersect
+    Colliding,
+
+    /// Deciding which edges are on the outside of the result
+    ClassifyingEdges,
+
+    /// Building the resulting paths
+    BuildingPaths,
+
+    /// Casting rays to find the outline of the area being filled
+    TracingOutline,
+
+    /// Fitting curves to the outline that was found
+    FittingCurves,
+
+    /// The operation has finished
+    Finished,
+}
+
+///
+/// Trait implemented by types that monitor the progress of a long-running operation, and that can cancel it
+///
+/// `progress()` is called between the phases of the operation, and between the steps of phases that can take a long time.
+/// The operation is cancelled if it returns `false`. Closures of the form `Fn(OperationPhase, f64) -> bool` implement
+/// this trait, as does `CancellationToken`.
+///
+pub trait ProgressMonitor {
+    ///
+    /// Reports the current phase of the operation and the approximate proportion of the whole operation that is complete
+    /// (from 0 to 1), returning `false` if the operation should be cancelled
+    ///
+    fn progress(&self, phase: OperationPhase, amount_complete: f64) -> bool;
+}
+
+impl<TFn> ProgressMonitor for TFn
+where
+    TFn: Fn(OperationPhase, f64) -> bool,
+{
+    #[inline]
+    fn progress(&self, phase: OperationPhase, amount_complete: f64) -> bool {
+        (self)(phase, amount_complete)
+    }
+}
+
+///
+/// A progress monitor that cancels an operation once `cancel()` has been called
+///
+/// Cancellation tokens can be cloned and sent to other threads: cancelling any of the clones cancels the operation. This
+/// is useful for stopping an operation running on a background thread from a UI thread.
+///
+#[derive(Clone, Debug, Default)]
+pub struct CancellationToken {
+    cancelled: Arc<AtomicBool>,
+}
+
+impl CancellationToken {
+    ///
+    /// Creates a new cancellation token that has not been cancelled
+    ///
+    pub fn new() -> CancellationToken {
+        CancellationToken::default()
+    }
+
+    ///
+    /// Cancels any operations using this token (or any of its clones)
+    ///
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    ///
+    /// True if this token has been cancelled
+    ///
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+}
+
+impl ProgressMonitor for CancellationToken {
+    #[inline]
+    fn progress(&self, _phase: OperationPhase, _amount_complete: f64) -> bool {
+        !self.is_cancelled()
+    }
+}
+
+///
+/// A progress monitor that never cancels the operation
+///
+pub(crate) struct NoProgressMonitor;
+
+impl ProgressMonitor for NoProgressMonitor {
+    #[inline]
+    fn progress(&self, _phase: OperationPhase, _amount_complete: f64) -> bool {
+        true
+    }
+}
+
+///
+/// Error returned when an operation is cancelled by its `ProgressMonitor`
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct OperationCancelled;
+
+impl fmt::Display for OperationCancelled {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "the operation was cancelled")
+    }
+}
+
+impl Error for OperationCancelled {}
+
+///
+/// Reports progress to a monitor, returning an error if the monitor has cancelled the operation
+///
+#[inline]
+pub(crate) fn report_progress<Monitor: ProgressMonitor + ?Sized>(
+    monitor: &Monitor,
+    phase: OperationPhase,
+    amount_complete: f64,
+) -> Result<(), OperationCancelled> {
+    if monitor.progress(phase, amount_complete) {
+        Ok(())
+    } else {
+        Err(OperationCancelled)
+    }
+}
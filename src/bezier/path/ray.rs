@@ -1,4 +1,6 @@
 use super::super::super::consts::{CLOSE_DISTANCE, SMALL_DISTANCE};
+#[cfg(feature = "robust_predicates")]
+use super::super::super::geo::orient_2d;
 use super::super::super::geo::{Coordinate, Coordinate2D};
 use super::super::super::line::{Line, Line2D};
 use super::super::curve::BezierCurve;
@@ -9,6 +11,9 @@ use super::graph_path::{GraphEdgeRef, GraphRayCollision};
 use smallvec::{smallvec, SmallVec};
 use std::cmp::Ordering;
 
+/// Distances to a ray that are smaller than this fraction of the size of the terms used to calculate them are treated as 0
+const RAY_SNAP_RELATIVE_DISTANCE: f64 = 1e-10;
+
 ///
 /// Represents a path that can be accessed by the ray collision algorithm
 ///
@@ -95,10 +100,70 @@ enum RayCanIntersect {
     CrossesRay,
 }
 
+///
+/// Returns the side of a ray that a point is on (as -1 or 1), given the distance from the point to the ray
+///
+#[cfg(not(feature = "robust_predicates"))]
+#[inline]
+fn side_of_ray<Point: Coordinate + Coordinate2D>(
+    _ray: &(Point, Point),
+    _point: &Point,
+    distance: f64,
+) -> f64 {
+    distance.signum()
+}
+
+///
+/// Returns the side of a ray that a point is on (as -1 or 1), using a robust orientation test
+///
+/// Points on the ray are treated as being on the positive side, as for the non-robust version, so the edges either side
+/// of a vertex that the ray passes through agree about which side it's on.
+///
+#[cfg(feature = "robust_predicates")]
+#[inline]
+fn side_of_ray<Point: Coordinate + Coordinate2D>(
+    ray: &(Point, Point),
+    point: &Point,
+    distance: f64,
+) -> f64 {
+    if distance == 0.0 {
+        1.0
+    } else {
+        orient_2d(&ray.0, &ray.1, point).signum()
+    }
+}
+
+///
+/// Returns the distance from a point to a ray, given the coefficients of the ray
+///
+/// Distances that are within the rounding error of the calculation are treated as being exactly on the ray, so rounding
+/// errors can't put a vertex that the ray passes through on different sides for the edges either side of it. The rounding
+/// error depends on the size of the terms being added together, so this works the same way for paths of any scale.
+///
+#[inline]
+fn distance_to_ray<Point: Coordinate + Coordinate2D>(
+    point: &Point,
+    (a, b, c): (f64, f64, f64),
+) -> f64 {
+    let (ax, by) = (a * point.x(), b * point.y());
+    let distance = ax + by + c;
+    let scale = ax.abs().max(by.abs()).max(c.abs());
+
+    if distance.abs() <= scale * RAY_SNAP_RELATIVE_DISTANCE {
+        0.0
+    } else {
+        distance
+    }
+}
+
 ///
 /// Given the coefficients of a ray, returns whether or not an edge can intersect it
 ///
-fn ray_can_intersect<Edge: BezierCurve>(edge: &Edge, (a, b, c): (f64, f64, f64)) -> RayCanIntersect
+fn ray_can_intersect<Edge: BezierCurve>(
+    edge: &Edge,
+    ray: &(Edge::Point, Edge::Point),
+    (a, b, c): (f64, f64, f64),
+) -> RayCanIntersect
 where
     Edge::Point: Coordinate + Coordinate2D,
 {
@@ -108,16 +173,16 @@ where
     let (cp1, cp2) = edge.control_points();
 
     // Calculate distances to each of the points
-    let start_distance = a * start_point.x() + b * start_point.y() + c;
-    let cp1_distance = a * cp1.x() + b * cp1.y() + c;
-    let cp2_distance = a * cp2.x() + b * cp2.y() + c;
-    let end_distance = a * end_point.x() + b * end_point.y() + c;
+    let start_distance = distance_to_ray(&start_point, (a, b, c));
+    let cp1_distance = distance_to_ray(&cp1, (a, b, c));
+    let cp2_distance = distance_to_ray(&cp2, (a, b, c));
+    let end_distance = distance_to_ray(&end_point, (a, b, c));
 
     // The sign of the distances indicate which side they're on
-    let side = start_distance.signum()
-        + end_distance.signum()
-        + cp1_distance.signum()
-        + cp2_distance.signum();
+    let side = side_of_ray(ray, &start_point, start_distance)
+        + side_of_ray(ray, &end_point, end_distance)
+        + side_of_ray(ray, &cp1, cp1_distance)
+        + side_of_ray(ray, &cp2, cp2_distance);
 
     if start_distance.abs() < SMALL_DISTANCE
         && end_distance.abs() < SMALL_DISTANCE
@@ -224,6 +289,7 @@ where
 
     // The coefficients are used to determine if a particular edge can collide with the curve and if it's collinear or not
     let ray_coeffs = ray.coefficients();
+    let ray_points = ray.points();
 
     for point_idx in 0..(path.num_points()) {
         for edge_idx in 0..(path.num_edges(point_idx)) {
@@ -234,7 +300,7 @@ where
             };
             let edge = path.get_edge(edge_ref);
 
-            let intersection_type = ray_can_intersect(&edge, ray_coeffs);
+            let intersection_type = ray_can_intersect(&edge, &ray_points, ray_coeffs);
 
             match intersection_type {
                 RayCanIntersect::CrossesRay => {
@@ -778,6 +844,25 @@ mod test {
     use crate::bezier::path::GraphPath;
     use crate::Coord2;
 
+    #[test]
+    fn distance_to_ray_keeps_small_distances_for_small_paths() {
+        let ray = (Coord2(0.0, 0.0), Coord2(1e-8, 0.0));
+        let distance = distance_to_ray(&Coord2(0.5e-8, 5e-10), ray.coefficients());
+
+        assert!((distance.abs() - 5e-10).abs() < 1e-20);
+    }
+
+    #[test]
+    fn distance_to_ray_snaps_rounding_errors() {
+        for scale in [1e-6, 1.0, 1e6, 1e9, 1e12] {
+            let ray = (Coord2(0.1, 0.2) * scale, Coord2(0.7, 1.3) * scale);
+            let point = ray.0 + (ray.1 - ray.0) * 0.37;
+            let (a, b, c) = ray.coefficients();
+
+            assert!(distance_to_ray(&point, (a, b, c)) == 0.0);
+        }
+    }
+
     #[test]
     fn raw_donut_collisions() {
         let donut = donut();
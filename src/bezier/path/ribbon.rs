@@ -0,0 +1,212 @@
+use super::super::super::geo::{Coordinate, Coordinate2D};
+use super::super::curve::{BezierCurve, BezierCurveFactory, Curve};
+use super::super::intersection::{curve_intersects_curve_clip, curve_intersects_ray};
+use super::super::normal::{NormalCurve, Normalize};
+use super::super::offset_adaptive::offset_adaptive;
+use super::measure::PathMeasure;
+use super::offset::round_join;
+use super::path::{BezierPath, BezierPathFactory};
+use super::to_curves::path_to_curves;
+
+///
+/// The rails and rungs generated by `path_ribbon()`
+///
+#[derive(Clone, Debug)]
+pub struct PathRibbon<P> {
+    /// The rail on the left-hand side of the path (in the direction of its normal)
+    pub left_rail: P,
+
+    /// The rail on the right-hand side of the path
+    pub right_rail: P,
+
+    /// The rungs joining the two rails, in order along the path, each running from the left rail to the right rail
+    pub rungs: Vec<P>,
+}
+
+///
+/// Generates one of the rails of a ribbon by offsetting the curves of a path
+///
+/// `offset` is the distance along the normal of the path. Where the offset sections don't meet at a corner in the path,
+/// they are joined with a round join on the outside of the corner, and trimmed back to where they cross on the inside.
+///
+fn ribbon_rail<Point>(curves: &[Curve<Point>], offset: f64, max_error: f64) -> Vec<Curve<Point>>
+where
+    Point: Coordinate + Coordinate2D + Normalize,
+{
+    let mut rail: Vec<Curve<Point>> = vec![];
+
+    for curve in curves.iter() {
+        let mut section = offset_adaptive(curve, offset, offset, max_error);
+        if section.is_empty() {
+            continue;
+        }
+
+        if let Some(last_curve) = rail.last().copied() {
+            let next_curve = section[0];
+
+            if !last_curve
+                .end_point()
+                .is_near_to(&next_curve.start_point(), max_error)
+            {
+                // The rail is on the outside of the corner if the path turns away from it
+                let last_tangent = last_curve.tangent_at_pos(1.0);
+                let next_tangent = next_curve.tangent_at_pos(0.0);
+                let turn =
+                    last_tangent.x() * next_tangent.y() - last_tangent.y() * next_tangent.x();
+
+                let crossing = if turn * offset < 0.0 {
+                    None
+                } else {
+                    curve_intersects_curve_clip(&last_curve, &next_curve, max_error)
+                        .into_iter()
+                        .max_by(|(t1, _), (t2, _)| t1.total_cmp(t2))
+                };
+
+                if let Some((last_t, next_t)) = crossing {
+                    // Trim both curves back to the point where they cross
+                    let rail_len = rail.len();
+                    rail[rail_len - 1] = Curve::from_curve(&last_curve.section(0.0, last_t));
+                    section[0] = Curve::from_curve(&next_curve.section(next_t, 1.0));
+                } else {
+                    let (cp1, cp2, end_point) = round_join(&last_curve, &next_curve, offset);
+                    rail.push(Curve::from_points(
+                        last_curve.end_point(),
+                        (cp1, cp2),
+                        end_point,
+                    ));
+                }
+            }
+        }
+
+        rail.extend(section);
+    }
+
+    rail
+}
+
+///
+/// Converts a list of curves into a path, which is just the specified start point if there are no curves
+///
+fn path_from_curves<Point: Coordinate, POut: BezierPathFactory<Point = Point>>(
+    start_point: Point,
+    curves: &[Curve<Point>],
+) -> POut {
+    let start_point = curves
+        .first()
+        .map(|curve| curve.start_point())
+        .unwrap_or(start_point);
+
+    POut::from_points(
+        start_point,
+        curves.iter().map(|curve| {
+            let (cp1, cp2) = curve.control_points();
+            (cp1, cp2, curve.end_point())
+        }),
+    )
+}
+
+///
+/// Finds the point where the line through a point on the path along its normal crosses a rail, choosing the crossing
+/// nearest to where the rail should be
+///
+fn rung_end_point<Point>(
+    rail: &[Curve<Point>],
+    position: Point,
+    normal: Point,
+    offset: f64,
+) -> Point
+where
+    Point: Coordinate + Coordinate2D,
+{
+    let expected = position + normal * offset;
+    let rung_line = (position, expected);
+
+    rail.iter()
+        .flat_map(|curve| curve_intersects_ray(curve, &rung_line))
+        .map(|(_curve_t, _line_t, point)| point)
+        .min_by(|point1, point2| {
+            point1
+                .distance_to(&expected)
+                .total_cmp(&point2.distance_to(&expected))
+        })
+        .unwrap_or(expected)
+}
+
+///
+/// Generates a 'ribbon' along a path: two rails offset `half_width` either side of it, and optionally a series of rungs
+/// joining the rails every `rung_spacing` units along the path
+///
+/// This is useful for drawing things like railway tracks, ladders and zips. The path is treated as an open line (use
+/// `path_offset()` to generate the outline of a closed shape), and the rails have round joins on the outside of any corners
+/// in the path. The first rung is at the start of the path, and the rungs are straight lines along the normal of the
+/// path that end exactly on the rails, so they can be drawn without gaps or overlaps. If `rung_spacing` is not a positive,
+/// finite distance, the only rung is the one at the start. Offsets and distances along the path are calculated to within
+/// `max_error`.
+///
+pub fn path_ribbon<P: BezierPath, POut>(
+    path: &P,
+    half_width: f64,
+    rung_spacing: Option<f64>,
+    max_error: f64,
+) -> PathRibbon<POut>
+where
+    P::Point: Coordinate + Coordinate2D + Normalize,
+    POut: BezierPathFactory<Point = P::Point>,
+{
+    // Degenerate sections have no normal, so they are skipped
+    let curves = path_to_curves::<_, Curve<_>>(path)
+        .filter(|curve| {
+            let (cp1, cp2) = curve.control_points();
+            let start = curve.start_point();
+
+            !(start.is_near_to(&cp1, max_error)
+                && start.is_near_to(&cp2, max_error)
+                && start.is_near_to(&curve.end_point(), max_error))
+        })
+        .collect::<Vec<_>>();
+
+    let left_rail = ribbon_rail(&curves, half_width, max_error);
+    let right_rail = ribbon_rail(&curves, -half_width, max_error);
+
+    // Place the rungs along the path, then extend them to meet the rails
+    let mut rungs = vec![];
+
+    if let Some(rung_spacing) = rung_spacing {
+        // A spacing that isn't a positive distance can't separate the rungs, so only the rung at the start is generated
+        let is_valid_spacing = rung_spacing.is_finite() && rung_spacing > 0.0;
+        let measure = PathMeasure::new(path, max_error);
+        let num_rungs = if left_rail.is_empty() {
+            0
+        } else if is_valid_spacing {
+            ((measure.length() + max_error) / rung_spacing).floor() as usize + 1
+        } else {
+            1
+        };
+        let rung_spacing = if is_valid_spacing { rung_spacing } else { 0.0 };
+
+        for rung_idx in 0..num_rungs {
+            if let Some(frame) = measure.frame_at_length((rung_idx as f64) * rung_spacing) {
+                let left = rung_end_point(&left_rail, frame.position, frame.normal, half_width);
+                let right = rung_end_point(&right_rail, frame.position, frame.normal, -half_width);
+                let rung = Curve::from_points(
+                    left,
+                    (
+                        left * (2.0 / 3.0) + right * (1.0 / 3.0),
+                        left * (1.0 / 3.0) + right * (2.0 / 3.0),
+                    ),
+                    right,
+                );
+
+                rungs.push(path_from_curves(left, &[rung]));
+            }
+        }
+    }
+
+    let start_point = path.start_point();
+
+    PathRibbon {
+        left_rail: path_from_curves(start_point, &left_rail),
+        right_rail: path_from_curves(start_point, &right_rail),
+        rungs,
+    }
+}
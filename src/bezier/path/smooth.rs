@@ -0,0 +1,319 @@
+use super::super::super::consts::SMALL_DISTANCE;
+use super::super::super::geo::{Coordinate, Coordinate2D};
+use super::super::subdivide::subdivide4;
+use super::path::{BezierPath, BezierPathFactory};
+
+/// The largest that the sum of the basis functions for the control points of a cubic curve can be (3t(1-t) at t = 0.5)
+const MAX_CONTROL_POINT_WEIGHT: f64 = 0.75;
+
+/// Maximum number of times the curves at a join are subdivided when trying to smooth it within the tolerance
+const MAX_JOIN_SUBDIVISIONS: usize = 8;
+
+/// Number of passes to make over the path when matching curvature (each join affects the curvature at its neighbours)
+const CURVATURE_PASSES: usize = 16;
+
+///
+/// The kind of continuity to enforce where the sections of a path meet
+///
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Continuity {
+    /// The sections meet with the same tangent direction (no visible corners)
+    G1,
+
+    /// The sections meet with the same tangent direction and curvature (no visible change in how sharply the path bends)
+    G2,
+}
+
+///
+/// A join between two curves in a path being smoothed
+///
+#[derive(Clone, Copy, Debug)]
+struct Join {
+    /// The index of the curve ending at this join
+    incoming: usize,
+
+    /// The index of the curve starting at this join
+    outgoing: usize,
+}
+
+///
+/// The 2D cross product of two vectors
+///
+#[inline]
+fn cross<Point: Coordinate + Coordinate2D>(a: &Point, b: &Point) -> f64 {
+    a.x() * b.y() - a.y() * b.x()
+}
+
+///
+/// The joins between the curves in a path (including the join between the end and the start if the path is closed)
+///
+fn joins<Point: Coordinate>(curves: &[[Point; 4]]) -> Vec<Join> {
+    let mut joins = (1..curves.len())
+        .map(|idx| Join {
+            incoming: idx - 1,
+            outgoing: idx,
+        })
+        .collect::<Vec<_>>();
+
+    if curves.len() > 1 && curves[curves.len() - 1][3].is_near_to(&curves[0][0], SMALL_DISTANCE) {
+        joins.push(Join {
+            incoming: curves.len() - 1,
+            outgoing: 0,
+        });
+    }
+
+    joins
+}
+
+///
+/// Finds the positions of the control points on either side of a join that make it tangent-continuous
+///
+/// Returns `None` if the join can't be smoothed (because one of the control points is on the join, or the curves double
+/// back on each other).
+///
+fn g1_handles<Point: Coordinate + Coordinate2D>(
+    incoming: &[Point; 4],
+    outgoing: &[Point; 4],
+) -> Option<(Point, Point)> {
+    let join = incoming[3];
+    let handle_in = join - incoming[2];
+    let handle_out = outgoing[1] - join;
+
+    if handle_in.magnitude() <= 0.0 || handle_out.magnitude() <= 0.0 {
+        return None;
+    }
+
+    let direction = handle_in.to_unit_vector() + handle_out.to_unit_vector();
+    if direction.magnitude() < 1e-6 {
+        return None;
+    }
+
+    // Move each control point to the closest point on the line through the join with the average direction
+    let direction = direction.to_unit_vector();
+    let len_in = handle_in.dot(&direction);
+    let len_out = handle_out.dot(&direction);
+
+    Some((join - direction * len_in, join + direction * len_out))
+}
+
+///
+/// Returns the part of a curve between two t values
+///
+fn curve_section<Point: Coordinate>(curve: &[Point; 4], t_min: f64, t_max: f64) -> [Point; 4] {
+    let (_, after) = subdivide4(t_min, curve[0], curve[1], curve[2], curve[3]);
+    let (section, _) = subdivide4(
+        (t_max - t_min) / (1.0 - t_min),
+        after.0,
+        after.1,
+        after.2,
+        after.3,
+    );
+
+    [section.0, section.1, section.2, section.3]
+}
+
+///
+/// Finds how much of the curves on either side of a join need to be split off so that the join can be smoothed by moving
+/// the control points by no more than `budget`
+///
+/// The result is the proportion of the t values of each curve that needs to be adjusted (1.0 if no splitting is needed).
+///
+fn join_proportion<Point: Coordinate + Coordinate2D>(
+    incoming: &[Point; 4],
+    outgoing: &[Point; 4],
+    budget: f64,
+) -> f64 {
+    let mut proportion = 1.0;
+
+    for _ in 0..MAX_JOIN_SUBDIVISIONS {
+        let incoming = curve_section(incoming, 1.0 - proportion, 1.0);
+        let outgoing = curve_section(outgoing, 0.0, proportion);
+
+        let distance = match g1_handles(&incoming, &outgoing) {
+            Some((new_in, new_out)) => new_in
+                .distance_to(&incoming[2])
+                .max(new_out.distance_to(&outgoing[1])),
+            None => return 1.0,
+        };
+
+        if distance <= budget {
+            break;
+        }
+
+        // The control points get shorter roughly in proportion to the size of the section
+        proportion *= (budget / distance * 0.9).min(0.5);
+    }
+
+    proportion
+}
+
+///
+/// Adjusts the lengths of the control points at a join (without changing their direction) so that the curvature on
+/// either side matches, as long as they stay within `budget` of their original positions
+///
+fn match_curvature<Point: Coordinate + Coordinate2D>(
+    curves: &mut [[Point; 4]],
+    original: &[[Point; 4]],
+    join: &Join,
+    budget: f64,
+) {
+    let (incoming, outgoing) = (curves[join.incoming], curves[join.outgoing]);
+    let point = incoming[3];
+    let handle_in = point - incoming[2];
+    let handle_out = outgoing[1] - point;
+    let (len_in, len_out) = (handle_in.magnitude(), handle_out.magnitude());
+
+    if len_in <= 0.0 || len_out <= 0.0 {
+        return;
+    }
+
+    // The curvature at the end of each curve is 2/3 * height / len^2, where the height is the distance of the next control
+    // point from the tangent line
+    let direction = handle_in.to_unit_vector();
+    let height_in = cross(&(point - incoming[1]), &direction);
+    let height_out = cross(&direction, &(outgoing[2] - point));
+
+    if height_in * height_out <= 0.0 {
+        // The curves bend in opposite directions (or one of them is straight), so changing the lengths can't match them
+        return;
+    }
+
+    // Pick the lengths closest to the current ones where the curvatures match
+    let ratio = (height_out / height_in).sqrt();
+    let new_len_in = (len_in + ratio * len_out) / (1.0 + ratio * ratio);
+    let new_len_out = new_len_in * ratio;
+
+    let new_in = point - direction * new_len_in;
+    let new_out = point + direction * new_len_out;
+
+    if new_in.distance_to(&original[join.incoming][2]) <= budget
+        && new_out.distance_to(&original[join.outgoing][1]) <= budget
+    {
+        curves[join.incoming][2] = new_in;
+        curves[join.outgoing][1] = new_out;
+    }
+}
+
+///
+/// Adjusts the control points of a path so that its sections join smoothly
+///
+/// With `Continuity::G1`, the control points either side of each join are moved so that they're in a line with it, removing
+/// any visible corners. With `Continuity::G2`, the lengths of the control points are also adjusted so that the curvature on
+/// each side of the join matches.
+///
+/// No part of the path moves by more than `tolerance`. Where a join can't be smoothed by just moving the control points,
+/// the curves on either side are subdivided so the part that needs to be adjusted is smaller. Joins that are too sharp to
+/// smooth (where the path turns back on itself) are left as they are. This is useful for cleaning up the kinks left at the
+/// points where paths were joined by the path arithmetic operations.
+///
+pub fn smooth_path<P: BezierPath, POut: BezierPathFactory<Point = P::Point>>(
+    path: &P,
+    continuity: Continuity,
+    tolerance: f64,
+) -> POut
+where
+    P::Point: Coordinate2D,
+{
+    // Moving a control point by a distance moves the curve by at most MAX_CONTROL_POINT_WEIGHT times that distance
+    let budget = tolerance.abs() / MAX_CONTROL_POINT_WEIGHT;
+
+    let mut last_point = path.start_point();
+    let original_curves = path
+        .points()
+        .map(|(cp1, cp2, end_point)| {
+            let curve = [last_point, cp1, cp2, end_point];
+            last_point = end_point;
+            curve
+        })
+        .collect::<Vec<_>>();
+
+    // Work out how much of the curves around each join need to be divided off so it can be smoothed within the tolerance
+    let original_joins = joins(&original_curves);
+    let mut start_proportion = vec![1.0; original_curves.len()];
+    let mut end_proportion = vec![1.0; original_curves.len()];
+
+    for join in original_joins.iter() {
+        let proportion = join_proportion(
+            &original_curves[join.incoming],
+            &original_curves[join.outgoing],
+            budget,
+        );
+
+        end_proportion[join.incoming] = proportion;
+        start_proportion[join.outgoing] = proportion;
+    }
+
+    // Divide the curves, remembering which of the new curves ends at one of the original joins
+    let mut curves = vec![];
+    let mut ends_at_join = vec![];
+
+    for (idx, curve) in original_curves.iter().enumerate() {
+        let is_incoming = original_joins.iter().any(|join| join.incoming == idx);
+
+        let mut splits = vec![0.0];
+        if start_proportion[idx] < 1.0 {
+            splits.push(start_proportion[idx].min(0.5));
+        }
+        if end_proportion[idx] < 1.0 {
+            splits.push((1.0 - end_proportion[idx]).max(0.5));
+        }
+        splits.push(1.0);
+        splits.dedup_by(|a, b| (*a - *b).abs() <= 1e-12);
+
+        for range in splits.windows(2) {
+            curves.push(curve_section(curve, range[0], range[1]));
+            ends_at_join.push(false);
+        }
+
+        if is_incoming {
+            *ends_at_join.last_mut().unwrap() = true;
+        }
+    }
+
+    // Make each of the original joins tangent-continuous
+    let split_curves = curves.clone();
+    let mut smoothed_joins = vec![];
+
+    for (idx, _) in ends_at_join
+        .iter()
+        .enumerate()
+        .filter(|(_, ends_at_join)| **ends_at_join)
+    {
+        let join = Join {
+            incoming: idx,
+            outgoing: (idx + 1) % curves.len(),
+        };
+        let (incoming, outgoing) = (curves[join.incoming], curves[join.outgoing]);
+
+        if let Some((new_in, new_out)) = g1_handles(&incoming, &outgoing) {
+            if new_in.distance_to(&incoming[2]) <= budget
+                && new_out.distance_to(&outgoing[1]) <= budget
+            {
+                curves[join.incoming][2] = new_in;
+                curves[join.outgoing][1] = new_out;
+                curves[join.outgoing][0] = incoming[3];
+                smoothed_joins.push(join);
+            }
+        }
+    }
+
+    // Match the curvature at the joins
+    if continuity == Continuity::G2 {
+        for _ in 0..CURVATURE_PASSES {
+            for join in smoothed_joins.iter() {
+                match_curvature(&mut curves, &split_curves, join, budget);
+            }
+        }
+    }
+
+    let start_point = curves
+        .first()
+        .map(|curve| curve[0])
+        .unwrap_or_else(|| path.start_point());
+    let points = curves
+        .into_iter()
+        .map(|curve| (curve[1], curve[2], curve[3]))
+        .collect::<Vec<_>>();
+
+    POut::from_points(start_point, points)
+}
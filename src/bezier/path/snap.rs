@@ -0,0 +1,201 @@
+use super::path_index::{IndexedPathCurve, PathIndex};
+use crate::bezier::basis::bezier_coefficients;
+use crate::bezier::curve::BezierCurve;
+use crate::bezier::intersection::curve_intersects_curve_clip;
+use crate::bezier::length::{curve_length, t_for_length};
+use crate::bezier::nearest::nearest_point_on_curve;
+use crate::bezier::polynomial::{poly_mul, poly_roots_in_range};
+use crate::consts::{CLOSE_DISTANCE, SMALL_DISTANCE};
+use crate::geo::{BoundingBox, Bounds, Coordinate, Coordinate2D};
+
+use std::cmp::Ordering;
+
+///
+/// The kinds of point that can be snapped to, in order of priority (the first kind is preferred when several targets are
+/// close to the cursor)
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum SnapKind {
+    /// The start or end point of a section of a path
+    EndPoint,
+
+    /// A point where two curves cross
+    Intersection,
+
+    /// The point half way along a section of a path
+    MidPoint,
+
+    /// A point where the line from the reference point touches a curve at a tangent
+    Tangent,
+
+    /// A point where the line from the reference point meets a curve at a right angle
+    Perpendicular,
+
+    /// The point on a curve nearest to the cursor
+    Nearest,
+}
+
+///
+/// A point that the cursor can snap to, as returned by `snap_to_paths()`
+///
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SnapTarget<Point> {
+    /// The kind of point that this is
+    pub kind: SnapKind,
+
+    /// The position of the snap point
+    pub position: Point,
+
+    /// The distance from the cursor to the snap point
+    pub distance: f64,
+
+    /// The ID of the path that the snap point is on (for intersections, this is the first of the paths)
+    pub path_id: usize,
+
+    /// The section of the path that the snap point is in
+    pub section: usize,
+
+    /// The t value of the snap point within the section
+    pub t: f64,
+}
+
+///
+/// Finds the t values where the line from a point to a curve meets the curve at a tangent (if `perpendicular` is false)
+/// or at a right angle (if it's true)
+///
+fn t_values_from_point<C: BezierCurve>(curve: &C, point: &C::Point, perpendicular: bool) -> Vec<f64>
+where
+    C::Point: Coordinate2D,
+{
+    let w1 = curve.start_point();
+    let (w2, w3) = curve.control_points();
+    let w4 = curve.end_point();
+
+    // Polynomials for the offset from the point and the derivative of the curve, from the constant term upwards
+    let (ax, bx, cx, dx) = bezier_coefficients(0, &w1, &w2, &w3, &w4);
+    let (ay, by, cy, dy) = bezier_coefficients(1, &w1, &w2, &w3, &w4);
+    let offset_x = [dx - point.x(), cx, bx, ax];
+    let offset_y = [dy - point.y(), cy, by, ay];
+    let tangent_x = [cx, 2.0 * bx, 3.0 * ax];
+    let tangent_y = [cy, 2.0 * by, 3.0 * ay];
+
+    // The dot product of the offset and the tangent is 0 where they're perpendicular, and the cross product is 0 where
+    // they're parallel
+    let poly = if perpendicular {
+        let x = poly_mul(&offset_x, &tangent_x);
+        let y = poly_mul(&offset_y, &tangent_y);
+        x.iter()
+            .zip(y.iter())
+            .map(|(x, y)| x + y)
+            .collect::<Vec<_>>()
+    } else {
+        let x = poly_mul(&offset_x, &tangent_y);
+        let y = poly_mul(&offset_y, &tangent_x);
+        x.iter()
+            .zip(y.iter())
+            .map(|(x, y)| x - y)
+            .collect::<Vec<_>>()
+    };
+
+    poly_roots_in_range(&poly, 0.0, 1.0, 0.0).into_vec()
+}
+
+///
+/// Finds the points that a cursor can snap to on the paths stored in an index
+///
+/// The targets are the end points and mid points of the sections of the paths, the places where curves intersect, the
+/// point on each curve nearest to the cursor and, if a `reference` point is supplied (usually the previous point placed
+/// by the user), the points where a line from the reference point would be tangent or perpendicular to a curve. Only
+/// targets within `radius` of the cursor are returned, and only curves whose bounding boxes come within `radius` of the
+/// cursor are checked, so this stays fast for documents with many paths (see `path_index()`).
+///
+/// Targets are sorted by priority (in the order of `SnapKind`) and then by distance from the cursor, so the first target
+/// is the one to snap to. Where targets of different kinds are in the same place (such as the end point of one section
+/// and the start point of the next, or an intersection at the end of a curve), only the one with the highest priority
+/// is returned.
+///
+pub fn snap_to_paths<Point>(
+    index: &PathIndex<Point>,
+    cursor: &Point,
+    radius: f64,
+    reference: Option<&Point>,
+) -> Vec<SnapTarget<Point>>
+where
+    Point: Coordinate + Coordinate2D,
+{
+    let cursor = *cursor;
+    let search_bounds = Bounds::from_min_max(cursor, cursor).inflate(radius);
+    let curves = index
+        .overlapping_bounds(&search_bounds)
+        .map(|(_id, indexed_curve)| indexed_curve)
+        .collect::<Vec<_>>();
+
+    let mut targets = vec![];
+    let mut add_target = |kind, indexed_curve: &IndexedPathCurve<Point>, t: f64| {
+        let position = indexed_curve.curve.point_at_pos(t);
+        let distance = position.distance_to(&cursor);
+
+        if distance <= radius {
+            targets.push(SnapTarget {
+                kind,
+                position,
+                distance,
+                path_id: indexed_curve.path_id,
+                section: indexed_curve.section,
+                t,
+            });
+        }
+    };
+
+    for (idx, indexed_curve) in curves.iter().enumerate() {
+        let curve = &indexed_curve.curve;
+
+        add_target(SnapKind::EndPoint, indexed_curve, 0.0);
+        add_target(SnapKind::EndPoint, indexed_curve, 1.0);
+
+        let length = curve_length(curve, SMALL_DISTANCE);
+        let mid_t = t_for_length(curve, length * 0.5, length, SMALL_DISTANCE);
+        add_target(SnapKind::MidPoint, indexed_curve, mid_t);
+
+        let (nearest_t, _) = nearest_point_on_curve(curve, &cursor);
+        add_target(SnapKind::Nearest, indexed_curve, nearest_t);
+
+        if let Some(reference) = reference {
+            for t in t_values_from_point(curve, reference, false) {
+                add_target(SnapKind::Tangent, indexed_curve, t);
+            }
+            for t in t_values_from_point(curve, reference, true) {
+                add_target(SnapKind::Perpendicular, indexed_curve, t);
+            }
+        }
+
+        for other_curve in curves.iter().skip(idx + 1) {
+            for (t, _) in curve_intersects_curve_clip(curve, &other_curve.curve, SMALL_DISTANCE) {
+                add_target(SnapKind::Intersection, indexed_curve, t);
+            }
+        }
+    }
+
+    // Order by priority, then by distance
+    targets.sort_by(|a, b| {
+        a.kind.cmp(&b.kind).then(
+            a.distance
+                .partial_cmp(&b.distance)
+                .unwrap_or(Ordering::Equal),
+        )
+    });
+
+    // Remove targets that are in the same place as a target with a higher priority
+    let mut result: Vec<SnapTarget<Point>> = vec![];
+    for target in targets {
+        if !result.iter().any(|existing| {
+            existing
+                .position
+                .is_near_to(&target.position, CLOSE_DISTANCE)
+        }) {
+            result.push(target);
+        }
+    }
+
+    result
+}
@@ -0,0 +1,385 @@
+use super::super::super::geo::{Coordinate, Coordinate2D, Coordinate3D};
+use super::super::curve::{BezierCurve, Curve};
+use super::super::fit::fit_curve;
+use super::super::tangent::Tangent;
+use super::flatten::path_flatten;
+use super::measure::PathMeasure;
+use super::path::{BezierPath, BezierPathFactory};
+
+/// Number of steps the rotation-minimising frame is calculated over between each cross section
+const FRAME_STEPS_PER_SECTION: usize = 8;
+
+/// Points in a swept outline where the direction changes by more than this (as the cosine of the angle) are kept as corners
+const MIN_SMOOTH_COS_ANGLE: f64 = 0.866;
+
+///
+/// How the profile is oriented as it is swept along a spine
+///
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum SweepOrientation {
+    /// The profile's x axis follows the tangent of the spine and its y axis follows the normal, so the profile rotates as
+    /// the spine turns
+    FollowSpine,
+
+    /// The profile keeps the same orientation, like a calligraphy pen held at a fixed angle
+    Fixed,
+}
+
+///
+/// Returns the index of the point with the largest value for a function
+///
+fn index_of_max<Point, ValueFn: Fn(&Point) -> f64>(points: &[Point], value: ValueFn) -> usize {
+    (0..points.len())
+        .max_by(|a, b| value(&points[*a]).total_cmp(&value(&points[*b])))
+        .unwrap_or(0)
+}
+
+///
+/// Returns the points of a polygon going from one vertex to another, in one of the two directions around it
+///
+fn polygon_chain<Point: Copy>(
+    polygon: &[Point],
+    from: usize,
+    to: usize,
+    forwards: bool,
+) -> Vec<Point> {
+    let len = polygon.len();
+    let mut chain = vec![polygon[from]];
+    let mut idx = from;
+
+    while idx != to {
+        idx = if forwards {
+            (idx + 1) % len
+        } else {
+            (idx + len - 1) % len
+        };
+        chain.push(polygon[idx]);
+    }
+
+    chain
+}
+
+///
+/// Returns the part of a placed profile between its left and right extremes that faces in a particular direction
+///
+fn cap_chain<Point: Coordinate + Coordinate2D>(
+    placed: &[Point],
+    left: usize,
+    right: usize,
+    direction: Point,
+) -> Vec<Point> {
+    let chain_a = polygon_chain(placed, left, right, true);
+    let chain_b = polygon_chain(placed, left, right, false);
+
+    let facing = |chain: &[Point]| {
+        chain.iter().map(|point| point.dot(&direction)).sum::<f64>() / (chain.len() as f64)
+    };
+
+    if facing(&chain_a) >= facing(&chain_b) {
+        chain_a
+    } else {
+        chain_b
+    }
+}
+
+///
+/// Fits curves to a closed outline, keeping any sharp corners in it
+///
+/// The result is the start point of the fitted path and its curves, which starts at the first corner in the outline.
+///
+fn fit_outline<Point: Coordinate + Coordinate2D>(
+    outline: &[Point],
+    max_error: f64,
+) -> (Point, Vec<(Point, Point, Point)>) {
+    let len = outline.len();
+    if len < 2 {
+        return (outline[0], vec![]);
+    }
+
+    // Find the corners in the outline
+    let is_corner = |idx: usize| {
+        let before = outline[idx] - outline[(idx + len - 1) % len];
+        let after = outline[(idx + 1) % len] - outline[idx];
+
+        before.magnitude() > 0.0
+            && after.magnitude() > 0.0
+            && before.to_unit_vector().dot(&after.to_unit_vector()) < MIN_SMOOTH_COS_ANGLE
+    };
+    let corners = (0..len).filter(|idx| is_corner(*idx)).collect::<Vec<_>>();
+
+    // Divide into runs that start and end at corners (or run all the way around if there are none)
+    let runs = if corners.is_empty() {
+        vec![(0, len)]
+    } else {
+        (0..corners.len())
+            .map(|idx| {
+                let start = corners[idx];
+                let end = if idx + 1 < corners.len() {
+                    corners[idx + 1]
+                } else {
+                    corners[0] + len
+                };
+
+                (start, end)
+            })
+            .collect()
+    };
+
+    let start_point = outline[runs[0].0];
+    let mut points = vec![];
+
+    for (start, end) in runs {
+        let run = (start..=end)
+            .map(|idx| outline[idx % len])
+            .collect::<Vec<_>>();
+
+        if let Some(curves) = fit_curve::<Curve<_>>(&run, max_error) {
+            points.extend(curves.into_iter().map(|curve| {
+                let (cp1, cp2) = curve.control_points();
+                (cp1, cp2, curve.end_point())
+            }));
+        }
+    }
+
+    (start_point, points)
+}
+
+///
+/// Sweeps a 2D profile along a spine, returning the outline of the area it covers
+///
+/// The profile is positioned so its origin is on the spine. With `SweepOrientation::FollowSpine`, its x axis points along
+/// the spine and its y axis points to the left of it, so a profile that is wider in y produces a wider stroke. With
+/// `SweepOrientation::Fixed`, the profile is only moved, not rotated, so the width of the stroke changes with the direction
+/// of the spine (as with a calligraphy pen).
+///
+/// The profile is placed every `spacing` units along the spine, and the outline follows the parts of each placement that
+/// are furthest to either side of the spine, so it's exact for convex profiles swept along straight lines and an
+/// approximation elsewhere. The resulting curves are fitted to within `max_error`. The outline goes forward along the
+/// left side of the spine, around the end of the stroke and back along the right side.
+///
+pub fn path_sweep<Profile, Spine, POut>(
+    profile: &Profile,
+    spine: &Spine,
+    orientation: SweepOrientation,
+    spacing: f64,
+    max_error: f64,
+) -> POut
+where
+    Profile: BezierPath,
+    Profile::Point: Coordinate + Coordinate2D,
+    Spine: BezierPath<Point = Profile::Point>,
+    POut: BezierPathFactory<Point = Profile::Point>,
+{
+    let spacing = spacing.max(1e-6);
+    let max_error = max_error.max(1e-9);
+
+    // Turn the profile into a polygon
+    let mut polygon = path_flatten(profile, max_error * 0.5);
+    polygon.dedup();
+    while polygon.len() > 1 && polygon[0] == polygon[polygon.len() - 1] {
+        polygon.pop();
+    }
+
+    if polygon.is_empty() {
+        return POut::from_points(spine.start_point(), vec![]);
+    }
+
+    // Find the frames where the profile is placed
+    let x_axis = Profile::Point::from_components(&[1.0, 0.0]);
+    let y_axis = Profile::Point::from_components(&[0.0, 1.0]);
+    let measure = PathMeasure::new(spine, max_error * 0.1);
+    let length = measure.length();
+    let num_steps = ((length / spacing).ceil() as usize).max(1);
+
+    let frames = (0..=num_steps)
+        .map(|step| {
+            measure
+                .frame_at_length(length * (step as f64) / (num_steps as f64))
+                .map(|frame| (frame.position, frame.tangent, frame.normal))
+                .unwrap_or((spine.start_point(), x_axis, y_axis))
+        })
+        .collect::<Vec<_>>();
+
+    // Place the profile at each frame and find the points furthest to the left and right of the spine
+    let mut left_rail = vec![];
+    let mut right_rail = vec![];
+    let mut placements = vec![];
+
+    for (position, tangent, normal) in frames.iter() {
+        let (profile_x, profile_y) = match orientation {
+            SweepOrientation::FollowSpine => (*tangent, *normal),
+            SweepOrientation::Fixed => (x_axis, y_axis),
+        };
+
+        let placed = polygon
+            .iter()
+            .map(|point| *position + profile_x * point.x() + profile_y * point.y())
+            .collect::<Vec<_>>();
+        let left = index_of_max(&placed, |point| (*point - *position).dot(normal));
+        let right = index_of_max(&placed, |point| -(*point - *position).dot(normal));
+
+        left_rail.push(placed[left]);
+        right_rail.push(placed[right]);
+        placements.push((placed, left, right));
+    }
+
+    // The caps at either end follow the parts of the profile facing away from the stroke
+    let (end_placed, end_left, end_right) = &placements[placements.len() - 1];
+    let end_tangent = frames[frames.len() - 1].1;
+    let end_cap = cap_chain(end_placed, *end_left, *end_right, end_tangent);
+
+    let (start_placed, start_left, start_right) = &placements[0];
+    let start_tangent = frames[0].1;
+    let mut start_cap = cap_chain(
+        start_placed,
+        *start_left,
+        *start_right,
+        start_tangent * -1.0,
+    );
+    start_cap.reverse();
+
+    right_rail.reverse();
+
+    // Join the parts into a single outline
+    let mut outline = left_rail;
+    outline.extend(end_cap);
+    outline.extend(right_rail);
+    outline.extend(start_cap);
+    outline.dedup();
+    while outline.len() > 1 && outline[0] == outline[outline.len() - 1] {
+        outline.pop();
+    }
+
+    let (start_point, points) = fit_outline(&outline, max_error);
+
+    POut::from_points(start_point, points)
+}
+
+///
+/// The cross product of two 3D vectors
+///
+#[inline]
+fn cross<Point: Coordinate + Coordinate3D>(a: &Point, b: &Point) -> Point {
+    Point::from_components(&[
+        a.y() * b.z() - a.z() * b.y(),
+        a.z() * b.x() - a.x() * b.z(),
+        a.x() * b.y() - a.y() * b.x(),
+    ])
+}
+
+///
+/// Reflects a vector in the plane at right angles to another vector (which should not be zero)
+///
+#[inline]
+fn reflect<Point: Coordinate>(vector: Point, plane_normal: &Point) -> Point {
+    let scale = 2.0 / plane_normal.dot(plane_normal);
+
+    vector - *plane_normal * (scale * plane_normal.dot(&vector))
+}
+
+///
+/// Places copies of a 2D profile at evenly spaced points along a 3D spine, at right angles to it
+///
+/// This returns `num_sections` cross sections (including ones at the start and end of the spine). The profile's origin is
+/// placed on the spine, and its x and y axes follow the normal and binormal of a rotation-minimising frame (calculated using
+/// the double reflection method), so the cross sections twist as little as possible as the spine bends. This is what's
+/// needed to build a surface like a tube or an extrusion around a space curve.
+///
+pub fn path_sweep_cross_sections<Profile, Spine, POut>(
+    profile: &Profile,
+    spine: &Spine,
+    num_sections: usize,
+) -> Vec<POut>
+where
+    Profile: BezierPath,
+    Profile::Point: Coordinate + Coordinate2D,
+    Spine: BezierPath,
+    Spine::Point: Coordinate + Coordinate3D,
+    POut: BezierPathFactory<Point = Spine::Point>,
+{
+    if num_sections == 0 {
+        return vec![];
+    }
+
+    let measure = PathMeasure::new(spine, 1e-6);
+    let length = measure.length();
+
+    // The position and unit tangent at a distance along the spine
+    let mut last_tangent = Spine::Point::from_components(&[1.0, 0.0, 0.0]);
+    let mut position_and_tangent = |distance: f64| {
+        if measure.curves().is_empty() {
+            return (spine.start_point(), last_tangent);
+        }
+
+        let (section, t) = measure.section_t_at_length(distance);
+        let curve = &measure.curves()[section];
+        let tangent = Tangent::from(curve).tangent(t);
+
+        if tangent.magnitude() > 0.0 {
+            last_tangent = tangent.to_unit_vector();
+        }
+
+        (curve.point_at_pos(t), last_tangent)
+    };
+
+    // Pick any normal for the initial frame, using the axis that's furthest from the tangent
+    let (mut position, mut tangent) = position_and_tangent(0.0);
+    let components = [tangent.x().abs(), tangent.y().abs(), tangent.z().abs()];
+    let axis_idx = index_of_max(&components, |component| -*component);
+    let mut axis = [0.0, 0.0, 0.0];
+    axis[axis_idx] = 1.0;
+    let axis = Spine::Point::from_components(&axis);
+    let mut normal = (axis - tangent * axis.dot(&tangent)).to_unit_vector();
+
+    // Move the frame along the spine, placing a cross section every few steps
+    let num_steps = (num_sections - 1).max(1) * FRAME_STEPS_PER_SECTION;
+    let mut sections = vec![];
+
+    for step in 0..=num_steps {
+        if step > 0 {
+            let (next_position, next_tangent) =
+                position_and_tangent(length * (step as f64) / (num_steps as f64));
+
+            // Double reflection: reflect the frame in the plane between the two positions, then in the plane that makes
+            // the tangents line up
+            let offset = next_position - position;
+            let (reflected_normal, reflected_tangent) = if offset.dot(&offset) > 0.0 {
+                (reflect(normal, &offset), reflect(tangent, &offset))
+            } else {
+                (normal, tangent)
+            };
+
+            let tangent_offset = next_tangent - reflected_tangent;
+            normal = if tangent_offset.dot(&tangent_offset) > 0.0 {
+                reflect(reflected_normal, &tangent_offset)
+            } else {
+                reflected_normal
+            };
+
+            // Avoid drifting away from a unit vector at right angles to the tangent due to rounding errors
+            let perpendicular = normal - next_tangent * normal.dot(&next_tangent);
+            if perpendicular.magnitude() > 0.0 {
+                normal = perpendicular.to_unit_vector();
+            }
+
+            position = next_position;
+            tangent = next_tangent;
+        }
+
+        if step % FRAME_STEPS_PER_SECTION == 0 && sections.len() < num_sections {
+            let binormal = cross(&tangent, &normal);
+            let place =
+                |point: Profile::Point| position + normal * point.x() + binormal * point.y();
+
+            let start_point = place(profile.start_point());
+            let points = profile
+                .points()
+                .map(|(cp1, cp2, end_point)| (place(cp1), place(cp2), place(end_point)))
+                .collect::<Vec<_>>();
+
+            sections.push(POut::from_points(start_point, points));
+        }
+    }
+
+    sections
+}
@@ -0,0 +1,335 @@
+use super::super::super::geo::{orient_2d, Coordinate, Coordinate2D};
+use super::flatten::path_flatten;
+use super::nesting::path_figures;
+use super::path::BezierPath;
+
+///
+/// A set of triangles generated by `path_tessellate()`
+///
+#[derive(Clone, PartialEq, Debug)]
+pub struct TriangleMesh<Point> {
+    /// The vertices of the mesh
+    pub vertices: Vec<Point>,
+
+    /// The triangles in the mesh, as indexes into the vertex list (each triangle is anticlockwise)
+    pub triangles: Vec<[usize; 3]>,
+}
+
+impl<Point> TriangleMesh<Point> {
+    ///
+    /// Creates an empty triangle mesh
+    ///
+    pub fn new() -> Self {
+        TriangleMesh {
+            vertices: vec![],
+            triangles: vec![],
+        }
+    }
+}
+
+impl<Point> Default for TriangleMesh<Point> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Point: Coordinate + Coordinate2D> TriangleMesh<Point> {
+    ///
+    /// Returns the points making up each triangle in the mesh
+    ///
+    pub fn triangle_points(&self) -> impl '_ + Iterator<Item = (Point, Point, Point)> {
+        self.triangles
+            .iter()
+            .map(move |[a, b, c]| (self.vertices[*a], self.vertices[*b], self.vertices[*c]))
+    }
+
+    ///
+    /// Returns the total area covered by the triangles in the mesh
+    ///
+    pub fn area(&self) -> f64 {
+        self.triangle_points()
+            .map(|(a, b, c)| orient_2d(&a, &b, &c) * 0.5)
+            .sum()
+    }
+
+    ///
+    /// Flattens a path and adds its points to the vertex list, returning the indexes of the polygon's vertices
+    ///
+    /// The returned polygon has no repeated points and is anticlockwise if `anticlockwise` is true. Paths with a point that
+    /// isn't finite can't be triangulated, so no polygon is returned for them.
+    ///
+    fn add_polygon<P: BezierPath<Point = Point>>(
+        &mut self,
+        path: &P,
+        tolerance: f64,
+        anticlockwise: bool,
+    ) -> Vec<usize> {
+        let mut points = path_flatten(path, tolerance);
+        if points
+            .iter()
+            .any(|point| !point.x().is_finite() || !point.y().is_finite())
+        {
+            return vec![];
+        }
+
+        points.dedup();
+        while points.len() > 1 && points[0] == points[points.len() - 1] {
+            points.pop();
+        }
+
+        // Use the shoelace formula to find the direction of the polygon
+        let twice_area = (0..points.len())
+            .map(|idx| {
+                let (p1, p2) = (points[idx], points[(idx + 1) % points.len()]);
+                p1.x() * p2.y() - p2.x() * p1.y()
+            })
+            .sum::<f64>();
+        if (twice_area > 0.0) != anticlockwise {
+            points.reverse();
+        }
+
+        let first_idx = self.vertices.len();
+        self.vertices.extend(points);
+
+        (first_idx..self.vertices.len()).collect()
+    }
+
+    ///
+    /// Joins a hole to the (anticlockwise) outline of a polygon, so that the outline goes around the hole in the opposite
+    /// direction
+    ///
+    /// This is the method described by David Eberly in 'Triangulation by Ear Clipping': a line is cast from the rightmost
+    /// point of the hole to the outline to find a vertex that can be seen from it, and the outline is then extended to go
+    /// along that line, around the hole and back again.
+    ///
+    fn bridge_hole(&self, outline: &mut Vec<usize>, hole: &[usize]) {
+        if hole.is_empty() || outline.is_empty() {
+            return;
+        }
+
+        let vertices = &self.vertices;
+
+        // The rightmost point in the hole
+        let (hole_pos, hole_idx) = hole
+            .iter()
+            .enumerate()
+            .max_by(|(_, a), (_, b)| vertices[**a].x().total_cmp(&vertices[**b].x()))
+            .map(|(pos, idx)| (pos, *idx))
+            .unwrap();
+        let m = vertices[hole_idx];
+
+        // Find the closest edge of the outline to the right of this point
+        let mut closest: Option<(f64, usize)> = None;
+        for pos in 0..outline.len() {
+            let (start, end) = (
+                vertices[outline[pos]],
+                vertices[outline[(pos + 1) % outline.len()]],
+            );
+
+            if (start.y() - m.y()) * (end.y() - m.y()) > 0.0 || start.y() == end.y() {
+                continue;
+            }
+
+            let t = (m.y() - start.y()) / (end.y() - start.y());
+            let x = start.x() + (end.x() - start.x()) * t;
+
+            if x >= m.x() && closest.map(|(closest_x, _)| x < closest_x).unwrap_or(true) {
+                // Choose the end of the edge that's furthest to the right as the candidate
+                let candidate = if start.x() > end.x() {
+                    pos
+                } else {
+                    (pos + 1) % outline.len()
+                };
+
+                closest = Some((x, candidate));
+            }
+        }
+
+        let bridge_pos = match closest {
+            Some((x, candidate)) => {
+                // Any reflex vertex inside the triangle formed by the hole point, the intersection and the candidate might
+                // block the view of the candidate: if there is one, pick the one closest in angle to the ray instead
+                let intersection = Point::from_components(&[x, m.y()]);
+                let candidate_point = vertices[outline[candidate]];
+                let mut best = candidate;
+                let mut best_angle = f64::MAX;
+
+                for pos in 0..outline.len() {
+                    let point = vertices[outline[pos]];
+                    if pos == candidate || point == candidate_point {
+                        continue;
+                    }
+
+                    let prev = vertices[outline[(pos + outline.len() - 1) % outline.len()]];
+                    let next = vertices[outline[(pos + 1) % outline.len()]];
+                    let is_reflex = orient_2d(&prev, &point, &next) < 0.0;
+
+                    if is_reflex && point_in_triangle(&point, &m, &intersection, &candidate_point) {
+                        let offset = point - m;
+                        let angle = offset.y().atan2(offset.x()).abs();
+
+                        if angle < best_angle {
+                            best = pos;
+                            best_angle = angle;
+                        }
+                    }
+                }
+
+                best
+            }
+
+            None => {
+                // Should not happen for holes inside the outline: fall back to the closest vertex
+                (0..outline.len())
+                    .min_by(|a, b| {
+                        let a = vertices[outline[*a]].distance_to(&m);
+                        let b = vertices[outline[*b]].distance_to(&m);
+                        a.total_cmp(&b)
+                    })
+                    .unwrap()
+            }
+        };
+
+        // Go from the bridge vertex around the hole and back again
+        let bridge_idx = outline[bridge_pos];
+        let mut bridge = vec![];
+        bridge.extend(hole[hole_pos..].iter().copied());
+        bridge.extend(hole[..=hole_pos].iter().copied());
+        bridge.push(bridge_idx);
+
+        let insert_pos = bridge_pos + 1;
+        outline.splice(insert_pos..insert_pos, bridge);
+    }
+
+    ///
+    /// Divides an anticlockwise polygon into triangles by repeatedly removing 'ears' (triangles formed by three adjacent
+    /// vertices that contain no other vertices)
+    ///
+    fn clip_ears(&mut self, polygon: Vec<usize>) {
+        let vertices = &self.vertices;
+        let mut polygon = polygon;
+
+        while polygon.len() >= 3 {
+            let len = polygon.len();
+            let mut clipped = false;
+
+            for pos in 0..len {
+                let (prev, cur, next) = (
+                    polygon[(pos + len - 1) % len],
+                    polygon[pos],
+                    polygon[(pos + 1) % len],
+                );
+                let (a, b, c) = (vertices[prev], vertices[cur], vertices[next]);
+                let orientation = orient_2d(&a, &b, &c);
+
+                if orientation == 0.0 {
+                    // Remove vertices that don't turn (no triangle is needed for these)
+                    polygon.remove(pos);
+                    clipped = true;
+                    break;
+                }
+
+                if orientation < 0.0 {
+                    // Reflex vertices can't be ears
+                    continue;
+                }
+
+                // Check that no other vertex is inside this triangle
+                let blocked = polygon.iter().any(|other| {
+                    let point = vertices[*other];
+
+                    point != a && point != b && point != c && point_in_triangle(&point, &a, &b, &c)
+                });
+
+                if !blocked {
+                    self.triangles.push([prev, cur, next]);
+                    polygon.remove(pos);
+                    clipped = true;
+                    break;
+                }
+            }
+
+            if !clipped {
+                // This can happen with self-intersecting polygons: clip the first convex vertex to make progress
+                let convex = (0..len).find(|pos| {
+                    let (prev, cur, next) = (
+                        polygon[(pos + len - 1) % len],
+                        polygon[*pos],
+                        polygon[(pos + 1) % len],
+                    );
+
+                    orient_2d(&vertices[prev], &vertices[cur], &vertices[next]) > 0.0
+                });
+
+                match convex {
+                    Some(pos) => {
+                        self.triangles.push([
+                            polygon[(pos + len - 1) % len],
+                            polygon[pos],
+                            polygon[(pos + 1) % len],
+                        ]);
+                        polygon.remove(pos);
+                    }
+
+                    None => break,
+                }
+            }
+        }
+    }
+}
+
+///
+/// True if a point is inside or on the edge of an anticlockwise triangle
+///
+#[inline]
+fn point_in_triangle<Point: Coordinate + Coordinate2D>(
+    p: &Point,
+    a: &Point,
+    b: &Point,
+    c: &Point,
+) -> bool {
+    orient_2d(a, b, p) >= 0.0 && orient_2d(b, c, p) >= 0.0 && orient_2d(c, a, p) >= 0.0
+}
+
+///
+/// Converts a set of paths into a set of triangles covering their interiors
+///
+/// The paths are flattened into polygons where no point is further than `tolerance` from the original path. Paths inside
+/// other paths are treated as holes (as found by `path_figures()`), so this fills using the even-odd rule. Paths should not
+/// intersect themselves or each other: use a function like `path_remove_overlapped_points()` to fix paths that do before
+/// tessellating them. The triangles are generated using ear clipping, and paths with NaN or infinite coordinates are left
+/// out of the mesh.
+///
+pub fn path_tessellate<P: BezierPath>(paths: &[P], tolerance: f64) -> TriangleMesh<P::Point>
+where
+    P::Point: Coordinate + Coordinate2D,
+{
+    let mut mesh = TriangleMesh::new();
+
+    for figure in path_figures::<_, (P::Point, Vec<(P::Point, P::Point, P::Point)>)>(paths) {
+        let mut outline = mesh.add_polygon(&figure[0], tolerance, true);
+        let mut holes = figure[1..]
+            .iter()
+            .map(|hole| mesh.add_polygon(hole, tolerance, false))
+            .filter(|hole| hole.len() >= 3)
+            .collect::<Vec<_>>();
+
+        // Holes are joined to the outline in order from right to left, so the bridges don't cross any holes that have yet to
+        // be added
+        let max_x = |polygon: &Vec<usize>| {
+            polygon
+                .iter()
+                .map(|idx| mesh.vertices[*idx].x())
+                .fold(f64::MIN, f64::max)
+        };
+        holes.sort_by(|a, b| max_x(b).total_cmp(&max_x(a)));
+
+        for hole in holes {
+            mesh.bridge_hole(&mut outline, &hole);
+        }
+
+        mesh.clip_ears(outline);
+    }
+
+    mesh
+}
@@ -0,0 +1,74 @@
+use super::super::curve::{BezierCurveFactory, Curve};
+use super::super::reduce_degree::reduce_to_quadratic;
+use super::super::subdivide::subdivide4;
+use super::path::BezierPath;
+use crate::geo::{Coordinate, Coordinate2D};
+
+/// The maximum number of times a curve is subdivided when converting it to quadratic curves
+const MAX_QUADRATIC_DEPTH: usize = 12;
+
+///
+/// A path made from quadratic curves: the start point, followed by the control point and end point of each curve
+///
+pub type QuadraticPath<Point> = (Point, Vec<(Point, Point)>);
+
+///
+/// Adds the quadratic curves approximating a cubic curve to a list of control points and end points
+///
+fn curve_to_quadratics<Point: Coordinate + Coordinate2D>(
+    (w1, w2, w3, w4): (Point, Point, Point, Point),
+    tolerance: f64,
+    depth: usize,
+    quadratics: &mut Vec<(Point, Point)>,
+) {
+    let (quadratic, error) = reduce_to_quadratic(&Curve::from_points(w1, (w2, w3), w4));
+
+    if depth >= MAX_QUADRATIC_DEPTH || error <= tolerance {
+        quadratics.push((quadratic.control_point, w4));
+    } else {
+        let (first, second) = subdivide4(0.5, w1, w2, w3, w4);
+
+        curve_to_quadratics(first, tolerance, depth + 1, quadratics);
+        curve_to_quadratics(second, tolerance, depth + 1, quadratics);
+    }
+}
+
+///
+/// Converts a set of paths made from cubic curves to paths made from quadratic curves
+///
+/// Each path in the result is a start point followed by the control point and end point of each quadratic curve, which
+/// is the form used by formats such as TrueType glyph outlines. Each cubic curve is divided in half until each piece
+/// can be represented by a quadratic curve that's within `tolerance` of it (see `reduce_to_quadratic()`), so curves with
+/// inflection points or loops are split into several pieces and curves that are already quadratic are passed through
+/// as a single piece. The end points of the original curves are always included in the result.
+///
+pub fn path_to_quadratics<P: BezierPath>(
+    paths: &[P],
+    tolerance: f64,
+) -> Vec<QuadraticPath<P::Point>>
+where
+    P::Point: Coordinate2D,
+{
+    let tolerance = tolerance.abs().max(1e-9);
+
+    paths
+        .iter()
+        .map(|path| {
+            let start_point = path.start_point();
+            let mut last_point = start_point;
+            let mut quadratics = vec![];
+
+            for (cp1, cp2, end_point) in path.points() {
+                curve_to_quadratics(
+                    (last_point, cp1, cp2, end_point),
+                    tolerance,
+                    0,
+                    &mut quadratics,
+                );
+                last_point = end_point;
+            }
+
+            (start_point, quadratics)
+        })
+        .collect()
+}
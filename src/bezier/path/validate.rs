@@ -0,0 +1,197 @@
+use super::path::{BezierPath, BezierPathFactory};
+use crate::bezier::{BezierCurve, BezierCurveFactory, Curve};
+use crate::consts::SMALL_DISTANCE;
+use crate::geo::Coordinate;
+
+// Path arithmetic treats points closer together than SMALL_DISTANCE as being the same point, so that's the distance used
+// to decide if a curve has no length or if a path is closed. Curves with no length produce edges with no direction in a
+// graph path, and curves that start and end at the same point produce edges that connect a point to itself: neither are
+// handled well when collisions are being detected.
+
+///
+/// Problems that can be found in a path by `validate_path()`
+///
+/// Curves are identified by their index in the path, where curve 0 runs from the start point to the first point.
+///
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PathDefect {
+    /// The path has no curves in it
+    Empty,
+
+    /// The start point of the path is infinite or NaN
+    NonFiniteStartPoint,
+
+    /// One of the points of the specified curve is infinite or NaN
+    NonFiniteCurve(usize),
+
+    /// The specified curve has no length (all of its points are in the same place)
+    ZeroLengthCurve(usize),
+
+    /// The specified curve starts and ends at the same point, but has some length (it forms a loop on its own)
+    CoincidentEndPoints(usize),
+
+    /// The path does not end where it starts, and has a gap of the specified length between the end and the start
+    NotClosed(f64),
+}
+
+///
+/// True if all of the components of a point are finite
+///
+#[inline]
+fn is_finite<Point: Coordinate>(point: &Point) -> bool {
+    (0..Point::len()).all(|component| point.get(component).is_finite())
+}
+
+///
+/// Returns the length of the control polygon of a curve (which is never less than the length of the curve)
+///
+#[inline]
+fn hull_length<Point: Coordinate>(curve: &Curve<Point>) -> f64 {
+    let (cp1, cp2) = curve.control_points;
+
+    curve.start_point.distance_to(&cp1) + cp1.distance_to(&cp2) + cp2.distance_to(&curve.end_point)
+}
+
+///
+/// Checks a path for problems that can cause path arithmetic and the other path algorithms to fail
+///
+/// This detects points that are infinite or NaN, curves that have no length, curves that start and end at the same
+/// point, and paths that don't end where they started. Points are considered to be the same if they're closer than the
+/// distance path arithmetic uses to decide if points are the same. An empty list is returned if the path has no
+/// defects. Use `repair_path()` to fix the problems that are found.
+///
+pub fn validate_path<P: BezierPath>(path: &P) -> Vec<PathDefect> {
+    let mut defects = vec![];
+
+    let start_point = path.start_point();
+    if !is_finite(&start_point) {
+        defects.push(PathDefect::NonFiniteStartPoint);
+    }
+
+    let mut last_point = start_point;
+    let mut num_curves = 0;
+
+    for (curve_idx, (cp1, cp2, end_point)) in path.points().enumerate() {
+        let curve = Curve::from_points(last_point, (cp1, cp2), end_point);
+        num_curves += 1;
+
+        if !is_finite(&cp1) || !is_finite(&cp2) || !is_finite(&end_point) {
+            defects.push(PathDefect::NonFiniteCurve(curve_idx));
+        } else if !is_finite(&last_point) {
+            // Reported as a part of the previous curve or the start point
+        } else if hull_length(&curve) < SMALL_DISTANCE {
+            defects.push(PathDefect::ZeroLengthCurve(curve_idx));
+        } else if last_point.distance_to(&end_point) < SMALL_DISTANCE {
+            defects.push(PathDefect::CoincidentEndPoints(curve_idx));
+        }
+
+        last_point = end_point;
+    }
+
+    if num_curves == 0 {
+        defects.push(PathDefect::Empty);
+    } else if is_finite(&start_point) && is_finite(&last_point) {
+        let gap = last_point.distance_to(&start_point);
+
+        if gap >= SMALL_DISTANCE {
+            defects.push(PathDefect::NotClosed(gap));
+        }
+    }
+
+    defects
+}
+
+///
+/// Creates a version of a path with the defects reported by `validate_path()` fixed, or `None` if there isn't enough
+/// left of the path to form a shape
+///
+/// Curves that contain non-finite points are removed, and the curves either side of them are joined up. Curves with no
+/// length are merged into the curves before them, and curves that form a loop on their own are divided in two so that
+/// their end points are different. Paths that don't end where they started are closed, by moving the end point if it's
+/// very close to the start or by adding a straight line otherwise.
+///
+pub fn repair_path<P: BezierPath, POut: BezierPathFactory<Point = P::Point>>(
+    path: &P,
+) -> Option<POut> {
+    // Use the start point and the curves with finite coordinates, joining up the curves either side of any gaps
+    let mut points = vec![];
+    if is_finite(&path.start_point()) {
+        points.push(path.start_point());
+    }
+
+    let mut curves: Vec<Curve<P::Point>> = vec![];
+    for (cp1, cp2, end_point) in path.points() {
+        if !is_finite(&cp1) || !is_finite(&cp2) || !is_finite(&end_point) {
+            continue;
+        }
+
+        let last_point = match (curves.last(), points.first()) {
+            (Some(last_curve), _) => last_curve.end_point,
+            (None, Some(start_point)) => *start_point,
+            (None, None) => {
+                // The start point was not finite: start at the end of the first finite curve instead
+                points.push(end_point);
+                continue;
+            }
+        };
+        let curve = Curve::from_points(last_point, (cp1, cp2), end_point);
+
+        if hull_length(&curve) < SMALL_DISTANCE {
+            // Merge zero-length curves into the curve before them
+            continue;
+        }
+
+        if last_point.distance_to(&end_point) < SMALL_DISTANCE {
+            // Split loops in two so they have distinct end points
+            let (first, second) = curve.subdivide::<Curve<_>>(0.5);
+            curves.push(Curve::from_points(
+                last_point,
+                first.control_points,
+                first.end_point,
+            ));
+            curves.push(Curve::from_points(
+                second.start_point,
+                second.control_points,
+                last_point,
+            ));
+            continue;
+        }
+
+        curves.push(curve);
+    }
+
+    // Close the path
+    let start_point = *points.first()?;
+    if let Some(last_curve) = curves.last_mut() {
+        let gap = last_curve.end_point.distance_to(&start_point);
+
+        if gap >= SMALL_DISTANCE {
+            let end_point = last_curve.end_point;
+            curves.push(Curve::from_points(
+                end_point,
+                (
+                    end_point + (start_point - end_point) * (1.0 / 3.0),
+                    end_point + (start_point - end_point) * (2.0 / 3.0),
+                ),
+                start_point,
+            ));
+        } else {
+            last_curve.end_point = start_point;
+        }
+    }
+
+    // Need at least two curves to enclose an area
+    if curves.len() < 2 {
+        return None;
+    }
+
+    let curves = curves.into_iter().map(|curve| {
+        (
+            curve.control_points.0,
+            curve.control_points.1,
+            curve.end_point,
+        )
+    });
+
+    Some(POut::from_points(start_point, curves))
+}
@@ -0,0 +1,128 @@
+use super::curve::{BezierCurve, BezierCurveFactory, Curve};
+use super::distort::sample_mapped_curve;
+use super::fit::fit_curve_cubic;
+use super::path::{BezierPath, BezierPathFactory};
+use crate::geo::{Coordinate, Coordinate2D, PerspectiveTransform2D};
+
+///
+/// Maps a direction at a point through a perspective transformation, returning a unit vector
+///
+fn transform_direction<Point: Coordinate + Coordinate2D>(
+    transform: &PerspectiveTransform2D,
+    point: &Point,
+    direction: &Point,
+) -> Option<Point> {
+    let [[a, b, c], [d, e, f], [g, h, i]] = transform.0;
+    let (x, y) = (point.x(), point.y());
+    let (dx, dy) = (direction.x(), direction.y());
+
+    // Derivative of (X/W, Y/W) in the direction (the common factor of 1/W^2 is dropped as this is normalized later)
+    let (px, py, w) = (a * x + b * y + c, d * x + e * y + f, g * x + h * y + i);
+    let dw = g * dx + h * dy;
+    let tangent = Point::from_components(&[
+        (a * dx + b * dy) * w - px * dw,
+        (d * dx + e * dy) * w - py * dw,
+    ]);
+
+    let length = tangent.magnitude();
+    if length > 0.0 && length.is_finite() {
+        Some(tangent * (1.0 / length))
+    } else {
+        None
+    }
+}
+
+///
+/// Returns the direction a curve is leaving its start point in, ignoring any control points that are on the start point
+///
+fn start_direction<Point: Coordinate>(start: Point, cp1: Point, cp2: Point, end: Point) -> Point {
+    [cp1, cp2, end]
+        .iter()
+        .map(|point| *point - start)
+        .find(|direction| direction.magnitude() > 0.0)
+        .unwrap_or(end - start)
+}
+
+///
+/// Applies a perspective transformation to a curve
+///
+/// Projective transformations don't map Bezier curves onto Bezier curves, so the transformed curve is sampled and refitted:
+/// the result is one or more curves that are within `max_error` of the true transformed curve. The tangents at the start and
+/// end of the curve are transformed exactly, so curves that join smoothly will still join smoothly after transformation.
+///
+/// Returns `None` if any of the control points of the curve are on or behind the horizon of the transformation.
+///
+pub fn perspective_transform_curve<CurveIn, CurveOut>(
+    curve: &CurveIn,
+    transform: &PerspectiveTransform2D,
+    max_error: f64,
+) -> Option<Vec<CurveOut>>
+where
+    CurveIn: BezierCurve,
+    CurveIn::Point: Coordinate2D,
+    CurveOut: BezierCurveFactory<Point = CurveIn::Point>,
+{
+    let start = curve.start_point();
+    let end = curve.end_point();
+    let (cp1, cp2) = curve.control_points();
+
+    // The curve is inside the hull of its control points, so it's entirely in front of the horizon if they are
+    for point in [start, cp1, cp2, end].iter() {
+        transform.transform_point(point)?;
+    }
+
+    let points = sample_mapped_curve(curve, |point| transform.transform_point(&point), max_error)?;
+
+    // Fall back to the direction between the samples if the curve has no direction at one of its ends
+    let start_tangent =
+        transform_direction(transform, &start, &start_direction(start, cp1, cp2, end))
+            .unwrap_or_else(|| (points[1] - points[0]).to_unit_vector());
+    let end_tangent = transform_direction(transform, &end, &start_direction(end, cp2, cp1, start))
+        .unwrap_or_else(|| (points[points.len() - 2] - points[points.len() - 1]).to_unit_vector());
+
+    Some(fit_curve_cubic(
+        &points,
+        &start_tangent,
+        &end_tangent,
+        max_error,
+    ))
+}
+
+///
+/// Applies a perspective transformation to a path
+///
+/// This can be used to map vector artwork onto an arbitrary quadrilateral: create the transformation using
+/// `PerspectiveTransform2D::bounds_to_quad()`. Each section of the path is sampled and refitted so the result is within
+/// `max_error` of the true transformed path, and the sections still join at the same points (and smooth joins remain
+/// smooth).
+///
+/// Returns `None` if any of the control points of the path are on or behind the horizon of the transformation.
+///
+pub fn perspective_transform_path<PathIn, PathOut>(
+    path: &PathIn,
+    transform: &PerspectiveTransform2D,
+    max_error: f64,
+) -> Option<PathOut>
+where
+    PathIn: BezierPath,
+    PathIn::Point: Coordinate2D,
+    PathOut: BezierPathFactory<Point = PathIn::Point>,
+{
+    let mut last_point = path.start_point();
+    let start_point = transform.transform_point(&last_point)?;
+    let mut new_points = vec![];
+
+    for (cp1, cp2, end_point) in path.points() {
+        let curve = Curve::from_points(last_point, (cp1, cp2), end_point);
+        let new_curves = perspective_transform_curve::<_, Curve<_>>(&curve, transform, max_error)?;
+
+        new_points.extend(new_curves.into_iter().map(|curve| {
+            let (cp1, cp2) = curve.control_points();
+            (cp1, cp2, curve.end_point())
+        }));
+
+        last_point = end_point;
+    }
+
+    Some(PathOut::from_points(start_point, new_points))
+}
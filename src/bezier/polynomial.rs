@@ -0,0 +1,119 @@
+use smallvec::SmallVec;
+
+/// Number of bisection steps used when refining a root (enough to reach the precision of an f64 in the range 0..1)
+const MAX_BISECTION_STEPS: usize = 64;
+
+/// Roots closer together than this are treated as the same root
+const ROOT_MERGE_DISTANCE: f64 = 1e-9;
+
+///
+/// Multiplies two polynomials (coefficients ordered from the constant term upwards)
+///
+pub(crate) fn poly_mul(p: &[f64], q: &[f64]) -> SmallVec<[f64; 7]> {
+    let mut result = SmallVec::from_elem(0.0, p.len() + q.len() - 1);
+
+    for (i, p) in p.iter().enumerate() {
+        for (j, q) in q.iter().enumerate() {
+            result[i + j] += p * q;
+        }
+    }
+
+    result
+}
+
+///
+/// Evaluates a polynomial (coefficients ordered from the constant term upwards)
+///
+#[inline]
+pub(crate) fn poly_eval(p: &[f64], t: f64) -> f64 {
+    p.iter()
+        .rev()
+        .fold(0.0, |total, coefficient| total * t + coefficient)
+}
+
+///
+/// Finds a root of a polynomial that's monotonic between `min` and `max` and has a different sign at each end
+///
+fn bisect_root(p: &[f64], min: f64, max: f64) -> f64 {
+    let (mut min, mut max) = (min, max);
+    let min_negative = poly_eval(p, min) < 0.0;
+
+    for _ in 0..MAX_BISECTION_STEPS {
+        let mid = (min + max) * 0.5;
+        if mid <= min || mid >= max {
+            break;
+        }
+
+        if (poly_eval(p, mid) < 0.0) == min_negative {
+            min = mid;
+        } else {
+            max = mid;
+        }
+    }
+
+    (min + max) * 0.5
+}
+
+///
+/// Finds the roots of a polynomial between `min` and `max`, including the points where it touches 0 without crossing it
+///
+/// The polynomial is split into monotonic sections at the roots of its derivative, so each section contains at most one
+/// crossing, which is found by bisection.
+///
+pub(crate) fn poly_roots_in_range(p: &[f64], min: f64, max: f64, zero: f64) -> SmallVec<[f64; 6]> {
+    // Remove any leading coefficients that are 0
+    let mut degree = p.len();
+    while degree > 0 && p[degree - 1] == 0.0 {
+        degree -= 1;
+    }
+    let p = &p[0..degree];
+
+    if degree <= 1 {
+        // Constants have no roots (or are 0 everywhere, where we treat them as having none)
+        return SmallVec::new();
+    }
+
+    // Split into monotonic sections at the roots of the derivative
+    let derivative = p
+        .iter()
+        .enumerate()
+        .skip(1)
+        .map(|(power, coefficient)| (power as f64) * coefficient)
+        .collect::<SmallVec<[f64; 6]>>();
+
+    let mut boundaries = SmallVec::<[f64; 8]>::new();
+    boundaries.push(min);
+    boundaries.extend(poly_roots_in_range(&derivative, min, max, 0.0));
+    boundaries.push(max);
+
+    let mut roots = SmallVec::<[f64; 6]>::new();
+    let add_root = |root: f64, roots: &mut SmallVec<[f64; 6]>| {
+        if roots
+            .last()
+            .map(|last| (root - last).abs() > ROOT_MERGE_DISTANCE)
+            .unwrap_or(true)
+        {
+            roots.push(root);
+        }
+    };
+
+    for (section_min, section_max) in boundaries.iter().zip(boundaries.iter().skip(1)) {
+        let (section_min, section_max) = (*section_min, *section_max);
+        let min_value = poly_eval(p, section_min);
+        let max_value = poly_eval(p, section_max);
+
+        if min_value.abs() <= zero {
+            // Crossing or touching at the start of the section
+            add_root(section_min, &mut roots);
+        } else if max_value.abs() > zero && (min_value < 0.0) != (max_value < 0.0) {
+            add_root(bisect_root(p, section_min, section_max), &mut roots);
+        }
+    }
+
+    // The end of the range is only checked as the start of a section above when it's not the end of the range
+    if poly_eval(p, max).abs() <= zero {
+        add_root(max, &mut roots);
+    }
+
+    roots
+}
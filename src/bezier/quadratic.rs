@@ -0,0 +1,65 @@
+use super::basis::de_casteljau3;
+use super::curve::BezierCurveFactory;
+use super::derivative::derivative3;
+use crate::geo::{Coordinate, Geo};
+
+///
+/// Represents a quadratic bezier curve (a curve with a single control point)
+///
+/// Quadratic curves are used by some file formats (TrueType glyph outlines, for example). The rest of the library works
+/// with cubic curves, so use `to_cubic()` to convert a quadratic curve into a curve that can be used with the other
+/// functions. `reduce_to_quadratic()` performs the reverse conversion, approximating a cubic curve with a quadratic one.
+///
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct QuadraticCurve<Coord: Coordinate> {
+    pub start_point: Coord,
+    pub control_point: Coord,
+    pub end_point: Coord,
+}
+
+impl<Coord: Coordinate> Geo for QuadraticCurve<Coord> {
+    type Point = Coord;
+}
+
+impl<Coord: Coordinate> QuadraticCurve<Coord> {
+    ///
+    /// Creates a new quadratic curve from its points
+    ///
+    #[inline]
+    pub fn from_points(start: Coord, control_point: Coord, end: Coord) -> Self {
+        Self {
+            start_point: start,
+            control_point,
+            end_point: end,
+        }
+    }
+
+    ///
+    /// Given a value t from 0 to 1, returns a point on this curve
+    ///
+    #[inline]
+    pub fn point_at_pos(&self, t: f64) -> Coord {
+        de_casteljau3(t, self.start_point, self.control_point, self.end_point)
+    }
+
+    ///
+    /// Converts this curve to the equivalent cubic curve
+    ///
+    /// Every quadratic curve can be represented exactly as a cubic curve, with the control points two thirds of the way
+    /// from each end point to the quadratic control point.
+    ///
+    pub fn to_cubic<Curve: BezierCurveFactory<Point = Coord>>(&self) -> Curve {
+        let cp1 = self.start_point + (self.control_point - self.start_point) * (2.0 / 3.0);
+        let cp2 = self.end_point + (self.control_point - self.end_point) * (2.0 / 3.0);
+
+        Curve::from_points(self.start_point, (cp1, cp2), self.end_point)
+    }
+
+    ///
+    /// Returns the derivative of this curve as a line (from the derivative at t=0 to the derivative at t=1)
+    ///
+    #[inline]
+    pub fn derivative(&self) -> (Coord, Coord) {
+        derivative3(self.start_point, self.control_point, self.end_point)
+    }
+}
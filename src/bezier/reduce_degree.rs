@@ -0,0 +1,70 @@
+use super::curve::{BezierCurve, Curve};
+use super::nearest::distance_to_curve;
+use super::offset_error::distance_to_segment;
+use super::quadratic::QuadraticCurve;
+use crate::geo::Coordinate2D;
+
+/// Number of points used to measure the error of a lower-degree approximation
+const NUM_ERROR_SAMPLES: usize = 32;
+
+///
+/// Finds the quadratic curve that best approximates a cubic curve, returning the curve and the largest distance between
+/// it and the original curve
+///
+/// The quadratic curve has the same start and end points as the original curve, so a series of connected curves will
+/// still be connected after they have been reduced. The control point is the one that minimises the squared distance
+/// between the two curves over their whole length, which works out as `(3*(cp1+cp2) - (start+end))/4`. The error is
+/// measured by sampling both curves and finding the distance to the nearest point on the other curve, so it's the
+/// distance between the shapes rather than how far apart the points at each t value are.
+///
+/// Curves that are already quadratic (where the control points are 2/3 of the way to a shared point) are reduced
+/// exactly, with an error of 0. Curves with loops or inflection points can't be represented well by a single quadratic
+/// curve, and will have a large error: such curves need to be subdivided first.
+///
+pub fn reduce_to_quadratic<C: BezierCurve>(curve: &C) -> (QuadraticCurve<C::Point>, f64)
+where
+    C::Point: Coordinate2D,
+{
+    let start = curve.start_point();
+    let (cp1, cp2) = curve.control_points();
+    let end = curve.end_point();
+
+    let control_point = ((cp1 + cp2) * 3.0 - (start + end)) * 0.25;
+    let quadratic = QuadraticCurve::from_points(start, control_point, end);
+
+    // Measure the distance in both directions
+    let as_cubic = quadratic.to_cubic::<Curve<_>>();
+    let error = (0..=NUM_ERROR_SAMPLES)
+        .map(|idx| (idx as f64) / (NUM_ERROR_SAMPLES as f64))
+        .map(|t| {
+            let from_original = distance_to_curve(&as_cubic, &curve.point_at_pos(t));
+            let from_quadratic = distance_to_curve(curve, &quadratic.point_at_pos(t));
+
+            from_original.max(from_quadratic)
+        })
+        .fold(0.0, f64::max);
+
+    (quadratic, error)
+}
+
+///
+/// Finds the line that best approximates a curve, returning the line and the largest distance between it and the
+/// original curve
+///
+/// The line runs between the start and end points of the curve, so a series of connected curves will still be connected
+/// after they have been reduced. The error is the largest distance from the curve to the line.
+///
+pub fn reduce_to_line<C: BezierCurve>(curve: &C) -> ((C::Point, C::Point), f64)
+where
+    C::Point: Coordinate2D,
+{
+    let line = (curve.start_point(), curve.end_point());
+
+    // Every point on the line is level with a point on the curve, so only the distance from the curve to the line is needed
+    let error = (0..=NUM_ERROR_SAMPLES)
+        .map(|idx| curve.point_at_pos((idx as f64) / (NUM_ERROR_SAMPLES as f64)))
+        .map(|point| distance_to_segment(&point, (&line.0, &line.1)))
+        .fold(0.0, f64::max);
+
+    (line, error)
+}
@@ -42,11 +42,34 @@ impl<'a, C: 'a + BezierCurve> CurveSection<'a, C> {
     ///
     /// Returns the t value on the full curve for a t value on the section
     ///
+    /// This is the same as `original_t_for_section_t()`
+    ///
     #[inline]
     pub fn t_for_t(&self, t: f64) -> f64 {
         t * self.t_m + self.t_c
     }
 
+    ///
+    /// Given a 't' value on this section, returns the equivalent value on the original curve
+    ///
+    /// This is the inverse of `section_t_for_original_t()`. The original curve is the curve returned by
+    /// `original_curve()`: for a section of a section (created by calling `section()` on a `CurveSection`), this
+    /// is the outer section and not the curve that it was created from. Use `subsection()` or `flatten()` to get a
+    /// section where the t values are for the underlying curve instead.
+    ///
+    #[inline]
+    pub fn original_t_for_section_t(&self, t: f64) -> f64 {
+        self.t_for_t(t)
+    }
+
+    ///
+    /// Returns the curve that this is a section of
+    ///
+    #[inline]
+    pub fn original_curve(&self) -> &'a C {
+        self.curve
+    }
+
     ///
     /// Returns true if this section is so small as to represent a point
     ///
@@ -66,6 +89,9 @@ impl<'a, C: 'a + BezierCurve> CurveSection<'a, C> {
     /// that `original_curve_t_values` will return the coordinates for the same
     /// original curve as the curve that this subsection was created from.
     ///
+    /// The t values are relative to this section, so `subsection(0.0, 1.0)` is the
+    /// same as this section.
+    ///
     pub fn subsection(&self, t_min: f64, t_max: f64) -> CurveSection<'a, C> {
         CurveSection::new(self.curve, self.t_for_t(t_min), self.t_for_t(t_max))
     }
@@ -81,12 +107,31 @@ impl<'a, C: 'a + BezierCurve> CurveSection<'a, C> {
     ///
     /// Given a 't' value on the original curve, returns the equivalent value on this section
     ///
+    /// This is the inverse of `original_t_for_section_t()`. Values outside of the range 0 to 1 are for points on the
+    /// original curve that are before or after this section.
+    ///
     #[inline]
     pub fn section_t_for_original_t(&self, t: f64) -> f64 {
         (t - self.t_c) / self.t_m
     }
 }
 
+impl<'a, 'b, C: 'b + BezierCurve> CurveSection<'a, CurveSection<'b, C>> {
+    ///
+    /// Converts a section of a section into a section of the underlying curve
+    ///
+    /// Calling `section()` on a `CurveSection` creates a section whose t values are relative to the outer section. The
+    /// flattened version covers the same part of the curve, but its `original_curve_t_values()` and
+    /// `original_t_for_section_t()` are for the curve that the outer section was created from, which is usually what's
+    /// needed when mapping results (such as intersections) back to the original curve.
+    ///
+    pub fn flatten(&self) -> CurveSection<'b, C> {
+        let (t_min, t_max) = self.original_curve_t_values();
+
+        self.curve.subsection(t_min, t_max)
+    }
+}
+
 impl<'a, C: 'a + BezierCurve> Geo for CurveSection<'a, C> {
     type Point = C::Point;
 }
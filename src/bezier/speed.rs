@@ -0,0 +1,148 @@
+use super::curve::{BezierCurve, BezierCurveFactory};
+use super::length::{curve_length, derivative_magnitude_at, t_for_length};
+use super::polynomial::{poly_mul, poly_roots_in_range};
+use crate::geo::Coordinate;
+
+/// The error to allow when measuring the lengths of curves to reparameterise them
+const ARC_LENGTH_ERROR: f64 = 1e-6;
+
+///
+/// The slowest and fastest points on a curve, as returned by `curve_speed_range()`
+///
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct CurveSpeedRange {
+    /// The t value where the curve is moving slowest
+    pub min_t: f64,
+
+    /// The speed (magnitude of the derivative) at the slowest point
+    pub min_speed: f64,
+
+    /// The t value where the curve is moving fastest
+    pub max_t: f64,
+
+    /// The speed (magnitude of the derivative) at the fastest point
+    pub max_speed: f64,
+}
+
+impl CurveSpeedRange {
+    ///
+    /// The ratio between the fastest and slowest speeds along the curve
+    ///
+    /// This is 1 for a curve that moves at a constant speed, and gets larger as the parameterisation gets less even. It's
+    /// infinite for curves that stop somewhere (at a cusp, or where a control point is on top of an end point).
+    ///
+    pub fn ratio(&self) -> f64 {
+        if self.min_speed <= 0.0 {
+            f64::INFINITY
+        } else {
+            self.max_speed / self.min_speed
+        }
+    }
+}
+
+///
+/// Finds where a curve is moving slowest and fastest as t goes from 0 to 1
+///
+/// Curves with a large ratio between these speeds are badly parameterised: equal steps in t will produce very unevenly
+/// spaced points along the curve, which is usually a sign that the control points are bunched up at one end. Such curves
+/// can be replaced with curves that move at a more even speed using `reparameterise_by_arc_length()`.
+///
+pub fn curve_speed_range<C: BezierCurve>(curve: &C) -> CurveSpeedRange {
+    let derivative = curve.derivative();
+    let (d1, d2, d3) = (
+        derivative.start_point,
+        derivative.control_point,
+        derivative.end_point,
+    );
+
+    // The speed is at a minimum or maximum where the derivative of the speed squared (2 * D(t) . D'(t)) is 0
+    let mut speed_squared_derivative = [0.0; 4];
+    for component in 0..C::Point::len() {
+        let (w1, w2, w3) = (d1.get(component), d2.get(component), d3.get(component));
+        let velocity = [w1, 2.0 * (w2 - w1), w1 - 2.0 * w2 + w3];
+        let acceleration = [2.0 * (w2 - w1), 2.0 * (w1 - 2.0 * w2 + w3)];
+
+        for (total, coefficient) in speed_squared_derivative
+            .iter_mut()
+            .zip(poly_mul(&velocity, &acceleration))
+        {
+            *total += coefficient;
+        }
+    }
+
+    let mut candidates = poly_roots_in_range(&speed_squared_derivative, 0.0, 1.0, 0.0);
+    candidates.push(0.0);
+    candidates.push(1.0);
+
+    let speeds = candidates
+        .into_iter()
+        .map(|t| (t, derivative_magnitude_at(curve, t)))
+        .collect::<Vec<_>>();
+
+    let (min_t, min_speed) =
+        speeds.iter().copied().fold(
+            (0.0, f64::MAX),
+            |min, speed| if speed.1 < min.1 { speed } else { min },
+        );
+    let (max_t, max_speed) =
+        speeds.iter().copied().fold(
+            (0.0, -1.0),
+            |max, speed| if speed.1 > max.1 { speed } else { max },
+        );
+
+    CurveSpeedRange {
+        min_t,
+        min_speed,
+        max_t,
+        max_speed,
+    }
+}
+
+///
+/// Replaces a curve with a series of curves that follow it at a more even speed
+///
+/// The curve is divided into `segments` sections of equal length. Each section is replaced by a curve with the same end
+/// points and the same tangent directions, whose speed at either end is the length of the section, so every section
+/// starts and ends at the same speed. More segments produce a result that follows the original curve more closely and
+/// at a more even speed.
+///
+pub fn reparameterise_by_arc_length<C, CurveOut>(curve: &C, segments: usize) -> Vec<CurveOut>
+where
+    C: BezierCurve,
+    CurveOut: BezierCurveFactory<Point = C::Point>,
+{
+    let segments = segments.max(1);
+    let total_length = curve_length(curve, ARC_LENGTH_ERROR);
+    let segment_length = total_length / (segments as f64);
+
+    // The velocity at each point where the curve is divided, scaled so the speed matches the segment length
+    let velocity_at = |t: f64| {
+        let tangent = curve.derivative().point_at_pos(t);
+        let speed = tangent.magnitude();
+
+        if speed > 0.0 {
+            tangent * (segment_length / speed)
+        } else {
+            C::Point::origin()
+        }
+    };
+
+    let split_points = (0..=segments)
+        .map(|idx| match idx {
+            0 => 0.0,
+            idx if idx == segments => 1.0,
+            idx => t_for_length(
+                curve,
+                segment_length * (idx as f64),
+                total_length,
+                ARC_LENGTH_ERROR,
+            ),
+        })
+        .map(|t| (curve.point_at_pos(t), velocity_at(t)))
+        .collect::<Vec<_>>();
+
+    split_points
+        .windows(2)
+        .map(|ends| CurveOut::from_hermite(ends[0].0, ends[0].1, ends[1].0, ends[1].1))
+        .collect()
+}
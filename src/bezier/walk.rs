@@ -85,6 +85,102 @@ pub fn walk_curve_evenly<Curve: BezierCurve>(
     }
 }
 
+///
+/// Walks a bezier curve by dividing it into sections that are close to straight lines
+///
+/// Each section deviates from its chord by no more than `max_error`, and turns through no more than `max_angle` (in
+/// radians). Sections are made shorter where the curve bends sharply and longer where it's flat, so this is a good way to
+/// generate the points for a mesh: limiting the error alone can leave visible facets on tight bends, where a short section
+/// turns through a large angle. Both limits are estimated from the control polygon of each section, so they are never
+/// exceeded, but the curve may be divided a little more than is strictly necessary.
+///
+/// Sections near a cusp may still turn by more than `max_angle`, as the direction changes suddenly there.
+///
+pub fn walk_curve_adaptively<Curve: BezierCurve>(
+    curve: &Curve,
+    max_error: f64,
+    max_angle: f64,
+) -> impl Iterator<Item = CurveSection<'_, Curve>> {
+    const MAX_DEPTH: usize = 16;
+
+    let max_error = max_error.abs();
+    let max_angle = max_angle.abs();
+
+    // Divide the curve until every section is within the limits, working from the start of the curve
+    let mut sections = vec![];
+    let mut waiting = vec![(0.0, 1.0, 0)];
+
+    while let Some((t_min, t_max, depth)) = waiting.pop() {
+        let section = curve.section(t_min, t_max);
+
+        if depth >= MAX_DEPTH
+            || (chord_deviation(&section) <= max_error
+                && control_polygon_turn(&section) <= max_angle)
+        {
+            sections.push(section);
+        } else {
+            let t_mid = (t_min + t_max) * 0.5;
+
+            waiting.push((t_mid, t_max, depth + 1));
+            waiting.push((t_min, t_mid, depth + 1));
+        }
+    }
+
+    sections.into_iter()
+}
+
+///
+/// Returns the largest distance from the control points of a curve to its chord (the curve is never further from its chord
+/// than this)
+///
+fn chord_deviation<Curve: BezierCurve>(curve: &Curve) -> f64 {
+    let start_point = curve.start_point();
+    let end_point = curve.end_point();
+    let (cp1, cp2) = curve.control_points();
+
+    let chord = end_point - start_point;
+    let chord_length_sq = chord.dot(&chord);
+
+    [cp1, cp2]
+        .iter()
+        .map(|point| {
+            if chord_length_sq <= 0.0 {
+                return point.distance_to(&start_point);
+            }
+
+            let offset = *point - start_point;
+            let t = offset.dot(&chord) / chord_length_sq;
+            let t = t.clamp(0.0, 1.0);
+
+            point.distance_to(&(start_point + chord * t))
+        })
+        .fold(0.0, f64::max)
+}
+
+///
+/// Returns the angle that the control polygon of a curve turns through (the curve never turns through more than this)
+///
+fn control_polygon_turn<Curve: BezierCurve>(curve: &Curve) -> f64 {
+    let (cp1, cp2) = curve.control_points();
+    let legs = [
+        cp1 - curve.start_point(),
+        cp2 - cp1,
+        curve.end_point() - cp2,
+    ];
+
+    // Legs with no length don't have a direction, so skip over them
+    let directions = legs
+        .iter()
+        .filter(|leg| leg.magnitude() > 0.0)
+        .map(|leg| leg.to_unit_vector())
+        .collect::<Vec<_>>();
+
+    directions
+        .windows(2)
+        .map(|pair| pair[0].dot(&pair[1]).clamp(-1.0, 1.0).acos())
+        .sum()
+}
+
 ///
 /// Iterator implemenation that performs an uneven walk along a curve
 ///
@@ -0,0 +1,196 @@
+use super::basis::{de_casteljau2, de_casteljau3, de_casteljau4};
+use super::curve::BezierCurve;
+use super::derivative::{derivative3, derivative4};
+use crate::geo::{Coordinate, Coordinate2D};
+
+// Moving each point of a curve a distance `d` along its normal (the tangent rotated anticlockwise) produces an offset
+// curve that moves at `1 - d*k` times the speed of the original, where `k` is the signed curvature. The length of the
+// offset curve is the integral of `|p'| * |1 - d*k|`, which works out as `|p'| - d * (p' x p'') / |p'|^2`. This is
+// integrated over a table of steps in t, so the offset never needs to be approximated with new curves.
+
+/// Number of steps to start with when building the table of lengths along the offset curve
+const INITIAL_STEPS: usize = 16;
+
+/// Maximum number of steps in the table of lengths along the offset curve
+const MAX_STEPS: usize = 4096;
+
+/// Number of iterations used to find the t value at a particular distance along the offset curve
+const LENGTH_SEARCH_ITERATIONS: usize = 32;
+
+/// Abscissae and weights for 5-point Gauss-Legendre quadrature over `-1..1`
+const GAUSS_LEGENDRE_5: [(f64, f64); 5] = [
+    (-0.906179845938664, 0.236926885056189),
+    (-0.538469310105683, 0.478628670499366),
+    (0.0, 0.568888888888889),
+    (0.538469310105683, 0.478628670499366),
+    (0.906179845938664, 0.236926885056189),
+];
+
+///
+/// A point on the offset of a curve, as generated by `walk_offset_curve_evenly()`
+///
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct OffsetFrame<Point> {
+    /// The t value of the point on the original curve that this point is offset from
+    pub t: f64,
+
+    /// The position of the point on the offset curve
+    pub position: Point,
+
+    /// Unit vector in the direction that the offset curve is moving in
+    pub tangent: Point,
+
+    /// Unit vector at right angles to the offset curve (the tangent rotated anticlockwise by 90 degrees)
+    pub normal: Point,
+}
+
+///
+/// Walks the offset of a curve at distance `offset`, returning a frame every `distance` units along the offset curve
+///
+/// The offset curve is the original curve with every point moved `offset` units along its normal (so positive offsets
+/// are to the left of the curve). Distances are measured along the offset curve itself, to within `max_error`, without
+/// generating the offset as a new set of curves: this is useful for placing stitches or decorations at a fixed distance
+/// from a line, where offsetting the curve and then walking the result would add the error from both approximations.
+///
+/// The first frame is at the start of the curve, and frames continue until the end of the offset curve is reached. Where
+/// the offset is larger than the radius of a bend, the offset curve runs backwards for a while (forming a loop): the
+/// backwards part is walked too, with the tangent of the frames pointing backwards along the original curve.
+///
+pub fn walk_offset_curve_evenly<Curve: BezierCurve>(
+    curve: &Curve,
+    offset: f64,
+    distance: f64,
+    max_error: f64,
+) -> impl Iterator<Item = OffsetFrame<Curve::Point>>
+where
+    Curve::Point: Coordinate2D,
+{
+    // Too small or negative values might produce bad effects due to floating point inprecision
+    let max_error = if max_error < 1e-10 { 1e-10 } else { max_error };
+    let distance = if distance < 1e-10 { 1e-10 } else { distance };
+
+    let w1 = curve.start_point();
+    let (w2, w3) = curve.control_points();
+    let w4 = curve.end_point();
+    let (d1, d2, d3) = derivative4(w1, w2, w3, w4);
+    let (dd1, dd2) = derivative3(d1, d2, d3);
+
+    // The speed that the offset point moves at for a particular t value (negative where it's moving backwards)
+    let signed_speed = move |t: f64| {
+        let first = de_casteljau3(t, d1, d2, d3);
+        let second = de_casteljau2(t, dd1, dd2);
+        let speed_squared = first.dot(&first);
+
+        if speed_squared <= 0.0 {
+            0.0
+        } else {
+            let cross = first.x() * second.y() - first.y() * second.x();
+            speed_squared.sqrt() - offset * cross / speed_squared
+        }
+    };
+
+    // The length of the offset between two t values
+    let length_between = move |t1: f64, t2: f64| {
+        let half_width = (t2 - t1) * 0.5;
+        let mid = (t1 + t2) * 0.5;
+
+        GAUSS_LEGENDRE_5
+            .iter()
+            .map(|(x, weight)| weight * signed_speed(mid + x * half_width).abs())
+            .sum::<f64>()
+            * half_width
+    };
+
+    // Build a table of lengths, dividing the curve further until the total length stops changing
+    let build_table = |num_steps: usize| {
+        let mut table = Vec::with_capacity(num_steps + 1);
+        let mut total = 0.0;
+        table.push(0.0);
+
+        for step in 0..num_steps {
+            let t1 = (step as f64) / (num_steps as f64);
+            let t2 = ((step + 1) as f64) / (num_steps as f64);
+            total += length_between(t1, t2);
+            table.push(total);
+        }
+
+        table
+    };
+
+    let mut table = build_table(INITIAL_STEPS);
+    while table.len() - 1 < MAX_STEPS {
+        let finer_table = build_table((table.len() - 1) * 2);
+        let converged =
+            (finer_table[finer_table.len() - 1] - table[table.len() - 1]).abs() <= max_error * 0.1;
+        table = finer_table;
+
+        if converged {
+            break;
+        }
+    }
+
+    let num_steps = table.len() - 1;
+    let total_length = table[num_steps];
+    let mut next_point = 0;
+
+    std::iter::from_fn(move || {
+        // Allow for a small amount of floating point error when deciding if the last point is at the end of the curve
+        let target = (next_point as f64) * distance;
+        if target > total_length + max_error {
+            return None;
+        }
+        next_point += 1;
+
+        // Find the step containing the target distance, then search for the t value within it
+        let step = table
+            .iter()
+            .skip(1)
+            .position(|length| *length >= target)
+            .unwrap_or(num_steps - 1);
+        let t_min = (step as f64) / (num_steps as f64);
+        let t_max = ((step + 1) as f64) / (num_steps as f64);
+        let remaining = target - table[step];
+
+        let t = if target <= 0.0 {
+            0.0
+        } else if target >= total_length {
+            1.0
+        } else {
+            let (mut low, mut high) = (t_min, t_max);
+            for _ in 0..LENGTH_SEARCH_ITERATIONS {
+                let mid = (low + high) * 0.5;
+
+                if length_between(t_min, mid) < remaining {
+                    low = mid;
+                } else {
+                    high = mid;
+                }
+            }
+
+            (low + high) * 0.5
+        };
+
+        // Work out the frame at this point: the offset curve is moving backwards where the curvature is too high
+        let point = de_casteljau4(t, w1, w2, w3, w4);
+        let first = de_casteljau3(t, d1, d2, d3);
+        let curve_tangent = if first.magnitude() > 0.0 {
+            first.to_unit_vector()
+        } else {
+            (w4 - w1).to_unit_vector()
+        };
+        let curve_normal = Curve::Point::from_components(&[-curve_tangent.y(), curve_tangent.x()]);
+        let is_backwards = signed_speed(t) < 0.0;
+        let tangent = if is_backwards {
+            curve_tangent * -1.0
+        } else {
+            curve_tangent
+        };
+
+        Some(OffsetFrame {
+            t,
+            position: point + curve_normal * offset,
+            tangent,
+            normal: Curve::Point::from_components(&[-tangent.y(), tangent.x()]),
+        })
+    })
+}
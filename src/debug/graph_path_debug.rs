@@ -5,6 +5,51 @@ use super::super::bezier::{
 
 use std::fmt::Write;
 
+///
+/// The size of the larger side of the area that a graph path is scaled to fit when it is written as an SVG
+///
+const SVG_DEBUG_SIZE: f64 = 1000.0;
+
+///
+/// Returns the stroke colour used for an edge of a particular kind when writing a graph path as an SVG
+///
+pub(crate) fn svg_edge_colour(kind: GraphPathEdgeKind) -> &'static str {
+    match kind {
+        GraphPathEdgeKind::Uncategorised => "yellow",
+        GraphPathEdgeKind::Visited => "red",
+        GraphPathEdgeKind::Exterior => "blue",
+        GraphPathEdgeKind::Interior => "green",
+    }
+}
+
+///
+/// Returns the offset and scale factor that will fit a graph path into the SVG debug area
+///
+/// Positions in the graph are mapped to the SVG as `(pos - offset) * scale`
+///
+pub(crate) fn svg_transform<P: Coordinate + Coordinate2D, Label: Copy>(
+    path: &GraphPath<P, Label>,
+) -> (P, f64) {
+    let bounds = path
+        .all_edges()
+        .fold(Bounds::empty(), |a, b| a.union_bounds(b.bounding_box()));
+    let bounds = (0..path.num_points())
+        .map(|point_idx| path.point_position(point_idx))
+        .fold(bounds, |a, pos| {
+            a.union_bounds(Bounds::from_min_max(pos, pos))
+        });
+
+    let size = bounds.max() - bounds.min();
+    let size = size.x().max(size.y());
+    let scale = if size.is_finite() && size > 0.0 {
+        SVG_DEBUG_SIZE / size
+    } else {
+        1.0
+    };
+
+    (bounds.min(), scale)
+}
+
 ///
 /// Writes out the graph path as an SVG string
 ///
@@ -14,11 +59,7 @@ pub fn graph_path_svg_string<P: Coordinate + Coordinate2D>(
 ) -> String {
     let mut result = String::new();
 
-    let bounds = path
-        .all_edges()
-        .fold(Bounds::empty(), |a, b| a.union_bounds(b.bounding_box()));
-    let offset = bounds.min();
-    let scale = 1000.0 / (bounds.max() - bounds.min()).x();
+    let (offset, scale) = svg_transform(path);
 
     let mut index = 0;
 
@@ -51,12 +92,7 @@ pub fn graph_path_svg_string<P: Coordinate + Coordinate2D>(
             let cp1 = (cp1 - offset) * scale;
             let cp2 = (cp2 - offset) * scale;
 
-            let kind = match edge.kind() {
-                GraphPathEdgeKind::Uncategorised => "yellow",
-                GraphPathEdgeKind::Visited => "red",
-                GraphPathEdgeKind::Exterior => "blue",
-                GraphPathEdgeKind::Interior => "green",
-            };
+            let kind = svg_edge_colour(edge.kind());
 
             writeln!(result, "<path d=\"M {} {} C {} {}, {} {}, {} {}\" fill=\"transparent\" stroke-width=\"1\" stroke=\"{}\" />",
                 start_point.x(), start_point.y(),
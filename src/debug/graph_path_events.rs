@@ -0,0 +1,120 @@
+use super::super::bezier::path::{GraphEdgeRef, GraphPathEdgeKind};
+
+use std::cell::RefCell;
+use std::ops::Range;
+use std::rc::Rc;
+
+///
+/// An event generated while colliding or classifying the edges of a `GraphPath`
+///
+/// These are generated by `collide()`, `self_collide()` and the ray casting functions (and so by all the path arithmetic
+/// functions) when the `instrumentation` feature is enabled. Use `trace_graph_path_events()` to receive them.
+///
+#[derive(Clone, Debug, PartialEq)]
+pub enum GraphPathEvent {
+    /// Started searching for collisions between the edges of two ranges of points
+    DetectingCollisions {
+        collide_from: Range<usize>,
+        collide_to: Range<usize>,
+    },
+
+    /// Found a place where two edges collide (at the specified t values along each edge)
+    CollisionFound {
+        edge1: GraphEdgeRef,
+        t1: f64,
+        edge2: GraphEdgeRef,
+        t2: f64,
+    },
+
+    /// Subdivided an edge at a collision: the t value is relative to the whole of the original edge, and the end point
+    /// is the index of the point where the new section ends
+    EdgeSubdivided {
+        edge: GraphEdgeRef,
+        t: f64,
+        end_point_idx: usize,
+    },
+
+    /// Cast a ray to classify an edge, crossing the graph at the specified number of places
+    RayCast {
+        edge: GraphEdgeRef,
+        ray: ((f64, f64), (f64, f64)),
+        num_collisions: usize,
+    },
+
+    /// Set the kind of an edge (and the edges connected to it) from the results of a ray cast
+    EdgeClassified {
+        edge: GraphEdgeRef,
+        kind: GraphPathEdgeKind,
+    },
+}
+
+/// A function that receives graph path events
+type EventTracer = Box<dyn FnMut(&GraphPathEvent)>;
+
+thread_local! {
+    /// The function that is currently receiving graph path events on this thread
+    static EVENT_TRACER: RefCell<Option<EventTracer>> = RefCell::new(None);
+}
+
+///
+/// Restores the previous event tracer when dropped (so the tracer is removed even if the action panics)
+///
+struct RestoreTracer(Option<EventTracer>);
+
+impl Drop for RestoreTracer {
+    fn drop(&mut self) {
+        let previous = self.0.take();
+        EVENT_TRACER.with(|tracer| *tracer.borrow_mut() = previous);
+    }
+}
+
+///
+/// Performs an action, calling `on_event` for every graph path event generated on this thread while it runs
+///
+/// Events are delivered as they happen, so a tracer that writes them out will show what happened before a failure even
+/// if the action panics. Calls to this function can be nested: the innermost tracer receives the events, and the outer
+/// one is restored once the action is complete. Events are only generated when the `instrumentation` feature is
+/// enabled.
+///
+pub fn trace_graph_path_events<TResult>(
+    on_event: impl 'static + FnMut(&GraphPathEvent),
+    action: impl FnOnce() -> TResult,
+) -> TResult {
+    let previous = EVENT_TRACER.with(|tracer| tracer.borrow_mut().replace(Box::new(on_event)));
+    let _restore = RestoreTracer(previous);
+
+    action()
+}
+
+///
+/// Performs an action, returning its result along with the graph path events that it generated
+///
+pub fn record_graph_path_events<TResult>(
+    action: impl FnOnce() -> TResult,
+) -> (TResult, Vec<GraphPathEvent>) {
+    let events = Rc::new(RefCell::new(vec![]));
+    let recorded = Rc::clone(&events);
+
+    let result = trace_graph_path_events(
+        move |event| recorded.borrow_mut().push(event.clone()),
+        action,
+    );
+
+    let events = events.replace(vec![]);
+    (result, events)
+}
+
+///
+/// Sends an event to the current tracer, if there is one
+///
+/// The event is only generated if something is listening for it. Events generated by the tracer itself are discarded.
+///
+pub(crate) fn emit_graph_path_event(event: impl FnOnce() -> GraphPathEvent) {
+    EVENT_TRACER.with(|tracer| {
+        if let Ok(mut tracer) = tracer.try_borrow_mut() {
+            if let Some(on_event) = tracer.as_mut() {
+                on_event(&event());
+            }
+        }
+    });
+}
@@ -1,5 +1,9 @@
 mod graph_path_debug;
+#[cfg(feature = "instrumentation")]
+mod graph_path_events;
 mod path_to_string;
 
 pub use self::graph_path_debug::*;
+#[cfg(feature = "instrumentation")]
+pub use self::graph_path_events::*;
 pub use self::path_to_string::*;
@@ -0,0 +1,100 @@
+use super::{BoundingBox, Bounds, Coordinate};
+
+///
+/// Trait implemented by types that can be compared to within a tolerance
+///
+/// The comparison is made point by point: two items are approximately equal if they're made up of the same number of
+/// points and each point is within `tolerance` of the corresponding point in the other item. Two curves that have the
+/// same shape but that are described by different points (such as a curve and its reverse) are not approximately equal.
+///
+pub trait ApproxEq {
+    ///
+    /// True if this item is within `tolerance` of another item
+    ///
+    fn approx_eq(&self, other: &Self, tolerance: f64) -> bool;
+}
+
+///
+/// Trait implemented by types that can generate a hashable key with their coordinates snapped to a grid
+///
+/// Items with the same key are approximately equal, so this is useful for caching the results of operations on paths
+/// that might be regenerated with tiny differences in their coordinates. Two points that are closer than `quantum` can
+/// still be snapped to different grid squares if they're either side of a grid line, so items that are approximately
+/// equal don't always have the same key.
+///
+pub trait QuantisedHash {
+    ///
+    /// Adds the coordinates of this item, divided by `quantum` and rounded, to a list of values
+    ///
+    fn quantised_components(&self, quantum: f64, components: &mut Vec<i64>);
+
+    ///
+    /// Returns a key for this item that can be hashed and compared for equality, with the coordinates snapped to a grid
+    /// of size `quantum`
+    ///
+    fn quantised_key(&self, quantum: f64) -> QuantisedKey {
+        let mut components = vec![];
+        self.quantised_components(quantum, &mut components);
+
+        QuantisedKey(components)
+    }
+}
+
+///
+/// A key generated by `QuantisedHash::quantised_key()`, which can be used as the key of a `HashMap`
+///
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct QuantisedKey(Vec<i64>);
+
+impl<Point: Coordinate> ApproxEq for Point {
+    #[inline]
+    fn approx_eq(&self, other: &Self, tolerance: f64) -> bool {
+        self.is_near_to(other, tolerance)
+    }
+}
+
+impl<Point: Coordinate> QuantisedHash for Point {
+    fn quantised_components(&self, quantum: f64, components: &mut Vec<i64>) {
+        for component_idx in 0..Point::len() {
+            components.push((self.get(component_idx) / quantum).round() as i64);
+        }
+    }
+}
+
+impl<Point: Coordinate> ApproxEq for Bounds<Point> {
+    #[inline]
+    fn approx_eq(&self, other: &Self, tolerance: f64) -> bool {
+        self.min().approx_eq(&other.min(), tolerance)
+            && self.max().approx_eq(&other.max(), tolerance)
+    }
+}
+
+impl<Point: Coordinate> QuantisedHash for Bounds<Point> {
+    fn quantised_components(&self, quantum: f64, components: &mut Vec<i64>) {
+        self.min().quantised_components(quantum, components);
+        self.max().quantised_components(quantum, components);
+    }
+}
+
+impl<T: ApproxEq> ApproxEq for Vec<T> {
+    fn approx_eq(&self, other: &Self, tolerance: f64) -> bool {
+        self.len() == other.len()
+            && self
+                .iter()
+                .zip(other.iter())
+                .all(|(a, b)| a.approx_eq(b, tolerance))
+    }
+}
+
+impl<T: QuantisedHash> QuantisedHash for Vec<T> {
+    fn quantised_components(&self, quantum: f64, components: &mut Vec<i64>) {
+        // Include the length of each item so that lists of items with different lengths can't produce the same key
+        for item in self.iter() {
+            let start_idx = components.len();
+            components.push(0);
+
+            item.quantised_components(quantum, components);
+            components[start_idx] = (components.len() - start_idx - 1) as i64;
+        }
+    }
+}
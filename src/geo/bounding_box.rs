@@ -77,6 +77,86 @@ pub trait BoundingBox: Geo + Sized {
         }
     }
 
+    ///
+    /// Creates the union of a set of bounding boxes
+    ///
+    fn union_all<BoundsIter: IntoIterator<Item = Self>>(bounds: BoundsIter) -> Self {
+        bounds
+            .into_iter()
+            .fold(Self::empty(), |union, bounds| union.union_bounds(bounds))
+    }
+
+    ///
+    /// Returns the region where this bounding box and another overlap, or `None` if they do not overlap
+    ///
+    fn intersect(&self, target: &Self) -> Option<Self> {
+        if self.overlaps(target) {
+            Some(Self::from_min_max(
+                Self::Point::from_biggest_components(self.min(), target.min()),
+                Self::Point::from_smallest_components(self.max(), target.max()),
+            ))
+        } else {
+            None
+        }
+    }
+
+    ///
+    /// Returns a bounding box that's larger than this one by the specified distance on every side
+    ///
+    /// Negative distances will shrink the bounding box (to no less than a single point at its center)
+    ///
+    fn inflate(&self, distance: f64) -> Self {
+        let (min, max) = (self.min(), self.max());
+        let mut new_min = vec![0.0; Self::Point::len()];
+        let mut new_max = vec![0.0; Self::Point::len()];
+
+        for p_index in 0..Self::Point::len() {
+            let (min_c, max_c) = (min.get(p_index) - distance, max.get(p_index) + distance);
+
+            if min_c <= max_c {
+                new_min[p_index] = min_c;
+                new_max[p_index] = max_c;
+            } else {
+                let center = (min.get(p_index) + max.get(p_index)) * 0.5;
+                new_min[p_index] = center;
+                new_max[p_index] = center;
+            }
+        }
+
+        Self::from_min_max(
+            Self::Point::from_components(&new_min),
+            Self::Point::from_components(&new_max),
+        )
+    }
+
+    ///
+    /// Returns true if another bounding box is entirely inside this one
+    ///
+    fn contains_bounds(&self, target: &Self) -> bool {
+        let (min1, max1) = (self.min(), self.max());
+        let (min2, max2) = (target.min(), target.max());
+
+        (0..Self::Point::len()).all(|p_index| {
+            min1.get(p_index) <= min2.get(p_index) && max1.get(p_index) >= max2.get(p_index)
+        })
+    }
+
+    ///
+    /// Returns the point at the center of this bounding box
+    ///
+    #[inline]
+    fn center(&self) -> Self::Point {
+        (self.min() + self.max()) * 0.5
+    }
+
+    ///
+    /// Returns the size of this bounding box along each axis
+    ///
+    #[inline]
+    fn extent(&self) -> Self::Point {
+        self.max() - self.min()
+    }
+
     ///
     /// Returns true if this bounding box overlaps another
     ///
@@ -0,0 +1,711 @@
+use super::bounding_box::{BoundingBox, Bounds};
+use super::coordinate::{Coordinate, Coordinate2D};
+use super::has_bounds::HasBoundingBox;
+
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+
+///
+/// Identifies an item stored in a `BoundingVolumeHierarchy`
+///
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct BvhItemId(usize);
+
+impl BvhItemId {
+    ///
+    /// Returns the index of this item (item indexes are re-used after an item is removed)
+    ///
+    #[inline]
+    pub fn index(&self) -> usize {
+        self.0
+    }
+}
+
+///
+/// What's stored in a node of the hierarchy
+///
+#[derive(Clone, Copy, Debug)]
+enum BvhNodeKind {
+    /// A leaf node, containing a single item
+    Leaf(BvhItemId),
+
+    /// A branch node, with two child nodes
+    Branch(usize, usize),
+}
+
+///
+/// A node in the bounding volume hierarchy
+///
+#[derive(Clone, Debug)]
+struct BvhNode<Point: Coordinate> {
+    /// The bounds of everything contained in this node
+    bounds: Bounds<Point>,
+
+    /// The parent of this node (None for the root node)
+    parent: Option<usize>,
+
+    /// The contents of this node
+    kind: BvhNodeKind,
+}
+
+///
+/// An item stored in the hierarchy
+///
+#[derive(Clone, Debug)]
+struct BvhItem<TItem: HasBoundingBox> {
+    /// The item itself
+    item: TItem,
+
+    /// The leaf node containing this item
+    node_idx: usize,
+}
+
+///
+/// A spatial index that stores items by their bounding boxes
+///
+/// This is a dynamic bounding volume hierarchy: a binary tree where each node has the bounding box of everything beneath it.
+/// It's a good choice for hit-testing large sets of items (for example, all the curves in a document), as queries only need
+/// to visit the parts of the tree that can contain matching items. Items can be added and removed at any time without
+/// rebuilding the whole index.
+///
+/// The bounding box of each item is read once when it's added, so items that change shape should be updated using `replace()`.
+///
+#[derive(Clone, Debug)]
+pub struct BoundingVolumeHierarchy<TItem: HasBoundingBox> {
+    /// The nodes in the tree
+    nodes: Vec<BvhNode<TItem::Point>>,
+
+    /// Indexes of nodes that are not in use
+    free_nodes: Vec<usize>,
+
+    /// The items in this tree
+    items: Vec<Option<BvhItem<TItem>>>,
+
+    /// Indexes of items that are not in use
+    free_items: Vec<usize>,
+
+    /// The root node of the tree
+    root: Option<usize>,
+
+    /// The number of items in the tree
+    len: usize,
+}
+
+///
+/// Returns a bounding box containing two others
+///
+/// (Unlike `union_bounds()`, this treats bounding boxes that are a single point as occupying that point)
+///
+#[inline]
+fn combine_bounds<Point: Coordinate>(a: &Bounds<Point>, b: &Bounds<Point>) -> Bounds<Point> {
+    Bounds::from_min_max(
+        Point::from_smallest_components(a.min(), b.min()),
+        Point::from_biggest_components(a.max(), b.max()),
+    )
+}
+
+///
+/// The cost of a node with a particular bounding box (the sum of the lengths of its sides, which unlike the area still works
+/// for boxes that are flat in one dimension)
+///
+#[inline]
+fn bounds_cost<Point: Coordinate>(bounds: &Bounds<Point>) -> f64 {
+    let extent = bounds.max() - bounds.min();
+
+    (0..Point::len())
+        .map(|component| extent.get(component))
+        .sum()
+}
+
+///
+/// The distance from a point to the nearest point in a bounding box (0 if the point is inside the box)
+///
+#[inline]
+fn distance_to_bounds<Point: Coordinate>(point: &Point, bounds: &Bounds<Point>) -> f64 {
+    let (min, max) = (bounds.min(), bounds.max());
+
+    (0..Point::len())
+        .map(|component| {
+            let pos = point.get(component);
+            let offset = (min.get(component) - pos)
+                .max(pos - max.get(component))
+                .max(0.0);
+
+            offset * offset
+        })
+        .sum::<f64>()
+        .sqrt()
+}
+
+///
+/// An entry in the queue used to search for the items nearest to a point
+///
+#[derive(Clone, Copy, Debug)]
+enum NearestEntry {
+    /// A node that has yet to be visited, which contains nothing nearer than the distance
+    Node(usize),
+
+    /// An item that's at exactly the specified distance
+    Item(BvhItemId),
+}
+
+///
+/// Orders nearest entries so that the `BinaryHeap` returns the nearest one first
+///
+#[derive(Clone, Copy, Debug)]
+struct NearestQueueItem(f64, NearestEntry);
+
+impl PartialEq for NearestQueueItem {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for NearestQueueItem {}
+
+impl PartialOrd for NearestQueueItem {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for NearestQueueItem {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Reversed so the smallest distance is at the top of the heap
+        other.0.total_cmp(&self.0)
+    }
+}
+
+impl<TItem: HasBoundingBox> Default for BoundingVolumeHierarchy<TItem> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<TItem: HasBoundingBox> BoundingVolumeHierarchy<TItem> {
+    ///
+    /// Creates a new, empty hierarchy
+    ///
+    pub fn new() -> Self {
+        BoundingVolumeHierarchy {
+            nodes: vec![],
+            free_nodes: vec![],
+            items: vec![],
+            free_items: vec![],
+            root: None,
+            len: 0,
+        }
+    }
+
+    ///
+    /// Creates a hierarchy containing a set of items (the IDs of the items will be 0, 1, 2, etc in the order they're
+    /// returned by the iterator)
+    ///
+    pub fn from_items<ItemIter: IntoIterator<Item = TItem>>(items: ItemIter) -> Self {
+        let mut bvh = Self::new();
+
+        for item in items {
+            bvh.insert(item);
+        }
+
+        bvh
+    }
+
+    ///
+    /// The number of items in this hierarchy
+    ///
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    ///
+    /// True if this hierarchy contains no items
+    ///
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    ///
+    /// Retrieves an item from this hierarchy
+    ///
+    pub fn get(&self, id: BvhItemId) -> Option<&TItem> {
+        self.items
+            .get(id.0)
+            .and_then(|item| item.as_ref())
+            .map(|item| &item.item)
+    }
+
+    ///
+    /// Returns all of the items in this hierarchy, in no particular order
+    ///
+    pub fn iter(&self) -> impl '_ + Iterator<Item = (BvhItemId, &TItem)> {
+        self.items
+            .iter()
+            .enumerate()
+            .filter_map(|(idx, item)| item.as_ref().map(|item| (BvhItemId(idx), &item.item)))
+    }
+
+    ///
+    /// Allocates a node, re-using a free node if possible
+    ///
+    fn allocate_node(&mut self, node: BvhNode<TItem::Point>) -> usize {
+        if let Some(node_idx) = self.free_nodes.pop() {
+            self.nodes[node_idx] = node;
+            node_idx
+        } else {
+            self.nodes.push(node);
+            self.nodes.len() - 1
+        }
+    }
+
+    ///
+    /// Recalculates the bounds of a node and all of its parents
+    ///
+    fn refit_from(&mut self, node_idx: Option<usize>) {
+        let mut node_idx = node_idx;
+
+        while let Some(idx) = node_idx {
+            if let BvhNodeKind::Branch(left, right) = self.nodes[idx].kind {
+                self.nodes[idx].bounds =
+                    combine_bounds(&self.nodes[left].bounds, &self.nodes[right].bounds);
+            }
+
+            node_idx = self.nodes[idx].parent;
+        }
+    }
+
+    ///
+    /// Finds the node that a leaf with the specified bounds should be placed next to
+    ///
+    fn find_sibling(&self, root: usize, bounds: &Bounds<TItem::Point>) -> usize {
+        let mut node_idx = root;
+
+        while let BvhNodeKind::Branch(left, right) = self.nodes[node_idx].kind {
+            // Cost of making the new leaf a sibling of this node
+            let node_bounds = &self.nodes[node_idx].bounds;
+            let combined_cost = bounds_cost(&combine_bounds(node_bounds, bounds));
+            let sibling_cost = 2.0 * combined_cost;
+
+            // Every node above the new leaf will grow by this amount if we descend further
+            let inherited_cost = 2.0 * (combined_cost - bounds_cost(node_bounds));
+
+            let child_cost = |child_idx: usize| {
+                let child = &self.nodes[child_idx];
+                let combined_cost = bounds_cost(&combine_bounds(&child.bounds, bounds));
+
+                match child.kind {
+                    BvhNodeKind::Leaf(_) => combined_cost + inherited_cost,
+                    BvhNodeKind::Branch(_, _) => {
+                        combined_cost - bounds_cost(&child.bounds) + inherited_cost
+                    }
+                }
+            };
+
+            let left_cost = child_cost(left);
+            let right_cost = child_cost(right);
+
+            if sibling_cost < left_cost && sibling_cost < right_cost {
+                break;
+            }
+
+            node_idx = if left_cost < right_cost { left } else { right };
+        }
+
+        node_idx
+    }
+
+    ///
+    /// Adds a leaf node to the tree
+    ///
+    fn insert_leaf(&mut self, leaf_idx: usize) {
+        let root = if let Some(root) = self.root {
+            root
+        } else {
+            self.nodes[leaf_idx].parent = None;
+            self.root = Some(leaf_idx);
+            return;
+        };
+
+        // Create a new branch containing the sibling and the new leaf
+        let leaf_bounds = self.nodes[leaf_idx].bounds;
+        let sibling = self.find_sibling(root, &leaf_bounds);
+        let old_parent = self.nodes[sibling].parent;
+
+        let new_parent = self.allocate_node(BvhNode {
+            bounds: combine_bounds(&self.nodes[sibling].bounds, &leaf_bounds),
+            parent: old_parent,
+            kind: BvhNodeKind::Branch(sibling, leaf_idx),
+        });
+
+        self.nodes[sibling].parent = Some(new_parent);
+        self.nodes[leaf_idx].parent = Some(new_parent);
+
+        // Attach the new branch where the sibling used to be
+        match old_parent {
+            None => self.root = Some(new_parent),
+            Some(old_parent) => {
+                if let BvhNodeKind::Branch(left, right) = self.nodes[old_parent].kind {
+                    self.nodes[old_parent].kind = if left == sibling {
+                        BvhNodeKind::Branch(new_parent, right)
+                    } else {
+                        BvhNodeKind::Branch(left, new_parent)
+                    };
+                }
+            }
+        }
+
+        self.refit_from(old_parent);
+    }
+
+    ///
+    /// Removes a leaf node from the tree (without freeing it)
+    ///
+    fn remove_leaf(&mut self, leaf_idx: usize) {
+        let parent = if let Some(parent) = self.nodes[leaf_idx].parent {
+            parent
+        } else {
+            self.root = None;
+            return;
+        };
+
+        // Replace the parent with the sibling of the leaf
+        let sibling = match self.nodes[parent].kind {
+            BvhNodeKind::Branch(left, right) => {
+                if left == leaf_idx {
+                    right
+                } else {
+                    left
+                }
+            }
+            BvhNodeKind::Leaf(_) => unreachable!(),
+        };
+        let grandparent = self.nodes[parent].parent;
+
+        self.nodes[sibling].parent = grandparent;
+        self.free_nodes.push(parent);
+
+        match grandparent {
+            None => self.root = Some(sibling),
+            Some(grandparent) => {
+                if let BvhNodeKind::Branch(left, right) = self.nodes[grandparent].kind {
+                    self.nodes[grandparent].kind = if left == parent {
+                        BvhNodeKind::Branch(sibling, right)
+                    } else {
+                        BvhNodeKind::Branch(left, sibling)
+                    };
+                }
+
+                self.refit_from(Some(grandparent));
+            }
+        }
+    }
+
+    ///
+    /// Adds an item to this hierarchy, returning its ID
+    ///
+    pub fn insert(&mut self, item: TItem) -> BvhItemId {
+        let bounds = item.get_bounding_box::<Bounds<_>>();
+
+        // Allocate an ID for this item
+        let item_id = if let Some(item_idx) = self.free_items.pop() {
+            BvhItemId(item_idx)
+        } else {
+            self.items.push(None);
+            BvhItemId(self.items.len() - 1)
+        };
+
+        // Add a leaf node for the item
+        let leaf_idx = self.allocate_node(BvhNode {
+            bounds,
+            parent: None,
+            kind: BvhNodeKind::Leaf(item_id),
+        });
+        self.insert_leaf(leaf_idx);
+
+        self.items[item_id.0] = Some(BvhItem {
+            item,
+            node_idx: leaf_idx,
+        });
+        self.len += 1;
+
+        item_id
+    }
+
+    ///
+    /// Removes an item from this hierarchy, returning it if it exists
+    ///
+    pub fn remove(&mut self, id: BvhItemId) -> Option<TItem> {
+        let item = self.items.get_mut(id.0)?.take()?;
+
+        self.remove_leaf(item.node_idx);
+        self.free_nodes.push(item.node_idx);
+        self.free_items.push(id.0);
+        self.len -= 1;
+
+        Some(item.item)
+    }
+
+    ///
+    /// Replaces an item in this hierarchy with a new one (which may have different bounds), returning the original item
+    ///
+    /// This keeps the same ID for the item. Nothing is changed if the ID does not refer to an item in this hierarchy.
+    ///
+    pub fn replace(&mut self, id: BvhItemId, new_item: TItem) -> Option<TItem> {
+        let node_idx = self.items.get(id.0)?.as_ref()?.node_idx;
+        let bounds = new_item.get_bounding_box::<Bounds<_>>();
+
+        // Move the leaf node to its new position in the tree
+        self.remove_leaf(node_idx);
+        self.nodes[node_idx].bounds = bounds;
+        self.insert_leaf(node_idx);
+
+        let old_item = self.items[id.0].replace(BvhItem {
+            item: new_item,
+            node_idx,
+        });
+
+        old_item.map(|old_item| old_item.item)
+    }
+
+    ///
+    /// Returns the bounds of everything in this hierarchy
+    ///
+    pub fn bounds(&self) -> Bounds<TItem::Point> {
+        self.root
+            .map(|root| self.nodes[root].bounds)
+            .unwrap_or_else(Bounds::empty)
+    }
+
+    ///
+    /// Finds all of the items whose bounding boxes match a test (which must also match the bounds of the nodes containing them)
+    ///
+    fn search<'a, TestFn>(
+        &'a self,
+        test: TestFn,
+    ) -> impl 'a + Iterator<Item = (BvhItemId, &'a TItem)>
+    where
+        TestFn: 'a + Fn(&Bounds<TItem::Point>) -> bool,
+    {
+        let mut stack = self.root.into_iter().collect::<Vec<_>>();
+
+        std::iter::from_fn(move || {
+            while let Some(node_idx) = stack.pop() {
+                let node = &self.nodes[node_idx];
+
+                if !test(&node.bounds) {
+                    continue;
+                }
+
+                match node.kind {
+                    BvhNodeKind::Branch(left, right) => {
+                        stack.push(right);
+                        stack.push(left);
+                    }
+                    BvhNodeKind::Leaf(item_id) => {
+                        let item = self.items[item_id.0].as_ref().unwrap();
+                        return Some((item_id, &item.item));
+                    }
+                }
+            }
+
+            None
+        })
+    }
+
+    ///
+    /// Returns the items whose bounding boxes overlap the specified bounding box
+    ///
+    pub fn overlapping_bounds<'a>(
+        &'a self,
+        bounds: &Bounds<TItem::Point>,
+    ) -> impl 'a + Iterator<Item = (BvhItemId, &'a TItem)> {
+        let bounds = *bounds;
+        self.search(move |node_bounds| node_bounds.overlaps(&bounds))
+    }
+
+    ///
+    /// Returns the items whose bounding boxes contain the specified point
+    ///
+    pub fn containing_point<'a>(
+        &'a self,
+        point: &TItem::Point,
+    ) -> impl 'a + Iterator<Item = (BvhItemId, &'a TItem)> {
+        let bounds = Bounds::from_min_max(*point, *point);
+        self.search(move |node_bounds| node_bounds.overlaps(&bounds))
+    }
+
+    ///
+    /// Returns the items in this hierarchy in order of their distance from a point
+    ///
+    /// `item_distance` returns the distance from the point to an item. This must be no less than the distance from the
+    /// point to the item's bounding box, which is the case for any distance to a point inside the item (for a
+    /// curve, for example, this is the distance to the nearest point on the curve). Items are returned as `(id, item,
+    /// distance)`, and are found as they're needed: only the parts of the tree that could contain an item nearer than
+    /// the last one returned are visited, so taking the first few items of a large hierarchy is fast.
+    ///
+    pub fn nearest_to_point<'a, DistanceFn>(
+        &'a self,
+        point: &TItem::Point,
+        item_distance: DistanceFn,
+    ) -> impl 'a + Iterator<Item = (BvhItemId, &'a TItem, f64)>
+    where
+        DistanceFn: 'a + Fn(&TItem) -> f64,
+    {
+        let point = *point;
+        let mut queue = self
+            .root
+            .map(|root| {
+                NearestQueueItem(
+                    distance_to_bounds(&point, &self.nodes[root].bounds),
+                    NearestEntry::Node(root),
+                )
+            })
+            .into_iter()
+            .collect::<BinaryHeap<_>>();
+
+        std::iter::from_fn(move || {
+            while let Some(NearestQueueItem(distance, entry)) = queue.pop() {
+                match entry {
+                    NearestEntry::Item(item_id) => {
+                        let item = self.items[item_id.0].as_ref().unwrap();
+                        return Some((item_id, &item.item, distance));
+                    }
+
+                    NearestEntry::Node(node_idx) => match self.nodes[node_idx].kind {
+                        BvhNodeKind::Branch(left, right) => {
+                            for child in [left, right] {
+                                let child_distance =
+                                    distance_to_bounds(&point, &self.nodes[child].bounds);
+                                queue.push(NearestQueueItem(
+                                    child_distance,
+                                    NearestEntry::Node(child),
+                                ));
+                            }
+                        }
+
+                        BvhNodeKind::Leaf(item_id) => {
+                            let item = self.items[item_id.0].as_ref().unwrap();
+                            queue.push(NearestQueueItem(
+                                item_distance(&item.item),
+                                NearestEntry::Item(item_id),
+                            ));
+                        }
+                    },
+                }
+            }
+
+            None
+        })
+    }
+
+    ///
+    /// Returns all of the pairs of items in this hierarchy whose bounding boxes overlap
+    ///
+    /// Each pair is returned once, in no particular order.
+    ///
+    pub fn overlapping_pairs(&self) -> Vec<(BvhItemId, BvhItemId)> {
+        let mut pairs = vec![];
+        let root = if let Some(root) = self.root {
+            root
+        } else {
+            return pairs;
+        };
+
+        // Every branch needs to be checked for overlaps between its two children
+        let mut to_check = vec![];
+
+        for node in self.nodes_below(root) {
+            if let BvhNodeKind::Branch(left, right) = self.nodes[node].kind {
+                to_check.push((left, right));
+            }
+        }
+
+        while let Some((node_a, node_b)) = to_check.pop() {
+            let (a, b) = (&self.nodes[node_a], &self.nodes[node_b]);
+
+            if !a.bounds.overlaps(&b.bounds) {
+                continue;
+            }
+
+            match (a.kind, b.kind) {
+                (BvhNodeKind::Leaf(item_a), BvhNodeKind::Leaf(item_b)) => {
+                    pairs.push((item_a, item_b))
+                }
+                (BvhNodeKind::Branch(left, right), BvhNodeKind::Leaf(_)) => {
+                    to_check.push((left, node_b));
+                    to_check.push((right, node_b));
+                }
+                (_, BvhNodeKind::Branch(left, right)) => {
+                    to_check.push((node_a, left));
+                    to_check.push((node_a, right));
+                }
+            }
+        }
+
+        pairs
+    }
+
+    ///
+    /// Returns the indexes of a node and all of the nodes below it
+    ///
+    fn nodes_below(&self, node_idx: usize) -> Vec<usize> {
+        let mut result = vec![];
+        let mut stack = vec![node_idx];
+
+        while let Some(node_idx) = stack.pop() {
+            result.push(node_idx);
+
+            if let BvhNodeKind::Branch(left, right) = self.nodes[node_idx].kind {
+                stack.push(left);
+                stack.push(right);
+            }
+        }
+
+        result
+    }
+}
+
+impl<TItem: HasBoundingBox> BoundingVolumeHierarchy<TItem>
+where
+    TItem::Point: Coordinate2D,
+{
+    ///
+    /// Returns the items whose bounding boxes are crossed by a ray
+    ///
+    /// As with the other ray functions in this library, the ray is the infinite line passing through the `from` and `to`
+    /// points. The results can contain items that the ray doesn't actually intersect, as only their bounding boxes are checked.
+    ///
+    pub fn crossed_by_ray<'a>(
+        &'a self,
+        from: &TItem::Point,
+        to: &TItem::Point,
+    ) -> impl 'a + Iterator<Item = (BvhItemId, &'a TItem)> {
+        let (from, to) = (*from, *to);
+        let direction = to - from;
+
+        self.search(move |node_bounds| {
+            // The ray crosses the bounds if the corners are not all on the same side of it
+            let (min, max) = (node_bounds.min(), node_bounds.max());
+            let corners = [
+                (min.x(), min.y()),
+                (max.x(), min.y()),
+                (max.x(), max.y()),
+                (min.x(), max.y()),
+            ];
+
+            let sides = corners
+                .iter()
+                .map(|(x, y)| direction.x() * (y - from.y()) - direction.y() * (x - from.x()));
+
+            let (mut any_below, mut any_above) = (false, false);
+            for side in sides {
+                any_below |= side <= 0.0;
+                any_above |= side >= 0.0;
+            }
+
+            any_below && any_above
+        })
+    }
+}
@@ -376,3 +376,129 @@ impl Coordinate for Coord2 {
         self.0 * target.0 + self.1 * target.1
     }
 }
+
+/// Represents a 3D point
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Coord3(pub f64, pub f64, pub f64);
+
+impl Coordinate3D for Coord3 {
+    ///
+    /// X component of this coordinate
+    ///
+    #[inline]
+    fn x(&self) -> f64 {
+        self.0
+    }
+
+    ///
+    /// Y component of this coordinate
+    ///
+    #[inline]
+    fn y(&self) -> f64 {
+        self.1
+    }
+
+    ///
+    /// Z component of this coordinate
+    ///
+    #[inline]
+    fn z(&self) -> f64 {
+        self.2
+    }
+}
+
+impl Add<Self> for Coord3 {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        Self(self.0 + rhs.0, self.1 + rhs.1, self.2 + rhs.2)
+    }
+}
+
+impl Sub<Self> for Coord3 {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        Self(self.0 - rhs.0, self.1 - rhs.1, self.2 - rhs.2)
+    }
+}
+
+impl Mul<f64> for Coord3 {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: f64) -> Self {
+        Self(self.0 * rhs, self.1 * rhs, self.2 * rhs)
+    }
+}
+
+impl From<(f64, f64, f64)> for Coord3 {
+    fn from((x, y, z): (f64, f64, f64)) -> Self {
+        Self(x, y, z)
+    }
+}
+
+impl From<Coord3> for (f64, f64, f64) {
+    fn from(c: Coord3) -> (f64, f64, f64) {
+        (c.0, c.1, c.2)
+    }
+}
+
+impl Coordinate for Coord3 {
+    #[inline]
+    fn from_components(components: &[f64]) -> Self {
+        Self(components[0], components[1], components[2])
+    }
+
+    #[inline]
+    fn origin() -> Self {
+        Self(0.0, 0.0, 0.0)
+    }
+
+    #[inline]
+    fn len() -> usize {
+        3
+    }
+
+    #[inline]
+    fn get(&self, index: usize) -> f64 {
+        match index {
+            0 => self.0,
+            1 => self.1,
+            2 => self.2,
+            _ => panic!("Coord3 only has three components"),
+        }
+    }
+
+    fn from_biggest_components(p1: Self, p2: Self) -> Self {
+        Self(
+            f64::from_biggest_components(p1.0, p2.0),
+            f64::from_biggest_components(p1.1, p2.1),
+            f64::from_biggest_components(p1.2, p2.2),
+        )
+    }
+
+    fn from_smallest_components(p1: Self, p2: Self) -> Self {
+        Self(
+            f64::from_smallest_components(p1.0, p2.0),
+            f64::from_smallest_components(p1.1, p2.1),
+            f64::from_smallest_components(p1.2, p2.2),
+        )
+    }
+
+    #[inline]
+    fn distance_to(&self, target: &Self) -> f64 {
+        let dist_x = target.0 - self.0;
+        let dist_y = target.1 - self.1;
+        let dist_z = target.2 - self.2;
+
+        f64::sqrt(dist_x * dist_x + dist_y * dist_y + dist_z * dist_z)
+    }
+
+    #[inline]
+    fn dot(&self, target: &Self) -> f64 {
+        self.0 * target.0 + self.1 * target.1 + self.2 * target.2
+    }
+}
@@ -9,17 +9,45 @@
 //! possible to request bounding boxes in types other than the default `Bounds` type supplied by the
 //! library.
 //!
+//! `Transform2D` represents an affine transformation, which can be applied to anything that implements the
+//! `Transformable` trait (including curves and paths). `PerspectiveTransform2D` represents a projective transformation,
+//! such as one that maps a rectangle onto an arbitrary quadrilateral.
+//!
+//! `ApproxEq` compares coordinates, bounds, curves and paths (and lists of them) to within a tolerance, and
+//! `QuantisedHash` generates keys with the coordinates snapped to a grid, so that paths can be used as the keys of a
+//! cache.
+//!
+//! `BoundingVolumeHierarchy` is a spatial index that can be used to quickly find the items in a large set that
+//! are near a point, a ray or another bounding box.
+//!
+//! `orient_2d()` and `in_circle()` are robust geometric predicates: the sign of their result is always correct, even
+//! for points that are very nearly collinear or cocircular. When the `robust_predicates` feature is enabled, these are
+//! used for the side-of-line tests made while ray casting, which are otherwise made with ordinary floating point
+//! arithmetic.
+//!
+//! The `sweep` module provides the sweep-line algorithm used to quickly find which of a set of objects have
+//! overlapping bounding boxes.
+//!
 
+mod approx_eq;
 mod bounding_box;
+mod bvh;
 mod coordinate;
 mod coordinate_ext;
 mod geo;
 mod has_bounds;
-mod sweep;
+mod predicates;
+mod transform;
+
+pub mod sweep;
 
+pub use self::approx_eq::*;
 pub use self::bounding_box::*;
+pub use self::bvh::*;
 pub use self::coordinate::*;
 pub use self::coordinate_ext::*;
 pub use self::geo::*;
 pub use self::has_bounds::*;
+pub use self::predicates::*;
 pub use self::sweep::*;
+pub use self::transform::*;
@@ -0,0 +1,191 @@
+use super::coordinate::{Coordinate, Coordinate2D};
+
+// Adaptive precision predicates, after Jonathan Shewchuk's 'Adaptive Precision Floating-Point Arithmetic and Fast Robust
+// Geometric Predicates' (https://www.cs.cmu.edu/~quake/robust.html).
+//
+// The result of each predicate is first calculated using normal floating point arithmetic. If that result is too close to 0
+// for its sign to be trusted, it's calculated again exactly by representing values as 'expansions': lists of non-overlapping
+// floating point values whose sum is the exact result.
+
+/// Half the distance between 1.0 and the next floating point value
+const HALF_EPSILON: f64 = f64::EPSILON * 0.5;
+
+/// Relative error bound for the fast version of the orientation test
+const ORIENT_ERROR_BOUND: f64 = (3.0 + 16.0 * HALF_EPSILON) * HALF_EPSILON;
+
+/// Relative error bound for the fast version of the in-circle test
+const IN_CIRCLE_ERROR_BOUND: f64 = (10.0 + 96.0 * HALF_EPSILON) * HALF_EPSILON;
+
+///
+/// Returns the exact sum of two values, as the rounded sum and the rounding error
+///
+#[inline]
+fn two_sum(a: f64, b: f64) -> (f64, f64) {
+    let sum = a + b;
+    let b_virtual = sum - a;
+    let a_virtual = sum - b_virtual;
+    let b_roundoff = b - b_virtual;
+    let a_roundoff = a - a_virtual;
+
+    (sum, a_roundoff + b_roundoff)
+}
+
+///
+/// Returns the exact difference of two values as an expansion
+///
+#[inline]
+fn two_diff(a: f64, b: f64) -> Vec<f64> {
+    let (diff, error) = two_sum(a, -b);
+
+    vec![error, diff]
+}
+
+///
+/// Adds two expansions together, returning the exact result
+///
+fn expansion_sum(e: &[f64], f: &[f64]) -> Vec<f64> {
+    let mut result = e.to_vec();
+
+    for &component in f.iter() {
+        // Grow the expansion by this component: carrying the sum through the whole expansion leaves it non-overlapping
+        let mut carry = component;
+        let mut grown = Vec::with_capacity(result.len() + 1);
+
+        for &existing in result.iter() {
+            let (sum, error) = two_sum(carry, existing);
+
+            if error != 0.0 {
+                grown.push(error);
+            }
+            carry = sum;
+        }
+
+        if carry != 0.0 || grown.is_empty() {
+            grown.push(carry);
+        }
+
+        result = grown;
+    }
+
+    result
+}
+
+///
+/// Multiplies two expansions together, returning the exact result
+///
+fn expansion_product(e: &[f64], f: &[f64]) -> Vec<f64> {
+    let mut result = vec![0.0];
+
+    for &a in e.iter() {
+        for &b in f.iter() {
+            let product = a * b;
+            let error = a.mul_add(b, -product);
+
+            result = expansion_sum(&result, &[error, product]);
+        }
+    }
+
+    result
+}
+
+///
+/// Returns the approximate value of an expansion (which has the same sign as the exact value)
+///
+#[inline]
+fn estimate(e: &[f64]) -> f64 {
+    e.iter().sum()
+}
+
+///
+/// Determines which side of the line `a`-`b` the point `c` is on
+///
+/// The result is positive if `a`, `b` and `c` are in anticlockwise order (`c` is to the left of the line when travelling
+/// from `a` to `b`), negative if they are in clockwise order and exactly 0 if they are collinear. The magnitude is twice
+/// the area of the triangle formed by the points. The sign of the result is always correct: it's calculated with extra
+/// precision when floating point rounding could change it.
+///
+pub fn orient_2d<Point: Coordinate + Coordinate2D>(a: &Point, b: &Point, c: &Point) -> f64 {
+    let det_left = (a.x() - c.x()) * (b.y() - c.y());
+    let det_right = (a.y() - c.y()) * (b.x() - c.x());
+    let det = det_left - det_right;
+
+    let error_bound = ORIENT_ERROR_BOUND * (det_left.abs() + det_right.abs());
+    if det.abs() > error_bound || det_left == 0.0 || det_right == 0.0 {
+        return det;
+    }
+
+    // Calculate the result exactly
+    let acx = two_diff(a.x(), c.x());
+    let bcy = two_diff(b.y(), c.y());
+    let acy = two_diff(a.y(), c.y());
+    let bcx = two_diff(b.x(), c.x());
+
+    let left = expansion_product(&acx, &bcy);
+    let right = expansion_product(&acy, &bcx);
+    let right = right
+        .into_iter()
+        .map(|component| -component)
+        .collect::<Vec<_>>();
+
+    estimate(&expansion_sum(&left, &right))
+}
+
+///
+/// Determines whether or not the point `d` is inside the circle passing through `a`, `b` and `c`
+///
+/// `a`, `b` and `c` should be in anticlockwise order (the sign of the result is reversed if they are clockwise). The
+/// result is positive if `d` is inside the circle, negative if it's outside and exactly 0 if all four points are on the
+/// same circle. As with `orient_2d()`, the sign of the result is always correct.
+///
+pub fn in_circle<Point: Coordinate + Coordinate2D>(
+    a: &Point,
+    b: &Point,
+    c: &Point,
+    d: &Point,
+) -> f64 {
+    let (adx, ady) = (a.x() - d.x(), a.y() - d.y());
+    let (bdx, bdy) = (b.x() - d.x(), b.y() - d.y());
+    let (cdx, cdy) = (c.x() - d.x(), c.y() - d.y());
+
+    let (bdx_cdy, cdx_bdy) = (bdx * cdy, cdx * bdy);
+    let (cdx_ady, adx_cdy) = (cdx * ady, adx * cdy);
+    let (adx_bdy, bdx_ady) = (adx * bdy, bdx * ady);
+
+    let a_lift = adx * adx + ady * ady;
+    let b_lift = bdx * bdx + bdy * bdy;
+    let c_lift = cdx * cdx + cdy * cdy;
+
+    let det =
+        a_lift * (bdx_cdy - cdx_bdy) + b_lift * (cdx_ady - adx_cdy) + c_lift * (adx_bdy - bdx_ady);
+    let permanent = (bdx_cdy.abs() + cdx_bdy.abs()) * a_lift
+        + (cdx_ady.abs() + adx_cdy.abs()) * b_lift
+        + (adx_bdy.abs() + bdx_ady.abs()) * c_lift;
+
+    let error_bound = IN_CIRCLE_ERROR_BOUND * permanent;
+    if det.abs() > error_bound {
+        return det;
+    }
+
+    // Calculate the result exactly
+    let (adx, ady) = (two_diff(a.x(), d.x()), two_diff(a.y(), d.y()));
+    let (bdx, bdy) = (two_diff(b.x(), d.x()), two_diff(b.y(), d.y()));
+    let (cdx, cdy) = (two_diff(c.x(), d.x()), two_diff(c.y(), d.y()));
+
+    let lift = |dx: &[f64], dy: &[f64]| {
+        expansion_sum(&expansion_product(dx, dx), &expansion_product(dy, dy))
+    };
+    let cross = |x1: &[f64], y2: &[f64], x2: &[f64], y1: &[f64]| {
+        let right = expansion_product(x2, y1)
+            .into_iter()
+            .map(|component| -component)
+            .collect::<Vec<_>>();
+
+        expansion_sum(&expansion_product(x1, y2), &right)
+    };
+
+    let a_term = expansion_product(&lift(&adx, &ady), &cross(&bdx, &cdy, &cdx, &bdy));
+    let b_term = expansion_product(&lift(&bdx, &bdy), &cross(&cdx, &ady, &adx, &cdy));
+    let c_term = expansion_product(&lift(&cdx, &cdy), &cross(&adx, &bdy, &bdx, &ady));
+
+    estimate(&expansion_sum(&expansion_sum(&a_term, &b_term), &c_term))
+}
@@ -1,13 +1,42 @@
+//!
+//! # Sweep-line collision detection for bounding boxes
+//!
+//! These functions provide the 'broad phase' used by the path arithmetic code to find which curves might intersect: they
+//! take lists of items that implement `HasBoundingBox`, ordered by the minimum x coordinate of their bounds, and return
+//! the pairs of items whose bounding boxes overlap. This is much faster than checking every pair of items when most items
+//! do not overlap each other.
+//!
+//! `sort_for_sweep()` can be used to put a list of items into the order required by the sweep functions.
+//!
+
 use crate::geo::{BoundingBox, Bounds, Coordinate2D, HasBoundingBox};
 
 use smallvec::{smallvec, SmallVec};
 
 use std::cmp::Ordering;
 
+///
+/// Sorts a list of items into the order required by `sweep_self()` and `sweep_against()` (by the minimum x coordinate
+/// of their bounding boxes)
+///
+pub fn sort_for_sweep<TItem>(items: &mut [TItem])
+where
+    TItem: HasBoundingBox,
+    TItem::Point: Coordinate2D,
+{
+    items.sort_by(|item1, item2| {
+        let min_x1 = item1.get_bounding_box::<Bounds<_>>().min().x();
+        let min_x2 = item2.get_bounding_box::<Bounds<_>>().min().x();
+
+        min_x1.partial_cmp(&min_x2).unwrap_or(Ordering::Equal)
+    });
+}
+
 ///
 /// Sweeps a set of objects with bounding boxes to find the potential collisions between them
 ///
-/// The objects must be sorted into order by their min-x position, with the lowest first
+/// The objects must be sorted into order by their min-x position, with the lowest first. Each pair of objects with
+/// overlapping bounding boxes is returned once, in no particular order.
 ///
 pub fn sweep_self<'a, TItem, BoundsIter>(
     ordered_items: BoundsIter,
@@ -28,17 +57,20 @@ where
 /// Sweeps two sets of objects to find the collisions between them
 ///
 /// This will only collide between objects in src and objects in tgt. Both must be sorted into order by
-/// their min-x position, with the lowest first
+/// their min-x position, with the lowest first. The two sets of objects can be of different types, so
+/// long as they use the same type of coordinate. The result is the pairs of source and target objects
+/// with overlapping bounding boxes.
 ///
-pub fn sweep_against<'a, TItem, SrcBoundsIter, TgtBoundsIter>(
+pub fn sweep_against<'a, TSrc, TTgt, SrcBoundsIter, TgtBoundsIter>(
     src: SrcBoundsIter,
     tgt: TgtBoundsIter,
-) -> impl 'a + Iterator<Item = (&'a TItem, &'a TItem)>
+) -> impl 'a + Iterator<Item = (&'a TSrc, &'a TTgt)>
 where
-    SrcBoundsIter: 'a + Iterator<Item = &'a TItem>,
-    TgtBoundsIter: 'a + Iterator<Item = &'a TItem>,
-    TItem: 'a + HasBoundingBox,
-    TItem::Point: Coordinate2D,
+    SrcBoundsIter: 'a + Iterator<Item = &'a TSrc>,
+    TgtBoundsIter: 'a + Iterator<Item = &'a TTgt>,
+    TSrc: 'a + HasBoundingBox,
+    TTgt: 'a + HasBoundingBox<Point = TSrc::Point>,
+    TSrc::Point: Coordinate2D,
 {
     SweepAgainstIterator {
         src_iterator: Some(src),
@@ -141,12 +173,13 @@ where
 ///
 /// Iterator that performs the sweep operation
 ///
-struct SweepAgainstIterator<'a, TItem, SrcIterator, TgtIterator>
+struct SweepAgainstIterator<'a, TSrc, TTgt, SrcIterator, TgtIterator>
 where
-    SrcIterator: 'a + Iterator<Item = &'a TItem>,
-    TgtIterator: 'a + Iterator<Item = &'a TItem>,
-    TItem: 'a + HasBoundingBox,
-    TItem::Point: Coordinate2D,
+    SrcIterator: 'a + Iterator<Item = &'a TSrc>,
+    TgtIterator: 'a + Iterator<Item = &'a TTgt>,
+    TSrc: 'a + HasBoundingBox,
+    TTgt: 'a + HasBoundingBox<Point = TSrc::Point>,
+    TSrc::Point: Coordinate2D,
 {
     /// Iterator, ordered by minimum X position
     src_iterator: Option<SrcIterator>,
@@ -158,21 +191,22 @@ where
     src_last_min_x: f64,
 
     /// Collided items that are pending a return
-    pending: SmallVec<[(&'a TItem, &'a TItem); 16]>,
+    pending: SmallVec<[(&'a TSrc, &'a TTgt); 16]>,
 
     /// Source items that have not yet been swept away, ordered by maximum x position (in reverse, so the next item to remove can be popped)
-    src_by_max_x: Vec<(Bounds<TItem::Point>, &'a TItem)>,
+    src_by_max_x: Vec<(Bounds<TSrc::Point>, &'a TSrc)>,
 }
 
-impl<'a, TItem, SrcIterator, TgtIterator> Iterator
-    for SweepAgainstIterator<'a, TItem, SrcIterator, TgtIterator>
+impl<'a, TSrc, TTgt, SrcIterator, TgtIterator> Iterator
+    for SweepAgainstIterator<'a, TSrc, TTgt, SrcIterator, TgtIterator>
 where
-    SrcIterator: 'a + Iterator<Item = &'a TItem>,
-    TgtIterator: 'a + Iterator<Item = &'a TItem>,
-    TItem: 'a + HasBoundingBox,
-    TItem::Point: Coordinate2D,
+    SrcIterator: 'a + Iterator<Item = &'a TSrc>,
+    TgtIterator: 'a + Iterator<Item = &'a TTgt>,
+    TSrc: 'a + HasBoundingBox,
+    TTgt: 'a + HasBoundingBox<Point = TSrc::Point>,
+    TSrc::Point: Coordinate2D,
 {
-    type Item = (&'a TItem, &'a TItem);
+    type Item = (&'a TSrc, &'a TTgt);
 
     fn next(&mut self) -> Option<Self::Item> {
         loop {
@@ -0,0 +1,342 @@
+use super::bounding_box::{BoundingBox, Bounds};
+use super::coordinate::{Coord2, Coordinate, Coordinate2D};
+
+use std::ops::Mul;
+
+///
+/// A 2D affine transformation, represented as the top two rows of a 3x3 matrix
+///
+/// A point `(x, y)` is transformed to `(m[0][0]*x + m[0][1]*y + m[0][2], m[1][0]*x + m[1][1]*y + m[1][2])`. Transformations
+/// can be combined by multiplying them: `a * b` is the transformation that applies `b` followed by `a`. `then()` can be used
+/// to build up a transformation in the order that it's applied.
+///
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Transform2D(pub [[f64; 3]; 2]);
+
+impl Default for Transform2D {
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+impl Transform2D {
+    ///
+    /// The transformation that leaves every point where it is
+    ///
+    pub fn identity() -> Self {
+        Transform2D([[1.0, 0.0, 0.0], [0.0, 1.0, 0.0]])
+    }
+
+    ///
+    /// A transformation that moves points by the specified offset
+    ///
+    pub fn translate(dx: f64, dy: f64) -> Self {
+        Transform2D([[1.0, 0.0, dx], [0.0, 1.0, dy]])
+    }
+
+    ///
+    /// A transformation that scales points about the origin
+    ///
+    pub fn scale(sx: f64, sy: f64) -> Self {
+        Transform2D([[sx, 0.0, 0.0], [0.0, sy, 0.0]])
+    }
+
+    ///
+    /// A transformation that rotates points anticlockwise about the origin by an angle in radians
+    ///
+    pub fn rotate(radians: f64) -> Self {
+        let (sin, cos) = radians.sin_cos();
+
+        Transform2D([[cos, -sin, 0.0], [sin, cos, 0.0]])
+    }
+
+    ///
+    /// A transformation that rotates points anticlockwise about a center point by an angle in radians
+    ///
+    pub fn rotate_around(center: &Coord2, radians: f64) -> Self {
+        Self::translate(-center.x(), -center.y())
+            .then(&Self::rotate(radians))
+            .then(&Self::translate(center.x(), center.y()))
+    }
+
+    ///
+    /// A transformation that skews points by the specified angles (in radians) along the x and y axes
+    ///
+    pub fn skew(x_radians: f64, y_radians: f64) -> Self {
+        Transform2D([[1.0, x_radians.tan(), 0.0], [y_radians.tan(), 1.0, 0.0]])
+    }
+
+    ///
+    /// Returns the transformation that applies this one and then another
+    ///
+    pub fn then(&self, next: &Transform2D) -> Self {
+        *next * *self
+    }
+
+    ///
+    /// Returns the transformation that reverses this one, or `None` if this transformation can't be reversed (because it
+    /// collapses everything onto a line or a point)
+    ///
+    pub fn invert(&self) -> Option<Self> {
+        let [[a, b, c], [d, e, f]] = self.0;
+        let determinant = a * e - b * d;
+
+        if determinant == 0.0 || !determinant.is_finite() {
+            return None;
+        }
+
+        let inv_det = 1.0 / determinant;
+        let (ia, ib, id, ie) = (e * inv_det, -b * inv_det, -d * inv_det, a * inv_det);
+
+        Some(Transform2D([
+            [ia, ib, -(ia * c + ib * f)],
+            [id, ie, -(id * c + ie * f)],
+        ]))
+    }
+
+    ///
+    /// Applies this transformation to a point
+    ///
+    #[inline]
+    pub fn transform_point<Point: Coordinate + Coordinate2D>(&self, point: &Point) -> Point {
+        let [[a, b, c], [d, e, f]] = self.0;
+        let (x, y) = (point.x(), point.y());
+
+        Point::from_components(&[a * x + b * y + c, d * x + e * y + f])
+    }
+}
+
+impl Mul<Transform2D> for Transform2D {
+    type Output = Transform2D;
+
+    fn mul(self, rhs: Transform2D) -> Transform2D {
+        let [[a1, b1, c1], [d1, e1, f1]] = self.0;
+        let [[a2, b2, c2], [d2, e2, f2]] = rhs.0;
+
+        Transform2D([
+            [a1 * a2 + b1 * d2, a1 * b2 + b1 * e2, a1 * c2 + b1 * f2 + c1],
+            [d1 * a2 + e1 * d2, d1 * b2 + e1 * e2, d1 * c2 + e1 * f2 + f1],
+        ])
+    }
+}
+
+///
+/// Trait implemented by types that can have an affine transformation applied to them
+///
+pub trait Transformable: Sized {
+    ///
+    /// Returns a copy of this item with a transformation applied to it
+    ///
+    fn transform(&self, transform: &Transform2D) -> Self;
+}
+
+impl Transformable for Coord2 {
+    #[inline]
+    fn transform(&self, transform: &Transform2D) -> Self {
+        transform.transform_point(self)
+    }
+}
+
+impl<Point: Coordinate + Coordinate2D> Transformable for Bounds<Point> {
+    ///
+    /// Transforms a bounding box (as rotations and skews will change its shape, this returns the bounding box of the
+    /// transformed corners)
+    ///
+    fn transform(&self, transform: &Transform2D) -> Self {
+        let (min, max) = (self.min(), self.max());
+        let corners = [
+            Point::from_components(&[min.x(), min.y()]),
+            Point::from_components(&[max.x(), min.y()]),
+            Point::from_components(&[max.x(), max.y()]),
+            Point::from_components(&[min.x(), max.y()]),
+        ];
+
+        Bounds::bounds_for_points(
+            corners
+                .iter()
+                .map(|corner| transform.transform_point(corner)),
+        )
+    }
+}
+
+///
+/// A 2D projective transformation (homography), represented as a 3x3 matrix
+///
+/// A point `(x, y)` is transformed to `(X/W, Y/W)`, where `(X, Y, W)` is the matrix multiplied by `(x, y, 1)`. Unlike affine
+/// transformations, projective transformations do not map Bezier curves onto Bezier curves, so paths have to be refitted
+/// after transforming them: see `perspective_transform_path()`. Points where `W` is zero or negative are 'behind the viewer'
+/// and have no transformed position.
+///
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct PerspectiveTransform2D(pub [[f64; 3]; 3]);
+
+impl Default for PerspectiveTransform2D {
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+impl From<Transform2D> for PerspectiveTransform2D {
+    fn from(transform: Transform2D) -> Self {
+        let [row1, row2] = transform.0;
+
+        PerspectiveTransform2D([row1, row2, [0.0, 0.0, 1.0]])
+    }
+}
+
+impl PerspectiveTransform2D {
+    ///
+    /// The transformation that leaves every point where it is
+    ///
+    pub fn identity() -> Self {
+        PerspectiveTransform2D([[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]])
+    }
+
+    ///
+    /// Creates the transformation that maps the unit square onto a quadrilateral
+    ///
+    /// The corners of the quad are given in the order `(0, 0)`, `(1, 0)`, `(1, 1)`, `(0, 1)`. Returns `None` if the corners are
+    /// degenerate (three or more of them are on the same line).
+    ///
+    pub fn unit_square_to_quad(quad: &[Coord2; 4]) -> Option<Self> {
+        let [Coord2(x0, y0), Coord2(x1, y1), Coord2(x2, y2), Coord2(x3, y3)] = *quad;
+
+        let sx = x0 - x1 + x2 - x3;
+        let sy = y0 - y1 + y2 - y3;
+
+        let (g, h) = if sx == 0.0 && sy == 0.0 {
+            // The quad is a parallelogram, so this is an affine transformation
+            (0.0, 0.0)
+        } else {
+            let (dx1, dx2) = (x1 - x2, x3 - x2);
+            let (dy1, dy2) = (y1 - y2, y3 - y2);
+            let determinant = dx1 * dy2 - dx2 * dy1;
+
+            if determinant == 0.0 || !determinant.is_finite() {
+                return None;
+            }
+
+            (
+                (sx * dy2 - dx2 * sy) / determinant,
+                (dx1 * sy - sx * dy1) / determinant,
+            )
+        };
+
+        let transform = PerspectiveTransform2D([
+            [x1 - x0 + g * x1, x3 - x0 + h * x3, x0],
+            [y1 - y0 + g * y1, y3 - y0 + h * y3, y0],
+            [g, h, 1.0],
+        ]);
+
+        transform.invert().map(|_| transform)
+    }
+
+    ///
+    /// Creates the transformation that maps one quadrilateral onto another
+    ///
+    /// Corresponding corners must be in the same order in both quads. Returns `None` if either quad is degenerate.
+    ///
+    pub fn quad_to_quad(from: &[Coord2; 4], to: &[Coord2; 4]) -> Option<Self> {
+        let from_square = Self::unit_square_to_quad(from)?.invert()?;
+        let to_quad = Self::unit_square_to_quad(to)?;
+
+        Some(from_square.then(&to_quad))
+    }
+
+    ///
+    /// Creates the transformation that maps the corners of a rectangle onto a quadrilateral
+    ///
+    /// The corners of the quad are given in the order (min x, min y), (max x, min y), (max x, max y), (min x, max y). This can
+    /// be used to map a piece of artwork onto an arbitrary quad by passing in its bounding box.
+    ///
+    pub fn bounds_to_quad(bounds: &Bounds<Coord2>, quad: &[Coord2; 4]) -> Option<Self> {
+        let (min, max) = (bounds.min(), bounds.max());
+        let rect = [
+            Coord2(min.x(), min.y()),
+            Coord2(max.x(), min.y()),
+            Coord2(max.x(), max.y()),
+            Coord2(min.x(), max.y()),
+        ];
+
+        Self::quad_to_quad(&rect, quad)
+    }
+
+    ///
+    /// Returns the transformation that applies this one and then another
+    ///
+    pub fn then(&self, next: &PerspectiveTransform2D) -> Self {
+        *next * *self
+    }
+
+    ///
+    /// Returns the transformation that reverses this one, or `None` if this transformation can't be reversed
+    ///
+    pub fn invert(&self) -> Option<Self> {
+        let [[a, b, c], [d, e, f], [g, h, i]] = self.0;
+
+        let (co_a, co_b, co_c) = (e * i - f * h, f * g - d * i, d * h - e * g);
+        let determinant = a * co_a + b * co_b + c * co_c;
+
+        if determinant == 0.0 || !determinant.is_finite() {
+            return None;
+        }
+
+        let inv_det = 1.0 / determinant;
+
+        Some(PerspectiveTransform2D([
+            [
+                co_a * inv_det,
+                (c * h - b * i) * inv_det,
+                (b * f - c * e) * inv_det,
+            ],
+            [
+                co_b * inv_det,
+                (a * i - c * g) * inv_det,
+                (c * d - a * f) * inv_det,
+            ],
+            [
+                co_c * inv_det,
+                (b * g - a * h) * inv_det,
+                (a * e - b * d) * inv_det,
+            ],
+        ]))
+    }
+
+    ///
+    /// Applies this transformation to a point, returning `None` if the point has no transformed position
+    ///
+    #[inline]
+    pub fn transform_point<Point: Coordinate + Coordinate2D>(
+        &self,
+        point: &Point,
+    ) -> Option<Point> {
+        let [[a, b, c], [d, e, f], [g, h, i]] = self.0;
+        let (x, y) = (point.x(), point.y());
+        let w = g * x + h * y + i;
+
+        if w > 0.0 && w.is_finite() {
+            Some(Point::from_components(&[
+                (a * x + b * y + c) / w,
+                (d * x + e * y + f) / w,
+            ]))
+        } else {
+            None
+        }
+    }
+}
+
+impl Mul<PerspectiveTransform2D> for PerspectiveTransform2D {
+    type Output = PerspectiveTransform2D;
+
+    fn mul(self, rhs: PerspectiveTransform2D) -> PerspectiveTransform2D {
+        let mut result = [[0.0; 3]; 3];
+
+        for (row, result_row) in result.iter_mut().enumerate() {
+            for (col, result_value) in result_row.iter_mut().enumerate() {
+                *result_value = (0..3).map(|k| self.0[row][k] * rhs.0[k][col]).sum();
+            }
+        }
+
+        PerspectiveTransform2D(result)
+    }
+}
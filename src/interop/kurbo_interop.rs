@@ -0,0 +1,124 @@
+use super::subpaths::SubpathBuilder;
+use crate::bezier::path::{BezierPath, BezierPathFactory};
+use crate::bezier::{BezierCurve, Curve};
+use crate::geo::{Coord2, Coordinate, Coordinate2D};
+
+use kurbo::{BezPath, CubicBez, PathEl};
+
+impl From<kurbo::Point> for Coord2 {
+    #[inline]
+    fn from(point: kurbo::Point) -> Coord2 {
+        Coord2(point.x, point.y)
+    }
+}
+
+impl From<Coord2> for kurbo::Point {
+    #[inline]
+    fn from(point: Coord2) -> kurbo::Point {
+        kurbo::Point::new(point.0, point.1)
+    }
+}
+
+impl From<CubicBez> for Curve<Coord2> {
+    #[inline]
+    fn from(curve: CubicBez) -> Curve<Coord2> {
+        Curve {
+            start_point: curve.p0.into(),
+            end_point: curve.p3.into(),
+            control_points: (curve.p1.into(), curve.p2.into()),
+        }
+    }
+}
+
+impl From<Curve<Coord2>> for CubicBez {
+    #[inline]
+    fn from(curve: Curve<Coord2>) -> CubicBez {
+        CubicBez::new(
+            curve.start_point,
+            curve.control_points.0,
+            curve.control_points.1,
+            curve.end_point,
+        )
+    }
+}
+
+///
+/// Converts a point of any coordinate type to a kurbo point
+///
+#[inline]
+fn to_kurbo_point<Point: Coordinate2D>(point: &Point) -> kurbo::Point {
+    kurbo::Point::new(point.x(), point.y())
+}
+
+///
+/// Converts a set of paths to a kurbo `BezPath`, with one closed subpath for each path
+///
+pub fn paths_to_kurbo<P: BezierPath>(paths: &[P]) -> BezPath
+where
+    P::Point: Coordinate2D,
+{
+    let mut bez_path = BezPath::new();
+
+    for path in paths.iter() {
+        bez_path.move_to(to_kurbo_point(&path.start_point()));
+        for (cp1, cp2, end_point) in path.points() {
+            bez_path.curve_to(
+                to_kurbo_point(&cp1),
+                to_kurbo_point(&cp2),
+                to_kurbo_point(&end_point),
+            );
+        }
+        bez_path.close_path();
+    }
+
+    bez_path
+}
+
+///
+/// Converts a kurbo `BezPath` to a set of paths, with one path for each subpath
+///
+/// Lines and quadratic curves are converted to the equivalent cubic curves, and subpaths that aren't closed are closed
+/// with a straight line.
+///
+pub fn paths_from_kurbo<POut: BezierPathFactory>(bez_path: &BezPath) -> Vec<POut>
+where
+    POut::Point: Coordinate + Coordinate2D,
+{
+    let from_kurbo_point = |point: &kurbo::Point| POut::Point::from_components(&[point.x, point.y]);
+    let mut subpaths = SubpathBuilder::new();
+
+    for element in bez_path.elements() {
+        match element {
+            PathEl::MoveTo(point) => subpaths.move_to(from_kurbo_point(point)),
+            PathEl::LineTo(point) => subpaths.line_to(from_kurbo_point(point)),
+            PathEl::QuadTo(cp, point) => {
+                subpaths.quad_to(from_kurbo_point(cp), from_kurbo_point(point))
+            }
+            PathEl::CurveTo(cp1, cp2, point) => subpaths.curve_to(
+                from_kurbo_point(cp1),
+                from_kurbo_point(cp2),
+                from_kurbo_point(point),
+            ),
+            PathEl::ClosePath => subpaths.close(),
+        }
+    }
+
+    subpaths.build()
+}
+
+///
+/// Converts a curve of any type to a kurbo `CubicBez`
+///
+pub fn curve_to_kurbo<C: BezierCurve>(curve: &C) -> CubicBez
+where
+    C::Point: Coordinate2D,
+{
+    let (cp1, cp2) = curve.control_points();
+
+    CubicBez::new(
+        to_kurbo_point(&curve.start_point()),
+        to_kurbo_point(&cp1),
+        to_kurbo_point(&cp2),
+        to_kurbo_point(&curve.end_point()),
+    )
+}
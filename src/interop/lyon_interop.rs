@@ -0,0 +1,131 @@
+use super::subpaths::SubpathBuilder;
+use crate::bezier::path::{BezierPath, BezierPathFactory};
+use crate::bezier::{BezierCurve, Curve};
+use crate::geo::{Coord2, Coordinate, Coordinate2D};
+
+use lyon_path::geom::CubicBezierSegment;
+use lyon_path::math::{point, Point};
+use lyon_path::{Path, PathEvent};
+
+// lyon stores its coordinates as `f32`s, so converting from `flo_curves` to lyon loses precision
+
+impl From<Point> for Coord2 {
+    #[inline]
+    fn from(point: Point) -> Coord2 {
+        Coord2(point.x as f64, point.y as f64)
+    }
+}
+
+impl From<Coord2> for Point {
+    #[inline]
+    fn from(coord: Coord2) -> Point {
+        point(coord.0 as f32, coord.1 as f32)
+    }
+}
+
+impl From<CubicBezierSegment<f32>> for Curve<Coord2> {
+    #[inline]
+    fn from(curve: CubicBezierSegment<f32>) -> Curve<Coord2> {
+        Curve {
+            start_point: curve.from.into(),
+            end_point: curve.to.into(),
+            control_points: (curve.ctrl1.into(), curve.ctrl2.into()),
+        }
+    }
+}
+
+impl From<Curve<Coord2>> for CubicBezierSegment<f32> {
+    #[inline]
+    fn from(curve: Curve<Coord2>) -> CubicBezierSegment<f32> {
+        CubicBezierSegment {
+            from: curve.start_point.into(),
+            ctrl1: curve.control_points.0.into(),
+            ctrl2: curve.control_points.1.into(),
+            to: curve.end_point.into(),
+        }
+    }
+}
+
+///
+/// Converts a point of any coordinate type to a lyon point
+///
+#[inline]
+fn to_lyon_point<P: Coordinate2D>(coord: &P) -> Point {
+    point(coord.x() as f32, coord.y() as f32)
+}
+
+///
+/// Converts a set of paths to a lyon `Path`, with one closed subpath for each path
+///
+pub fn paths_to_lyon<P: BezierPath>(paths: &[P]) -> Path
+where
+    P::Point: Coordinate2D,
+{
+    let mut builder = Path::builder();
+
+    for path in paths.iter() {
+        builder.begin(to_lyon_point(&path.start_point()));
+        for (cp1, cp2, end_point) in path.points() {
+            builder.cubic_bezier_to(
+                to_lyon_point(&cp1),
+                to_lyon_point(&cp2),
+                to_lyon_point(&end_point),
+            );
+        }
+        builder.end(true);
+    }
+
+    builder.build()
+}
+
+///
+/// Converts a lyon `Path` to a set of paths, with one path for each subpath
+///
+/// Lines and quadratic curves are converted to the equivalent cubic curves, and subpaths that aren't closed are closed
+/// with a straight line.
+///
+pub fn paths_from_lyon<POut: BezierPathFactory>(path: &Path) -> Vec<POut>
+where
+    POut::Point: Coordinate + Coordinate2D,
+{
+    let from_lyon_point =
+        |point: Point| POut::Point::from_components(&[point.x as f64, point.y as f64]);
+    let mut subpaths = SubpathBuilder::new();
+
+    for event in path.iter() {
+        match event {
+            PathEvent::Begin { at } => subpaths.move_to(from_lyon_point(at)),
+            PathEvent::Line { to, .. } => subpaths.line_to(from_lyon_point(to)),
+            PathEvent::Quadratic { ctrl, to, .. } => {
+                subpaths.quad_to(from_lyon_point(ctrl), from_lyon_point(to))
+            }
+            PathEvent::Cubic {
+                ctrl1, ctrl2, to, ..
+            } => subpaths.curve_to(
+                from_lyon_point(ctrl1),
+                from_lyon_point(ctrl2),
+                from_lyon_point(to),
+            ),
+            PathEvent::End { .. } => subpaths.close(),
+        }
+    }
+
+    subpaths.build()
+}
+
+///
+/// Converts a curve of any type to a lyon `CubicBezierSegment`
+///
+pub fn curve_to_lyon<C: BezierCurve>(curve: &C) -> CubicBezierSegment<f32>
+where
+    C::Point: Coordinate2D,
+{
+    let (cp1, cp2) = curve.control_points();
+
+    CubicBezierSegment {
+        from: to_lyon_point(&curve.start_point()),
+        ctrl1: to_lyon_point(&cp1),
+        ctrl2: to_lyon_point(&cp2),
+        to: to_lyon_point(&curve.end_point()),
+    }
+}
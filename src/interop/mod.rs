@@ -0,0 +1,27 @@
+//!
+//! # Converting to and from other curve libraries
+//!
+//! Paths often need to be passed between `flo_curves` and other libraries, for rendering or for use with their own
+//! algorithms. The conversions in this module are each enabled by a cargo feature, so the other libraries are only
+//! needed by code that uses them:
+//!
+//! * `kurbo_interop` converts between `Coord2`, `Curve` and paths and the kurbo `Point`, `CubicBez` and `BezPath` types
+//! * `lyon_interop` converts between `Coord2`, `Curve` and paths and the lyon `Point`, `CubicBezierSegment` and `Path` types
+//!
+//! Coordinates and curves implement `From` in both directions. Paths in `flo_curves` are made up of a list of subpaths, so
+//! they're converted with the `paths_to_` and `paths_from_` functions. Lines and quadratic curves in the other libraries'
+//! paths are converted to the equivalent cubic curves, and subpaths that are not closed are closed with a straight line
+//! as `flo_curves` always treats paths as closed.
+//!
+
+#[cfg(feature = "kurbo_interop")]
+mod kurbo_interop;
+#[cfg(feature = "lyon_interop")]
+mod lyon_interop;
+#[cfg(any(feature = "kurbo_interop", feature = "lyon_interop"))]
+mod subpaths;
+
+#[cfg(feature = "kurbo_interop")]
+pub use self::kurbo_interop::*;
+#[cfg(feature = "lyon_interop")]
+pub use self::lyon_interop::*;
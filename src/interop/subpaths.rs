@@ -0,0 +1,120 @@
+use crate::bezier::path::BezierPathFactory;
+use crate::bezier::{Curve, QuadraticCurve};
+use crate::geo::Coordinate;
+
+/// A subpath, as a start point and a list of curves
+type Subpath<Point> = (Point, Vec<(Point, Point, Point)>);
+
+///
+/// Collects the subpaths of a path from another library into a set of `flo_curves` paths
+///
+/// Lines and quadratic curves are converted to cubic curves, and subpaths that don't end where they started are closed
+/// with a straight line.
+///
+pub(super) struct SubpathBuilder<Point> {
+    /// The start point and the curves of the subpath that's being built
+    current: Option<Subpath<Point>>,
+
+    /// The subpaths that have been completed so far
+    subpaths: Vec<Subpath<Point>>,
+}
+
+impl<Point: Coordinate> SubpathBuilder<Point> {
+    ///
+    /// Creates a builder with no subpaths
+    ///
+    pub(super) fn new() -> Self {
+        SubpathBuilder {
+            current: None,
+            subpaths: vec![],
+        }
+    }
+
+    ///
+    /// The point that the next curve will start at
+    ///
+    fn last_point(&self) -> Option<Point> {
+        self.current.as_ref().map(|(start, curves)| {
+            curves
+                .last()
+                .map(|(_, _, end_point)| *end_point)
+                .unwrap_or(*start)
+        })
+    }
+
+    ///
+    /// Starts a new subpath, ending the current one
+    ///
+    pub(super) fn move_to(&mut self, point: Point) {
+        self.close();
+        self.current = Some((point, vec![]));
+    }
+
+    ///
+    /// Adds a straight line to the current subpath
+    ///
+    pub(super) fn line_to(&mut self, point: Point) {
+        let start = self.last_point().unwrap_or(point);
+        let cp1 = start + (point - start) * (1.0 / 3.0);
+        let cp2 = start + (point - start) * (2.0 / 3.0);
+
+        self.curve_to(cp1, cp2, point);
+    }
+
+    ///
+    /// Adds a quadratic curve to the current subpath
+    ///
+    pub(super) fn quad_to(&mut self, control_point: Point, point: Point) {
+        let start = self.last_point().unwrap_or(control_point);
+        let quadratic = QuadraticCurve::from_points(start, control_point, point);
+        let cubic = quadratic.to_cubic::<Curve<_>>();
+
+        self.curve_to(cubic.control_points.0, cubic.control_points.1, point);
+    }
+
+    ///
+    /// Adds a cubic curve to the current subpath
+    ///
+    pub(super) fn curve_to(&mut self, cp1: Point, cp2: Point, point: Point) {
+        if self.current.is_none() {
+            // Curves with no preceding move start a subpath at their first control point
+            self.current = Some((cp1, vec![]));
+        }
+
+        if let Some((_, curves)) = self.current.as_mut() {
+            curves.push((cp1, cp2, point));
+        }
+    }
+
+    ///
+    /// Closes the current subpath, adding a line back to the start if it doesn't end there
+    ///
+    pub(super) fn close(&mut self) {
+        if let (Some(start), Some(last_point)) = (
+            self.current.as_ref().map(|(start, _)| *start),
+            self.last_point(),
+        ) {
+            if !last_point.is_near_to(&start, 1e-9) {
+                self.line_to(start);
+            }
+        }
+
+        if let Some((start, curves)) = self.current.take() {
+            if !curves.is_empty() {
+                self.subpaths.push((start, curves));
+            }
+        }
+    }
+
+    ///
+    /// Returns the subpaths that were built, as paths
+    ///
+    pub(super) fn build<POut: BezierPathFactory<Point = Point>>(mut self) -> Vec<POut> {
+        self.close();
+
+        self.subpaths
+            .into_iter()
+            .map(|(start, curves)| POut::from_points(start, curves))
+            .collect()
+    }
+}
@@ -20,11 +20,18 @@
 
 #[macro_use]
 mod test_assert;
+#[macro_use]
+mod trace_event;
 pub mod arc;
 pub mod bezier;
 mod consts;
 pub mod debug;
+pub mod interop;
 pub mod line;
+pub mod primitives;
+pub mod surface;
+#[cfg(feature = "testing")]
+pub mod testing;
 
 pub mod geo;
 pub use self::geo::*;
@@ -1,5 +1,6 @@
 use super::super::geo::{Coordinate, Coordinate2D};
 use super::line::Line;
+use super::ray::Ray;
 
 /// Smallest divisor magnitude to use in ray_intersects_ray (the closer the divisor is to 0, the more close to parallel the lines are), so this
 /// determines the shallowest angle allowed between two lines before we consider them to be parallel.
@@ -13,6 +14,20 @@ const RAY_DIVISOR_SMALLEST_VALUE: f64 = 2e-12;
 /// in higher dimensions)
 ///
 pub fn line_intersects_line<L: Line>(line1: &L, line2: &L) -> Option<L::Point>
+where
+    L::Point: Coordinate2D,
+{
+    segment_intersects_segment(line1, line2).map(|(point, _, _)| point)
+}
+
+///
+/// Returns the point at which two line segments intersect, along with the t value of the intersection along each of the
+/// segments (where t=0 is the start of the segment and t=1 is the end)
+///
+/// Parallel segments (including collinear segments that overlap) are not considered to intersect. Only the 2-dimensional
+/// form is supported at the moment.
+///
+pub fn segment_intersects_segment<L: Line>(line1: &L, line2: &L) -> Option<(L::Point, f64, f64)>
 where
     L::Point: Coordinate2D,
 {
@@ -22,16 +37,19 @@ where
     let ((x1, y1), (x2, y2)) = (line1_points.0.coords(), line1_points.1.coords());
     let ((x3, y3), (x4, y4)) = (line2_points.0.coords(), line2_points.1.coords());
 
-    let ua = ((x4 - x3) * (y1 - y3) - (y4 - y3) * (x1 - x3))
-        / ((y4 - y3) * (x2 - x1) - (x4 - x3) * (y2 - y1));
-    let ub = ((x2 - x1) * (y1 - y3) - (y2 - y1) * (x1 - x3))
-        / ((y4 - y3) * (x2 - x1) - (x4 - x3) * (y2 - y1));
+    let divisor = (y4 - y3) * (x2 - x1) - (x4 - x3) * (y2 - y1);
+    if divisor == 0.0 {
+        // Lines are parallel
+        return None;
+    }
 
-    if (0.0..=1.0).contains(&ua) && ub >= 0.0 && ub <= 1.0 {
-        Some(L::Point::from_components(&[
-            x1 + (ua * (x2 - x1)),
-            y1 + (ua * (y2 - y1)),
-        ]))
+    let ua = ((x4 - x3) * (y1 - y3) - (y4 - y3) * (x1 - x3)) / divisor;
+    let ub = ((x2 - x1) * (y1 - y3) - (y2 - y1) * (x1 - x3)) / divisor;
+
+    if (0.0..=1.0).contains(&ua) && (0.0..=1.0).contains(&ub) {
+        let point = L::Point::from_components(&[x1 + (ua * (x2 - x1)), y1 + (ua * (y2 - y1))]);
+
+        Some((point, ua, ub))
     } else {
         None
     }
@@ -97,6 +115,44 @@ where
     }
 }
 
+///
+/// Returns the point at which a line and a ray intersect (if they intersect), along with the t value of the intersection
+/// along the ray
+///
+/// This is the same as `line_intersects_ray()`, except the ray is described explicitly by a `Ray` rather than a `Line`.
+///
+pub fn line_ray_intersection<L, R>(line: &L, ray: &R) -> Option<(L::Point, f64)>
+where
+    L: Line,
+    L::Point: Coordinate2D,
+    R: Ray<Point = L::Point>,
+{
+    let point = line_intersects_ray(&line.points(), &ray.to_line())?;
+    let direction = ray.direction();
+    let t = (point - ray.origin()).dot(&direction) / direction.dot(&direction);
+
+    Some((point, t))
+}
+
+///
+/// Returns the point at which two rays intersect (if they intersect), along with the t value of the intersection along
+/// each ray
+///
+/// This is the same as `ray_intersects_ray()`, except the rays are described explicitly by a `Ray` rather than a `Line`.
+///
+pub fn ray_ray_intersection<R: Ray>(ray1: &R, ray2: &R) -> Option<(R::Point, f64, f64)>
+where
+    R::Point: Coordinate2D,
+{
+    let point = ray_intersects_ray(&ray1.to_line(), &ray2.to_line())?;
+    let t_for_ray = |ray: &R| {
+        let direction = ray.direction();
+        (point - ray.origin()).dot(&direction) / direction.dot(&direction)
+    };
+
+    Some((point, t_for_ray(ray1), t_for_ray(ray2)))
+}
+
 ///
 /// Determines if a 2D line has intersected a bounding box (and returns the intersection if it exists)
 ///
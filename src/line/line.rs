@@ -1,3 +1,5 @@
+#[cfg(feature = "robust_predicates")]
+use super::super::geo::orient_2d;
 use super::super::geo::{Coordinate, Coordinate2D, Geo};
 use super::coefficients::line_coefficients_2d;
 
@@ -131,10 +133,14 @@ impl<Point: Coordinate2D + Coordinate + Clone, L: Line<Point = Point>> Line2D fo
     fn which_side(&self, p: &Self::Point) -> i8 {
         let (start, end) = self.points();
 
+        #[cfg(not(feature = "robust_predicates"))]
         let side = ((p.x() - start.x()) * (end.y() - start.y())
             - (p.y() - start.y()) * (end.x() - start.x()))
         .signum();
 
+        #[cfg(feature = "robust_predicates")]
+        let side = (0.0 - orient_2d(&start, &end, p)).signum();
+
         if side < 0.0 {
             -1
         } else if side > 0.0 {
@@ -8,15 +8,20 @@
 //! The basic line type is simply a tuple of two points (that is, any tuple of two values of the same type that
 //! implements `Coordinate`).
 //!
+//! Rays (lines that extend infinitely through a point in a particular direction) are represented by the `Ray`
+//! trait. `SimpleRay` is the basic implementation, storing an origin and a direction.
+//!
 
 mod coefficients;
 mod intersection;
 mod line;
+mod ray;
 mod to_curve;
 
 pub use self::coefficients::*;
 pub use self::intersection::*;
 pub use self::line::*;
+pub use self::ray::*;
 pub use self::to_curve::*;
 
 pub use super::geo::*;
@@ -0,0 +1,90 @@
+use super::super::geo::{Coordinate, Geo};
+
+///
+/// Represents a ray: an infinitely long line passing through an origin point in a particular direction
+///
+/// The points on a ray are described by a `t` value, with the point at `t` being `origin + direction * t`. As with the
+/// other ray functions in this library, a ray extends in both directions from its origin: points 'behind' the origin have
+/// negative `t` values, which can be filtered out when only the half-line in front of the origin is of interest.
+///
+pub trait Ray: Geo {
+    ///
+    /// The point that the ray passes through at `t = 0`
+    ///
+    fn origin(&self) -> Self::Point;
+
+    ///
+    /// The direction of the ray (the distance moved for every unit of `t`)
+    ///
+    fn direction(&self) -> Self::Point;
+
+    ///
+    /// Returns the point at a particular `t` value along this ray
+    ///
+    #[inline]
+    fn point_at_pos(&self, t: f64) -> Self::Point {
+        self.origin() + self.direction() * t
+    }
+
+    ///
+    /// Returns the line from the origin of this ray to the point at `t = 1`
+    ///
+    /// This can be used with the functions that take a `Line` and treat it as a ray: the `t` values they return for the line
+    /// are the same as the `t` values along this ray.
+    ///
+    #[inline]
+    fn to_line(&self) -> (Self::Point, Self::Point) {
+        let origin = self.origin();
+
+        (origin, origin + self.direction())
+    }
+}
+
+///
+/// A ray described by its origin and direction
+///
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct SimpleRay<Point> {
+    /// The point that the ray passes through at `t = 0`
+    pub origin: Point,
+
+    /// The direction of the ray
+    pub direction: Point,
+}
+
+impl<Point: Coordinate> SimpleRay<Point> {
+    ///
+    /// Creates a new ray from an origin and a direction
+    ///
+    #[inline]
+    pub fn new(origin: Point, direction: Point) -> Self {
+        SimpleRay { origin, direction }
+    }
+
+    ///
+    /// Creates the ray that starts at `from` and passes through `to` at `t = 1`
+    ///
+    #[inline]
+    pub fn through(from: Point, to: Point) -> Self {
+        SimpleRay {
+            origin: from,
+            direction: to - from,
+        }
+    }
+}
+
+impl<Point: Coordinate> Geo for SimpleRay<Point> {
+    type Point = Point;
+}
+
+impl<Point: Coordinate> Ray for SimpleRay<Point> {
+    #[inline]
+    fn origin(&self) -> Point {
+        self.origin
+    }
+
+    #[inline]
+    fn direction(&self) -> Point {
+        self.direction
+    }
+}
@@ -0,0 +1,49 @@
+use crate::bezier::path::BezierPathFactory;
+use crate::geo::{Coordinate, Coordinate2D};
+
+/// Distance of the control points from the ends of a bezier curve approximating a quarter of a unit circle
+const QUARTER_CIRCLE_KAPPA: f64 = 0.552_284_749_830_793_6;
+
+///
+/// Creates an axis-aligned ellipse from its center and its radius along the x and y axes
+///
+/// The ellipse is made from four curves, starting at the point on the positive x axis.
+///
+pub fn ellipse_path<P>(center: P::Point, radius_x: f64, radius_y: f64) -> P
+where
+    P: BezierPathFactory,
+    P::Point: Coordinate2D,
+{
+    let (cx, cy) = (center.x(), center.y());
+    let (kx, ky) = (
+        radius_x * QUARTER_CIRCLE_KAPPA,
+        radius_y * QUARTER_CIRCLE_KAPPA,
+    );
+    let point = |x: f64, y: f64| P::Point::from_components(&[cx + x, cy + y]);
+
+    P::from_points(
+        point(radius_x, 0.0),
+        vec![
+            (
+                point(radius_x, ky),
+                point(kx, radius_y),
+                point(0.0, radius_y),
+            ),
+            (
+                point(-kx, radius_y),
+                point(-radius_x, ky),
+                point(-radius_x, 0.0),
+            ),
+            (
+                point(-radius_x, -ky),
+                point(-kx, -radius_y),
+                point(0.0, -radius_y),
+            ),
+            (
+                point(kx, -radius_y),
+                point(radius_x, -ky),
+                point(radius_x, 0.0),
+            ),
+        ],
+    )
+}
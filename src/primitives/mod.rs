@@ -0,0 +1,18 @@
+//!
+//! # Constructors for common shapes
+//!
+//! The functions in this module create bezier paths for simple shapes: rectangles (optionally with rounded corners),
+//! ellipses, superellipses (including the 'squircle'), regular polygons and stars. They can generate any type that
+//! implements `BezierPathFactory`. All of the shapes are closed paths that run anticlockwise (with the y axis pointing
+//! upwards).
+//!
+
+mod ellipse;
+mod polygon;
+mod rectangle;
+mod superellipse;
+
+pub use self::ellipse::*;
+pub use self::polygon::*;
+pub use self::rectangle::*;
+pub use self::superellipse::*;
@@ -0,0 +1,81 @@
+use crate::bezier::path::{BezierPathBuilder, BezierPathFactory};
+use crate::geo::{Coordinate, Coordinate2D};
+
+use std::f64;
+
+///
+/// Creates a closed path through a list of vertices
+///
+fn polygon_through<P: BezierPathFactory>(vertices: Vec<P::Point>) -> P {
+    let mut builder = BezierPathBuilder::<P>::start(vertices[0]);
+
+    for vertex in vertices.iter().skip(1).chain(vertices.iter().take(1)) {
+        builder = builder.line_to(*vertex);
+    }
+
+    builder.build()
+}
+
+///
+/// Creates a regular polygon with the specified number of sides
+///
+/// `radius` is the distance from the center to each vertex. The first vertex is at the angle `rotation` (in radians,
+/// anticlockwise from the positive x axis). Fewer than 3 sides are treated as 3 sides.
+///
+pub fn regular_polygon_path<P>(center: P::Point, radius: f64, num_sides: usize, rotation: f64) -> P
+where
+    P: BezierPathFactory,
+    P::Point: Coordinate2D,
+{
+    let num_sides = num_sides.max(3);
+    let vertices = (0..num_sides)
+        .map(|side| {
+            let angle = rotation + (side as f64) * 2.0 * f64::consts::PI / (num_sides as f64);
+
+            P::Point::from_components(&[
+                center.x() + radius * angle.cos(),
+                center.y() + radius * angle.sin(),
+            ])
+        })
+        .collect();
+
+    polygon_through(vertices)
+}
+
+///
+/// Creates a star with the specified number of points
+///
+/// The tips of the star are `outer_radius` from the center, and the vertices between them are `inner_radius` from the
+/// center. The first tip is at the angle `rotation` (in radians, anticlockwise from the positive x axis). Fewer than 2
+/// points are treated as 2 points.
+///
+pub fn star_path<P>(
+    center: P::Point,
+    outer_radius: f64,
+    inner_radius: f64,
+    num_points: usize,
+    rotation: f64,
+) -> P
+where
+    P: BezierPathFactory,
+    P::Point: Coordinate2D,
+{
+    let num_points = num_points.max(2);
+    let vertices = (0..num_points * 2)
+        .map(|vertex| {
+            let angle = rotation + (vertex as f64) * f64::consts::PI / (num_points as f64);
+            let radius = if vertex % 2 == 0 {
+                outer_radius
+            } else {
+                inner_radius
+            };
+
+            P::Point::from_components(&[
+                center.x() + radius * angle.cos(),
+                center.y() + radius * angle.sin(),
+            ])
+        })
+        .collect();
+
+    polygon_through(vertices)
+}
@@ -0,0 +1,69 @@
+use crate::bezier::path::{BezierPathBuilder, BezierPathFactory};
+use crate::geo::{Coordinate, Coordinate2D};
+
+///
+/// Creates a rectangle from its minimum and maximum corners
+///
+pub fn rectangle_path<P>(min: P::Point, max: P::Point) -> P
+where
+    P: BezierPathFactory,
+    P::Point: Coordinate2D,
+{
+    rounded_rectangle_path(min, max, [0.0; 4])
+}
+
+///
+/// Creates a rectangle with rounded corners
+///
+/// The radii are given for each corner, in the order (min x, min y), (max x, min y), (max x, max y), (min x, max y). A radius
+/// of 0 leaves that corner square. If the radii on one side add up to more than the length of that side, every radius is
+/// scaled down so that they fit.
+///
+pub fn rounded_rectangle_path<P>(min: P::Point, max: P::Point, radii: [f64; 4]) -> P
+where
+    P: BezierPathFactory,
+    P::Point: Coordinate2D,
+{
+    let (min_x, min_y) = (min.x().min(max.x()), min.y().min(max.y()));
+    let (max_x, max_y) = (min.x().max(max.x()), min.y().max(max.y()));
+    let (width, height) = (max_x - min_x, max_y - min_y);
+
+    // Scale the radii so that the corners on each side fit
+    let radii = [
+        radii[0].max(0.0),
+        radii[1].max(0.0),
+        radii[2].max(0.0),
+        radii[3].max(0.0),
+    ];
+    let scale = [
+        (radii[0] + radii[1], width),
+        (radii[1] + radii[2], height),
+        (radii[2] + radii[3], width),
+        (radii[3] + radii[0], height),
+    ]
+    .iter()
+    .filter(|(total, _)| *total > 0.0)
+    .map(|(total, length)| length / total)
+    .fold(1.0, f64::min);
+
+    let corners = [
+        P::Point::from_components(&[min_x, min_y]),
+        P::Point::from_components(&[max_x, min_y]),
+        P::Point::from_components(&[max_x, max_y]),
+        P::Point::from_components(&[min_x, max_y]),
+    ];
+
+    // Draw the rectangle, rounding off each corner (the corner at the start is rounded when the path is closed)
+    let mut builder = BezierPathBuilder::<P>::start(corners[0]);
+
+    for corner_idx in [1, 2, 3, 0].iter() {
+        builder = builder.line_to(corners[*corner_idx]);
+
+        let radius = radii[*corner_idx] * scale;
+        if radius > 0.0 {
+            builder = builder.fillet(radius);
+        }
+    }
+
+    builder.build()
+}
@@ -0,0 +1,112 @@
+use crate::bezier::path::BezierPathFactory;
+use crate::bezier::{fit_curve_cubic, BezierCurve, BezierCurveFactory, Curve};
+use crate::geo::{Coordinate, Coordinate2D};
+
+use std::f64;
+
+/// Number of points sampled from each quarter of a superellipse when fitting curves to it
+const POINTS_PER_QUADRANT: usize = 64;
+
+/// The exponent of the superellipse generated by `squircle_path()`
+const SQUIRCLE_EXPONENT: f64 = 4.0;
+
+///
+/// Creates an axis-aligned superellipse (also called a Lamé curve), the shape made from the points where
+/// `|x/radius_x|^exponent + |y/radius_y|^exponent = 1`
+///
+/// An exponent of 2 produces an ellipse, and larger exponents produce shapes that are closer to a rectangle with smoothly
+/// rounded corners (the 'squircle' used for icons is usually a superellipse with an exponent of 4 or 5). An exponent of
+/// 1 produces a diamond, and exponents between 0 and 1 produce a star with four pointed arms. The shape is generated by
+/// fitting curves to points sampled from its first quarter, to within `max_error`, and then reflecting them to make the
+/// other quarters, so the path is symmetrical and starts at the point on the positive x axis.
+///
+pub fn superellipse_path<P>(
+    center: P::Point,
+    radius_x: f64,
+    radius_y: f64,
+    exponent: f64,
+    max_error: f64,
+) -> P
+where
+    P: BezierPathFactory,
+    P::Point: Coordinate2D,
+{
+    let exponent = exponent.max(f64::EPSILON);
+    let point = |x: f64, y: f64| P::Point::from_components(&[x, y]);
+
+    // Fit curves against the first quadrant, which runs from the x axis to the y axis
+    let quadrant = if exponent == 1.0 {
+        vec![Curve::from_points(
+            point(radius_x, 0.0),
+            (
+                point(radius_x * 2.0 / 3.0, radius_y / 3.0),
+                point(radius_x / 3.0, radius_y * 2.0 / 3.0),
+            ),
+            point(0.0, radius_y),
+        )]
+    } else {
+        let power = 2.0 / exponent;
+        let samples = (0..=POINTS_PER_QUADRANT)
+            .map(|idx| {
+                let angle = (idx as f64) / (POINTS_PER_QUADRANT as f64) * f64::consts::FRAC_PI_2;
+                let (sin, cos) = angle.sin_cos();
+
+                point(radius_x * cos.powf(power), radius_y * sin.powf(power))
+            })
+            .collect::<Vec<_>>();
+
+        // Shapes with exponents greater than 1 bulge outwards and meet the axes at right angles, and shapes with lower
+        // exponents come to a point along the axes
+        let (start_tangent, end_tangent) = if exponent > 1.0 {
+            (point(0.0, 1.0), point(1.0, 0.0))
+        } else {
+            (point(-1.0, 0.0), point(0.0, -1.0))
+        };
+
+        fit_curve_cubic::<Curve<_>>(&samples, &start_tangent, &end_tangent, max_error)
+    };
+
+    // Reflect the first quadrant to generate the others, running anticlockwise around the shape
+    let (cx, cy) = (center.x(), center.y());
+    let reflect =
+        |p: P::Point, x_sign: f64, y_sign: f64| point(cx + p.x() * x_sign, cy + p.y() * y_sign);
+
+    let mut sections = vec![];
+    for (x_sign, y_sign) in [(1.0, 1.0), (-1.0, 1.0), (-1.0, -1.0), (1.0, -1.0)] {
+        // Reflecting in just one axis reverses the direction of the curves
+        let reversed = x_sign * y_sign < 0.0;
+
+        if reversed {
+            for curve in quadrant.iter().rev() {
+                let (cp1, cp2) = curve.control_points();
+                sections.push((
+                    reflect(cp2, x_sign, y_sign),
+                    reflect(cp1, x_sign, y_sign),
+                    reflect(curve.start_point(), x_sign, y_sign),
+                ));
+            }
+        } else {
+            for curve in quadrant.iter() {
+                let (cp1, cp2) = curve.control_points();
+                sections.push((
+                    reflect(cp1, x_sign, y_sign),
+                    reflect(cp2, x_sign, y_sign),
+                    reflect(curve.end_point(), x_sign, y_sign),
+                ));
+            }
+        }
+    }
+
+    P::from_points(point(cx + radius_x, cy), sections)
+}
+
+///
+/// Creates a 'squircle': a superellipse with an exponent of 4, which looks like a square with smoothly rounded corners
+///
+pub fn squircle_path<P>(center: P::Point, radius: f64, max_error: f64) -> P
+where
+    P: BezierPathFactory,
+    P::Point: Coordinate2D,
+{
+    superellipse_path(center, radius, radius, SQUIRCLE_EXPONENT, max_error)
+}
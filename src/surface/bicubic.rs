@@ -0,0 +1,156 @@
+use crate::bezier::{de_casteljau4, BezierCurveFactory, Curve};
+use crate::geo::{Coordinate, Coordinate3D, Geo};
+
+///
+/// A bicubic (tensor product) bezier patch
+///
+/// The surface is defined by a 4x4 grid of control points, indexed as `control_points[v][u]`. Each row of the grid is
+/// a bezier curve in the `u` direction and each column is a bezier curve in the `v` direction. The patch passes through
+/// the corner control points, and its edges are the curves formed by the outer rows and columns.
+///
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct BicubicPatch<Point> {
+    /// The control points for this patch, as `[v][u]`
+    pub control_points: [[Point; 4]; 4],
+}
+
+impl<Point: Coordinate> Geo for BicubicPatch<Point> {
+    type Point = Point;
+}
+
+///
+/// Returns the control points of the derivative of a cubic bezier curve (a quadratic curve)
+///
+#[inline]
+fn derivative_points<Point: Coordinate>(w: &[Point; 4]) -> [Point; 3] {
+    [
+        (w[1] - w[0]) * 3.0,
+        (w[2] - w[1]) * 3.0,
+        (w[3] - w[2]) * 3.0,
+    ]
+}
+
+///
+/// Evaluates a quadratic bezier curve
+///
+#[inline]
+fn de_casteljau3<Point: Coordinate>(t: f64, w: &[Point; 3]) -> Point {
+    let mt = 1.0 - t;
+
+    w[0] * (mt * mt) + w[1] * (2.0 * mt * t) + w[2] * (t * t)
+}
+
+impl<Point: Coordinate> BicubicPatch<Point> {
+    ///
+    /// Creates a patch from a grid of control points, indexed as `[v][u]`
+    ///
+    pub fn new(control_points: [[Point; 4]; 4]) -> Self {
+        BicubicPatch { control_points }
+    }
+
+    ///
+    /// The control points of the curve in the `v` direction through column `u_idx` of the control point grid
+    ///
+    #[inline]
+    fn column(&self, u_idx: usize) -> [Point; 4] {
+        let cp = &self.control_points;
+
+        [cp[0][u_idx], cp[1][u_idx], cp[2][u_idx], cp[3][u_idx]]
+    }
+
+    ///
+    /// Evaluates each row of the grid at `u`, returning the control points of the curve across the patch at that `u` value
+    ///
+    #[inline]
+    fn rows_at(&self, u: f64) -> [Point; 4] {
+        let cp = &self.control_points;
+        let row =
+            |v_idx: usize| de_casteljau4(u, cp[v_idx][0], cp[v_idx][1], cp[v_idx][2], cp[v_idx][3]);
+
+        [row(0), row(1), row(2), row(3)]
+    }
+
+    ///
+    /// Evaluates each column of the grid at `v`, returning the control points of the curve across the patch at that `v` value
+    ///
+    #[inline]
+    fn columns_at(&self, v: f64) -> [Point; 4] {
+        let column = |u_idx: usize| {
+            let w = self.column(u_idx);
+            de_casteljau4(v, w[0], w[1], w[2], w[3])
+        };
+
+        [column(0), column(1), column(2), column(3)]
+    }
+
+    ///
+    /// Finds the point on this patch at a particular `(u, v)` position
+    ///
+    pub fn point_at(&self, u: f64, v: f64) -> Point {
+        let w = self.rows_at(u);
+
+        de_casteljau4(v, w[0], w[1], w[2], w[3])
+    }
+
+    ///
+    /// Returns the partial derivative of the surface in the `u` direction at a particular position
+    ///
+    pub fn tangent_u(&self, u: f64, v: f64) -> Point {
+        let w = self.columns_at(v);
+
+        de_casteljau3(u, &derivative_points(&w))
+    }
+
+    ///
+    /// Returns the partial derivative of the surface in the `v` direction at a particular position
+    ///
+    pub fn tangent_v(&self, u: f64, v: f64) -> Point {
+        let w = self.rows_at(u);
+
+        de_casteljau3(v, &derivative_points(&w))
+    }
+
+    ///
+    /// Returns the curve across this patch where `v` has a particular value (going from `u = 0` to `u = 1`)
+    ///
+    pub fn curve_at_v(&self, v: f64) -> Curve<Point> {
+        let w = self.columns_at(v);
+
+        Curve::from_points(w[0], (w[1], w[2]), w[3])
+    }
+
+    ///
+    /// Returns the curve across this patch where `u` has a particular value (going from `v = 0` to `v = 1`)
+    ///
+    pub fn curve_at_u(&self, u: f64) -> Curve<Point> {
+        let w = self.rows_at(u);
+
+        Curve::from_points(w[0], (w[1], w[2]), w[3])
+    }
+}
+
+impl<Point: Coordinate + Coordinate3D> BicubicPatch<Point> {
+    ///
+    /// Returns the unit normal of the surface at a particular position
+    ///
+    /// The normal is the cross product of the `u` and `v` tangents, so it faces towards the viewer when `u` increases to
+    /// the right and `v` increases upwards. At degenerate points where there's no well-defined normal (for example, where
+    /// a row of control points collapses to a single point), this returns `None`.
+    ///
+    pub fn normal_at(&self, u: f64, v: f64) -> Option<Point> {
+        let du = self.tangent_u(u, v);
+        let dv = self.tangent_v(u, v);
+
+        let normal = Point::from_components(&[
+            du.y() * dv.z() - du.z() * dv.y(),
+            du.z() * dv.x() - du.x() * dv.z(),
+            du.x() * dv.y() - du.y() * dv.x(),
+        ]);
+
+        if normal.magnitude() > 0.0 {
+            Some(normal.to_unit_vector())
+        } else {
+            None
+        }
+    }
+}
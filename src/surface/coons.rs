@@ -0,0 +1,184 @@
+use crate::bezier::{BezierCurve, BezierCurveFactory, Curve};
+use crate::geo::{Coordinate, Geo};
+
+///
+/// A Coons patch: a surface bounded by four bezier curves
+///
+/// The patch is described by a position `(u, v)` where both `u` and `v` are in the range `0.0..=1.0`. The corners of the
+/// patch are at `(0, 0)`, `(1, 0)`, `(1, 1)` and `(0, 1)` and the boundary curves join these corners.
+///
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct CoonsPatch<Point: Coordinate> {
+    /// The boundary curve where `v` is 0, from `(0, 0)` to `(1, 0)`
+    v0: Curve<Point>,
+
+    /// The boundary curve where `v` is 1, from `(0, 1)` to `(1, 1)`
+    v1: Curve<Point>,
+
+    /// The boundary curve where `u` is 0, from `(0, 0)` to `(0, 1)`
+    u0: Curve<Point>,
+
+    /// The boundary curve where `u` is 1, from `(1, 0)` to `(1, 1)`
+    u1: Curve<Point>,
+}
+
+impl<Point: Coordinate> Geo for CoonsPatch<Point> {
+    type Point = Point;
+}
+
+///
+/// Returns the control points of a straight line as a bezier curve, with the points evenly spaced along it
+///
+#[inline]
+fn linear_control_points<Point: Coordinate>(start: Point, end: Point) -> [Point; 4] {
+    let offset = end - start;
+
+    [
+        start,
+        start + offset * (1.0 / 3.0),
+        start + offset * (2.0 / 3.0),
+        end,
+    ]
+}
+
+///
+/// Returns the start point, control points and end point of a curve as an array
+///
+#[inline]
+fn curve_points<Point: Coordinate>(curve: &Curve<Point>) -> [Point; 4] {
+    let (cp1, cp2) = curve.control_points();
+
+    [curve.start_point(), cp1, cp2, curve.end_point()]
+}
+
+impl<Point: Coordinate> CoonsPatch<Point> {
+    ///
+    /// Creates a Coons patch from four curves that form a loop around its edge
+    ///
+    /// Each curve should start where the previous one ends: `top` goes from `(0, 0)` to `(1, 0)`, `right` from `(1, 0)` to
+    /// `(1, 1)`, `bottom` from `(1, 1)` to `(0, 1)` and `left` from `(0, 1)` back to `(0, 0)`. This is the same order the
+    /// edges of a patch are described in by gradient mesh formats such as the PDF and SVG ones.
+    ///
+    pub fn new(
+        top: Curve<Point>,
+        right: Curve<Point>,
+        bottom: Curve<Point>,
+        left: Curve<Point>,
+    ) -> Self {
+        CoonsPatch {
+            v0: top,
+            u1: right,
+            v1: bottom.reverse(),
+            u0: left.reverse(),
+        }
+    }
+
+    ///
+    /// Returns the four boundary curves of this patch, in the same order they are passed to `new()`
+    ///
+    pub fn boundary_curves(&self) -> [Curve<Point>; 4] {
+        [self.v0, self.u1, self.v1.reverse(), self.u0.reverse()]
+    }
+
+    ///
+    /// Returns the corners of this patch, at `(0, 0)`, `(1, 0)`, `(1, 1)` and `(0, 1)`
+    ///
+    pub fn corners(&self) -> [Point; 4] {
+        [
+            self.v0.start_point(),
+            self.v0.end_point(),
+            self.v1.end_point(),
+            self.v1.start_point(),
+        ]
+    }
+
+    ///
+    /// Finds the point on this patch at a particular `(u, v)` position
+    ///
+    pub fn point_at(&self, u: f64, v: f64) -> Point {
+        let [p00, p10, p11, p01] = self.corners();
+
+        // Blend the curves on opposite sides of the patch, then remove the part of the corners that was counted twice
+        let ruled_v = self.v0.point_at_pos(u) * (1.0 - v) + self.v1.point_at_pos(u) * v;
+        let ruled_u = self.u0.point_at_pos(v) * (1.0 - u) + self.u1.point_at_pos(v) * u;
+        let bilinear = p00 * ((1.0 - u) * (1.0 - v))
+            + p10 * (u * (1.0 - v))
+            + p11 * (u * v)
+            + p01 * ((1.0 - u) * v);
+
+        ruled_v + ruled_u - bilinear
+    }
+
+    ///
+    /// Returns the curve across this patch where `v` has a particular value (going from `u = 0` to `u = 1`)
+    ///
+    /// The curves across a Coons patch whose boundaries are cubic curves are also cubic curves, so this is exact.
+    ///
+    pub fn curve_at_v(&self, v: f64) -> Curve<Point> {
+        let [p00, p10, p11, p01] = self.corners();
+        let v0 = curve_points(&self.v0);
+        let v1 = curve_points(&self.v1);
+
+        // The parts that vary linearly with u
+        let start = self.u0.point_at_pos(v) - (p00 * (1.0 - v) + p01 * v);
+        let end = self.u1.point_at_pos(v) - (p10 * (1.0 - v) + p11 * v);
+        let linear = linear_control_points(start, end);
+
+        let point = |idx: usize| v0[idx] * (1.0 - v) + v1[idx] * v + linear[idx];
+
+        Curve::from_points(point(0), (point(1), point(2)), point(3))
+    }
+
+    ///
+    /// Returns the curve across this patch where `u` has a particular value (going from `v = 0` to `v = 1`)
+    ///
+    /// As for `curve_at_v()`, this is exact.
+    ///
+    pub fn curve_at_u(&self, u: f64) -> Curve<Point> {
+        let [p00, p10, p11, p01] = self.corners();
+        let u0 = curve_points(&self.u0);
+        let u1 = curve_points(&self.u1);
+
+        // The parts that vary linearly with v
+        let start = self.v0.point_at_pos(u) - (p00 * (1.0 - u) + p10 * u);
+        let end = self.v1.point_at_pos(u) - (p01 * (1.0 - u) + p11 * u);
+        let linear = linear_control_points(start, end);
+
+        let point = |idx: usize| u0[idx] * (1.0 - u) + u1[idx] * u + linear[idx];
+
+        Curve::from_points(point(0), (point(1), point(2)), point(3))
+    }
+
+    ///
+    /// Returns the patch covering a rectangular region of this patch
+    ///
+    /// The boundaries of the returned patch are the curves across this patch at the edges of the region. The Coons patch
+    /// of these curves is the same surface as the region of this patch, so no accuracy is lost by subdividing.
+    ///
+    pub fn section(&self, u_min: f64, v_min: f64, u_max: f64, v_max: f64) -> CoonsPatch<Point> {
+        let section = |curve: Curve<Point>, t_min: f64, t_max: f64| {
+            Curve::from_curve(&curve.section(t_min, t_max))
+        };
+
+        CoonsPatch {
+            v0: section(self.curve_at_v(v_min), u_min, u_max),
+            v1: section(self.curve_at_v(v_max), u_min, u_max),
+            u0: section(self.curve_at_u(u_min), v_min, v_max),
+            u1: section(self.curve_at_u(u_max), v_min, v_max),
+        }
+    }
+
+    ///
+    /// Divides this patch into four sub-patches at `(u, v)`
+    ///
+    /// The patches are returned in the order `(0, 0)-(u, v)`, `(u, 0)-(1, v)`, `(u, v)-(1, 1)`, `(0, v)-(u, 1)`.
+    ///
+    pub fn subdivide(&self, u: f64, v: f64) -> [CoonsPatch<Point>; 4] {
+        [
+            self.section(0.0, 0.0, u, v),
+            self.section(u, 0.0, 1.0, v),
+            self.section(u, v, 1.0, 1.0),
+            self.section(0.0, v, u, 1.0),
+        ]
+    }
+}
@@ -0,0 +1,20 @@
+//!
+//! # Surfaces defined by bezier curves
+//!
+//! `CoonsPatch` describes a surface that fills the space between four boundary curves. It can be used with any
+//! coordinate type, so as well as describing shapes it can be used to interpolate colours across a gradient mesh.
+//!
+//! `BicubicPatch` is a tensor product bezier surface defined by a grid of control points. With 3D coordinates such as
+//! `Coord3`, it can also calculate the normals of the surface.
+//!
+//! `ruled_surface()` generates the surface made up of straight lines between two paths, such as a ribbon joining two
+//! space curves.
+//!
+
+mod bicubic;
+mod coons;
+mod ruled;
+
+pub use self::bicubic::*;
+pub use self::coons::*;
+pub use self::ruled::*;
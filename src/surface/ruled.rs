@@ -0,0 +1,104 @@
+use super::bicubic::BicubicPatch;
+use crate::bezier::path::{matching_sections, path_to_curves, BezierPath, PathMeasure};
+use crate::bezier::{BezierCurve, Curve};
+use crate::line::line_to_bezier;
+
+/// Maximum error to allow when measuring the lengths of the paths to find the rulings of a surface
+const RULING_MAX_ERROR: f64 = 1e-6;
+
+///
+/// Generates a ruled surface between two paths
+///
+/// A ruled surface is made up of straight lines (rulings) joining corresponding points on the two paths. The points are
+/// matched up by arc length, so the point a certain proportion of the way along `path_a` is joined to the point the same
+/// proportion of the way along `path_b`. The paths are not reversed or rotated to match each other: they should both go
+/// in the same direction.
+///
+/// The result is a list of patches, one for each section of the paths after they have been split at the joins in either
+/// path. In each patch, the `u` direction goes along the paths and the `v` direction goes from `path_a` (at `v = 0`) to
+/// `path_b` (at `v = 1`).
+///
+pub fn ruled_surface<PathA, PathB>(
+    path_a: &PathA,
+    path_b: &PathB,
+) -> Vec<BicubicPatch<PathA::Point>>
+where
+    PathA: BezierPath,
+    PathB: BezierPath<Point = PathA::Point>,
+{
+    let curves_a = path_to_curves::<_, Curve<_>>(path_a).collect::<Vec<_>>();
+    let curves_b = path_to_curves::<_, Curve<_>>(path_b).collect::<Vec<_>>();
+
+    if curves_a.is_empty() || curves_b.is_empty() {
+        return vec![];
+    }
+
+    let (sections_a, sections_b) = matching_sections(&curves_a, &curves_b);
+
+    sections_a
+        .iter()
+        .zip(sections_b.iter())
+        .map(|(section_a, section_b)| {
+            let (cp1_a, cp2_a) = section_a.control_points();
+            let (cp1_b, cp2_b) = section_b.control_points();
+            let row_a = [section_a.start_point(), cp1_a, cp2_a, section_a.end_point()];
+            let row_b = [section_b.start_point(), cp1_b, cp2_b, section_b.end_point()];
+
+            // Rows that interpolate linearly between the two curves make every curve in the v direction a straight line
+            let row_at = |v: f64| {
+                [
+                    row_a[0] * (1.0 - v) + row_b[0] * v,
+                    row_a[1] * (1.0 - v) + row_b[1] * v,
+                    row_a[2] * (1.0 - v) + row_b[2] * v,
+                    row_a[3] * (1.0 - v) + row_b[3] * v,
+                ]
+            };
+
+            BicubicPatch::new([row_a, row_at(1.0 / 3.0), row_at(2.0 / 3.0), row_b])
+        })
+        .collect()
+}
+
+///
+/// Generates evenly spaced cross-sections across the ruled surface between two paths
+///
+/// This returns `num_rulings` straight lines (as curves), each going from a point on `path_a` to the point the same
+/// proportion of its arc length along `path_b`. The first and last rulings join the start points and end points of the
+/// paths.
+///
+pub fn ruled_surface_rulings<PathA, PathB>(
+    path_a: &PathA,
+    path_b: &PathB,
+    num_rulings: usize,
+) -> Vec<Curve<PathA::Point>>
+where
+    PathA: BezierPath,
+    PathB: BezierPath<Point = PathA::Point>,
+{
+    let measure_a = PathMeasure::new(path_a, RULING_MAX_ERROR);
+    let measure_b = PathMeasure::new(path_b, RULING_MAX_ERROR);
+    let (length_a, length_b) = (measure_a.length(), measure_b.length());
+
+    let point_at = |measure: &PathMeasure<_>, start_point, length, proportion: f64| {
+        if measure.curves().is_empty() {
+            start_point
+        } else {
+            measure.point_at_length(length * proportion)
+        }
+    };
+
+    (0..num_rulings)
+        .map(|idx| {
+            let proportion = if num_rulings > 1 {
+                (idx as f64) / ((num_rulings - 1) as f64)
+            } else {
+                0.0
+            };
+
+            let start = point_at(&measure_a, path_a.start_point(), length_a, proportion);
+            let end = point_at(&measure_b, path_b.start_point(), length_b, proportion);
+
+            line_to_bezier(&(start, end))
+        })
+        .collect()
+}
@@ -0,0 +1,181 @@
+//!
+//! # Generating random curves and paths
+//!
+//! The generators in this module create random curves and shapes for testing code that uses `flo_curves`: they're
+//! useful for property tests and fuzzing, and for benchmarks that need a lot of varied input. They're enabled by the
+//! `testing` feature, and take any `rand` random number generator, so seeding the generator makes the results
+//! repeatable.
+//!
+//! The shapes are all star-shaped around their center (every point on the outline can be seen from the center), which
+//! guarantees that they don't intersect themselves. The outlines go anticlockwise.
+//!
+
+use crate::bezier::path::BezierPathFactory;
+use crate::bezier::{BezierCurveFactory, Curve};
+use crate::geo::{BoundingBox, Coordinate, Coordinate2D};
+
+use rand::Rng;
+
+use std::f64::consts::PI;
+
+///
+/// Generates a random point within a bounding box
+///
+pub fn random_point<Point, Bounds, R>(rng: &mut R, bounds: &Bounds) -> Point
+where
+    Point: Coordinate + Coordinate2D,
+    Bounds: BoundingBox<Point = Point>,
+    R: Rng + ?Sized,
+{
+    let (min, max) = (bounds.min(), bounds.max());
+
+    Point::from_components(&[
+        rng.gen_range(min.x()..=max.x()),
+        rng.gen_range(min.y()..=max.y()),
+    ])
+}
+
+///
+/// Generates a random curve with its start, end and control points all within a bounding box
+///
+pub fn random_curve<C, Bounds, R>(rng: &mut R, bounds: &Bounds) -> C
+where
+    C: BezierCurveFactory,
+    C::Point: Coordinate2D,
+    Bounds: BoundingBox<Point = C::Point>,
+    R: Rng + ?Sized,
+{
+    let start_point = random_point(rng, bounds);
+    let cp1 = random_point(rng, bounds);
+    let cp2 = random_point(rng, bounds);
+    let end_point = random_point(rng, bounds);
+
+    C::from_points(start_point, (cp1, cp2), end_point)
+}
+
+///
+/// Generates a random simple polygon (a path made up of straight lines that doesn't intersect itself) with `num_points`
+/// corners (at least 3), lying within `radius` of `center`
+///
+/// The corners are placed at random angles around the center, between `radius/2` and `radius` away from it.
+///
+pub fn random_simple_polygon<P, R>(
+    rng: &mut R,
+    center: P::Point,
+    radius: f64,
+    num_points: usize,
+) -> P
+where
+    P: BezierPathFactory,
+    P::Point: Coordinate2D,
+    R: Rng + ?Sized,
+{
+    let mut angles = (0..num_points.max(3))
+        .map(|_| rng.gen_range(0.0..(2.0 * PI)))
+        .collect::<Vec<_>>();
+    angles.sort_by(|a, b| a.total_cmp(b));
+
+    let corners = angles
+        .into_iter()
+        .map(|angle| {
+            let distance = rng.gen_range((radius * 0.5)..=radius);
+
+            center + P::Point::from_components(&[angle.cos() * distance, angle.sin() * distance])
+        })
+        .collect::<Vec<_>>();
+
+    let start_point = corners[0];
+    let lines = corners
+        .iter()
+        .skip(1)
+        .chain(Some(&start_point))
+        .scan(start_point, |last_point, &point| {
+            let from = *last_point;
+            *last_point = point;
+
+            Some((
+                from + (point - from) * (1.0 / 3.0),
+                from + (point - from) * (2.0 / 3.0),
+                point,
+            ))
+        })
+        .collect::<Vec<_>>();
+
+    P::from_points(start_point, lines)
+}
+
+///
+/// Generates a random smooth closed path lying within `radius` of `center`
+///
+/// The distance from the center to the outline varies smoothly between `radius/2` and `radius`, following a sum of
+/// `complexity` sine waves with increasing frequencies: a complexity of 0 generates a circle, and higher values
+/// generate shapes with more bumps in them. The path is made up of `4*(complexity+1)` curves.
+///
+pub fn random_smooth_path<P, R>(rng: &mut R, center: P::Point, radius: f64, complexity: usize) -> P
+where
+    P: BezierPathFactory,
+    P::Point: Coordinate2D,
+    R: Rng + ?Sized,
+{
+    // Choose an amplitude and a phase for each wave: the amplitudes add up to at most 1
+    let waves = (1..=complexity)
+        .map(|frequency| {
+            let amplitude = rng.gen_range(-1.0..=1.0) / (complexity as f64);
+            let phase = rng.gen_range(0.0..(2.0 * PI));
+
+            (frequency as f64, amplitude, phase)
+        })
+        .collect::<Vec<_>>();
+
+    // Distance from the center and its derivative at a particular angle
+    let distance = |angle: f64| {
+        let (offset, derivative) = waves.iter().fold(
+            (0.0, 0.0),
+            |(offset, derivative), (frequency, amplitude, phase)| {
+                let wave_angle = frequency * angle + phase;
+
+                (
+                    offset + amplitude * wave_angle.cos(),
+                    derivative - amplitude * frequency * wave_angle.sin(),
+                )
+            },
+        );
+
+        (radius * (0.75 + offset * 0.25), radius * derivative * 0.25)
+    };
+
+    // Point on the outline and its derivative at a particular angle
+    let outline = |angle: f64| {
+        let (r, dr) = distance(angle);
+        let (sin, cos) = angle.sin_cos();
+
+        let point = center + P::Point::from_components(&[r * cos, r * sin]);
+        let tangent = P::Point::from_components(&[dr * cos - r * sin, dr * sin + r * cos]);
+
+        (point, tangent)
+    };
+
+    // Build the path from hermite curves, scaling the derivatives by the angle that each curve covers (using
+    // 4*tan(angle/4) rather than the angle itself makes the curves exact for circles)
+    let num_curves = 4 * (complexity + 1);
+    let step = 2.0 * PI / (num_curves as f64);
+    let scale = 4.0 * (step / 4.0).tan();
+    let (start_point, _) = outline(0.0);
+
+    let curves = (0..num_curves)
+        .map(|idx| {
+            let (p0, m0) = outline((idx as f64) * step);
+            let (p1, m1) = outline(((idx + 1) as f64) * step);
+            let p1 = if idx == num_curves - 1 {
+                start_point
+            } else {
+                p1
+            };
+
+            let curve = Curve::from_hermite(p0, m0 * scale, p1, m1 * scale);
+            (curve.control_points.0, curve.control_points.1, p1)
+        })
+        .collect::<Vec<_>>();
+
+    P::from_points(start_point, curves)
+}
@@ -0,0 +1,11 @@
+#[cfg(not(feature = "instrumentation"))]
+macro_rules! trace_event {
+    ($event:expr) => {{}};
+}
+
+#[cfg(feature = "instrumentation")]
+macro_rules! trace_event {
+    ($event:expr) => {{
+        crate::debug::emit_graph_path_event(|| $event);
+    }};
+}
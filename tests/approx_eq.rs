@@ -0,0 +1,97 @@
+use flo_curves::bezier::path::{BezierPathBuilder, SimpleBezierPath};
+use flo_curves::bezier::Curve;
+use flo_curves::geo::{ApproxEq, BoundingBox, Bounds, QuantisedHash};
+use flo_curves::{BezierCurveFactory, Coord2};
+
+use std::collections::HashMap;
+
+fn square(offset: f64) -> SimpleBezierPath {
+    BezierPathBuilder::<SimpleBezierPath>::start(Coord2(offset, offset))
+        .line_to(Coord2(10.0 + offset, offset))
+        .line_to(Coord2(10.0 + offset, 10.0 + offset))
+        .line_to(Coord2(offset, 10.0 + offset))
+        .line_to(Coord2(offset, offset))
+        .build()
+}
+
+#[test]
+fn points_approx_eq() {
+    assert!(Coord2(1.0, 2.0).approx_eq(&Coord2(1.0005, 2.0), 0.001));
+    assert!(!Coord2(1.0, 2.0).approx_eq(&Coord2(1.002, 2.0), 0.001));
+}
+
+#[test]
+fn curves_approx_eq() {
+    let curve = Curve::from_points(
+        Coord2(0.0, 0.0),
+        (Coord2(1.0, 2.0), Coord2(3.0, 4.0)),
+        Coord2(5.0, 6.0),
+    );
+    let nearby = Curve::from_points(
+        Coord2(0.0, 0.0),
+        (Coord2(1.0, 2.0005), Coord2(3.0, 4.0)),
+        Coord2(5.0, 6.0),
+    );
+    let reversed = Curve::from_points(
+        Coord2(5.0, 6.0),
+        (Coord2(3.0, 4.0), Coord2(1.0, 2.0)),
+        Coord2(0.0, 0.0),
+    );
+
+    assert!(curve.approx_eq(&nearby, 0.001));
+    assert!(!curve.approx_eq(&nearby, 0.0001));
+    assert!(!curve.approx_eq(&reversed, 0.001));
+}
+
+#[test]
+fn bounds_approx_eq() {
+    let bounds = Bounds::from_min_max(Coord2(0.0, 0.0), Coord2(10.0, 10.0));
+    let nearby = Bounds::from_min_max(Coord2(0.0, 0.0), Coord2(10.0, 10.0005));
+
+    assert!(bounds.approx_eq(&nearby, 0.001));
+    assert!(!bounds.approx_eq(&nearby, 0.0001));
+}
+
+#[test]
+fn paths_approx_eq() {
+    assert!(square(0.0).approx_eq(&square(0.0005), 0.001));
+    assert!(!square(0.0).approx_eq(&square(0.002), 0.001));
+
+    let mut extra_point = square(0.0);
+    extra_point.1.push(extra_point.1[3]);
+    assert!(!square(0.0).approx_eq(&extra_point, 0.001));
+
+    assert!(vec![square(0.0), square(20.0)].approx_eq(&vec![square(0.0005), square(20.0)], 0.001));
+    assert!(!vec![square(0.0), square(20.0)].approx_eq(&vec![square(0.0)], 0.001));
+}
+
+#[test]
+fn quantised_keys_for_nearby_paths_are_equal() {
+    let key = square(0.0).quantised_key(0.01);
+
+    assert!(square(0.0001).quantised_key(0.01) == key);
+    assert!(square(0.1).quantised_key(0.01) != key);
+}
+
+#[test]
+fn paths_as_cache_keys() {
+    let mut cache = HashMap::new();
+    cache.insert(square(0.0).quantised_key(0.01), "first");
+    cache.insert(square(20.0).quantised_key(0.01), "second");
+
+    assert!(cache.get(&square(0.0002).quantised_key(0.01)) == Some(&"first"));
+    assert!(cache.get(&square(20.0).quantised_key(0.01)) == Some(&"second"));
+    assert!(!cache.contains_key(&square(5.0).quantised_key(0.01)));
+}
+
+#[test]
+fn lists_with_different_lengths_have_different_keys() {
+    let path = square(0.0);
+    let (start, curves) = path.clone();
+    let split = vec![
+        (start, curves[0..2].to_vec()),
+        (curves[1].2, curves[2..].to_vec()),
+    ];
+
+    assert!(vec![path].quantised_key(0.01) != split.quantised_key(0.01));
+}
@@ -1,8 +1,16 @@
-use flo_curves::bezier::path::algorithms::{flood_fill_concave, FillSettings, RayCollision};
-use flo_curves::bezier::path::{BezierPath, SimpleBezierPath};
+use flo_curves::bezier::path::algorithms::{
+    flood_fill_concave, flood_fill_concave_with_progress, FillSettings, RayCollision,
+};
+use flo_curves::bezier::path::{
+    BezierPath, CancellationToken, FillRule, OperationCancelled, OperationPhase, SimpleBezierPath,
+};
+
 use flo_curves::bezier::{
-    BezierCurve, BoundingBox, Coord2, Coordinate, Coordinate2D, Coordinate3D, Curve,
+    BezierCurve, BoundingBox, Bounds, Coord2, Coordinate, Coordinate2D, Curve,
 };
+use std::cell::RefCell;
+
+use super::{polygon_ray_cast, square};
 
 fn circle_ray_cast(
     circle_center: Coord2,
@@ -401,3 +409,100 @@ fn fill_doughnut_without_escaping_gaps() {
         }
     }
 }
+
+#[test]
+fn fill_concave_nested_squares_non_zero() {
+    // The concave fill should also be able to cross the inner square using the non-zero rule
+    let ray_cast = polygon_ray_cast(vec![square(0.0, 20.0), square(5.0, 15.0)]);
+    let path = flood_fill_concave::<SimpleBezierPath, _, _, _, _>(
+        Coord2(10.0, 10.0),
+        &FillSettings::default().with_fill_rule(FillRule::NonZero),
+        ray_cast,
+    );
+
+    assert!(path.is_some());
+
+    let path = path.unwrap();
+    assert!(path.len() == 1);
+
+    let bounds = path[0].bounding_box::<Bounds<Coord2>>();
+    assert!(bounds.min().distance_to(&Coord2(0.0, 0.0)) < 2.0);
+    assert!(bounds.max().distance_to(&Coord2(20.0, 20.0)) < 2.0);
+}
+
+#[test]
+fn fill_concave_doughnut_with_progress() {
+    let circle_center = Coord2(10.0, 10.0);
+    let outer_circle = circle_ray_cast(circle_center, 100.0);
+    let inner_circle = circle_ray_cast(circle_center, 50.0);
+    let doughnut = |from: Coord2, to: Coord2| {
+        outer_circle(from, to)
+            .into_iter()
+            .chain(inner_circle(from, to))
+    };
+
+    let progress = RefCell::new(vec![]);
+    let path = flood_fill_concave_with_progress::<SimpleBezierPath, _, _, _, _, _>(
+        circle_center + Coord2(60.0, 0.0),
+        &FillSettings::default(),
+        doughnut,
+        &|phase, amount_complete| {
+            progress.borrow_mut().push((phase, amount_complete));
+            true
+        },
+    );
+
+    assert!(path.unwrap().unwrap().len() == 2);
+
+    // Rays are cast from several edges to get around the inner circle, and progress is reported for each one
+    let progress = progress.into_inner();
+    let num_tracing = progress
+        .iter()
+        .filter(|(phase, _)| *phase == OperationPhase::TracingOutline)
+        .count();
+    assert!(num_tracing > 2);
+    assert!(progress.last() == Some(&(OperationPhase::Finished, 1.0)));
+    assert!(progress
+        .iter()
+        .all(|(_, amount_complete)| (0.0..=1.0).contains(amount_complete)));
+}
+
+#[test]
+fn cancel_fill_concave() {
+    let circle_center = Coord2(10.0, 10.0);
+    let outer_circle = circle_ray_cast(circle_center, 100.0);
+    let inner_circle = circle_ray_cast(circle_center, 50.0);
+    let doughnut = |from: Coord2, to: Coord2| {
+        outer_circle(from, to)
+            .into_iter()
+            .chain(inner_circle(from, to))
+    };
+
+    // Cancel after the first set of extra rays
+    let num_calls = RefCell::new(0);
+    let path = flood_fill_concave_with_progress::<SimpleBezierPath, _, _, _, _, _>(
+        circle_center + Coord2(60.0, 0.0),
+        &FillSettings::default(),
+        &doughnut,
+        &|_phase, _amount_complete| {
+            *num_calls.borrow_mut() += 1;
+            *num_calls.borrow() < 3
+        },
+    );
+
+    assert!(path == Err(OperationCancelled));
+    assert!(*num_calls.borrow() == 3);
+
+    // A cancelled token stops the fill straight away
+    let token = CancellationToken::new();
+    token.clone().cancel();
+
+    let path = flood_fill_concave_with_progress::<SimpleBezierPath, _, _, _, _, _>(
+        circle_center + Coord2(60.0, 0.0),
+        &FillSettings::default(),
+        &doughnut,
+        &token,
+    );
+
+    assert!(path == Err(OperationCancelled));
+}
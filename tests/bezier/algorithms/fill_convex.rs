@@ -1,11 +1,13 @@
 use flo_curves::bezier::path::algorithms::{
     flood_fill_convex, trace_outline_convex, FillSettings, RayCollision,
 };
-use flo_curves::bezier::path::{BezierPath, SimpleBezierPath};
+use flo_curves::bezier::path::{BezierPath, FillRule, SimpleBezierPath};
 use flo_curves::bezier::{
-    BezierCurve, BoundingBox, Coord2, Coordinate, Coordinate2D, Coordinate3D, Curve,
+    BezierCurve, BoundingBox, Bounds, Coord2, Coordinate, Coordinate2D, Curve,
 };
 
+use super::{polygon_ray_cast, square};
+
 fn circle_ray_cast(
     circle_center: Coord2,
     radius: f64,
@@ -158,3 +160,57 @@ fn fill_convex_doughnut() {
         }
     }
 }
+
+#[test]
+fn fill_convex_nested_squares_even_odd() {
+    // With the even-odd rule, the fill stops at the inner square even though both squares are wound the same way
+    let ray_cast = polygon_ray_cast(vec![square(0.0, 20.0), square(5.0, 15.0)]);
+    let path = flood_fill_convex::<SimpleBezierPath, _, _, _, _>(
+        Coord2(10.0, 10.0),
+        &FillSettings::default(),
+        ray_cast,
+    );
+
+    assert!(path.is_some());
+
+    let bounds = path.unwrap().bounding_box::<Bounds<Coord2>>();
+    assert!(bounds.min().distance_to(&Coord2(5.0, 5.0)) < 2.0);
+    assert!(bounds.max().distance_to(&Coord2(15.0, 15.0)) < 2.0);
+}
+
+#[test]
+fn fill_convex_nested_squares_non_zero() {
+    // With the non-zero rule, the inner square is inside the outer one so the whole outer square is filled
+    let ray_cast = polygon_ray_cast(vec![square(0.0, 20.0), square(5.0, 15.0)]);
+    let path = flood_fill_convex::<SimpleBezierPath, _, _, _, _>(
+        Coord2(10.0, 10.0),
+        &FillSettings::default().with_fill_rule(FillRule::NonZero),
+        ray_cast,
+    );
+
+    assert!(path.is_some());
+
+    let bounds = path.unwrap().bounding_box::<Bounds<Coord2>>();
+    assert!(bounds.min().distance_to(&Coord2(0.0, 0.0)) < 2.0);
+    assert!(bounds.max().distance_to(&Coord2(20.0, 20.0)) < 2.0);
+}
+
+#[test]
+fn fill_convex_nested_squares_non_zero_opposite_winding() {
+    // With the non-zero rule, a square wound in the opposite direction cuts a hole, so the center is outside and only the hole is filled
+    let ray_cast = polygon_ray_cast(vec![
+        square(0.0, 20.0),
+        square(5.0, 15.0).into_iter().rev().collect(),
+    ]);
+    let path = flood_fill_convex::<SimpleBezierPath, _, _, _, _>(
+        Coord2(10.0, 10.0),
+        &FillSettings::default().with_fill_rule(FillRule::NonZero),
+        ray_cast,
+    );
+
+    assert!(path.is_some());
+
+    let bounds = path.unwrap().bounding_box::<Bounds<Coord2>>();
+    assert!(bounds.min().distance_to(&Coord2(5.0, 5.0)) < 2.0);
+    assert!(bounds.max().distance_to(&Coord2(15.0, 15.0)) < 2.0);
+}
@@ -0,0 +1,100 @@
+use flo_curves::arc::Circle;
+use flo_curves::bezier::path::algorithms::{flood_fill_in_paths, FillSettings};
+use flo_curves::bezier::path::{BezierPath, FillRule, SimpleBezierPath};
+use flo_curves::bezier::{BezierCurve, Coord2, Coordinate, Curve};
+
+///
+/// Checks that every point on a path is a particular distance from a center point
+///
+fn assert_path_radius(path: &SimpleBezierPath, center: Coord2, radius: f64) {
+    for curve in path.to_curves::<Curve<Coord2>>() {
+        for t in 0..100 {
+            let t = (t as f64) / 100.0;
+            let distance = center.distance_to(&curve.point_at_pos(t));
+
+            assert!((distance - radius).abs() < 2.0);
+        }
+    }
+}
+
+#[test]
+fn fill_in_circle() {
+    let circle_center = Coord2(10.0, 10.0);
+    let circle = Circle::new(circle_center, 100.0).to_path::<SimpleBezierPath>();
+
+    let path = flood_fill_in_paths::<_, SimpleBezierPath>(
+        &[circle],
+        circle_center,
+        &FillSettings::default(),
+    );
+
+    assert!(path.is_some());
+    assert!(path.as_ref().unwrap().len() == 1);
+
+    assert_path_radius(&path.unwrap()[0], circle_center, 100.0);
+}
+
+#[test]
+fn fill_in_doughnut() {
+    // The inner circle should become a hole in the fill
+    let circle_center = Coord2(10.0, 10.0);
+    let outer_circle = Circle::new(circle_center, 100.0).to_path::<SimpleBezierPath>();
+    let inner_circle = Circle::new(circle_center, 50.0).to_path::<SimpleBezierPath>();
+
+    let path = flood_fill_in_paths::<_, SimpleBezierPath>(
+        &[outer_circle, inner_circle],
+        circle_center + Coord2(60.0, 0.0),
+        &FillSettings::default(),
+    );
+
+    assert!(path.is_some());
+    assert!(path.as_ref().unwrap().len() == 2);
+
+    let path = path.unwrap();
+    assert_path_radius(&path[0], circle_center, 100.0);
+    assert_path_radius(&path[1], circle_center, 50.0);
+}
+
+#[test]
+fn fill_in_nested_circles() {
+    // Filling from the center of several nested circles should only fill the innermost one
+    let circle_center = Coord2(10.0, 10.0);
+    let circles = vec![
+        Circle::new(circle_center, 100.0).to_path::<SimpleBezierPath>(),
+        Circle::new(circle_center, 50.0).to_path::<SimpleBezierPath>(),
+        Circle::new(circle_center, 20.0).to_path::<SimpleBezierPath>(),
+    ];
+
+    let path = flood_fill_in_paths::<_, SimpleBezierPath>(
+        &circles,
+        circle_center,
+        &FillSettings::default(),
+    );
+
+    assert!(path.is_some());
+    assert!(path.as_ref().unwrap().len() == 1);
+
+    assert_path_radius(&path.unwrap()[0], circle_center, 20.0);
+}
+
+#[test]
+fn fill_in_nested_circles_non_zero() {
+    // All of the circles are wound the same way, so with the non-zero rule the fill should reach the outer circle
+    let circle_center = Coord2(10.0, 10.0);
+    let circles = vec![
+        Circle::new(circle_center, 100.0).to_path::<SimpleBezierPath>(),
+        Circle::new(circle_center, 50.0).to_path::<SimpleBezierPath>(),
+        Circle::new(circle_center, 20.0).to_path::<SimpleBezierPath>(),
+    ];
+
+    let path = flood_fill_in_paths::<_, SimpleBezierPath>(
+        &circles,
+        circle_center,
+        &FillSettings::default().with_fill_rule(FillRule::NonZero),
+    );
+
+    assert!(path.is_some());
+    assert!(path.as_ref().unwrap().len() == 1);
+
+    assert_path_radius(&path.unwrap()[0], circle_center, 100.0);
+}
@@ -1,2 +1,57 @@
 mod fill_concave;
 mod fill_convex;
+mod fill_paths;
+
+use flo_curves::bezier::path::algorithms::RayCollision;
+use flo_curves::bezier::{Coord2, Coordinate2D};
+
+///
+/// Creates a ray casting function for a set of polygons, which returns directional collisions with their edges
+///
+pub fn polygon_ray_cast(
+    polygons: Vec<Vec<Coord2>>,
+) -> impl Fn(Coord2, Coord2) -> Vec<RayCollision<Coord2, ()>> {
+    move |from: Coord2, to: Coord2| {
+        let ray = to - from;
+        let mut collisions = vec![];
+
+        for polygon in polygons.iter() {
+            for edge_idx in 0..polygon.len() {
+                let start = polygon[edge_idx];
+                let end = polygon[(edge_idx + 1) % polygon.len()];
+                let edge = end - start;
+
+                let denominator = ray.x() * edge.y() - ray.y() * edge.x();
+                if denominator == 0.0 {
+                    continue;
+                }
+
+                // Position along the edge of the intersection (the end point is counted as part of the next edge)
+                let offset = from - start;
+                let t = (ray.x() * offset.y() - ray.y() * offset.x()) / denominator;
+
+                if (0.0..1.0).contains(&t) {
+                    collisions.push(RayCollision::with_direction(
+                        start + edge * t,
+                        (),
+                        denominator.signum() as i32,
+                    ));
+                }
+            }
+        }
+
+        collisions
+    }
+}
+
+///
+/// The vertices of a square between two coordinates
+///
+pub fn square(min: f64, max: f64) -> Vec<Coord2> {
+    vec![
+        Coord2(min, min),
+        Coord2(min, max),
+        Coord2(max, max),
+        Coord2(max, min),
+    ]
+}
@@ -1,5 +1,6 @@
 use super::approx_equal;
 use flo_curves::bezier;
+use flo_curves::{BezierCurve, BezierCurveFactory, Coord2, Coordinate};
 
 #[test]
 fn basis_at_t0_is_w1() {
@@ -22,3 +23,56 @@ fn basis_agrees_with_de_casteljau() {
         assert!(approx_equal(basis, de_casteljau));
     }
 }
+
+#[test]
+fn polynomial_coefficients_agree_with_basis() {
+    let curve = bezier::Curve::from_points(
+        Coord2(1.0, 2.0),
+        (Coord2(4.0, 7.0), Coord2(-3.0, 5.0)),
+        Coord2(6.0, -1.0),
+    );
+    let (a, b, c, d) = curve.polynomial_coefficients();
+
+    for x in 0..100 {
+        let t = (x as f64) / 100.0;
+        let polynomial = a * (t * t * t) + b * (t * t) + c * t + d;
+
+        assert!(polynomial.distance_to(&curve.point_at_pos(t)) < 0.0001);
+    }
+
+    // Converting back gives the original curve
+    let from_polynomial = bezier::Curve::from_polynomial(a, b, c, d);
+    assert!(from_polynomial.start_point.distance_to(&curve.start_point) < 0.0001);
+    assert!(
+        from_polynomial
+            .control_points
+            .0
+            .distance_to(&curve.control_points.0)
+            < 0.0001
+    );
+    assert!(
+        from_polynomial
+            .control_points
+            .1
+            .distance_to(&curve.control_points.1)
+            < 0.0001
+    );
+    assert!(from_polynomial.end_point.distance_to(&curve.end_point) < 0.0001);
+}
+
+#[test]
+fn hermite_curve_has_requested_tangents() {
+    let (p0, m0) = (Coord2(0.0, 0.0), Coord2(3.0, 6.0));
+    let (p1, m1) = (Coord2(10.0, 0.0), Coord2(6.0, -3.0));
+    let curve = bezier::Curve::from_hermite(p0, m0, p1, m1);
+
+    assert!(curve.start_point == p0);
+    assert!(curve.end_point == p1);
+
+    let (_, _, c, _) = curve.polynomial_coefficients();
+    assert!(c.distance_to(&m0) < 0.0001);
+
+    // The derivative at t=1 is 3a + 2b + c
+    let (a, b, c, _) = curve.polynomial_coefficients();
+    assert!((a * 3.0 + b * 2.0 + c).distance_to(&m1) < 0.0001);
+}
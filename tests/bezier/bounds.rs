@@ -1,7 +1,7 @@
 use flo_curves::bezier;
 use flo_curves::bezier::{BezierCurve, BezierCurveFactory};
-use flo_curves::geo::Coord2;
 use flo_curves::geo::Coordinate;
+use flo_curves::geo::{BoundingBox, Bounds, Coord2, Coordinate2D, Transform2D, Transformable};
 
 #[test]
 fn get_straight_line_bounds() {
@@ -29,3 +29,182 @@ fn get_curved_line_bounds() {
     assert!(bounds.0.distance_to(&Coord2(-0.3, 1.0)) < 0.0001);
     assert!(bounds.1.distance_to(&Coord2(2.0, 3.0)) < 0.0001);
 }
+
+///
+/// True if the points along a curve section never change direction in any dimension
+///
+fn is_monotonic<C: BezierCurve<Point = Coord2>>(section: &C) -> bool {
+    let points = (0..=32)
+        .map(|step| section.point_at_pos((step as f64) / 32.0))
+        .collect::<Vec<_>>();
+
+    let increasing_x = points.windows(2).all(|p| p[1].0 >= p[0].0 - 1e-9);
+    let decreasing_x = points.windows(2).all(|p| p[1].0 <= p[0].0 + 1e-9);
+    let increasing_y = points.windows(2).all(|p| p[1].1 >= p[0].1 - 1e-9);
+    let decreasing_y = points.windows(2).all(|p| p[1].1 <= p[0].1 + 1e-9);
+
+    (increasing_x || decreasing_x) && (increasing_y || decreasing_y)
+}
+
+#[test]
+fn split_s_curve_into_monotonic() {
+    let curve = bezier::Curve::from_points(
+        Coord2(0.0, 0.0),
+        (Coord2(100.0, 100.0), Coord2(-50.0, 100.0)),
+        Coord2(50.0, 0.0),
+    );
+
+    let sections = bezier::split_into_monotonic(&curve);
+
+    // Changes direction twice in x and once in y
+    assert!(sections.len() == 4);
+    assert!(!is_monotonic(&curve));
+
+    for section in sections.iter() {
+        assert!(is_monotonic(section));
+    }
+
+    // Sections cover the whole curve, in order
+    assert!(sections[0].original_curve_t_values().0 == 0.0);
+    assert!(sections[3].original_curve_t_values().1 == 1.0);
+    for pair in sections.windows(2) {
+        assert!(pair[0].original_curve_t_values().1 == pair[1].original_curve_t_values().0);
+    }
+
+    // The sections are reparameterised to match the original curve
+    for section in sections.iter() {
+        let original_t = section.t_for_t(0.5);
+        assert!(section
+            .point_at_pos(0.5)
+            .is_near_to(&curve.point_at_pos(original_t), 1e-9));
+    }
+}
+
+#[test]
+fn split_monotonic_curve() {
+    let curve = bezier::Curve::from_points(
+        Coord2(0.0, 0.0),
+        (Coord2(10.0, 40.0), Coord2(60.0, 50.0)),
+        Coord2(100.0, 100.0),
+    );
+
+    let sections = bezier::split_into_monotonic(&curve);
+
+    assert!(sections.len() == 1);
+    assert!(sections[0].original_curve_t_values() == (0.0, 1.0));
+}
+
+#[test]
+fn split_quadratic_derivative_into_monotonic() {
+    // The x derivative of this curve is linear rather than quadratic
+    let curve = bezier::Curve::from_points(
+        Coord2(0.0, 0.0),
+        (Coord2(100.0, 0.0), Coord2(100.0, 50.0)),
+        Coord2(0.0, 100.0),
+    );
+
+    let sections = bezier::split_into_monotonic(&curve);
+
+    assert!(sections.len() == 2);
+    for section in sections.iter() {
+        assert!(is_monotonic(section));
+    }
+}
+
+#[test]
+fn interval_bounds_enclose_curve() {
+    let curve = bezier::Curve::from_points(
+        Coord2(0.0, 0.0),
+        (Coord2(100.0, 100.0), Coord2(-50.0, 100.0)),
+        Coord2(50.0, 0.0),
+    );
+
+    for subdivisions in [1, 2, 4, 16] {
+        let bounds: Bounds<Coord2> = bezier::curve_interval_bounds(&curve, 0.2, 0.7, subdivisions);
+
+        for step in 0..=100 {
+            let t = 0.2 + 0.5 * (step as f64) / 100.0;
+            let point = curve.point_at_pos(t);
+
+            assert!(point.x() >= bounds.min().x() && point.x() <= bounds.max().x());
+            assert!(point.y() >= bounds.min().y() && point.y() <= bounds.max().y());
+        }
+    }
+}
+
+#[test]
+fn interval_bounds_are_tighter_than_control_points() {
+    let curve = bezier::Curve::from_points(
+        Coord2(0.0, 0.0),
+        (Coord2(100.0, 100.0), Coord2(-50.0, 100.0)),
+        Coord2(50.0, 0.0),
+    );
+
+    let hull: Bounds<Coord2> = curve.fast_bounding_box();
+    let whole: Bounds<Coord2> = bezier::curve_interval_bounds(&curve, 0.0, 1.0, 8);
+    let exact: Bounds<Coord2> = curve.bounding_box();
+
+    assert!(hull.inflate(1e-9).contains_bounds(&whole));
+    assert!(whole.contains_bounds(&exact));
+    assert!(whole.max().y() < hull.max().y() - 1.0);
+
+    // Gets closer to the exact bounds as the number of subdivisions increases
+    let coarse: Bounds<Coord2> = bezier::curve_interval_bounds(&curve, 0.0, 1.0, 4);
+    let fine: Bounds<Coord2> = bezier::curve_interval_bounds(&curve, 0.0, 1.0, 64);
+
+    assert!(coarse.inflate(1e-9).contains_bounds(&fine));
+    assert!(fine.min().distance_to(&exact.min()) < 0.1);
+    assert!(fine.max().distance_to(&exact.max()) < 0.1);
+}
+
+#[test]
+fn interval_bounds_of_point() {
+    let curve = bezier::Curve::from_points(
+        Coord2(10.0, 20.0),
+        (Coord2(10.0, 20.0), Coord2(10.0, 20.0)),
+        Coord2(10.0, 20.0),
+    );
+
+    let bounds: Bounds<Coord2> = bezier::curve_interval_bounds(&curve, 0.0, 1.0, 4);
+
+    assert!(bounds.min().distance_to(&Coord2(10.0, 20.0)) < 1e-9);
+    assert!(bounds.max().distance_to(&Coord2(10.0, 20.0)) < 1e-9);
+}
+
+#[test]
+fn transformed_bounds_match_bounds_of_transformed_curve() {
+    let curve = bezier::Curve::from_points(
+        Coord2(10.0, 20.0),
+        (Coord2(-40.0, 80.0), Coord2(120.0, -30.0)),
+        Coord2(60.0, 50.0),
+    );
+    let transform = Transform2D::rotate(0.7)
+        .then(&Transform2D::skew(0.3, -0.2))
+        .then(&Transform2D::scale(2.0, 0.5))
+        .then(&Transform2D::translate(15.0, -5.0));
+
+    let expected: Bounds<Coord2> = curve.transform(&transform).bounding_box();
+    let bounds: Bounds<Coord2> = bezier::bounding_box_of_transformed(&curve, &transform);
+
+    assert!(bounds.min().distance_to(&expected.min()) < 1e-9);
+    assert!(bounds.max().distance_to(&expected.max()) < 1e-9);
+}
+
+#[test]
+fn rotated_bounds_are_not_rotated_box() {
+    // A straight diagonal line rotated by 45 degrees lies along one of the axes, so its box has no area
+    let curve = bezier::Curve::from_points(
+        Coord2(0.0, 0.0),
+        (Coord2(1.0, 1.0), Coord2(2.0, 2.0)),
+        Coord2(3.0, 3.0),
+    );
+    let transform = Transform2D::rotate(std::f64::consts::FRAC_PI_4);
+
+    let bounds: Bounds<Coord2> = bezier::bounding_box_of_transformed(&curve, &transform);
+
+    let size = bounds.max() - bounds.min();
+    let (short, long) = (size.x().min(size.y()), size.x().max(size.y()));
+
+    assert!(short.abs() < 1e-9);
+    assert!((long - 18.0_f64.sqrt()).abs() < 1e-9);
+}
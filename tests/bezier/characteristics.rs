@@ -1,6 +1,6 @@
 use flo_curves::bezier::{
-    characterize_curve, features_for_curve, BezierCurve, BezierCurve2D, BezierCurveFactory, Coord2,
-    Coordinate, Curve, CurveFeatures,
+    characterize_curve, features_for_curve, find_cusp, find_inflection_points, BezierCurve,
+    BezierCurve2D, BezierCurveFactory, Coord2, Coordinate, Curve, CurveFeatures,
 };
 use flo_curves::{bezier, Line};
 
@@ -46,3 +46,99 @@ fn detect_loop_2_features() {
         _ => assert!(false),
     }
 }
+
+#[test]
+fn inflection_point_in_s_curve() {
+    let curve = Curve::from_points(
+        Coord2(0.0, 0.0),
+        (Coord2(1.0, 1.0), Coord2(2.0, -1.0)),
+        Coord2(3.0, 0.0),
+    );
+
+    let inflections = find_inflection_points(&curve);
+
+    assert!(inflections.len() == 1);
+    assert!((inflections[0] - 0.5).abs() < 1e-9);
+    assert!(find_cusp(&curve).is_none());
+}
+
+#[test]
+fn inflection_points_match_features() {
+    let curve = Curve::from_points(
+        Coord2(0.0, 0.0),
+        (Coord2(0.0, 1.0), Coord2(1.0, 1.0)),
+        Coord2(-1.0, 0.5),
+    );
+
+    let inflections = find_inflection_points(&curve);
+
+    match features_for_curve(&curve, 0.01) {
+        CurveFeatures::DoubleInflectionPoint(t1, t2) => {
+            let (t1, t2) = if t1 < t2 { (t1, t2) } else { (t2, t1) };
+
+            assert!(inflections.len() == 2);
+            assert!((inflections[0] - t1).abs() < 1e-9);
+            assert!((inflections[1] - t2).abs() < 1e-9);
+        }
+        CurveFeatures::SingleInflectionPoint(t) => {
+            assert!(inflections.len() == 1);
+            assert!((inflections[0] - t).abs() < 1e-9);
+        }
+        other => panic!("Unexpected features {:?}", other),
+    }
+}
+
+#[test]
+fn no_inflection_points_in_arch_or_line() {
+    let arch = Curve::from_points(
+        Coord2(0.0, 0.0),
+        (Coord2(1.0, 2.0), Coord2(3.0, 2.0)),
+        Coord2(4.0, 0.0),
+    );
+    let line = Curve::from_points(
+        Coord2(0.0, 0.0),
+        (Coord2(1.0, 1.0), Coord2(2.0, 2.0)),
+        Coord2(3.0, 3.0),
+    );
+
+    assert!(find_inflection_points(&arch).is_empty());
+    assert!(find_inflection_points(&line).is_empty());
+    assert!(find_cusp(&arch).is_none());
+    assert!(find_cusp(&line).is_none());
+}
+
+#[test]
+fn find_cusp_position() {
+    let curve = Curve::from_points(
+        Coord2(0.0, 0.0),
+        (Coord2(1.0, 1.0), Coord2(0.0, 1.0)),
+        Coord2(1.0, 0.0),
+    );
+
+    assert!(characterize_curve(&curve) == bezier::CurveCategory::Cusp);
+
+    let cusp = find_cusp(&curve).unwrap();
+    assert!((cusp - 0.5).abs() < 1e-6);
+
+    // The cusp isn't an inflection point
+    assert!(find_inflection_points(&curve)
+        .iter()
+        .all(|t| (t - cusp).abs() > 1e-6));
+}
+
+#[test]
+fn find_cusp_in_transformed_curve() {
+    // Same cusp as above, but scaled and rotated so the derivative's components aren't as simple
+    let transform = |p: Coord2| {
+        let (sin, cos) = (0.3_f64).sin_cos();
+        Coord2(p.0 * cos - p.1 * sin, p.0 * sin + p.1 * cos) * 7.0 + Coord2(3.0, 4.0)
+    };
+    let curve = Curve::from_points(
+        transform(Coord2(0.0, 0.0)),
+        (transform(Coord2(1.0, 1.0)), transform(Coord2(0.0, 1.0))),
+        transform(Coord2(1.0, 0.0)),
+    );
+
+    let cusp = find_cusp(&curve).unwrap();
+    assert!((cusp - 0.5).abs() < 1e-6);
+}
@@ -0,0 +1,68 @@
+use flo_curves::bezier::{clip_curve_to_bounds, BezierCurve, BezierCurveFactory, Curve};
+use flo_curves::{BoundingBox, Bounds, Coord2, Coordinate, Coordinate2D};
+
+fn tile() -> Bounds<Coord2> {
+    Bounds::from_min_max(Coord2(0.0, 0.0), Coord2(10.0, 10.0))
+}
+
+#[test]
+fn clip_line_crossing_bounds() {
+    let line = Curve::from_points(
+        Coord2(-5.0, 5.0),
+        (Coord2(0.0, 5.0), Coord2(10.0, 5.0)),
+        Coord2(15.0, 5.0),
+    );
+
+    let clipped = clip_curve_to_bounds(&line, &tile());
+
+    assert!(clipped.len() == 1);
+    assert!(clipped[0].start_point().is_near_to(&Coord2(0.0, 5.0), 1e-6));
+    assert!(clipped[0].end_point().is_near_to(&Coord2(10.0, 5.0), 1e-6));
+}
+
+#[test]
+fn clip_curve_leaving_and_entering_bounds() {
+    // Goes up out of the top of the tile and back down again
+    let curve = Curve::from_points(
+        Coord2(1.0, 5.0),
+        (Coord2(3.0, 15.0), Coord2(7.0, 15.0)),
+        Coord2(9.0, 5.0),
+    );
+
+    let clipped = clip_curve_to_bounds(&curve, &tile());
+
+    assert!(clipped.len() == 2);
+    assert!(clipped[0].start_point() == Coord2(1.0, 5.0));
+    assert!((clipped[0].end_point().y() - 10.0).abs() < 1e-6);
+    assert!((clipped[1].start_point().y() - 10.0).abs() < 1e-6);
+    assert!(clipped[1].end_point() == Coord2(9.0, 5.0));
+
+    // The clipped sections follow the original curve
+    for section in clipped.iter() {
+        for t in [0.0, 0.25, 0.5, 0.75, 1.0] {
+            let point = section.point_at_pos(t);
+            assert!(point.y() <= 10.0 + 1e-6);
+        }
+    }
+}
+
+#[test]
+fn clip_curve_inside_and_outside_bounds() {
+    let inside = Curve::from_points(
+        Coord2(1.0, 1.0),
+        (Coord2(3.0, 8.0), Coord2(7.0, 2.0)),
+        Coord2(9.0, 9.0),
+    );
+    let outside = Curve::from_points(
+        Coord2(11.0, 1.0),
+        (Coord2(13.0, 8.0), Coord2(17.0, 2.0)),
+        Coord2(19.0, 9.0),
+    );
+
+    let clipped_inside = clip_curve_to_bounds(&inside, &tile());
+    assert!(clipped_inside.len() == 1);
+    assert!(clipped_inside[0].start_point() == inside.start_point());
+    assert!(clipped_inside[0].end_point() == inside.end_point());
+
+    assert!(clip_curve_to_bounds(&outside, &tile()).is_empty());
+}
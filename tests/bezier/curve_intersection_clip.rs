@@ -1,6 +1,6 @@
 use flo_curves::bezier;
 use flo_curves::line;
-use flo_curves::{BezierCurve, BezierCurveFactory, BoundingBox, Coord2, Coordinate, Line};
+use flo_curves::{BezierCurve, BezierCurveFactory, Coord2, Coordinate, Coordinate2D};
 
 #[test]
 fn find_intersection_on_straight_line_not_middle() {
@@ -1046,3 +1046,173 @@ fn intersections_on_curve_sections() {
     // Second intersection should be at point 1.0 on curve1
     assert!((intersections[1].0 - 1.0).abs() < 0.01);
 }
+
+#[test]
+fn overlapping_curves_report_a_single_overlap() {
+    let curve1 = bezier::Curve::from_points(
+        Coord2(346.69864, 710.2048),
+        (Coord2(350.41446, 706.8076), Coord2(353.61026, 702.4266)),
+        Coord2(356.28525, 698.20306),
+    );
+    let curve2 = bezier::Curve::from_points(
+        Coord2(346.69864, 710.2048),
+        (Coord2(350.41446, 706.8076), Coord2(353.61026, 702.4266)),
+        Coord2(356.28525, 698.20306),
+    );
+    let curve2 = bezier::Curve::from_curve(&curve2.section(0.2, 0.6));
+
+    let intersections = bezier::curve_intersects_curve_with_overlaps(&curve1, &curve2, 0.01);
+
+    println!("{:?}", intersections);
+
+    assert!(intersections.len() == 1);
+
+    match intersections[0] {
+        bezier::CurveIntersection::Overlap((t1a, t1b), (t2a, t2b)) => {
+            assert!((t1a - 0.2).abs() < 0.001);
+            assert!((t1b - 0.6).abs() < 0.001);
+            assert!(t2a.abs() < 0.001);
+            assert!((t2b - 1.0).abs() < 0.001);
+        }
+
+        _ => panic!("expected an overlap, found {:?}", intersections[0]),
+    }
+}
+
+#[test]
+fn crossing_curves_report_points_with_overlaps() {
+    let curve1 =
+        line::line_to_bezier::<_, bezier::Curve<_>>(&(Coord2(0.0, 0.0), Coord2(10.0, 10.0)));
+    let curve2 =
+        line::line_to_bezier::<_, bezier::Curve<_>>(&(Coord2(10.0, 0.0), Coord2(0.0, 10.0)));
+
+    let intersections = bezier::curve_intersects_curve_with_overlaps(&curve1, &curve2, 0.1);
+
+    println!("{:?}", intersections);
+
+    assert!(intersections.len() == 1);
+
+    match intersections[0] {
+        bezier::CurveIntersection::Point(t1, t2) => {
+            assert!(curve1.point_at_pos(t1).distance_to(&Coord2(5.0, 5.0)) < 0.1);
+            assert!(curve2.point_at_pos(t2).distance_to(&Coord2(5.0, 5.0)) < 0.1);
+        }
+
+        _ => panic!("expected a point intersection, found {:?}", intersections[0]),
+    }
+}
+
+#[test]
+fn detailed_intersection_of_perpendicular_lines() {
+    let curve1 =
+        line::line_to_bezier::<_, bezier::Curve<_>>(&(Coord2(0.0, 5.0), Coord2(10.0, 5.0)));
+    let curve2 =
+        line::line_to_bezier::<_, bezier::Curve<_>>(&(Coord2(3.0, 0.0), Coord2(3.0, 10.0)));
+
+    let intersections = bezier::curve_intersects_curve_detailed(&curve1, &curve2, 0.01);
+
+    assert!(intersections.len() == 1);
+    assert!(intersections[0].position.distance_to(&Coord2(3.0, 5.0)) < 0.01);
+    assert!((intersections[0].t1 - 0.3).abs() < 0.01);
+    assert!((intersections[0].t2 - 0.5).abs() < 0.01);
+    assert!((intersections[0].angle - std::f64::consts::PI / 2.0).abs() < 0.01);
+    assert!(!intersections[0].is_tangent);
+}
+
+#[test]
+fn detailed_intersection_of_line_crossing_arch() {
+    let arch = bezier::Curve::from_points(
+        Coord2(0.0, 0.0),
+        (Coord2(0.0, 10.0), Coord2(10.0, 10.0)),
+        Coord2(10.0, 0.0),
+    );
+    let line = line::line_to_bezier::<_, bezier::Curve<_>>(&(Coord2(-5.0, 7.0), Coord2(15.0, 7.0)));
+
+    let intersections = bezier::curve_intersects_curve_detailed(&arch, &line, 0.01);
+
+    assert!(intersections.len() == 2);
+    assert!(intersections
+        .iter()
+        .all(|intersection| !intersection.is_tangent));
+    assert!(intersections
+        .iter()
+        .all(|intersection| (intersection.position.y() - 7.0).abs() < 0.01));
+}
+
+#[test]
+fn detailed_intersection_of_line_touching_arch() {
+    // The top of the arch is at (5.0, 7.5)
+    let arch = bezier::Curve::from_points(
+        Coord2(0.0, 0.0),
+        (Coord2(0.0, 10.0), Coord2(10.0, 10.0)),
+        Coord2(10.0, 0.0),
+    );
+    let line = line::line_to_bezier::<_, bezier::Curve<_>>(&(Coord2(-5.0, 7.5), Coord2(15.0, 7.5)));
+
+    let intersections = bezier::curve_intersects_curve_detailed(&arch, &line, 0.01);
+    println!("{:?}", intersections);
+
+    assert!(!intersections.is_empty());
+    assert!(intersections
+        .iter()
+        .all(|intersection| intersection.is_tangent));
+    assert!(intersections
+        .iter()
+        .all(|intersection| intersection.position.distance_to(&Coord2(5.0, 7.5)) < 0.1));
+    assert!(intersections
+        .iter()
+        .all(|intersection| intersection.angle < 0.1));
+}
+
+#[test]
+fn find_tangency_of_line_touching_arch() {
+    let arch = bezier::Curve::from_points(
+        Coord2(0.0, 0.0),
+        (Coord2(0.0, 10.0), Coord2(10.0, 10.0)),
+        Coord2(10.0, 0.0),
+    );
+    let line = line::line_to_bezier::<_, bezier::Curve<_>>(&(Coord2(-5.0, 7.5), Coord2(15.0, 7.5)));
+
+    let tangencies = bezier::curve_tangencies(&arch, &line, 0.01);
+
+    assert!(tangencies.len() == 1);
+    assert!((tangencies[0].t1 - 0.5).abs() < 0.001);
+    assert!(tangencies[0].position.distance_to(&Coord2(5.0, 7.5)) < 0.001);
+    assert!(tangencies[0].distance < 0.001);
+}
+
+#[test]
+fn find_tangency_of_line_close_to_arch() {
+    // The line misses the top of the arch, so there's no intersection, but they're within the tolerance
+    let arch = bezier::Curve::from_points(
+        Coord2(0.0, 0.0),
+        (Coord2(0.0, 10.0), Coord2(10.0, 10.0)),
+        Coord2(10.0, 0.0),
+    );
+    let line = line::line_to_bezier::<_, bezier::Curve<_>>(&(Coord2(-5.0, 7.6), Coord2(15.0, 7.6)));
+
+    assert!(bezier::curve_intersects_curve_clip(&arch, &line, 0.01).is_empty());
+
+    let tangencies = bezier::curve_tangencies(&arch, &line, 0.2);
+    assert!(tangencies.len() == 1);
+    assert!((tangencies[0].distance - 0.1).abs() < 0.001);
+
+    // Nothing is found if the tolerance is smaller than the gap
+    assert!(bezier::curve_tangencies(&arch, &line, 0.05).is_empty());
+}
+
+#[test]
+fn crossing_curves_are_not_tangent() {
+    let arch = bezier::Curve::from_points(
+        Coord2(0.0, 0.0),
+        (Coord2(0.0, 10.0), Coord2(10.0, 10.0)),
+        Coord2(10.0, 0.0),
+    );
+    let crossing =
+        line::line_to_bezier::<_, bezier::Curve<_>>(&(Coord2(-5.0, 7.0), Coord2(15.0, 7.0)));
+    let perpendicular =
+        line::line_to_bezier::<_, bezier::Curve<_>>(&(Coord2(5.0, 0.0), Coord2(5.0, 10.0)));
+
+    assert!(bezier::curve_tangencies(&arch, &crossing, 0.1).is_empty());
+    assert!(bezier::curve_tangencies(&arch, &perpendicular, 0.1).is_empty());
+}
@@ -1,6 +1,50 @@
 use flo_curves::bezier;
+use flo_curves::bezier::NormalCurve;
+use flo_curves::{BezierCurve, BezierCurveFactory, Coord2, Coordinate, Line};
 
 #[test]
 fn take_first_derivative() {
     assert!(bezier::derivative4(1.0, 2.0, 3.0, 4.0) == (3.0, 3.0, 3.0));
 }
+
+#[test]
+fn curve_derivative_matches_tangent() {
+    let curve = bezier::Curve::from_points(
+        Coord2(1.0, 2.0),
+        (Coord2(4.0, 7.0), Coord2(-3.0, 5.0)),
+        Coord2(6.0, -1.0),
+    );
+    let derivative = curve.derivative();
+
+    for x in 0..=100 {
+        let t = (x as f64) / 100.0;
+
+        assert!(
+            derivative
+                .point_at_pos(t)
+                .distance_to(&curve.tangent_at_pos(t))
+                < 0.0001
+        );
+    }
+}
+
+#[test]
+fn curve_second_derivative() {
+    let curve = bezier::Curve::from_points(
+        Coord2(1.0, 2.0),
+        (Coord2(4.0, 7.0), Coord2(-3.0, 5.0)),
+        Coord2(6.0, -1.0),
+    );
+    let second_derivative = curve.second_derivative();
+    let (a, b, _, _) = curve.polynomial_coefficients();
+
+    // The second derivative of a*t^3 + b*t^2 + ... is 6a*t + 2b
+    for x in 0..=10 {
+        let t = (x as f64) / 10.0;
+        let expected = a * (6.0 * t) + b * 2.0;
+
+        assert!(second_derivative.point_at_pos(t).distance_to(&expected) < 0.0001);
+    }
+
+    assert!(curve.derivative().derivative() == second_derivative);
+}
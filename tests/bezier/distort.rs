@@ -1,6 +1,8 @@
+use flo_curves::arc::Circle;
+use flo_curves::bezier::path::{path_to_curves, BezierPath, BezierPathBuilder, SimpleBezierPath};
 use flo_curves::bezier::{
-    distort_curve, walk_curve_evenly, BezierCurve, BezierCurveFactory, Coord2, Coordinate2D,
-    Coordinate3D, Curve,
+    distort_curve, distort_path, distort_path_continuous, walk_curve_evenly, warp_path,
+    BezierCurve, BezierCurveFactory, Coord2, Coordinate, Coordinate2D, Curve,
 };
 
 #[test]
@@ -41,3 +43,105 @@ fn line_to_sine_wave() {
         }
     }
 }
+
+#[test]
+fn distort_path_continuous_keeps_joins_continuous() {
+    let square = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(0.0, 0.0))
+        .line_to(Coord2(100.0, 0.0))
+        .line_to(Coord2(100.0, 100.0))
+        .line_to(Coord2(0.0, 100.0))
+        .line_to(Coord2(0.0, 0.0))
+        .build();
+
+    // This distortion moves the start and end of each curve by different amounts
+    let distorted = distort_path_continuous::<_, _, SimpleBezierPath>(
+        &square,
+        |point, _curve, t| Coord2(point.x() + t * 5.0, point.y()),
+        1.0,
+        0.1,
+    )
+    .unwrap();
+
+    let curves = path_to_curves::<_, Curve<_>>(&distorted).collect::<Vec<_>>();
+
+    for (curve, next_curve) in curves.iter().zip(curves.iter().skip(1)) {
+        assert!(curve.end_point() == next_curve.start_point());
+    }
+
+    assert!(curves.last().unwrap().end_point() == distorted.start_point());
+}
+
+#[test]
+fn warp_circle_into_arch() {
+    let circle = Circle::new(Coord2(50.0, 50.0), 40.0).to_path::<SimpleBezierPath>();
+    let warp = |point: Coord2| {
+        Coord2(
+            point.x(),
+            point.y() + ((point.x() - 50.0) / 50.0).powi(2) * -20.0,
+        )
+    };
+
+    let max_error = 0.1;
+    let warped = warp_path::<_, _, SimpleBezierPath>(&circle, warp, max_error);
+
+    assert!(warped.start_point() == warp(circle.start_point()));
+
+    let warped_curves = path_to_curves::<_, Curve<_>>(&warped).collect::<Vec<_>>();
+
+    // Sections should join up and the path should still be closed
+    for (curve, next_curve) in warped_curves.iter().zip(warped_curves.iter().skip(1)) {
+        assert!(curve.end_point() == next_curve.start_point());
+    }
+    assert!(
+        warped_curves
+            .last()
+            .unwrap()
+            .end_point()
+            .distance_to(&warped.start_point())
+            < 1e-9
+    );
+
+    // Every point on the original path should be close to the warped path after warping
+    let warped_points = warped_curves
+        .iter()
+        .flat_map(|curve| (0..=1000).map(move |t| curve.point_at_pos((t as f64) / 1000.0)))
+        .collect::<Vec<_>>();
+
+    for curve in path_to_curves::<_, Curve<_>>(&circle) {
+        for t in 0..=20 {
+            let expected = warp(curve.point_at_pos((t as f64) / 20.0));
+            let distance = warped_points
+                .iter()
+                .map(|point| point.distance_to(&expected))
+                .fold(f64::MAX, f64::min);
+
+            assert!(distance < max_error);
+        }
+    }
+}
+
+#[test]
+fn distort_path_distorts_each_section_separately() {
+    let line = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(0.0, 0.0))
+        .line_to(Coord2(100.0, 0.0))
+        .line_to(Coord2(200.0, 0.0))
+        .build();
+
+    // Each section is fitted from where the distortion moves its own start point to, rather than from the end of the previous section
+    let distorted = distort_path::<_, _, SimpleBezierPath>(
+        &line,
+        |point, _curve, t| Coord2(point.x(), point.y() + t * 5.0),
+        1.0,
+        0.1,
+    )
+    .unwrap();
+
+    let second_section_cp1 = path_to_curves::<_, Curve<_>>(&distorted)
+        .find(|curve| curve.start_point().distance_to(&Coord2(100.0, 5.0)) < 0.1)
+        .unwrap()
+        .control_points()
+        .0;
+
+    // The second section starts at y=0 before it's distorted, so its first control point is well below the join
+    assert!(second_section_cp1.y() < 3.0);
+}
@@ -0,0 +1,58 @@
+use flo_curves::bezier;
+use flo_curves::{BezierCurve, Coordinate2D};
+
+#[test]
+fn linear_easing_returns_input() {
+    let linear = bezier::EasingCurve::preset(bezier::EasingPreset::Linear);
+
+    for x in 0..=100 {
+        let x = (x as f64) / 100.0;
+        assert!((linear.ease(x) - x).abs() < 0.0001);
+    }
+}
+
+#[test]
+fn easing_matches_curve() {
+    let presets = [
+        bezier::EasingCurve::preset(bezier::EasingPreset::Ease),
+        bezier::EasingCurve::preset(bezier::EasingPreset::EaseIn),
+        bezier::EasingCurve::preset(bezier::EasingPreset::EaseOut),
+        bezier::EasingCurve::preset(bezier::EasingPreset::EaseInOut),
+        bezier::EasingCurve::new(0.7, -0.5, 0.3, 1.5),
+    ];
+
+    for easing in presets.iter() {
+        for t in 0..=100 {
+            let point = easing.point_at_pos((t as f64) / 100.0);
+
+            assert!((easing.ease(point.x()) - point.y()).abs() < 0.0001);
+        }
+    }
+}
+
+#[test]
+fn ease_in_out_is_symmetric() {
+    let ease_in_out = bezier::EasingCurve::preset(bezier::EasingPreset::EaseInOut);
+
+    assert!(ease_in_out.ease(0.0) == 0.0);
+    assert!(ease_in_out.ease(1.0) == 1.0);
+    assert!((ease_in_out.ease(0.5) - 0.5).abs() < 0.0001);
+    assert!(ease_in_out.ease(0.1) < 0.1);
+    assert!((ease_in_out.ease(0.25) + ease_in_out.ease(0.75) - 1.0).abs() < 0.0001);
+
+    // Inputs are clamped
+    assert!(ease_in_out.ease(-1.0) == 0.0);
+    assert!(ease_in_out.ease(2.0) == 1.0);
+}
+
+#[test]
+fn easing_with_flat_start() {
+    // The curve is flat in x at the start, where Newton-Raphson iteration converges slowly
+    let easing = bezier::EasingCurve::new(0.0, 1.0, 0.0, 1.0);
+
+    for t in 1..100 {
+        let point = easing.point_at_pos((t as f64) / 100.0);
+
+        assert!((easing.ease(point.x()) - point.y()).abs() < 0.0001);
+    }
+}
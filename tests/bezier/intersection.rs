@@ -1,6 +1,7 @@
 use flo_curves::bezier;
 use flo_curves::line;
-use flo_curves::{BezierCurve, BezierCurveFactory, BoundingBox, Coord2, Coordinate, Line};
+use flo_curves::line::Ray;
+use flo_curves::{BezierCurve, BezierCurveFactory, BoundingBox, Coord2, Coordinate, Coordinate2D};
 
 #[test]
 fn find_intersection_on_straight_line() {
@@ -478,3 +479,235 @@ fn ray_missing_root_3() {
     println!("{:?}", roots);
     assert!(roots.into_iter().any(|r| (r - x).abs() < 0.01));
 }
+
+#[test]
+fn curve_intersects_explicit_ray() {
+    let ray = line::SimpleRay::new(Coord2(0.0, 6.0), Coord2(10.0, -2.0));
+    let curve = bezier::Curve {
+        start_point: Coord2(0.0, 2.0),
+        end_point: Coord2(10.0, 8.0),
+        control_points: (Coord2(0.0, 20.0), Coord2(10.0, -10.0)),
+    };
+
+    let intersections = bezier::curve_ray_intersections(&curve, &ray);
+    assert!(intersections.len() == 3);
+
+    // The ray t values are in units of the ray's direction
+    for (curve_t, ray_t, point) in intersections {
+        assert!(curve.point_at_pos(curve_t).distance_to(&point) < 0.01);
+        assert!(ray.point_at_pos(ray_t).distance_to(&point) < 0.01);
+    }
+}
+
+#[test]
+fn ray_extends_behind_origin() {
+    // The ray starts after the curve, but still extends backwards to meet it
+    let ray = line::SimpleRay::through(Coord2(20.0, 5.0), Coord2(30.0, 5.0));
+    let curve =
+        line::line_to_bezier::<_, bezier::Curve<_>>(&(Coord2(10.0, 0.0), Coord2(0.0, 10.0)));
+
+    let intersections = bezier::curve_ray_intersections(&curve, &ray);
+    assert!(intersections.len() == 1);
+    assert!((intersections[0].1 - -1.5).abs() < 0.001);
+    assert!(intersections[0].2.distance_to(&Coord2(5.0, 5.0)) < 0.01);
+}
+
+#[test]
+fn curve_crosses_circle_twice() {
+    // Straight line through the middle of a circle
+    let curve = line::line_to_bezier::<_, bezier::Curve<_>>(&(Coord2(0.0, 5.0), Coord2(10.0, 5.0)));
+    let intersections = bezier::curve_intersects_circle(&curve, &Coord2(5.0, 5.0), 2.0);
+
+    assert!(intersections.len() == 2);
+    assert!(intersections[0].1.distance_to(&Coord2(3.0, 5.0)) < 1e-6);
+    assert!(intersections[1].1.distance_to(&Coord2(7.0, 5.0)) < 1e-6);
+    assert!(
+        curve
+            .point_at_pos(intersections[0].0)
+            .distance_to(&Coord2(3.0, 5.0))
+            < 1e-6
+    );
+    assert!(
+        curve
+            .point_at_pos(intersections[1].0)
+            .distance_to(&Coord2(7.0, 5.0))
+            < 1e-6
+    );
+}
+
+#[test]
+fn curve_misses_circle() {
+    let curve =
+        line::line_to_bezier::<_, bezier::Curve<_>>(&(Coord2(0.0, 10.0), Coord2(10.0, 10.0)));
+    let intersections = bezier::curve_intersects_circle(&curve, &Coord2(5.0, 5.0), 2.0);
+
+    assert!(intersections.is_empty());
+}
+
+#[test]
+fn curve_touches_circle() {
+    // Line tangent to the top of the circle
+    let curve = line::line_to_bezier::<_, bezier::Curve<_>>(&(Coord2(0.0, 7.0), Coord2(10.0, 7.0)));
+    let intersections = bezier::curve_intersects_circle(&curve, &Coord2(5.0, 5.0), 2.0);
+
+    assert!(intersections.len() == 1);
+    assert!(intersections[0].1.distance_to(&Coord2(5.0, 7.0)) < 1e-3);
+}
+
+#[test]
+fn curve_ends_inside_circle() {
+    let curve = line::line_to_bezier::<_, bezier::Curve<_>>(&(Coord2(0.0, 5.0), Coord2(5.0, 5.0)));
+    let intersections = bezier::curve_intersects_circle(&curve, &Coord2(5.0, 5.0), 2.0);
+
+    assert!(intersections.len() == 1);
+    assert!(intersections[0].1.distance_to(&Coord2(3.0, 5.0)) < 1e-6);
+}
+
+#[test]
+fn wavy_curve_crosses_circle() {
+    // S-shaped curve that crosses the edge of the circle four times
+    let curve = bezier::Curve {
+        start_point: Coord2(0.0, 5.0),
+        end_point: Coord2(10.0, 5.0),
+        control_points: (Coord2(5.0, 15.0), Coord2(5.0, -5.0)),
+    };
+    let center = Coord2(4.0, 4.0);
+    let radius = 4.0;
+    let intersections = bezier::curve_intersects_circle(&curve, &center, radius);
+
+    // Check against a dense sampling of the curve
+    let mut crossings = 0;
+    let mut last_inside = curve.point_at_pos(0.0).distance_to(&center) < radius;
+    for step in 1..=10000 {
+        let t = (step as f64) / 10000.0;
+        let inside = curve.point_at_pos(t).distance_to(&center) < radius;
+        if inside != last_inside {
+            crossings += 1;
+        }
+        last_inside = inside;
+    }
+
+    assert!(crossings == 4);
+    assert!(intersections.len() == crossings);
+
+    for (t, point) in intersections {
+        assert!(curve.point_at_pos(t).distance_to(&point) < 1e-9);
+        assert!((point.distance_to(&center) - radius).abs() < 1e-6);
+    }
+}
+
+#[test]
+fn curve_crosses_ellipse_conic() {
+    // x^2/16 + y^2/4 = 1, multiplied through by 16
+    let ellipse = bezier::Conic {
+        a: 1.0,
+        b: 0.0,
+        c: 4.0,
+        d: 0.0,
+        e: 0.0,
+        f: -16.0,
+    };
+    let curve =
+        line::line_to_bezier::<_, bezier::Curve<_>>(&(Coord2(-10.0, 0.0), Coord2(10.0, 0.0)));
+    let intersections = bezier::curve_intersects_conic(&curve, &ellipse);
+
+    assert!(intersections.len() == 2);
+    assert!(intersections[0].1.distance_to(&Coord2(-4.0, 0.0)) < 1e-6);
+    assert!(intersections[1].1.distance_to(&Coord2(4.0, 0.0)) < 1e-6);
+
+    for (_, point) in intersections {
+        assert!(ellipse.value_at(&point).abs() < 1e-6);
+    }
+}
+
+#[test]
+fn curve_entirely_inside_bounds() {
+    let curve = bezier::Curve::from_points(
+        Coord2(2.0, 2.0),
+        (Coord2(3.0, 8.0), Coord2(7.0, 8.0)),
+        Coord2(8.0, 2.0),
+    );
+    let bounds = flo_curves::Bounds::from_min_max(Coord2(0.0, 0.0), Coord2(10.0, 10.0));
+
+    assert!(bezier::curve_intersects_bounds(&curve, &bounds) == vec![(0.0, 1.0)]);
+}
+
+#[test]
+fn curve_entirely_outside_bounds() {
+    let curve = bezier::Curve::from_points(
+        Coord2(12.0, 2.0),
+        (Coord2(13.0, 8.0), Coord2(17.0, 8.0)),
+        Coord2(18.0, 2.0),
+    );
+    let bounds = flo_curves::Bounds::from_min_max(Coord2(0.0, 0.0), Coord2(10.0, 10.0));
+
+    assert!(bezier::curve_intersects_bounds(&curve, &bounds).is_empty());
+}
+
+#[test]
+fn line_crossing_bounds() {
+    let curve =
+        line::line_to_bezier::<_, bezier::Curve<_>>(&(Coord2(-5.0, 5.0), Coord2(15.0, 5.0)));
+    let bounds = flo_curves::Bounds::from_min_max(Coord2(0.0, 0.0), Coord2(10.0, 10.0));
+
+    let spans = bezier::curve_intersects_bounds(&curve, &bounds);
+
+    assert!(spans.len() == 1);
+    assert!(curve
+        .point_at_pos(spans[0].0)
+        .is_near_to(&Coord2(0.0, 5.0), 0.001));
+    assert!(curve
+        .point_at_pos(spans[0].1)
+        .is_near_to(&Coord2(10.0, 5.0), 0.001));
+}
+
+#[test]
+fn curve_leaving_and_entering_bounds() {
+    // Arch that goes out of the top of the rectangle and comes back in again
+    let curve = bezier::Curve::from_points(
+        Coord2(2.0, 2.0),
+        (Coord2(3.0, 18.0), Coord2(7.0, 18.0)),
+        Coord2(8.0, 2.0),
+    );
+    let bounds = flo_curves::Bounds::from_min_max(Coord2(0.0, 0.0), Coord2(10.0, 10.0));
+
+    let spans = bezier::curve_intersects_bounds(&curve, &bounds);
+
+    assert!(spans.len() == 2);
+    assert!(spans[0].0 == 0.0);
+    assert!(spans[1].1 == 1.0);
+    assert!((curve.point_at_pos(spans[0].1).y() - 10.0).abs() < 0.001);
+    assert!((curve.point_at_pos(spans[1].0).y() - 10.0).abs() < 0.001);
+
+    // Every point in a span is inside the bounds, and every point between them is outside
+    for (t_enter, t_exit) in spans.iter() {
+        for idx in 0..=10 {
+            let t = t_enter + (t_exit - t_enter) * (idx as f64) / 10.0;
+            assert!(curve.point_at_pos(t).y() <= 10.0001);
+        }
+    }
+    assert!(curve.point_at_pos((spans[0].1 + spans[1].0) * 0.5).y() > 10.0);
+}
+
+#[test]
+fn curve_touching_corner_of_bounds() {
+    let curve =
+        line::line_to_bezier::<_, bezier::Curve<_>>(&(Coord2(-2.0, 8.0), Coord2(4.0, 14.0)));
+    let bounds = flo_curves::Bounds::from_min_max(Coord2(0.0, 0.0), Coord2(10.0, 10.0));
+
+    assert!(bezier::curve_intersects_bounds(&curve, &bounds).is_empty());
+}
+
+#[test]
+fn curve_touching_edge_from_inside() {
+    // Reaches y=10 at t=0.5 without leaving the rectangle
+    let curve = bezier::Curve::from_points(
+        Coord2(2.0, 2.0),
+        (Coord2(3.0, 38.0 / 3.0), Coord2(7.0, 38.0 / 3.0)),
+        Coord2(8.0, 2.0),
+    );
+    let bounds = flo_curves::Bounds::from_min_max(Coord2(0.0, 0.0), Coord2(10.0, 10.0));
+
+    assert!((curve.point_at_pos(0.5).y() - 10.0).abs() < 0.0001);
+    assert!(bezier::curve_intersects_bounds(&curve, &bounds) == vec![(0.0, 1.0)]);
+}
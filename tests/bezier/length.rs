@@ -1,6 +1,8 @@
 use flo_curves::bezier::{
-    chord_length, curve_length, walk_curve_unevenly, BezierCurve, BezierCurveFactory, Coord2, Curve,
+    chord_length, curve_length, curve_length_quadrature, derivative_magnitude_at, trim_curve,
+    walk_curve_unevenly, BezierCurve, BezierCurveFactory, Coord2, Curve,
 };
+use flo_curves::Coordinate;
 
 ///
 /// Estimates a curve's length by subdividing it a lot
@@ -80,3 +82,87 @@ fn measure_length_4() {
 
     assert!((by_measuring - by_subdivision).abs() < 1.0);
 }
+
+#[test]
+fn trim_curve_by_length() {
+    let c = Curve::from_points(
+        Coord2(170.83203, 534.28906),
+        (Coord2(35.15625, 502.65625), Coord2(0.52734375, 478.67188)),
+        Coord2(262.95313, 533.2656),
+    );
+    let total_length = curve_length(&c, 0.01);
+
+    let trimmed = trim_curve(&c, 20.0, 80.0, 0.01);
+    let trimmed_length = curve_length(&trimmed, 0.01);
+
+    assert!((trimmed_length - 60.0).abs() < 0.1);
+
+    // The two remaining parts of the curve make up the rest of its length
+    let before = trim_curve(&c, 0.0, 20.0, 0.01);
+    let after = trim_curve(&c, 80.0, total_length, 0.01);
+
+    assert!((curve_length(&before, 0.01) - 20.0).abs() < 0.1);
+    assert!(before.end_point().distance_to(&trimmed.start_point()) < 1e-6);
+    assert!(after.start_point().distance_to(&trimmed.end_point()) < 1e-6);
+    assert!(after.end_point().distance_to(&c.end_point()) < 1e-6);
+}
+
+#[test]
+fn trim_curve_clamps_and_swaps_lengths() {
+    let c = Curve::from_points(
+        Coord2(0.0, 0.0),
+        (Coord2(30.0, 0.0), Coord2(70.0, 0.0)),
+        Coord2(100.0, 0.0),
+    );
+
+    let whole = trim_curve(&c, -10.0, 200.0, 0.01);
+    assert!(whole.start_point() == c.start_point());
+    assert!(whole.end_point() == c.end_point());
+
+    let swapped = trim_curve(&c, 75.0, 25.0, 0.01);
+    assert!(swapped.start_point().distance_to(&Coord2(25.0, 0.0)) < 0.01);
+    assert!(swapped.end_point().distance_to(&Coord2(75.0, 0.0)) < 0.01);
+}
+
+#[test]
+fn measure_length_by_quadrature() {
+    let c = Curve::from_points(
+        Coord2(412.0, 500.0),
+        (Coord2(412.0, 500.0), Coord2(163.0, 504.0)),
+        Coord2(308.0, 665.0),
+    );
+    let by_subdivision = subdivide_length(&c);
+    let by_quadrature = curve_length_quadrature(&c, 16);
+
+    assert!((by_quadrature - by_subdivision).abs() < 1.0);
+}
+
+#[test]
+fn quadrature_of_straight_line_is_exact() {
+    // The speed along a line with evenly spaced control points is constant, so every order should give the same result
+    let c = Curve::from_points(
+        Coord2(0.0, 0.0),
+        (Coord2(10.0, 20.0), Coord2(20.0, 40.0)),
+        Coord2(30.0, 60.0),
+    );
+    let length = Coord2(30.0, 60.0).magnitude();
+
+    for order in 0..20 {
+        assert!((curve_length_quadrature(&c, order) - length).abs() < 1e-9);
+    }
+
+    assert!((derivative_magnitude_at(&c, 0.3) - length).abs() < 1e-9);
+}
+
+#[test]
+fn quadrature_matches_curve_length() {
+    let c = Curve::from_points(
+        Coord2(987.7637, 993.9645),
+        (Coord2(991.1699, 994.0231), Coord2(1043.5605, 853.44885)),
+        Coord2(1064.9473, 994.277),
+    );
+    let by_measuring = curve_length(&c, 0.0001);
+
+    assert!((curve_length_quadrature(&c, 8) - by_measuring).abs() < 0.5);
+    assert!((curve_length_quadrature(&c, 16) - by_measuring).abs() < 0.05);
+}
@@ -7,19 +7,25 @@ mod path;
 mod basis;
 mod bounds;
 mod characteristics;
+mod clip;
 mod curve_intersection_clip;
 mod deform;
 mod derivative;
 mod distort;
+mod easing;
 mod intersection;
 mod length;
+mod nearest;
 mod normal;
 mod offset;
+mod oriented_bounds;
 mod overlaps;
+mod reduce_degree;
 mod search;
 mod section;
 mod self_intersection;
 mod solve;
+mod speed;
 mod subdivide;
 mod tangent;
 mod walk;
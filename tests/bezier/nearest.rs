@@ -0,0 +1,55 @@
+use flo_curves::bezier::{distance_to_curve, nearest_point_on_curve, Curve};
+use flo_curves::line::line_to_bezier;
+use flo_curves::{BezierCurve, BezierCurveFactory, Coord2, Coordinate};
+
+#[test]
+fn nearest_point_on_straight_line() {
+    let curve = line_to_bezier::<_, Curve<_>>(&(Coord2(0.0, 0.0), Coord2(10.0, 0.0)));
+    let (t, point) = nearest_point_on_curve(&curve, &Coord2(4.0, 3.0));
+
+    assert!(point.distance_to(&Coord2(4.0, 0.0)) < 1e-6);
+    assert!(curve.point_at_pos(t).distance_to(&point) < 1e-9);
+    assert!((distance_to_curve(&curve, &Coord2(4.0, 3.0)) - 3.0).abs() < 1e-6);
+}
+
+#[test]
+fn nearest_point_beyond_end() {
+    let curve = line_to_bezier::<_, Curve<_>>(&(Coord2(0.0, 0.0), Coord2(10.0, 0.0)));
+    let (t, point) = nearest_point_on_curve(&curve, &Coord2(15.0, 2.0));
+
+    assert!(t == 1.0);
+    assert!(point == Coord2(10.0, 0.0));
+}
+
+#[test]
+fn nearest_point_matches_sampling() {
+    let curve = Curve::from_points(
+        Coord2(0.0, 2.0),
+        (Coord2(0.0, 20.0), Coord2(10.0, -10.0)),
+        Coord2(10.0, 8.0),
+    );
+
+    for target in [
+        Coord2(5.0, 5.0),
+        Coord2(-3.0, 7.0),
+        Coord2(2.0, 12.0),
+        Coord2(9.0, 0.0),
+        Coord2(1.0, 1.0),
+    ]
+    .iter()
+    {
+        let distance = distance_to_curve(&curve, target);
+
+        // No sampled point should be closer than the nearest point
+        let sampled = (0..=10000)
+            .map(|step| {
+                curve
+                    .point_at_pos((step as f64) / 10000.0)
+                    .distance_to(target)
+            })
+            .fold(f64::MAX, f64::min);
+
+        assert!(distance <= sampled + 1e-9);
+        assert!(sampled - distance < 0.01);
+    }
+}
@@ -1,7 +1,7 @@
 use flo_curves::bezier::NormalCurve;
 use flo_curves::bezier::{
-    curve_intersects_ray, offset, offset_lms_sampling, BezierCurve, BezierCurveFactory,
-    BoundingBox, Coord2, Coordinate, Coordinate2D, Coordinate3D, Curve, Normalize,
+    curve_intersects_ray, offset, offset_adaptive, offset_lms_sampling, offset_max_error,
+    BezierCurve, BezierCurveFactory, Coord2, Coordinate, Coordinate2D, Curve, Normalize,
 };
 use flo_curves::line;
 use flo_curves::line::Line2D;
@@ -360,3 +360,144 @@ fn offset_lms_sampling_arc_fit_single_curve() {
     // We should be able to find a single bezier curve that fits these points
     assert!(offset_arc.len() == 1);
 }
+
+#[test]
+fn offset_max_error_for_lms_arc_is_small() {
+    use flo_curves::arc::Circle;
+
+    let circle = Circle::new(Coord2(0.0, 0.0), 100.0);
+    let arc = circle.arc(0.0, f64::consts::PI / 2.0);
+    let arc_curve = arc.to_bezier_curve::<Curve<Coord2>>();
+
+    let offset_arc =
+        offset_lms_sampling(&arc_curve, |_t| 10.0, |_t| 0.0, 20, 0.01).expect("Offset curve");
+    let error = offset_max_error(&arc_curve, &offset_arc, |_t| 10.0, |_t| 0.0, 100);
+
+    assert!(error < 0.1);
+}
+
+#[test]
+fn offset_max_error_measures_wrong_offset() {
+    let c = Curve::from_points(
+        Coord2(110.0, 110.0),
+        (Coord2(110.0, 300.0), Coord2(500.0, 300.0)),
+        Coord2(500.0, 110.0),
+    );
+    let offset_curve = offset(&c, 10.0, 10.0);
+
+    // Compared to the offset it was generated with the error is small, but an offset 5 units further out is 5 units away
+    let error = offset_max_error(&c, &offset_curve, |_t| 10.0, |_t| 0.0, 100);
+    let wrong_error = offset_max_error(&c, &offset_curve, |_t| 15.0, |_t| 0.0, 100);
+
+    assert!(error <= 2.0);
+    assert!((wrong_error - 5.0).abs() <= 2.0);
+}
+
+#[test]
+fn offset_max_error_detects_missing_sections() {
+    let c = Curve::from_points(
+        Coord2(110.0, 110.0),
+        (Coord2(110.0, 300.0), Coord2(500.0, 300.0)),
+        Coord2(500.0, 110.0),
+    );
+    let offset_curve = offset(&c, 10.0, 10.0);
+
+    // Leaving out the second half of the offset curve leaves the end of the ideal offset far from the result
+    let first_half = offset_curve
+        .iter()
+        .map(|curve| curve.section(0.0, 0.5))
+        .map(|section| Curve::from_curve(&section))
+        .collect::<Vec<_>>();
+    let full_error = offset_max_error(&c, &offset_curve, |_t| 10.0, |_t| 0.0, 100);
+    let half_error = offset_max_error(&c, &first_half, |_t| 10.0, |_t| 0.0, 100);
+
+    assert!(half_error > full_error + 10.0);
+}
+
+///
+/// Checks that a set of curves has no gaps and that none of the curves cross each other
+///
+fn assert_connected_without_crossings(curves: &[Curve<Coord2>]) {
+    use flo_curves::bezier::curve_intersects_curve_clip;
+
+    for idx in 1..curves.len() {
+        assert!(curves[idx - 1]
+            .end_point()
+            .is_near_to(&curves[idx].start_point(), 0.01));
+    }
+
+    for idx1 in 0..curves.len() {
+        for idx2 in (idx1 + 1)..curves.len() {
+            let crossings = curve_intersects_curve_clip(&curves[idx1], &curves[idx2], 0.01)
+                .into_iter()
+                .filter(|(t1, t2)| {
+                    // Curves that follow each other meet where they join
+                    let joined = (idx2 == idx1 + 1) && *t1 > 0.99 && *t2 < 0.01;
+                    !joined
+                })
+                .collect::<Vec<_>>();
+
+            println!("{} {} {:?}", idx1, idx2, crossings);
+            assert!(crossings.is_empty());
+        }
+    }
+}
+
+#[test]
+fn offset_adaptive_gentle_curve_is_within_error() {
+    let c = Curve::from_points(
+        Coord2(110.0, 110.0),
+        (Coord2(110.0, 300.0), Coord2(500.0, 300.0)),
+        Coord2(500.0, 110.0),
+    );
+    let offset_curve = offset_adaptive(&c, 10.0, 10.0, 0.1);
+
+    assert!(offset_max_error(&c, &offset_curve, |_t| 10.0, |_t| 0.0, 100) < 0.2);
+    assert_connected_without_crossings(&offset_curve);
+}
+
+#[test]
+fn offset_adaptive_removes_loop_inside_tight_bend() {
+    // Hairpin bend, with the offset on the inside larger than the radius of curvature at the top
+    let c = Curve::from_points(
+        Coord2(0.0, 0.0),
+        (Coord2(0.0, 100.0), Coord2(40.0, 100.0)),
+        Coord2(40.0, 0.0),
+    );
+
+    let offset_curve = offset_adaptive(&c, -15.0, -15.0, 0.1);
+
+    // The result should start and end in the right place
+    assert!(offset_curve[0]
+        .start_point()
+        .is_near_to(&Coord2(15.0, 0.0), 0.01));
+    assert!(offset_curve[offset_curve.len() - 1]
+        .end_point()
+        .is_near_to(&Coord2(25.0, 0.0), 0.01));
+
+    // No part of the result should be closer to the curve than the offset
+    for curve in offset_curve.iter() {
+        for t in 0..=10 {
+            let point = curve.point_at_pos((t as f64) / 10.0);
+            let distance = flo_curves::bezier::distance_to_curve(&c, &point);
+
+            assert!(distance > 15.0 - 0.5);
+        }
+    }
+
+    assert_connected_without_crossings(&offset_curve);
+}
+
+#[test]
+fn offset_adaptive_outside_tight_bend() {
+    let c = Curve::from_points(
+        Coord2(0.0, 0.0),
+        (Coord2(0.0, 100.0), Coord2(40.0, 100.0)),
+        Coord2(40.0, 0.0),
+    );
+
+    let offset_curve = offset_adaptive(&c, 15.0, 15.0, 0.1);
+
+    assert!(offset_max_error(&c, &offset_curve, |_t| 15.0, |_t| 0.0, 100) < 0.5);
+    assert_connected_without_crossings(&offset_curve);
+}
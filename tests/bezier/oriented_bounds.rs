@@ -0,0 +1,64 @@
+use flo_curves::bezier::{curve_oriented_bounding_box, BezierCurve, Curve, OrientedBounds};
+use flo_curves::{BezierCurveFactory, BoundingBox, Bounds, Coord2, Coordinate, Coordinate2D};
+
+#[test]
+fn diagonal_line_oriented_bounds() {
+    // A straight diagonal line has an oriented bounding box with no height
+    let line = Curve::from_points(
+        Coord2(0.0, 0.0),
+        (Coord2(30.0, 30.0), Coord2(70.0, 70.0)),
+        Coord2(100.0, 100.0),
+    );
+    let bounds = curve_oriented_bounding_box(&line);
+
+    assert!((bounds.width().max(bounds.height()) - 100.0 * 2.0f64.sqrt()).abs() < 0.01);
+    assert!(bounds.width().min(bounds.height()) < 0.01);
+    assert!(bounds.center().distance_to(&Coord2(50.0, 50.0)) < 0.01);
+}
+
+#[test]
+fn diagonal_curve_oriented_bounds_contains_curve() {
+    // A gentle S-curve running diagonally
+    let curve = Curve::from_points(
+        Coord2(0.0, 0.0),
+        (Coord2(60.0, 20.0), Coord2(40.0, 80.0)),
+        Coord2(100.0, 100.0),
+    );
+    let bounds = curve_oriented_bounding_box(&curve);
+    let axis_aligned = curve.bounding_box::<Bounds<_>>();
+    let axis_aligned_size = axis_aligned.max() - axis_aligned.min();
+
+    // Should be smaller than the axis-aligned bounding box
+    assert!(bounds.area() < axis_aligned_size.x() * axis_aligned_size.y() * 0.5);
+
+    // Every point on the curve should be inside the box (allowing for rounding errors)
+    let slightly_larger = OrientedBounds::new(
+        bounds.center(),
+        bounds.axis(),
+        bounds.width() + 1e-6,
+        bounds.height() + 1e-6,
+    );
+
+    for t in 0..=100 {
+        let t = (t as f64) / 100.0;
+        assert!(slightly_larger.contains_point(&curve.point_at_pos(t)));
+    }
+}
+
+#[test]
+fn corners_of_oriented_bounds() {
+    let line = Curve::from_points(
+        Coord2(0.0, 0.0),
+        (Coord2(1.0, 1.0), Coord2(2.0, 2.0)),
+        Coord2(3.0, 3.0),
+    );
+    let bounds = curve_oriented_bounding_box(&line);
+    let corners = bounds.corners();
+
+    assert!(corners
+        .iter()
+        .any(|corner| corner.distance_to(&Coord2(0.0, 0.0)) < 0.01));
+    assert!(corners
+        .iter()
+        .any(|corner| corner.distance_to(&Coord2(3.0, 3.0)) < 0.01));
+}
@@ -1,9 +1,11 @@
 use flo_curves::arc::Circle;
 use flo_curves::bezier::path::{
-    path_add, path_combine, path_remove_interior_points, path_remove_overlapped_points, BezierPath,
-    BezierPathBuilder, BezierPathFactory, GraphPath, PathCombine, PathDirection, PathLabel,
-    SimpleBezierPath,
+    path_add, path_add_with_labels, path_add_with_progress, path_combine,
+    path_remove_interior_points, path_remove_overlapped_points, BezierPath, BezierPathBuilder,
+    CancellationToken, GraphPath, OperationCancelled, OperationPhase, PathCombine, PathDirection,
+    PathLabel, SimpleBezierPath,
 };
+use flo_curves::bezier::{nearest_point_on_curve, Curve};
 use flo_curves::debug::graph_path_svg_string;
 use flo_curves::{BezierCurve, BoundingBox, Coord2, Coordinate, Line};
 
@@ -968,3 +970,153 @@ fn rectangle_add_with_shared_point_6() {
     assert!(points[6].2.distance_to(&Coord2(5.0, 1.0)) < 0.1);
     assert!(points[7].2.distance_to(&Coord2(1.0, 1.0)) < 0.1);
 }
+
+#[test]
+fn add_with_progress_reports_each_phase() {
+    let circle1 = [Circle::new(Coord2(5.0, 5.0), 4.0).to_path::<SimpleBezierPath>()];
+    let circle2 = [Circle::new(Coord2(7.0, 5.0), 4.0).to_path::<SimpleBezierPath>()];
+
+    let phases = std::cell::RefCell::new(vec![]);
+    let combined = path_add_with_progress::<_, _, SimpleBezierPath, _>(
+        &circle1,
+        &circle2,
+        0.01,
+        &|phase, amount_complete| {
+            phases.borrow_mut().push((phase, amount_complete));
+            true
+        },
+    );
+
+    assert!(combined == Ok(path_add::<_, _, SimpleBezierPath>(&circle1, &circle2, 0.01)));
+
+    // Progress is reported while the paths are collided, and then between the remaining phases
+    let phases = phases.into_inner();
+    let (colliding, other_phases): (Vec<_>, Vec<_>) = phases
+        .iter()
+        .copied()
+        .partition(|(phase, _)| *phase == OperationPhase::Colliding);
+
+    assert!(colliding.len() > 1);
+    assert!(colliding
+        .iter()
+        .all(|(_, amount_complete)| (0.0..=0.6).contains(amount_complete)));
+    assert!(phases.windows(2).all(|pair| pair[0].1 <= pair[1].1));
+    assert!(
+        other_phases
+            == vec![
+                (OperationPhase::ClassifyingEdges, 0.6),
+                (OperationPhase::BuildingPaths, 0.9),
+                (OperationPhase::Finished, 1.0)
+            ]
+    );
+}
+
+#[test]
+fn cancel_add() {
+    let circle1 = [Circle::new(Coord2(5.0, 5.0), 4.0).to_path::<SimpleBezierPath>()];
+    let circle2 = [Circle::new(Coord2(7.0, 5.0), 4.0).to_path::<SimpleBezierPath>()];
+
+    // Cancel once the paths have been collided
+    let combined = path_add_with_progress::<_, _, SimpleBezierPath, _>(
+        &circle1,
+        &circle2,
+        0.01,
+        &|phase, _amount_complete| phase == OperationPhase::Colliding,
+    );
+    assert!(combined == Err(OperationCancelled));
+
+    // Cancel part of the way through colliding the paths
+    let num_calls = std::cell::Cell::new(0);
+    let combined = path_add_with_progress::<_, _, SimpleBezierPath, _>(
+        &circle1,
+        &circle2,
+        0.01,
+        &|phase, _amount_complete| {
+            assert!(phase == OperationPhase::Colliding);
+
+            num_calls.set(num_calls.get() + 1);
+            num_calls.get() < 3
+        },
+    );
+    assert!(combined == Err(OperationCancelled));
+    assert!(num_calls.get() == 3);
+
+    let token = CancellationToken::new();
+    let combined =
+        path_add_with_progress::<_, _, SimpleBezierPath, _>(&circle1, &circle2, 0.01, &token);
+    assert!(combined.is_ok());
+
+    token.cancel();
+    let combined =
+        path_add_with_progress::<_, _, SimpleBezierPath, _>(&circle1, &circle2, 0.01, &token);
+    assert!(combined == Err(OperationCancelled));
+}
+
+#[test]
+fn add_with_labels_reports_source_sections() {
+    let rectangle1 = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(1.0, 1.0))
+        .line_to(Coord2(5.0, 1.0))
+        .line_to(Coord2(5.0, 5.0))
+        .line_to(Coord2(1.0, 5.0))
+        .line_to(Coord2(1.0, 1.0))
+        .build();
+    let rectangle2 = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(3.0, 3.0))
+        .line_to(Coord2(7.0, 3.0))
+        .line_to(Coord2(7.0, 7.0))
+        .line_to(Coord2(3.0, 7.0))
+        .line_to(Coord2(3.0, 3.0))
+        .build();
+
+    let inputs = [rectangle1.clone(), rectangle2.clone()];
+    let input_curves = inputs
+        .iter()
+        .map(|path| path.to_curves::<Curve<Coord2>>())
+        .collect::<Vec<_>>();
+
+    let combined =
+        path_add_with_labels::<_, _, SimpleBezierPath>(&[rectangle1], &[rectangle2], 0.01);
+
+    assert!(combined.len() == 1);
+
+    let (path, sources) = &combined[0];
+    let curves = path.to_curves::<Curve<Coord2>>();
+    assert!(curves.len() == sources.len());
+    assert!(curves.len() == 8);
+
+    // Every section of the result should be on the section of the input path that it says it came from
+    for (curve, source) in curves.iter().zip(sources.iter()) {
+        assert!(source.path_idx == 0);
+        assert!(source.section_idx < 4);
+
+        let source_curve = &input_curves[source.path_set as usize][source.section_idx];
+        let mid_point = curve.point_at_pos(0.5);
+        let (_, nearest) = nearest_point_on_curve(source_curve, &mid_point);
+
+        assert!(nearest.distance_to(&mid_point) < 0.01);
+    }
+
+    assert!(sources.iter().any(|source| source.path_set == 0));
+    assert!(sources.iter().any(|source| source.path_set == 1));
+}
+
+#[test]
+fn add_with_labels_with_empty_path() {
+    let circle = Circle::new(Coord2(5.0, 5.0), 4.0).to_path::<SimpleBezierPath>();
+
+    let combined = path_add_with_labels::<_, SimpleBezierPath, SimpleBezierPath>(
+        std::slice::from_ref(&circle),
+        &[],
+        0.01,
+    );
+
+    assert!(combined.len() == 1);
+    assert!(combined[0].0 == circle);
+    assert!(combined[0].1.len() == circle.points().count());
+    assert!(combined[0]
+        .1
+        .iter()
+        .enumerate()
+        .all(|(idx, source)| source.path_set == 0
+            && source.path_idx == 0
+            && source.section_idx == idx));
+}
@@ -1,9 +1,9 @@
 use flo_curves::arc::Circle;
 use flo_curves::bezier::path::{
-    path_add_chain, path_remove_interior_points, BezierPath, BezierPathBuilder, BezierPathFactory,
+    path_add_chain, path_add_many, path_remove_interior_points, BezierPath, BezierPathBuilder,
     GraphPath, SimpleBezierPath,
 };
-use flo_curves::{BezierCurve, Coord2, Coordinate, Line};
+use flo_curves::{BoundingBox, Bounds, Coord2, Coordinate, Coordinate2D};
 
 use super::svg::svg_path_string;
 
@@ -538,3 +538,84 @@ fn rectangle_add_with_shared_point_6() {
     assert!(points[6].2.distance_to(&Coord2(5.0, 1.0)) < 0.1);
     assert!(points[7].2.distance_to(&Coord2(1.0, 1.0)) < 0.1);
 }
+
+#[test]
+fn add_many_overlapping_circles() {
+    // A row of circles that each overlap their neighbours
+    let circles = (0..9)
+        .map(|idx| {
+            Circle::new(Coord2(5.0 + (idx as f64) * 3.0, 5.0), 2.0).to_path::<SimpleBezierPath>()
+        })
+        .collect::<Vec<_>>();
+
+    let combined = path_add_many::<_, SimpleBezierPath, _>(circles.clone(), 0.01);
+    let chained = path_add_chain::<_, SimpleBezierPath>(
+        &circles
+            .into_iter()
+            .map(|circle| vec![circle])
+            .collect::<Vec<_>>(),
+        0.01,
+    );
+
+    assert!(combined.len() == 1);
+    assert!(chained.len() == 1);
+
+    // Every point should be on the outside of one of the circles
+    for (_, _, point) in combined[0].points() {
+        let distance = (0..9)
+            .map(|idx| Coord2(5.0 + (idx as f64) * 3.0, 5.0).distance_to(&point))
+            .fold(f64::MAX, f64::min);
+
+        assert!((distance - 2.0).abs() < 0.01);
+    }
+
+    assert!(combined[0].points().count() == chained[0].points().count());
+}
+
+#[test]
+fn add_many_separate_circles() {
+    let circles = (0..5)
+        .map(|idx| {
+            Circle::new(Coord2(5.0 + (idx as f64) * 10.0, 5.0), 2.0).to_path::<SimpleBezierPath>()
+        })
+        .collect::<Vec<_>>();
+
+    let combined = path_add_many::<_, SimpleBezierPath, _>(circles, 0.01);
+
+    assert!(combined.len() == 5);
+}
+
+#[test]
+fn add_many_in_any_order() {
+    // Circles in a grid, added in a jumbled order
+    let mut circles = vec![];
+    for idx in 0..16 {
+        let idx = (idx * 7) % 16;
+        let (x, y) = ((idx % 4) as f64, (idx / 4) as f64);
+
+        circles.push(
+            Circle::new(Coord2(5.0 + x * 3.0, 5.0 + y * 3.0), 2.0).to_path::<SimpleBezierPath>(),
+        );
+    }
+
+    let combined = path_add_many::<_, SimpleBezierPath, _>(circles, 0.01);
+
+    // The outside of the grid is one path, and there are holes between the circles
+    let outer = combined
+        .iter()
+        .filter(|path| {
+            let bounds = path.bounding_box::<Bounds<Coord2>>();
+            bounds.min().x() < 3.5 && bounds.max().x() > 15.5
+        })
+        .count();
+
+    assert!(outer == 1);
+    assert!(combined.len() == 10);
+}
+
+#[test]
+fn add_many_with_no_paths() {
+    let combined = path_add_many::<SimpleBezierPath, SimpleBezierPath, _>(vec![], 0.01);
+
+    assert!(combined.is_empty());
+}
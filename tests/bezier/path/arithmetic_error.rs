@@ -0,0 +1,132 @@
+use flo_curves::arc::Circle;
+use flo_curves::bezier::path::{
+    path_add, path_intersect, path_remove_interior_points, path_sub, try_path_add,
+    try_path_intersect, try_path_remove_interior_points, try_path_sub, GraphPath,
+    GraphPathEdgeKind, PathArithmeticError, PathLabel, RayCastStrategy, SimpleBezierPath,
+};
+use flo_curves::Coord2;
+
+fn overlapping_circles() -> (SimpleBezierPath, SimpleBezierPath) {
+    (
+        Circle::new(Coord2(5.0, 5.0), 4.0).to_path::<SimpleBezierPath>(),
+        Circle::new(Coord2(9.0, 5.0), 4.0).to_path::<SimpleBezierPath>(),
+    )
+}
+
+fn open_graph_path() -> GraphPath<Coord2, PathLabel> {
+    // A square with its left-hand edge missing
+    GraphPath::from_debug_string(
+        "graph_path v1
+next_path_index 1
+point 0 5.0 1.0
+  edge 1 following 0 kind Uncategorised cp1 5.0 2.0 cp2 5.0 4.0 label 0 Anticlockwise
+point 1 5.0 5.0
+  edge 2 following 0 kind Uncategorised cp1 4.0 5.0 cp2 2.0 5.0 label 0 Anticlockwise
+point 2 1.0 5.0
+point 3 1.0 1.0
+  edge 0 following 0 kind Uncategorised cp1 2.0 1.0 cp2 4.0 1.0 label 0 Anticlockwise
+",
+    )
+    .unwrap()
+}
+
+#[test]
+fn try_add_matches_add() {
+    let (circle1, circle2) = overlapping_circles();
+
+    let added = path_add::<_, _, SimpleBezierPath>(
+        std::slice::from_ref(&circle1),
+        std::slice::from_ref(&circle2),
+        0.01,
+    );
+    let try_added = try_path_add::<_, _, SimpleBezierPath>(&[circle1], &[circle2], 0.01);
+
+    assert!(try_added == Ok(added));
+}
+
+#[test]
+fn try_sub_matches_sub() {
+    let (circle1, circle2) = overlapping_circles();
+
+    let subtracted = path_sub::<_, _, SimpleBezierPath>(
+        std::slice::from_ref(&circle1),
+        std::slice::from_ref(&circle2),
+        0.01,
+    );
+    let try_subtracted = try_path_sub::<_, _, SimpleBezierPath>(&[circle1], &[circle2], 0.01);
+
+    assert!(try_subtracted == Ok(subtracted));
+}
+
+#[test]
+fn try_intersect_matches_intersect() {
+    let (circle1, circle2) = overlapping_circles();
+
+    let intersected = path_intersect::<_, _, SimpleBezierPath>(
+        std::slice::from_ref(&circle1),
+        std::slice::from_ref(&circle2),
+        0.01,
+    );
+    let try_intersected =
+        try_path_intersect::<_, _, SimpleBezierPath>(&[circle1], &[circle2], 0.01);
+
+    assert!(try_intersected == Ok(intersected));
+}
+
+#[test]
+fn try_remove_interior_points_matches_remove_interior_points() {
+    let (circle1, circle2) = overlapping_circles();
+    let paths = [circle1, circle2];
+
+    let removed = path_remove_interior_points::<_, SimpleBezierPath>(&paths, 0.01);
+    let try_removed = try_path_remove_interior_points::<_, SimpleBezierPath>(&paths, 0.01);
+
+    assert!(try_removed == Ok(removed));
+}
+
+#[test]
+fn open_path_reports_inconsistent_collisions() {
+    let mut graph_path = open_graph_path();
+
+    let result = graph_path.try_set_edge_kinds_by_ray_casting(
+        |path_crossings| (path_crossings[0] & 1) != 0,
+        &RayCastStrategy::single_ray(),
+    );
+
+    match result {
+        Err(PathArithmeticError::InconsistentRayCollisions { num_collisions, .. }) => {
+            assert!((num_collisions & 1) != 0)
+        }
+        other => panic!("Expected inconsistent collisions, got {:?}", other),
+    }
+}
+
+#[test]
+fn set_edge_kinds_still_classifies_open_path() {
+    // The non-try version makes a best guess rather than stopping
+    let mut graph_path = open_graph_path();
+
+    graph_path.set_edge_kinds_by_ray_casting_with_strategy(
+        |path_crossings| (path_crossings[0] & 1) != 0,
+        &RayCastStrategy::single_ray(),
+    );
+
+    assert!(graph_path
+        .all_edge_refs()
+        .all(|edge| graph_path.edge_kind(edge) != GraphPathEdgeKind::Uncategorised));
+}
+
+#[test]
+fn display_inconsistent_collisions() {
+    let mut graph_path = open_graph_path();
+
+    let error = graph_path
+        .try_set_edge_kinds_by_ray_casting(
+            |path_crossings| (path_crossings[0] & 1) != 0,
+            &RayCastStrategy::single_ray(),
+        )
+        .unwrap_err();
+    let message = error.to_string();
+
+    assert!(message.starts_with("inconsistent ray collisions for edge"));
+}
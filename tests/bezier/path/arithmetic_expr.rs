@@ -0,0 +1,71 @@
+use flo_curves::arc::Circle;
+use flo_curves::bezier::path::{path_add, path_sub, BezierPath, PathExpr, SimpleBezierPath};
+use flo_curves::{BoundingBox, Coord2, Coordinate};
+
+fn circle(x: f64, y: f64, radius: f64) -> Vec<SimpleBezierPath> {
+    vec![Circle::new(Coord2(x, y), radius).to_path::<SimpleBezierPath>()]
+}
+
+#[test]
+fn union_of_two_circles() {
+    let expr = PathExpr::union(vec![
+        PathExpr::path(circle(5.0, 5.0, 4.0)),
+        PathExpr::path(circle(9.0, 5.0, 4.0)),
+    ]);
+
+    let result = expr.evaluate::<SimpleBezierPath>(0.01);
+
+    assert!(result.len() == 1);
+
+    let bounds = result[0].bounding_box::<(Coord2, Coord2)>();
+    assert!(bounds.min().distance_to(&Coord2(1.0, 1.0)) < 0.01);
+    assert!(bounds.max().distance_to(&Coord2(13.0, 9.0)) < 0.01);
+}
+
+#[test]
+fn union_then_subtract_matches_separate_operations() {
+    let expr = PathExpr::union(vec![
+        PathExpr::path(circle(5.0, 5.0, 4.0)),
+        PathExpr::path(circle(9.0, 5.0, 4.0)),
+    ])
+    .subtract(PathExpr::path(circle(7.0, 5.0, 1.0)));
+
+    let result = expr.evaluate::<SimpleBezierPath>(0.01);
+
+    let added =
+        path_add::<_, _, SimpleBezierPath>(&circle(5.0, 5.0, 4.0), &circle(9.0, 5.0, 4.0), 0.01);
+    let expected = path_sub::<_, _, SimpleBezierPath>(&added, &circle(7.0, 5.0, 1.0), 0.01);
+
+    // Outer edge with a hole in the middle
+    assert!(result.len() == 2);
+    assert!(result.len() == expected.len());
+}
+
+#[test]
+fn intersection_then_subtract() {
+    let expr = PathExpr::intersect(vec![
+        PathExpr::path(circle(5.0, 5.0, 4.0)),
+        PathExpr::path(circle(9.0, 5.0, 4.0)),
+    ])
+    .subtract(PathExpr::path(circle(7.0, 5.0, 1.0)));
+
+    let result = expr.evaluate::<SimpleBezierPath>(0.01);
+
+    // The lens shape where the two circles overlap, with a hole cut in it
+    assert!(result.len() == 2);
+
+    let lens = result
+        .iter()
+        .map(|path| path.bounding_box::<(Coord2, Coord2)>())
+        .find(|bounds| (bounds.min().0 - 5.0).abs() < 0.01)
+        .expect("lens");
+    assert!((lens.max().0 - 9.0).abs() < 0.01);
+}
+
+#[test]
+fn intersect_nothing_is_empty() {
+    let expr = PathExpr::<SimpleBezierPath>::intersect(vec![]);
+    let result = expr.evaluate::<SimpleBezierPath>(0.01);
+
+    assert!(result.is_empty());
+}
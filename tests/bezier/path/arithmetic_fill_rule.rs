@@ -0,0 +1,57 @@
+use flo_curves::arc::Circle;
+use flo_curves::bezier::path::{path_add_with_fill_rules, BezierPath, FillRule, SimpleBezierPath};
+use flo_curves::{BoundingBox, Coord2};
+
+fn ring() -> Vec<SimpleBezierPath> {
+    // Two circles wound in the same direction
+    let outer = Circle::new(Coord2(5.0, 5.0), 4.0).to_path::<SimpleBezierPath>();
+    let inner = Circle::new(Coord2(5.0, 5.0), 2.0).to_path::<SimpleBezierPath>();
+
+    vec![outer, inner]
+}
+
+#[test]
+fn even_odd_ring_has_hole() {
+    let result =
+        path_add_with_fill_rules::<_, SimpleBezierPath>(&[(ring(), FillRule::EvenOdd)], 0.01);
+
+    assert!(result.len() == 2);
+}
+
+#[test]
+fn non_zero_ring_is_filled() {
+    let result =
+        path_add_with_fill_rules::<_, SimpleBezierPath>(&[(ring(), FillRule::NonZero)], 0.01);
+
+    assert!(result.len() == 1);
+
+    let bounds = result[0].bounding_box::<(Coord2, Coord2)>();
+    assert!((bounds.max().0 - 9.0).abs() < 0.01);
+}
+
+#[test]
+fn non_zero_reversed_inner_path_has_hole() {
+    let outer = Circle::new(Coord2(5.0, 5.0), 4.0).to_path::<SimpleBezierPath>();
+    let inner = Circle::new(Coord2(5.0, 5.0), 2.0).to_path::<SimpleBezierPath>();
+    let inner = inner.reversed::<SimpleBezierPath>();
+
+    let result = path_add_with_fill_rules::<_, SimpleBezierPath>(
+        &[(vec![outer, inner], FillRule::NonZero)],
+        0.01,
+    );
+
+    assert!(result.len() == 2);
+}
+
+#[test]
+fn fill_rules_apply_to_each_shape() {
+    // The even-odd ring leaves a hole, and a smaller circle filled with the non-zero rule sits inside that hole
+    let dot = Circle::new(Coord2(5.0, 5.0), 1.0).to_path::<SimpleBezierPath>();
+
+    let result = path_add_with_fill_rules::<_, SimpleBezierPath>(
+        &[(ring(), FillRule::EvenOdd), (vec![dot], FillRule::NonZero)],
+        0.01,
+    );
+
+    assert!(result.len() == 3);
+}
@@ -0,0 +1,109 @@
+use flo_curves::arc::Circle;
+use flo_curves::bezier::path::{
+    path_is_polygon, polygon_add, polygon_intersect, polygon_sub, BezierPath, BezierPathBuilder,
+    SimpleBezierPath,
+};
+use flo_curves::Coord2;
+
+fn square(min: Coord2, max: Coord2) -> SimpleBezierPath {
+    BezierPathBuilder::<SimpleBezierPath>::start(min)
+        .line_to(Coord2(min.0, max.1))
+        .line_to(max)
+        .line_to(Coord2(max.0, min.1))
+        .line_to(min)
+        .build()
+}
+
+fn has_vertex(path: &SimpleBezierPath, vertex: Coord2) -> bool {
+    path.points().any(|(_, _, point)| point == vertex)
+}
+
+#[test]
+fn square_is_polygon() {
+    assert!(path_is_polygon(&square(Coord2(0.0, 0.0), Coord2(4.0, 4.0))));
+}
+
+#[test]
+fn circle_is_not_polygon() {
+    let circle = Circle::new(Coord2(5.0, 5.0), 4.0).to_path::<SimpleBezierPath>();
+    assert!(!path_is_polygon(&circle));
+}
+
+#[test]
+fn add_overlapping_squares() {
+    let square1 = square(Coord2(0.0, 0.0), Coord2(4.0, 4.0));
+    let square2 = square(Coord2(2.0, 2.0), Coord2(6.0, 6.0));
+
+    let added = polygon_add::<_, _, SimpleBezierPath>(&[square1], &[square2], 0.01);
+
+    assert!(added.len() == 1);
+    assert!(added[0].1.len() == 8);
+
+    // Intersection points are exact
+    assert!(has_vertex(&added[0], Coord2(2.0, 4.0)));
+    assert!(has_vertex(&added[0], Coord2(4.0, 2.0)));
+}
+
+#[test]
+fn subtract_overlapping_squares() {
+    let square1 = square(Coord2(0.0, 0.0), Coord2(4.0, 4.0));
+    let square2 = square(Coord2(2.0, 2.0), Coord2(6.0, 6.0));
+
+    let subtracted = polygon_sub::<_, _, SimpleBezierPath>(&[square1], &[square2], 0.01);
+
+    assert!(subtracted.len() == 1);
+    assert!(subtracted[0].1.len() == 6);
+    assert!(has_vertex(&subtracted[0], Coord2(2.0, 2.0)));
+    assert!(has_vertex(&subtracted[0], Coord2(2.0, 4.0)));
+    assert!(has_vertex(&subtracted[0], Coord2(4.0, 2.0)));
+}
+
+#[test]
+fn intersect_overlapping_squares() {
+    let square1 = square(Coord2(0.0, 0.0), Coord2(4.0, 4.0));
+    let square2 = square(Coord2(2.0, 2.0), Coord2(6.0, 6.0));
+
+    let intersected = polygon_intersect::<_, _, SimpleBezierPath>(&[square1], &[square2], 0.01);
+
+    assert!(intersected.len() == 1);
+    assert!(intersected[0].1.len() == 4);
+    assert!(has_vertex(&intersected[0], Coord2(2.0, 2.0)));
+    assert!(has_vertex(&intersected[0], Coord2(4.0, 4.0)));
+    assert!(has_vertex(&intersected[0], Coord2(2.0, 4.0)));
+    assert!(has_vertex(&intersected[0], Coord2(4.0, 2.0)));
+}
+
+#[test]
+fn add_squares_sharing_an_edge() {
+    let square1 = square(Coord2(0.0, 0.0), Coord2(4.0, 4.0));
+    let square2 = square(Coord2(4.0, 1.0), Coord2(8.0, 3.0));
+
+    let added = polygon_add::<_, _, SimpleBezierPath>(&[square1], &[square2], 0.01);
+
+    assert!(added.len() == 1);
+    assert!(added[0].1.len() == 8);
+}
+
+#[test]
+fn line_with_overshooting_control_points_is_not_polygon() {
+    // The control points are on the line, but outside of the section, so the curve doubles back on itself
+    let path = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(0.0, 0.0))
+        .curve_to((Coord2(-2.0, 0.0), Coord2(6.0, 0.0)), Coord2(4.0, 0.0))
+        .line_to(Coord2(4.0, 4.0))
+        .line_to(Coord2(0.0, 0.0))
+        .build();
+
+    assert!(!path_is_polygon(&path));
+}
+
+#[test]
+fn add_polygon_inside_polygon() {
+    let square1 = square(Coord2(0.0, 0.0), Coord2(6.0, 6.0));
+    let square2 = square(Coord2(2.0, 2.0), Coord2(4.0, 4.0));
+
+    let added = polygon_add::<_, _, SimpleBezierPath>(&[square1], &[square2], 0.01);
+
+    assert!(added.len() == 1);
+    assert!(added[0].1.len() == 4);
+    assert!(has_vertex(&added[0], Coord2(6.0, 6.0)));
+}
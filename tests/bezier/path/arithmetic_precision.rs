@@ -0,0 +1,171 @@
+use flo_curves::arc::Circle;
+use flo_curves::bezier::path::{
+    path_add, path_add_with_precision, path_sub, path_sub_with_precision, BezierPath, GraphPath,
+    PathDirection, PathLabel, PrecisionContext, SimpleBezierPath,
+};
+use flo_curves::{BoundingBox, Bounds, Coord2, Coordinate};
+
+#[test]
+fn default_precision_matches_path_add() {
+    let circle1 = Circle::new(Coord2(5.0, 5.0), 4.0).to_path::<SimpleBezierPath>();
+    let circle2 = Circle::new(Coord2(7.0, 5.0), 4.0).to_path::<SimpleBezierPath>();
+
+    let combined = path_add::<_, _, SimpleBezierPath>(
+        std::slice::from_ref(&circle1),
+        std::slice::from_ref(&circle2),
+        0.01,
+    );
+    let with_precision = path_add_with_precision::<_, _, SimpleBezierPath>(
+        &[circle1],
+        &[circle2],
+        &PrecisionContext::default(),
+    );
+
+    assert!(combined == with_precision);
+}
+
+#[test]
+fn add_tiny_overlapping_circles() {
+    // Circles 10,000 times smaller than usual: the default snap distance is larger than the whole shape
+    let scale = 1e-4;
+    let circle1 = Circle::new(Coord2(5.0, 5.0) * scale, 4.0 * scale).to_path::<SimpleBezierPath>();
+    let circle2 = Circle::new(Coord2(7.0, 5.0) * scale, 4.0 * scale).to_path::<SimpleBezierPath>();
+
+    let precision = PrecisionContext::default().scaled(scale);
+    let combined =
+        path_add_with_precision::<_, _, SimpleBezierPath>(&[circle1], &[circle2], &precision);
+
+    assert!(combined.len() == 1);
+
+    // Should produce the same result as combining the circles at the normal scale
+    let large_circle1 = Circle::new(Coord2(5.0, 5.0), 4.0).to_path::<SimpleBezierPath>();
+    let large_circle2 = Circle::new(Coord2(7.0, 5.0), 4.0).to_path::<SimpleBezierPath>();
+    let large_combined =
+        path_add::<_, _, SimpleBezierPath>(&[large_circle1], &[large_circle2], 0.01);
+
+    assert!(large_combined.len() == 1);
+    assert!(combined[0].1.len() == large_combined[0].1.len());
+
+    for ((_, _, point), (_, _, large_point)) in combined[0].points().zip(large_combined[0].points())
+    {
+        assert!(point.distance_to(&(large_point * scale)) < 1e-9);
+    }
+
+    // All of the points should be on one of the circles
+    for (_, _, point) in combined[0].points() {
+        let distance_to_circle1 = (Coord2(5.0, 5.0) * scale).distance_to(&point);
+        let distance_to_circle2 = (Coord2(7.0, 5.0) * scale).distance_to(&point);
+
+        assert!(
+            (distance_to_circle1 - 4.0 * scale).abs() < 0.01 * scale
+                || (distance_to_circle2 - 4.0 * scale).abs() < 0.01 * scale
+        );
+    }
+}
+
+#[test]
+fn subtract_huge_circles() {
+    // Circles 10,000 times larger than usual
+    let scale = 1e4;
+    let circle1 = Circle::new(Coord2(5.0, 5.0) * scale, 4.0 * scale).to_path::<SimpleBezierPath>();
+    let circle2 = Circle::new(Coord2(7.0, 5.0) * scale, 4.0 * scale).to_path::<SimpleBezierPath>();
+
+    let precision = PrecisionContext::default().scaled(scale);
+    let subtracted =
+        path_sub_with_precision::<_, _, SimpleBezierPath>(&[circle1], &[circle2], &precision);
+
+    let large_circle1 = Circle::new(Coord2(5.0, 5.0), 4.0).to_path::<SimpleBezierPath>();
+    let large_circle2 = Circle::new(Coord2(7.0, 5.0), 4.0).to_path::<SimpleBezierPath>();
+    let expected = path_sub::<_, _, SimpleBezierPath>(&[large_circle1], &[large_circle2], 0.01);
+
+    assert!(subtracted.len() == 1);
+    assert!(subtracted[0].1.len() == expected[0].1.len());
+
+    for ((_, _, point), (_, _, expected_point)) in subtracted[0].points().zip(expected[0].points())
+    {
+        assert!(point.distance_to(&(expected_point * scale)) < 1e-6 * scale);
+    }
+}
+
+#[test]
+fn collide_tiny_circles_with_precision() {
+    let scale = 1e-4;
+    let circle1 = Circle::new(Coord2(5.0, 5.0) * scale, 4.0 * scale).to_path::<SimpleBezierPath>();
+    let circle2 = Circle::new(Coord2(7.0, 5.0) * scale, 4.0 * scale).to_path::<SimpleBezierPath>();
+
+    let precision = PrecisionContext::default().scaled(scale);
+    let graph_path = GraphPath::from_path_with_precision(
+        &circle1,
+        PathLabel(0, PathDirection::Clockwise),
+        &precision,
+    )
+    .collide_with_precision(
+        GraphPath::from_path_with_precision(
+            &circle2,
+            PathLabel(1, PathDirection::Clockwise),
+            &precision,
+        ),
+        &precision,
+    );
+
+    // Each circle is divided at the two points where they intersect
+    let large_circle1 = Circle::new(Coord2(5.0, 5.0), 4.0).to_path::<SimpleBezierPath>();
+    let large_circle2 = Circle::new(Coord2(7.0, 5.0), 4.0).to_path::<SimpleBezierPath>();
+    let large_graph_path =
+        GraphPath::from_path(&large_circle1, PathLabel(0, PathDirection::Clockwise)).collide(
+            GraphPath::from_path(&large_circle2, PathLabel(1, PathDirection::Clockwise)),
+            0.01,
+        );
+
+    assert!(graph_path.num_points() == large_graph_path.num_points());
+    assert!(graph_path.all_edges().count() == large_graph_path.all_edges().count());
+}
+
+#[test]
+fn automatic_precision_from_bounds() {
+    let precision = PrecisionContext::for_bounds(&Bounds::from_min_max(
+        Coord2(0.0, 0.0),
+        Coord2(1000.0, 500.0),
+    ));
+    assert!(precision == PrecisionContext::default());
+
+    let precision =
+        PrecisionContext::for_bounds(&Bounds::from_min_max(Coord2(0.0, 0.0), Coord2(0.5, 1.0)));
+    assert!(
+        (precision.snap_distance - PrecisionContext::default().snap_distance * 0.001).abs() < 1e-12
+    );
+    assert!((precision.accuracy - PrecisionContext::default().accuracy * 0.001).abs() < 1e-12);
+
+    let precision = PrecisionContext::for_paths::<SimpleBezierPath>(&[]);
+    assert!(precision == PrecisionContext::default());
+}
+
+#[test]
+fn add_unit_circles_with_automatic_precision() {
+    // Geometry in the 0..1 range should give the same result as the same geometry in the 0..1000 range
+    let circle1 = Circle::new(Coord2(0.4, 0.5), 0.3).to_path::<SimpleBezierPath>();
+    let circle2 = Circle::new(Coord2(0.6, 0.5), 0.3).to_path::<SimpleBezierPath>();
+    let large_circle1 = Circle::new(Coord2(400.0, 500.0), 300.0).to_path::<SimpleBezierPath>();
+    let large_circle2 = Circle::new(Coord2(600.0, 500.0), 300.0).to_path::<SimpleBezierPath>();
+
+    let precision = PrecisionContext::for_paths(&[circle1.clone(), circle2.clone()]);
+    let large_precision =
+        PrecisionContext::for_paths(&[large_circle1.clone(), large_circle2.clone()]);
+
+    let combined =
+        path_add_with_precision::<_, _, SimpleBezierPath>(&[circle1], &[circle2], &precision);
+    let large_combined = path_add_with_precision::<_, _, SimpleBezierPath>(
+        &[large_circle1],
+        &[large_circle2],
+        &large_precision,
+    );
+
+    assert!(combined.len() == 1);
+    assert!(large_combined.len() == 1);
+    assert!(combined[0].1.len() == large_combined[0].1.len());
+
+    for ((_, _, point), (_, _, large_point)) in combined[0].points().zip(large_combined[0].points())
+    {
+        assert!(point.distance_to(&(large_point * 0.001)) < 1e-6);
+    }
+}
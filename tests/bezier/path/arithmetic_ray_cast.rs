@@ -0,0 +1,87 @@
+use flo_curves::arc::Circle;
+use flo_curves::bezier::path::{
+    path_add, path_signed_area, BezierPathBuilder, GraphPath, PathDirection, PathLabel,
+    RayCastStrategy, SimpleBezierPath,
+};
+use flo_curves::Coord2;
+
+fn rectangle(min: Coord2, max: Coord2) -> SimpleBezierPath {
+    BezierPathBuilder::<SimpleBezierPath>::start(min)
+        .line_to(Coord2(max.0, min.1))
+        .line_to(max)
+        .line_to(Coord2(min.0, max.1))
+        .line_to(min)
+        .build()
+}
+
+fn add_with_strategy(
+    path1: &SimpleBezierPath,
+    path2: &SimpleBezierPath,
+    strategy: &RayCastStrategy,
+) -> Vec<SimpleBezierPath> {
+    let mut merged_path = GraphPath::from_path(path1, PathLabel(0, PathDirection::from(path1)));
+    merged_path = merged_path.collide(
+        GraphPath::from_path(path2, PathLabel(1, PathDirection::from(path2))),
+        0.01,
+    );
+
+    merged_path.set_edge_kinds_by_ray_casting_with_strategy(
+        |path_crossings| path_crossings[0] != 0 || path_crossings[1] != 0,
+        strategy,
+    );
+    merged_path.heal_exterior_gaps();
+
+    merged_path.exterior_paths()
+}
+
+#[test]
+fn create_ray_cast_strategy() {
+    let strategy = RayCastStrategy::new(vec![(0.5, 0.0), (1.5, 0.2)]);
+    assert!(strategy.rays().collect::<Vec<_>>() == vec![(0.5, 0.0), (1.0, 0.2)]);
+
+    // An empty strategy casts a single ray at the midpoint of each edge
+    let empty = RayCastStrategy::new(vec![]);
+    assert!(empty == RayCastStrategy::single_ray());
+    assert!(empty.rays().collect::<Vec<_>>() == vec![(0.5, 0.0)]);
+
+    // The default strategy starts with the same ray
+    assert!(RayCastStrategy::default().rays().next() == Some((0.5, 0.0)));
+    assert!(RayCastStrategy::default().rays().count() > 1);
+}
+
+#[test]
+fn strategies_agree_on_overlapping_circles() {
+    let circle1 = Circle::new(Coord2(5.0, 5.0), 4.0).to_path::<SimpleBezierPath>();
+    let circle2 = Circle::new(Coord2(7.0, 5.0), 4.0).to_path::<SimpleBezierPath>();
+
+    let strategies = [
+        RayCastStrategy::single_ray(),
+        RayCastStrategy::default(),
+        RayCastStrategy::new(vec![(0.3, 0.2), (0.6, -0.1)]),
+    ];
+
+    for strategy in strategies.iter() {
+        let combined = add_with_strategy(&circle1, &circle2, strategy);
+
+        assert!(combined.len() == 1);
+        assert!((path_signed_area(&combined[0]).abs() - 66.0).abs() < 1.0);
+    }
+}
+
+#[test]
+fn add_with_grazing_ray() {
+    // The ray cast from the middle of the bottom edge of the first rectangle runs straight along the left edge of the
+    // second one
+    let rect1 = rectangle(Coord2(0.0, 0.0), Coord2(10.0, 10.0));
+    let rect2 = rectangle(Coord2(5.0, -20.0), Coord2(15.0, -10.0));
+
+    let combined = add_with_strategy(&rect1, &rect2, &RayCastStrategy::default());
+
+    assert!(combined.len() == 2);
+    for path in combined.iter() {
+        assert!((path_signed_area(path).abs() - 100.0).abs() < 1e-6);
+    }
+
+    let combined: Vec<SimpleBezierPath> = path_add(&[rect1], &[rect2], 0.01);
+    assert!(combined.len() == 2);
+}
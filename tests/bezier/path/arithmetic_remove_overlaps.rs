@@ -0,0 +1,55 @@
+use flo_curves::arc::Circle;
+use flo_curves::bezier::path::{
+    path_remove_overlaps, BezierPath, PathWithIsClockwise, SimpleBezierPath,
+};
+use flo_curves::{BoundingBox, Coord2};
+
+#[test]
+fn remove_overlaps_joins_overlapping_circles() {
+    let circle1 = Circle::new(Coord2(5.0, 5.0), 4.0).to_path::<SimpleBezierPath>();
+    let circle2 = Circle::new(Coord2(9.0, 5.0), 4.0).to_path::<SimpleBezierPath>();
+
+    let removed = path_remove_overlaps::<_, SimpleBezierPath>(&[circle1, circle2], 0.01);
+
+    assert!(removed.len() == 1);
+    assert!(removed[0].is_clockwise());
+
+    let bounds = removed[0].bounding_box::<(Coord2, Coord2)>();
+    assert!((bounds.min().0 - 1.0).abs() < 0.01);
+    assert!((bounds.max().0 - 13.0).abs() < 0.01);
+}
+
+#[test]
+fn remove_overlaps_makes_outer_paths_clockwise() {
+    let circle = Circle::new(Coord2(5.0, 5.0), 4.0).to_path::<SimpleBezierPath>();
+    let circle = if circle.is_clockwise() {
+        circle.reversed::<SimpleBezierPath>()
+    } else {
+        circle
+    };
+
+    let removed = path_remove_overlaps::<_, SimpleBezierPath>(&[circle], 0.01);
+
+    assert!(removed.len() == 1);
+    assert!(removed[0].is_clockwise());
+}
+
+#[test]
+fn remove_overlaps_keeps_holes_anticlockwise() {
+    let outer = Circle::new(Coord2(5.0, 5.0), 4.0).to_path::<SimpleBezierPath>();
+    let inner = Circle::new(Coord2(5.0, 5.0), 2.0).to_path::<SimpleBezierPath>();
+    let inner = inner.reversed::<SimpleBezierPath>();
+
+    let removed = path_remove_overlaps::<_, SimpleBezierPath>(&[outer, inner], 0.01);
+
+    assert!(removed.len() == 2);
+
+    let (outer, inner) = if removed[0].bounding_box::<(Coord2, Coord2)>().min().0 < 2.0 {
+        (&removed[0], &removed[1])
+    } else {
+        (&removed[1], &removed[0])
+    };
+
+    assert!(outer.is_clockwise());
+    assert!(!inner.is_clockwise());
+}
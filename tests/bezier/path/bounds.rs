@@ -1,5 +1,7 @@
 use flo_curves::arc::Circle;
-use flo_curves::bezier::path::{BezierPath, SimpleBezierPath};
+use flo_curves::bezier::path::{
+    path_oriented_bounding_box, BezierPath, BezierPathBuilder, SimpleBezierPath,
+};
 use flo_curves::{BezierCurve, Coord2, Coordinate, Coordinate2D, Coordinate3D};
 
 #[test]
@@ -31,3 +33,33 @@ fn circle_path_fast_bounds() {
     assert!(bounds.1.x() >= 9.0);
     assert!(bounds.1.y() >= 9.0);
 }
+
+#[test]
+fn rotated_rectangle_oriented_bounds() {
+    // A 40x10 rectangle, rotated by 30 degrees
+    let angle = 30.0f64.to_radians();
+    let (sin, cos) = angle.sin_cos();
+    let rotate = |x: f64, y: f64| Coord2(x * cos - y * sin + 100.0, x * sin + y * cos + 50.0);
+
+    let rectangle = BezierPathBuilder::<SimpleBezierPath>::start(rotate(0.0, 0.0))
+        .line_to(rotate(40.0, 0.0))
+        .line_to(rotate(40.0, 10.0))
+        .line_to(rotate(0.0, 10.0))
+        .line_to(rotate(0.0, 0.0))
+        .build();
+
+    let bounds = path_oriented_bounding_box(&rectangle);
+
+    assert!((bounds.area() - 400.0).abs() < 0.01);
+    assert!((bounds.width().max(bounds.height()) - 40.0).abs() < 0.01);
+    assert!(bounds.center().distance_to(&rotate(20.0, 5.0)) < 0.01);
+}
+
+#[test]
+fn circle_path_oriented_bounds() {
+    // Circles don't have a better orientation than the axis-aligned one
+    let circle: SimpleBezierPath = Circle::new(Coord2(5.0, 5.0), 4.0).to_path();
+    let bounds = path_oriented_bounding_box(&circle);
+
+    assert!((bounds.area() - 64.0).abs() < 0.5);
+}
@@ -0,0 +1,80 @@
+use flo_curves::bezier::path::{
+    canonicalize_path, canonicalize_paths, path_add, BezierPathBuilder, SimpleBezierPath,
+};
+use flo_curves::geo::ApproxEq;
+use flo_curves::Coord2;
+
+fn square(x: f64, y: f64, size: f64) -> SimpleBezierPath {
+    BezierPathBuilder::<SimpleBezierPath>::start(Coord2(x, y))
+        .line_to(Coord2(x + size, y))
+        .line_to(Coord2(x + size, y + size))
+        .line_to(Coord2(x, y + size))
+        .line_to(Coord2(x, y))
+        .build()
+}
+
+#[test]
+fn rotate_to_lowest_point() {
+    let path = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(10.0, 10.0))
+        .line_to(Coord2(0.0, 10.0))
+        .line_to(Coord2(0.0, 0.0))
+        .line_to(Coord2(10.0, 0.0))
+        .line_to(Coord2(10.0, 10.0))
+        .build();
+
+    let canonical = canonicalize_path::<_, SimpleBezierPath>(&path);
+
+    assert!(canonical.0 == Coord2(0.0, 0.0));
+    assert!(canonical.1 == vec![path.1[2], path.1[3], path.1[0], path.1[1]]);
+}
+
+#[test]
+fn canonical_path_is_unchanged() {
+    let path = square(0.0, 0.0, 10.0);
+
+    assert!(canonicalize_path::<_, SimpleBezierPath>(&path) == path);
+}
+
+#[test]
+fn open_path_is_not_rotated() {
+    let path = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(10.0, 10.0))
+        .line_to(Coord2(0.0, 10.0))
+        .line_to(Coord2(0.0, 0.0))
+        .build();
+
+    assert!(canonicalize_path::<_, SimpleBezierPath>(&path) == path);
+}
+
+#[test]
+fn sort_paths() {
+    let paths = vec![
+        square(20.0, 0.0, 5.0),
+        square(0.0, 10.0, 5.0),
+        square(0.0, 0.0, 5.0),
+    ];
+
+    let canonical = canonicalize_paths::<_, SimpleBezierPath>(&paths);
+
+    assert!(canonical == vec![paths[2].clone(), paths[1].clone(), paths[0].clone()]);
+}
+
+#[test]
+fn same_result_regardless_of_input_order() {
+    let (square1, square2, square3) = (
+        square(0.0, 0.0, 5.0),
+        square(3.0, 3.0, 5.0),
+        square(20.0, 0.0, 5.0),
+    );
+
+    let result1 = path_add::<_, _, SimpleBezierPath>(
+        &[square1.clone(), square3.clone()],
+        std::slice::from_ref(&square2),
+        0.01,
+    );
+    let result2 = path_add::<_, _, SimpleBezierPath>(&[square2], &[square3, square1], 0.01);
+
+    assert!(result1.len() == 2, "{:?}", result1);
+    // The intersection points can be slightly different depending on the order the paths are added in
+    assert!(canonicalize_paths::<_, SimpleBezierPath>(&result1)
+        .approx_eq(&canonicalize_paths::<_, SimpleBezierPath>(&result2), 0.001));
+}
@@ -0,0 +1,96 @@
+use flo_curves::arc::Circle;
+use flo_curves::bezier::path::{
+    path_stroke_centerline, path_to_curves, BezierPath, BezierPathBuilder, SimpleBezierPath,
+};
+use flo_curves::bezier::{BezierCurve, Curve};
+use flo_curves::{Coord2, Coordinate, Coordinate2D};
+
+use std::f64;
+
+#[test]
+fn centerline_of_straight_stroke() {
+    let outline = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(0.0, 0.0))
+        .line_to(Coord2(100.0, 0.0))
+        .line_to(Coord2(100.0, 10.0))
+        .line_to(Coord2(0.0, 10.0))
+        .line_to(Coord2(0.0, 0.0))
+        .build();
+
+    let stroke = path_stroke_centerline::<_, SimpleBezierPath>(&outline, 1.0, 0.01).unwrap();
+
+    for curve in path_to_curves::<_, Curve<_>>(&stroke.centerline) {
+        for t in [0.0, 0.25, 0.5, 0.75, 1.0] {
+            assert!((curve.point_at_pos(t).y() - 5.0).abs() < 0.1);
+        }
+    }
+
+    let start = stroke.centerline.start_point();
+    let end = stroke.centerline.points().last().unwrap().2;
+    assert!((start.x() - end.x()).abs() > 95.0);
+
+    assert!(stroke.widths.len() > 90);
+    assert!(stroke
+        .widths
+        .iter()
+        .all(|(_, width)| (width - 10.0).abs() < 1e-6));
+    assert!(stroke.widths[0].0 == 0.0);
+    assert!((stroke.widths.last().unwrap().0 - (start.x() - end.x()).abs()).abs() < 1.0);
+}
+
+#[test]
+fn centerline_of_curved_stroke() {
+    // A quarter of a ring, from radius 40 to radius 60
+    let outer = Circle::new(Coord2(0.0, 0.0), 60.0);
+    let inner = Circle::new(Coord2(0.0, 0.0), 40.0);
+    let outer_arc = outer
+        .arc(0.0, f64::consts::FRAC_PI_2)
+        .to_bezier_curve::<Curve<_>>();
+    let inner_arc = inner
+        .arc(f64::consts::FRAC_PI_2, 0.0)
+        .to_bezier_curve::<Curve<_>>();
+
+    let outline = BezierPathBuilder::<SimpleBezierPath>::start(outer_arc.start_point())
+        .curve_to(outer_arc.control_points(), outer_arc.end_point())
+        .line_to(inner_arc.start_point())
+        .curve_to(inner_arc.control_points(), inner_arc.end_point())
+        .line_to(outer_arc.start_point())
+        .build();
+
+    let stroke = path_stroke_centerline::<_, SimpleBezierPath>(&outline, 0.5, 0.01).unwrap();
+
+    for curve in path_to_curves::<_, Curve<_>>(&stroke.centerline) {
+        for t in [0.0, 0.25, 0.5, 0.75, 1.0] {
+            assert!((curve.point_at_pos(t).magnitude() - 50.0).abs() < 0.2);
+        }
+    }
+
+    assert!(stroke
+        .widths
+        .iter()
+        .all(|(_, width)| (width - 20.0).abs() < 0.2));
+
+    // Follows most of the quarter circle
+    let total_length = stroke.widths.last().unwrap().0;
+    assert!(total_length > 50.0 * f64::consts::FRAC_PI_2 * 0.9);
+}
+
+#[test]
+fn circle_has_no_centerline() {
+    let circle = Circle::new(Coord2(0.0, 0.0), 10.0).to_path::<SimpleBezierPath>();
+
+    assert!(path_stroke_centerline::<_, SimpleBezierPath>(&circle, 1.0, 0.01).is_none());
+}
+
+#[test]
+fn invalid_spacing_has_no_centerline() {
+    let outline = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(0.0, 0.0))
+        .line_to(Coord2(10.0, 0.0))
+        .line_to(Coord2(10.0, 10.0))
+        .line_to(Coord2(0.0, 10.0))
+        .line_to(Coord2(0.0, 0.0))
+        .build();
+
+    for spacing in [0.0, -1.0, f64::NAN, f64::INFINITY] {
+        assert!(path_stroke_centerline::<_, SimpleBezierPath>(&outline, spacing, 0.01).is_none());
+    }
+}
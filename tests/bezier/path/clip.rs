@@ -0,0 +1,123 @@
+use flo_curves::arc::Circle;
+use flo_curves::bezier::path::{
+    clip_path_to_bounds, path_contains_point, path_signed_area, BezierPath, BezierPathBuilder,
+    SimpleBezierPath,
+};
+use flo_curves::{BoundingBox, Bounds, Coord2, Coordinate, Coordinate2D};
+
+use std::f64;
+
+fn rectangle(min: Coord2, max: Coord2) -> SimpleBezierPath {
+    BezierPathBuilder::<SimpleBezierPath>::start(min)
+        .line_to(Coord2(max.x(), min.y()))
+        .line_to(max)
+        .line_to(Coord2(min.x(), max.y()))
+        .line_to(min)
+        .build()
+}
+
+#[test]
+fn clip_circle_in_half() {
+    let circle = Circle::new(Coord2(5.0, 5.0), 4.0).to_path::<SimpleBezierPath>();
+    let bounds = Bounds::from_min_max(Coord2(5.0, 0.0), Coord2(20.0, 20.0));
+
+    let clipped: Vec<SimpleBezierPath> = clip_path_to_bounds(&circle, &bounds);
+
+    assert!(clipped.len() == 1);
+
+    let full_area = path_signed_area(&circle);
+    let half_area = path_signed_area(&clipped[0]);
+    assert!(full_area.signum() == half_area.signum());
+    assert!((half_area.abs() - f64::consts::PI * 8.0).abs() < 0.1);
+
+    assert!(path_contains_point(&clipped[0], &Coord2(7.0, 5.0)));
+    assert!(!path_contains_point(&clipped[0], &Coord2(3.0, 5.0)));
+}
+
+#[test]
+fn clip_overlapping_square() {
+    let square = rectangle(Coord2(-5.0, -5.0), Coord2(5.0, 5.0));
+    let bounds = Bounds::from_min_max(Coord2(0.0, 0.0), Coord2(10.0, 10.0));
+
+    let clipped: Vec<SimpleBezierPath> = clip_path_to_bounds(&square, &bounds);
+
+    assert!(clipped.len() == 1);
+    assert!((path_signed_area(&clipped[0]) - 25.0).abs() < 1e-6);
+
+    let clipped_bounds = clipped[0].bounding_box::<Bounds<_>>();
+    assert!(clipped_bounds.min().is_near_to(&Coord2(0.0, 0.0), 1e-6));
+    assert!(clipped_bounds.max().is_near_to(&Coord2(5.0, 5.0), 1e-6));
+}
+
+#[test]
+fn clip_clockwise_square_keeps_direction() {
+    let square = rectangle(Coord2(-5.0, -5.0), Coord2(5.0, 5.0)).reversed::<SimpleBezierPath>();
+    let bounds = Bounds::from_min_max(Coord2(0.0, 0.0), Coord2(10.0, 10.0));
+
+    let clipped: Vec<SimpleBezierPath> = clip_path_to_bounds(&square, &bounds);
+
+    assert!(clipped.len() == 1);
+    assert!((path_signed_area(&clipped[0]) + 25.0).abs() < 1e-6);
+}
+
+#[test]
+fn clip_u_shape_into_two_parts() {
+    let u_shape = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(0.0, 0.0))
+        .line_to(Coord2(30.0, 0.0))
+        .line_to(Coord2(30.0, 20.0))
+        .line_to(Coord2(20.0, 20.0))
+        .line_to(Coord2(20.0, 5.0))
+        .line_to(Coord2(10.0, 5.0))
+        .line_to(Coord2(10.0, 20.0))
+        .line_to(Coord2(0.0, 20.0))
+        .line_to(Coord2(0.0, 0.0))
+        .build();
+    let bounds = Bounds::from_min_max(Coord2(-1.0, 10.0), Coord2(31.0, 30.0));
+
+    let clipped: Vec<SimpleBezierPath> = clip_path_to_bounds(&u_shape, &bounds);
+
+    assert!(clipped.len() == 2);
+    for part in clipped.iter() {
+        assert!((path_signed_area(part) - 100.0).abs() < 1e-6);
+    }
+
+    assert!(clipped
+        .iter()
+        .any(|part| path_contains_point(part, &Coord2(5.0, 15.0))));
+    assert!(clipped
+        .iter()
+        .any(|part| path_contains_point(part, &Coord2(25.0, 15.0))));
+}
+
+#[test]
+fn clip_path_inside_bounds() {
+    let square = rectangle(Coord2(2.0, 2.0), Coord2(8.0, 8.0));
+    let bounds = Bounds::from_min_max(Coord2(0.0, 0.0), Coord2(10.0, 10.0));
+
+    let clipped: Vec<SimpleBezierPath> = clip_path_to_bounds(&square, &bounds);
+
+    assert!(clipped.len() == 1);
+    assert!(clipped[0].start_point() == square.start_point());
+    assert!(clipped[0].points().collect::<Vec<_>>() == square.points().collect::<Vec<_>>());
+}
+
+#[test]
+fn clip_bounds_inside_path() {
+    let square = rectangle(Coord2(-10.0, -10.0), Coord2(20.0, 20.0));
+    let bounds = Bounds::from_min_max(Coord2(0.0, 0.0), Coord2(10.0, 10.0));
+
+    let clipped: Vec<SimpleBezierPath> = clip_path_to_bounds(&square, &bounds);
+
+    assert!(clipped.len() == 1);
+    assert!((path_signed_area(&clipped[0]) - 100.0).abs() < 1e-6);
+}
+
+#[test]
+fn clip_path_outside_bounds() {
+    let square = rectangle(Coord2(12.0, 12.0), Coord2(18.0, 18.0));
+    let bounds = Bounds::from_min_max(Coord2(0.0, 0.0), Coord2(10.0, 10.0));
+
+    let clipped: Vec<SimpleBezierPath> = clip_path_to_bounds(&square, &bounds);
+
+    assert!(clipped.is_empty());
+}
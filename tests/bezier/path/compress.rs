@@ -0,0 +1,105 @@
+use flo_curves::arc::Circle;
+use flo_curves::bezier::path::{
+    compress_path, path_signed_area, BezierPath, BezierPathBuilder, CompressedPath,
+    CompressedSectionKind, SimpleBezierPath,
+};
+use flo_curves::bezier::{distance_to_curve, Curve};
+use flo_curves::{BezierCurve, Coord2};
+
+///
+/// Largest distance from the points on one path to the nearest point on another path
+///
+fn path_distance(from: &SimpleBezierPath, to: &SimpleBezierPath) -> f64 {
+    let from = from.to_curves::<Curve<_>>();
+    let to = to.to_curves::<Curve<_>>();
+
+    from.iter()
+        .flat_map(|curve| (0..=20).map(move |idx| curve.point_at_pos((idx as f64) / 20.0)))
+        .map(|point| {
+            to.iter()
+                .map(|curve| distance_to_curve(curve, &point))
+                .fold(f64::MAX, f64::min)
+        })
+        .fold(0.0, f64::max)
+}
+
+#[test]
+fn compress_stepped_square() {
+    let mut builder = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(0.0, 0.0));
+    for step in 1..=100 {
+        builder = builder.line_to(Coord2((step as f64) * 0.1, 0.0));
+    }
+    let path = builder
+        .line_to(Coord2(10.0, 10.0))
+        .line_to(Coord2(0.0, 10.0))
+        .line_to(Coord2(0.0, 0.0))
+        .build();
+
+    let (compressed, error) = compress_path(&path, 0.01);
+
+    assert!(error <= 0.01);
+    assert!(compressed.sections() == [CompressedSectionKind::Line; 4]);
+    assert!(compressed.values().len() == 10);
+
+    let decompressed = compressed.to_path::<SimpleBezierPath>();
+    assert!((path_signed_area(&decompressed) - 100.0).abs() < 40.0 * 0.01);
+}
+
+#[test]
+fn compress_quadratic_curves() {
+    // A cubic curve that is really a quadratic curve, followed by a line back to the start
+    let path = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(0.0, 0.0))
+        .curve_to(
+            (
+                Coord2(20.0 / 3.0, 40.0 / 3.0),
+                Coord2(40.0 / 3.0, 40.0 / 3.0),
+            ),
+            Coord2(20.0, 0.0),
+        )
+        .line_to(Coord2(0.0, 0.0))
+        .build();
+
+    let (compressed, error) = compress_path(&path, 0.01);
+
+    assert!(error <= 0.01);
+    assert!(
+        compressed.sections()
+            == [
+                CompressedSectionKind::Quadratic,
+                CompressedSectionKind::Line
+            ]
+    );
+}
+
+#[test]
+fn compressed_circle_is_within_error() {
+    let path = Circle::new(Coord2(100.0, 100.0), 50.0).to_path::<SimpleBezierPath>();
+
+    let (compressed, error) = compress_path(&path, 0.1);
+    let decompressed = compressed.to_path::<SimpleBezierPath>();
+
+    assert!(error <= 0.1);
+    assert!(decompressed.1.len() == path.1.len());
+    assert!(path_distance(&path, &decompressed) <= error + 0.001);
+    assert!(path_distance(&decompressed, &path) <= error + 0.001);
+}
+
+#[test]
+fn compressed_path_from_parts() {
+    let path = Circle::new(Coord2(100.0, 100.0), 50.0).to_path::<SimpleBezierPath>();
+    let (compressed, _) = compress_path(&path, 0.1);
+
+    let restored = CompressedPath::<Coord2>::from_parts(
+        compressed.quantum(),
+        compressed.sections().to_vec(),
+        compressed.values().to_vec(),
+    );
+    assert!(restored == Some(compressed.clone()));
+
+    let wrong_length = CompressedPath::<Coord2>::from_parts(
+        compressed.quantum(),
+        compressed.sections().to_vec(),
+        compressed.values()[1..].to_vec(),
+    );
+    assert!(wrong_length.is_none());
+}
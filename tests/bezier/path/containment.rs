@@ -0,0 +1,86 @@
+use flo_curves::arc::Circle;
+use flo_curves::bezier::path::{
+    classify_paths, path_contains_path, BezierPathBuilder, PathRelation, SimpleBezierPath,
+};
+use flo_curves::Coord2;
+
+fn rectangle(min: Coord2, max: Coord2) -> SimpleBezierPath {
+    BezierPathBuilder::<SimpleBezierPath>::start(min)
+        .line_to(Coord2(max.0, min.1))
+        .line_to(max)
+        .line_to(Coord2(min.0, max.1))
+        .line_to(min)
+        .build()
+}
+
+#[test]
+fn separate_circles_are_disjoint() {
+    let circle1 = Circle::new(Coord2(5.0, 5.0), 4.0).to_path::<SimpleBezierPath>();
+    let circle2 = Circle::new(Coord2(20.0, 5.0), 4.0).to_path::<SimpleBezierPath>();
+
+    assert!(classify_paths(&circle1, &circle2) == PathRelation::Disjoint);
+    assert!(!path_contains_path(&circle1, &circle2));
+}
+
+#[test]
+fn disjoint_with_overlapping_bounds() {
+    // The circles' bounding boxes overlap, but the circles do not
+    let circle1 = Circle::new(Coord2(5.0, 5.0), 4.0).to_path::<SimpleBezierPath>();
+    let circle2 = Circle::new(Coord2(12.0, 12.0), 5.0).to_path::<SimpleBezierPath>();
+
+    assert!(classify_paths(&circle1, &circle2) == PathRelation::Disjoint);
+}
+
+#[test]
+fn nested_circles() {
+    let outer = Circle::new(Coord2(5.0, 5.0), 4.0).to_path::<SimpleBezierPath>();
+    let inner = Circle::new(Coord2(5.0, 5.0), 2.0).to_path::<SimpleBezierPath>();
+
+    assert!(classify_paths(&outer, &inner) == PathRelation::AContainsB);
+    assert!(classify_paths(&inner, &outer) == PathRelation::BContainsA);
+    assert!(path_contains_path(&outer, &inner));
+    assert!(!path_contains_path(&inner, &outer));
+}
+
+#[test]
+fn overlapping_circles() {
+    let circle1 = Circle::new(Coord2(5.0, 5.0), 4.0).to_path::<SimpleBezierPath>();
+    let circle2 = Circle::new(Coord2(7.0, 5.0), 4.0).to_path::<SimpleBezierPath>();
+
+    assert!(classify_paths(&circle1, &circle2) == PathRelation::Overlapping);
+    assert!(!path_contains_path(&circle1, &circle2));
+}
+
+#[test]
+fn identical_circles() {
+    let circle1 = Circle::new(Coord2(5.0, 5.0), 4.0).to_path::<SimpleBezierPath>();
+    let circle2 = Circle::new(Coord2(5.0, 5.0), 4.0).to_path::<SimpleBezierPath>();
+
+    assert!(classify_paths(&circle1, &circle2) == PathRelation::Identical);
+    assert!(path_contains_path(&circle1, &circle2));
+}
+
+#[test]
+fn inner_circle_touching_outer() {
+    let outer = Circle::new(Coord2(5.0, 5.0), 4.0).to_path::<SimpleBezierPath>();
+    let inner = Circle::new(Coord2(7.0, 5.0), 2.0).to_path::<SimpleBezierPath>();
+
+    assert!(classify_paths(&outer, &inner) == PathRelation::AContainsB);
+}
+
+#[test]
+fn rectangles_sharing_an_edge() {
+    let left = rectangle(Coord2(0.0, 0.0), Coord2(5.0, 5.0));
+    let right = rectangle(Coord2(5.0, 0.0), Coord2(10.0, 5.0));
+
+    assert!(classify_paths(&left, &right) == PathRelation::Disjoint);
+}
+
+#[test]
+fn rectangle_inside_sharing_an_edge() {
+    let outer = rectangle(Coord2(0.0, 0.0), Coord2(10.0, 10.0));
+    let inner = rectangle(Coord2(0.0, 2.0), Coord2(5.0, 8.0));
+
+    assert!(classify_paths(&outer, &inner) == PathRelation::AContainsB);
+    assert!(path_contains_path(&outer, &inner));
+}
@@ -0,0 +1,108 @@
+use flo_curves::bezier::path::{
+    path_signed_area, paths_from_geojson, paths_from_wkt, paths_to_geojson, paths_to_wkt,
+    BezierPathBuilder, GeoFormatError, SimpleBezierPath,
+};
+use flo_curves::Coord2;
+
+///
+/// A 10x10 square with a 2x2 hole in it
+///
+fn square_with_hole() -> Vec<SimpleBezierPath> {
+    let square = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(0.0, 0.0))
+        .line_to(Coord2(10.0, 0.0))
+        .line_to(Coord2(10.0, 10.0))
+        .line_to(Coord2(0.0, 10.0))
+        .line_to(Coord2(0.0, 0.0))
+        .build();
+    let hole = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(2.0, 2.0))
+        .line_to(Coord2(4.0, 2.0))
+        .line_to(Coord2(4.0, 4.0))
+        .line_to(Coord2(2.0, 4.0))
+        .line_to(Coord2(2.0, 2.0))
+        .build();
+
+    vec![square, hole]
+}
+
+#[test]
+fn write_wkt() {
+    let wkt = paths_to_wkt(&square_with_hole(), 0.01);
+
+    assert!(
+        wkt == "MULTIPOLYGON (((0 0, 10 0, 10 10, 0 10, 0 0), (2 2, 2 4, 4 4, 4 2, 2 2)))",
+        "{}",
+        wkt
+    );
+}
+
+#[test]
+fn read_wkt_multipolygon() {
+    let paths = paths_from_wkt::<SimpleBezierPath>(
+        "MULTIPOLYGON (((0 0, 10 0, 10 10, 0 10, 0 0), (2 2, 4 2, 4 4, 2 4, 2 2)), ((20 0, 25 0, 25 5, 20 0)))",
+    )
+    .unwrap();
+
+    assert!(paths.len() == 3);
+    assert!((path_signed_area(&paths[0]) - 100.0).abs() < 0.0001);
+    assert!((path_signed_area(&paths[1]) + 4.0).abs() < 0.0001);
+    assert!((path_signed_area(&paths[2]) - 12.5).abs() < 0.0001);
+}
+
+#[test]
+fn read_wkt_polygon_with_z() {
+    let paths =
+        paths_from_wkt::<SimpleBezierPath>("polygon z ((0 0 1, 0 10 1, 10 0 1, 0 0 1))").unwrap();
+
+    assert!(paths.len() == 1);
+    assert!((path_signed_area(&paths[0]) - 50.0).abs() < 0.0001);
+
+    assert!(paths_from_wkt::<SimpleBezierPath>("POLYGON EMPTY")
+        .unwrap()
+        .is_empty());
+}
+
+#[test]
+fn read_invalid_wkt() {
+    assert!(matches!(
+        paths_from_wkt::<SimpleBezierPath>("POINT (1 2)"),
+        Err(GeoFormatError::UnsupportedGeometry(_))
+    ));
+    assert!(matches!(
+        paths_from_wkt::<SimpleBezierPath>("POLYGON ((0 0, 1 1"),
+        Err(GeoFormatError::Syntax(_))
+    ));
+}
+
+#[test]
+fn geojson_round_trip() {
+    let geojson = paths_to_geojson(&square_with_hole(), 0.01);
+    let paths = paths_from_geojson::<SimpleBezierPath>(&geojson).unwrap();
+
+    assert!(paths.len() == 2);
+    assert!((path_signed_area(&paths[0]) - 100.0).abs() < 0.0001);
+    assert!((path_signed_area(&paths[1]) + 4.0).abs() < 0.0001);
+}
+
+#[test]
+fn read_geojson_feature_collection() {
+    let geojson = r#"{
+        "type": "FeatureCollection",
+        "features": [
+            { "type": "Feature", "properties": {}, "geometry": {
+                "type": "Polygon",
+                "coordinates": [[[0, 0], [0, 10], [10, 10], [10, 0], [0, 0]]]
+            } },
+            { "type": "Feature", "properties": {}, "geometry": null }
+        ]
+    }"#;
+    let paths = paths_from_geojson::<SimpleBezierPath>(geojson).unwrap();
+
+    // Exterior rings are made to go anticlockwise
+    assert!(paths.len() == 1);
+    assert!((path_signed_area(&paths[0]) - 100.0).abs() < 0.0001);
+
+    assert!(matches!(
+        paths_from_geojson::<SimpleBezierPath>(r#"{ "type": "LineString", "coordinates": [] }"#),
+        Err(GeoFormatError::UnsupportedGeometry(_))
+    ));
+}
@@ -1,9 +1,9 @@
 use flo_curves::arc::Circle;
 use flo_curves::bezier::path::{
     BezierPath, BezierPathBuilder, BezierPathFactory, GraphEdge, GraphPath, GraphPathEdgeKind,
-    GraphRayCollision, PathDirection, PathLabel, SimpleBezierPath,
+    GraphRayCollision, PathDirection, PathLabel, PathWithIsClockwise, SimpleBezierPath,
 };
-use flo_curves::{BezierCurve, BoundingBox, Coord2, Coordinate, Coordinate2D, Coordinate3D, Line};
+use flo_curves::{BezierCurve, BoundingBox, Bounds, Coord2, Coordinate, Coordinate2D, Line};
 
 use std::f64;
 
@@ -1964,3 +1964,609 @@ pub fn ray_cast_identical_rectangles() {
     // when the edges precisely overlap)
     assert!(edge1.label() != edge3.label());
 }
+
+#[test]
+fn faces_for_single_square() {
+    let square = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(1.0, 1.0))
+        .line_to(Coord2(5.0, 1.0))
+        .line_to(Coord2(5.0, 5.0))
+        .line_to(Coord2(1.0, 5.0))
+        .line_to(Coord2(1.0, 1.0))
+        .build();
+    let graph_path = GraphPath::from_path(&square, 1);
+
+    let faces = graph_path.faces::<SimpleBezierPath>();
+
+    assert!(faces.len() == 1);
+    assert!(faces[0].0 .1.len() == 4);
+    assert!(faces[0].1 == vec![1, 1, 1, 1]);
+}
+
+#[test]
+fn faces_for_overlapping_squares() {
+    let square1 = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(1.0, 1.0))
+        .line_to(Coord2(5.0, 1.0))
+        .line_to(Coord2(5.0, 5.0))
+        .line_to(Coord2(1.0, 5.0))
+        .line_to(Coord2(1.0, 1.0))
+        .build();
+    let square2 = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(3.0, 3.0))
+        .line_to(Coord2(7.0, 3.0))
+        .line_to(Coord2(7.0, 7.0))
+        .line_to(Coord2(3.0, 7.0))
+        .line_to(Coord2(3.0, 3.0))
+        .build();
+
+    let graph_path = GraphPath::from_path(&square1, 1);
+    let graph_path = graph_path.collide(GraphPath::from_path(&square2, 2), 0.01);
+
+    let faces = graph_path.faces::<SimpleBezierPath>();
+    println!("{:?}", faces);
+
+    // Two L-shapes and the square where they overlap
+    assert!(faces.len() == 3);
+
+    let overlap = faces
+        .iter()
+        .find(|(face, _labels)| {
+            let bounds = face.bounding_box::<(Coord2, Coord2)>();
+            bounds.min().distance_to(&Coord2(3.0, 3.0)) < 0.01
+                && bounds.max().distance_to(&Coord2(5.0, 5.0)) < 0.01
+        })
+        .expect("Overlapping region");
+
+    assert!(overlap.1.contains(&1));
+    assert!(overlap.1.contains(&2));
+
+    // Every section in a face has a label
+    assert!(faces
+        .iter()
+        .all(|(face, labels)| face.1.len() == labels.len()));
+}
+
+#[test]
+fn faces_for_circle_are_clockwise() {
+    let circle = Circle::new(Coord2(5.0, 5.0), 4.0).to_path::<SimpleBezierPath>();
+    let graph_path = GraphPath::from_path(&circle, ());
+
+    let faces = graph_path.faces::<SimpleBezierPath>();
+
+    assert!(faces.len() == 1);
+    assert!(faces[0].0.is_clockwise());
+}
+
+#[test]
+fn faces_for_overlapping_squares_are_clockwise() {
+    let square1 = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(1.0, 1.0))
+        .line_to(Coord2(5.0, 1.0))
+        .line_to(Coord2(5.0, 5.0))
+        .line_to(Coord2(1.0, 5.0))
+        .line_to(Coord2(1.0, 1.0))
+        .build();
+    let square2 = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(3.0, 7.0))
+        .line_to(Coord2(7.0, 7.0))
+        .line_to(Coord2(7.0, 3.0))
+        .line_to(Coord2(3.0, 3.0))
+        .line_to(Coord2(3.0, 7.0))
+        .build();
+
+    let graph_path = GraphPath::from_path(&square1, 1);
+    let graph_path = graph_path.collide(GraphPath::from_path(&square2, 2), 0.01);
+
+    let faces = graph_path.faces::<SimpleBezierPath>();
+
+    // The inputs go in opposite directions, but the faces are all clockwise
+    assert!(faces.len() == 3);
+    assert!(faces.iter().all(|(face, _labels)| face.is_clockwise()));
+}
+
+#[test]
+fn debug_string_round_trip() {
+    let circle1 = Circle::new(Coord2(5.0, 5.0), 4.0).to_path::<SimpleBezierPath>();
+    let circle2 = Circle::new(Coord2(9.0, 5.0), 4.0).to_path::<SimpleBezierPath>();
+
+    let mut graph_path =
+        GraphPath::from_path(&circle1, PathLabel(0, PathDirection::from(&circle1)));
+    graph_path = graph_path.collide(
+        GraphPath::from_path(&circle2, PathLabel(1, PathDirection::from(&circle2))),
+        0.01,
+    );
+    graph_path.set_exterior_by_adding();
+
+    let debug_string = graph_path.to_debug_string();
+    println!("{}", debug_string);
+
+    let read_path = GraphPath::<Coord2, PathLabel>::from_debug_string(&debug_string).unwrap();
+
+    assert!(read_path.num_points() == graph_path.num_points());
+    assert!(read_path.to_debug_string() == debug_string);
+
+    let original_exterior = graph_path.exterior_paths::<SimpleBezierPath>();
+    let read_exterior = read_path.exterior_paths::<SimpleBezierPath>();
+    assert!(original_exterior == read_exterior);
+}
+
+#[test]
+fn debug_string_with_no_labels() {
+    let circle = Circle::new(Coord2(5.0, 5.0), 4.0).to_path::<SimpleBezierPath>();
+    let graph_path = GraphPath::from_path(&circle, ());
+
+    let read_path =
+        GraphPath::<Coord2, ()>::from_debug_string(&graph_path.to_debug_string()).unwrap();
+
+    assert!(read_path.to_debug_string() == graph_path.to_debug_string());
+    assert!(read_path.edges_for_point(0).count() == 1);
+}
+
+#[test]
+fn debug_string_reports_bad_edges() {
+    let debug_string = "graph_path v1\nnext_path_index 1\npoint 0 1.0 2.0\n  edge 3 following 0 kind Exterior cp1 1.0 2.0 cp2 1.0 2.0 label 7\n";
+
+    let read_path = GraphPath::<Coord2, u32>::from_debug_string(debug_string);
+
+    assert!(read_path.is_err());
+}
+
+#[test]
+fn svg_debug_string_shows_edges_and_points() {
+    let circle1 = Circle::new(Coord2(5.0, 5.0), 4.0).to_path::<SimpleBezierPath>();
+    let circle2 = Circle::new(Coord2(9.0, 5.0), 4.0).to_path::<SimpleBezierPath>();
+    let mut graph_path = labelled_circle_collision(&circle1, &circle2);
+    graph_path.set_exterior_by_adding();
+
+    let svg = graph_path.to_svg_debug_string();
+    println!("{}", svg);
+
+    assert!(svg.starts_with("<g "));
+    assert!(svg.trim_end().ends_with("</g>"));
+
+    // One path element per edge, one dot per point
+    assert!(svg.matches("<path ").count() == graph_path.all_edges().count());
+    assert!(svg.matches("<circle ").count() == graph_path.num_points());
+
+    // Both kinds of edge are present after adding, and the two labels are drawn differently
+    assert!(svg.contains("stroke=\"blue\""));
+    assert!(svg.contains("stroke=\"green\""));
+    assert!(svg.contains("stroke-dasharray=\"none\""));
+    assert!(svg.contains("stroke-dasharray=\"8 4\""));
+    assert!(svg.contains("label \"0 Clockwise\""));
+    assert!(!svg.contains("orphaned"));
+}
+
+#[test]
+fn svg_debug_string_shows_orphaned_points() {
+    let debug_string = "graph_path v1\nnext_path_index 1\npoint 0 1.0 2.0\n  edge 1 following 0 kind Uncategorised cp1 1.0 2.0 cp2 3.0 4.0 label 7\npoint 1 3.0 4.0\n  edge 0 following 0 kind Uncategorised cp1 3.0 4.0 cp2 1.0 2.0 label 7\npoint 2 5.0 5.0\n";
+    let graph_path = GraphPath::<Coord2, u32>::from_debug_string(debug_string).unwrap();
+
+    let svg = graph_path.to_svg_debug_string();
+
+    assert!(svg.matches("stroke=\"magenta\"").count() == 1);
+    assert!(svg.contains("Point 2 (orphaned): Coord2(5, 5)"));
+    assert!(svg.matches("stroke=\"yellow\"").count() == 2);
+}
+
+#[test]
+fn svg_debug_string_for_empty_graph() {
+    let graph_path = GraphPath::<Coord2, ()>::new();
+
+    let svg = graph_path.to_svg_debug_string();
+
+    assert!(svg == "<g class=\"graph-path-debug\">\n</g>\n");
+}
+
+fn labelled_circle_collision(
+    circle1: &SimpleBezierPath,
+    circle2: &SimpleBezierPath,
+) -> GraphPath<Coord2, PathLabel> {
+    let graph_path = GraphPath::from_path(circle1, PathLabel(0, PathDirection::from(circle1)));
+
+    graph_path.collide(
+        GraphPath::from_path(circle2, PathLabel(1, PathDirection::from(circle2))),
+        0.01,
+    )
+}
+
+#[test]
+fn remove_edges_with_label_restores_original_path() {
+    let circle1 = Circle::new(Coord2(5.0, 5.0), 4.0).to_path::<SimpleBezierPath>();
+    let circle2 = Circle::new(Coord2(9.0, 5.0), 4.0).to_path::<SimpleBezierPath>();
+    let original = GraphPath::from_path(&circle1, PathLabel(0, PathDirection::from(&circle1)));
+
+    let mut graph_path = labelled_circle_collision(&circle1, &circle2);
+    assert!(graph_path.num_points() > original.num_points() + 4);
+
+    graph_path.remove_edges_with_label(|PathLabel(path_number, _)| *path_number == 1);
+
+    assert!(graph_path.num_points() == original.num_points());
+    assert!(graph_path
+        .all_edges()
+        .all(|edge| edge.label().0 == 0 && edge.kind() == GraphPathEdgeKind::Uncategorised));
+
+    // The joined edges should be the same as the original ones
+    for point_idx in 0..original.num_points() {
+        let original_edge = original.edges_for_point(point_idx).next().unwrap();
+        let edge = graph_path.edges_for_point(point_idx).next().unwrap();
+        let (original_cp1, original_cp2) = original_edge.control_points();
+        let (cp1, cp2) = edge.control_points();
+
+        assert!(edge
+            .start_point()
+            .is_near_to(&original_edge.start_point(), 0.01));
+        assert!(cp1.is_near_to(&original_cp1, 0.05));
+        assert!(cp2.is_near_to(&original_cp2, 0.05));
+        assert!(edge
+            .end_point()
+            .is_near_to(&original_edge.end_point(), 0.01));
+    }
+}
+
+#[test]
+fn replace_operand_by_removing_edges() {
+    let circle1 = Circle::new(Coord2(5.0, 5.0), 4.0).to_path::<SimpleBezierPath>();
+    let circle2 = Circle::new(Coord2(9.0, 5.0), 4.0).to_path::<SimpleBezierPath>();
+    let circle3 = Circle::new(Coord2(5.0, 9.0), 4.0).to_path::<SimpleBezierPath>();
+
+    // Replace circle 2 with circle 3
+    let mut graph_path = labelled_circle_collision(&circle1, &circle2);
+    graph_path.remove_edges_with_label(|PathLabel(path_number, _)| *path_number == 1);
+    graph_path = graph_path.collide(
+        GraphPath::from_path(&circle3, PathLabel(1, PathDirection::from(&circle3))),
+        0.01,
+    );
+    graph_path.set_exterior_by_adding();
+    graph_path.heal_exterior_gaps();
+
+    // Should be the same as building the graph from circle 1 and circle 3
+    let mut expected = labelled_circle_collision(&circle1, &circle3);
+    expected.set_exterior_by_adding();
+    expected.heal_exterior_gaps();
+
+    let result = graph_path.exterior_paths::<SimpleBezierPath>();
+    let expected = expected.exterior_paths::<SimpleBezierPath>();
+
+    assert!(result.len() == 1);
+    assert!(expected.len() == 1);
+    assert!(result[0].1.len() == expected[0].1.len());
+
+    let bounds = result[0].bounding_box::<Bounds<Coord2>>();
+    let expected_bounds = expected[0].bounding_box::<Bounds<Coord2>>();
+    assert!(bounds.min().is_near_to(&expected_bounds.min(), 0.01));
+    assert!(bounds.max().is_near_to(&expected_bounds.max(), 0.01));
+}
+
+#[test]
+fn repeatedly_replacing_operand_does_not_add_points() {
+    let circle1 = Circle::new(Coord2(5.0, 5.0), 4.0).to_path::<SimpleBezierPath>();
+    let mut graph_path =
+        GraphPath::from_path(&circle1, PathLabel(0, PathDirection::from(&circle1)));
+    let num_points = graph_path.num_points();
+
+    for frame in 0..20 {
+        let moving =
+            Circle::new(Coord2(7.0 + (frame as f64) * 0.1, 5.0), 3.0).to_path::<SimpleBezierPath>();
+
+        graph_path.remove_edges_with_label(|PathLabel(path_number, _)| *path_number == 1);
+        assert!(graph_path.num_points() == num_points);
+
+        graph_path = graph_path.collide(
+            GraphPath::from_path(&moving, PathLabel(1, PathDirection::from(&moving))),
+            0.01,
+        );
+    }
+}
+
+#[test]
+fn collide_crossing_straight_edges() {
+    // Two rectangles made of straight lines
+    let rectangle1 = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(1.0, 1.0))
+        .line_to(Coord2(5.0, 1.0))
+        .line_to(Coord2(5.0, 5.0))
+        .line_to(Coord2(1.0, 5.0))
+        .line_to(Coord2(1.0, 1.0))
+        .build();
+    let rectangle2 = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(3.0, 0.3))
+        .line_to(Coord2(7.0, 0.3))
+        .line_to(Coord2(7.0, 3.7))
+        .line_to(Coord2(3.0, 3.7))
+        .line_to(Coord2(3.0, 0.3))
+        .build();
+
+    let graph_path = GraphPath::from_path(&rectangle1, ());
+    let graph_path = graph_path.collide(GraphPath::from_path(&rectangle2, ()), 0.01);
+
+    // Two new points where the edges cross, which should be on the lines
+    assert!(graph_path.num_points() == 10);
+
+    let crossings = (0..graph_path.num_points())
+        .map(|point_idx| graph_path.point_position(point_idx))
+        .filter(|point| {
+            point.is_near_to(&Coord2(3.0, 1.0), 1e-9) || point.is_near_to(&Coord2(5.0, 3.7), 1e-9)
+        })
+        .count();
+    assert!(crossings == 2);
+
+    // The edges should be split at the crossing points
+    for point_idx in 0..graph_path.num_points() {
+        for edge in graph_path.edges_for_point(point_idx) {
+            let (cp1, cp2) = edge.control_points();
+            let start_point = edge.start_point();
+            let end_point = edge.end_point();
+
+            let on_line = |point: Coord2| {
+                (point.x() - start_point.x()).abs() < 1e-9
+                    && (point.x() - end_point.x()).abs() < 1e-9
+                    || (point.y() - start_point.y()).abs() < 1e-9
+                        && (point.y() - end_point.y()).abs() < 1e-9
+            };
+
+            assert!(on_line(cp1));
+            assert!(on_line(cp2));
+        }
+    }
+}
+
+#[test]
+fn collide_straight_edge_ending_on_another() {
+    // A triangle with a point that just touches the edge of a rectangle
+    let rectangle = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(1.0, 1.0))
+        .line_to(Coord2(5.0, 1.0))
+        .line_to(Coord2(5.0, 5.0))
+        .line_to(Coord2(1.0, 5.0))
+        .line_to(Coord2(1.0, 1.0))
+        .build();
+    let triangle = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(3.0, 5.0))
+        .line_to(Coord2(4.0, 8.0))
+        .line_to(Coord2(2.0, 8.0))
+        .line_to(Coord2(3.0, 5.0))
+        .build();
+
+    let graph_path = GraphPath::from_path(&rectangle, ());
+    let graph_path = graph_path.collide(GraphPath::from_path(&triangle, ()), 0.01);
+
+    // The top edge of the rectangle should be split where the triangle touches it
+    let touching_point = (0..graph_path.num_points())
+        .find(|point_idx| {
+            graph_path
+                .point_position(*point_idx)
+                .is_near_to(&Coord2(3.0, 5.0), 0.001)
+        })
+        .unwrap();
+
+    assert!(graph_path.edges_for_point(touching_point).count() == 2);
+}
+
+#[test]
+fn reuse_graph_path_for_arithmetic() {
+    let circle1 = Circle::new(Coord2(5.0, 5.0), 4.0).to_path::<SimpleBezierPath>();
+    let mut graph_path = GraphPath::new();
+
+    for frame in 0..10 {
+        let circle2 =
+            Circle::new(Coord2(7.0 + (frame as f64) * 0.2, 5.0), 4.0).to_path::<SimpleBezierPath>();
+
+        graph_path.clear();
+        graph_path.add_merged_paths(vec![(
+            &circle1,
+            PathLabel(0, PathDirection::from(&circle1)),
+        )]);
+        graph_path.collide_paths(
+            vec![(&circle2, PathLabel(1, PathDirection::from(&circle2)))],
+            0.01,
+        );
+        graph_path.set_exterior_by_adding();
+        graph_path.heal_exterior_gaps();
+
+        // Should be the same as building the graph from scratch
+        let mut expected = labelled_circle_collision(&circle1, &circle2);
+        expected.set_exterior_by_adding();
+        expected.heal_exterior_gaps();
+
+        assert!(graph_path.num_points() == expected.num_points());
+        assert!(
+            graph_path.exterior_paths::<SimpleBezierPath>()
+                == expected.exterior_paths::<SimpleBezierPath>()
+        );
+    }
+}
+
+#[test]
+fn add_merged_paths_matches_from_merged_paths() {
+    let circle1 = Circle::new(Coord2(5.0, 5.0), 4.0).to_path::<SimpleBezierPath>();
+    let circle2 = Circle::new(Coord2(9.0, 5.0), 4.0).to_path::<SimpleBezierPath>();
+    let paths = vec![(&circle1, 1), (&circle2, 2)];
+
+    let mut graph_path = GraphPath::new();
+    graph_path.add_merged_paths(paths.clone());
+    let expected = GraphPath::from_merged_paths(paths);
+
+    assert!(graph_path.to_debug_string() == expected.to_debug_string());
+}
+
+///
+/// Creates a graph from a set of paths with all of the edges marked as exterior edges
+///
+fn exterior_graph(paths: &[SimpleBezierPath]) -> GraphPath<Coord2, PathLabel> {
+    let mut graph_path = GraphPath::from_merged_paths(
+        paths
+            .iter()
+            .enumerate()
+            .map(|(path_idx, path)| (path, PathLabel(path_idx as u32, PathDirection::from(path)))),
+    );
+
+    for edge in graph_path.all_edge_refs().collect::<Vec<_>>() {
+        graph_path.set_edge_kind(edge, GraphPathEdgeKind::Exterior);
+    }
+
+    graph_path
+}
+
+#[test]
+fn reverse_path_reverses_whole_loop() {
+    use flo_curves::bezier::path::path_signed_area;
+
+    let circle = Circle::new(Coord2(5.0, 5.0), 4.0).to_path::<SimpleBezierPath>();
+    let mut graph_path = exterior_graph(std::slice::from_ref(&circle));
+
+    let edge = graph_path.all_edge_refs().next().unwrap();
+    graph_path.reverse_path(edge);
+
+    // Every edge should still be followed by an edge that starts where it ends
+    for edge in graph_path.all_edges() {
+        let following = graph_path.get_edge(graph_path.following_edge_ref(edge.clone().into()));
+        assert!(following.start_point_index() == edge.end_point_index());
+    }
+
+    let reversed = graph_path.exterior_paths::<SimpleBezierPath>();
+    assert!(reversed.len() == 1);
+    assert!((path_signed_area(&reversed[0]) + path_signed_area(&circle)).abs() < 1e-6);
+}
+
+#[test]
+fn reverse_single_edge() {
+    let path = (
+        Coord2(10.0, 11.0),
+        vec![
+            (Coord2(15.0, 16.0), Coord2(17.0, 18.0), Coord2(19.0, 20.0)),
+            (Coord2(21.0, 22.0), Coord2(23.0, 24.0), Coord2(10.0, 11.0)),
+        ],
+    );
+    let mut graph_path = GraphPath::from_path(&path, ());
+
+    let edge = graph_path.edge_refs_for_point(0).next().unwrap();
+    let reversed = graph_path.reverse_edge(edge);
+    let reversed = graph_path.get_edge(reversed);
+
+    assert!(reversed.start_point() == Coord2(19.0, 20.0));
+    assert!(reversed.control_points() == (Coord2(17.0, 18.0), Coord2(15.0, 16.0)));
+    assert!(reversed.end_point() == Coord2(10.0, 11.0));
+
+    assert!(graph_path.edges_for_point(0).count() == 0);
+    assert!(graph_path.reverse_edges_for_point(0).count() == 2);
+}
+
+#[test]
+fn exterior_paths_with_consistent_direction() {
+    use flo_curves::bezier::path::path_signed_area;
+
+    // Outline and hole going in the same direction
+    let outer = Circle::new(Coord2(10.0, 10.0), 8.0).to_path::<SimpleBezierPath>();
+    let hole = Circle::new(Coord2(10.0, 10.0), 4.0).to_path::<SimpleBezierPath>();
+    let graph_path = exterior_graph(&[outer, hole]);
+
+    let clockwise =
+        graph_path.exterior_paths_with_direction::<SimpleBezierPath>(PathDirection::Clockwise);
+    assert!(clockwise.len() == 2);
+
+    let (outline, hole) =
+        if path_signed_area(&clockwise[0]).abs() > path_signed_area(&clockwise[1]).abs() {
+            (&clockwise[0], &clockwise[1])
+        } else {
+            (&clockwise[1], &clockwise[0])
+        };
+    assert!(path_signed_area(outline) < 0.0);
+    assert!(path_signed_area(hole) > 0.0);
+
+    let anticlockwise =
+        graph_path.exterior_paths_with_direction::<SimpleBezierPath>(PathDirection::Anticlockwise);
+    assert!(anticlockwise
+        .iter()
+        .zip(clockwise.iter())
+        .all(|(a, b)| (path_signed_area(a) + path_signed_area(b)).abs() < 1e-6));
+}
+
+#[test]
+fn edges_with_label_after_collision() {
+    let circle1 = Circle::new(Coord2(5.0, 5.0), 4.0).to_path::<SimpleBezierPath>();
+    let circle2 = Circle::new(Coord2(8.0, 5.0), 4.0).to_path::<SimpleBezierPath>();
+
+    let label1 = PathLabel(0, PathDirection::from(&circle1));
+    let label2 = PathLabel(1, PathDirection::from(&circle2));
+    let graph_path = GraphPath::from_path(&circle1, label1)
+        .collide(GraphPath::from_path(&circle2, label2), 0.01);
+
+    let num_edges = graph_path.all_edges().count();
+    let edges1 = graph_path.edges_with_label(label1).collect::<Vec<_>>();
+    let edges2 = graph_path.edges_with_label(label2).collect::<Vec<_>>();
+
+    // The circles are split into more than their original 4 sections where they cross
+    assert!(edges1.len() > 4);
+    assert!(edges2.len() > 4);
+    assert!(edges1.len() + edges2.len() == num_edges);
+    assert!(edges1.iter().all(|edge| edge.label() == label1));
+    assert!(edges2.iter().all(|edge| edge.label() == label2));
+}
+
+#[test]
+fn exterior_paths_with_labels_after_adding() {
+    let circle1 = Circle::new(Coord2(5.0, 5.0), 4.0).to_path::<SimpleBezierPath>();
+    let circle2 = Circle::new(Coord2(8.0, 5.0), 4.0).to_path::<SimpleBezierPath>();
+
+    let label1 = PathLabel(0, PathDirection::from(&circle1));
+    let label2 = PathLabel(1, PathDirection::from(&circle2));
+    let mut graph_path = GraphPath::from_path(&circle1, label1)
+        .collide(GraphPath::from_path(&circle2, label2), 0.01);
+    graph_path.set_exterior_by_adding();
+
+    let paths = graph_path.exterior_paths_with_labels::<SimpleBezierPath>();
+    assert!(paths.len() == 1);
+
+    let (path, labels) = &paths[0];
+    let sections = path.points().collect::<Vec<_>>();
+    assert!(sections.len() == labels.len());
+
+    // Sections to the left of the crossing points come from the first circle, and sections to the right come from the second
+    let mut start_point = path.start_point();
+    for ((_, _, end_point), label) in sections.iter().zip(labels.iter()) {
+        let mid_x = (start_point.x() + end_point.x()) * 0.5;
+
+        if mid_x < 6.4 {
+            assert!(*label == label1);
+        } else if mid_x > 6.6 {
+            assert!(*label == label2);
+        }
+
+        start_point = *end_point;
+    }
+
+    assert!(labels.contains(&label1));
+    assert!(labels.contains(&label2));
+
+    // Should match the paths without the labels
+    let plain_paths = graph_path.exterior_paths::<SimpleBezierPath>();
+    assert!(plain_paths.len() == 1);
+    assert!(plain_paths[0] == *path);
+}
+
+#[test]
+fn section_labels_survive_collision_and_map_labels() {
+    let rectangle1 = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(1.0, 1.0))
+        .line_to(Coord2(5.0, 1.0))
+        .line_to(Coord2(5.0, 5.0))
+        .line_to(Coord2(1.0, 5.0))
+        .line_to(Coord2(1.0, 1.0))
+        .build();
+    let rectangle2 = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(3.0, 3.0))
+        .line_to(Coord2(7.0, 3.0))
+        .line_to(Coord2(7.0, 7.0))
+        .line_to(Coord2(3.0, 7.0))
+        .line_to(Coord2(3.0, 3.0))
+        .build();
+
+    let graph1 = GraphPath::from_path_with_section_labels(&rectangle1, |section| (0, section));
+    let graph2 = GraphPath::from_path_with_section_labels(&rectangle2, |section| (1, section));
+    let collided = graph1.collide(graph2, 0.01);
+
+    // The right-hand side of the first rectangle is split where the second rectangle crosses it
+    assert!(collided.edges_with_label((0, 1)).count() == 2);
+    assert!(collided
+        .edges_with_label((0, 1))
+        .all(|edge| edge.start_point().x() == 5.0 && edge.end_point().x() == 5.0));
+
+    // Mapping the labels leaves the edges where they are
+    let mapped = collided.map_labels(|(path, _section)| path);
+    assert!(mapped.num_points() == collided.num_points());
+    for edge in collided.all_edge_refs() {
+        assert!(mapped.get_edge(edge).start_point() == collided.get_edge(edge).start_point());
+        assert!(mapped.edge_label(edge) == collided.edge_label(edge).0);
+    }
+}
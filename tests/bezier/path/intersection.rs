@@ -1,6 +1,7 @@
 use flo_curves::arc::Circle;
 use flo_curves::bezier::path::{
-    path_intersects_line, path_intersects_path, BezierPath, SimpleBezierPath,
+    path_intersects_line, path_intersects_path, path_self_intersections, BezierPath,
+    BezierPathBuilder, SimpleBezierPath,
 };
 use flo_curves::bezier::{BezierCurve, BoundingBox, Coord2, Coordinate, Curve};
 
@@ -141,3 +142,54 @@ fn circle_intersects_circle() {
 
     assert!(intersections.len() == 2);
 }
+
+#[test]
+fn circle_has_no_self_intersections() {
+    let circle: SimpleBezierPath = Circle::new(Coord2(5.0, 5.0), 4.0).to_path();
+
+    let intersections = path_self_intersections(&circle, 0.01);
+
+    assert!(intersections.is_empty());
+}
+
+#[test]
+fn figure_of_eight_intersects_itself_once() {
+    // Bow-tie shape, where the two diagonals cross at (5, 5)
+    let bow_tie = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(0.0, 0.0))
+        .line_to(Coord2(10.0, 10.0))
+        .line_to(Coord2(10.0, 0.0))
+        .line_to(Coord2(0.0, 10.0))
+        .line_to(Coord2(0.0, 0.0))
+        .build();
+
+    let intersections = path_self_intersections(&bow_tie, 0.01);
+    println!("{:?}", intersections);
+
+    assert!(intersections.len() == 1);
+
+    let curves = bow_tie.to_curves::<Curve<_>>();
+    let ((idx1, t1), (idx2, t2)) = intersections[0];
+
+    assert!(idx1 == 0);
+    assert!(idx2 == 2);
+    assert!(curves[idx1].point_at_pos(t1).distance_to(&Coord2(5.0, 5.0)) < 0.01);
+    assert!(curves[idx2].point_at_pos(t2).distance_to(&Coord2(5.0, 5.0)) < 0.01);
+}
+
+#[test]
+fn loop_within_a_segment_is_a_self_intersection() {
+    let looped = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(148.0, 151.0))
+        .curve_to(
+            (Coord2(292.0, 199.0), Coord2(73.0, 221.0)),
+            Coord2(249.0, 136.0),
+        )
+        .line_to(Coord2(148.0, 151.0))
+        .build();
+
+    let intersections = path_self_intersections(&looped, 0.01);
+    println!("{:?}", intersections);
+
+    assert!(intersections
+        .iter()
+        .any(|((idx1, _), (idx2, _))| *idx1 == 0 && *idx2 == 0));
+}
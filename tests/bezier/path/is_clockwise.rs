@@ -1,4 +1,6 @@
-use flo_curves::bezier::path::{BezierPathBuilder, PathWithIsClockwise, SimpleBezierPath};
+use flo_curves::bezier::path::{
+    BezierPath, BezierPathBuilder, PathWithIsClockwise, SimpleBezierPath,
+};
 use flo_curves::Coord2;
 
 #[test]
@@ -24,3 +26,36 @@ pub fn rectangle_is_anticlockwise() {
 
     assert!(!rectangle1.is_clockwise());
 }
+
+#[test]
+pub fn ensure_clockwise_reverses_anticlockwise_path() {
+    let anticlockwise = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(1.0, 1.0))
+        .line_to(Coord2(5.0, 1.0))
+        .line_to(Coord2(5.0, 5.0))
+        .line_to(Coord2(1.0, 5.0))
+        .line_to(Coord2(1.0, 1.0))
+        .build();
+    assert!(!anticlockwise.is_clockwise());
+
+    let clockwise = anticlockwise.ensure_clockwise::<SimpleBezierPath>();
+    assert!(clockwise.is_clockwise());
+    assert!(clockwise == anticlockwise.reversed::<SimpleBezierPath>());
+
+    // Paths that are already clockwise are left alone
+    assert!(clockwise.ensure_clockwise::<SimpleBezierPath>() == clockwise);
+}
+
+#[test]
+pub fn ensure_anticlockwise_reverses_clockwise_path() {
+    let clockwise = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(1.0, 1.0))
+        .line_to(Coord2(1.0, 5.0))
+        .line_to(Coord2(5.0, 5.0))
+        .line_to(Coord2(5.0, 1.0))
+        .line_to(Coord2(1.0, 1.0))
+        .build();
+    assert!(clockwise.is_clockwise());
+
+    let anticlockwise = clockwise.ensure_anticlockwise::<SimpleBezierPath>();
+    assert!(!anticlockwise.is_clockwise());
+    assert!(anticlockwise.ensure_anticlockwise::<SimpleBezierPath>() == anticlockwise);
+}
@@ -0,0 +1,94 @@
+use flo_curves::arc::Circle;
+use flo_curves::bezier::path::{
+    path_joins, BezierPathBuilder, BezierPathFactory, PathJoinKind, SimpleBezierPath,
+};
+use flo_curves::{Coord2, Coordinate};
+
+use std::f64;
+
+#[test]
+fn square_has_four_corners() {
+    let square = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(0.0, 0.0))
+        .line_to(Coord2(10.0, 0.0))
+        .line_to(Coord2(10.0, 10.0))
+        .line_to(Coord2(0.0, 10.0))
+        .line_to(Coord2(0.0, 0.0))
+        .build();
+
+    let joins = path_joins(&square, 0.01);
+
+    assert!(joins.len() == 4);
+    for (idx, join) in joins.iter().enumerate() {
+        assert!(join.section == idx);
+        assert!(join.kind == PathJoinKind::Corner);
+        assert!((join.angle - f64::consts::FRAC_PI_2).abs() < 1e-9);
+    }
+
+    assert!(joins[0].position == Coord2(10.0, 0.0));
+    assert!(joins[3].position == Coord2(0.0, 0.0));
+}
+
+#[test]
+fn clockwise_turns_are_negative() {
+    let path = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(0.0, 0.0))
+        .line_to(Coord2(10.0, 0.0))
+        .line_to(Coord2(10.0, -10.0))
+        .build();
+
+    let joins = path_joins(&path, 0.01);
+
+    // Open path, so only the one join
+    assert!(joins.len() == 1);
+    assert!(joins[0].kind == PathJoinKind::Corner);
+    assert!((joins[0].angle + f64::consts::FRAC_PI_2).abs() < 1e-9);
+}
+
+#[test]
+fn circle_is_smooth() {
+    let circle = Circle::new(Coord2(5.0, 5.0), 4.0).to_path::<SimpleBezierPath>();
+    let joins = path_joins(&circle, 0.01);
+
+    assert!(joins.len() == 4);
+    for join in joins {
+        assert!(join.kind == PathJoinKind::Smooth);
+        assert!(join.angle.abs() < 1e-6);
+    }
+}
+
+#[test]
+fn cusp_where_path_doubles_back() {
+    let path = SimpleBezierPath::from_points(
+        Coord2(0.0, 0.0),
+        vec![
+            (Coord2(3.0, 0.0), Coord2(7.0, 0.0), Coord2(10.0, 0.0)),
+            (Coord2(7.0, 0.1), Coord2(3.0, 2.0), Coord2(0.0, 5.0)),
+        ],
+    );
+
+    let joins = path_joins(&path, 0.1);
+
+    assert!(joins.len() == 1);
+    assert!(joins[0].kind == PathJoinKind::Cusp);
+    assert!(joins[0].angle > 0.0);
+}
+
+#[test]
+fn point_sections_are_skipped() {
+    let path = SimpleBezierPath::from_points(
+        Coord2(0.0, 0.0),
+        vec![
+            (Coord2(3.0, 0.0), Coord2(7.0, 0.0), Coord2(10.0, 0.0)),
+            (Coord2(10.0, 0.0), Coord2(10.0, 0.0), Coord2(10.0, 0.0)),
+            (Coord2(10.0, 0.0), Coord2(15.0, 0.0), Coord2(20.0, 5.0)),
+        ],
+    );
+
+    let joins = path_joins(&path, 0.01);
+
+    // The join is between the first and last sections, and uses the direction of the control point when the handle is zero
+    // length
+    assert!(joins.len() == 1);
+    assert!(joins[0].section == 0);
+    assert!(joins[0].kind == PathJoinKind::Smooth);
+    assert!(joins[0].position.is_near_to(&Coord2(10.0, 0.0), 1e-9));
+}
@@ -0,0 +1,70 @@
+use flo_curves::bezier::path::{
+    path_markers, BezierPath, BezierPathBuilder, Marker, MarkerShape, SimpleBezierPath,
+};
+use flo_curves::{BoundingBox, Bounds, Coord2, Coordinate, Coordinate2D};
+
+fn corner_path() -> SimpleBezierPath {
+    BezierPathBuilder::<SimpleBezierPath>::start(Coord2(0.0, 0.0))
+        .line_to(Coord2(100.0, 0.0))
+        .line_to(Coord2(100.0, 100.0))
+        .build()
+}
+
+#[test]
+fn arrowheads_point_away_from_path() {
+    let marker = Marker::new(MarkerShape::Triangle {
+        length: 10.0,
+        width: 6.0,
+    });
+    let markers = path_markers::<_, SimpleBezierPath>(&corner_path(), &marker);
+
+    assert!(markers.len() == 2);
+
+    // The start marker has its tip at the start point and points backwards along the path
+    let start_points = markers[0].points().map(|(_, _, p)| p).collect::<Vec<_>>();
+    assert!(markers[0].start_point().distance_to(&Coord2(0.0, 0.0)) < 1e-6);
+    assert!(start_points[0].distance_to(&Coord2(10.0, -3.0)) < 1e-3);
+    assert!(start_points[1].distance_to(&Coord2(10.0, 3.0)) < 1e-3);
+
+    // The end marker points upwards
+    let end_bounds = markers[1].bounding_box::<Bounds<_>>();
+    assert!(markers[1].start_point().distance_to(&Coord2(100.0, 100.0)) < 1e-6);
+    assert!(end_bounds.min().distance_to(&Coord2(97.0, 90.0)) < 1e-3);
+    assert!(end_bounds.max().distance_to(&Coord2(103.0, 100.0)) < 1e-3);
+}
+
+#[test]
+fn vertex_markers_follow_average_direction() {
+    let marker = Marker::new(MarkerShape::Custom((
+        Coord2(0.0, 0.0),
+        vec![(Coord2(3.0, 0.0), Coord2(7.0, 0.0), Coord2(10.0, 0.0))],
+    )))
+    .with_start(false)
+    .with_end(false)
+    .with_vertices(true);
+    let markers = path_markers::<_, SimpleBezierPath>(&corner_path(), &marker);
+
+    assert!(markers.len() == 1);
+
+    // Direction at the corner is halfway between right and up
+    let end_point = markers[0].points().last().unwrap().2;
+    let expected = Coord2(100.0, 0.0) + Coord2(1.0, 1.0).to_unit_vector() * 10.0;
+
+    assert!(markers[0].start_point().distance_to(&Coord2(100.0, 0.0)) < 1e-6);
+    assert!(end_point.distance_to(&expected) < 1e-3);
+}
+
+#[test]
+fn circle_markers_are_centered() {
+    let marker = Marker::new(MarkerShape::Circle { radius: 5.0 }).with_vertices(true);
+    let markers = path_markers::<_, SimpleBezierPath>(&corner_path(), &marker);
+
+    let expected_centers = [Coord2(0.0, 0.0), Coord2(100.0, 0.0), Coord2(100.0, 100.0)];
+
+    assert!(markers.len() == 3);
+    for (marker, center) in markers.iter().zip(expected_centers.iter()) {
+        let bounds = marker.bounding_box::<Bounds<_>>();
+        assert!(bounds.center().distance_to(center) < 1e-3);
+        assert!((bounds.max().x() - bounds.min().x() - 10.0).abs() < 0.1);
+    }
+}
@@ -0,0 +1,249 @@
+use flo_curves::arc::Circle;
+use flo_curves::bezier::path::{
+    path_frame_at_length, trim_path, walk_path_evenly, BezierPath, BezierPathBuilder, PathMeasure,
+    SimpleBezierPath,
+};
+use flo_curves::{BezierCurve, Coord2, Coordinate};
+
+use std::f64;
+
+fn square() -> SimpleBezierPath {
+    BezierPathBuilder::<SimpleBezierPath>::start(Coord2(0.0, 0.0))
+        .line_to(Coord2(100.0, 0.0))
+        .line_to(Coord2(100.0, 100.0))
+        .line_to(Coord2(0.0, 100.0))
+        .line_to(Coord2(0.0, 0.0))
+        .build()
+}
+
+#[test]
+fn measure_square() {
+    let measure = PathMeasure::new(&square(), 0.01);
+
+    assert!((measure.length() - 400.0).abs() < 0.1);
+    assert!(measure.point_at_length(0.0) == Coord2(0.0, 0.0));
+    assert!(
+        measure
+            .point_at_length(150.0)
+            .distance_to(&Coord2(100.0, 50.0))
+            < 0.1
+    );
+    assert!(
+        measure
+            .point_at_length(250.0)
+            .distance_to(&Coord2(50.0, 100.0))
+            < 0.1
+    );
+
+    // Joins are reported as the start of the following section
+    let (section, t) = measure.section_t_at_length(measure.length_at(1, 0.0));
+    assert!(section == 1);
+    assert!(t < 1e-3);
+
+    // Positions past the end are clamped
+    assert!(measure.section_t_at_length(1000.0) == (3, 1.0));
+}
+
+#[test]
+fn length_at_is_inverse_of_section_t_at_length() {
+    let circle = Circle::new(Coord2(0.0, 0.0), 50.0).to_path::<SimpleBezierPath>();
+    let measure = PathMeasure::new(&circle, 0.001);
+
+    assert!((measure.length() - f64::consts::PI * 100.0).abs() < 0.1);
+
+    for distance in [10.0, 75.0, 123.4, 250.0, 300.0].iter() {
+        let (section, t) = measure.section_t_at_length(*distance);
+        assert!((measure.length_at(section, t) - distance).abs() < 0.01);
+    }
+}
+
+#[test]
+fn walk_square_across_joins() {
+    let points = walk_path_evenly(&square(), 30.0, 0.01).collect::<Vec<_>>();
+
+    // 0, 30, ..., 390
+    assert!(points.len() == 14);
+
+    for (idx, point) in points.iter().enumerate() {
+        assert!((point.distance - (idx as f64) * 30.0).abs() < 1e-9);
+
+        // A point at a join might be at the end of the earlier section or the start of the later one
+        if (idx * 30) % 100 != 0 {
+            assert!(point.section == ((idx * 30) / 100));
+        }
+    }
+
+    // The point at 120 is 20 units along the second side
+    assert!(points[4].point.distance_to(&Coord2(100.0, 20.0)) < 0.1);
+    assert!((points[4].t - 0.2).abs() < 0.01);
+}
+
+#[test]
+fn walk_circle_evenly() {
+    let circle = Circle::new(Coord2(0.0, 0.0), 50.0).to_path::<SimpleBezierPath>();
+    let points = walk_path_evenly(&circle, 5.0, 0.001).collect::<Vec<_>>();
+
+    let curves = circle.to_curves::<flo_curves::bezier::Curve<_>>();
+
+    // Points should be on the circle and evenly spaced
+    for point in points.iter() {
+        assert!(
+            curves[point.section]
+                .point_at_pos(point.t)
+                .distance_to(&point.point)
+                < 1e-9
+        );
+        assert!((point.point.magnitude() - 50.0).abs() < 0.1);
+    }
+
+    for pair in points.windows(2) {
+        let chord = pair[0].point.distance_to(&pair[1].point);
+        assert!((chord - 5.0).abs() < 0.01);
+    }
+}
+
+#[test]
+fn frames_along_square() {
+    let measure = PathMeasure::new(&square(), 0.01);
+
+    let frame = measure.frame_at_length(50.0).unwrap();
+    assert!(frame.position.distance_to(&Coord2(50.0, 0.0)) < 0.1);
+    assert!(frame.tangent.distance_to(&Coord2(1.0, 0.0)) < 1e-6);
+    assert!(frame.normal.distance_to(&Coord2(0.0, 1.0)) < 1e-6);
+
+    // At a join, the frame follows the later section
+    let join = measure.length_at(1, 0.0);
+    let frame = measure.frame_at_length(join).unwrap();
+    assert!(frame.section == 1);
+    assert!(frame.position.distance_to(&Coord2(100.0, 0.0)) < 1e-6);
+    assert!(frame.tangent.distance_to(&Coord2(0.0, 1.0)) < 1e-6);
+    assert!(frame.normal.distance_to(&Coord2(-1.0, 0.0)) < 1e-6);
+}
+
+#[test]
+fn frames_along_circle_are_perpendicular_to_radius() {
+    let circle = Circle::new(Coord2(0.0, 0.0), 50.0).to_path::<SimpleBezierPath>();
+    let measure = PathMeasure::new(&circle, 0.001);
+
+    for step in 0..40 {
+        let frame = measure
+            .frame_at_length((step as f64) * measure.length() / 40.0)
+            .unwrap();
+        let radius = frame.position.to_unit_vector();
+
+        assert!((frame.tangent.magnitude() - 1.0).abs() < 1e-9);
+        assert!((frame.normal.magnitude() - 1.0).abs() < 1e-9);
+        assert!(frame.tangent.dot(&radius).abs() < 0.01);
+        assert!((frame.normal.dot(&radius).abs() - 1.0).abs() < 0.01);
+    }
+}
+
+#[test]
+fn frame_with_coincident_control_points() {
+    let path: SimpleBezierPath = (
+        Coord2(0.0, 0.0),
+        vec![
+            (Coord2(0.0, 0.0), Coord2(100.0, 0.0), Coord2(100.0, 0.0)),
+            (Coord2(100.0, 0.0), Coord2(100.0, 0.0), Coord2(100.0, 0.0)),
+            (
+                Coord2(100.0, 0.0),
+                Coord2(100.0, 100.0),
+                Coord2(100.0, 100.0),
+            ),
+        ],
+    );
+
+    // Start of a curve where the control point is on the start point
+    let frame = path_frame_at_length(&path, 0.0, 0.01).unwrap();
+    assert!(frame.tangent.distance_to(&Coord2(1.0, 0.0)) < 1e-6);
+
+    // The join goes through a section that's just a point, so the direction comes from the following section
+    let frame = path_frame_at_length(&path, 100.0, 0.01).unwrap();
+    assert!(frame.position.distance_to(&Coord2(100.0, 0.0)) < 1e-6);
+    assert!(frame.tangent.distance_to(&Coord2(0.0, 1.0)) < 1e-6);
+}
+
+#[test]
+fn frame_with_both_control_points_on_start() {
+    let path: SimpleBezierPath = (
+        Coord2(0.0, 0.0),
+        vec![
+            (Coord2(0.0, 0.0), Coord2(0.0, 0.0), Coord2(100.0, 0.0)),
+            (
+                Coord2(100.0, 0.0),
+                Coord2(100.0, 100.0),
+                Coord2(100.0, 100.0),
+            ),
+        ],
+    );
+
+    // The section has no speed at its start, but still has a direction, so it shouldn't use the direction of the next section
+    let frame = path_frame_at_length(&path, 0.0, 0.01).unwrap();
+    assert!(frame.section == 0);
+    assert!(frame.tangent.distance_to(&Coord2(1.0, 0.0)) < 1e-6);
+}
+
+#[test]
+fn empty_path_has_no_frame() {
+    let path: SimpleBezierPath = (Coord2(0.0, 0.0), vec![]);
+
+    assert!(path_frame_at_length(&path, 0.0, 0.01).is_none());
+}
+
+#[test]
+fn trim_square_across_corners() {
+    let trimmed: SimpleBezierPath = trim_path(&square(), 50.0, 250.0, 0.01);
+    let points = trimmed.points().collect::<Vec<_>>();
+
+    assert!(trimmed.start_point().distance_to(&Coord2(50.0, 0.0)) < 0.01);
+    assert!(points.len() == 3);
+    assert!(points[0].2.distance_to(&Coord2(100.0, 0.0)) < 1e-6);
+    assert!(points[1].2.distance_to(&Coord2(100.0, 100.0)) < 1e-6);
+    assert!(points[2].2.distance_to(&Coord2(50.0, 100.0)) < 0.01);
+
+    let measure = PathMeasure::new(&trimmed, 0.01);
+    assert!((measure.length() - 200.0).abs() < 0.01);
+}
+
+#[test]
+fn trim_square_at_joins() {
+    let trimmed: SimpleBezierPath = trim_path(&square(), 100.0, 200.0, 0.01);
+    let points = trimmed.points().collect::<Vec<_>>();
+
+    // Ends at the end of the second section rather than the start of the third
+    assert!(trimmed.start_point().distance_to(&Coord2(100.0, 0.0)) < 1e-6);
+    assert!(points.len() == 1);
+    assert!(points[0].2.distance_to(&Coord2(100.0, 100.0)) < 1e-6);
+}
+
+#[test]
+fn trim_circle_progressively() {
+    let circle = Circle::new(Coord2(0.0, 0.0), 100.0).to_path::<SimpleBezierPath>();
+    let measure = PathMeasure::new(&circle, 0.01);
+    let length = measure.length();
+
+    for step in 1..=10 {
+        let distance = length * (step as f64) / 10.0;
+        let trimmed: SimpleBezierPath = measure.trim(0.0, distance);
+
+        assert!(trimmed.start_point() == circle.start_point());
+        assert!((PathMeasure::new(&trimmed, 0.01).length() - distance).abs() < 0.1);
+        assert!(
+            trimmed
+                .points()
+                .last()
+                .unwrap()
+                .2
+                .distance_to(&measure.point_at_length(distance))
+                < 1e-6
+        );
+    }
+}
+
+#[test]
+fn trim_empty_path() {
+    let path: SimpleBezierPath = (Coord2(0.0, 0.0), vec![]);
+    let trimmed: SimpleBezierPath = trim_path(&path, 0.0, 10.0, 0.01);
+
+    assert!(trimmed.points().count() == 0);
+}
@@ -0,0 +1,115 @@
+use flo_curves::bezier::path::{
+    path_medial_axis, BezierPathBuilder, MedialAxisSegment, SimpleBezierPath,
+};
+use flo_curves::{Coord2, Coordinate, Coordinate2D};
+
+fn rectangle() -> SimpleBezierPath {
+    BezierPathBuilder::<SimpleBezierPath>::start(Coord2(0.0, 0.0))
+        .line_to(Coord2(100.0, 0.0))
+        .line_to(Coord2(100.0, 20.0))
+        .line_to(Coord2(0.0, 20.0))
+        .line_to(Coord2(0.0, 0.0))
+        .build()
+}
+
+fn segment_length(segment: &MedialAxisSegment<Coord2>) -> f64 {
+    segment.start.distance_to(&segment.end)
+}
+
+#[test]
+fn rectangle_medial_axis_follows_center() {
+    let segments = path_medial_axis(&rectangle(), 2.0, 0.01);
+
+    assert!(!segments.is_empty());
+
+    // Every part of the axis is inside the rectangle
+    for segment in segments.iter() {
+        for point in [segment.start, segment.end] {
+            assert!(point.x() > -1e-6 && point.x() < 100.0 + 1e-6);
+            assert!(point.y() > -1e-6 && point.y() < 20.0 + 1e-6);
+        }
+    }
+
+    // Away from the ends, the axis runs along the middle and the inscribed circles are half the height of the rectangle
+    let middle = segments
+        .iter()
+        .filter(|segment| segment.start.x() > 20.0 && segment.start.x() < 80.0)
+        .filter(|segment| segment.end.x() > 20.0 && segment.end.x() < 80.0)
+        .collect::<Vec<_>>();
+
+    for segment in middle.iter() {
+        assert!((segment.start.y() - 10.0).abs() < 0.5);
+        assert!((segment.end.y() - 10.0).abs() < 0.5);
+        assert!((segment.start_radius - 10.0).abs() < 0.5);
+        assert!((segment.end_radius - 10.0).abs() < 0.5);
+    }
+
+    let middle_length = middle
+        .iter()
+        .map(|segment| segment_length(segment))
+        .sum::<f64>();
+    assert!(middle_length > 55.0);
+}
+
+#[test]
+fn l_shape_medial_axis_covers_both_arms() {
+    let l_shape = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(0.0, 0.0))
+        .line_to(Coord2(100.0, 0.0))
+        .line_to(Coord2(100.0, 20.0))
+        .line_to(Coord2(20.0, 20.0))
+        .line_to(Coord2(20.0, 100.0))
+        .line_to(Coord2(0.0, 100.0))
+        .line_to(Coord2(0.0, 0.0))
+        .build();
+
+    let segments = path_medial_axis(&l_shape, 2.0, 0.01);
+
+    let horizontal_arm = segments
+        .iter()
+        .filter(|segment| segment.start.x() > 40.0 && (segment.start.y() - 10.0).abs() < 0.5)
+        .map(segment_length)
+        .sum::<f64>();
+    let vertical_arm = segments
+        .iter()
+        .filter(|segment| segment.start.y() > 40.0 && (segment.start.x() - 10.0).abs() < 0.5)
+        .map(segment_length)
+        .sum::<f64>();
+
+    assert!(horizontal_arm > 40.0);
+    assert!(vertical_arm > 40.0);
+
+    // No part of the axis is in the empty corner of the L
+    assert!(segments
+        .iter()
+        .all(|segment| !(segment.start.x() > 21.0 && segment.start.y() > 21.0)));
+}
+
+#[test]
+fn radius_is_distance_to_outline() {
+    let segments = path_medial_axis(&rectangle(), 2.0, 0.01);
+
+    for segment in segments.iter() {
+        let point = segment.start;
+        let expected = point
+            .x()
+            .min(100.0 - point.x())
+            .min(point.y())
+            .min(20.0 - point.y());
+
+        assert!((segment.start_radius - expected).abs() < 1e-6);
+    }
+}
+
+#[test]
+fn empty_path_has_no_medial_axis() {
+    let path: SimpleBezierPath = (Coord2(0.0, 0.0), vec![]);
+
+    assert!(path_medial_axis(&path, 2.0, 0.01).is_empty());
+}
+
+#[test]
+fn invalid_spacing_has_no_medial_axis() {
+    for spacing in [0.0, -2.0, f64::NAN, f64::INFINITY] {
+        assert!(path_medial_axis(&rectangle(), spacing, 0.01).is_empty());
+    }
+}
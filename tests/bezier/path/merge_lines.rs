@@ -0,0 +1,96 @@
+use flo_curves::bezier::path::{
+    path_merge_collinear_lines, path_signed_area, BezierPathBuilder, SimpleBezierPath,
+};
+use flo_curves::Coord2;
+
+#[test]
+fn merge_square_made_of_steps() {
+    let corners = [
+        Coord2(0.0, 0.0),
+        Coord2(10.0, 0.0),
+        Coord2(10.0, 10.0),
+        Coord2(0.0, 10.0),
+    ];
+    let mut builder = BezierPathBuilder::<SimpleBezierPath>::start(corners[0]);
+
+    for side in 0..4 {
+        let (from, to) = (corners[side], corners[(side + 1) % 4]);
+
+        for step in 1..=10 {
+            builder = builder.line_to(from + (to - from) * ((step as f64) / 10.0));
+
+            // Add some duplicate points too
+            if step == 5 {
+                builder = builder.line_to(from + (to - from) * 0.5);
+            }
+        }
+    }
+
+    let path = builder.build();
+    assert!(path.1.len() == 44);
+
+    let merged = path_merge_collinear_lines::<_, SimpleBezierPath>(&path, 0.001);
+
+    assert!(merged.1.len() == 4);
+    assert!(merged.0 == Coord2(0.0, 0.0));
+    assert!(merged.1.iter().map(|(_, _, end_point)| *end_point).eq(vec![
+        Coord2(10.0, 0.0),
+        Coord2(10.0, 10.0),
+        Coord2(0.0, 10.0),
+        Coord2(0.0, 0.0)
+    ]));
+    assert!((path_signed_area(&merged) - 100.0).abs() < 0.0001);
+}
+
+#[test]
+fn start_point_in_middle_of_line_is_moved() {
+    let path = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(5.0, 0.0))
+        .line_to(Coord2(10.0, 0.0))
+        .line_to(Coord2(10.0, 10.0))
+        .line_to(Coord2(0.0, 10.0))
+        .line_to(Coord2(0.0, 0.0))
+        .line_to(Coord2(5.0, 0.0))
+        .build();
+
+    let merged = path_merge_collinear_lines::<_, SimpleBezierPath>(&path, 0.001);
+
+    assert!(merged.0 == Coord2(0.0, 0.0));
+    assert!(merged.1.len() == 4);
+    assert!(merged.1[0].2 == Coord2(10.0, 0.0));
+    assert!((path_signed_area(&merged) - 100.0).abs() < 0.0001);
+}
+
+#[test]
+fn lines_that_double_back_are_not_merged() {
+    let path = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(0.0, 0.0))
+        .line_to(Coord2(10.0, 0.0))
+        .line_to(Coord2(5.0, 0.0))
+        .line_to(Coord2(5.0, 10.0))
+        .line_to(Coord2(0.0, 0.0))
+        .build();
+
+    let merged = path_merge_collinear_lines::<_, SimpleBezierPath>(&path, 0.001);
+
+    assert!(merged.0 == path.0);
+    assert!(merged
+        .1
+        .iter()
+        .map(|(_, _, end_point)| *end_point)
+        .eq(path.1.iter().map(|(_, _, end_point)| *end_point)));
+}
+
+#[test]
+fn curves_are_preserved() {
+    let path = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(0.0, 0.0))
+        .line_to(Coord2(5.0, 0.0))
+        .line_to(Coord2(10.0, 0.0))
+        .curve_to((Coord2(15.0, 5.0), Coord2(5.0, 15.0)), Coord2(0.0, 10.0))
+        .line_to(Coord2(0.0, 0.0))
+        .build();
+
+    let merged = path_merge_collinear_lines::<_, SimpleBezierPath>(&path, 0.001);
+
+    assert!(merged.1.len() == 3);
+    assert!(merged.1[1] == path.1[2]);
+    assert!((path_signed_area(&merged) - path_signed_area(&path)).abs() < 0.0001);
+}
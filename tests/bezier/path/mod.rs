@@ -2,14 +2,46 @@ mod arithmetic_add;
 mod arithmetic_chain_add;
 mod arithmetic_complicated_paths;
 mod arithmetic_cut;
+mod arithmetic_error;
+mod arithmetic_expr;
+mod arithmetic_fill_rule;
 mod arithmetic_intersect;
+mod arithmetic_polygon;
+mod arithmetic_precision;
+mod arithmetic_ray_cast;
+mod arithmetic_remove_overlaps;
 mod arithmetic_sub;
 mod bounds;
+mod canonical;
+mod centerline;
+mod clip;
+mod compress;
+mod containment;
+#[cfg(feature = "geo_formats")]
+mod geo_formats;
 mod graph_path;
 mod intersection;
 mod is_clockwise;
+mod joins;
+mod markers;
+mod measure;
+mod medial_axis;
+mod merge_lines;
+mod morph;
+mod nesting;
+mod offset;
 mod path;
+mod path_index;
 mod point;
+mod polygon;
 mod rays;
+mod ribbon;
+mod scanline;
+mod smooth;
+mod snap;
 mod svg;
+mod sweep;
+mod tessellate;
 mod to_curves;
+mod to_quadratics;
+mod validate;
@@ -0,0 +1,180 @@
+use flo_curves::arc::Circle;
+use flo_curves::bezier::path::{
+    morph_correspondence, morph_path, morph_path_with_correspondence, path_to_curves, BezierPath,
+    BezierPathBuilder, PathMorph, SimpleBezierPath,
+};
+use flo_curves::bezier::Curve;
+use flo_curves::geo::{Transform2D, Transformable};
+use flo_curves::{BezierCurve, BoundingBox, Bounds, Coord2, Coordinate};
+
+fn square(min: f64, max: f64) -> SimpleBezierPath {
+    BezierPathBuilder::<SimpleBezierPath>::start(Coord2(min, min))
+        .line_to(Coord2(max, min))
+        .line_to(Coord2(max, max))
+        .line_to(Coord2(min, max))
+        .line_to(Coord2(min, min))
+        .build()
+}
+
+fn triangle() -> SimpleBezierPath {
+    BezierPathBuilder::<SimpleBezierPath>::start(Coord2(0.0, 0.0))
+        .line_to(Coord2(100.0, 0.0))
+        .line_to(Coord2(50.0, 100.0))
+        .line_to(Coord2(0.0, 0.0))
+        .build()
+}
+
+///
+/// Finds the distance from every point on one path to the nearest point on another
+///
+fn max_distance_between(from: &SimpleBezierPath, to: &SimpleBezierPath) -> f64 {
+    let to_points = path_to_curves::<_, Curve<_>>(to)
+        .flat_map(|curve| (0..=10000).map(move |t| curve.point_at_pos((t as f64) / 10000.0)))
+        .collect::<Vec<_>>();
+
+    path_to_curves::<_, Curve<_>>(from)
+        .flat_map(|curve| (0..=20).map(move |t| curve.point_at_pos((t as f64) / 20.0)))
+        .map(|point| {
+            to_points
+                .iter()
+                .map(|to_point| to_point.distance_to(&point))
+                .fold(f64::MAX, f64::min)
+        })
+        .fold(0.0, f64::max)
+}
+
+#[test]
+fn morph_between_squares() {
+    let morphed =
+        morph_path::<_, _, SimpleBezierPath>(&square(0.0, 100.0), &square(0.0, 200.0), 0.5);
+
+    assert!(morphed.start_point() == Coord2(0.0, 0.0));
+
+    let corners = morphed
+        .points()
+        .map(|(_, _, point)| point)
+        .collect::<Vec<_>>();
+    assert!(corners.len() == 4);
+    assert!(corners[0].distance_to(&Coord2(150.0, 0.0)) < 1e-6);
+    assert!(corners[1].distance_to(&Coord2(150.0, 150.0)) < 1e-6);
+    assert!(corners[2].distance_to(&Coord2(0.0, 150.0)) < 1e-6);
+}
+
+#[test]
+fn morph_different_section_counts() {
+    let triangle = triangle();
+    let circle = Circle::new(Coord2(50.0, 50.0), 40.0).to_path::<SimpleBezierPath>();
+
+    let start = morph_path::<_, _, SimpleBezierPath>(&triangle, &circle, 0.0);
+    let end = morph_path::<_, _, SimpleBezierPath>(&triangle, &circle, 1.0);
+    let middle = morph_path::<_, _, SimpleBezierPath>(&triangle, &circle, 0.5);
+
+    // Both ends of the morph have the same number of sections but the same shape as the originals
+    assert!(start.points().count() == end.points().count());
+    assert!(start.points().count() > 4);
+
+    assert!(max_distance_between(&start, &triangle) < 0.01);
+    assert!(max_distance_between(&triangle, &start) < 0.01);
+    assert!(max_distance_between(&end, &circle) < 0.01);
+    assert!(max_distance_between(&circle, &end) < 0.01);
+
+    // The middle of the morph should be a closed path
+    let last_point = middle.points().last().unwrap().2;
+    assert!(last_point.distance_to(&middle.start_point()) < 1e-6);
+}
+
+#[test]
+fn morph_reversed_path_does_not_collapse() {
+    let square = square(0.0, 100.0);
+    let reversed = square.reversed::<SimpleBezierPath>();
+
+    // If the direction wasn't matched, the halfway point would pull opposite sides of the square together
+    let morphed = morph_path::<_, _, SimpleBezierPath>(&square, &reversed, 0.5);
+    let bounds = morphed.bounding_box::<Bounds<_>>();
+
+    assert!(bounds.min().distance_to(&Coord2(0.0, 0.0)) < 1e-6);
+    assert!(bounds.max().distance_to(&Coord2(100.0, 100.0)) < 1e-6);
+    assert!(max_distance_between(&morphed, &square) < 1e-6);
+}
+
+#[test]
+fn correspondence_for_reversed_square() {
+    let square = square(0.0, 100.0);
+    let reversed = square.reversed::<SimpleBezierPath>();
+
+    let correspondence = morph_correspondence(&square, &reversed);
+
+    assert!(correspondence.reversed);
+    assert!(correspondence.start_position.abs() < 1e-6);
+}
+
+#[test]
+fn correspondence_for_square_with_different_start() {
+    let square = square(0.0, 100.0);
+    let shifted = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(100.0, 100.0))
+        .line_to(Coord2(0.0, 100.0))
+        .line_to(Coord2(0.0, 0.0))
+        .line_to(Coord2(100.0, 0.0))
+        .line_to(Coord2(100.0, 100.0))
+        .build();
+
+    let correspondence = morph_correspondence(&square, &shifted);
+    assert!(!correspondence.reversed);
+    assert!((correspondence.start_position - 0.5).abs() < 1e-6);
+
+    // Nothing should move when morphing between the two versions of the square
+    let morphed = morph_path_with_correspondence::<_, _, SimpleBezierPath>(
+        &square,
+        &shifted,
+        &correspondence,
+        0.5,
+    );
+    assert!(morphed.start_point().distance_to(&Coord2(0.0, 0.0)) < 1e-6);
+    assert!(max_distance_between(&morphed, &square) < 1e-6);
+    assert!(max_distance_between(&square, &morphed) < 1e-6);
+}
+
+#[test]
+fn correspondence_for_rotated_circle_starts_nearby() {
+    let circle = Circle::new(Coord2(50.0, 50.0), 40.0).to_path::<SimpleBezierPath>();
+    let rotated = circle.transform(&Transform2D::rotate_around(&Coord2(50.0, 50.0), 1.0));
+
+    let morphed = morph_path::<_, _, SimpleBezierPath>(&circle, &rotated, 0.5);
+
+    // Every point can stay where it is, so the halfway shape should still be the circle
+    assert!(morphed.start_point().distance_to(&circle.start_point()) < 2.5);
+    assert!(max_distance_between(&morphed, &circle) < 0.5);
+}
+
+#[test]
+fn correspondence_for_reversed_open_path() {
+    let line = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(0.0, 0.0))
+        .line_to(Coord2(100.0, 0.0))
+        .build();
+    let reversed = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(100.0, 0.0))
+        .line_to(Coord2(0.0, 0.0))
+        .build();
+
+    let correspondence = morph_correspondence(&line, &reversed);
+
+    assert!(correspondence.reversed);
+    assert!(correspondence.start_position == 0.0);
+}
+
+#[test]
+fn prepared_morph_matches_morph_path() {
+    let triangle = triangle();
+    let circle = Circle::new(Coord2(50.0, 50.0), 40.0).to_path::<SimpleBezierPath>();
+
+    let morph = PathMorph::new(&triangle, &circle);
+    assert!(morph.correspondence() == morph_correspondence(&triangle, &circle));
+
+    for t in [0.0, 0.25, 0.5, 1.0].iter() {
+        let prepared = morph.path_at::<SimpleBezierPath>(*t);
+        let morphed = morph_path::<_, _, SimpleBezierPath>(&triangle, &circle, *t);
+
+        assert!(prepared.start_point().distance_to(&morphed.start_point()) < 1e-6);
+        assert!(max_distance_between(&prepared, &morphed) < 1e-6);
+        assert!(max_distance_between(&morphed, &prepared) < 1e-6);
+    }
+}
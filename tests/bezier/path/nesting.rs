@@ -0,0 +1,127 @@
+use flo_curves::arc::Circle;
+use flo_curves::bezier::path::{
+    normalize_winding, path_figures, path_nesting_tree, path_signed_area, BezierPath,
+    BezierPathBuilder, PathDirection, PathWithIsClockwise, SimpleBezierPath,
+};
+use flo_curves::Coord2;
+
+use std::f64;
+
+#[test]
+fn signed_area_of_square() {
+    let square = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(1.0, 1.0))
+        .line_to(Coord2(3.0, 1.0))
+        .line_to(Coord2(3.0, 3.0))
+        .line_to(Coord2(1.0, 3.0))
+        .line_to(Coord2(1.0, 1.0))
+        .build();
+
+    assert!((path_signed_area(&square) - 4.0).abs() < 1e-9);
+    assert!((path_signed_area(&square.reversed::<SimpleBezierPath>()) + 4.0).abs() < 1e-9);
+    assert!(!square.is_clockwise());
+}
+
+#[test]
+fn signed_area_of_circle() {
+    let circle = Circle::new(Coord2(5.0, 5.0), 4.0).to_path::<SimpleBezierPath>();
+    let area = path_signed_area(&circle).abs();
+
+    assert!((area - f64::consts::PI * 16.0).abs() < 0.1);
+    assert!((path_signed_area(&circle) < 0.0) == circle.is_clockwise());
+}
+
+#[test]
+fn nest_rings_and_islands() {
+    let outer = Circle::new(Coord2(10.0, 10.0), 8.0).to_path::<SimpleBezierPath>();
+    let hole = Circle::new(Coord2(10.0, 10.0), 5.0).to_path::<SimpleBezierPath>();
+    let island = Circle::new(Coord2(10.0, 10.0), 2.0).to_path::<SimpleBezierPath>();
+    let separate = Circle::new(Coord2(30.0, 10.0), 3.0).to_path::<SimpleBezierPath>();
+
+    // Order shouldn't matter
+    let paths = vec![island, separate, outer, hole];
+    let tree = path_nesting_tree(&paths);
+
+    assert!(tree.len() == 2);
+    assert!(tree[0].path_idx == 1);
+    assert!(tree[0].children.is_empty());
+    assert!(tree[1].path_idx == 2);
+    assert!(!tree[1].is_hole());
+
+    assert!(tree[1].children.len() == 1);
+    let hole_node = &tree[1].children[0];
+    assert!(hole_node.path_idx == 3);
+    assert!(hole_node.is_hole());
+
+    assert!(hole_node.children.len() == 1);
+    assert!(hole_node.children[0].path_idx == 0);
+    assert!(hole_node.children[0].depth == 2);
+    assert!(!hole_node.children[0].is_hole());
+}
+
+#[test]
+fn nest_paths_with_nan_area() {
+    let circle = Circle::new(Coord2(10.0, 10.0), 8.0).to_path::<SimpleBezierPath>();
+    let nan_path = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(f64::NAN, 1.0))
+        .line_to(Coord2(3.0, 1.0))
+        .line_to(Coord2(3.0, 3.0))
+        .line_to(Coord2(f64::NAN, 1.0))
+        .build();
+
+    // Paths whose area can't be found shouldn't stop the other paths from being sorted by size
+    let paths = vec![nan_path, circle];
+    let tree = path_nesting_tree(&paths);
+
+    assert!(tree.iter().any(|node| node.path_idx == 1));
+}
+
+#[test]
+fn figures_have_normalised_winding() {
+    let outer = Circle::new(Coord2(10.0, 10.0), 8.0).to_path::<SimpleBezierPath>();
+    let hole = Circle::new(Coord2(10.0, 10.0), 5.0).to_path::<SimpleBezierPath>();
+    let island = Circle::new(Coord2(10.0, 10.0), 2.0).to_path::<SimpleBezierPath>();
+
+    let figures = path_figures::<_, SimpleBezierPath>(&[outer, hole, island]);
+
+    // The outer ring with its hole, then the island
+    assert!(figures.len() == 2);
+    assert!(figures[0].len() == 2);
+    assert!(figures[1].len() == 1);
+
+    assert!(path_signed_area(&figures[0][0]) < 0.0);
+    assert!(path_signed_area(&figures[0][1]) > 0.0);
+    assert!(path_signed_area(&figures[1][0]) < 0.0);
+}
+
+#[test]
+fn normalize_winding_of_inconsistent_paths() {
+    let outer = Circle::new(Coord2(10.0, 10.0), 8.0).to_path::<SimpleBezierPath>();
+    let hole = Circle::new(Coord2(10.0, 10.0), 5.0).to_path::<SimpleBezierPath>();
+    let island = Circle::new(Coord2(10.0, 10.0), 2.0).to_path::<SimpleBezierPath>();
+    let separate = Circle::new(Coord2(30.0, 10.0), 3.0).to_path::<SimpleBezierPath>();
+
+    // Start with all of the paths going in different directions
+    let paths = vec![
+        outer.reversed::<SimpleBezierPath>(),
+        hole.clone(),
+        island.reversed::<SimpleBezierPath>(),
+        separate,
+    ];
+
+    let clockwise = normalize_winding::<_, SimpleBezierPath>(&paths, PathDirection::Clockwise);
+
+    // Paths are in the original order, with the outlines clockwise and the hole anticlockwise
+    assert!(clockwise.len() == 4);
+    assert!(path_signed_area(&clockwise[0]) < 0.0);
+    assert!(path_signed_area(&clockwise[1]) > 0.0);
+    assert!(path_signed_area(&clockwise[2]) < 0.0);
+    assert!(path_signed_area(&clockwise[3]) < 0.0);
+    assert!((path_signed_area(&clockwise[1]) - path_signed_area(&hole).abs()).abs() < 1e-6);
+
+    let anticlockwise =
+        normalize_winding::<_, SimpleBezierPath>(&paths, PathDirection::Anticlockwise);
+
+    assert!(path_signed_area(&anticlockwise[0]) > 0.0);
+    assert!(path_signed_area(&anticlockwise[1]) < 0.0);
+    assert!(path_signed_area(&anticlockwise[2]) > 0.0);
+    assert!(path_signed_area(&anticlockwise[3]) > 0.0);
+}
@@ -0,0 +1,160 @@
+use flo_curves::arc::Circle;
+use flo_curves::bezier::path::{path_offset, BezierPath, BezierPathBuilder, SimpleBezierPath};
+use flo_curves::bezier::Curve;
+use flo_curves::{BezierCurve, BoundingBox, Coord2, Coordinate, Coordinate2D};
+
+fn square() -> SimpleBezierPath {
+    BezierPathBuilder::<SimpleBezierPath>::start(Coord2(1.0, 1.0))
+        .line_to(Coord2(1.0, 5.0))
+        .line_to(Coord2(5.0, 5.0))
+        .line_to(Coord2(5.0, 1.0))
+        .line_to(Coord2(1.0, 1.0))
+        .build()
+}
+
+#[test]
+fn offset_circle_outwards() {
+    let circle = Circle::new(Coord2(5.0, 5.0), 4.0).to_path::<SimpleBezierPath>();
+    let offset = path_offset::<_, SimpleBezierPath>(&circle, 1.0, 0.01);
+
+    assert!(offset.len() == 1);
+
+    // Every point on the offset circle should be the offset distance from the original
+    for curve in offset[0].to_curves::<Curve<_>>() {
+        for t in 0..10 {
+            let point = curve.point_at_pos((t as f64) / 10.0);
+            assert!((point.distance_to(&Coord2(5.0, 5.0)) - 5.0).abs() < 0.05);
+        }
+    }
+}
+
+#[test]
+fn offset_reversed_circle_outwards() {
+    let circle = Circle::new(Coord2(5.0, 5.0), 4.0).to_path::<SimpleBezierPath>();
+    let circle = circle.reversed::<SimpleBezierPath>();
+    let offset = path_offset::<_, SimpleBezierPath>(&circle, 1.0, 0.01);
+
+    assert!(offset.len() == 1);
+
+    let bounds = offset[0].bounding_box::<(Coord2, Coord2)>();
+    assert!(bounds.min().distance_to(&Coord2(0.0, 0.0)) < 0.05);
+    assert!(bounds.max().distance_to(&Coord2(10.0, 10.0)) < 0.05);
+}
+
+#[test]
+fn offset_square_outwards() {
+    let offset = path_offset::<_, SimpleBezierPath>(&square(), 1.0, 0.01);
+    println!("{:?}", offset);
+
+    assert!(offset.len() == 1);
+
+    let bounds = offset[0].bounding_box::<(Coord2, Coord2)>();
+    assert!(bounds.min().distance_to(&Coord2(0.0, 0.0)) < 0.05);
+    assert!(bounds.max().distance_to(&Coord2(6.0, 6.0)) < 0.05);
+
+    // Corners are rounded
+    assert!(!offset[0]
+        .points()
+        .any(|(_, _, point)| point.distance_to(&Coord2(0.0, 0.0)) < 0.1));
+}
+
+#[test]
+fn offset_square_inwards() {
+    let offset = path_offset::<_, SimpleBezierPath>(&square(), -1.0, 0.01);
+    println!("{:?}", offset);
+
+    assert!(offset.len() == 1);
+
+    let bounds = offset[0].bounding_box::<(Coord2, Coord2)>();
+    assert!(bounds.min().distance_to(&Coord2(2.0, 2.0)) < 0.05);
+    assert!(bounds.max().distance_to(&Coord2(4.0, 4.0)) < 0.05);
+}
+
+#[test]
+fn offset_square_inwards_past_center() {
+    let offset = path_offset::<_, SimpleBezierPath>(&square(), -3.0, 0.01);
+    println!("{:?}", offset);
+
+    assert!(offset.is_empty());
+}
+
+#[test]
+fn offset_dumbbell_inwards_splits_in_two() {
+    // Two 10x10 squares joined by a handle that's 2 units wide
+    let dumbbell = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(0.0, 0.0))
+        .line_to(Coord2(0.0, 10.0))
+        .line_to(Coord2(10.0, 10.0))
+        .line_to(Coord2(10.0, 6.0))
+        .line_to(Coord2(20.0, 6.0))
+        .line_to(Coord2(20.0, 10.0))
+        .line_to(Coord2(30.0, 10.0))
+        .line_to(Coord2(30.0, 0.0))
+        .line_to(Coord2(20.0, 0.0))
+        .line_to(Coord2(20.0, 4.0))
+        .line_to(Coord2(10.0, 4.0))
+        .line_to(Coord2(10.0, 0.0))
+        .line_to(Coord2(0.0, 0.0))
+        .build();
+
+    // A small inset keeps the handle
+    let offset = path_offset::<_, SimpleBezierPath>(&dumbbell, -0.5, 0.01);
+    assert!(offset.len() == 1);
+
+    // Insetting by more than half the width of the handle removes it, leaving the two ends
+    let offset = path_offset::<_, SimpleBezierPath>(&dumbbell, -2.0, 0.01);
+    println!("{:?}", offset);
+    assert!(offset.len() == 2);
+
+    let mut bounds = offset
+        .iter()
+        .map(|path| path.bounding_box::<(Coord2, Coord2)>())
+        .collect::<Vec<_>>();
+    bounds.sort_by(|a, b| a.min().x().partial_cmp(&b.min().x()).unwrap());
+
+    assert!(bounds[0].min().distance_to(&Coord2(2.0, 2.0)) < 0.05);
+    assert!(bounds[0].max().y() < 8.05);
+    assert!(bounds[0].max().x() < 10.0);
+    assert!(bounds[1].min().x() > 20.0);
+    assert!(bounds[1].max().distance_to(&Coord2(28.0, 8.0)) < 0.05);
+}
+
+#[test]
+fn offset_rounded_shape_inwards_past_tight_curves() {
+    // A 20x10 rectangle with corners of radius 1: insetting by more than the radius makes the offset corners turn back on
+    // themselves
+    let rounded_rect = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(1.0, 0.0))
+        .line_to(Coord2(19.0, 0.0))
+        .curve_to((Coord2(19.55, 0.0), Coord2(20.0, 0.45)), Coord2(20.0, 1.0))
+        .line_to(Coord2(20.0, 9.0))
+        .curve_to(
+            (Coord2(20.0, 9.55), Coord2(19.55, 10.0)),
+            Coord2(19.0, 10.0),
+        )
+        .line_to(Coord2(1.0, 10.0))
+        .curve_to((Coord2(0.45, 10.0), Coord2(0.0, 9.55)), Coord2(0.0, 9.0))
+        .line_to(Coord2(0.0, 1.0))
+        .curve_to((Coord2(0.0, 0.45), Coord2(0.45, 0.0)), Coord2(1.0, 0.0))
+        .build();
+
+    let offset = path_offset::<_, SimpleBezierPath>(&rounded_rect, -2.0, 0.01);
+    println!("{:?}", offset);
+    assert!(offset.len() == 1);
+
+    let bounds = offset[0].bounding_box::<(Coord2, Coord2)>();
+    assert!(bounds.min().distance_to(&Coord2(2.0, 2.0)) < 0.05);
+    assert!(bounds.max().distance_to(&Coord2(18.0, 8.0)) < 0.05);
+
+    // Every point on the result should be on the edge of the inset rectangle
+    for curve in offset[0].to_curves::<Curve<_>>() {
+        for t in 0..10 {
+            let point = curve.point_at_pos((t as f64) / 10.0);
+            let distance_to_edge = (point.x() - 2.0)
+                .abs()
+                .min((point.x() - 18.0).abs())
+                .min((point.y() - 2.0).abs())
+                .min((point.y() - 8.0).abs());
+
+            assert!(distance_to_edge < 0.05);
+        }
+    }
+}
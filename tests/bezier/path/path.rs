@@ -1,4 +1,5 @@
-use flo_curves::bezier::path::{BezierPath, BezierPathBuilder, SimpleBezierPath};
+use flo_curves::bezier::path::{path_to_curves, BezierPath, BezierPathBuilder, SimpleBezierPath};
+use flo_curves::bezier::Curve;
 use flo_curves::{BezierCurve, Coord2, Coordinate, Line};
 
 #[test]
@@ -21,3 +22,85 @@ fn reverse_rectangle() {
     assert!(points[2].2 == Coord2(1.0, 5.0));
     assert!(points[3].2 == Coord2(1.0, 1.0));
 }
+
+#[test]
+fn rounded_rectangle() {
+    let rectangle = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(0.0, 0.0))
+        .line_to_rounded(Coord2(100.0, 0.0), 10.0)
+        .line_to_rounded(Coord2(100.0, 50.0), 10.0)
+        .line_to_rounded(Coord2(0.0, 50.0), 10.0)
+        .line_to_rounded(Coord2(0.0, 0.0), 10.0)
+        .build();
+
+    // Each corner is replaced with an arc, including the one at the start
+    let curves = path_to_curves::<_, Curve<_>>(&rectangle).collect::<Vec<_>>();
+    assert!(curves.len() == 8);
+    assert!(rectangle.start_point().distance_to(&Coord2(10.0, 0.0)) < 1e-6);
+    assert!(curves[7].end_point().distance_to(&rectangle.start_point()) < 1e-6);
+
+    // Sections should join up
+    for (curve, next_curve) in curves.iter().zip(curves.iter().skip(1)) {
+        assert!(curve.end_point().distance_to(&next_curve.start_point()) < 1e-6);
+    }
+
+    // The arcs should be circular
+    let centers = [
+        Coord2(90.0, 10.0),
+        Coord2(90.0, 40.0),
+        Coord2(10.0, 40.0),
+        Coord2(10.0, 10.0),
+    ];
+    for (arc, center) in curves.iter().skip(1).step_by(2).zip(centers.iter()) {
+        for t in 0..=10 {
+            let distance = arc.point_at_pos((t as f64) / 10.0).distance_to(center);
+            assert!((distance - 10.0).abs() < 0.01);
+        }
+    }
+}
+
+#[test]
+fn fillet_radius_is_clamped_on_short_lines() {
+    let triangle = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(0.0, 0.0))
+        .line_to(Coord2(20.0, 0.0))
+        .fillet(100.0)
+        .line_to(Coord2(20.0, 20.0))
+        .build();
+
+    let points = triangle.points().collect::<Vec<_>>();
+    assert!(points.len() == 3);
+
+    // The arc can only use up half of each line
+    assert!(points[0].2.distance_to(&Coord2(10.0, 0.0)) < 1e-6);
+    assert!(points[1].2.distance_to(&Coord2(20.0, 10.0)) < 1e-6);
+    assert!(points[2].2.distance_to(&Coord2(20.0, 20.0)) < 1e-6);
+}
+
+#[test]
+fn chamfer_corner() {
+    let path = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(0.0, 0.0))
+        .line_to(Coord2(100.0, 0.0))
+        .chamfer(5.0)
+        .line_to(Coord2(100.0, 100.0))
+        .build();
+
+    let curves = path_to_curves::<_, Curve<_>>(&path).collect::<Vec<_>>();
+    assert!(curves.len() == 3);
+    assert!(curves[1].start_point().distance_to(&Coord2(95.0, 0.0)) < 1e-6);
+    assert!(curves[1].end_point().distance_to(&Coord2(100.0, 5.0)) < 1e-6);
+    assert!(curves[1].point_at_pos(0.5).distance_to(&Coord2(97.5, 2.5)) < 0.01);
+}
+
+#[test]
+fn corners_next_to_curves_are_not_rounded() {
+    let path = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(0.0, 0.0))
+        .line_to_rounded(Coord2(100.0, 0.0), 10.0)
+        .curve_to(
+            (Coord2(150.0, 20.0), Coord2(120.0, 80.0)),
+            Coord2(100.0, 100.0),
+        )
+        .build();
+
+    let points = path.points().collect::<Vec<_>>();
+    assert!(points.len() == 2);
+    assert!(points[0].2 == Coord2(100.0, 0.0));
+}
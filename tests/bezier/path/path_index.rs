@@ -0,0 +1,129 @@
+use flo_curves::arc::Circle;
+use flo_curves::bezier::path::{
+    add_path_to_index, nearest_curves, path_index, path_ray_intersections, path_to_curves,
+    ray_cast_paths, SimpleBezierPath,
+};
+use flo_curves::bezier::{distance_to_curve, Curve};
+use flo_curves::line::SimpleRay;
+use flo_curves::{Coord2, Coordinate, Coordinate2D};
+
+#[test]
+fn ray_cast_many_circles() {
+    // A row of circles along the x axis
+    let circles = (0..100)
+        .map(|idx| Circle::new(Coord2((idx as f64) * 10.0, 0.0), 4.0).to_path::<SimpleBezierPath>())
+        .collect::<Vec<_>>();
+    let index = path_index(&circles);
+
+    // Vertical ray through the center of circle 42
+    let ray = (Coord2(420.0, -100.0), Coord2(420.0, 100.0));
+    let hits = ray_cast_paths(&index, &ray);
+
+    let expected = path_ray_intersections(&circles[42], &SimpleRay::through(ray.0, ray.1)).count();
+    assert!(expected >= 2);
+    assert!(hits.len() == expected);
+    assert!(hits.iter().all(|(path_id, _, _, _)| *path_id == 42));
+
+    // First hit is the bottom of the circle, as it's nearest the start of the ray
+    assert!(hits[0].3.distance_to(&Coord2(420.0, -4.0)) < 0.01);
+    assert!(hits[hits.len() - 1].3.distance_to(&Coord2(420.0, 4.0)) < 0.01);
+}
+
+#[test]
+fn ray_cast_along_row_of_circles() {
+    let circles = (0..10)
+        .map(|idx| Circle::new(Coord2((idx as f64) * 10.0, 0.0), 4.0).to_path::<SimpleBezierPath>())
+        .collect::<Vec<_>>();
+    let index = path_index(&circles);
+
+    // Horizontal ray passes through every circle, and the hits should be in order along the ray
+    let ray = (Coord2(-100.0, 0.5), Coord2(-90.0, 0.5));
+    let hits = ray_cast_paths(&index, &ray);
+
+    let expected = circles
+        .iter()
+        .map(|circle| path_ray_intersections(circle, &SimpleRay::through(ray.0, ray.1)).count())
+        .sum::<usize>();
+    assert!(hits.len() == expected);
+
+    for hit_pair in hits.windows(2) {
+        assert!(hit_pair[0].3.x() <= hit_pair[1].3.x());
+    }
+}
+
+#[test]
+fn ray_cast_misses_removed_path() {
+    let circle1 = Circle::new(Coord2(0.0, 0.0), 4.0).to_path::<SimpleBezierPath>();
+    let circle2 = Circle::new(Coord2(20.0, 0.0), 4.0).to_path::<SimpleBezierPath>();
+
+    let mut index = path_index(vec![&circle1]);
+    let circle2_ids = add_path_to_index(&mut index, 7, &circle2);
+
+    let ray = (Coord2(-10.0, 0.5), Coord2(30.0, 0.5));
+    assert!(ray_cast_paths(&index, &ray)
+        .iter()
+        .any(|(path_id, _, _, _)| *path_id == 7));
+
+    for id in circle2_ids {
+        index.remove(id);
+    }
+
+    let hits = ray_cast_paths(&index, &ray);
+    assert!(!hits.is_empty());
+    assert!(hits.iter().all(|(path_id, _, _, _)| *path_id == 0));
+}
+
+#[test]
+fn nearest_curves_in_grid_of_circles() {
+    // A grid of circles, 10 units apart
+    let circles = (0..400)
+        .map(|idx| {
+            let (x, y) = ((idx % 20) as f64, (idx / 20) as f64);
+            Circle::new(Coord2(x * 10.0, y * 10.0), 4.0).to_path::<SimpleBezierPath>()
+        })
+        .collect::<Vec<_>>();
+    let index = path_index(&circles);
+
+    // Point just outside the circle at (50, 50)
+    let point = Coord2(54.5, 50.0);
+    let nearest = nearest_curves(&index, &point, 3);
+
+    assert!(nearest.len() == 3);
+    assert!(nearest.windows(2).all(|pair| pair[0].4 <= pair[1].4));
+
+    let (path_id, _section, _t, nearest_point, distance) = nearest[0];
+    assert!(path_id == 5 * 20 + 5);
+    assert!((distance - 0.5).abs() < 0.01);
+    assert!(nearest_point.is_near_to(&Coord2(54.0, 50.0), 0.01));
+    assert!((nearest_point.distance_to(&point) - distance).abs() < 1e-9);
+}
+
+#[test]
+fn nearest_curves_matches_brute_force() {
+    let circles = (0..50)
+        .map(|idx| {
+            Circle::new(
+                Coord2((idx as f64) * 7.0, ((idx * 3) % 11) as f64 * 5.0),
+                3.0,
+            )
+            .to_path::<SimpleBezierPath>()
+        })
+        .collect::<Vec<_>>();
+    let index = path_index(&circles);
+    let point = Coord2(100.0, 20.0);
+
+    let nearest = nearest_curves(&index, &point, 5);
+
+    // Measure the distance to every curve in every path
+    let mut all_distances = circles
+        .iter()
+        .flat_map(path_to_curves::<_, Curve<_>>)
+        .map(|curve| distance_to_curve(&curve, &point))
+        .collect::<Vec<_>>();
+    all_distances.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+    for (found, expected) in nearest.iter().zip(all_distances.iter()) {
+        assert!((found.4 - expected).abs() < 1e-9);
+    }
+    assert!(nearest_curves(&index, &point, 1000).len() == 200);
+}
@@ -1,5 +1,5 @@
 use flo_curves::arc::Circle;
-use flo_curves::bezier::path::{path_contains_point, SimpleBezierPath};
+use flo_curves::bezier::path::{path_contains_point, path_stroke_contains_point, SimpleBezierPath};
 use flo_curves::Coord2;
 
 #[test]
@@ -133,3 +133,55 @@ fn crossing_first_point_leaves_us_outside_circle() {
     // This line crosses the first point of the circle (which can appear as a crossing at both the start and end of the path, which might fool the algorithm into thinking the point is inside)
     assert!(!path_contains_point(&path, &Coord2(1.5, 1.5)));
 }
+
+#[test]
+fn stroke_contains_point_near_outline() {
+    let circle = Circle::new(Coord2(5.0, 5.0), 4.0).to_path::<SimpleBezierPath>();
+
+    // Just inside and outside the outline, within the stroke
+    assert!(path_stroke_contains_point(&circle, &Coord2(5.0, 9.4), 1.0));
+    assert!(path_stroke_contains_point(&circle, &Coord2(5.0, 8.6), 1.0));
+    assert!(path_stroke_contains_point(&circle, &Coord2(1.4, 5.0), 1.0));
+}
+
+#[test]
+fn stroke_does_not_contain_center() {
+    // The center is inside the path, but not inside the stroke
+    let circle = Circle::new(Coord2(5.0, 5.0), 4.0).to_path::<SimpleBezierPath>();
+
+    assert!(path_contains_point(&circle, &Coord2(5.0, 5.0)));
+    assert!(!path_stroke_contains_point(&circle, &Coord2(5.0, 5.0), 1.0));
+    assert!(!path_stroke_contains_point(&circle, &Coord2(5.0, 8.0), 1.0));
+    assert!(!path_stroke_contains_point(
+        &circle,
+        &Coord2(5.0, 10.0),
+        1.0
+    ));
+    assert!(!path_stroke_contains_point(
+        &circle,
+        &Coord2(20.0, 20.0),
+        1.0
+    ));
+}
+
+#[test]
+fn wide_stroke_contains_center() {
+    let circle = Circle::new(Coord2(5.0, 5.0), 4.0).to_path::<SimpleBezierPath>();
+
+    assert!(path_stroke_contains_point(&circle, &Coord2(5.0, 5.0), 8.1));
+}
+
+#[test]
+fn circle_contains_point_on_diagonal() {
+    // The ray to this point passes through the vertex where the circle starts and ends
+    let circle = Circle::new(Coord2(0.0, 0.0), 10.0).to_path::<SimpleBezierPath>();
+
+    assert!(path_contains_point(
+        &circle,
+        &Coord2(3.5355339059327378, 3.5355339059327373)
+    ));
+    assert!(path_contains_point(
+        &circle,
+        &Coord2(-3.5355339059327373, -3.5355339059327378)
+    ));
+}
@@ -0,0 +1,89 @@
+use flo_curves::arc::Circle;
+use flo_curves::bezier::path::{
+    path_signed_area, path_to_polygon, polygon_to_path, BezierPathBuilder, Polygon,
+    SimpleBezierPath,
+};
+use flo_curves::Coord2;
+
+#[test]
+fn polygon_rings_are_oriented() {
+    // Clockwise exterior, anticlockwise hole
+    let polygon = Polygon::new(
+        vec![
+            Coord2(0.0, 0.0),
+            Coord2(0.0, 10.0),
+            Coord2(10.0, 10.0),
+            Coord2(10.0, 0.0),
+        ],
+        vec![vec![
+            Coord2(2.0, 2.0),
+            Coord2(4.0, 2.0),
+            Coord2(4.0, 4.0),
+            Coord2(2.0, 4.0),
+        ]],
+    );
+
+    assert!(polygon.exterior[1] == Coord2(10.0, 0.0));
+    assert!(polygon.holes[0][1] == Coord2(2.0, 4.0));
+    assert!((polygon.area() - 96.0).abs() < 0.0001);
+    assert!(polygon.rings().count() == 2);
+}
+
+#[test]
+fn circle_with_hole_to_polygon() {
+    let outer = Circle::new(Coord2(0.0, 0.0), 10.0).to_path::<SimpleBezierPath>();
+    let inner = Circle::new(Coord2(0.0, 0.0), 5.0).to_path::<SimpleBezierPath>();
+    let island = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(-1.0, -1.0))
+        .line_to(Coord2(1.0, -1.0))
+        .line_to(Coord2(1.0, 1.0))
+        .line_to(Coord2(-1.0, 1.0))
+        .line_to(Coord2(-1.0, -1.0))
+        .build();
+
+    let polygons = path_to_polygon(&[inner, island, outer], 0.01);
+
+    // The island inside the hole is a separate polygon
+    assert!(polygons.len() == 2);
+    assert!(polygons[0].holes.len() == 1);
+    assert!(polygons[1].holes.is_empty());
+    assert!(polygons[1].exterior.len() == 4);
+
+    let ring_area = std::f64::consts::PI * (100.0 - 25.0);
+    assert!((polygons[0].area() - ring_area).abs() < 0.5);
+    assert!((polygons[1].area() - 4.0).abs() < 0.0001);
+
+    // Every vertex is (close to) one of the circles
+    for hole_point in polygons[0].holes[0].iter() {
+        let distance = (hole_point.0 * hole_point.0 + hole_point.1 * hole_point.1).sqrt();
+        assert!((distance - 5.0).abs() < 0.02);
+    }
+}
+
+#[test]
+fn polygon_to_path_and_back() {
+    let polygon = Polygon::new(
+        vec![
+            Coord2(0.0, 0.0),
+            Coord2(10.0, 0.0),
+            Coord2(10.0, 10.0),
+            Coord2(0.0, 10.0),
+        ],
+        vec![vec![
+            Coord2(2.0, 2.0),
+            Coord2(2.0, 4.0),
+            Coord2(4.0, 4.0),
+            Coord2(4.0, 2.0),
+        ]],
+    );
+
+    let paths = polygon_to_path::<SimpleBezierPath>(&polygon);
+    assert!(paths.len() == 2);
+    assert!((path_signed_area(&paths[0]) - 100.0).abs() < 0.0001);
+    assert!((path_signed_area(&paths[1]) + 4.0).abs() < 0.0001);
+
+    let round_trip = path_to_polygon(&paths, 0.01);
+    assert!(round_trip.len() == 1);
+    assert!((round_trip[0].area() - polygon.area()).abs() < 0.0001);
+    assert!(round_trip[0].exterior.len() == 4);
+    assert!(round_trip[0].holes[0].len() == 4);
+}
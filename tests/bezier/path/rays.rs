@@ -5,6 +5,8 @@ use flo_curves::bezier::{
     curve_intersects_ray, BezierCurveFactory, BoundingBox, Coord2, Coordinate, Curve,
 };
 
+use flo_curves::line::SimpleRay;
+
 use std::collections::HashMap;
 
 #[test]
@@ -762,3 +764,27 @@ fn ray_glancing_2_reversed() {
 
     assert!(edge_collisions.into_iter().all(|(_, count)| count == 1));
 }
+
+#[test]
+fn collisions_with_explicit_ray() {
+    let square = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(1.0, 1.0))
+        .line_to(Coord2(5.0, 1.0))
+        .line_to(Coord2(5.0, 5.0))
+        .line_to(Coord2(1.0, 5.0))
+        .line_to(Coord2(1.0, 1.0))
+        .build();
+    let graph_path = GraphPath::from_path(&square, ());
+
+    let ray = SimpleRay::new(Coord2(0.0, 2.0), Coord2(2.0, 0.0));
+    let collisions = graph_path.collisions_with_ray(&ray);
+    let line_collisions = graph_path.ray_collisions(&(Coord2(0.0, 2.0), Coord2(2.0, 2.0)));
+
+    assert!(collisions.len() == 2);
+    assert!(collisions == line_collisions);
+
+    // Ray t values are measured using the direction of the ray
+    let mut ray_t = collisions.iter().map(|(_, _, t, _)| *t).collect::<Vec<_>>();
+    ray_t.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    assert!((ray_t[0] - 0.5).abs() < 0.01);
+    assert!((ray_t[1] - 2.5).abs() < 0.01);
+}
@@ -0,0 +1,146 @@
+use flo_curves::arc::Circle;
+use flo_curves::bezier::path::{
+    path_ribbon, path_to_curves, BezierPath, BezierPathBuilder, PathRibbon, SimpleBezierPath,
+};
+use flo_curves::bezier::{nearest_point_on_curve, Curve};
+use flo_curves::{BezierCurve, Coord2, Coordinate, Coordinate2D};
+
+use std::f64;
+
+///
+/// Returns the distance from a point to the nearest point on a path
+///
+fn distance_to_path(path: &SimpleBezierPath, point: &Coord2) -> f64 {
+    path_to_curves::<_, Curve<_>>(path)
+        .map(|curve| nearest_point_on_curve(&curve, point).1.distance_to(point))
+        .fold(f64::MAX, f64::min)
+}
+
+#[test]
+fn ribbon_along_straight_line() {
+    let line = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(0.0, 0.0))
+        .line_to(Coord2(100.0, 0.0))
+        .build();
+
+    let ribbon: PathRibbon<SimpleBezierPath> = path_ribbon(&line, 5.0, Some(10.0), 0.01);
+
+    // The normal points to the left of the direction of the line
+    assert!(
+        ribbon
+            .left_rail
+            .start_point()
+            .distance_to(&Coord2(0.0, 5.0))
+            < 1e-6
+    );
+    assert!(
+        ribbon
+            .right_rail
+            .start_point()
+            .distance_to(&Coord2(0.0, -5.0))
+            < 1e-6
+    );
+    assert!(
+        ribbon
+            .left_rail
+            .points()
+            .last()
+            .unwrap()
+            .2
+            .distance_to(&Coord2(100.0, 5.0))
+            < 1e-6
+    );
+
+    assert!(ribbon.rungs.len() == 11);
+    for (idx, rung) in ribbon.rungs.iter().enumerate() {
+        let x = (idx as f64) * 10.0;
+        let end_point = rung.points().last().unwrap().2;
+
+        assert!(rung.start_point().distance_to(&Coord2(x, 5.0)) < 1e-6);
+        assert!(end_point.distance_to(&Coord2(x, -5.0)) < 1e-6);
+    }
+}
+
+#[test]
+fn ribbon_without_rungs() {
+    let line = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(0.0, 0.0))
+        .line_to(Coord2(100.0, 0.0))
+        .build();
+
+    let ribbon: PathRibbon<SimpleBezierPath> = path_ribbon(&line, 5.0, None, 0.01);
+
+    assert!(ribbon.rungs.is_empty());
+    assert!(ribbon.left_rail.points().count() >= 1);
+}
+
+#[test]
+fn ribbon_with_invalid_rung_spacing() {
+    let line = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(0.0, 0.0))
+        .line_to(Coord2(100.0, 0.0))
+        .build();
+
+    for rung_spacing in [0.0, -10.0, f64::NAN, f64::INFINITY] {
+        let ribbon: PathRibbon<SimpleBezierPath> =
+            path_ribbon(&line, 5.0, Some(rung_spacing), 0.01);
+
+        assert!(ribbon.rungs.len() == 1);
+        assert!(ribbon.rungs[0].start_point().distance_to(&Coord2(0.0, 5.0)) < 1e-6);
+    }
+}
+
+#[test]
+fn rungs_meet_rails_on_curve() {
+    let arc = Circle::new(Coord2(50.0, 50.0), 40.0)
+        .arc(0.0, f64::consts::PI)
+        .to_path::<SimpleBezierPath>(0.001);
+
+    let ribbon: PathRibbon<SimpleBezierPath> = path_ribbon(&arc, 4.0, Some(7.0), 0.01);
+
+    assert!(ribbon.rungs.len() > 10);
+
+    for rung in ribbon.rungs.iter() {
+        let start_point = rung.start_point();
+        let end_point = rung.points().last().unwrap().2;
+
+        // The ends of the rungs are exactly on the rails
+        assert!(distance_to_path(&ribbon.left_rail, &start_point) < 1e-6);
+        assert!(distance_to_path(&ribbon.right_rail, &end_point) < 1e-6);
+
+        // Both rails are close to the true offset of the arc (which runs clockwise, so the left rail is on the outside)
+        assert!((start_point.distance_to(&Coord2(50.0, 50.0)) - 44.0).abs() < 0.02);
+        assert!((end_point.distance_to(&Coord2(50.0, 50.0)) - 36.0).abs() < 0.02);
+    }
+}
+
+#[test]
+fn ribbon_around_corner() {
+    let corner = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(0.0, 0.0))
+        .line_to(Coord2(50.0, 0.0))
+        .line_to(Coord2(50.0, 50.0))
+        .build();
+
+    let ribbon: PathRibbon<SimpleBezierPath> = path_ribbon(&corner, 5.0, None, 0.01);
+
+    // The rails should be continuous
+    for rail in [&ribbon.left_rail, &ribbon.right_rail] {
+        let curves = path_to_curves::<_, Curve<_>>(rail).collect::<Vec<_>>();
+        for pair in curves.windows(2) {
+            assert!(pair[0].end_point().distance_to(&pair[1].start_point()) < 1e-6);
+        }
+    }
+
+    // The path turns left, so the left rail is trimmed on the inside of the corner and the right rail goes around it
+    assert!(distance_to_path(&ribbon.left_rail, &Coord2(45.0, 5.0)) < 0.01);
+    assert!(distance_to_path(&ribbon.right_rail, &Coord2(50.0, 0.0)) > 4.99);
+    assert!(distance_to_path(&ribbon.right_rail, &Coord2(50.0, 0.0)) < 5.01);
+
+    // Neither rail comes closer to the path than the half-width
+    for rail in [&ribbon.left_rail, &ribbon.right_rail] {
+        for curve in path_to_curves::<_, Curve<_>>(rail) {
+            for t in 0..=10 {
+                let point = curve.point_at_pos((t as f64) / 10.0);
+                assert!(distance_to_path(&corner, &point) > 4.99);
+                assert!(point.x() < 55.01 && point.y() > -5.01);
+            }
+        }
+    }
+}
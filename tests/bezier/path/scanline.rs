@@ -0,0 +1,252 @@
+use flo_curves::arc::Circle;
+use flo_curves::bezier::path::{
+    path_contains_point, path_contains_points, path_scanline_intersections,
+    path_scanline_range_intersections, path_winding_numbers, BezierPath, BezierPathBuilder,
+    ScanlineDirection, SimpleBezierPath,
+};
+use flo_curves::Coord2;
+
+fn square() -> SimpleBezierPath {
+    BezierPathBuilder::<SimpleBezierPath>::start(Coord2(0.0, 0.0))
+        .line_to(Coord2(10.0, 0.0))
+        .line_to(Coord2(10.0, 10.0))
+        .line_to(Coord2(0.0, 10.0))
+        .line_to(Coord2(0.0, 0.0))
+        .build()
+}
+
+#[test]
+fn scanline_through_square() {
+    let intersections = path_scanline_intersections(&square(), 5.0);
+
+    assert!(
+        intersections
+            == vec![
+                (0.0, ScanlineDirection::Downwards),
+                (10.0, ScanlineDirection::Upwards)
+            ]
+    );
+}
+
+#[test]
+fn scanline_through_clockwise_square() {
+    let square = square().reversed::<SimpleBezierPath>();
+    let intersections = path_scanline_intersections(&square, 5.0);
+
+    assert!(
+        intersections
+            == vec![
+                (0.0, ScanlineDirection::Upwards),
+                (10.0, ScanlineDirection::Downwards)
+            ]
+    );
+}
+
+#[test]
+fn scanline_along_edges() {
+    // The bottom edge is on the scanline, so the square is entirely above it
+    assert!(path_scanline_intersections(&square(), 0.0).is_empty());
+
+    // The top edge is on the scanline: the sides cross it as they reach it
+    let intersections = path_scanline_intersections(&square(), 10.0);
+    assert!(intersections.len() == 2);
+    assert!(intersections[0].0 == 0.0);
+    assert!(intersections[1].0 == 10.0);
+}
+
+#[test]
+fn scanline_through_vertex_counts_once() {
+    let diamond = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(5.0, 0.0))
+        .line_to(Coord2(10.0, 5.0))
+        .line_to(Coord2(5.0, 10.0))
+        .line_to(Coord2(0.0, 5.0))
+        .line_to(Coord2(5.0, 0.0))
+        .build();
+
+    let intersections = path_scanline_intersections(&diamond, 5.0);
+
+    assert!(
+        intersections
+            == vec![
+                (0.0, ScanlineDirection::Downwards),
+                (10.0, ScanlineDirection::Upwards)
+            ]
+    );
+}
+
+#[test]
+fn scanline_touching_peak_cancels_out() {
+    let triangle = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(0.0, 0.0))
+        .line_to(Coord2(10.0, 0.0))
+        .line_to(Coord2(5.0, 10.0))
+        .line_to(Coord2(0.0, 0.0))
+        .build();
+
+    let intersections = path_scanline_intersections(&triangle, 10.0);
+    let winding: i32 = intersections
+        .iter()
+        .map(|(_, direction)| direction.winding())
+        .sum();
+
+    assert!(intersections.len().is_multiple_of(2));
+    assert!(winding == 0);
+    assert!(intersections.iter().all(|(x, _)| *x == 5.0));
+}
+
+#[test]
+fn scanline_through_circle() {
+    let circle = Circle::new(Coord2(5.0, 5.0), 4.0).to_path::<SimpleBezierPath>();
+    let intersections = path_scanline_intersections(&circle, 5.5);
+
+    assert!(intersections.len() == 2);
+
+    let expected = 5.0 + (16.0f64 - 0.25).sqrt();
+    assert!((intersections[0].0 - (10.0 - expected)).abs() < 0.05);
+    assert!((intersections[1].0 - expected).abs() < 0.05);
+    assert!(intersections[0].1 != intersections[1].1);
+}
+
+#[test]
+fn scanline_spans_match_contains_point() {
+    let path = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(0.0, 0.0))
+        .curve_to(
+            (Coord2(20.0, -10.0), Coord2(30.0, 30.0)),
+            Coord2(20.0, 20.0),
+        )
+        .curve_to((Coord2(15.0, 15.0), Coord2(10.0, 40.0)), Coord2(5.0, 20.0))
+        .curve_to((Coord2(0.0, 10.0), Coord2(-10.0, 5.0)), Coord2(0.0, 0.0))
+        .build();
+
+    for y_idx in 0..20 {
+        let y = -2.25 + (y_idx as f64) * 1.5;
+        let intersections = path_scanline_intersections(&path, y);
+
+        assert!(intersections.len().is_multiple_of(2));
+
+        // The midpoint of every other span should be inside the path
+        for (idx, span) in intersections.windows(2).enumerate() {
+            if span[1].0 - span[0].0 < 0.1 {
+                continue;
+            }
+
+            let midpoint = Coord2((span[0].0 + span[1].0) * 0.5, y);
+            assert!(path_contains_point(&path, &midpoint) == (idx % 2 == 0));
+        }
+    }
+}
+
+#[test]
+fn scanline_range_matches_single_scanlines() {
+    let circle = Circle::new(Coord2(5.0, 5.0), 4.0).to_path::<SimpleBezierPath>();
+    let range = path_scanline_range_intersections(&circle, 0.5, 1.0, 10);
+
+    assert!(range.len() == 10);
+    for (idx, intersections) in range.iter().enumerate() {
+        let y = 0.5 + (idx as f64);
+        assert!(*intersections == path_scanline_intersections(&circle, y));
+    }
+
+    assert!(range[0].is_empty());
+    assert!(range[5].len() == 2);
+}
+
+#[test]
+fn winding_numbers_for_square() {
+    let points = [
+        Coord2(5.0, 5.0),
+        Coord2(-5.0, 5.0),
+        Coord2(15.0, 5.0),
+        Coord2(5.0, 15.0),
+        Coord2(2.0, 5.0),
+    ];
+
+    assert!(path_winding_numbers(&square(), &points) == vec![1, 0, 0, 0, 1]);
+
+    let clockwise = square().reversed::<SimpleBezierPath>();
+    assert!(path_winding_numbers(&clockwise, &points) == vec![-1, 0, 0, 0, -1]);
+}
+
+#[test]
+fn winding_numbers_for_overlapping_loops() {
+    // Two anticlockwise squares joined into one path, overlapping in the middle
+    let path = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(0.0, 0.0))
+        .line_to(Coord2(10.0, 0.0))
+        .line_to(Coord2(10.0, 10.0))
+        .line_to(Coord2(0.0, 10.0))
+        .line_to(Coord2(0.0, 0.0))
+        .line_to(Coord2(5.0, 0.0))
+        .line_to(Coord2(15.0, 0.0))
+        .line_to(Coord2(15.0, 10.0))
+        .line_to(Coord2(5.0, 10.0))
+        .line_to(Coord2(5.0, 0.0))
+        .line_to(Coord2(0.0, 0.0))
+        .build();
+
+    let points = [
+        Coord2(2.5, 5.0),
+        Coord2(7.5, 5.0),
+        Coord2(12.5, 5.0),
+        Coord2(20.0, 5.0),
+    ];
+
+    assert!(path_winding_numbers(&path, &points) == vec![1, 2, 1, 0]);
+    assert!(path_contains_points(&path, &points) == vec![true, true, true, false]);
+}
+
+#[test]
+fn winding_numbers_for_non_finite_points() {
+    let points = [
+        Coord2(5.0, f64::NAN),
+        Coord2(5.0, 5.0),
+        Coord2(f64::NAN, 5.0),
+        Coord2(f64::INFINITY, f64::NEG_INFINITY),
+        Coord2(2.0, 5.0),
+    ];
+
+    assert!(path_winding_numbers(&square(), &points) == vec![0, 1, 0, 0, 1]);
+}
+
+#[test]
+fn contains_points_matches_contains_point() {
+    let path = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(0.0, 0.0))
+        .curve_to(
+            (Coord2(20.0, -10.0), Coord2(30.0, 30.0)),
+            Coord2(20.0, 20.0),
+        )
+        .curve_to((Coord2(15.0, 15.0), Coord2(10.0, 40.0)), Coord2(5.0, 20.0))
+        .curve_to((Coord2(0.0, 10.0), Coord2(-10.0, 5.0)), Coord2(0.0, 0.0))
+        .build();
+
+    // A grid of points (which share scanlines) followed by some scattered points (which don't)
+    let mut points = vec![];
+    for y_idx in 0..40 {
+        for x_idx in 0..40 {
+            points.push(Coord2(
+                -5.125 + (x_idx as f64) * 0.875,
+                -5.125 + (y_idx as f64) * 0.875,
+            ));
+        }
+    }
+    for idx in 0..500 {
+        let t = idx as f64;
+        points.push(Coord2(
+            12.0 + (t * 0.7).sin() * 17.0,
+            14.0 + (t * 1.3).cos() * 17.0,
+        ));
+    }
+
+    let contains = path_contains_points(&path, &points);
+
+    assert!(contains.len() == points.len());
+    assert!(contains.iter().any(|inside| *inside));
+    assert!(contains.iter().any(|inside| !*inside));
+
+    for (point, inside) in points.iter().zip(contains.iter()) {
+        assert!(path_contains_point(&path, point) == *inside);
+    }
+}
+
+#[test]
+fn contains_no_points() {
+    assert!(path_contains_points(&square(), &[]).is_empty());
+}
@@ -0,0 +1,162 @@
+use flo_curves::arc::Circle;
+use flo_curves::bezier::path::{
+    path_contains_point, path_to_curves, smooth_path, BezierPath, BezierPathBuilder,
+    BezierPathFactory, Continuity, SimpleBezierPath,
+};
+use flo_curves::bezier::{distance_to_curve, BezierCurve, Curve};
+use flo_curves::{Coord2, Coordinate, Coordinate2D};
+
+///
+/// The largest distance between any point on one path and the nearest point on another
+///
+fn max_distance(from: &SimpleBezierPath, to: &SimpleBezierPath) -> f64 {
+    let to_curves = path_to_curves::<_, Curve<_>>(to).collect::<Vec<_>>();
+
+    path_to_curves::<_, Curve<_>>(from)
+        .flat_map(|curve| (0..=20).map(move |step| curve.point_at_pos((step as f64) / 20.0)))
+        .map(|point| {
+            to_curves
+                .iter()
+                .map(|curve| distance_to_curve(curve, &point))
+                .fold(f64::MAX, f64::min)
+        })
+        .fold(0.0, f64::max)
+}
+
+fn cross(a: Coord2, b: Coord2) -> f64 {
+    a.x() * b.y() - a.y() * b.x()
+}
+
+///
+/// The signed curvature at the start and end of a curve
+///
+fn end_curvatures(curve: &Curve<Coord2>) -> (f64, f64) {
+    let (w1, (w2, w3), w4) = (
+        curve.start_point(),
+        curve.control_points(),
+        curve.end_point(),
+    );
+
+    let start = (2.0 / 3.0) * cross(w2 - w1, w3 - w2) / (w2 - w1).magnitude().powi(3);
+    let end = (2.0 / 3.0) * cross(w3 - w2, w4 - w3) / (w4 - w3).magnitude().powi(3);
+
+    (start, end)
+}
+
+///
+/// The cosine of the angle between the curves at each join in a path
+///
+fn join_cos_angles(path: &SimpleBezierPath) -> Vec<f64> {
+    let curves = path_to_curves::<_, Curve<_>>(path).collect::<Vec<_>>();
+
+    curves
+        .windows(2)
+        .map(|pair| {
+            let incoming = (pair[0].end_point() - pair[0].control_points().1).to_unit_vector();
+            let outgoing = (pair[1].control_points().0 - pair[1].start_point()).to_unit_vector();
+
+            incoming.dot(&outgoing)
+        })
+        .collect()
+}
+
+fn kinked_path() -> SimpleBezierPath {
+    SimpleBezierPath::from_points(
+        Coord2(0.0, 0.0),
+        vec![
+            (Coord2(3.0, 0.0), Coord2(7.0, 0.0), Coord2(10.0, 0.0)),
+            (Coord2(13.0, 0.6), Coord2(17.0, 1.6), Coord2(20.0, 2.0)),
+        ],
+    )
+}
+
+#[test]
+fn smooth_gentle_kink() {
+    let path = kinked_path();
+    let smoothed: SimpleBezierPath = smooth_path(&path, Continuity::G1, 0.5);
+
+    assert!(smoothed.1.len() == 2);
+    assert!(smoothed.start_point() == path.start_point());
+    assert!(smoothed.1[1].2 == path.1[1].2);
+    assert!(join_cos_angles(&smoothed)[0] > 1.0 - 1e-9);
+    assert!(max_distance(&smoothed, &path) <= 0.5);
+    assert!(max_distance(&path, &smoothed) <= 0.5);
+}
+
+#[test]
+fn smooth_kink_with_small_tolerance() {
+    let path = kinked_path();
+    let smoothed: SimpleBezierPath = smooth_path(&path, Continuity::G1, 0.01);
+
+    // The curves are divided so the adjustment can be made close to the join
+    assert!(smoothed.1.len() > 2);
+    assert!(max_distance(&smoothed, &path) <= 0.01);
+    assert!(max_distance(&path, &smoothed) <= 0.01);
+
+    // Every join is now smooth (the new joins were already smooth as they're the result of subdividing)
+    for cos_angle in join_cos_angles(&smoothed) {
+        assert!(cos_angle > 1.0 - 1e-6, "{}", cos_angle);
+    }
+}
+
+#[test]
+fn smooth_square_corners() {
+    let square = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(0.0, 0.0))
+        .line_to(Coord2(10.0, 0.0))
+        .line_to(Coord2(10.0, 10.0))
+        .line_to(Coord2(0.0, 10.0))
+        .line_to(Coord2(0.0, 0.0))
+        .build();
+
+    let smoothed: SimpleBezierPath = smooth_path(&square, Continuity::G1, 0.1);
+
+    assert!(max_distance(&smoothed, &square) <= 0.1);
+    assert!(max_distance(&square, &smoothed) <= 0.1);
+    assert!(path_contains_point(&smoothed, &Coord2(5.0, 5.0)));
+
+    // The join between the end and the start is also smoothed
+    let mut closed = smoothed.clone();
+    closed.1.push(closed.1[0]);
+    let closed = SimpleBezierPath::from_points(smoothed.1.last().unwrap().2, closed.1);
+
+    for cos_angle in join_cos_angles(&closed) {
+        assert!(cos_angle > 1.0 - 1e-6, "{}", cos_angle);
+    }
+}
+
+#[test]
+fn smooth_curvature() {
+    let path = SimpleBezierPath::from_points(
+        Coord2(0.0, 0.0),
+        vec![
+            (Coord2(3.0, 0.0), Coord2(6.0, 1.0), Coord2(8.0, 3.0)),
+            (Coord2(9.0, 4.0), Coord2(10.0, 6.0), Coord2(10.0, 9.0)),
+        ],
+    );
+
+    let curves = path_to_curves::<_, Curve<_>>(&path).collect::<Vec<_>>();
+    let (before_in, before_out) = (end_curvatures(&curves[0]).1, end_curvatures(&curves[1]).0);
+    assert!((before_in - before_out).abs() > 0.01);
+
+    let smoothed: SimpleBezierPath = smooth_path(&path, Continuity::G2, 0.5);
+    let curves = path_to_curves::<_, Curve<_>>(&smoothed).collect::<Vec<_>>();
+    let (after_in, after_out) = (end_curvatures(&curves[0]).1, end_curvatures(&curves[1]).0);
+
+    assert!(
+        (after_in - after_out).abs() < 1e-3 * after_in.abs(),
+        "{} {}",
+        after_in,
+        after_out
+    );
+    assert!(join_cos_angles(&smoothed)[0] > 1.0 - 1e-9);
+    assert!(max_distance(&smoothed, &path) <= 0.5);
+}
+
+#[test]
+fn smooth_path_leaves_smooth_path_alone() {
+    let circle = Circle::new(Coord2(5.0, 5.0), 4.0).to_path::<SimpleBezierPath>();
+    let smoothed: SimpleBezierPath = smooth_path(&circle, Continuity::G1, 0.1);
+
+    assert!(smoothed.1.len() == circle.1.len());
+    assert!(max_distance(&smoothed, &circle) < 1e-6);
+}
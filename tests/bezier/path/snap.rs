@@ -0,0 +1,98 @@
+use flo_curves::arc::Circle;
+use flo_curves::bezier::path::{
+    path_index, snap_to_paths, BezierPathBuilder, SimpleBezierPath, SnapKind,
+};
+use flo_curves::{Coord2, Coordinate};
+
+fn square(x: f64, y: f64, size: f64) -> SimpleBezierPath {
+    BezierPathBuilder::<SimpleBezierPath>::start(Coord2(x, y))
+        .line_to(Coord2(x + size, y))
+        .line_to(Coord2(x + size, y + size))
+        .line_to(Coord2(x, y + size))
+        .line_to(Coord2(x, y))
+        .build()
+}
+
+#[test]
+fn snap_to_corner() {
+    let index = path_index(&[square(0.0, 0.0, 10.0)]);
+
+    let targets = snap_to_paths(&index, &Coord2(10.3, 9.8), 1.0, None);
+
+    assert!(targets[0].kind == SnapKind::EndPoint);
+    assert!(targets[0].position.is_near_to(&Coord2(10.0, 10.0), 1e-9));
+
+    // The corner is shared by two sections but is only reported once
+    let num_corners = targets
+        .iter()
+        .filter(|target| target.kind == SnapKind::EndPoint)
+        .count();
+    assert!(num_corners == 1);
+
+    // The nearest points on the two sides are also candidates
+    assert!(targets.iter().any(|target| target.kind == SnapKind::Nearest
+        && target.position.is_near_to(&Coord2(10.0, 9.8), 1e-6)));
+}
+
+#[test]
+fn snap_to_midpoint() {
+    let index = path_index(&[square(0.0, 0.0, 10.0)]);
+
+    let targets = snap_to_paths(&index, &Coord2(5.2, -0.3), 1.0, None);
+
+    assert!(targets[0].kind == SnapKind::MidPoint);
+    assert!(targets[0].position.is_near_to(&Coord2(5.0, 0.0), 0.01));
+    assert!(targets[0].section == 0);
+    assert!(targets.iter().all(|target| target.distance <= 1.0));
+}
+
+#[test]
+fn snap_to_intersection() {
+    let index = path_index(&[square(0.0, 0.0, 10.0), square(5.0, 3.0, 10.0)]);
+
+    // The squares cross at (10, 3)
+    let targets = snap_to_paths(&index, &Coord2(9.6, 3.3), 1.0, None);
+
+    assert!(targets[0].kind == SnapKind::Intersection);
+    assert!(targets[0].position.is_near_to(&Coord2(10.0, 3.0), 0.01));
+}
+
+#[test]
+fn nothing_to_snap_to() {
+    let index = path_index(&[square(0.0, 0.0, 10.0)]);
+
+    assert!(snap_to_paths(&index, &Coord2(5.0, 5.0), 1.0, None).is_empty());
+}
+
+#[test]
+fn snap_to_tangent_from_reference() {
+    let circle = Circle::new(Coord2(0.0, 0.0), 4.0).to_path::<SimpleBezierPath>();
+    let index = path_index(&[circle]);
+
+    // Lines from (10, 0) touch the circle at x = 16/10
+    let tangent_point = Coord2(1.6, (16.0f64 - 1.6 * 1.6).sqrt());
+    let targets = snap_to_paths(
+        &index,
+        &(tangent_point + Coord2(0.2, 0.2)),
+        0.5,
+        Some(&Coord2(10.0, 0.0)),
+    );
+
+    assert!(targets[0].kind == SnapKind::Tangent);
+    assert!(targets[0].position.is_near_to(&tangent_point, 0.01));
+}
+
+#[test]
+fn snap_to_perpendicular_from_reference() {
+    let index = path_index(&[square(0.0, 0.0, 10.0)]);
+
+    let targets = snap_to_paths(&index, &Coord2(3.2, 0.4), 1.0, Some(&Coord2(3.0, 20.0)));
+
+    assert!(targets[0].kind == SnapKind::Perpendicular);
+    assert!(targets[0].position.is_near_to(&Coord2(3.0, 0.0), 1e-6));
+
+    // Without a reference point, the nearest point is the best target
+    let targets = snap_to_paths(&index, &Coord2(3.2, 0.4), 1.0, None);
+    assert!(targets[0].kind == SnapKind::Nearest);
+    assert!(targets[0].position.is_near_to(&Coord2(3.2, 0.0), 1e-6));
+}
@@ -0,0 +1,208 @@
+use flo_curves::arc::Circle;
+use flo_curves::bezier::path::{
+    path_contains_point, path_signed_area, path_sweep, path_sweep_cross_sections, BezierPath,
+    BezierPathBuilder, BezierPathFactory, SimpleBezierPath, SweepOrientation,
+};
+use flo_curves::bezier::{BezierCurve, BezierCurveFactory, Curve};
+use flo_curves::{BoundingBox, Bounds, Coord2, Coord3, Coordinate};
+
+///
+/// A bezier path in three dimensions, for the spines and cross-sections of 3D sweeps
+///
+type Path3 = (Coord3, Vec<(Coord3, Coord3, Coord3)>);
+
+fn rectangle_profile(width: f64, height: f64) -> SimpleBezierPath {
+    let (x, y) = (width * 0.5, height * 0.5);
+
+    BezierPathBuilder::<SimpleBezierPath>::start(Coord2(-x, -y))
+        .line_to(Coord2(x, -y))
+        .line_to(Coord2(x, y))
+        .line_to(Coord2(-x, y))
+        .line_to(Coord2(-x, -y))
+        .build()
+}
+
+fn line_spine(start: Coord2, end: Coord2) -> SimpleBezierPath {
+    BezierPathBuilder::<SimpleBezierPath>::start(start)
+        .line_to(end)
+        .build()
+}
+
+#[test]
+fn sweep_square_along_line() {
+    let stroke: SimpleBezierPath = path_sweep(
+        &rectangle_profile(2.0, 2.0),
+        &line_spine(Coord2(0.0, 0.0), Coord2(10.0, 0.0)),
+        SweepOrientation::FollowSpine,
+        0.5,
+        0.01,
+    );
+
+    // Swept area is the rectangle from (-1, -1) to (11, 1), going clockwise
+    let area = path_signed_area(&stroke);
+    assert!((area + 24.0).abs() < 0.1, "{}", area);
+
+    assert!(path_contains_point(&stroke, &Coord2(5.0, 0.5)));
+    assert!(path_contains_point(&stroke, &Coord2(10.5, 0.0)));
+    assert!(path_contains_point(&stroke, &Coord2(-0.5, -0.5)));
+    assert!(!path_contains_point(&stroke, &Coord2(5.0, 1.5)));
+    assert!(!path_contains_point(&stroke, &Coord2(12.0, 0.0)));
+}
+
+#[test]
+fn sweep_follows_spine_direction() {
+    // A profile that is tall in y makes a wide stroke when the spine goes upwards
+    let stroke: SimpleBezierPath = path_sweep(
+        &rectangle_profile(0.2, 2.0),
+        &line_spine(Coord2(0.0, 0.0), Coord2(0.0, 10.0)),
+        SweepOrientation::FollowSpine,
+        0.5,
+        0.01,
+    );
+    let bounds = stroke.bounding_box::<Bounds<_>>();
+
+    assert!(bounds.min().is_near_to(&Coord2(-1.0, -0.1), 0.02));
+    assert!(bounds.max().is_near_to(&Coord2(1.0, 10.1), 0.02));
+}
+
+#[test]
+fn sweep_with_fixed_pen() {
+    // A fixed pen is tall in y, so makes thick horizontal lines and thin vertical ones
+    let pen = rectangle_profile(0.2, 2.0);
+
+    let horizontal: SimpleBezierPath = path_sweep(
+        &pen,
+        &line_spine(Coord2(0.0, 0.0), Coord2(10.0, 0.0)),
+        SweepOrientation::Fixed,
+        0.5,
+        0.01,
+    );
+    let vertical: SimpleBezierPath = path_sweep(
+        &pen,
+        &line_spine(Coord2(0.0, 0.0), Coord2(0.0, 10.0)),
+        SweepOrientation::Fixed,
+        0.5,
+        0.01,
+    );
+
+    let horizontal = horizontal.bounding_box::<Bounds<_>>();
+    let vertical = vertical.bounding_box::<Bounds<_>>();
+
+    assert!(horizontal.min().is_near_to(&Coord2(-0.1, -1.0), 0.02));
+    assert!(horizontal.max().is_near_to(&Coord2(10.1, 1.0), 0.02));
+    assert!(vertical.min().is_near_to(&Coord2(-0.1, -1.0), 0.02));
+    assert!(vertical.max().is_near_to(&Coord2(0.1, 11.0), 0.02));
+}
+
+#[test]
+fn sweep_circle_along_curve() {
+    let spine = SimpleBezierPath::from_points(
+        Coord2(0.0, 0.0),
+        vec![(Coord2(3.0, 6.0), Coord2(7.0, -6.0), Coord2(10.0, 0.0))],
+    );
+    let pen = Circle::new(Coord2(0.0, 0.0), 1.0).to_path::<SimpleBezierPath>();
+
+    let stroke: SimpleBezierPath =
+        path_sweep(&pen, &spine, SweepOrientation::FollowSpine, 0.1, 0.01);
+
+    // Every point on the spine is inside the stroke, and points well away from the spine are not
+    let spine_curve = Curve::from_points(
+        Coord2(0.0, 0.0),
+        (Coord2(3.0, 6.0), Coord2(7.0, -6.0)),
+        Coord2(10.0, 0.0),
+    );
+    for t in [0.0, 0.2, 0.4, 0.6, 0.8, 1.0] {
+        let point = spine_curve.point_at_pos(t);
+
+        assert!(path_contains_point(&stroke, &point));
+        assert!(!path_contains_point(&stroke, &(point + Coord2(0.0, 2.0))));
+        assert!(!path_contains_point(&stroke, &(point - Coord2(0.0, 2.0))));
+    }
+}
+
+#[test]
+fn cross_sections_along_straight_spine() {
+    let spine: Path3 = BezierPathFactory::from_points(
+        Coord3(0.0, 0.0, 0.0),
+        vec![(
+            Coord3(0.0, 0.0, 1.0),
+            Coord3(0.0, 0.0, 2.0),
+            Coord3(0.0, 0.0, 3.0),
+        )],
+    );
+    let profile = Circle::new(Coord2(0.0, 0.0), 1.0).to_path::<SimpleBezierPath>();
+
+    let sections: Vec<Path3> = path_sweep_cross_sections(&profile, &spine, 4);
+
+    assert!(sections.len() == 4);
+    for (idx, section) in sections.iter().enumerate() {
+        let z = idx as f64;
+
+        assert!((section.start_point().2 - z).abs() < 1e-4);
+        for (cp1, cp2, end_point) in section.points() {
+            assert!((cp1.2 - z).abs() < 1e-4);
+            assert!((cp2.2 - z).abs() < 1e-4);
+            assert!((end_point.2 - z).abs() < 1e-4);
+            assert!(((end_point - Coord3(0.0, 0.0, z)).magnitude() - 1.0).abs() < 1e-4);
+        }
+    }
+}
+
+#[test]
+fn cross_sections_are_perpendicular_to_curved_spine() {
+    let spine_curve = Curve::from_points(
+        Coord3(0.0, 0.0, 0.0),
+        (Coord3(5.0, 0.0, 2.0), Coord3(5.0, 5.0, 4.0)),
+        Coord3(0.0, 5.0, 6.0),
+    );
+    let spine: Path3 = BezierPathFactory::from_points(
+        spine_curve.start_point(),
+        vec![(
+            spine_curve.control_points().0,
+            spine_curve.control_points().1,
+            spine_curve.end_point(),
+        )],
+    );
+    let profile = Circle::new(Coord2(0.0, 0.0), 0.5).to_path::<SimpleBezierPath>();
+
+    let sections: Vec<Path3> = path_sweep_cross_sections(&profile, &spine, 10);
+    assert!(sections.len() == 10);
+
+    // The first and last sections surround the ends of the spine
+    let center = |section: &Path3| {
+        let points = section
+            .points()
+            .map(|(_, _, end_point)| end_point)
+            .collect::<Vec<_>>();
+        points
+            .iter()
+            .fold(Coord3::origin(), |sum, point| sum + *point)
+            * (1.0 / (points.len() as f64))
+    };
+    assert!(center(&sections[0]).is_near_to(&spine_curve.start_point(), 1e-4));
+    assert!(center(&sections[9]).is_near_to(&spine_curve.end_point(), 1e-4));
+
+    // Each section is flat and at right angles to the spine
+    for section in sections.iter() {
+        let section_center = center(section);
+        let points = section
+            .points()
+            .map(|(_, _, end_point)| end_point)
+            .collect::<Vec<_>>();
+        let normal = {
+            let a = points[0] - section_center;
+            let b = points[1] - section_center;
+            Coord3(
+                a.1 * b.2 - a.2 * b.1,
+                a.2 * b.0 - a.0 * b.2,
+                a.0 * b.1 - a.1 * b.0,
+            )
+            .to_unit_vector()
+        };
+
+        for point in points.iter() {
+            assert!(((*point - section_center).magnitude() - 0.5).abs() < 1e-4);
+            assert!((*point - section_center).dot(&normal).abs() < 1e-4);
+        }
+    }
+}
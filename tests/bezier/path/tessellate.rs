@@ -0,0 +1,150 @@
+use flo_curves::arc::Circle;
+use flo_curves::bezier::path::{
+    path_flatten, path_tessellate, BezierPathBuilder, SimpleBezierPath, TriangleMesh,
+};
+use flo_curves::geo::orient_2d;
+use flo_curves::{Coord2, Coordinate};
+
+use std::f64;
+
+fn check_triangles(mesh: &TriangleMesh<Coord2>) {
+    for (a, b, c) in mesh.triangle_points() {
+        assert!(orient_2d(&a, &b, &c) >= 0.0);
+    }
+}
+
+#[test]
+fn flatten_circle() {
+    let circle = Circle::new(Coord2(5.0, 5.0), 4.0).to_path::<SimpleBezierPath>();
+    let points = path_flatten(&circle, 0.01);
+
+    assert!(points.len() > 8);
+    assert!(points[0] == circle.0);
+    assert!(points[points.len() - 1].is_near_to(&circle.0, 0.001));
+
+    for point in points.iter() {
+        assert!((point.distance_to(&Coord2(5.0, 5.0)) - 4.0).abs() < 0.01);
+    }
+
+    // The middle of each line should also be close to the circle
+    for (start, end) in points.iter().zip(points.iter().skip(1)) {
+        let mid = (*start + *end) * 0.5;
+        assert!((mid.distance_to(&Coord2(5.0, 5.0)) - 4.0).abs() < 0.02);
+    }
+}
+
+#[test]
+fn tessellate_square() {
+    let square = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(1.0, 1.0))
+        .line_to(Coord2(3.0, 1.0))
+        .line_to(Coord2(3.0, 3.0))
+        .line_to(Coord2(1.0, 3.0))
+        .line_to(Coord2(1.0, 1.0))
+        .build();
+    let mesh = path_tessellate(&[square], 0.01);
+
+    assert!(mesh.vertices.len() == 4);
+    assert!(mesh.triangles.len() == 2);
+    assert!((mesh.area() - 4.0).abs() < 1e-9);
+    check_triangles(&mesh);
+}
+
+#[test]
+fn tessellate_concave_shape() {
+    // L-shaped path (clockwise)
+    let path = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(0.0, 0.0))
+        .line_to(Coord2(0.0, 4.0))
+        .line_to(Coord2(2.0, 4.0))
+        .line_to(Coord2(2.0, 2.0))
+        .line_to(Coord2(4.0, 2.0))
+        .line_to(Coord2(4.0, 0.0))
+        .line_to(Coord2(0.0, 0.0))
+        .build();
+    let mesh = path_tessellate(&[path], 0.01);
+
+    assert!(mesh.triangles.len() == 4);
+    assert!((mesh.area() - 12.0).abs() < 1e-9);
+    check_triangles(&mesh);
+}
+
+#[test]
+fn tessellate_circle() {
+    let circle = Circle::new(Coord2(5.0, 5.0), 4.0).to_path::<SimpleBezierPath>();
+    let mesh = path_tessellate(&[circle], 0.01);
+
+    assert!((mesh.area() - f64::consts::PI * 16.0).abs() < 0.2);
+    assert!(mesh.triangles.len() == mesh.vertices.len() - 2);
+    check_triangles(&mesh);
+}
+
+#[test]
+fn tessellate_circle_with_zero_tolerance() {
+    let circle = Circle::new(Coord2(5.0, 5.0), 4.0).to_path::<SimpleBezierPath>();
+    let mesh = path_tessellate(&[circle], 0.0);
+
+    // The bezier approximation of the circle is slightly larger than the real circle
+    assert!(mesh.vertices.len() < 10000);
+    assert!((mesh.area() - f64::consts::PI * 16.0).abs() < 0.05);
+    check_triangles(&mesh);
+}
+
+#[test]
+fn tessellate_path_with_nan_point() {
+    let square = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(0.0, 0.0))
+        .line_to(Coord2(10.0, 0.0))
+        .line_to(Coord2(10.0, f64::NAN))
+        .line_to(Coord2(0.0, 10.0))
+        .line_to(Coord2(0.0, 0.0))
+        .build();
+
+    assert!(path_flatten(&square, 0.01).len() == 5);
+
+    let mesh = path_tessellate(&[square], 0.01);
+    assert!(mesh.triangles.is_empty());
+}
+
+#[test]
+fn tessellate_ring_with_island() {
+    let outer = Circle::new(Coord2(10.0, 10.0), 8.0).to_path::<SimpleBezierPath>();
+    let hole = Circle::new(Coord2(10.0, 10.0), 5.0).to_path::<SimpleBezierPath>();
+    let island = Circle::new(Coord2(10.0, 10.0), 2.0).to_path::<SimpleBezierPath>();
+    let mesh = path_tessellate(&[outer, hole, island], 0.01);
+
+    let expected_area = f64::consts::PI * (64.0 - 25.0 + 4.0);
+    assert!((mesh.area() - expected_area).abs() < 0.5);
+    check_triangles(&mesh);
+
+    // No triangle should cover the middle of the hole
+    for (a, b, c) in mesh.triangle_points() {
+        let center = (a + b + c) * (1.0 / 3.0);
+        let distance = center.distance_to(&Coord2(10.0, 10.0));
+
+        assert!(!(2.01..=4.99).contains(&distance));
+    }
+}
+
+#[test]
+fn tessellate_square_with_two_holes() {
+    let square = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(0.0, 0.0))
+        .line_to(Coord2(10.0, 0.0))
+        .line_to(Coord2(10.0, 10.0))
+        .line_to(Coord2(0.0, 10.0))
+        .line_to(Coord2(0.0, 0.0))
+        .build();
+    let hole1 = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(2.0, 2.0))
+        .line_to(Coord2(4.0, 2.0))
+        .line_to(Coord2(4.0, 8.0))
+        .line_to(Coord2(2.0, 8.0))
+        .line_to(Coord2(2.0, 2.0))
+        .build();
+    let hole2 = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(6.0, 2.0))
+        .line_to(Coord2(8.0, 2.0))
+        .line_to(Coord2(8.0, 8.0))
+        .line_to(Coord2(6.0, 8.0))
+        .line_to(Coord2(6.0, 2.0))
+        .build();
+    let mesh = path_tessellate(&[square, hole1, hole2], 0.01);
+
+    assert!((mesh.area() - (100.0 - 24.0)).abs() < 1e-9);
+    check_triangles(&mesh);
+}
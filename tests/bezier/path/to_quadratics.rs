@@ -0,0 +1,89 @@
+use flo_curves::arc::Circle;
+use flo_curves::bezier::path::{
+    path_to_quadratics, BezierPath, BezierPathFactory, SimpleBezierPath,
+};
+use flo_curves::bezier::{distance_to_curve, Curve, QuadraticCurve};
+use flo_curves::{Coord2, Coordinate};
+
+///
+/// Checks that a set of quadratic curves follows a path to within a tolerance
+///
+fn assert_quadratics_follow_path(
+    path: &SimpleBezierPath,
+    (start_point, quadratics): &(Coord2, Vec<(Coord2, Coord2)>),
+    tolerance: f64,
+) {
+    assert!(*start_point == path.start_point());
+
+    let curves = path.to_curves::<Curve<_>>().into_iter().collect::<Vec<_>>();
+    let mut last_point = *start_point;
+
+    for (cp, end_point) in quadratics.iter() {
+        let quadratic = QuadraticCurve::from_points(last_point, *cp, *end_point);
+
+        for idx in 0..=10 {
+            let point = quadratic.point_at_pos((idx as f64) / 10.0);
+            let distance = curves
+                .iter()
+                .map(|curve| distance_to_curve(curve, &point))
+                .fold(f64::MAX, f64::min);
+
+            assert!(distance <= tolerance + 0.001);
+        }
+
+        last_point = *end_point;
+    }
+
+    assert!(last_point == path.points().last().unwrap().2);
+}
+
+#[test]
+fn circle_to_quadratics() {
+    let circle = Circle::new(Coord2(50.0, 50.0), 40.0).to_path::<SimpleBezierPath>();
+    let quadratics = path_to_quadratics(std::slice::from_ref(&circle), 0.01);
+
+    assert!(quadratics.len() == 1);
+
+    // Each quarter of the circle needs splitting to get to this tolerance
+    assert!(quadratics[0].1.len() > 4);
+    assert_quadratics_follow_path(&circle, &quadratics[0], 0.01);
+
+    // A looser tolerance uses fewer curves
+    let loose = path_to_quadratics(&[circle], 1.0);
+    assert!(loose[0].1.len() < quadratics[0].1.len());
+}
+
+#[test]
+fn curve_with_inflection_to_quadratics() {
+    let path = (
+        Coord2(0.0, 0.0),
+        vec![
+            (Coord2(10.0, 20.0), Coord2(20.0, -20.0), Coord2(30.0, 0.0)),
+            (Coord2(20.0, -5.0), Coord2(10.0, -5.0), Coord2(0.0, 0.0)),
+        ],
+    );
+    let path = SimpleBezierPath::from_path(&path);
+    let quadratics = path_to_quadratics(std::slice::from_ref(&path), 0.05);
+
+    assert_quadratics_follow_path(&path, &quadratics[0], 0.05);
+}
+
+#[test]
+fn quadratic_path_is_unchanged() {
+    let q1 = QuadraticCurve::from_points(Coord2(0.0, 0.0), Coord2(5.0, 10.0), Coord2(10.0, 0.0));
+    let q2 = QuadraticCurve::from_points(Coord2(10.0, 0.0), Coord2(5.0, -10.0), Coord2(0.0, 0.0));
+    let (c1, c2) = (q1.to_cubic::<Curve<_>>(), q2.to_cubic::<Curve<_>>());
+    let path = SimpleBezierPath::from_points(
+        c1.start_point,
+        vec![
+            (c1.control_points.0, c1.control_points.1, c1.end_point),
+            (c2.control_points.0, c2.control_points.1, c2.end_point),
+        ],
+    );
+
+    let quadratics = path_to_quadratics(&[path], 0.001);
+
+    assert!(quadratics[0].1.len() == 2);
+    assert!(quadratics[0].1[0].0.is_near_to(&Coord2(5.0, 10.0), 0.001));
+    assert!(quadratics[0].1[1].0.is_near_to(&Coord2(5.0, -10.0), 0.001));
+}
@@ -0,0 +1,119 @@
+use flo_curves::bezier::path::{
+    path_signed_area, repair_path, validate_path, BezierPathBuilder, PathDefect, SimpleBezierPath,
+};
+use flo_curves::Coord2;
+
+fn square() -> SimpleBezierPath {
+    BezierPathBuilder::<SimpleBezierPath>::start(Coord2(0.0, 0.0))
+        .line_to(Coord2(10.0, 0.0))
+        .line_to(Coord2(10.0, 10.0))
+        .line_to(Coord2(0.0, 10.0))
+        .line_to(Coord2(0.0, 0.0))
+        .build()
+}
+
+#[test]
+fn square_has_no_defects() {
+    assert!(validate_path(&square()).is_empty());
+}
+
+#[test]
+fn empty_path() {
+    let path: SimpleBezierPath = (Coord2(1.0, 1.0), vec![]);
+
+    assert!(validate_path(&path) == vec![PathDefect::Empty]);
+    assert!(repair_path::<_, SimpleBezierPath>(&path).is_none());
+}
+
+#[test]
+fn nan_control_point() {
+    let mut path = square();
+    path.1[1].0 = Coord2(f64::NAN, 5.0);
+
+    assert!(validate_path(&path) == vec![PathDefect::NonFiniteCurve(1)]);
+
+    // The curve is removed, and the curve after it is joined to the curve before it
+    let repaired = repair_path::<_, SimpleBezierPath>(&path).unwrap();
+    assert!(validate_path(&repaired).is_empty());
+    assert!(repaired.1.len() == 3);
+    assert!(repaired.1[0].2 == Coord2(10.0, 0.0));
+    assert!(repaired.1[1].2 == Coord2(0.0, 10.0));
+    assert!(repaired.1[2].2 == Coord2(0.0, 0.0));
+}
+
+#[test]
+fn nan_start_point() {
+    let mut path = square();
+    path.0 = Coord2(f64::NAN, f64::NAN);
+
+    assert!(validate_path(&path) == vec![PathDefect::NonFiniteStartPoint]);
+
+    let repaired = repair_path::<_, SimpleBezierPath>(&path).unwrap();
+    assert!(validate_path(&repaired).is_empty());
+    assert!(repaired.0 == Coord2(10.0, 0.0));
+}
+
+#[test]
+fn repeated_point() {
+    let path = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(0.0, 0.0))
+        .line_to(Coord2(10.0, 0.0))
+        .line_to(Coord2(10.0, 0.0))
+        .line_to(Coord2(10.0, 10.0))
+        .line_to(Coord2(0.0, 10.0))
+        .line_to(Coord2(0.0, 0.0))
+        .build();
+
+    assert!(validate_path(&path) == vec![PathDefect::ZeroLengthCurve(1)]);
+
+    let repaired = repair_path::<_, SimpleBezierPath>(&path).unwrap();
+    assert!(repaired == square());
+}
+
+#[test]
+fn curve_that_loops_back_on_itself() {
+    let path = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(0.0, 0.0))
+        .line_to(Coord2(10.0, 0.0))
+        .curve_to((Coord2(20.0, 0.0), Coord2(20.0, 10.0)), Coord2(10.0, 0.0))
+        .line_to(Coord2(10.0, 10.0))
+        .line_to(Coord2(0.0, 0.0))
+        .build();
+
+    assert!(validate_path(&path) == vec![PathDefect::CoincidentEndPoints(1)]);
+
+    let repaired = repair_path::<_, SimpleBezierPath>(&path).unwrap();
+    assert!(validate_path(&repaired).is_empty());
+    assert!(repaired.1.len() == 5);
+    assert!((path_signed_area(&repaired) - path_signed_area(&path)).abs() < 0.0001);
+}
+
+#[test]
+fn unclosed_path() {
+    let path = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(0.0, 0.0))
+        .line_to(Coord2(10.0, 0.0))
+        .line_to(Coord2(10.0, 10.0))
+        .line_to(Coord2(0.0, 10.0))
+        .build();
+
+    assert!(validate_path(&path) == vec![PathDefect::NotClosed(10.0)]);
+
+    let repaired = repair_path::<_, SimpleBezierPath>(&path).unwrap();
+    assert!(validate_path(&repaired).is_empty());
+    assert!(repaired.1.len() == 4);
+    assert!(repaired.1[3].2 == Coord2(0.0, 0.0));
+    assert!((path_signed_area(&repaired) - 100.0).abs() < 0.0001);
+}
+
+#[test]
+fn nearly_closed_path() {
+    let path = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(0.0, 0.0))
+        .line_to(Coord2(10.0, 0.0))
+        .line_to(Coord2(10.0, 10.0))
+        .line_to(Coord2(0.0, 10.0))
+        .line_to(Coord2(0.0, 0.0005))
+        .build();
+
+    assert!(validate_path(&path).is_empty());
+
+    let repaired = repair_path::<_, SimpleBezierPath>(&path).unwrap();
+    assert!(repaired.1[3].2 == Coord2(0.0, 0.0));
+}
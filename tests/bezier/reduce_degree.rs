@@ -0,0 +1,70 @@
+use flo_curves::bezier;
+use flo_curves::bezier::{BezierCurve, BezierCurveFactory};
+use flo_curves::*;
+
+#[test]
+fn quadratic_curve_reduces_exactly() {
+    let quadratic =
+        bezier::QuadraticCurve::from_points(Coord2(0.0, 0.0), Coord2(5.0, 10.0), Coord2(10.0, 0.0));
+    let cubic = quadratic.to_cubic::<bezier::Curve<_>>();
+
+    let (reduced, error) = bezier::reduce_to_quadratic(&cubic);
+
+    assert!(error < 0.001);
+    assert!(reduced.control_point.distance_to(&Coord2(5.0, 10.0)) < 0.001);
+
+    for idx in 0..=10 {
+        let t = (idx as f64) / 10.0;
+        assert!(
+            cubic
+                .point_at_pos(t)
+                .distance_to(&quadratic.point_at_pos(t))
+                < 0.001
+        );
+    }
+}
+
+#[test]
+fn reduce_arch_to_quadratic() {
+    let arch = bezier::Curve::from_points(
+        Coord2(0.0, 0.0),
+        (Coord2(0.0, 10.0), Coord2(10.0, 10.0)),
+        Coord2(10.0, 0.0),
+    );
+
+    let (reduced, error) = bezier::reduce_to_quadratic(&arch);
+
+    // The ends are unchanged, and the curve is close but not exact
+    assert!(reduced.start_point == Coord2(0.0, 0.0));
+    assert!(reduced.end_point == Coord2(10.0, 0.0));
+    assert!(reduced.control_point.distance_to(&Coord2(5.0, 15.0)) < 0.001);
+    assert!(error > 0.1);
+    assert!(error < 2.0);
+
+    // The error is the largest distance between the curves
+    let reduced_cubic = reduced.to_cubic::<bezier::Curve<_>>();
+    for idx in 0..=100 {
+        let point = arch.point_at_pos((idx as f64) / 100.0);
+        assert!(bezier::distance_to_curve(&reduced_cubic, &point) <= error + 0.001);
+    }
+}
+
+#[test]
+fn reduce_curve_to_line() {
+    let straight =
+        line::line_to_bezier::<_, bezier::Curve<_>>(&(Coord2(1.0, 2.0), Coord2(9.0, 6.0)));
+    let (line, error) = bezier::reduce_to_line(&straight);
+
+    assert!(line == (Coord2(1.0, 2.0), Coord2(9.0, 6.0)));
+    assert!(error < 0.001);
+
+    let arch = bezier::Curve::from_points(
+        Coord2(0.0, 0.0),
+        (Coord2(0.0, 10.0), Coord2(10.0, 10.0)),
+        Coord2(10.0, 0.0),
+    );
+    let (_, error) = bezier::reduce_to_line(&arch);
+
+    // The top of the arch is at 7.5
+    assert!((error - 7.5).abs() < 0.001);
+}
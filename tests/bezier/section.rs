@@ -99,3 +99,77 @@ fn recover_original_t_values_from_subsection() {
 
     assert!(sub_section.original_curve_t_values() == (0.375, 0.625));
 }
+
+#[test]
+fn map_t_values_between_section_and_original() {
+    let original_curve = Curve::from_points(
+        Coord2(2.0, 3.0),
+        (Coord2(4.0, 5.0), Coord2(5.0, 0.0)),
+        Coord2(6.0, 2.0),
+    );
+    let section = original_curve.section(0.2, 0.6);
+
+    assert!((section.original_t_for_section_t(0.0) - 0.2).abs() < 1e-12);
+    assert!((section.original_t_for_section_t(0.5) - 0.4).abs() < 1e-12);
+    assert!((section.original_t_for_section_t(1.0) - 0.6).abs() < 1e-12);
+
+    // The two mappings are the inverse of each other
+    for t in 0..=10 {
+        let t = (t as f64) / 10.0;
+        let original_t = section.original_t_for_section_t(t);
+
+        assert!((section.section_t_for_original_t(original_t) - t).abs() < 1e-12);
+        assert!(
+            section
+                .point_at_pos(t)
+                .distance_to(&original_curve.point_at_pos(original_t))
+                < 1e-9
+        );
+    }
+
+    // Points outside of the section map to values outside of 0..1
+    assert!((section.section_t_for_original_t(0.0) + 0.5).abs() < 1e-12);
+    assert!((section.section_t_for_original_t(1.0) - 2.0).abs() < 1e-12);
+}
+
+#[test]
+fn flatten_section_of_section() {
+    let original_curve = Curve::from_points(
+        Coord2(2.0, 3.0),
+        (Coord2(4.0, 5.0), Coord2(5.0, 0.0)),
+        Coord2(6.0, 2.0),
+    );
+    let outer = original_curve.section(0.2, 0.6);
+    let nested = outer.section(0.5, 1.0);
+
+    // The nested section's t values are relative to the outer section
+    let (t_min, t_max) = nested.original_curve_t_values();
+    assert!((t_min - 0.5).abs() < 1e-12);
+    assert!((t_max - 1.0).abs() < 1e-12);
+
+    // Flattening it gives t values on the original curve, for the same part of the curve
+    let flattened = nested.flatten();
+    let (t_min, t_max) = flattened.original_curve_t_values();
+    assert!((t_min - 0.4).abs() < 1e-12);
+    assert!((t_max - 0.6).abs() < 1e-12);
+
+    // Same as creating a subsection directly
+    let subsection = outer.subsection(0.5, 1.0);
+    assert!(subsection.original_curve_t_values() == flattened.original_curve_t_values());
+
+    for t in 0..=10 {
+        let t = (t as f64) / 10.0;
+        assert!(
+            nested
+                .point_at_pos(t)
+                .distance_to(&flattened.point_at_pos(t))
+                < 1e-9
+        );
+        assert!(
+            original_curve
+                .point_at_pos(flattened.original_t_for_section_t(t))
+                .distance_to(&nested.point_at_pos(t))
+                < 1e-9
+        );
+    }
+}
@@ -0,0 +1,98 @@
+use flo_curves::bezier;
+use flo_curves::bezier::NormalCurve;
+use flo_curves::{BezierCurve, BezierCurveFactory, Coord2, Coordinate};
+
+#[test]
+fn evenly_spaced_line_has_constant_speed() {
+    let line = bezier::Curve::from_points(
+        Coord2(0.0, 0.0),
+        (Coord2(10.0 / 3.0, 0.0), Coord2(20.0 / 3.0, 0.0)),
+        Coord2(10.0, 0.0),
+    );
+    let speed = bezier::curve_speed_range(&line);
+
+    assert!((speed.min_speed - 10.0).abs() < 0.001);
+    assert!((speed.max_speed - 10.0).abs() < 0.001);
+    assert!((speed.ratio() - 1.0).abs() < 0.001);
+}
+
+#[test]
+fn bunched_control_points_have_uneven_speed() {
+    let bunched = bezier::Curve::from_points(
+        Coord2(0.0, 0.0),
+        (Coord2(0.5, 0.0), Coord2(1.0, 0.0)),
+        Coord2(10.0, 0.0),
+    );
+    let speed = bezier::curve_speed_range(&bunched);
+
+    // Fastest at the end, where the curve has to jump from the control points to the end point
+    assert!((speed.max_t - 1.0).abs() < 0.001);
+    assert!((speed.max_speed - 27.0).abs() < 0.001);
+    assert!(speed.ratio() > 10.0);
+
+    // Slowest at the start, where the control points are close together
+    assert!(speed.min_t.abs() < 0.001);
+    assert!((speed.min_speed - 1.5).abs() < 0.001);
+}
+
+#[test]
+fn curve_with_cusp_stops_in_the_middle() {
+    let cusp = bezier::Curve::from_points(
+        Coord2(0.0, 0.0),
+        (Coord2(10.0, 10.0), Coord2(0.0, 10.0)),
+        Coord2(10.0, 0.0),
+    );
+    let speed = bezier::curve_speed_range(&cusp);
+
+    assert!(speed.min_speed < 0.001);
+    assert!((speed.min_t - 0.5).abs() < 0.001);
+    assert!(speed.ratio() > 1000.0);
+}
+
+#[test]
+fn reparameterise_bunched_curve() {
+    let bunched = bezier::Curve::from_points(
+        Coord2(0.0, 0.0),
+        (Coord2(0.5, 3.0), Coord2(1.0, 3.0)),
+        Coord2(10.0, 0.0),
+    );
+    let curves = bezier::reparameterise_by_arc_length::<_, bezier::Curve<_>>(&bunched, 8);
+
+    assert!(curves.len() == 8);
+    assert!(curves[0].start_point() == bunched.start_point());
+    assert!(curves[7].end_point().distance_to(&bunched.end_point()) < 0.001);
+
+    let original_ratio = bezier::curve_speed_range(&bunched).ratio();
+    let mut min_speed = f64::MAX;
+    let mut max_speed = 0.0f64;
+
+    for (idx, curve) in curves.iter().enumerate() {
+        // Curves are connected, and follow the original curve
+        if idx > 0 {
+            assert!(
+                curves[idx - 1]
+                    .end_point()
+                    .distance_to(&curve.start_point())
+                    < 0.001
+            );
+        }
+
+        for sample in 0..=10 {
+            let point = curve.point_at_pos((sample as f64) / 10.0);
+            assert!(bezier::distance_to_curve(&bunched, &point) < 0.05);
+        }
+
+        let speed = bezier::curve_speed_range(curve);
+        min_speed = min_speed.min(speed.min_speed);
+        max_speed = max_speed.max(speed.max_speed);
+
+        // Tangent directions are preserved
+        let original_t = bunched.t_for_point(&curve.start_point()).unwrap();
+        let original_tangent = bunched.tangent_at_pos(original_t).to_unit_vector();
+        let tangent = curve.tangent_at_pos(0.0).to_unit_vector();
+        assert!(original_tangent.distance_to(&tangent) < 0.01);
+    }
+
+    assert!(max_speed / min_speed < 2.0);
+    assert!(max_speed / min_speed < original_ratio);
+}
@@ -1,6 +1,7 @@
 use flo_curves::bezier::{
-    chord_length, curve_length, walk_curve_evenly, walk_curve_unevenly, BezierCurveFactory, Coord2,
-    Coordinate, Curve,
+    chord_length, curve_length, walk_curve_adaptively, walk_curve_evenly, walk_curve_unevenly,
+    walk_offset_curve_evenly, BezierCurve, BezierCurveFactory, Coord2, Coordinate, Curve,
+    NormalCurve,
 };
 
 #[test]
@@ -270,3 +271,157 @@ fn varying_walk_1() {
     println!("{:?}", (total_length - actual_length).abs());
     assert!((total_length - actual_length).abs() < 4.0);
 }
+
+#[test]
+fn adaptive_walk_straight_line() {
+    let c = Curve::from_points(
+        Coord2(0.0, 0.0),
+        (Coord2(30.0, 0.0), Coord2(70.0, 0.0)),
+        Coord2(100.0, 0.0),
+    );
+    let sections = walk_curve_adaptively(&c, 0.1, 0.1).collect::<Vec<_>>();
+
+    assert!(sections.len() == 1);
+    assert!(sections[0].original_curve_t_values() == (0.0, 1.0));
+}
+
+#[test]
+fn adaptive_walk_limits_angle() {
+    let c = Curve::from_points(
+        Coord2(0.0, 0.0),
+        (Coord2(0.0, 55.0), Coord2(45.0, 100.0)),
+        Coord2(100.0, 100.0),
+    );
+
+    // A large error allowance means that only the angle limits the size of the sections
+    let sections = walk_curve_adaptively(&c, 1000.0, 0.1).collect::<Vec<_>>();
+    assert!(sections.len() >= 16);
+
+    for section in sections.iter() {
+        let (cp1, cp2) = section.control_points();
+        let start_direction = (cp1 - section.start_point()).to_unit_vector();
+        let end_direction = (section.end_point() - cp2).to_unit_vector();
+
+        assert!(start_direction.dot(&end_direction).acos() <= 0.1 + 1e-9);
+    }
+}
+
+#[test]
+fn adaptive_walk_limits_error() {
+    let c = Curve::from_points(
+        Coord2(412.0, 500.0),
+        (Coord2(412.0, 500.0), Coord2(163.0, 504.0)),
+        Coord2(308.0, 665.0),
+    );
+
+    // A large angle allowance means that only the error limits the size of the sections
+    let sections = walk_curve_adaptively(&c, 0.5, 10.0).collect::<Vec<_>>();
+    assert!(sections.len() > 1);
+
+    for section in sections.iter() {
+        let (start, end) = (section.start_point(), section.end_point());
+
+        for t in [0.25, 0.5, 0.75] {
+            let point = section.point_at_pos(t);
+            let chord = end - start;
+            let along = (point - start).dot(&chord) / chord.dot(&chord);
+            let nearest = start + chord * along.clamp(0.0, 1.0);
+
+            assert!(point.distance_to(&nearest) <= 0.5 + 1e-9);
+        }
+    }
+}
+
+#[test]
+fn adaptive_walk_covers_curve() {
+    let c = Curve::from_points(
+        Coord2(412.0, 500.0),
+        (Coord2(412.0, 500.0), Coord2(163.0, 504.0)),
+        Coord2(308.0, 665.0),
+    );
+    let sections = walk_curve_adaptively(&c, 0.1, 0.05).collect::<Vec<_>>();
+
+    assert!(sections[0].original_curve_t_values().0 == 0.0);
+    assert!(sections[sections.len() - 1].original_curve_t_values().1 == 1.0);
+
+    for pair in sections.windows(2) {
+        assert!(pair[0].original_curve_t_values().1 == pair[1].original_curve_t_values().0);
+    }
+}
+
+#[test]
+fn walk_offset_of_straight_line() {
+    let c = Curve::from_points(
+        Coord2(0.0, 0.0),
+        (Coord2(3.0, 0.0), Coord2(7.0, 0.0)),
+        Coord2(10.0, 0.0),
+    );
+    let frames = walk_offset_curve_evenly(&c, 2.0, 1.0, 0.001).collect::<Vec<_>>();
+
+    // Positive offsets are to the left of the curve
+    assert!(frames.len() == 11);
+    for (idx, frame) in frames.iter().enumerate() {
+        assert!(frame.position.is_near_to(&Coord2(idx as f64, 2.0), 0.001));
+        assert!(frame.tangent.is_near_to(&Coord2(1.0, 0.0), 1e-9));
+        assert!(frame.normal.is_near_to(&Coord2(0.0, 1.0), 1e-9));
+    }
+}
+
+#[test]
+fn walk_offset_of_arc() {
+    // Quarter circle of radius 10, offset outwards to radius 15 (the arc runs clockwise, so outwards is to its left)
+    let c = flo_curves::arc::Circle::new(Coord2(0.0, 0.0), 10.0)
+        .arc(0.0, std::f64::consts::PI * 0.5)
+        .to_bezier_curve::<Curve<_>>();
+    let offset_length = 15.0 * std::f64::consts::PI * 0.5;
+
+    let frames = walk_offset_curve_evenly(&c, 5.0, 1.0, 0.001).collect::<Vec<_>>();
+
+    assert!(frames.len() == (offset_length.floor() as usize) + 1);
+    for frame in frames.iter() {
+        let radius = frame.position.magnitude();
+        assert!((radius - 15.0).abs() < 0.05);
+    }
+
+    // Points are evenly spaced along the offset arc (the chord of a 1 unit arc of radius 15 is only slightly shorter)
+    let expected_chord = 2.0 * 15.0 * (0.5 / 15.0f64).sin();
+    for pair in frames.windows(2) {
+        let chord = pair[0].position.distance_to(&pair[1].position);
+        assert!((chord - expected_chord).abs() < 0.01);
+    }
+}
+
+#[test]
+fn walk_offset_inside_arc_is_shorter() {
+    let c = flo_curves::arc::Circle::new(Coord2(0.0, 0.0), 10.0)
+        .arc(0.0, std::f64::consts::PI * 0.5)
+        .to_bezier_curve::<Curve<_>>();
+
+    let inside = walk_offset_curve_evenly(&c, -5.0, 0.5, 0.001).count();
+    let outside = walk_offset_curve_evenly(&c, 5.0, 0.5, 0.001).count();
+    let original = walk_offset_curve_evenly(&c, 0.0, 0.5, 0.001).count();
+
+    // Radius 5, 15 and 10
+    assert!(inside == ((5.0 * std::f64::consts::PI * 0.5 / 0.5).floor() as usize) + 1);
+    assert!(outside == ((15.0 * std::f64::consts::PI * 0.5 / 0.5).floor() as usize) + 1);
+    assert!(original == ((10.0 * std::f64::consts::PI * 0.5 / 0.5).floor() as usize) + 1);
+}
+
+#[test]
+fn walk_offset_through_loop() {
+    // A tight bend where the offset is larger than the radius of curvature, so the offset runs backwards for a bit
+    let c = Curve::from_points(
+        Coord2(0.0, 0.0),
+        (Coord2(10.0, 0.0), Coord2(10.0, 0.0)),
+        Coord2(10.0, 10.0),
+    );
+    let frames = walk_offset_curve_evenly(&c, 6.0, 0.25, 0.001).collect::<Vec<_>>();
+
+    assert!(frames
+        .iter()
+        .any(|frame| frame.tangent.dot(&c.tangent_at_pos(frame.t)) < 0.0));
+    for pair in frames.windows(2) {
+        assert!(pair[0].t < pair[1].t);
+        assert!(pair[0].position.distance_to(&pair[1].position) <= 0.2501);
+    }
+}
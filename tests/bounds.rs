@@ -61,3 +61,69 @@ fn from_points() {
     assert!(r.min() == Coord2(10.0, 30.0));
     assert!(r.max() == Coord2(60.0, 70.00));
 }
+
+#[test]
+fn union_of_many_rects() {
+    let rects = vec![
+        Bounds::from_min_max(Coord2(30.0, 30.0), Coord2(60.0, 40.0)),
+        Bounds::from_min_max(Coord2(20.0, 25.0), Coord2(35.0, 35.0)),
+        Bounds::from_min_max(Coord2(50.0, 10.0), Coord2(55.0, 20.0)),
+    ];
+
+    let union = Bounds::union_all(rects);
+
+    assert!(union == Bounds::from_min_max(Coord2(20.0, 10.0), Coord2(60.0, 40.0)));
+}
+
+#[test]
+fn union_of_no_rects() {
+    let union = Bounds::<Coord2>::union_all(vec![]);
+
+    assert!(union.is_empty());
+}
+
+#[test]
+fn intersect_overlapping_rects() {
+    let r1 = Bounds::from_min_max(Coord2(30.0, 30.0), Coord2(60.0, 40.0));
+    let r2 = Bounds::from_min_max(Coord2(20.0, 25.0), Coord2(35.0, 35.0));
+
+    assert!(
+        r1.intersect(&r2) == Some(Bounds::from_min_max(Coord2(30.0, 30.0), Coord2(35.0, 35.0)))
+    );
+}
+
+#[test]
+fn intersect_non_overlapping_rects() {
+    let r1 = Bounds::from_min_max(Coord2(30.0, 30.0), Coord2(60.0, 40.0));
+    let r2 = Bounds::from_min_max(Coord2(9.0, 10.0), Coord2(20.0, 25.0));
+
+    assert!(r1.intersect(&r2).is_none());
+}
+
+#[test]
+fn inflate_rect() {
+    let r1 = Bounds::from_min_max(Coord2(30.0, 30.0), Coord2(60.0, 40.0));
+
+    assert!(r1.inflate(5.0) == Bounds::from_min_max(Coord2(25.0, 25.0), Coord2(65.0, 45.0)));
+    assert!(r1.inflate(-6.0) == Bounds::from_min_max(Coord2(36.0, 35.0), Coord2(54.0, 35.0)));
+}
+
+#[test]
+fn contains_rects() {
+    let outer = Bounds::from_min_max(Coord2(10.0, 10.0), Coord2(60.0, 40.0));
+    let inner = Bounds::from_min_max(Coord2(20.0, 25.0), Coord2(35.0, 35.0));
+    let overlapping = Bounds::from_min_max(Coord2(50.0, 25.0), Coord2(70.0, 35.0));
+
+    assert!(outer.contains_bounds(&inner));
+    assert!(outer.contains_bounds(&outer));
+    assert!(!outer.contains_bounds(&overlapping));
+    assert!(!inner.contains_bounds(&outer));
+}
+
+#[test]
+fn center_and_extent() {
+    let r1 = Bounds::from_min_max(Coord2(30.0, 30.0), Coord2(60.0, 40.0));
+
+    assert!(r1.center() == Coord2(45.0, 35.0));
+    assert!(r1.extent() == Coord2(30.0, 10.0));
+}
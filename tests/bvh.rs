@@ -0,0 +1,223 @@
+use flo_curves::geo::{
+    BoundingBox, BoundingVolumeHierarchy, Bounds, BvhItemId, Coord2, Coordinate, Coordinate2D,
+};
+
+use rand::prelude::*;
+
+fn random_bounds(rng: &mut StdRng, n: usize) -> Vec<Bounds<Coord2>> {
+    (0..n)
+        .map(|_| {
+            let x = rng.gen::<f64>() * 900.0;
+            let y = rng.gen::<f64>() * 900.0;
+            let w = rng.gen::<f64>() * 100.0;
+            let h = rng.gen::<f64>() * 100.0;
+
+            Bounds::from_min_max(Coord2(x, y), Coord2(x + w, y + h))
+        })
+        .collect()
+}
+
+fn sorted_ids<'a>(ids: impl Iterator<Item = (BvhItemId, &'a Bounds<Coord2>)>) -> Vec<usize> {
+    let mut ids = ids.map(|(id, _)| id.index()).collect::<Vec<_>>();
+    ids.sort_unstable();
+    ids
+}
+
+#[test]
+fn empty_bvh() {
+    let bvh = BoundingVolumeHierarchy::<Bounds<Coord2>>::new();
+
+    assert!(bvh.is_empty());
+    assert!(bvh.containing_point(&Coord2(10.0, 10.0)).count() == 0);
+    assert!(bvh.overlapping_pairs().is_empty());
+}
+
+#[test]
+fn point_query_matches_brute_force() {
+    let mut rng = StdRng::from_seed([1; 32]);
+    let bounds = random_bounds(&mut rng, 500);
+    let bvh = BoundingVolumeHierarchy::from_items(bounds.clone());
+
+    assert!(bvh.len() == 500);
+
+    for _ in 0..100 {
+        let point = Coord2(rng.gen::<f64>() * 1000.0, rng.gen::<f64>() * 1000.0);
+        let found = sorted_ids(bvh.containing_point(&point));
+        let expected = (0..bounds.len())
+            .filter(|idx| bounds[*idx].overlaps(&Bounds::from_min_max(point, point)))
+            .collect::<Vec<_>>();
+
+        assert!(found == expected);
+    }
+}
+
+#[test]
+fn bounds_query_matches_brute_force() {
+    let mut rng = StdRng::from_seed([2; 32]);
+    let bounds = random_bounds(&mut rng, 500);
+    let bvh = BoundingVolumeHierarchy::from_items(bounds.clone());
+
+    for query in random_bounds(&mut rng, 50) {
+        let found = sorted_ids(bvh.overlapping_bounds(&query));
+        let expected = (0..bounds.len())
+            .filter(|idx| bounds[*idx].overlaps(&query))
+            .collect::<Vec<_>>();
+
+        assert!(found == expected);
+    }
+}
+
+#[test]
+fn ray_query_matches_brute_force() {
+    let mut rng = StdRng::from_seed([3; 32]);
+    let bounds = random_bounds(&mut rng, 500);
+    let bvh = BoundingVolumeHierarchy::from_items(bounds.clone());
+
+    let from = Coord2(0.0, 100.0);
+    let to = Coord2(100.0, 150.0);
+    let found = sorted_ids(bvh.crossed_by_ray(&from, &to));
+
+    // A box is crossed by the line if its corners are on different sides of it
+    let side = |x: f64, y: f64| {
+        (to.x() - from.x()) * (y - from.y()) - (to.y() - from.y()) * (x - from.x())
+    };
+    let expected = (0..bounds.len())
+        .filter(|idx| {
+            let (min, max) = (bounds[*idx].min(), bounds[*idx].max());
+            let sides = [
+                side(min.x(), min.y()),
+                side(max.x(), min.y()),
+                side(max.x(), max.y()),
+                side(min.x(), max.y()),
+            ];
+
+            sides.iter().any(|side| *side <= 0.0) && sides.iter().any(|side| *side >= 0.0)
+        })
+        .collect::<Vec<_>>();
+
+    assert!(!expected.is_empty());
+    assert!(found == expected);
+}
+
+#[test]
+fn overlapping_pairs_match_brute_force() {
+    let mut rng = StdRng::from_seed([4; 32]);
+    let bounds = random_bounds(&mut rng, 300);
+    let bvh = BoundingVolumeHierarchy::from_items(bounds.clone());
+
+    let mut found = bvh
+        .overlapping_pairs()
+        .into_iter()
+        .map(|(a, b)| (a.index().min(b.index()), a.index().max(b.index())))
+        .collect::<Vec<_>>();
+    found.sort_unstable();
+
+    let mut expected = vec![];
+    for idx1 in 0..bounds.len() {
+        for idx2 in (idx1 + 1)..bounds.len() {
+            if bounds[idx1].overlaps(&bounds[idx2]) {
+                expected.push((idx1, idx2));
+            }
+        }
+    }
+
+    assert!(found == expected);
+}
+
+#[test]
+fn remove_and_replace_items() {
+    let mut rng = StdRng::from_seed([5; 32]);
+    let mut bounds = random_bounds(&mut rng, 200)
+        .into_iter()
+        .map(Some)
+        .collect::<Vec<_>>();
+    let mut bvh = BoundingVolumeHierarchy::from_items(bounds.iter().map(|item| item.unwrap()));
+
+    // Remove every third item and move every fifth item
+    for (idx, item) in bounds.iter_mut().enumerate() {
+        let id = bvh.iter().find(|(id, _)| id.index() == idx).unwrap().0;
+
+        if idx % 3 == 0 {
+            assert!(bvh.remove(id) == *item);
+            *item = None;
+        } else if idx % 5 == 0 {
+            let moved =
+                Bounds::from_min_max(Coord2(2000.0, 2000.0), Coord2(2010.0 + idx as f64, 2010.0));
+            assert!(bvh.replace(id, moved) == *item);
+            *item = Some(moved);
+        }
+    }
+
+    assert!(bvh.len() == bounds.iter().filter(|item| item.is_some()).count());
+
+    for query in random_bounds(&mut rng, 50)
+        .into_iter()
+        .chain(std::iter::once(Bounds::from_min_max(
+            Coord2(2005.0, 2005.0),
+            Coord2(2006.0, 2006.0),
+        )))
+    {
+        let found = sorted_ids(bvh.overlapping_bounds(&query));
+        let expected = (0..bounds.len())
+            .filter(|idx| {
+                bounds[*idx]
+                    .map(|item| item.overlaps(&query))
+                    .unwrap_or(false)
+            })
+            .collect::<Vec<_>>();
+
+        assert!(found == expected);
+    }
+
+    // Re-adding an item should re-use a free slot
+    let new_id = bvh.insert(Bounds::from_min_max(Coord2(0.0, 0.0), Coord2(1.0, 1.0)));
+    assert!(new_id.index() < bounds.len());
+}
+
+#[test]
+fn nearest_query_matches_brute_force() {
+    let mut rng = StdRng::from_seed([7; 32]);
+    let bounds = random_bounds(&mut rng, 500);
+    let bvh = BoundingVolumeHierarchy::from_items(bounds.clone());
+
+    // Distance to the center of each box (which is always further away than the box itself)
+    let center_distance =
+        |point: Coord2, bounds: &Bounds<Coord2>| bounds.center().distance_to(&point);
+
+    for _ in 0..50 {
+        let point = Coord2(rng.gen::<f64>() * 1000.0, rng.gen::<f64>() * 1000.0);
+        let found = bvh
+            .nearest_to_point(&point, move |bounds| center_distance(point, bounds))
+            .take(10)
+            .map(|(_id, _bounds, distance)| distance)
+            .collect::<Vec<_>>();
+
+        let mut expected = bounds
+            .iter()
+            .map(|bounds| center_distance(point, bounds))
+            .collect::<Vec<_>>();
+        expected.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        expected.truncate(10);
+
+        assert!(found == expected);
+    }
+}
+
+#[test]
+fn nearest_returns_every_item() {
+    let mut rng = StdRng::from_seed([8; 32]);
+    let bounds = random_bounds(&mut rng, 100);
+    let bvh = BoundingVolumeHierarchy::from_items(bounds);
+
+    let point = Coord2(500.0, 500.0);
+    let found = bvh
+        .nearest_to_point(&point, move |bounds| bounds.center().distance_to(&point))
+        .collect::<Vec<_>>();
+
+    assert!(found.len() == 100);
+    assert!(found.windows(2).all(|pair| pair[0].2 <= pair[1].2));
+    assert!(BoundingVolumeHierarchy::<Bounds<Coord2>>::new()
+        .nearest_to_point(&point, |_| 0.0)
+        .next()
+        .is_none());
+}
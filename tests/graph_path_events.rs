@@ -0,0 +1,114 @@
+#![cfg(feature = "instrumentation")]
+
+use flo_curves::arc::Circle;
+use flo_curves::bezier::path::{path_add, GraphPath, GraphPathEdgeKind, SimpleBezierPath};
+use flo_curves::debug::{record_graph_path_events, trace_graph_path_events, GraphPathEvent};
+use flo_curves::Coord2;
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+#[test]
+fn path_add_generates_events() {
+    let circle1 = Circle::new(Coord2(5.0, 5.0), 4.0).to_path::<SimpleBezierPath>();
+    let circle2 = Circle::new(Coord2(8.0, 5.0), 4.0).to_path::<SimpleBezierPath>();
+
+    let (combined, events) = record_graph_path_events(|| {
+        path_add::<_, _, SimpleBezierPath>(&[circle1], &[circle2], 0.01)
+    });
+
+    assert!(combined.len() == 1);
+
+    // The circles cross at two places
+    let num_collisions = events
+        .iter()
+        .filter(|event| matches!(event, GraphPathEvent::CollisionFound { .. }))
+        .count();
+    assert!(num_collisions >= 2);
+
+    assert!(matches!(
+        events[0],
+        GraphPathEvent::DetectingCollisions { .. }
+    ));
+    assert!(events
+        .iter()
+        .any(|event| matches!(event, GraphPathEvent::EdgeSubdivided { .. })));
+    assert!(events
+        .iter()
+        .any(|event| matches!(event, GraphPathEvent::RayCast { .. })));
+    assert!(events.iter().any(|event| matches!(
+        event,
+        GraphPathEvent::EdgeClassified {
+            kind: GraphPathEdgeKind::Exterior,
+            ..
+        }
+    )));
+    assert!(events.iter().any(|event| matches!(
+        event,
+        GraphPathEvent::EdgeClassified {
+            kind: GraphPathEdgeKind::Interior,
+            ..
+        }
+    )));
+}
+
+#[test]
+fn self_collide_generates_events() {
+    let circle1 = Circle::new(Coord2(5.0, 5.0), 4.0).to_path::<SimpleBezierPath>();
+    let circle2 = Circle::new(Coord2(8.0, 5.0), 4.0).to_path::<SimpleBezierPath>();
+
+    let mut graph_path = GraphPath::from_path(&circle1, ());
+    graph_path = graph_path.merge(GraphPath::from_path(&circle2, ()));
+    let num_points = graph_path.num_points();
+
+    let (_, events) = record_graph_path_events(|| graph_path.self_collide(0.01));
+
+    assert!(
+        events[0]
+            == GraphPathEvent::DetectingCollisions {
+                collide_from: 0..num_points,
+                collide_to: 0..num_points,
+            }
+    );
+    assert!(events
+        .iter()
+        .any(|event| matches!(event, GraphPathEvent::CollisionFound { .. })));
+}
+
+#[test]
+fn no_events_without_tracer() {
+    let circle1 = Circle::new(Coord2(5.0, 5.0), 4.0).to_path::<SimpleBezierPath>();
+    let circle2 = Circle::new(Coord2(8.0, 5.0), 4.0).to_path::<SimpleBezierPath>();
+
+    let num_events = Rc::new(RefCell::new(0));
+    let counter = Rc::clone(&num_events);
+    trace_graph_path_events(move |_| *counter.borrow_mut() += 1, || ());
+
+    path_add::<_, _, SimpleBezierPath>(&[circle1], &[circle2], 0.01);
+
+    assert!(*num_events.borrow() == 0);
+}
+
+#[test]
+fn nested_tracers() {
+    let circle1 = Circle::new(Coord2(5.0, 5.0), 4.0).to_path::<SimpleBezierPath>();
+    let circle2 = Circle::new(Coord2(8.0, 5.0), 4.0).to_path::<SimpleBezierPath>();
+
+    let (inner_events, outer_events) = record_graph_path_events(|| {
+        let (_, inner_events) = record_graph_path_events(|| {
+            path_add::<_, _, SimpleBezierPath>(
+                std::slice::from_ref(&circle1),
+                std::slice::from_ref(&circle2),
+                0.01,
+            )
+        });
+
+        // The outer tracer receives the events once the inner one is finished
+        path_add::<_, _, SimpleBezierPath>(&[circle1], &[circle2], 0.01);
+
+        inner_events
+    });
+
+    assert!(!inner_events.is_empty());
+    assert!(inner_events == outer_events);
+}
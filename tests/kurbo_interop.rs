@@ -0,0 +1,71 @@
+#![cfg(feature = "kurbo_interop")]
+
+use flo_curves::bezier::path::{path_signed_area, BezierPathBuilder, SimpleBezierPath};
+use flo_curves::bezier::Curve;
+use flo_curves::interop::{paths_from_kurbo, paths_to_kurbo};
+use flo_curves::{BezierCurveFactory, Coord2};
+
+use kurbo::{BezPath, CubicBez, PathEl};
+
+#[test]
+fn convert_points() {
+    let point: kurbo::Point = Coord2(1.0, 2.0).into();
+    assert!(point == kurbo::Point::new(1.0, 2.0));
+
+    let coord: Coord2 = point.into();
+    assert!(coord == Coord2(1.0, 2.0));
+}
+
+#[test]
+fn curve_round_trip() {
+    let curve = Curve::from_points(
+        Coord2(1.0, 2.0),
+        (Coord2(3.0, 4.0), Coord2(5.0, 6.0)),
+        Coord2(7.0, 8.0),
+    );
+    let cubic: CubicBez = curve.into();
+
+    assert!(cubic.p0 == kurbo::Point::new(1.0, 2.0));
+    assert!(cubic.p1 == kurbo::Point::new(3.0, 4.0));
+    assert!(cubic.p2 == kurbo::Point::new(5.0, 6.0));
+    assert!(cubic.p3 == kurbo::Point::new(7.0, 8.0));
+    assert!(Curve::from(cubic) == curve);
+}
+
+#[test]
+fn path_round_trip() {
+    let square = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(0.0, 0.0))
+        .line_to(Coord2(10.0, 0.0))
+        .line_to(Coord2(10.0, 10.0))
+        .line_to(Coord2(0.0, 10.0))
+        .line_to(Coord2(0.0, 0.0))
+        .build();
+    let bez_path = paths_to_kurbo(std::slice::from_ref(&square));
+
+    assert!(bez_path.elements().len() == 6);
+    assert!(bez_path.elements()[5] == PathEl::ClosePath);
+
+    let paths = paths_from_kurbo::<SimpleBezierPath>(&bez_path);
+    assert!(paths == vec![square]);
+}
+
+#[test]
+fn open_subpaths_are_closed() {
+    let mut bez_path = BezPath::new();
+    bez_path.move_to((0.0, 0.0));
+    bez_path.line_to((10.0, 0.0));
+    bez_path.quad_to((10.0, 10.0), (0.0, 10.0));
+
+    bez_path.move_to((20.0, 0.0));
+    bez_path.line_to((30.0, 0.0));
+    bez_path.line_to((30.0, 10.0));
+    bez_path.close_path();
+
+    let paths = paths_from_kurbo::<SimpleBezierPath>(&bez_path);
+
+    assert!(paths.len() == 2);
+    assert!(paths[0].1.len() == 3);
+    assert!(paths[0].1[2].2 == Coord2(0.0, 0.0));
+    assert!(paths[1].1.len() == 3);
+    assert!((path_signed_area(&paths[1]) - 50.0).abs() < 0.0001);
+}
@@ -1,6 +1,7 @@
 use flo_curves::line::{
-    line_clip_to_bounds, line_intersects_line, line_intersects_ray, ray_intersects_ray, Coord2,
-    Coordinate, Line2D,
+    line_clip_to_bounds, line_intersects_line, line_intersects_ray, line_ray_intersection,
+    ray_intersects_ray, ray_ray_intersection, segment_intersects_segment, Coord2, Coordinate,
+    SimpleRay,
 };
 
 #[test]
@@ -156,3 +157,77 @@ fn line_out_of_bounds_crossing() {
 
     assert!(clipped.is_none());
 }
+
+#[test]
+fn segment_intersection_with_t_values() {
+    let (point, t1, t2) = segment_intersects_segment(
+        &(Coord2(10.0, 20.0), Coord2(50.0, 60.0)),
+        &(Coord2(10.0, 45.0), Coord2(50.0, 35.0)),
+    )
+    .unwrap();
+
+    assert!(point.distance_to(&Coord2(30.0, 40.0)) < 0.01);
+    assert!((t1 - 0.5).abs() < 0.0001);
+    assert!((t2 - 0.5).abs() < 0.0001);
+}
+
+#[test]
+fn segment_intersection_at_uneven_t_values() {
+    let (point, t1, t2) = segment_intersects_segment(
+        &(Coord2(0.0, 0.0), Coord2(100.0, 0.0)),
+        &(Coord2(25.0, -10.0), Coord2(25.0, 30.0)),
+    )
+    .unwrap();
+
+    assert!(point.distance_to(&Coord2(25.0, 0.0)) < 0.01);
+    assert!((t1 - 0.25).abs() < 0.0001);
+    assert!((t2 - 0.25).abs() < 0.0001);
+}
+
+#[test]
+fn segments_that_do_not_meet() {
+    // These would meet if the second segment were longer
+    assert!(segment_intersects_segment(
+        &(Coord2(0.0, 0.0), Coord2(100.0, 0.0)),
+        &(Coord2(25.0, 10.0), Coord2(25.0, 30.0)),
+    )
+    .is_none());
+}
+
+#[test]
+fn parallel_segments_do_not_intersect() {
+    assert!(segment_intersects_segment(
+        &(Coord2(0.0, 0.0), Coord2(100.0, 0.0)),
+        &(Coord2(0.0, 10.0), Coord2(100.0, 10.0)),
+    )
+    .is_none());
+    assert!(segment_intersects_segment(
+        &(Coord2(0.0, 0.0), Coord2(100.0, 0.0)),
+        &(Coord2(50.0, 0.0), Coord2(150.0, 0.0)),
+    )
+    .is_none());
+}
+
+#[test]
+fn line_intersects_explicit_ray() {
+    let ray = SimpleRay::new(Coord2(10.0, 45.0), Coord2(4.0, -1.0));
+    let (point, t) =
+        line_ray_intersection(&(Coord2(10.0, 20.0), Coord2(50.0, 60.0)), &ray).unwrap();
+
+    assert!(point.distance_to(&Coord2(30.0, 40.0)) < 0.01);
+    assert!((t - 5.0).abs() < 0.0001);
+}
+
+#[test]
+fn explicit_rays_intersect() {
+    let ray1 = SimpleRay::through(Coord2(0.0, 0.0), Coord2(1.0, 1.0));
+    let ray2 = SimpleRay::new(Coord2(10.0, 0.0), Coord2(-2.0, 2.0));
+    let (point, t1, t2) = ray_ray_intersection(&ray1, &ray2).unwrap();
+
+    assert!(point.distance_to(&Coord2(5.0, 5.0)) < 0.01);
+    assert!((t1 - 5.0).abs() < 0.0001);
+    assert!((t2 - 2.5).abs() < 0.0001);
+
+    let parallel = SimpleRay::new(Coord2(0.0, 10.0), Coord2(1.0, 1.0));
+    assert!(ray_ray_intersection(&ray1, &parallel).is_none());
+}
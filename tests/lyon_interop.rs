@@ -0,0 +1,84 @@
+#![cfg(feature = "lyon_interop")]
+
+use flo_curves::bezier::path::{path_signed_area, BezierPathBuilder, SimpleBezierPath};
+use flo_curves::bezier::Curve;
+use flo_curves::interop::{paths_from_lyon, paths_to_lyon};
+use flo_curves::{BezierCurveFactory, Coord2, Coordinate};
+
+use lyon_path::geom::CubicBezierSegment;
+use lyon_path::math::point;
+use lyon_path::{Path, PathEvent};
+
+#[test]
+fn convert_points() {
+    let lyon_point: lyon_path::math::Point = Coord2(1.0, 2.0).into();
+    assert!(lyon_point == point(1.0, 2.0));
+
+    let coord: Coord2 = lyon_point.into();
+    assert!(coord == Coord2(1.0, 2.0));
+}
+
+#[test]
+fn curve_round_trip() {
+    let curve = Curve::from_points(
+        Coord2(1.0, 2.0),
+        (Coord2(3.0, 4.0), Coord2(5.0, 6.0)),
+        Coord2(7.0, 8.0),
+    );
+    let cubic: CubicBezierSegment<f32> = curve.into();
+
+    assert!(cubic.from == point(1.0, 2.0));
+    assert!(cubic.ctrl1 == point(3.0, 4.0));
+    assert!(cubic.ctrl2 == point(5.0, 6.0));
+    assert!(cubic.to == point(7.0, 8.0));
+    assert!(Curve::from(cubic) == curve);
+}
+
+#[test]
+fn path_round_trip() {
+    let square = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(0.0, 0.0))
+        .line_to(Coord2(10.0, 0.0))
+        .line_to(Coord2(10.0, 10.0))
+        .line_to(Coord2(0.0, 10.0))
+        .line_to(Coord2(0.0, 0.0))
+        .build();
+    let lyon_path = paths_to_lyon(std::slice::from_ref(&square));
+
+    let events = lyon_path.iter().collect::<Vec<_>>();
+    assert!(events.len() == 6);
+    assert!(matches!(events[5], PathEvent::End { close: true, .. }));
+
+    // Converting to lyon reduces the precision of the control points
+    let paths = paths_from_lyon::<SimpleBezierPath>(&lyon_path);
+    assert!(paths.len() == 1);
+    assert!(paths[0].0 == square.0);
+    for ((cp1, cp2, end_point), (expected_cp1, expected_cp2, expected_end_point)) in
+        paths[0].1.iter().zip(square.1.iter())
+    {
+        assert!(cp1.is_near_to(expected_cp1, 1e-5));
+        assert!(cp2.is_near_to(expected_cp2, 1e-5));
+        assert!(end_point == expected_end_point);
+    }
+}
+
+#[test]
+fn open_subpaths_are_closed() {
+    let mut builder = Path::builder();
+    builder.begin(point(0.0, 0.0));
+    builder.line_to(point(10.0, 0.0));
+    builder.quadratic_bezier_to(point(10.0, 10.0), point(0.0, 10.0));
+    builder.end(false);
+
+    builder.begin(point(20.0, 0.0));
+    builder.line_to(point(30.0, 0.0));
+    builder.line_to(point(30.0, 10.0));
+    builder.end(true);
+
+    let paths = paths_from_lyon::<SimpleBezierPath>(&builder.build());
+
+    assert!(paths.len() == 2);
+    assert!(paths[0].1.len() == 3);
+    assert!(paths[0].1[2].2 == Coord2(0.0, 0.0));
+    assert!(paths[1].1.len() == 3);
+    assert!((path_signed_area(&paths[1]) - 50.0).abs() < 0.0001);
+}
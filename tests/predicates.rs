@@ -0,0 +1,134 @@
+use flo_curves::geo::{in_circle, orient_2d};
+use flo_curves::line::Line2D;
+use flo_curves::Coord2;
+
+use rand::prelude::*;
+
+#[test]
+fn orientation_of_simple_triangles() {
+    let a = Coord2(0.0, 0.0);
+    let b = Coord2(10.0, 0.0);
+    let c = Coord2(0.0, 10.0);
+
+    assert!(orient_2d(&a, &b, &c) > 0.0);
+    assert!(orient_2d(&a, &c, &b) < 0.0);
+    assert!((orient_2d(&a, &b, &c) - 100.0).abs() < 1e-9);
+    assert!(orient_2d(&a, &b, &Coord2(20.0, 0.0)) == 0.0);
+}
+
+#[test]
+fn orientation_of_nearly_collinear_points() {
+    // Integer coordinates that are too large for the products to be represented exactly, but small enough that the
+    // exact result can be calculated using 128-bit integers
+    let mut rng = StdRng::seed_from_u64(42);
+
+    for _ in 0..1000 {
+        let (ax, ay) = (
+            rng.gen_range(-(1i64 << 50)..(1i64 << 50)),
+            rng.gen_range(-(1i64 << 50)..(1i64 << 50)),
+        );
+        let (dx, dy) = (rng.gen_range(1i64..1000), rng.gen_range(1i64..1000));
+        let (k, m) = (rng.gen_range(1i64..1000), rng.gen_range(1000i64..2000));
+        let offset = rng.gen_range(-1i64..=1);
+
+        let (bx, by) = (ax + k * dx, ay + k * dy + offset);
+        let (cx, cy) = (ax + m * dx, ay + m * dy);
+
+        let exact =
+            ((ax - cx) as i128) * ((by - cy) as i128) - ((ay - cy) as i128) * ((bx - cx) as i128);
+        let robust = orient_2d(
+            &Coord2(ax as f64, ay as f64),
+            &Coord2(bx as f64, by as f64),
+            &Coord2(cx as f64, cy as f64),
+        );
+
+        assert!(
+            (exact.signum() as f64) == robust.signum() || (exact == 0 && robust == 0.0),
+            "{} {}",
+            exact,
+            robust
+        );
+    }
+}
+
+#[test]
+fn orientation_with_tiny_offsets() {
+    // Points near (0.5, 0.5) on the line through (12, 12) and (24, 24) are a classic case where the simple calculation fails
+    let b = Coord2(12.0, 12.0);
+    let c = Coord2(24.0, 24.0);
+    let step = f64::EPSILON;
+
+    for x in 0..64 {
+        for y in 0..64 {
+            let a = Coord2(0.5 + (x as f64) * step, 0.5 + (y as f64) * step);
+            let side = orient_2d(&a, &b, &c);
+
+            // The point is to the left of the line (seen from c to b) when x > y
+            if x == y {
+                assert!(side == 0.0);
+            } else if x > y {
+                assert!(side < 0.0);
+            } else {
+                assert!(side > 0.0);
+            }
+        }
+    }
+}
+
+#[test]
+fn which_side_agrees_with_orientation() {
+    let line = (Coord2(0.0, 0.0), Coord2(10.0, 10.0));
+
+    assert!(
+        line.which_side(&Coord2(0.0, 10.0))
+            == -(orient_2d(&line.0, &line.1, &Coord2(0.0, 10.0)).signum() as i8)
+    );
+    assert!(
+        line.which_side(&Coord2(10.0, 0.0))
+            == -(orient_2d(&line.0, &line.1, &Coord2(10.0, 0.0)).signum() as i8)
+    );
+}
+
+#[test]
+fn points_inside_and_outside_circle() {
+    let a = Coord2(5.0, 0.0);
+    let b = Coord2(0.0, 5.0);
+    let c = Coord2(-5.0, 0.0);
+
+    assert!(in_circle(&a, &b, &c, &Coord2(0.0, 0.0)) > 0.0);
+    assert!(in_circle(&a, &b, &c, &Coord2(10.0, 10.0)) < 0.0);
+    assert!(in_circle(&a, &c, &b, &Coord2(0.0, 0.0)) < 0.0);
+}
+
+#[test]
+fn cocircular_points() {
+    // These points are all on a circle of radius 5, even when moved far away from the origin
+    for &offset in [0.0, 1e6, 1e9 + 0.5].iter() {
+        let point = |x: f64, y: f64| Coord2(x + offset, y + offset);
+
+        assert!(
+            in_circle(
+                &point(5.0, 0.0),
+                &point(3.0, 4.0),
+                &point(-4.0, 3.0),
+                &point(0.0, -5.0)
+            ) == 0.0
+        );
+        assert!(
+            in_circle(
+                &point(5.0, 0.0),
+                &point(3.0, 4.0),
+                &point(-4.0, 3.0),
+                &point(0.0, -5.0 + 1e-6)
+            ) > 0.0
+        );
+        assert!(
+            in_circle(
+                &point(5.0, 0.0),
+                &point(3.0, 4.0),
+                &point(-4.0, 3.0),
+                &point(0.0, -5.0 - 1e-6)
+            ) < 0.0
+        );
+    }
+}
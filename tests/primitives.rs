@@ -0,0 +1,192 @@
+use flo_curves::bezier::path::{path_to_curves, BezierPath, PathWithIsClockwise, SimpleBezierPath};
+use flo_curves::bezier::Curve;
+use flo_curves::primitives::{
+    ellipse_path, rectangle_path, regular_polygon_path, rounded_rectangle_path, squircle_path,
+    star_path, superellipse_path,
+};
+use flo_curves::{BezierCurve, BoundingBox, Bounds, Coord2, Coordinate, Coordinate2D};
+
+use std::f64;
+
+#[test]
+fn rectangle() {
+    let rectangle = rectangle_path::<SimpleBezierPath>(Coord2(10.0, 20.0), Coord2(30.0, 50.0));
+    let corners = rectangle.points().map(|(_, _, p)| p).collect::<Vec<_>>();
+
+    assert!(rectangle.start_point() == Coord2(10.0, 20.0));
+    assert!(
+        corners
+            == vec![
+                Coord2(30.0, 20.0),
+                Coord2(30.0, 50.0),
+                Coord2(10.0, 50.0),
+                Coord2(10.0, 20.0)
+            ]
+    );
+    assert!(!rectangle.is_clockwise());
+}
+
+#[test]
+fn rounded_rectangle_with_different_radii() {
+    let rectangle = rounded_rectangle_path::<SimpleBezierPath>(
+        Coord2(0.0, 0.0),
+        Coord2(100.0, 50.0),
+        [10.0, 0.0, 20.0, 5.0],
+    );
+
+    // Three rounded corners, each one adding an extra section
+    assert!(rectangle.points().count() == 7);
+
+    let bounds = rectangle.bounding_box::<Bounds<_>>();
+    assert!(bounds.min().distance_to(&Coord2(0.0, 0.0)) < 1e-6);
+    assert!(bounds.max().distance_to(&Coord2(100.0, 50.0)) < 1e-6);
+
+    // The square corner is still there
+    assert!(rectangle
+        .points()
+        .any(|(_, _, p)| p.distance_to(&Coord2(100.0, 0.0)) < 1e-6));
+
+    // The corners that are rounded are cut off
+    let curves = path_to_curves::<_, Curve<_>>(&rectangle).collect::<Vec<_>>();
+    assert!(curves
+        .iter()
+        .all(|curve| curve.point_at_pos(0.5).distance_to(&Coord2(100.0, 50.0)) > 5.0));
+}
+
+#[test]
+fn rounded_rectangle_radii_are_scaled_to_fit() {
+    let rectangle = rounded_rectangle_path::<SimpleBezierPath>(
+        Coord2(0.0, 0.0),
+        Coord2(40.0, 20.0),
+        [50.0, 50.0, 50.0, 50.0],
+    );
+
+    // The radii scale to 10, making a stadium shape
+    let curves = path_to_curves::<_, Curve<_>>(&rectangle).collect::<Vec<_>>();
+    for curve in curves.iter() {
+        let mid = curve.point_at_pos(0.5);
+        let distance_to_left = mid.distance_to(&Coord2(10.0, 10.0));
+        let distance_to_right = mid.distance_to(&Coord2(30.0, 10.0));
+
+        assert!(
+            mid.y() < 1e-6
+                || mid.y() > 20.0 - 1e-6
+                || (distance_to_left - 10.0).abs() < 0.01
+                || (distance_to_right - 10.0).abs() < 0.01
+        );
+    }
+
+    let bounds = rectangle.bounding_box::<Bounds<_>>();
+    assert!(bounds.min().distance_to(&Coord2(0.0, 0.0)) < 1e-6);
+    assert!(bounds.max().distance_to(&Coord2(40.0, 20.0)) < 1e-6);
+}
+
+#[test]
+fn ellipse() {
+    let ellipse = ellipse_path::<SimpleBezierPath>(Coord2(5.0, 5.0), 20.0, 10.0);
+
+    assert!(!ellipse.is_clockwise());
+    assert!(ellipse.start_point() == Coord2(25.0, 5.0));
+
+    for curve in path_to_curves::<_, Curve<_>>(&ellipse) {
+        for t in 0..=10 {
+            let point = curve.point_at_pos((t as f64) / 10.0) - Coord2(5.0, 5.0);
+            let ellipse_distance = (point.x() / 20.0).powi(2) + (point.y() / 10.0).powi(2);
+
+            assert!((ellipse_distance - 1.0).abs() < 0.001);
+        }
+    }
+}
+
+#[test]
+fn hexagon() {
+    let hexagon = regular_polygon_path::<SimpleBezierPath>(Coord2(0.0, 0.0), 10.0, 6, 0.0);
+    let vertices = hexagon.points().map(|(_, _, p)| p).collect::<Vec<_>>();
+
+    assert!(!hexagon.is_clockwise());
+    assert!(vertices.len() == 6);
+    assert!(hexagon.start_point() == Coord2(10.0, 0.0));
+    assert!(vertices[5] == hexagon.start_point());
+
+    for (idx, vertex) in vertices.iter().enumerate() {
+        let angle = ((idx + 1) as f64) * f64::consts::PI / 3.0;
+        assert!(vertex.distance_to(&Coord2(10.0 * angle.cos(), 10.0 * angle.sin())) < 1e-9);
+    }
+}
+
+#[test]
+fn five_pointed_star() {
+    let star = star_path::<SimpleBezierPath>(Coord2(0.0, 0.0), 10.0, 4.0, 5, f64::consts::PI / 2.0);
+    let vertices = star.points().map(|(_, _, p)| p).collect::<Vec<_>>();
+
+    assert!(vertices.len() == 10);
+    assert!(star.start_point().distance_to(&Coord2(0.0, 10.0)) < 1e-9);
+
+    for (idx, vertex) in vertices.iter().enumerate() {
+        let expected_radius = if idx % 2 == 0 { 4.0 } else { 10.0 };
+        assert!((vertex.magnitude() - expected_radius).abs() < 1e-9);
+    }
+}
+
+///
+/// Returns how far a point is from the edge of a superellipse, measured along the line from its center
+///
+fn superellipse_error(point: Coord2, radius_x: f64, radius_y: f64, exponent: f64) -> f64 {
+    let scale = ((point.x() / radius_x).abs().powf(exponent)
+        + (point.y() / radius_y).abs().powf(exponent))
+    .powf(1.0 / exponent);
+
+    point.magnitude() * (1.0 - 1.0 / scale).abs()
+}
+
+#[test]
+fn superellipse_is_on_curve() {
+    for exponent in [0.5, 1.0, 2.0, 4.0, 8.0] {
+        let superellipse =
+            superellipse_path::<SimpleBezierPath>(Coord2(0.0, 0.0), 20.0, 10.0, exponent, 0.01);
+
+        assert!(superellipse.start_point() == Coord2(20.0, 0.0));
+        assert!(!superellipse.is_clockwise());
+
+        for curve in path_to_curves::<_, Curve<_>>(&superellipse) {
+            for t in 0..=10 {
+                let point = curve.point_at_pos((t as f64) / 10.0);
+                assert!(superellipse_error(point, 20.0, 10.0, exponent) < 0.05);
+            }
+        }
+
+        let bounds = superellipse.bounding_box::<Bounds<_>>();
+        assert!(bounds.min().distance_to(&Coord2(-20.0, -10.0)) < 0.01);
+        assert!(bounds.max().distance_to(&Coord2(20.0, 10.0)) < 0.01);
+    }
+}
+
+#[test]
+fn superellipse_is_symmetrical() {
+    let superellipse =
+        superellipse_path::<SimpleBezierPath>(Coord2(5.0, 5.0), 10.0, 10.0, 3.0, 0.01);
+    let points = superellipse.points().collect::<Vec<_>>();
+
+    // Each quarter is made of the same number of curves
+    assert!(points.len() % 4 == 0);
+
+    let quarter = points.len() / 4;
+    assert!(points[quarter - 1].2.distance_to(&Coord2(5.0, 15.0)) < 1e-9);
+    assert!(points[quarter * 2 - 1].2.distance_to(&Coord2(-5.0, 5.0)) < 1e-9);
+    assert!(points[quarter * 3 - 1].2.distance_to(&Coord2(5.0, -5.0)) < 1e-9);
+    assert!(points[quarter * 4 - 1].2.distance_to(&Coord2(15.0, 5.0)) < 1e-9);
+}
+
+#[test]
+fn squircle_is_between_circle_and_square() {
+    let squircle = squircle_path::<SimpleBezierPath>(Coord2(0.0, 0.0), 10.0, 0.001);
+
+    // The point on the diagonal is at (10 / 2^0.25) on each axis, which is outside the circle but inside the square
+    let diagonal = 10.0 / 2.0f64.powf(0.25);
+    let nearest_diagonal = path_to_curves::<_, Curve<_>>(&squircle)
+        .flat_map(|curve| (0..=100).map(move |t| curve.point_at_pos((t as f64) / 100.0)))
+        .map(|point| point.distance_to(&Coord2(diagonal, diagonal)))
+        .fold(f64::MAX, f64::min);
+
+    assert!(nearest_diagonal < 0.05);
+}
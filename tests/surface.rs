@@ -0,0 +1,354 @@
+use flo_curves::bezier::path::{BezierPathFactory, SimpleBezierPath};
+use flo_curves::bezier::Curve;
+use flo_curves::line::line_to_bezier;
+use flo_curves::surface::{ruled_surface, ruled_surface_rulings, BicubicPatch, CoonsPatch};
+use flo_curves::{BezierCurve, BezierCurveFactory, Coord2, Coord3, Coordinate, Coordinate3D};
+
+fn curved_patch() -> CoonsPatch<Coord2> {
+    CoonsPatch::new(
+        Curve::from_points(
+            Coord2(0.0, 0.0),
+            (Coord2(3.0, -2.0), Coord2(7.0, 2.0)),
+            Coord2(10.0, 0.0),
+        ),
+        Curve::from_points(
+            Coord2(10.0, 0.0),
+            (Coord2(12.0, 3.0), Coord2(9.0, 7.0)),
+            Coord2(11.0, 10.0),
+        ),
+        Curve::from_points(
+            Coord2(11.0, 10.0),
+            (Coord2(7.0, 13.0), Coord2(3.0, 8.0)),
+            Coord2(-1.0, 10.0),
+        ),
+        Curve::from_points(
+            Coord2(-1.0, 10.0),
+            (Coord2(1.0, 7.0), Coord2(-2.0, 3.0)),
+            Coord2(0.0, 0.0),
+        ),
+    )
+}
+
+#[test]
+fn flat_patch_is_bilinear() {
+    let patch = CoonsPatch::new(
+        line_to_bezier::<_, Curve<_>>(&(Coord2(0.0, 0.0), Coord2(10.0, 0.0))),
+        line_to_bezier::<_, Curve<_>>(&(Coord2(10.0, 0.0), Coord2(10.0, 10.0))),
+        line_to_bezier::<_, Curve<_>>(&(Coord2(10.0, 10.0), Coord2(0.0, 10.0))),
+        line_to_bezier::<_, Curve<_>>(&(Coord2(0.0, 10.0), Coord2(0.0, 0.0))),
+    );
+
+    for (u, v) in [(0.0, 0.0), (0.5, 0.5), (0.25, 0.75), (1.0, 0.3)].iter() {
+        let point = patch.point_at(*u, *v);
+        assert!(point.distance_to(&Coord2(u * 10.0, v * 10.0)) < 0.01);
+    }
+}
+
+#[test]
+fn patch_follows_boundaries() {
+    let patch = curved_patch();
+    let [top, right, bottom, left] = patch.boundary_curves();
+
+    for step in 0..=10 {
+        let t = (step as f64) / 10.0;
+
+        assert!(patch.point_at(t, 0.0).distance_to(&top.point_at_pos(t)) < 1e-9);
+        assert!(patch.point_at(1.0, t).distance_to(&right.point_at_pos(t)) < 1e-9);
+        assert!(
+            patch
+                .point_at(1.0 - t, 1.0)
+                .distance_to(&bottom.point_at_pos(t))
+                < 1e-9
+        );
+        assert!(
+            patch
+                .point_at(0.0, 1.0 - t)
+                .distance_to(&left.point_at_pos(t))
+                < 1e-9
+        );
+    }
+
+    assert!(
+        patch.corners()
+            == [
+                Coord2(0.0, 0.0),
+                Coord2(10.0, 0.0),
+                Coord2(11.0, 10.0),
+                Coord2(-1.0, 10.0)
+            ]
+    );
+}
+
+#[test]
+fn iso_curves_are_on_patch() {
+    let patch = curved_patch();
+
+    for fixed in [0.0, 0.3, 0.5, 0.8].iter() {
+        let along_u = patch.curve_at_v(*fixed);
+        let along_v = patch.curve_at_u(*fixed);
+
+        for step in 0..=10 {
+            let t = (step as f64) / 10.0;
+
+            assert!(
+                along_u
+                    .point_at_pos(t)
+                    .distance_to(&patch.point_at(t, *fixed))
+                    < 1e-9
+            );
+            assert!(
+                along_v
+                    .point_at_pos(t)
+                    .distance_to(&patch.point_at(*fixed, t))
+                    < 1e-9
+            );
+        }
+    }
+}
+
+#[test]
+fn subdivided_patches_match_original() {
+    let patch = curved_patch();
+    let [p1, p2, p3, p4] = patch.subdivide(0.5, 0.25);
+
+    for u_step in 0..=4 {
+        for v_step in 0..=4 {
+            let (u, v) = ((u_step as f64) / 4.0, (v_step as f64) / 4.0);
+
+            assert!(
+                p1.point_at(u, v)
+                    .distance_to(&patch.point_at(u * 0.5, v * 0.25))
+                    < 1e-9
+            );
+            assert!(
+                p2.point_at(u, v)
+                    .distance_to(&patch.point_at(0.5 + u * 0.5, v * 0.25))
+                    < 1e-9
+            );
+            assert!(
+                p3.point_at(u, v)
+                    .distance_to(&patch.point_at(0.5 + u * 0.5, 0.25 + v * 0.75))
+                    < 1e-9
+            );
+            assert!(
+                p4.point_at(u, v)
+                    .distance_to(&patch.point_at(u * 0.5, 0.25 + v * 0.75))
+                    < 1e-9
+            );
+        }
+    }
+}
+
+fn wavy_bicubic_patch() -> BicubicPatch<Coord3> {
+    let heights = [
+        [0.0, 1.0, -1.0, 0.5],
+        [2.0, -1.0, 3.0, 0.0],
+        [-1.0, 2.0, 1.0, -2.0],
+        [0.0, 0.5, 1.5, 1.0],
+    ];
+
+    let mut control_points = [[Coord3::origin(); 4]; 4];
+    for v_idx in 0..4 {
+        for u_idx in 0..4 {
+            control_points[v_idx][u_idx] = Coord3(
+                (u_idx as f64) * 3.0 + (v_idx as f64) * 0.5,
+                (v_idx as f64) * 2.0,
+                heights[v_idx][u_idx],
+            );
+        }
+    }
+
+    BicubicPatch::new(control_points)
+}
+
+#[test]
+fn flat_bicubic_patch_has_vertical_normal() {
+    let mut control_points = [[Coord3::origin(); 4]; 4];
+    for (v_idx, row) in control_points.iter_mut().enumerate() {
+        for (u_idx, point) in row.iter_mut().enumerate() {
+            *point = Coord3(u_idx as f64, v_idx as f64, 0.0);
+        }
+    }
+    let patch = BicubicPatch::new(control_points);
+
+    for (u, v) in [(0.0, 0.0), (0.3, 0.7), (0.5, 0.5), (1.0, 0.2)] {
+        let point = patch.point_at(u, v);
+        let normal = patch.normal_at(u, v).unwrap();
+
+        assert!(point.is_near_to(&Coord3(u * 3.0, v * 3.0, 0.0), 1e-9));
+        assert!(normal.is_near_to(&Coord3(0.0, 0.0, 1.0), 1e-9));
+    }
+}
+
+#[test]
+fn bicubic_patch_passes_through_corners() {
+    let patch = wavy_bicubic_patch();
+    let cp = patch.control_points;
+
+    assert!(patch.point_at(0.0, 0.0).is_near_to(&cp[0][0], 1e-9));
+    assert!(patch.point_at(1.0, 0.0).is_near_to(&cp[0][3], 1e-9));
+    assert!(patch.point_at(1.0, 1.0).is_near_to(&cp[3][3], 1e-9));
+    assert!(patch.point_at(0.0, 1.0).is_near_to(&cp[3][0], 1e-9));
+}
+
+#[test]
+fn bicubic_iso_curves_are_on_patch() {
+    let patch = wavy_bicubic_patch();
+
+    for pos in [0.0, 0.25, 0.6, 1.0] {
+        let along_u = patch.curve_at_v(pos);
+        let along_v = patch.curve_at_u(pos);
+
+        for t in [0.0, 0.1, 0.5, 0.8, 1.0] {
+            assert!(along_u
+                .point_at_pos(t)
+                .is_near_to(&patch.point_at(t, pos), 1e-9));
+            assert!(along_v
+                .point_at_pos(t)
+                .is_near_to(&patch.point_at(pos, t), 1e-9));
+        }
+    }
+}
+
+#[test]
+fn bicubic_tangents_match_finite_differences() {
+    let patch = wavy_bicubic_patch();
+    let delta = 1e-6;
+
+    for (u, v) in [(0.2, 0.3), (0.5, 0.5), (0.9, 0.1)] {
+        let du = (patch.point_at(u + delta, v) - patch.point_at(u - delta, v)) * (0.5 / delta);
+        let dv = (patch.point_at(u, v + delta) - patch.point_at(u, v - delta)) * (0.5 / delta);
+
+        assert!(patch.tangent_u(u, v).is_near_to(&du, 1e-4));
+        assert!(patch.tangent_v(u, v).is_near_to(&dv, 1e-4));
+
+        // The normal should be perpendicular to both tangents
+        let normal = patch.normal_at(u, v).unwrap();
+        assert!((normal.magnitude() - 1.0).abs() < 1e-9);
+        assert!(normal.dot(&du).abs() < 1e-4);
+        assert!(normal.dot(&dv).abs() < 1e-4);
+    }
+}
+
+#[test]
+fn degenerate_bicubic_patch_has_no_normal() {
+    let patch = BicubicPatch::new([[Coord3(1.0, 2.0, 3.0); 4]; 4]);
+
+    assert!(patch.normal_at(0.5, 0.5).is_none());
+}
+
+#[test]
+fn coord3_components() {
+    let point = Coord3(1.0, 2.0, 3.0);
+
+    assert!(point.x() == 1.0 && point.y() == 2.0 && point.z() == 3.0);
+    assert!(Coord3::len() == 3);
+    assert!((point - Coord3(1.0, 0.0, 3.0)).magnitude() == 2.0);
+    assert!(point.dot(&Coord3(1.0, 1.0, 1.0)) == 6.0);
+}
+
+#[test]
+fn ruled_surface_follows_both_paths() {
+    let path_a = SimpleBezierPath::from_points(
+        Coord2(0.0, 0.0),
+        vec![
+            (Coord2(2.0, 3.0), Coord2(5.0, 3.0), Coord2(7.0, 0.0)),
+            (Coord2(8.0, -1.0), Coord2(9.0, -1.0), Coord2(10.0, 0.0)),
+        ],
+    );
+    let path_b = SimpleBezierPath::from_points(
+        Coord2(0.0, 10.0),
+        vec![(Coord2(3.0, 14.0), Coord2(7.0, 6.0), Coord2(10.0, 10.0))],
+    );
+
+    let patches = ruled_surface(&path_a, &path_b);
+
+    // Split at the join in path_a
+    assert!(patches.len() == 2);
+
+    // The patches join up along the paths
+    assert!(patches[0]
+        .point_at(0.0, 0.0)
+        .is_near_to(&Coord2(0.0, 0.0), 1e-9));
+    assert!(patches[0]
+        .point_at(0.0, 1.0)
+        .is_near_to(&Coord2(0.0, 10.0), 1e-9));
+    assert!(patches[0]
+        .point_at(1.0, 0.0)
+        .is_near_to(&Coord2(7.0, 0.0), 1e-9));
+    assert!(patches[1]
+        .point_at(0.0, 0.5)
+        .is_near_to(&patches[0].point_at(1.0, 0.5), 1e-9));
+    assert!(patches[1]
+        .point_at(1.0, 0.0)
+        .is_near_to(&Coord2(10.0, 0.0), 1e-9));
+    assert!(patches[1]
+        .point_at(1.0, 1.0)
+        .is_near_to(&Coord2(10.0, 10.0), 1e-9));
+
+    // Curves across the patch are straight lines
+    for patch in patches.iter() {
+        for u in [0.1, 0.5, 0.9] {
+            let start = patch.point_at(u, 0.0);
+            let end = patch.point_at(u, 1.0);
+
+            for v in [0.25, 0.5, 0.75] {
+                let expected = start * (1.0 - v) + end * v;
+                assert!(patch.point_at(u, v).is_near_to(&expected, 1e-9));
+            }
+        }
+    }
+}
+
+#[test]
+fn ruled_surface_rulings_between_space_curves() {
+    let path_a: (Coord3, Vec<(Coord3, Coord3, Coord3)>) = BezierPathFactory::from_points(
+        Coord3(0.0, 0.0, 0.0),
+        vec![(
+            Coord3(1.0, 0.0, 0.0),
+            Coord3(2.0, 0.0, 0.0),
+            Coord3(3.0, 0.0, 0.0),
+        )],
+    );
+    let path_b: (Coord3, Vec<(Coord3, Coord3, Coord3)>) = BezierPathFactory::from_points(
+        Coord3(0.0, 1.0, 2.0),
+        vec![
+            (
+                Coord3(1.0, 1.0, 2.0),
+                Coord3(2.0, 1.0, 2.0),
+                Coord3(3.0, 1.0, 2.0),
+            ),
+            (
+                Coord3(4.0, 1.0, 2.0),
+                Coord3(5.0, 1.0, 2.0),
+                Coord3(6.0, 1.0, 2.0),
+            ),
+        ],
+    );
+
+    let rulings = ruled_surface_rulings(&path_a, &path_b, 4);
+
+    assert!(rulings.len() == 4);
+    for (idx, ruling) in rulings.iter().enumerate() {
+        let proportion = (idx as f64) / 3.0;
+
+        // Points are evenly spaced along each path, even though path_b is twice as long and made of two sections
+        assert!(ruling
+            .start_point()
+            .is_near_to(&Coord3(3.0 * proportion, 0.0, 0.0), 1e-4));
+        assert!(ruling
+            .end_point()
+            .is_near_to(&Coord3(6.0 * proportion, 1.0, 2.0), 1e-4));
+    }
+}
+
+#[test]
+fn ruled_surface_of_empty_path() {
+    let path_a = SimpleBezierPath::from_points(Coord2(0.0, 0.0), vec![]);
+    let path_b = SimpleBezierPath::from_points(
+        Coord2(0.0, 10.0),
+        vec![(Coord2(3.0, 14.0), Coord2(7.0, 6.0), Coord2(10.0, 10.0))],
+    );
+
+    assert!(ruled_surface(&path_a, &path_b).is_empty());
+}
@@ -1,5 +1,6 @@
+use flo_curves::bezier::{BezierCurve, BezierCurveFactory, Curve};
 use flo_curves::geo::{
-    sweep_against, sweep_self, BoundingBox, Bounds, Coord2, Coordinate, Coordinate2D, Coordinate3D,
+    sweep, sweep_against, sweep_self, BoundingBox, Bounds, Coord2, Coordinate2D,
 };
 
 use rand::prelude::*;
@@ -236,3 +237,46 @@ fn sweep_self_1000_random() {
 
     assert!(collisions.len() == slow_collisions.len());
 }
+
+#[test]
+fn sort_for_sweep_orders_by_min_x() {
+    let mut bounds = vec![
+        Bounds::from_min_max(Coord2(300.0, 200.0), Coord2(400.0, 300.0)),
+        Bounds::from_min_max(Coord2(100.0, 250.0), Coord2(250.0, 350.0)),
+        Bounds::from_min_max(Coord2(200.0, 0.0), Coord2(210.0, 10.0)),
+    ];
+    sweep::sort_for_sweep(&mut bounds);
+
+    assert!(bounds[0].min().x() == 100.0);
+    assert!(bounds[1].min().x() == 200.0);
+    assert!(bounds[2].min().x() == 300.0);
+}
+
+#[test]
+fn sweep_against_different_types() {
+    // Curves can be swept against plain bounding boxes
+    let mut curves = vec![
+        Curve::from_points(
+            Coord2(100.0, 100.0),
+            (Coord2(120.0, 120.0), Coord2(140.0, 140.0)),
+            Coord2(160.0, 160.0),
+        ),
+        Curve::from_points(
+            Coord2(500.0, 100.0),
+            (Coord2(520.0, 120.0), Coord2(540.0, 140.0)),
+            Coord2(560.0, 160.0),
+        ),
+    ];
+    let mut bounds = vec![
+        Bounds::from_min_max(Coord2(150.0, 150.0), Coord2(200.0, 200.0)),
+        Bounds::from_min_max(Coord2(0.0, 0.0), Coord2(50.0, 50.0)),
+    ];
+    sweep::sort_for_sweep(&mut curves);
+    sweep::sort_for_sweep(&mut bounds);
+
+    let collisions = sweep::sweep_against(curves.iter(), bounds.iter()).collect::<Vec<_>>();
+
+    assert!(collisions.len() == 1);
+    assert!(collisions[0].0.start_point() == Coord2(100.0, 100.0));
+    assert!(collisions[0].1.min() == Coord2(150.0, 150.0));
+}
@@ -0,0 +1,119 @@
+#![cfg(feature = "testing")]
+
+use flo_curves::bezier::path::{path_signed_area, BezierPath, SimpleBezierPath};
+use flo_curves::bezier::{curve_intersects_curve_clip, Curve};
+use flo_curves::geo::Bounds;
+use flo_curves::testing::{random_curve, random_simple_polygon, random_smooth_path};
+use flo_curves::{BezierCurve, BoundingBox, Coord2, Coordinate};
+
+use rand::prelude::*;
+
+fn seeded_rng() -> StdRng {
+    StdRng::from_seed([
+        0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24,
+        25, 26, 27, 28, 29, 30, 31,
+    ])
+}
+
+///
+/// True if any two curves in a path cross each other (other than where neighbouring curves meet)
+///
+fn self_intersects(path: &SimpleBezierPath) -> bool {
+    let curves = path.to_curves::<Curve<_>>();
+
+    for idx1 in 0..curves.len() {
+        for idx2 in (idx1 + 2)..curves.len() {
+            if idx1 == 0 && idx2 == curves.len() - 1 {
+                continue;
+            }
+
+            if !curve_intersects_curve_clip(&curves[idx1], &curves[idx2], 0.01).is_empty() {
+                return true;
+            }
+        }
+    }
+
+    false
+}
+
+#[test]
+fn random_curves_are_inside_bounds() {
+    let mut rng = seeded_rng();
+    let bounds = Bounds::from_min_max(Coord2(10.0, 20.0), Coord2(30.0, 50.0));
+
+    for _ in 0..100 {
+        let curve = random_curve::<Curve<Coord2>, _, _>(&mut rng, &bounds);
+        let curve_bounds = curve.bounding_box::<Bounds<Coord2>>();
+
+        assert!(bounds.contains_bounds(&curve_bounds));
+    }
+}
+
+#[test]
+fn random_polygons_are_simple() {
+    let mut rng = seeded_rng();
+
+    for num_points in 3..20 {
+        let polygon = random_simple_polygon::<SimpleBezierPath, _>(
+            &mut rng,
+            Coord2(100.0, 100.0),
+            50.0,
+            num_points,
+        );
+
+        assert!(polygon.1.len() == num_points);
+        assert!(path_signed_area(&polygon) > 0.0);
+        assert!(!self_intersects(&polygon));
+
+        for (_, _, point) in polygon.points() {
+            assert!(point.distance_to(&Coord2(100.0, 100.0)) <= 50.0 + 1e-9);
+        }
+    }
+}
+
+#[test]
+fn random_smooth_paths_are_simple() {
+    let mut rng = seeded_rng();
+
+    for complexity in 0..8 {
+        let path = random_smooth_path::<SimpleBezierPath, _>(
+            &mut rng,
+            Coord2(100.0, 100.0),
+            50.0,
+            complexity,
+        );
+
+        assert!(path.1.len() == 4 * (complexity + 1));
+        assert!(path.1.last().unwrap().2 == path.0);
+        assert!(path_signed_area(&path) > 0.0);
+        assert!(!self_intersects(&path));
+
+        for (_, _, point) in path.points() {
+            let distance = point.distance_to(&Coord2(100.0, 100.0));
+            assert!((25.0 - 1e-9..=50.0 + 1e-9).contains(&distance));
+        }
+    }
+}
+
+#[test]
+fn zero_complexity_is_a_circle() {
+    let path =
+        random_smooth_path::<SimpleBezierPath, _>(&mut seeded_rng(), Coord2(0.0, 0.0), 40.0, 0);
+
+    for curve in path.to_curves::<Curve<_>>() {
+        for idx in 0..=10 {
+            let distance = curve.point_at_pos((idx as f64) / 10.0).magnitude();
+            assert!((distance - 30.0).abs() < 0.01);
+        }
+    }
+}
+
+#[test]
+fn same_seed_generates_same_path() {
+    let path1 =
+        random_smooth_path::<SimpleBezierPath, _>(&mut seeded_rng(), Coord2(0.0, 0.0), 40.0, 5);
+    let path2 =
+        random_smooth_path::<SimpleBezierPath, _>(&mut seeded_rng(), Coord2(0.0, 0.0), 40.0, 5);
+
+    assert!(path1 == path2);
+}
@@ -0,0 +1,295 @@
+use flo_curves::arc::Circle;
+use flo_curves::bezier::path::{path_to_curves, BezierPath, GraphPath, SimpleBezierPath};
+use flo_curves::bezier::{perspective_transform_path, BezierCurve, BezierCurveFactory, Curve};
+use flo_curves::geo::{
+    BoundingBox, Bounds, Coord2, Coordinate, PerspectiveTransform2D, Transform2D, Transformable,
+};
+
+use std::f64;
+
+#[test]
+fn translate_point() {
+    let point = Coord2(1.0, 2.0).transform(&Transform2D::translate(10.0, 20.0));
+
+    assert!(point == Coord2(11.0, 22.0));
+}
+
+#[test]
+fn rotate_point() {
+    let point = Coord2(1.0, 0.0).transform(&Transform2D::rotate(f64::consts::PI / 2.0));
+
+    assert!(point.distance_to(&Coord2(0.0, 1.0)) < 1e-9);
+}
+
+#[test]
+fn rotate_point_around_center() {
+    let transform = Transform2D::rotate_around(&Coord2(5.0, 5.0), f64::consts::PI);
+    let point = Coord2(6.0, 5.0).transform(&transform);
+
+    assert!(point.distance_to(&Coord2(4.0, 5.0)) < 1e-9);
+}
+
+#[test]
+fn skew_point() {
+    let point = Coord2(0.0, 2.0).transform(&Transform2D::skew(f64::consts::PI / 4.0, 0.0));
+
+    assert!(point.distance_to(&Coord2(2.0, 2.0)) < 1e-9);
+}
+
+#[test]
+fn compose_in_order() {
+    // Scale then translate is different from translate then scale
+    let scale_then_translate = Transform2D::scale(2.0, 2.0).then(&Transform2D::translate(1.0, 0.0));
+    let translate_then_scale = Transform2D::translate(1.0, 0.0).then(&Transform2D::scale(2.0, 2.0));
+
+    assert!(Coord2(1.0, 1.0).transform(&scale_then_translate) == Coord2(3.0, 2.0));
+    assert!(Coord2(1.0, 1.0).transform(&translate_then_scale) == Coord2(4.0, 2.0));
+
+    // Multiplication applies the right-hand transform first
+    assert!(
+        Transform2D::translate(1.0, 0.0) * Transform2D::scale(2.0, 2.0) == scale_then_translate
+    );
+}
+
+#[test]
+fn invert_transform() {
+    let transform = Transform2D::rotate(0.3)
+        .then(&Transform2D::scale(2.0, 3.0))
+        .then(&Transform2D::skew(0.2, 0.1))
+        .then(&Transform2D::translate(5.0, -7.0));
+    let inverse = transform.invert().unwrap();
+
+    let point = Coord2(3.0, 4.0);
+    assert!(
+        point
+            .transform(&transform)
+            .transform(&inverse)
+            .distance_to(&point)
+            < 1e-9
+    );
+
+    assert!(Transform2D::scale(0.0, 1.0).invert().is_none());
+}
+
+#[test]
+fn transform_curve_control_points() {
+    let curve = Curve::from_points(
+        Coord2(0.0, 0.0),
+        (Coord2(1.0, 2.0), Coord2(3.0, 4.0)),
+        Coord2(5.0, 0.0),
+    );
+    let transform = Transform2D::rotate(0.7).then(&Transform2D::translate(2.0, 3.0));
+    let transformed = curve.transform(&transform);
+
+    // Affine transforms map points on the curve to points on the transformed curve
+    for t in 0..=10 {
+        let t = (t as f64) / 10.0;
+        let expected = curve.point_at_pos(t).transform(&transform);
+
+        assert!(transformed.point_at_pos(t).distance_to(&expected) < 1e-9);
+    }
+}
+
+#[test]
+fn transform_bounds() {
+    let bounds = Bounds::from_min_max(Coord2(0.0, 0.0), Coord2(2.0, 2.0));
+    let rotated = bounds.transform(&Transform2D::rotate_around(
+        &Coord2(1.0, 1.0),
+        f64::consts::PI / 4.0,
+    ));
+
+    let half_diagonal = 2.0f64.sqrt();
+    assert!(
+        rotated
+            .min()
+            .distance_to(&Coord2(1.0 - half_diagonal, 1.0 - half_diagonal))
+            < 1e-9
+    );
+    assert!(
+        rotated
+            .max()
+            .distance_to(&Coord2(1.0 + half_diagonal, 1.0 + half_diagonal))
+            < 1e-9
+    );
+}
+
+#[test]
+fn transform_path() {
+    let circle = Circle::new(Coord2(0.0, 0.0), 1.0).to_path::<SimpleBezierPath>();
+    let transformed =
+        circle.transform(&Transform2D::scale(2.0, 3.0).then(&Transform2D::translate(10.0, 10.0)));
+
+    assert!(
+        transformed.start_point()
+            == circle
+                .start_point()
+                .transform(&Transform2D::scale(2.0, 3.0).then(&Transform2D::translate(10.0, 10.0)))
+    );
+
+    let bounds = transformed.bounding_box::<Bounds<_>>();
+    assert!(bounds.min().distance_to(&Coord2(8.0, 7.0)) < 0.01);
+    assert!(bounds.max().distance_to(&Coord2(12.0, 13.0)) < 0.01);
+}
+
+#[test]
+fn transform_graph_path() {
+    let circle = Circle::new(Coord2(0.0, 0.0), 1.0).to_path::<SimpleBezierPath>();
+    let transform = Transform2D::translate(5.0, 0.0);
+
+    let graph_path = GraphPath::from_path(&circle, ());
+    let transformed = graph_path.transform(&transform);
+    let expected = GraphPath::from_path(&circle.transform(&transform), ());
+
+    assert!(transformed.num_points() == expected.num_points());
+
+    for point_idx in 0..transformed.num_points() {
+        for (edge, expected_edge) in transformed
+            .edges_for_point(point_idx)
+            .zip(expected.edges_for_point(point_idx))
+        {
+            assert!(edge.start_point().distance_to(&expected_edge.start_point()) < 1e-9);
+            assert!(edge.end_point().distance_to(&expected_edge.end_point()) < 1e-9);
+        }
+    }
+}
+
+#[test]
+fn unit_square_to_quad_maps_corners() {
+    let quad = [
+        Coord2(1.0, 1.0),
+        Coord2(5.0, 2.0),
+        Coord2(4.0, 6.0),
+        Coord2(0.0, 3.0),
+    ];
+    let transform = PerspectiveTransform2D::unit_square_to_quad(&quad).unwrap();
+    let square = [
+        Coord2(0.0, 0.0),
+        Coord2(1.0, 0.0),
+        Coord2(1.0, 1.0),
+        Coord2(0.0, 1.0),
+    ];
+
+    for (corner, expected) in square.iter().zip(quad.iter()) {
+        assert!(
+            transform
+                .transform_point(corner)
+                .unwrap()
+                .distance_to(expected)
+                < 1e-9
+        );
+    }
+}
+
+#[test]
+fn quad_to_quad_and_invert() {
+    let from = [
+        Coord2(0.0, 0.0),
+        Coord2(10.0, 0.0),
+        Coord2(10.0, 10.0),
+        Coord2(0.0, 10.0),
+    ];
+    let to = [
+        Coord2(2.0, 0.0),
+        Coord2(8.0, 1.0),
+        Coord2(12.0, 9.0),
+        Coord2(-1.0, 7.0),
+    ];
+    let transform = PerspectiveTransform2D::quad_to_quad(&from, &to).unwrap();
+    let inverse = transform.invert().unwrap();
+
+    for (corner, expected) in from.iter().zip(to.iter()) {
+        assert!(
+            transform
+                .transform_point(corner)
+                .unwrap()
+                .distance_to(expected)
+                < 1e-9
+        );
+    }
+
+    let point = Coord2(3.0, 7.0);
+    let round_trip = inverse
+        .transform_point(&transform.transform_point(&point).unwrap())
+        .unwrap();
+    assert!(round_trip.distance_to(&point) < 1e-9);
+}
+
+#[test]
+fn degenerate_quad_has_no_transform() {
+    let quad = [
+        Coord2(0.0, 0.0),
+        Coord2(1.0, 1.0),
+        Coord2(2.0, 2.0),
+        Coord2(3.0, 3.0),
+    ];
+
+    assert!(PerspectiveTransform2D::unit_square_to_quad(&quad).is_none());
+}
+
+#[test]
+fn perspective_from_affine_matches_affine() {
+    let affine = Transform2D::rotate(0.4).then(&Transform2D::translate(3.0, -2.0));
+    let perspective = PerspectiveTransform2D::from(affine);
+    let point = Coord2(2.0, 5.0);
+
+    assert!(
+        perspective
+            .transform_point(&point)
+            .unwrap()
+            .distance_to(&point.transform(&affine))
+            < 1e-9
+    );
+}
+
+#[test]
+fn perspective_transform_path_is_within_tolerance() {
+    let circle = Circle::new(Coord2(5.0, 5.0), 4.0).to_path::<SimpleBezierPath>();
+    let bounds = Bounds::from_min_max(Coord2(0.0, 0.0), Coord2(10.0, 10.0));
+    let quad = [
+        Coord2(0.0, 0.0),
+        Coord2(20.0, 0.0),
+        Coord2(14.0, 8.0),
+        Coord2(6.0, 8.0),
+    ];
+    let transform = PerspectiveTransform2D::bounds_to_quad(&bounds, &quad).unwrap();
+
+    let max_error = 0.01;
+    let transformed =
+        perspective_transform_path::<_, SimpleBezierPath>(&circle, &transform, max_error).unwrap();
+
+    assert!(
+        transformed
+            .start_point()
+            .distance_to(&transform.transform_point(&circle.start_point()).unwrap())
+            < 1e-9
+    );
+
+    // Every point on the original path should map to somewhere close to the transformed path
+    let transformed_points = path_to_curves::<_, Curve<_>>(&transformed)
+        .flat_map(|curve| (0..=2000).map(move |t| curve.point_at_pos((t as f64) / 2000.0)))
+        .collect::<Vec<_>>();
+
+    for curve in path_to_curves::<_, Curve<_>>(&circle) {
+        for t in 0..=20 {
+            let expected = transform
+                .transform_point(&curve.point_at_pos((t as f64) / 20.0))
+                .unwrap();
+            let distance = transformed_points
+                .iter()
+                .map(|point| point.distance_to(&expected))
+                .fold(f64::MAX, f64::min);
+
+            assert!(distance < max_error);
+        }
+    }
+}
+
+#[test]
+fn perspective_transform_path_behind_horizon() {
+    let circle = Circle::new(Coord2(0.0, 0.0), 4.0).to_path::<SimpleBezierPath>();
+
+    // The line x = 1 is the horizon of this transformation
+    let transform = PerspectiveTransform2D([[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [-1.0, 0.0, 1.0]]);
+
+    assert!(perspective_transform_path::<_, SimpleBezierPath>(&circle, &transform, 0.01).is_none());
+}